@@ -0,0 +1,32 @@
+-- =============================================================================
+-- 번역 용어집(glossary) + QA 린트 결과 적재
+-- =============================================================================
+-- 목적: 주어/서술어/부사어 등 문법 용어가 guide·study 해설 번역마다 제각각 옮겨지는
+--   문제 대응. 언어별 "한국어 용어 → 필수 번역어 + 금지 변형" 표를 두고,
+--   QA 린트(admin/translation/qa.rs) 결과를 content_translations 행에 적재.
+-- QA 결과 컬럼은 번역문 변경 시 NULL 로 초기화 (= 미검사) — 재스캔 전까지 stale.
+-- 오류(qa_error_count > 0) 가 남은 번역은 approved 전환 불가 (service 레이어 가드).
+-- =============================================================================
+
+CREATE TABLE translation_glossary (
+    glossary_id          BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    lang                 supported_language_enum NOT NULL,
+    term_ko              VARCHAR(100) NOT NULL,          -- 원문 용어 (예: '주어')
+    required_translation VARCHAR(200) NOT NULL,          -- 필수 번역어 (예: 'subject')
+    forbidden_variants   TEXT[] NOT NULL DEFAULT '{}',   -- 금지 변형 (예: {'topic','agent'})
+    glossary_note        TEXT,
+    updated_by_user_id   BIGINT,
+    glossary_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    glossary_updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_glossary_lang_term UNIQUE (lang, term_ko)
+);
+
+ALTER TABLE content_translations
+    ADD COLUMN qa_error_count   INT,
+    ADD COLUMN qa_warning_count INT,
+    ADD COLUMN qa_issues        JSONB,
+    ADD COLUMN qa_checked_at    TIMESTAMPTZ;
+
+-- 언어별 QA 대시보드 집계용
+CREATE INDEX idx_ct_lang_qa ON content_translations (lang, qa_error_count);
@@ -0,0 +1,32 @@
+-- =============================================================================
+-- 콘텐츠 공개(open) 전 번역 커버리지 정책
+-- =============================================================================
+-- 목적: admin 에서 video / lesson / study 를 state=open 으로 전환할 때 대상 언어
+--   번역이 대부분 비어 있어도 그대로 공개되던 문제 대응.
+-- 콘텐츠 타입별 1행 — required_fields 중 원문이 있는 필드는 required_langs 모두
+--   min_status 이상 번역이 있어야 공개 가능 (service 레이어 가드, 미충족 시 422).
+-- HYMN 은 force_publish 로 우회 가능 — admin_action_log 에 PUBLISH_OVERRIDE 기록.
+-- 기본값: 교재 최초 20개 언어 (ko/en 제외 user_language_enum 과 동일) × approved.
+-- =============================================================================
+
+CREATE TABLE content_publish_policy (
+    policy_content_type content_type_enum PRIMARY KEY,           -- video | lesson | study
+    required_langs      supported_language_enum[] NOT NULL DEFAULT '{}',
+    required_fields     TEXT[] NOT NULL DEFAULT '{}',            -- source-fields 의 field_name
+    min_status          translation_status_enum NOT NULL DEFAULT 'approved',
+    policy_enabled      BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by_user_id  BIGINT,
+    policy_updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO content_publish_policy (policy_content_type, required_langs, required_fields)
+VALUES
+    ('video',
+     '{ja,zh_cn,zh_tw,vi,th,id,my,mn,ru,es,pt,fr,de,hi,ne,si,km,uz,kk,tg}',
+     '{video_title,video_subtitle,video_tag_title,video_tag_subtitle}'),
+    ('lesson',
+     '{ja,zh_cn,zh_tw,vi,th,id,my,mn,ru,es,pt,fr,de,hi,ne,si,km,uz,kk,tg}',
+     '{lesson_title,lesson_subtitle,lesson_description}'),
+    ('study',
+     '{ja,zh_cn,zh_tw,vi,th,id,my,mn,ru,es,pt,fr,de,hi,ne,si,km,uz,kk,tg}',
+     '{study_title,study_subtitle,study_description}');
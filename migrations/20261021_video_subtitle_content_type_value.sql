@@ -0,0 +1,10 @@
+-- =============================================================================
+-- 비디오 자막 큐 — content_type_enum 값 추가
+-- =============================================================================
+-- 목적: 한국어(원문) 자막 트랙의 큐 텍스트를 content_translations 공유 표에
+--   (content_type='video_subtitle_cue', content_id=cue_id, field_name='cue_text', lang)
+--   로 적재해 기존 번역 워크플로(draft → reviewed → approved, QA 린트)를 그대로 사용.
+-- 단독 마이그레이션: ALTER TYPE ADD VALUE 안전성 (선례 20260212/20260517/20260612).
+-- =============================================================================
+
+ALTER TYPE content_type_enum ADD VALUE IF NOT EXISTS 'video_subtitle_cue';
@@ -0,0 +1,45 @@
+-- =============================================================================
+-- 비디오 자막 트랙 + 큐
+-- =============================================================================
+-- 트랙: video × supported_language 당 1개 (SRT/VTT 업로드 → WebVTT 큐로 정규화).
+-- 큐: 트랙 내 순번(cue_seq) 기준 재업로드 시 cue_id 유지 — ko 트랙 큐 번역
+--   (content_type='video_subtitle_cue') 이 타이밍 수정만으로 끊기지 않도록.
+-- 업로드 트랙이 없는 언어는 ko 큐 번역이 전부 approved 일 때 번역 트랙으로 제공.
+-- video_subtitle_vimeo: Vimeo 로 push 한 텍스트 트랙 URI (업로드/번역 트랙 공통, 재push 시 교체).
+-- =============================================================================
+
+CREATE TABLE video_subtitle (
+    video_subtitle_id      BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    video_id               INT NOT NULL REFERENCES video (video_id) ON DELETE CASCADE,
+    lang                   supported_language_enum NOT NULL,
+    subtitle_label         VARCHAR(100),
+    subtitle_source_format VARCHAR(10) NOT NULL,                 -- srt | vtt
+    updated_by_user_id     BIGINT,
+    subtitle_created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    subtitle_updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_video_subtitle_video_lang UNIQUE (video_id, lang),
+    CONSTRAINT chk_video_subtitle_format CHECK (subtitle_source_format IN ('srt', 'vtt'))
+);
+
+CREATE TABLE video_subtitle_cue (
+    cue_id            BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    video_subtitle_id BIGINT NOT NULL REFERENCES video_subtitle(video_subtitle_id) ON DELETE CASCADE,
+    cue_seq           INT NOT NULL,
+    cue_start_ms      INT NOT NULL,
+    cue_end_ms        INT NOT NULL,
+    cue_text          TEXT NOT NULL,
+
+    CONSTRAINT uq_video_subtitle_cue_seq UNIQUE (video_subtitle_id, cue_seq),
+    CONSTRAINT chk_video_subtitle_cue_time CHECK (cue_end_ms > cue_start_ms AND cue_start_ms >= 0)
+);
+
+CREATE TABLE video_subtitle_vimeo (
+    video_id            INT NOT NULL REFERENCES video (video_id) ON DELETE CASCADE,
+    lang                supported_language_enum NOT NULL,
+    vimeo_texttrack_uri VARCHAR(255) NOT NULL,
+    pushed_by_user_id   BIGINT,
+    pushed_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (video_id, lang)
+);
@@ -0,0 +1,22 @@
+-- =============================================================================
+-- 비디오 구간 시청 기록 (merged interval set)
+-- =============================================================================
+-- 클라이언트가 보고한 시청 구간 [start, end) 을 사용자×비디오 단위로 병합해 저장.
+-- 완료 판정은 progress_rate 자기신고 대신 실제 커버리지(video_watched_sec / video_duration).
+-- 관리자 통계의 시청 유지율 히트맵도 이 테이블에서 초 단위 버킷으로 집계.
+-- =============================================================================
+
+ALTER TABLE video_log
+    ADD COLUMN video_watched_sec INT NOT NULL DEFAULT 0;              -- 병합 구간 총 길이 (중복 시청 제외)
+
+CREATE TABLE video_watch_range (
+    user_id         BIGINT NOT NULL,
+    video_id        INT NOT NULL REFERENCES video (video_id) ON DELETE CASCADE,
+    range_start_sec INT NOT NULL,
+    range_end_sec   INT NOT NULL,
+
+    PRIMARY KEY (user_id, video_id, range_start_sec),
+    CONSTRAINT chk_video_watch_range CHECK (range_end_sec > range_start_sec AND range_start_sec >= 0)
+);
+
+CREATE INDEX idx_video_watch_range_video ON video_watch_range (video_id);
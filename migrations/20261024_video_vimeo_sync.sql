@@ -0,0 +1,41 @@
+-- =============================================================================
+-- Vimeo 메타데이터 주기 동기화
+-- =============================================================================
+-- video_vimeo_sync: 비디오별 마지막 동기화 결과 + Vimeo 측 스냅샷.
+--   Vimeo 에서 제목/설명이 바뀐 경우(스냅샷 대비)에만 video_tag 제목을 갱신해
+--   관리자가 직접 수정한 제목을 매 주기 덮어쓰지 않는다. duration/thumbnail 은 항상 Vimeo 기준.
+--   sync_status: ok | not_found (404) | private (403 / privacy=nobody) | error
+-- vimeo_sync_run: 실행 리포트 (schedule | manual). run_finished_at NULL = 실행 중.
+-- =============================================================================
+
+CREATE TABLE video_vimeo_sync (
+    video_id          INT PRIMARY KEY REFERENCES video (video_id) ON DELETE CASCADE,
+    sync_status       VARCHAR(20) NOT NULL,
+    vimeo_name        TEXT,
+    vimeo_description TEXT,
+    sync_error        TEXT,
+    last_synced_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_ok_at        TIMESTAMPTZ,
+
+    CONSTRAINT chk_video_vimeo_sync_status
+        CHECK (sync_status IN ('ok', 'not_found', 'private', 'error'))
+);
+
+CREATE INDEX idx_video_vimeo_sync_status ON video_vimeo_sync (sync_status)
+    WHERE sync_status <> 'ok';
+
+CREATE TABLE vimeo_sync_run (
+    run_id               BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    run_trigger          VARCHAR(10) NOT NULL,                   -- schedule | manual
+    run_full             BOOLEAN NOT NULL DEFAULT TRUE,          -- 전체 실행만 동시 실행 1개로 제한
+    triggered_by_user_id BIGINT,
+    run_started_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    run_finished_at      TIMESTAMPTZ,
+    checked_count        INT NOT NULL DEFAULT 0,
+    updated_count        INT NOT NULL DEFAULT 0,
+    not_found_count      INT NOT NULL DEFAULT 0,
+    private_count        INT NOT NULL DEFAULT 0,
+    failed_count         INT NOT NULL DEFAULT 0,
+
+    CONSTRAINT chk_vimeo_sync_run_trigger CHECK (run_trigger IN ('schedule', 'manual'))
+);
@@ -0,0 +1,21 @@
+-- =============================================================================
+-- 비디오 호스트 선택 (Vimeo | 자체 HLS)
+-- =============================================================================
+-- video_host: vimeo (기존, video_url_vimeo 필수) | hls (video_hls_path 필수)
+--   hls: HLS_ROOT_DIR 하위의 패키징 완료 디렉터리 (master.m3u8 + 세그먼트 + enc.key)
+--   video_url_vimeo 는 hls 비디오에서 NULL 허용으로 완화.
+-- =============================================================================
+
+CREATE TYPE video_host_enum AS ENUM ('vimeo', 'hls');
+
+ALTER TABLE video
+    ADD COLUMN video_host     video_host_enum NOT NULL DEFAULT 'vimeo',
+    ADD COLUMN video_hls_path VARCHAR(255);
+
+ALTER TABLE video ALTER COLUMN video_url_vimeo DROP NOT NULL;
+
+ALTER TABLE video
+    ADD CONSTRAINT chk_video_host_source CHECK (
+        (video_host = 'vimeo' AND video_url_vimeo IS NOT NULL)
+        OR (video_host = 'hls' AND video_hls_path IS NOT NULL)
+    );
@@ -0,0 +1,31 @@
+-- =============================================================================
+-- 영상 내 퀴즈 큐 포인트
+-- =============================================================================
+-- video_cue_point: 재생 중 cue_time_ms 에 일시정지하고 기존 study_task 를 출제.
+--   cue_skippable = false 면 플레이어는 정답(또는 제출) 전 재생 재개를 막는다.
+-- study_task_log.video_cue_point_id: 큐 포인트에서 제출된 답안의 영상 컨텍스트
+--   (큐별 분석용, 큐 삭제 시 NULL).
+-- =============================================================================
+
+CREATE TABLE video_cue_point (
+    cue_point_id       BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    video_id           INT NOT NULL REFERENCES video (video_id) ON DELETE CASCADE,
+    study_task_id      INT NOT NULL REFERENCES study_task (study_task_id) ON DELETE CASCADE,
+    cue_time_ms        INT NOT NULL,
+    cue_skippable      BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_by_user_id BIGINT,
+    cue_created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    cue_updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_video_cue_point_time CHECK (cue_time_ms >= 0),
+    CONSTRAINT uq_video_cue_point_time UNIQUE (video_id, cue_time_ms)
+);
+
+CREATE INDEX idx_video_cue_point_task ON video_cue_point (study_task_id);
+
+ALTER TABLE study_task_log
+    ADD COLUMN video_cue_point_id BIGINT REFERENCES video_cue_point (cue_point_id) ON DELETE SET NULL;
+
+CREATE INDEX idx_study_task_log_cue_point
+    ON study_task_log (video_cue_point_id)
+    WHERE video_cue_point_id IS NOT NULL;
@@ -0,0 +1,54 @@
+-- =============================================================================
+-- 레슨 선수 조건 (잠금/해제 규칙) + 사용자별 수동 해제
+-- =============================================================================
+-- lesson_prerequisite: lesson_id 를 시작하려면 만족해야 하는 조건 (AND).
+--   prereq_kind = lesson → required_lesson_id 진도율 ≥ prereq_threshold (%)
+--   prereq_kind = course → required_course_id 진도율 ≥ prereq_threshold (%)
+--   prereq_kind = study  → required_study_id 정답 처리 문제 수 ≥ prereq_threshold (개)
+--   순환(레슨 → 레슨/코스 소속 레슨 경로)은 저장 시 서비스에서 거부.
+-- lesson_unlock_override: 관리자가 특정 사용자에게 조건 무시 해제를 부여.
+-- =============================================================================
+
+CREATE TYPE lesson_prereq_kind_enum AS ENUM ('lesson', 'course', 'study');
+
+CREATE TABLE lesson_prerequisite (
+    prerequisite_id    BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    lesson_id          INT NOT NULL REFERENCES lesson (lesson_id) ON DELETE CASCADE,
+    prereq_kind        lesson_prereq_kind_enum NOT NULL,
+    required_lesson_id INT REFERENCES lesson (lesson_id) ON DELETE CASCADE,
+    required_course_id INT REFERENCES course (course_id) ON DELETE CASCADE,
+    required_study_id  INT REFERENCES study (study_id) ON DELETE CASCADE,
+    prereq_threshold   INT NOT NULL,
+    updated_by_user_id BIGINT,
+    prereq_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_lesson_prereq_target CHECK (
+        (prereq_kind = 'lesson' AND required_lesson_id IS NOT NULL
+            AND required_course_id IS NULL AND required_study_id IS NULL)
+        OR (prereq_kind = 'course' AND required_course_id IS NOT NULL
+            AND required_lesson_id IS NULL AND required_study_id IS NULL)
+        OR (prereq_kind = 'study' AND required_study_id IS NOT NULL
+            AND required_lesson_id IS NULL AND required_course_id IS NULL)
+    ),
+    CONSTRAINT chk_lesson_prereq_threshold CHECK (
+        (prereq_kind IN ('lesson', 'course') AND prereq_threshold BETWEEN 1 AND 100)
+        OR (prereq_kind = 'study' AND prereq_threshold >= 1)
+    )
+);
+
+CREATE INDEX idx_lesson_prerequisite_lesson ON lesson_prerequisite (lesson_id);
+CREATE UNIQUE INDEX uq_lesson_prereq_lesson
+    ON lesson_prerequisite (lesson_id, required_lesson_id) WHERE prereq_kind = 'lesson';
+CREATE UNIQUE INDEX uq_lesson_prereq_course
+    ON lesson_prerequisite (lesson_id, required_course_id) WHERE prereq_kind = 'course';
+CREATE UNIQUE INDEX uq_lesson_prereq_study
+    ON lesson_prerequisite (lesson_id, required_study_id) WHERE prereq_kind = 'study';
+
+CREATE TABLE lesson_unlock_override (
+    lesson_id          INT NOT NULL REFERENCES lesson (lesson_id) ON DELETE CASCADE,
+    user_id            BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    granted_by_user_id BIGINT REFERENCES users (user_id),
+    unlock_reason      TEXT,
+    unlock_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (lesson_id, user_id)
+);
@@ -0,0 +1,25 @@
+-- =============================================================================
+-- 코스 수료증
+-- =============================================================================
+-- users_course 진도율이 100% 에 도달하면 (user_id, course_id) 당 1건 발급.
+--   certificate_code: 공개 검증용 코드 (AMK-XXXX-XXXX-XXXX, 서버 CSPRNG 로 생성)
+--   폐기(revoke)된 수료증은 행을 유지 → 검증 API 가 "revoked" 로 응답,
+--   같은 (user_id, course_id) 로 자동 재발급되지 않음.
+-- 이름은 저장하지 않음 (users.user_name 암호문을 렌더링 시 복호화).
+-- =============================================================================
+
+CREATE TABLE course_certificate (
+    certificate_id                 BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    certificate_code               VARCHAR(32) NOT NULL UNIQUE,
+    user_id                        BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    course_id                      INT NOT NULL REFERENCES course (course_id) ON DELETE CASCADE,
+    user_course_id                 BIGINT REFERENCES users_course (user_course_id) ON DELETE SET NULL,
+    certificate_issued_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    certificate_revoked_at         TIMESTAMPTZ,
+    certificate_revoked_by_user_id BIGINT REFERENCES users (user_id),
+    certificate_revoke_reason      TEXT,
+
+    CONSTRAINT uq_course_certificate_user_course UNIQUE (user_id, course_id)
+);
+
+CREATE INDEX idx_course_certificate_course ON course_certificate (course_id, certificate_issued_at DESC);
@@ -0,0 +1,42 @@
+-- =============================================================================
+-- 콘텐츠 예약 공개/비공개
+-- =============================================================================
+-- 비디오/학습/레슨/가이드/코스의 state 를 지정 시각에 전환 (jobs::publish_scheduler).
+--   publish   → video/study/lesson/guide = open,  course = active
+--   unpublish → video/study/lesson/guide = close, course = inactive
+-- 대상·액션별 pending 은 1건만. 관리자가 state 를 직접 바꾸면 해당 대상의 pending 예약은
+-- cancelled (cancel_reason = 'manual_state_change') 로 명시 취소된다.
+-- 실행 시 감사/변경 로그는 예약을 만든 관리자 명의 + source = scheduler 로 기록.
+-- =============================================================================
+
+CREATE TYPE publish_target_enum AS ENUM ('video', 'study', 'lesson', 'guide', 'course');
+CREATE TYPE publish_schedule_status_enum AS ENUM ('pending', 'done', 'cancelled', 'failed');
+
+CREATE TABLE content_publish_schedule (
+    schedule_id          BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    target_type          publish_target_enum NOT NULL,
+    target_id            BIGINT NOT NULL,
+    schedule_action      admin_action_enum NOT NULL,
+    scheduled_at         TIMESTAMPTZ NOT NULL,
+    schedule_status      publish_schedule_status_enum NOT NULL DEFAULT 'pending',
+    -- 번역 커버리지 정책 우회 (HYMN 만 설정 가능, publish 에만 의미)
+    force_publish        BOOLEAN NOT NULL DEFAULT false,
+    created_by_user_id   BIGINT NOT NULL REFERENCES users (user_id),
+    schedule_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    executed_at          TIMESTAMPTZ,
+    cancelled_at         TIMESTAMPTZ,
+    cancelled_by_user_id BIGINT REFERENCES users (user_id),
+    cancel_reason        TEXT,
+    failure_reason       TEXT,
+
+    CONSTRAINT chk_publish_schedule_action CHECK (schedule_action IN ('publish', 'unpublish'))
+);
+
+CREATE UNIQUE INDEX uq_publish_schedule_pending
+    ON content_publish_schedule (target_type, target_id, schedule_action)
+    WHERE schedule_status = 'pending';
+CREATE INDEX idx_publish_schedule_due
+    ON content_publish_schedule (scheduled_at)
+    WHERE schedule_status = 'pending';
+CREATE INDEX idx_publish_schedule_target
+    ON content_publish_schedule (target_type, target_id);
@@ -0,0 +1,53 @@
+-- =============================================================================
+-- 클래스 (Manager 역할 전용 학습자 그룹)
+-- =============================================================================
+-- manager 가 클래스를 만들고 학습자를 참여 코드 또는 이메일 초대로 받는다.
+--   classroom_join_code: 학습자가 입력하는 참여 코드 (재발급 시 이전 코드 무효)
+--   classroom_invite   : 이메일 초대 — 이메일은 암호문 + blind index 로만 저장,
+--                        토큰은 SHA-256 해시만 저장 (원문은 메일 링크에만 존재)
+-- 학습자 진도 조회는 항상 classroom_member(active) 를 경유 → manager 는 자기 클래스
+-- 밖의 학습자를 볼 수 없다 (HYMN/admin 은 전체 클래스 열람 가능).
+-- 제거(removed)된 멤버는 행을 유지 — 참여 코드로는 재참여 불가, 이메일 초대 수락 시에만 active 복귀.
+-- =============================================================================
+
+CREATE TYPE classroom_member_state_enum AS ENUM ('active', 'removed');
+
+CREATE TABLE classroom (
+    classroom_id          BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    owner_user_id         BIGINT NOT NULL REFERENCES users (user_id),
+    classroom_name        VARCHAR(120) NOT NULL,
+    classroom_description TEXT,
+    classroom_join_code   VARCHAR(16) NOT NULL UNIQUE,
+    classroom_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    classroom_updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_classroom_owner ON classroom (owner_user_id);
+
+CREATE TABLE classroom_member (
+    classroom_id             BIGINT NOT NULL REFERENCES classroom (classroom_id) ON DELETE CASCADE,
+    user_id                  BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    member_state             classroom_member_state_enum NOT NULL DEFAULT 'active',
+    member_joined_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    member_removed_at        TIMESTAMPTZ,
+    member_removed_by_user_id BIGINT REFERENCES users (user_id),
+
+    PRIMARY KEY (classroom_id, user_id)
+);
+
+CREATE INDEX idx_classroom_member_user ON classroom_member (user_id);
+
+CREATE TABLE classroom_invite (
+    invite_id              BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    classroom_id           BIGINT NOT NULL REFERENCES classroom (classroom_id) ON DELETE CASCADE,
+    invite_email           TEXT NOT NULL,          -- 암호문 (AAD = classroom_invite.invite_email)
+    invite_email_idx       TEXT NOT NULL,          -- blind index (수락 시 본인 이메일과 대조)
+    invite_token_hash      TEXT NOT NULL UNIQUE,
+    invited_by_user_id     BIGINT NOT NULL REFERENCES users (user_id),
+    invite_created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    invite_expires_at      TIMESTAMPTZ NOT NULL,
+    invite_accepted_at     TIMESTAMPTZ,
+    invite_accepted_user_id BIGINT REFERENCES users (user_id)
+);
+
+CREATE INDEX idx_classroom_invite_classroom ON classroom_invite (classroom_id, invite_created_at DESC);
@@ -0,0 +1,83 @@
+-- =============================================================================
+-- 과제 (마감일 + 제출 추적)
+-- =============================================================================
+-- manager(또는 HYMN/admin)가 클래스 전체 또는 지정 학습자에게 콘텐츠 묶음을 과제로 낸다.
+--   대상 = classroom_member(active) ∪ assignment_assignee
+--   항목 = study / lesson / video / guide (guide 는 sentence_no 범위 지정 가능)
+-- 완료 여부는 별도 제출 없이 기존 상태/로그 테이블에서 파생:
+--   video  = video_log.video_completed_log
+--   lesson = lesson_progress.lesson_progress_percent >= 100
+--   study  = 해당 study 의 모든 task 가 study_task_status 에서 solved
+--   guide  = 범위 내 모든 문장이 guide_sentence_status 에서 solved
+-- 처음 완료가 감지된 시점을 assignment_submission 에 고정 기록 (이후 재학습으로 바뀌지 않음).
+-- 지각 정책: allow = 마감 후 완료도 인정(late 표시, late_until 까지), deny = 마감 후 완료 불인정.
+-- 마감 전 리마인더 메일은 jobs::assignment_reminder 가 (과제, 학습자) 당 1회 발송.
+-- =============================================================================
+
+CREATE TYPE assignment_item_type_enum AS ENUM ('study', 'lesson', 'video', 'guide');
+CREATE TYPE assignment_late_policy_enum AS ENUM ('allow', 'deny');
+
+CREATE TABLE assignment (
+    assignment_id          BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    classroom_id           BIGINT REFERENCES classroom (classroom_id) ON DELETE CASCADE,
+    created_by_user_id     BIGINT NOT NULL REFERENCES users (user_id),
+    assignment_title       VARCHAR(200) NOT NULL,
+    assignment_description TEXT,
+    assignment_open_at     TIMESTAMPTZ NOT NULL,
+    assignment_due_at      TIMESTAMPTZ NOT NULL,
+    assignment_late_policy assignment_late_policy_enum NOT NULL DEFAULT 'allow',
+    assignment_late_until  TIMESTAMPTZ,            -- allow 일 때 지각 인정 마감 (NULL = 무기한)
+    assignment_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    assignment_updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_assignment_window CHECK (assignment_due_at > assignment_open_at),
+    CONSTRAINT chk_assignment_late_until CHECK (
+        assignment_late_until IS NULL
+        OR (assignment_late_policy = 'allow' AND assignment_late_until > assignment_due_at)
+    )
+);
+
+CREATE INDEX idx_assignment_classroom ON assignment (classroom_id);
+CREATE INDEX idx_assignment_creator ON assignment (created_by_user_id, assignment_due_at DESC);
+CREATE INDEX idx_assignment_due ON assignment (assignment_due_at);
+
+CREATE TABLE assignment_item (
+    assignment_item_id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    assignment_id      BIGINT NOT NULL REFERENCES assignment (assignment_id) ON DELETE CASCADE,
+    item_seq           INT NOT NULL,
+    item_type          assignment_item_type_enum NOT NULL,
+    target_id          BIGINT NOT NULL,         -- study_id / lesson_id / video_id / guide_id
+    sentence_start     INT,                     -- guide 전용 sentence_no 범위 (NULL = 단원 전체)
+    sentence_end       INT,
+
+    CONSTRAINT uq_assignment_item_seq UNIQUE (assignment_id, item_seq),
+    CONSTRAINT chk_assignment_item_range CHECK (
+        (sentence_start IS NULL AND sentence_end IS NULL)
+        OR (item_type = 'guide' AND sentence_start IS NOT NULL AND sentence_end IS NOT NULL
+            AND sentence_start <= sentence_end)
+    )
+);
+
+CREATE TABLE assignment_assignee (
+    assignment_id BIGINT NOT NULL REFERENCES assignment (assignment_id) ON DELETE CASCADE,
+    user_id       BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    PRIMARY KEY (assignment_id, user_id)
+);
+
+CREATE INDEX idx_assignment_assignee_user ON assignment_assignee (user_id);
+
+CREATE TABLE assignment_submission (
+    assignment_id           BIGINT NOT NULL REFERENCES assignment (assignment_id) ON DELETE CASCADE,
+    user_id                 BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    submission_completed_at TIMESTAMPTZ NOT NULL,
+    submission_is_late      BOOLEAN NOT NULL DEFAULT false,
+    submission_recorded_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (assignment_id, user_id)
+);
+
+CREATE TABLE assignment_reminder (
+    assignment_id    BIGINT NOT NULL REFERENCES assignment (assignment_id) ON DELETE CASCADE,
+    user_id          BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    reminder_sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (assignment_id, user_id)
+);
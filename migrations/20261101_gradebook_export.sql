@@ -0,0 +1,38 @@
+-- =============================================================================
+-- 성적부 내보내기 (CSV / XLSX)
+-- =============================================================================
+-- 범위: 클래스(active 멤버) 또는 코스 수강생(users_course active) 중 하나.
+-- 점수는 기존 진행 테이블에서 계산 (study = 해결 task 비율, lesson = 진도율,
+-- assignment = 완료 100 / 지각 late_credit / 그 외 0) 후 카테고리 가중 평균.
+-- 대상 인원이 많으면 요청 시 pending 으로만 기록하고 jobs::gradebook_export 가 생성한다.
+-- 생성 파일은 export_file 에 보관하고 export_expires_at 이 지나면 파일만 비운다 (행은 감사용 유지).
+-- 실명 포함(include_names) 내보내기는 복호화 시 감사 로그(DECRYPT_GRADEBOOK_NAMES)를 남긴다.
+-- =============================================================================
+
+CREATE TYPE gradebook_export_status_enum AS ENUM ('pending', 'running', 'done', 'failed');
+CREATE TYPE gradebook_export_format_enum AS ENUM ('csv', 'xlsx');
+
+CREATE TABLE gradebook_export (
+    export_id             BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    requested_by_user_id  BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    classroom_id          BIGINT REFERENCES classroom (classroom_id) ON DELETE CASCADE,
+    course_id             INT REFERENCES course (course_id) ON DELETE CASCADE,
+    export_format         gradebook_export_format_enum NOT NULL,
+    export_weights        JSONB NOT NULL,
+    export_include_names  BOOLEAN NOT NULL DEFAULT false,
+    export_status         gradebook_export_status_enum NOT NULL DEFAULT 'pending',
+    export_error          TEXT,
+    export_row_count      INT,
+    export_file_name      VARCHAR(200),
+    export_file           BYTEA,
+    export_created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    export_started_at     TIMESTAMPTZ,
+    export_finished_at    TIMESTAMPTZ,
+    export_expires_at     TIMESTAMPTZ,
+
+    CONSTRAINT chk_gradebook_export_scope CHECK ((classroom_id IS NULL) <> (course_id IS NULL))
+);
+
+CREATE INDEX idx_gradebook_export_requester ON gradebook_export (requested_by_user_id, export_created_at DESC);
+CREATE INDEX idx_gradebook_export_pending ON gradebook_export (export_id) WHERE export_status IN ('pending', 'running');
+CREATE INDEX idx_gradebook_export_expires ON gradebook_export (export_expires_at) WHERE export_file IS NOT NULL;
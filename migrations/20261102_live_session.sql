@@ -0,0 +1,80 @@
+-- =============================================================================
+-- 라이브 수업 (course_type = 'live')
+-- =============================================================================
+-- 코스에 회차(live_session)를 편성한다. 회차마다 강사, 정원, 시작/종료 시각(TIMESTAMPTZ)과
+-- 표시용 IANA 시간대, 회의실 프로바이더가 발급한 참가 URL 을 가진다.
+-- 수강 신청(live_enrollment):
+--   정원 미만이면 enrolled, 가득 차면 waitlisted (신청 순 대기).
+--   enrolled 취소 또는 정원 증가 시 대기 1순위부터 자동 승격.
+-- 출석(live_attendance)은 강사/관리자가 회차 시작 후 기록하며 코스 진도율에 반영된다:
+--   진도율 = AVG(공개 레슨 진도 ∪ 취소되지 않은 회차 출석 100/0)
+-- 시작 전 리마인더 메일은 jobs::live_reminder 가 (회차, 학습자) 당 1회 발송.
+-- 개인 캘린더 구독(ICS)은 live_calendar_feed 의 토큰(해시만 저장)으로 인증.
+-- =============================================================================
+
+CREATE TYPE live_session_state_enum AS ENUM ('scheduled', 'cancelled');
+CREATE TYPE live_enrollment_state_enum AS ENUM ('enrolled', 'waitlisted', 'cancelled');
+
+CREATE TABLE live_session (
+    live_session_id     BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    course_id           INT NOT NULL REFERENCES course (course_id) ON DELETE CASCADE,
+    teacher_user_id     BIGINT NOT NULL REFERENCES users (user_id),
+    created_by_user_id  BIGINT NOT NULL REFERENCES users (user_id),
+    session_title       VARCHAR(200) NOT NULL,
+    session_description TEXT,
+    session_start_at    TIMESTAMPTZ NOT NULL,
+    session_end_at      TIMESTAMPTZ NOT NULL,
+    session_timezone    VARCHAR(64) NOT NULL DEFAULT 'Asia/Seoul',
+    session_capacity    INT NOT NULL,
+    session_state       live_session_state_enum NOT NULL DEFAULT 'scheduled',
+    meeting_provider    VARCHAR(30) NOT NULL,
+    meeting_external_id VARCHAR(200) NOT NULL,
+    meeting_url         TEXT NOT NULL,
+    session_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    session_updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_live_session_window CHECK (session_end_at > session_start_at),
+    CONSTRAINT chk_live_session_capacity CHECK (session_capacity > 0)
+);
+
+CREATE INDEX idx_live_session_course ON live_session (course_id, session_start_at);
+CREATE INDEX idx_live_session_teacher ON live_session (teacher_user_id, session_start_at);
+CREATE INDEX idx_live_session_start ON live_session (session_start_at)
+    WHERE session_state = 'scheduled';
+
+CREATE TABLE live_enrollment (
+    live_session_id        BIGINT NOT NULL REFERENCES live_session (live_session_id) ON DELETE CASCADE,
+    user_id                BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    enrollment_state       live_enrollment_state_enum NOT NULL,
+    enrollment_created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),   -- 대기 순번 기준 (재신청 시 갱신)
+    enrollment_updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (live_session_id, user_id)
+);
+
+CREATE INDEX idx_live_enrollment_user ON live_enrollment (user_id, enrollment_state);
+CREATE INDEX idx_live_enrollment_waitlist ON live_enrollment (live_session_id, enrollment_created_at)
+    WHERE enrollment_state = 'waitlisted';
+
+CREATE TABLE live_attendance (
+    live_session_id     BIGINT NOT NULL REFERENCES live_session (live_session_id) ON DELETE CASCADE,
+    user_id             BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    attended            BOOLEAN NOT NULL,
+    recorded_by_user_id BIGINT NOT NULL REFERENCES users (user_id),
+    recorded_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (live_session_id, user_id)
+);
+
+CREATE INDEX idx_live_attendance_user ON live_attendance (user_id);
+
+CREATE TABLE live_reminder (
+    live_session_id  BIGINT NOT NULL REFERENCES live_session (live_session_id) ON DELETE CASCADE,
+    user_id          BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    reminder_sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (live_session_id, user_id)
+);
+
+CREATE TABLE live_calendar_feed (
+    user_id         BIGINT PRIMARY KEY REFERENCES users (user_id) ON DELETE CASCADE,
+    feed_token_hash VARCHAR(64) NOT NULL UNIQUE,
+    feed_created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
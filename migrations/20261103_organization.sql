@@ -0,0 +1,88 @@
+-- =============================================================================
+-- 기관 계정 + 좌석 라이선스 (B2B)
+-- =============================================================================
+-- 학교/어학원 등 기관(organization)이 코스 또는 구독 등급 좌석 N개를 유효기간과 함께 구매.
+--   org_admin   : 기관 관리자 — 좌석 초대/회수, 대시보드 열람 (기관/라이선스 생성은 HYMN/admin)
+--   org_license : 좌석 묶음 — scope=course 면 해당 코스 1개, scope=subscription 이면 전체 active 코스
+--                 textbook_order_id 로 교재 일괄 주문과 연결 가능 (선택)
+--   org_seat    : 좌석 1개 = 이메일 초대 1건. 이메일은 암호문 + blind index, 토큰은 SHA-256 해시만 저장
+--                 invited → (수락) active → (회수) revoked. 만료된 초대는 좌석을 점유하지 않음
+-- 수락 시 users_course 행을 만들어 기존 수강권 검사(레슨/라이브/성적부/수료증)가 그대로 동작하고,
+-- 좌석이 만든 행은 user_course_org_seat_id 로 표시 → 회수 시 그 행만 비활성화한다.
+-- 이미 더 긴 개인 수강권(구독/수동 부여)이 있으면 건드리지 않는다.
+-- =============================================================================
+
+CREATE TYPE org_license_scope_enum AS ENUM ('course', 'subscription');
+CREATE TYPE org_seat_state_enum AS ENUM ('invited', 'active', 'revoked');
+
+CREATE TABLE organization (
+    org_id                 BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    org_name               VARCHAR(200) NOT NULL,
+    org_created_by_user_id BIGINT NOT NULL REFERENCES users (user_id),
+    org_created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    org_updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE org_admin (
+    org_id              BIGINT NOT NULL REFERENCES organization (org_id) ON DELETE CASCADE,
+    user_id             BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    org_admin_added_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    org_admin_added_by_user_id BIGINT REFERENCES users (user_id),
+
+    PRIMARY KEY (org_id, user_id)
+);
+
+CREATE INDEX idx_org_admin_user ON org_admin (user_id);
+
+CREATE TABLE org_license (
+    license_id              BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    org_id                  BIGINT NOT NULL REFERENCES organization (org_id) ON DELETE CASCADE,
+    license_scope           org_license_scope_enum NOT NULL,
+    course_id               INT REFERENCES course (course_id),
+    license_seat_count      INT NOT NULL,
+    license_valid_from      TIMESTAMPTZ NOT NULL,
+    license_valid_until     TIMESTAMPTZ NOT NULL,
+    textbook_order_id       BIGINT REFERENCES textbook (order_id) ON DELETE SET NULL,
+    license_note            TEXT,
+    license_created_by_user_id BIGINT NOT NULL REFERENCES users (user_id),
+    license_created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    license_updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_org_license_scope_course CHECK (
+        (license_scope = 'course' AND course_id IS NOT NULL)
+        OR (license_scope = 'subscription' AND course_id IS NULL)
+    ),
+    CONSTRAINT chk_org_license_seat_count CHECK (license_seat_count > 0),
+    CONSTRAINT chk_org_license_window CHECK (license_valid_until > license_valid_from)
+);
+
+CREATE INDEX idx_org_license_org ON org_license (org_id, license_created_at DESC);
+
+CREATE TABLE org_seat (
+    seat_id                 BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    license_id              BIGINT NOT NULL REFERENCES org_license (license_id) ON DELETE CASCADE,
+    seat_state              org_seat_state_enum NOT NULL DEFAULT 'invited',
+    seat_email              TEXT NOT NULL,          -- 암호문 (AAD = org_seat.seat_email)
+    seat_email_idx          TEXT NOT NULL,          -- blind index (수락 시 본인 이메일과 대조)
+    seat_token_hash         TEXT NOT NULL UNIQUE,
+    seat_invited_by_user_id BIGINT NOT NULL REFERENCES users (user_id),
+    seat_invited_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    seat_invite_expires_at  TIMESTAMPTZ NOT NULL,
+    user_id                 BIGINT REFERENCES users (user_id) ON DELETE SET NULL,
+    seat_accepted_at        TIMESTAMPTZ,
+    seat_revoked_at         TIMESTAMPTZ,
+    seat_revoked_by_user_id BIGINT REFERENCES users (user_id)
+);
+
+-- 같은 라이선스에 같은 이메일의 살아있는 좌석은 1개만 (회수 후 재초대는 허용)
+CREATE UNIQUE INDEX uq_org_seat_license_email
+    ON org_seat (license_id, seat_email_idx) WHERE seat_state <> 'revoked';
+CREATE INDEX idx_org_seat_license ON org_seat (license_id, seat_invited_at DESC);
+CREATE INDEX idx_org_seat_user ON org_seat (user_id) WHERE seat_state = 'active';
+
+-- 좌석이 만든 수강권 표시 (좌석 회수 시 이 행만 비활성화)
+ALTER TABLE users_course
+    ADD COLUMN user_course_org_seat_id BIGINT REFERENCES org_seat (seat_id) ON DELETE SET NULL;
+
+CREATE INDEX idx_users_course_org_seat ON users_course (user_course_org_seat_id)
+    WHERE user_course_org_seat_id IS NOT NULL;
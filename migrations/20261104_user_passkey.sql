@@ -0,0 +1,30 @@
+-- =============================================================================
+-- 패스키 (WebAuthn)
+-- =============================================================================
+-- 비밀번호 없는 1차 로그인 (discoverable credential + user verification) 과
+-- mfa_login 의 TOTP 대체 2차 인증에 사용한다.
+--   passkey_credential_id: 인증기가 발급한 credential ID (base64url)
+--   passkey_public_key   : COSE_Key 원본 바이트 (ES256 / RS256)
+--   passkey_sign_count   : 서명 카운터 — 0 이 아닌 인증기는 매 인증마다 증가해야 함 (복제 탐지)
+-- 챌린지 상태는 Redis (ak:webauthn:*) 에만 저장하고 DB 에는 남기지 않는다.
+-- =============================================================================
+
+ALTER TYPE login_method_enum ADD VALUE IF NOT EXISTS 'passkey';
+
+CREATE TABLE user_passkey (
+    passkey_id              BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    user_id                 BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    passkey_credential_id   TEXT NOT NULL UNIQUE,
+    passkey_public_key      BYTEA NOT NULL,
+    passkey_alg             INT NOT NULL,
+    passkey_sign_count      BIGINT NOT NULL DEFAULT 0,
+    passkey_name            VARCHAR(100) NOT NULL,
+    passkey_transports      TEXT[] NOT NULL DEFAULT '{}',
+    passkey_aaguid          UUID,
+    passkey_backup_eligible BOOLEAN NOT NULL DEFAULT false,
+    passkey_backed_up       BOOLEAN NOT NULL DEFAULT false,
+    passkey_created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    passkey_last_used_at    TIMESTAMPTZ
+);
+
+CREATE INDEX idx_user_passkey_user ON user_passkey (user_id);
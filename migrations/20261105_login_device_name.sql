@@ -0,0 +1,9 @@
+-- =============================================================================
+-- 로그인 세션 기기 이름
+-- =============================================================================
+-- /users/me/sessions 에서 사용자가 자기 세션(기기)에 붙이는 표시 이름.
+-- NULL 이면 클라이언트가 브라우저/OS 로 기본 이름을 만든다.
+-- 세션 ID 는 refresh 회전 시에도 유지되므로 이름은 세션 수명 동안 보존된다.
+-- =============================================================================
+
+ALTER TABLE login ADD COLUMN IF NOT EXISTS login_device_name VARCHAR(100);
@@ -0,0 +1,43 @@
+-- =============================================================================
+-- 로그인 위험 평가 (새 국가 / 새 기기 / 불가능한 이동 속도)
+-- =============================================================================
+-- 로그인 성공 시 사용자의 이전 로그인 이력(login)과 비교해 위험 신호를 판정한다.
+--   new_country      : 최근 이력에 없는 국가
+--   new_device       : 최근 이력에 없는 브라우저/OS 조합 (UA family)
+--   impossible_travel: 직전 로그인 위치에서 LOGIN_RISK_MAX_TRAVEL_KMH 초과 속도로 이동
+-- 조치(LOGIN_RISK_ACTION):
+--   log     : 이벤트만 기록
+--   alert   : 기록 + 알림 메일 ("본인이 아닙니다" → 전체 세션 폐기 링크)
+--   step_up : 2차 인증을 거치지 않은 로그인은 메일 인증 코드 확인 후에만 세션 발급
+-- 위경도는 GeoIP City DB 의 대략적 위치 (오프라인 mmdb 미설정 시 NULL).
+-- =============================================================================
+
+ALTER TABLE login
+    ADD COLUMN IF NOT EXISTS login_latitude  DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS login_longitude DOUBLE PRECISION;
+
+CREATE TYPE login_risk_action_enum AS ENUM ('log', 'alert', 'step_up');
+
+CREATE TABLE login_risk_event (
+    login_risk_event_id     BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    user_id                 BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    -- step_up 챌린지 단계에서는 세션이 아직 없음 (인증 완료 시 채움)
+    login_session_id        UUID,
+    risk_login_method       VARCHAR(20) NOT NULL,
+    risk_reasons            TEXT[] NOT NULL,
+    risk_action             login_risk_action_enum NOT NULL,
+    risk_country            CHAR(2),
+    risk_prev_country       CHAR(2),
+    risk_city               VARCHAR(100),
+    risk_device             VARCHAR(20),
+    risk_browser            VARCHAR(100),
+    risk_os                 VARCHAR(100),
+    risk_distance_km        DOUBLE PRECISION,
+    risk_speed_kmh          DOUBLE PRECISION,
+    risk_step_up_passed_at  TIMESTAMPTZ,
+    risk_user_revoked_at    TIMESTAMPTZ,
+    risk_created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_risk_event_created ON login_risk_event (risk_created_at DESC);
+CREATE INDEX idx_login_risk_event_user ON login_risk_event (user_id, risk_created_at DESC);
@@ -0,0 +1,12 @@
+-- =============================================================================
+-- 비밀번호 없는 로그인 (이메일 매직 링크 / 6자리 일회용 코드)
+-- =============================================================================
+-- 이메일 소유 확인만으로 1차 로그인한다. 토큰/코드는 Redis (ak:pwless_*) 에만
+-- TTL(PASSWORDLESS_LOGIN_TTL_SEC) 과 함께 저장하고 확인 즉시 삭제한다 (일회용).
+--   email_link: 메일의 로그인 링크 (토큰은 SHA-256 해시로만 보관)
+--   email_code: 메일의 6자리 코드 (HMAC 해시, 이메일 blind index 키)
+-- MFA 사용 계정은 기존과 같이 mfa_login 으로 2차 인증을 마쳐야 세션이 발급된다.
+-- =============================================================================
+
+ALTER TYPE login_method_enum ADD VALUE IF NOT EXISTS 'email_link';
+ALTER TYPE login_method_enum ADD VALUE IF NOT EXISTS 'email_code';
@@ -0,0 +1,11 @@
+-- =============================================================================
+-- 카카오 / 네이버 소셜 로그인
+-- =============================================================================
+-- 범용 OIDC/OAuth2 클라이언트 위의 공급자. users_oauth.oauth_provider 와
+-- login.login_method 가 같은 login_method_enum 을 쓰므로 값만 추가한다.
+--   kakao: 카카오 OpenID Connect (웹 Authorization Code + PKCE / 모바일 SDK ID token)
+--   naver: 네이버 OAuth2 (웹 Authorization Code / 모바일 SDK access token → 프로필 API)
+-- =============================================================================
+
+ALTER TYPE login_method_enum ADD VALUE IF NOT EXISTS 'kakao';
+ALTER TYPE login_method_enum ADD VALUE IF NOT EXISTS 'naver';
@@ -0,0 +1,47 @@
+-- =============================================================================
+-- 기관 SSO (OIDC / SAML) — 이메일 도메인 기반 로그인 + JIT 가입
+-- =============================================================================
+-- 제휴 대학 등 기관 IdP 로 로그인. 기관당 설정 1개, 도메인은 여러 개.
+--   org_sso        : 프로토콜별 IdP 설정 (HYMN/admin 이 등록 — 도메인 소유 확인은 계약 시 수행)
+--                    oidc = issuer discovery, client secret 은 암호문 (AAD = org_sso.oidc_client_secret)
+--                    saml = IdP 메타데이터 XML 원문 (entityID 는 검증/조회용으로 추출해 둠)
+--                    sso_attribute_map = users 필드 → IdP 속성/클레임 이름 (미지정 필드는 표준 이름)
+--   org_sso_domain : 로그인 discovery 키. 도메인은 전역 유일 (한 도메인 = 한 기관)
+-- sso_enforced = 기관 관리자가 켜는 "SSO 전용" — 해당 도메인 계정의 비밀번호/소셜/패스키/이메일 로그인과
+--                일반 가입을 막는다 (HYMN/admin 플랫폼 운영 계정은 잠김 방지를 위해 제외)
+-- SSO 계정 연결은 users_oauth (oauth_provider = 'sso', subject blind index = "{org_id}:{subject}")
+-- =============================================================================
+
+ALTER TYPE login_method_enum ADD VALUE IF NOT EXISTS 'sso';
+
+CREATE TYPE org_sso_protocol_enum AS ENUM ('oidc', 'saml');
+
+CREATE TABLE org_sso (
+    org_id                  BIGINT PRIMARY KEY REFERENCES organization (org_id) ON DELETE CASCADE,
+    sso_protocol            org_sso_protocol_enum NOT NULL,
+    sso_enabled             BOOLEAN NOT NULL DEFAULT TRUE,
+    sso_enforced            BOOLEAN NOT NULL DEFAULT FALSE,
+    sso_jit_enabled         BOOLEAN NOT NULL DEFAULT TRUE,
+    oidc_issuer             TEXT,
+    oidc_client_id          TEXT,
+    oidc_client_secret      TEXT,                   -- 암호문 (AAD = org_sso.oidc_client_secret)
+    saml_metadata_xml       TEXT,
+    saml_idp_entity_id      TEXT,
+    sso_attribute_map       JSONB NOT NULL DEFAULT '{}'::jsonb,
+    sso_updated_by_user_id  BIGINT REFERENCES users (user_id) ON DELETE SET NULL,
+    sso_created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sso_updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_org_sso_protocol CHECK (
+        (sso_protocol = 'oidc' AND oidc_issuer IS NOT NULL AND oidc_client_id IS NOT NULL)
+        OR (sso_protocol = 'saml' AND saml_metadata_xml IS NOT NULL AND saml_idp_entity_id IS NOT NULL)
+    )
+);
+
+CREATE TABLE org_sso_domain (
+    sso_domain          VARCHAR(253) PRIMARY KEY,   -- 소문자
+    org_id              BIGINT NOT NULL REFERENCES org_sso (org_id) ON DELETE CASCADE,
+    sso_domain_added_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_org_sso_domain_org ON org_sso_domain (org_id);
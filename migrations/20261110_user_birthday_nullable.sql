@@ -0,0 +1,9 @@
+-- =============================================================================
+-- users.user_birthday NULL 허용
+-- =============================================================================
+-- 기관 SSO JIT 가입은 IdP 가 생년월일을 주지 않으면 알 수 없다 → 가입일 등 임의 값 대신 NULL 로 둔다.
+-- 일반 가입 / 관리자 생성은 여전히 서비스 레이어에서 필수 입력.
+-- users_log.user_birthday_log 는 원래 NULL 허용.
+-- =============================================================================
+
+ALTER TABLE users ALTER COLUMN user_birthday DROP NOT NULL;
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::types::{LessonAccess, LessonState};
+
+// ==========================================
+// 요청
+// ==========================================
+
+/// 코스에 레슨 연결 요청
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CourseLessonAttachReq {
+    pub lesson_id: i32,
+    /// 삽입 위치 (1부터). 없으면 맨 뒤, 기존 레슨은 한 칸씩 뒤로 밀림
+    #[validate(range(min = 1))]
+    pub seq: Option<i32>,
+}
+
+/// 코스 레슨 순서 변경 요청 — 현재 연결된 레슨 전체를 원하는 순서로
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CourseLessonReorderReq {
+    #[validate(length(min = 1))]
+    pub lesson_ids: Vec<i32>,
+}
+
+/// 수료증 폐기 요청
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CertificateRevokeReq {
+    #[validate(length(min = 1, max = 500))]
+    pub reason: String,
+}
+
+// ==========================================
+// 응답
+// ==========================================
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminCourseLessonRes {
+    pub lesson_id: i32,
+    pub course_lesson_seq: i32,
+    pub lesson_idx: String,
+    pub lesson_title: String,
+    pub lesson_state: LessonState,
+    pub lesson_access: LessonAccess,
+    pub course_lesson_created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminCourseLessonListRes {
+    pub course_id: i32,
+    pub lessons: Vec<AdminCourseLessonRes>,
+}
+
+/// 코스 수료증 (관리자 조회)
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminCertificateRes {
+    pub certificate_id: i64,
+    pub certificate_code: String,
+    pub user_id: i64,
+    pub user_nickname: String,
+    pub course_id: i32,
+    pub certificate_issued_at: DateTime<Utc>,
+    pub certificate_revoked_at: Option<DateTime<Utc>>,
+    pub certificate_revoked_by_user_id: Option<i64>,
+    pub certificate_revoke_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminCertificateListRes {
+    pub course_id: i32,
+    pub items: Vec<AdminCertificateRes>,
+}
@@ -0,0 +1,214 @@
+use super::dto::{
+    AdminCertificateListRes, AdminCertificateRes, AdminCourseLessonListRes, CertificateRevokeReq,
+    CourseLessonAttachReq, CourseLessonReorderReq,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::api::auth::extractor::AuthUser;
+use crate::error::AppResult;
+use crate::extract::AppJson;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+
+/// 코스 커리큘럼 (연결된 레슨 목록)
+#[utoipa::path(
+    get,
+    path = "/admin/courses/{course_id}/lessons",
+    tag = "admin_course",
+    params(
+        ("course_id" = i32, Path, description = "Course ID")
+    ),
+    responses(
+        (status = 200, description = "Course lessons in order", body = AdminCourseLessonListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Course not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_course_lessons(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(course_id): Path<i32>,
+) -> AppResult<Json<AdminCourseLessonListRes>> {
+    let res = super::service::list_course_lessons(&st, auth_user.sub, course_id).await?;
+    Ok(Json(res))
+}
+
+/// 코스에 레슨 연결
+#[utoipa::path(
+    post,
+    path = "/admin/courses/{course_id}/lessons",
+    tag = "admin_course",
+    params(
+        ("course_id" = i32, Path, description = "Course ID")
+    ),
+    request_body = CourseLessonAttachReq,
+    responses(
+        (status = 201, description = "Lesson attached", body = AdminCourseLessonListRes),
+        (status = 400, description = "Invalid seq or lesson, or prerequisite cycle", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Course not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Lesson already attached", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_attach_course_lesson(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(course_id): Path<i32>,
+    AppJson(req): AppJson<CourseLessonAttachReq>,
+) -> AppResult<(StatusCode, Json<AdminCourseLessonListRes>)> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res =
+        super::service::attach_lesson(&st, auth_user.sub, course_id, req, ip_address, user_agent)
+            .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+/// 코스 레슨 순서 변경
+#[utoipa::path(
+    put,
+    path = "/admin/courses/{course_id}/lessons/order",
+    tag = "admin_course",
+    params(
+        ("course_id" = i32, Path, description = "Course ID")
+    ),
+    request_body = CourseLessonReorderReq,
+    responses(
+        (status = 200, description = "Lessons reordered", body = AdminCourseLessonListRes),
+        (status = 400, description = "lesson_ids does not match attached lessons", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Course not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_reorder_course_lessons(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(course_id): Path<i32>,
+    AppJson(req): AppJson<CourseLessonReorderReq>,
+) -> AppResult<Json<AdminCourseLessonListRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res =
+        super::service::reorder_lessons(&st, auth_user.sub, course_id, req, ip_address, user_agent)
+            .await?;
+    Ok(Json(res))
+}
+
+/// 코스에서 레슨 연결 해제
+#[utoipa::path(
+    delete,
+    path = "/admin/courses/{course_id}/lessons/{lesson_id}",
+    tag = "admin_course",
+    params(
+        ("course_id" = i32, Path, description = "Course ID"),
+        ("lesson_id" = i32, Path, description = "Lesson ID")
+    ),
+    responses(
+        (status = 204, description = "Lesson detached"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Lesson not attached to course", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_detach_course_lesson(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((course_id, lesson_id)): Path<(i32, i32)>,
+) -> AppResult<StatusCode> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    super::service::detach_lesson(
+        &st,
+        auth_user.sub,
+        course_id,
+        lesson_id,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 코스 수료증 목록
+#[utoipa::path(
+    get,
+    path = "/admin/courses/{course_id}/certificates",
+    tag = "admin_course",
+    params(
+        ("course_id" = i32, Path, description = "Course ID")
+    ),
+    responses(
+        (status = 200, description = "Issued certificates", body = AdminCertificateListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Course not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_course_certificates(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(course_id): Path<i32>,
+) -> AppResult<Json<AdminCertificateListRes>> {
+    let res = super::service::list_certificates(&st, auth_user.sub, course_id).await?;
+    Ok(Json(res))
+}
+
+/// 수료증 폐기
+#[utoipa::path(
+    post,
+    path = "/admin/courses/{course_id}/certificates/{certificate_id}/revoke",
+    tag = "admin_course",
+    params(
+        ("course_id" = i32, Path, description = "Course ID"),
+        ("certificate_id" = i64, Path, description = "Certificate ID")
+    ),
+    request_body = CertificateRevokeReq,
+    responses(
+        (status = 200, description = "Certificate revoked", body = AdminCertificateRes),
+        (status = 400, description = "Invalid reason", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Certificate not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Already revoked", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_revoke_course_certificate(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((course_id, certificate_id)): Path<(i32, i64)>,
+    AppJson(req): AppJson<CertificateRevokeReq>,
+) -> AppResult<Json<AdminCertificateRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res = super::service::revoke_certificate(
+        &st,
+        auth_user.sub,
+        course_id,
+        certificate_id,
+        req,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,276 @@
+use serde_json::Value;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use super::dto::{AdminCertificateRes, AdminCourseLessonRes};
+use crate::error::AppResult;
+
+pub async fn exists_course(db: &PgPool, course_id: i32) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"SELECT EXISTS(SELECT 1 FROM course WHERE course_id = $1 AND course_state <> 'deleted')"#,
+    )
+    .bind(course_id)
+    .fetch_one(db)
+    .await?;
+    Ok(exists)
+}
+
+pub async fn exists_lesson(db: &PgPool, lesson_id: i32) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"SELECT EXISTS(SELECT 1 FROM lesson WHERE lesson_id = $1)"#,
+    )
+    .bind(lesson_id)
+    .fetch_one(db)
+    .await?;
+    Ok(exists)
+}
+
+/// 코스 커리큘럼 (순서대로)
+pub async fn find_course_lessons(
+    executor: impl sqlx::PgExecutor<'_>,
+    course_id: i32,
+) -> AppResult<Vec<AdminCourseLessonRes>> {
+    let rows = sqlx::query_as::<_, AdminCourseLessonRes>(
+        r#"
+        SELECT
+            cl.lesson_id,
+            cl.course_lesson_seq,
+            l.lesson_idx,
+            l.lesson_title,
+            l.lesson_state,
+            l.lesson_access,
+            cl.course_lesson_created_at
+        FROM course_lesson cl
+        JOIN lesson l ON l.lesson_id = cl.lesson_id
+        WHERE cl.course_id = $1
+        ORDER BY cl.course_lesson_seq ASC
+        "#,
+    )
+    .bind(course_id)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
+
+/// 순서 충돌 없이 seq 를 옮기기 위해 대상 행을 음수로 임시 이동
+/// (unique_course_lesson_seq 가 즉시 검사 인덱스라 한 번에 +1/-1 하면 중간 충돌)
+async fn shift_seqs_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    course_id: i32,
+    from_seq: i32,
+    delta: i32,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE course_lesson
+        SET course_lesson_seq = -(course_lesson_seq + $3)
+        WHERE course_id = $1 AND course_lesson_seq >= $2
+        "#,
+    )
+    .bind(course_id)
+    .bind(from_seq)
+    .bind(delta)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE course_lesson
+        SET course_lesson_seq = -course_lesson_seq
+        WHERE course_id = $1 AND course_lesson_seq < 0
+        "#,
+    )
+    .bind(course_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// 레슨 연결 — seq 없으면 맨 뒤, 있으면 그 자리에 끼워 넣음 (맨 뒤보다 크면 맨 뒤)
+pub async fn attach_lesson_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    course_id: i32,
+    lesson_id: i32,
+    seq: Option<i32>,
+) -> AppResult<i32> {
+    let next_seq = sqlx::query_scalar::<_, i32>(
+        r#"SELECT COALESCE(MAX(course_lesson_seq), 0) + 1 FROM course_lesson WHERE course_id = $1"#,
+    )
+    .bind(course_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let seq = seq.map_or(next_seq, |s| s.min(next_seq));
+    if seq < next_seq {
+        shift_seqs_tx(tx, course_id, seq, 1).await?;
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO course_lesson (course_id, lesson_id, course_lesson_seq)
+        VALUES ($1, $2, $3)
+        "#,
+    )
+    .bind(course_id)
+    .bind(lesson_id)
+    .bind(seq)
+    .execute(&mut **tx)
+    .await?;
+    Ok(seq)
+}
+
+/// 레슨 연결 해제 + 뒤 레슨 당기기 — 대상 없으면 None
+pub async fn detach_lesson_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    course_id: i32,
+    lesson_id: i32,
+) -> AppResult<Option<i32>> {
+    let removed = sqlx::query_scalar::<_, i32>(
+        r#"
+        DELETE FROM course_lesson
+        WHERE course_id = $1 AND lesson_id = $2
+        RETURNING course_lesson_seq
+        "#,
+    )
+    .bind(course_id)
+    .bind(lesson_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    if let Some(seq) = removed {
+        shift_seqs_tx(tx, course_id, seq + 1, -1).await?;
+    }
+    Ok(removed)
+}
+
+/// 전체 순서 재배치 — lesson_ids 순서대로 1..N
+pub async fn reorder_lessons_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    course_id: i32,
+    lesson_ids: &[i32],
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE course_lesson
+        SET course_lesson_seq = -course_lesson_seq
+        WHERE course_id = $1
+        "#,
+    )
+    .bind(course_id)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE course_lesson cl
+        SET course_lesson_seq = o.seq::int
+        FROM UNNEST($2::int[]) WITH ORDINALITY AS o(lesson_id, seq)
+        WHERE cl.course_id = $1 AND cl.lesson_id = o.lesson_id
+        "#,
+    )
+    .bind(course_id)
+    .bind(lesson_ids)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// 코스 변경 로그 (admin_course_log)
+pub async fn create_course_log_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    admin_user_id: i64,
+    course_id: i32,
+    action: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO admin_course_log (
+            admin_user_id,
+            admin_pick_course_id,
+            admin_course_action,
+            admin_course_before,
+            admin_course_after
+        )
+        VALUES ($1, $2, CAST($3 AS admin_action_enum), $4, $5)
+        "#,
+    )
+    .bind(admin_user_id)
+    .bind(course_id)
+    .bind(action)
+    .bind(before)
+    .bind(after)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+const ADMIN_CERTIFICATE_SELECT: &str = r#"
+    SELECT
+        cc.certificate_id,
+        cc.certificate_code,
+        cc.user_id,
+        u.user_nickname,
+        cc.course_id,
+        cc.certificate_issued_at,
+        cc.certificate_revoked_at,
+        cc.certificate_revoked_by_user_id,
+        cc.certificate_revoke_reason
+    FROM course_certificate cc
+    JOIN users u ON u.user_id = cc.user_id
+"#;
+
+/// 코스 수료증 목록 (최근 발급순)
+pub async fn find_course_certificates(
+    db: &PgPool,
+    course_id: i32,
+) -> AppResult<Vec<AdminCertificateRes>> {
+    let sql = format!(
+        "{ADMIN_CERTIFICATE_SELECT} WHERE cc.course_id = $1 ORDER BY cc.certificate_issued_at DESC"
+    );
+    let rows = sqlx::query_as::<_, AdminCertificateRes>(&sql)
+        .bind(course_id)
+        .fetch_all(db)
+        .await?;
+    Ok(rows)
+}
+
+/// 폐기 대상 수료증 (행 잠금)
+pub async fn find_course_certificate_for_update_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    course_id: i32,
+    certificate_id: i64,
+) -> AppResult<Option<AdminCertificateRes>> {
+    let sql = format!(
+        "{ADMIN_CERTIFICATE_SELECT} WHERE cc.course_id = $1 AND cc.certificate_id = $2 FOR UPDATE OF cc"
+    );
+    let row = sqlx::query_as::<_, AdminCertificateRes>(&sql)
+        .bind(course_id)
+        .bind(certificate_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+    Ok(row)
+}
+
+pub async fn revoke_certificate_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    certificate_id: i64,
+    revoked_by_user_id: i64,
+    reason: &str,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE course_certificate
+        SET certificate_revoked_at = NOW(),
+            certificate_revoked_by_user_id = $2,
+            certificate_revoke_reason = $3
+        WHERE certificate_id = $1
+        "#,
+    )
+    .bind(certificate_id)
+    .bind(revoked_by_user_id)
+    .bind(reason)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
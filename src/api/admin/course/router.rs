@@ -0,0 +1,33 @@
+use super::handler::{
+    admin_attach_course_lesson, admin_detach_course_lesson, admin_list_course_certificates,
+    admin_list_course_lessons, admin_reorder_course_lessons, admin_revoke_course_certificate,
+};
+use crate::AppState;
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
+
+pub fn admin_course_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{course_id}/lessons",
+            get(admin_list_course_lessons).post(admin_attach_course_lesson),
+        )
+        .route(
+            "/{course_id}/lessons/order",
+            put(admin_reorder_course_lessons),
+        )
+        .route(
+            "/{course_id}/lessons/{lesson_id}",
+            delete(admin_detach_course_lesson),
+        )
+        .route(
+            "/{course_id}/certificates",
+            get(admin_list_course_certificates),
+        )
+        .route(
+            "/{course_id}/certificates/{certificate_id}/revoke",
+            post(admin_revoke_course_certificate),
+        )
+}
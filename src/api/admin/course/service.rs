@@ -0,0 +1,332 @@
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use validator::Validate;
+
+use super::dto::{
+    AdminCertificateListRes, AdminCertificateRes, AdminCourseLessonListRes, CertificateRevokeReq,
+    CourseLessonAttachReq, CourseLessonReorderReq,
+};
+use crate::api::certificate::service::issue_for_completed;
+use crate::api::course::repo::recompute_progress_for_course;
+use crate::error::{AppError, AppResult};
+use crate::types::UserAuth;
+use crate::AppState;
+
+const PG_UNIQUE_VIOLATION: &str = "23505";
+
+async fn check_admin_rbac(pool: &sqlx::PgPool, actor_user_id: i64) -> AppResult<UserAuth> {
+    let actor = crate::api::user::repo::find_user(pool, actor_user_id)
+        .await?
+        .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+
+    match actor.user_auth {
+        UserAuth::Hymn | UserAuth::Admin | UserAuth::Manager => Ok(actor.user_auth),
+        _ => Err(AppError::Forbidden("Forbidden".to_string())),
+    }
+}
+
+fn is_unique_violation(err: &AppError) -> bool {
+    if let AppError::Sqlx(sqlx::Error::Database(db)) = err {
+        db.code().as_deref() == Some(PG_UNIQUE_VIOLATION)
+    } else {
+        false
+    }
+}
+
+async fn ensure_course(st: &AppState, course_id: i32) -> AppResult<()> {
+    if !super::repo::exists_course(&st.db, course_id).await? {
+        return Err(AppError::NotFound);
+    }
+    Ok(())
+}
+
+/// 코스 커리큘럼 조회
+pub async fn list_course_lessons(
+    st: &AppState,
+    actor_user_id: i64,
+    course_id: i32,
+) -> AppResult<AdminCourseLessonListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    ensure_course(st, course_id).await?;
+
+    let lessons = super::repo::find_course_lessons(&st.db, course_id).await?;
+    Ok(AdminCourseLessonListRes { course_id, lessons })
+}
+
+/// 레슨 연결 — 수강생 진도율도 새 커리큘럼 기준으로 재계산
+pub async fn attach_lesson(
+    st: &AppState,
+    actor_user_id: i64,
+    course_id: i32,
+    req: CourseLessonAttachReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminCourseLessonListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    ensure_course(st, course_id).await?;
+    if !super::repo::exists_lesson(&st.db, req.lesson_id).await? {
+        return Err(AppError::BadRequest(format!(
+            "lesson {} not found",
+            req.lesson_id
+        )));
+    }
+    let mut tx = st.db.begin().await?;
+    crate::api::admin::lesson::prerequisite::service::ensure_course_attach_acyclic_tx(
+        &mut tx,
+        course_id,
+        req.lesson_id,
+    )
+    .await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "ATTACH_COURSE_LESSON",
+        "course_lesson",
+        Some(i64::from(course_id)),
+        &serde_json::json!({ "course_id": course_id, "req": &req }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    let seq = super::repo::attach_lesson_tx(&mut tx, course_id, req.lesson_id, req.seq)
+        .await
+        .map_err(|e| {
+            if is_unique_violation(&e) {
+                AppError::Conflict("lesson already attached to this course".into())
+            } else {
+                e
+            }
+        })?;
+    super::repo::create_course_log_tx(
+        &mut tx,
+        actor_user_id,
+        course_id,
+        "create",
+        None,
+        Some(&serde_json::json!({ "lesson_id": req.lesson_id, "seq": seq })),
+    )
+    .await?;
+    recompute_progress_for_course(&mut *tx, i64::from(course_id), None).await?;
+    // 커리큘럼 변경으로 100% 가 된 수강생에게 수료증 발급
+    issue_for_completed(&mut tx, None, Some(i64::from(course_id))).await?;
+    let lessons = super::repo::find_course_lessons(&mut *tx, course_id).await?;
+    tx.commit().await?;
+
+    Ok(AdminCourseLessonListRes { course_id, lessons })
+}
+
+/// 레슨 연결 해제 — 뒤 레슨 seq 를 당기고 진도율 재계산
+pub async fn detach_lesson(
+    st: &AppState,
+    actor_user_id: i64,
+    course_id: i32,
+    lesson_id: i32,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<()> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+
+    let mut tx = st.db.begin().await?;
+    let seq = super::repo::detach_lesson_tx(&mut tx, course_id, lesson_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    super::repo::create_course_log_tx(
+        &mut tx,
+        actor_user_id,
+        course_id,
+        "delete",
+        Some(&serde_json::json!({ "lesson_id": lesson_id, "seq": seq })),
+        None,
+    )
+    .await?;
+    recompute_progress_for_course(&mut *tx, i64::from(course_id), None).await?;
+    // 커리큘럼 변경으로 100% 가 된 수강생에게 수료증 발급
+    issue_for_completed(&mut tx, None, Some(i64::from(course_id))).await?;
+    tx.commit().await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "DETACH_COURSE_LESSON",
+        "course_lesson",
+        Some(i64::from(course_id)),
+        &serde_json::json!({ "course_id": course_id, "lesson_id": lesson_id }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+    Ok(())
+}
+
+/// 순서 변경 — 현재 연결된 레슨 전체를 빠짐없이 한 번씩 지정해야 함
+pub async fn reorder_lessons(
+    st: &AppState,
+    actor_user_id: i64,
+    course_id: i32,
+    req: CourseLessonReorderReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminCourseLessonListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    ensure_course(st, course_id).await?;
+
+    let mut tx = st.db.begin().await?;
+    let before = super::repo::find_course_lessons(&mut *tx, course_id).await?;
+    let current: Vec<i32> = before.iter().map(|l| l.lesson_id).collect();
+    validate_reorder(&current, &req.lesson_ids)?;
+
+    super::repo::reorder_lessons_tx(&mut tx, course_id, &req.lesson_ids).await?;
+    super::repo::create_course_log_tx(
+        &mut tx,
+        actor_user_id,
+        course_id,
+        "reorder",
+        Some(&serde_json::json!({ "lesson_ids": current })),
+        Some(&serde_json::json!({ "lesson_ids": &req.lesson_ids })),
+    )
+    .await?;
+    let lessons = super::repo::find_course_lessons(&mut *tx, course_id).await?;
+    tx.commit().await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "REORDER_COURSE_LESSONS",
+        "course_lesson",
+        Some(i64::from(course_id)),
+        &serde_json::json!({ "course_id": course_id, "req": &req }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    Ok(AdminCourseLessonListRes { course_id, lessons })
+}
+
+/// 코스 수료증 목록
+pub async fn list_certificates(
+    st: &AppState,
+    actor_user_id: i64,
+    course_id: i32,
+) -> AppResult<AdminCertificateListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    ensure_course(st, course_id).await?;
+
+    let items = super::repo::find_course_certificates(&st.db, course_id).await?;
+    Ok(AdminCertificateListRes { course_id, items })
+}
+
+/// 수료증 폐기 — 공개 검증 시 revoked 로 표시, 같은 코스로 자동 재발급되지 않음
+pub async fn revoke_certificate(
+    st: &AppState,
+    actor_user_id: i64,
+    course_id: i32,
+    certificate_id: i64,
+    req: CertificateRevokeReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminCertificateRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+
+    let mut tx = st.db.begin().await?;
+    let before =
+        super::repo::find_course_certificate_for_update_tx(&mut tx, course_id, certificate_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+    if before.certificate_revoked_at.is_some() {
+        return Err(AppError::Conflict("certificate already revoked".into()));
+    }
+
+    super::repo::revoke_certificate_tx(&mut tx, certificate_id, actor_user_id, &req.reason).await?;
+    super::repo::create_course_log_tx(
+        &mut tx,
+        actor_user_id,
+        course_id,
+        "update",
+        Some(&serde_json::json!({ "certificate_id": certificate_id, "revoked": false })),
+        Some(&serde_json::json!({
+            "certificate_id": certificate_id,
+            "revoked": true,
+            "reason": &req.reason,
+        })),
+    )
+    .await?;
+    let after =
+        super::repo::find_course_certificate_for_update_tx(&mut tx, course_id, certificate_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+    tx.commit().await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "REVOKE_CERTIFICATE",
+        "course_certificate",
+        Some(certificate_id),
+        &serde_json::json!({
+            "course_id": course_id,
+            "user_id": before.user_id,
+            "certificate_code": before.certificate_code,
+            "reason": &req.reason,
+        }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    Ok(after)
+}
+
+/// 재배치 목록 검증 = 중복 없음 + 현재 연결된 레슨과 정확히 같은 집합
+fn validate_reorder(current: &[i32], requested: &[i32]) -> AppResult<()> {
+    let requested_set: HashSet<i32> = requested.iter().copied().collect();
+    if requested_set.len() != requested.len() {
+        return Err(AppError::BadRequest(
+            "duplicate lesson_id in lesson_ids".into(),
+        ));
+    }
+    let current_set: HashSet<i32> = current.iter().copied().collect();
+    if requested_set != current_set {
+        return Err(AppError::BadRequest(
+            "lesson_ids must list every lesson currently attached to the course".into(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_reorder_accepts_permutation() {
+        assert!(validate_reorder(&[1, 2, 3], &[3, 1, 2]).is_ok());
+        assert!(validate_reorder(&[1, 2, 3], &[1, 2, 3]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_reorder_rejects_duplicates() {
+        let r = validate_reorder(&[1, 2, 3], &[1, 1, 2, 3]);
+        assert!(matches!(r, Err(AppError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_reorder_rejects_missing_or_unknown() {
+        let r = validate_reorder(&[1, 2, 3], &[1, 2]);
+        assert!(matches!(r, Err(AppError::BadRequest(_))));
+        let r = validate_reorder(&[1, 2, 3], &[1, 2, 4]);
+        assert!(matches!(r, Err(AppError::BadRequest(_))));
+    }
+}
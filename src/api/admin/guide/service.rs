@@ -5,7 +5,7 @@ use std::net::IpAddr;
 use validator::Validate;
 
 use crate::error::{AppError, AppResult};
-use crate::types::{SupportedLanguage, UserAuth};
+use crate::types::{PublishTarget, SupportedLanguage, UserAuth};
 use crate::AppState;
 
 use super::dto::{
@@ -135,6 +135,16 @@ pub async fn update_meta(
         req.subtitle_en.as_deref(),
     )
     .await?;
+    // 수동 state 변경은 예약 공개/비공개보다 우선 — pending 예약 명시 취소
+    if req.guide_state.is_some() {
+        crate::api::admin::schedule::service::cancel_on_manual_change(
+            &mut tx,
+            PublishTarget::Guide,
+            guide_id,
+            actor,
+        )
+        .await?;
+    }
     tx.commit().await?;
 
     Ok(AdminOkRes {
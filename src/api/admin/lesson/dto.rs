@@ -120,6 +120,8 @@ pub struct LessonUpdateReq {
     pub lesson_description: Option<String>,
     pub lesson_state: Option<LessonState>,
     pub lesson_access: Option<LessonAccess>,
+    /// HYMN 전용 — state=open 전환 시 번역 커버리지 공개 정책 우회 (PUBLISH_OVERRIDE 감사 기록)
+    pub force_publish: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
@@ -1,5 +1,6 @@
 pub mod dto;
 pub mod handler;
+pub mod prerequisite;
 pub mod repo;
 pub mod router;
 pub mod service;
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::types::LessonPrereqKind;
+
+// ==========================================
+// 요청
+// ==========================================
+
+/// 선수 조건 1건
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct LessonPrerequisiteRuleReq {
+    pub kind: LessonPrereqKind,
+    /// 선행 레슨/코스/학습 ID (kind 에 따라)
+    pub required_id: i32,
+    /// lesson/course = 최소 진도율 (1~100), study = 최소 정답 문제 수 (1 이상)
+    #[schema(example = 80)]
+    pub threshold: i32,
+}
+
+/// 레슨 선수 조건 전체 교체 요청 (빈 목록이면 조건 해제)
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct LessonPrerequisiteSetReq {
+    #[validate(length(max = 20))]
+    pub rules: Vec<LessonPrerequisiteRuleReq>,
+}
+
+/// 사용자별 수동 해제 요청
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct LessonUnlockCreateReq {
+    pub user_id: i64,
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+// ==========================================
+// 응답
+// ==========================================
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminLessonPrerequisiteRes {
+    pub prerequisite_id: i64,
+    pub prereq_kind: LessonPrereqKind,
+    pub required_lesson_id: Option<i32>,
+    pub required_course_id: Option<i32>,
+    pub required_study_id: Option<i32>,
+    pub required_title: Option<String>,
+    pub prereq_threshold: i32,
+    pub updated_by_user_id: Option<i64>,
+    pub prereq_created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminLessonPrerequisiteListRes {
+    pub lesson_id: i32,
+    pub rules: Vec<AdminLessonPrerequisiteRes>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminLessonUnlockRes {
+    pub lesson_id: i32,
+    pub user_id: i64,
+    pub granted_by_user_id: Option<i64>,
+    pub unlock_reason: Option<String>,
+    pub unlock_created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminLessonUnlockListRes {
+    pub lesson_id: i32,
+    pub unlocks: Vec<AdminLessonUnlockRes>,
+}
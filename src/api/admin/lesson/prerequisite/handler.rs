@@ -0,0 +1,176 @@
+use super::dto::{
+    AdminLessonPrerequisiteListRes, AdminLessonUnlockListRes, AdminLessonUnlockRes,
+    LessonPrerequisiteSetReq, LessonUnlockCreateReq,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::api::auth::extractor::AuthUser;
+use crate::error::AppResult;
+use crate::extract::AppJson;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+
+/// 레슨 선수 조건 조회
+#[utoipa::path(
+    get,
+    path = "/admin/lessons/{lesson_id}/prerequisites",
+    tag = "admin_lesson",
+    params(
+        ("lesson_id" = i32, Path, description = "Lesson ID")
+    ),
+    responses(
+        (status = 200, description = "Prerequisite rules", body = AdminLessonPrerequisiteListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Lesson not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_lesson_prerequisites(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(lesson_id): Path<i32>,
+) -> AppResult<Json<AdminLessonPrerequisiteListRes>> {
+    let res = super::service::list_prerequisites(&st, auth_user.sub, lesson_id).await?;
+    Ok(Json(res))
+}
+
+/// 레슨 선수 조건 전체 교체
+#[utoipa::path(
+    put,
+    path = "/admin/lessons/{lesson_id}/prerequisites",
+    tag = "admin_lesson",
+    params(
+        ("lesson_id" = i32, Path, description = "Lesson ID")
+    ),
+    request_body = LessonPrerequisiteSetReq,
+    responses(
+        (status = 200, description = "Prerequisite rules saved", body = AdminLessonPrerequisiteListRes),
+        (status = 400, description = "Invalid rule, unknown target or cycle", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Lesson not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_set_lesson_prerequisites(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(lesson_id): Path<i32>,
+    AppJson(req): AppJson<LessonPrerequisiteSetReq>,
+) -> AppResult<Json<AdminLessonPrerequisiteListRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res = super::service::set_prerequisites(
+        &st,
+        auth_user.sub,
+        lesson_id,
+        req,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+/// 레슨 수동 해제 목록
+#[utoipa::path(
+    get,
+    path = "/admin/lessons/{lesson_id}/unlocks",
+    tag = "admin_lesson",
+    params(
+        ("lesson_id" = i32, Path, description = "Lesson ID")
+    ),
+    responses(
+        (status = 200, description = "Per-user unlock overrides", body = AdminLessonUnlockListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Lesson not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_lesson_unlocks(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(lesson_id): Path<i32>,
+) -> AppResult<Json<AdminLessonUnlockListRes>> {
+    let res = super::service::list_unlocks(&st, auth_user.sub, lesson_id).await?;
+    Ok(Json(res))
+}
+
+/// 특정 사용자에게 레슨 수동 해제
+#[utoipa::path(
+    post,
+    path = "/admin/lessons/{lesson_id}/unlocks",
+    tag = "admin_lesson",
+    params(
+        ("lesson_id" = i32, Path, description = "Lesson ID")
+    ),
+    request_body = LessonUnlockCreateReq,
+    responses(
+        (status = 201, description = "Lesson unlocked for user", body = AdminLessonUnlockRes),
+        (status = 400, description = "Unknown user", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Lesson not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_create_lesson_unlock(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(lesson_id): Path<i32>,
+    AppJson(req): AppJson<LessonUnlockCreateReq>,
+) -> AppResult<(StatusCode, Json<AdminLessonUnlockRes>)> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res =
+        super::service::create_unlock(&st, auth_user.sub, lesson_id, req, ip_address, user_agent)
+            .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+/// 레슨 수동 해제 취소
+#[utoipa::path(
+    delete,
+    path = "/admin/lessons/{lesson_id}/unlocks/{user_id}",
+    tag = "admin_lesson",
+    params(
+        ("lesson_id" = i32, Path, description = "Lesson ID"),
+        ("user_id" = i64, Path, description = "User ID")
+    ),
+    responses(
+        (status = 204, description = "Unlock revoked"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Unlock not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_delete_lesson_unlock(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((lesson_id, user_id)): Path<(i32, i64)>,
+) -> AppResult<StatusCode> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    super::service::delete_unlock(
+        &st,
+        auth_user.sub,
+        lesson_id,
+        user_id,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,219 @@
+use sqlx::{PgPool, Postgres, Transaction};
+
+use super::dto::{AdminLessonPrerequisiteRes, AdminLessonUnlockRes, LessonPrerequisiteRuleReq};
+use crate::error::AppResult;
+use crate::types::LessonPrereqKind;
+
+pub async fn exists_lesson(db: &PgPool, lesson_id: i32) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"SELECT EXISTS(SELECT 1 FROM lesson WHERE lesson_id = $1)"#,
+    )
+    .bind(lesson_id)
+    .fetch_one(db)
+    .await?;
+    Ok(exists)
+}
+
+/// 선행 대상 존재 확인 (kind 별 테이블)
+pub async fn exists_required(db: &PgPool, kind: LessonPrereqKind, id: i32) -> AppResult<bool> {
+    let sql = match kind {
+        LessonPrereqKind::Lesson => "SELECT EXISTS(SELECT 1 FROM lesson WHERE lesson_id = $1)",
+        LessonPrereqKind::Course => {
+            "SELECT EXISTS(SELECT 1 FROM course WHERE course_id = $1 AND course_state <> 'deleted')"
+        }
+        LessonPrereqKind::Study => "SELECT EXISTS(SELECT 1 FROM study WHERE study_id = $1)",
+    };
+    let exists = sqlx::query_scalar::<_, bool>(sql)
+        .bind(id)
+        .fetch_one(db)
+        .await?;
+    Ok(exists)
+}
+
+pub async fn find_prerequisites(
+    executor: impl sqlx::PgExecutor<'_>,
+    lesson_id: i32,
+) -> AppResult<Vec<AdminLessonPrerequisiteRes>> {
+    let rows = sqlx::query_as::<_, AdminLessonPrerequisiteRes>(
+        r#"
+        SELECT
+            p.prerequisite_id,
+            p.prereq_kind,
+            p.required_lesson_id,
+            p.required_course_id,
+            p.required_study_id,
+            COALESCE(l.lesson_title, c.course_title, s.study_title) AS required_title,
+            p.prereq_threshold,
+            p.updated_by_user_id,
+            p.prereq_created_at
+        FROM lesson_prerequisite p
+        LEFT JOIN lesson l ON l.lesson_id = p.required_lesson_id
+        LEFT JOIN course c ON c.course_id = p.required_course_id
+        LEFT JOIN study s ON s.study_id = p.required_study_id
+        WHERE p.lesson_id = $1
+        ORDER BY p.prerequisite_id
+        "#,
+    )
+    .bind(lesson_id)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
+
+/// 레슨 의존 그래프 변경 직렬화용 transaction-scoped advisory lock (그래프 전역 1개).
+/// 선수 조건 교체와 코스 레슨 연결이 각자 순환 검사를 통과한 뒤 동시에 저장되어
+/// 순환이 생기는 것을 막는다. 트랜잭션 종료 시 자동 해제.
+pub async fn acquire_dependency_graph_lock_tx(tx: &mut Transaction<'_, Postgres>) -> AppResult<()> {
+    sqlx::query("SELECT pg_advisory_xact_lock($1)")
+        .bind(0x7072657265_i64) // 'prere' hash
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/// 레슨 의존 그래프 간선 (lesson → 선행 레슨). 코스 조건은 코스 소속 레슨 전체로 전개
+pub async fn find_dependency_edges(
+    executor: impl sqlx::PgExecutor<'_>,
+) -> AppResult<Vec<(i32, i32)>> {
+    let rows = sqlx::query_as::<_, (i32, i32)>(
+        r#"
+        SELECT lesson_id, required_lesson_id
+        FROM lesson_prerequisite
+        WHERE prereq_kind = 'lesson'
+        UNION
+        SELECT p.lesson_id, cl.lesson_id
+        FROM lesson_prerequisite p
+        JOIN course_lesson cl ON cl.course_id = p.required_course_id
+        WHERE p.prereq_kind = 'course'
+        "#,
+    )
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
+
+/// 코스별 소속 레슨 (course_id, lesson_id)
+pub async fn find_course_members(
+    executor: impl sqlx::PgExecutor<'_>,
+    course_ids: &[i32],
+) -> AppResult<Vec<(i32, i32)>> {
+    let rows = sqlx::query_as::<_, (i32, i32)>(
+        r#"SELECT course_id, lesson_id FROM course_lesson WHERE course_id = ANY($1)"#,
+    )
+    .bind(course_ids)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
+
+/// 선수 조건 전체 교체
+pub async fn replace_prerequisites_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    lesson_id: i32,
+    rules: &[LessonPrerequisiteRuleReq],
+    actor_user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(r#"DELETE FROM lesson_prerequisite WHERE lesson_id = $1"#)
+        .bind(lesson_id)
+        .execute(&mut **tx)
+        .await?;
+
+    for rule in rules {
+        let id = Some(rule.required_id);
+        let (lesson, course, study) = match rule.kind {
+            LessonPrereqKind::Lesson => (id, None, None),
+            LessonPrereqKind::Course => (None, id, None),
+            LessonPrereqKind::Study => (None, None, id),
+        };
+        sqlx::query(
+            r#"
+            INSERT INTO lesson_prerequisite (
+                lesson_id, prereq_kind, required_lesson_id, required_course_id,
+                required_study_id, prereq_threshold, updated_by_user_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(lesson_id)
+        .bind(rule.kind)
+        .bind(lesson)
+        .bind(course)
+        .bind(study)
+        .bind(rule.threshold)
+        .bind(actor_user_id)
+        .execute(&mut **tx)
+        .await?;
+    }
+    Ok(())
+}
+
+pub async fn find_unlocks(db: &PgPool, lesson_id: i32) -> AppResult<Vec<AdminLessonUnlockRes>> {
+    let rows = sqlx::query_as::<_, AdminLessonUnlockRes>(
+        r#"
+        SELECT lesson_id, user_id, granted_by_user_id, unlock_reason, unlock_created_at
+        FROM lesson_unlock_override
+        WHERE lesson_id = $1
+        ORDER BY unlock_created_at DESC
+        "#,
+    )
+    .bind(lesson_id)
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
+
+/// 수동 해제 부여 (이미 있으면 사유/부여자 갱신)
+pub async fn upsert_unlock(
+    db: &PgPool,
+    lesson_id: i32,
+    user_id: i64,
+    reason: Option<&str>,
+    actor_user_id: i64,
+) -> AppResult<AdminLessonUnlockRes> {
+    let row = sqlx::query_as::<_, AdminLessonUnlockRes>(
+        r#"
+        INSERT INTO lesson_unlock_override (lesson_id, user_id, granted_by_user_id, unlock_reason)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (lesson_id, user_id) DO UPDATE SET
+            granted_by_user_id = EXCLUDED.granted_by_user_id,
+            unlock_reason = EXCLUDED.unlock_reason,
+            unlock_created_at = NOW()
+        RETURNING lesson_id, user_id, granted_by_user_id, unlock_reason, unlock_created_at
+        "#,
+    )
+    .bind(lesson_id)
+    .bind(user_id)
+    .bind(actor_user_id)
+    .bind(reason)
+    .fetch_one(db)
+    .await?;
+    Ok(row)
+}
+
+pub async fn delete_unlock(db: &PgPool, lesson_id: i32, user_id: i64) -> AppResult<bool> {
+    let res =
+        sqlx::query(r#"DELETE FROM lesson_unlock_override WHERE lesson_id = $1 AND user_id = $2"#)
+            .bind(lesson_id)
+            .bind(user_id)
+            .execute(db)
+            .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// 특정 코스를 선수 조건으로 가진 레슨들
+pub async fn find_lessons_requiring_course(
+    executor: impl sqlx::PgExecutor<'_>,
+    course_id: i32,
+) -> AppResult<Vec<i32>> {
+    let rows = sqlx::query_scalar::<_, i32>(
+        r#"
+        SELECT lesson_id
+        FROM lesson_prerequisite
+        WHERE prereq_kind = 'course' AND required_course_id = $1
+        "#,
+    )
+    .bind(course_id)
+    .fetch_all(executor)
+    .await?;
+    Ok(rows)
+}
@@ -0,0 +1,27 @@
+use super::handler::{
+    admin_create_lesson_unlock, admin_delete_lesson_unlock, admin_list_lesson_prerequisites,
+    admin_list_lesson_unlocks, admin_set_lesson_prerequisites,
+};
+use crate::AppState;
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+/// 레슨 선수 조건 라우터 (/{lesson_id}/prerequisites 하위)
+pub fn admin_prerequisite_router() -> Router<AppState> {
+    Router::new().route(
+        "/",
+        get(admin_list_lesson_prerequisites).put(admin_set_lesson_prerequisites),
+    )
+}
+
+/// 레슨 사용자별 수동 해제 라우터 (/{lesson_id}/unlocks 하위)
+pub fn admin_unlock_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(admin_list_lesson_unlocks).post(admin_create_lesson_unlock),
+        )
+        .route("/{user_id}", delete(admin_delete_lesson_unlock))
+}
@@ -0,0 +1,347 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+
+use sqlx::{Postgres, Transaction};
+use validator::Validate;
+
+use super::dto::{
+    AdminLessonPrerequisiteListRes, AdminLessonUnlockListRes, AdminLessonUnlockRes,
+    LessonPrerequisiteRuleReq, LessonPrerequisiteSetReq, LessonUnlockCreateReq,
+};
+use crate::api::admin::lesson::repo::{create_lesson_log_tx, LessonLogParams};
+use crate::api::admin::lesson::service::check_admin_rbac;
+use crate::error::{AppError, AppResult};
+use crate::types::LessonPrereqKind;
+use crate::AppState;
+
+async fn ensure_lesson(st: &AppState, lesson_id: i32) -> AppResult<()> {
+    if !super::repo::exists_lesson(&st.db, lesson_id).await? {
+        return Err(AppError::NotFound);
+    }
+    Ok(())
+}
+
+/// 선수 조건 조회
+pub async fn list_prerequisites(
+    st: &AppState,
+    actor_user_id: i64,
+    lesson_id: i32,
+) -> AppResult<AdminLessonPrerequisiteListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    ensure_lesson(st, lesson_id).await?;
+
+    let rules = super::repo::find_prerequisites(&st.db, lesson_id).await?;
+    Ok(AdminLessonPrerequisiteListRes { lesson_id, rules })
+}
+
+/// 선수 조건 전체 교체 — 대상 존재 + 순환 여부 검증 후 저장
+pub async fn set_prerequisites(
+    st: &AppState,
+    actor_user_id: i64,
+    lesson_id: i32,
+    req: LessonPrerequisiteSetReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminLessonPrerequisiteListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    ensure_lesson(st, lesson_id).await?;
+    validate_rules(&req.rules)?;
+
+    for rule in &req.rules {
+        if !super::repo::exists_required(&st.db, rule.kind, rule.required_id).await? {
+            return Err(AppError::BadRequest(format!(
+                "{:?} {} not found",
+                rule.kind, rule.required_id
+            )));
+        }
+    }
+
+    // 순환 검사와 저장을 같은 tx 에서 그래프 락을 잡고 수행 (동시 변경으로 인한 순환 방지)
+    let mut tx = st.db.begin().await?;
+    super::repo::acquire_dependency_graph_lock_tx(&mut tx).await?;
+
+    // 기존 그래프에서 이 레슨의 간선만 새 규칙으로 바꿔 순환 검사
+    let course_ids: Vec<i32> = req
+        .rules
+        .iter()
+        .filter(|r| r.kind == LessonPrereqKind::Course)
+        .map(|r| r.required_id)
+        .collect();
+    let course_members = super::repo::find_course_members(&mut *tx, &course_ids).await?;
+
+    let mut edges: Vec<(i32, i32)> = super::repo::find_dependency_edges(&mut *tx)
+        .await?
+        .into_iter()
+        .filter(|(from, _)| *from != lesson_id)
+        .collect();
+    for rule in &req.rules {
+        match rule.kind {
+            LessonPrereqKind::Lesson => edges.push((lesson_id, rule.required_id)),
+            LessonPrereqKind::Course => edges.extend(
+                course_members
+                    .iter()
+                    .filter(|(course_id, _)| *course_id == rule.required_id)
+                    .map(|(_, member)| (lesson_id, *member)),
+            ),
+            LessonPrereqKind::Study => {}
+        }
+    }
+    if has_cycle(&edges) {
+        return Err(AppError::BadRequest(
+            "prerequisite rules would create a cycle".into(),
+        ));
+    }
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "SET_LESSON_PREREQUISITES",
+        "lesson_prerequisite",
+        Some(i64::from(lesson_id)),
+        &serde_json::json!({ "lesson_id": lesson_id, "req": &req }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    let before = super::repo::find_prerequisites(&mut *tx, lesson_id).await?;
+    super::repo::replace_prerequisites_tx(&mut tx, lesson_id, &req.rules, actor_user_id).await?;
+    let rules = super::repo::find_prerequisites(&mut *tx, lesson_id).await?;
+
+    let before_val = serde_json::json!({ "prerequisites": before });
+    let after_val = serde_json::json!({ "prerequisites": &rules });
+    create_lesson_log_tx(
+        &mut tx,
+        &LessonLogParams {
+            admin_user_id: actor_user_id,
+            action: "update",
+            lesson_id,
+            lesson_item_seq: None,
+            video_id: None,
+            task_id: None,
+            before: Some(&before_val),
+            after: Some(&after_val),
+        },
+    )
+    .await?;
+    tx.commit().await?;
+
+    Ok(AdminLessonPrerequisiteListRes { lesson_id, rules })
+}
+
+/// 코스에 레슨을 추가해도 순환이 생기지 않는지 (해당 코스를 조건으로 가진 레슨 → 새 레슨).
+/// 그래프 락을 잡으므로 호출자는 같은 tx 에서 연결을 저장해야 한다.
+pub async fn ensure_course_attach_acyclic_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    course_id: i32,
+    lesson_id: i32,
+) -> AppResult<()> {
+    super::repo::acquire_dependency_graph_lock_tx(tx).await?;
+    let dependents = super::repo::find_lessons_requiring_course(&mut **tx, course_id).await?;
+    if dependents.is_empty() {
+        return Ok(());
+    }
+
+    let mut edges = super::repo::find_dependency_edges(&mut **tx).await?;
+    edges.extend(dependents.into_iter().map(|from| (from, lesson_id)));
+    if has_cycle(&edges) {
+        return Err(AppError::BadRequest(
+            "attaching this lesson would create a prerequisite cycle".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// 수동 해제 목록
+pub async fn list_unlocks(
+    st: &AppState,
+    actor_user_id: i64,
+    lesson_id: i32,
+) -> AppResult<AdminLessonUnlockListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    ensure_lesson(st, lesson_id).await?;
+
+    let unlocks = super::repo::find_unlocks(&st.db, lesson_id).await?;
+    Ok(AdminLessonUnlockListRes { lesson_id, unlocks })
+}
+
+/// 특정 사용자 수동 해제
+pub async fn create_unlock(
+    st: &AppState,
+    actor_user_id: i64,
+    lesson_id: i32,
+    req: LessonUnlockCreateReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminLessonUnlockRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    ensure_lesson(st, lesson_id).await?;
+    if crate::api::user::repo::find_user(&st.db, req.user_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::BadRequest(format!(
+            "user {} not found",
+            req.user_id
+        )));
+    }
+
+    let res = super::repo::upsert_unlock(
+        &st.db,
+        lesson_id,
+        req.user_id,
+        req.reason.as_deref(),
+        actor_user_id,
+    )
+    .await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "UNLOCK_LESSON",
+        "lesson_unlock_override",
+        Some(req.user_id),
+        &serde_json::json!({ "lesson_id": lesson_id, "req": &req }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+    Ok(res)
+}
+
+/// 수동 해제 취소
+pub async fn delete_unlock(
+    st: &AppState,
+    actor_user_id: i64,
+    lesson_id: i32,
+    user_id: i64,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<()> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+
+    if !super::repo::delete_unlock(&st.db, lesson_id, user_id).await? {
+        return Err(AppError::NotFound);
+    }
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "REVOKE_LESSON_UNLOCK",
+        "lesson_unlock_override",
+        Some(user_id),
+        &serde_json::json!({ "lesson_id": lesson_id }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+    Ok(())
+}
+
+/// 규칙 형식 검증 = kind 별 threshold 범위 + (kind, required_id) 중복 없음
+fn validate_rules(rules: &[LessonPrerequisiteRuleReq]) -> AppResult<()> {
+    let mut seen = HashSet::new();
+    for rule in rules {
+        let valid = match rule.kind {
+            LessonPrereqKind::Lesson | LessonPrereqKind::Course => {
+                (1..=100).contains(&rule.threshold)
+            }
+            LessonPrereqKind::Study => rule.threshold >= 1,
+        };
+        if !valid {
+            return Err(AppError::BadRequest(format!(
+                "invalid threshold {} for {:?} rule",
+                rule.threshold, rule.kind
+            )));
+        }
+        if !seen.insert((rule.kind, rule.required_id)) {
+            return Err(AppError::BadRequest(format!(
+                "duplicate {:?} rule for {}",
+                rule.kind, rule.required_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// 방향 그래프 순환 여부 (Kahn 위상 정렬 — 정렬되지 않고 남는 노드가 있으면 순환)
+fn has_cycle(edges: &[(i32, i32)]) -> bool {
+    let mut adjacency: HashMap<i32, Vec<i32>> = HashMap::new();
+    let mut in_degree: HashMap<i32, usize> = HashMap::new();
+    for &(from, to) in edges {
+        adjacency.entry(from).or_default().push(to);
+        *in_degree.entry(to).or_default() += 1;
+        in_degree.entry(from).or_default();
+    }
+
+    let mut queue: Vec<i32> = in_degree
+        .iter()
+        .filter(|(_, d)| **d == 0)
+        .map(|(n, _)| *n)
+        .collect();
+    let mut visited = 0usize;
+    while let Some(node) = queue.pop() {
+        visited += 1;
+        for next in adjacency.get(&node).into_iter().flatten() {
+            if let Some(d) = in_degree.get_mut(next) {
+                *d -= 1;
+                if *d == 0 {
+                    queue.push(*next);
+                }
+            }
+        }
+    }
+    visited < in_degree.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: LessonPrereqKind, required_id: i32, threshold: i32) -> LessonPrerequisiteRuleReq {
+        LessonPrerequisiteRuleReq {
+            kind,
+            required_id,
+            threshold,
+        }
+    }
+
+    #[test]
+    fn test_has_cycle_detects_cycles() {
+        assert!(!has_cycle(&[]));
+        assert!(!has_cycle(&[(3, 2), (2, 1), (3, 1)]), "DAG");
+        assert!(has_cycle(&[(1, 1)]), "self loop");
+        assert!(has_cycle(&[(1, 2), (2, 3), (3, 1)]));
+        assert!(has_cycle(&[(9, 8), (1, 2), (2, 1)]), "disconnected cycle");
+    }
+
+    #[test]
+    fn test_validate_rules_threshold_ranges() {
+        assert!(validate_rules(&[rule(LessonPrereqKind::Lesson, 1, 80)]).is_ok());
+        assert!(validate_rules(&[rule(LessonPrereqKind::Study, 1, 250)]).is_ok());
+        assert!(validate_rules(&[rule(LessonPrereqKind::Course, 1, 101)]).is_err());
+        assert!(validate_rules(&[rule(LessonPrereqKind::Lesson, 1, 0)]).is_err());
+        assert!(validate_rules(&[rule(LessonPrereqKind::Study, 1, 0)]).is_err());
+    }
+
+    #[test]
+    fn test_validate_rules_rejects_duplicates() {
+        let r = validate_rules(&[
+            rule(LessonPrereqKind::Lesson, 1, 80),
+            rule(LessonPrereqKind::Lesson, 1, 50),
+        ]);
+        assert!(matches!(r, Err(AppError::BadRequest(_))));
+        // 같은 ID 라도 종류가 다르면 별개
+        assert!(validate_rules(&[
+            rule(LessonPrereqKind::Lesson, 1, 80),
+            rule(LessonPrereqKind::Study, 1, 5),
+        ])
+        .is_ok());
+    }
+}
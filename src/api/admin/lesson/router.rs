@@ -9,6 +9,7 @@ use super::handler::{
     admin_list_lesson_progress, admin_list_lessons, admin_update_lesson, admin_update_lesson_item,
     admin_update_lesson_progress,
 };
+use super::prerequisite::router::{admin_prerequisite_router, admin_unlock_router};
 
 pub fn admin_lesson_router() -> Router<AppState> {
     Router::new()
@@ -37,6 +38,8 @@ pub fn admin_lesson_router() -> Router<AppState> {
             patch(admin_update_lesson_item).delete(admin_delete_lesson_item),
         )
         .route("/{lesson_id}/progress", patch(admin_update_lesson_progress))
+        .nest("/{lesson_id}/prerequisites", admin_prerequisite_router())
+        .nest("/{lesson_id}/unlocks", admin_unlock_router())
         .route(
             "/{lesson_id}",
             get(admin_get_lesson).patch(admin_update_lesson),
@@ -2,8 +2,10 @@ use std::net::IpAddr;
 
 use validator::Validate;
 
+use crate::api::admin::schedule::service::cancel_on_manual_change;
+use crate::api::admin::translation::service::TranslationService;
 use crate::error::{AppError, AppResult};
-use crate::types::{LessonAccess, LessonState, UserAuth};
+use crate::types::{ContentType, LessonAccess, LessonState, PublishTarget, UserAuth};
 use crate::AppState;
 
 use super::dto::{
@@ -22,7 +24,10 @@ use super::repo::{self, LessonLogParams};
 
 const PG_UNIQUE_VIOLATION: &str = "23505";
 
-async fn check_admin_rbac(pool: &sqlx::PgPool, actor_user_id: i64) -> AppResult<UserAuth> {
+pub(super) async fn check_admin_rbac(
+    pool: &sqlx::PgPool,
+    actor_user_id: i64,
+) -> AppResult<UserAuth> {
     let actor = crate::api::user::repo::find_user(pool, actor_user_id)
         .await?
         .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
@@ -306,6 +311,14 @@ pub async fn admin_update_lesson_progress(
     )
     .await?;
 
+    crate::api::course::repo::recompute_progress_for_lesson(
+        &mut *tx,
+        req.user_id,
+        i64::from(lesson_id),
+    )
+    .await?;
+    crate::api::certificate::service::issue_for_completed(&mut tx, Some(req.user_id), None).await?;
+
     tx.commit().await?;
 
     Ok(after)
@@ -397,6 +410,15 @@ pub async fn admin_bulk_update_lesson_progress(
             )
             .await?;
 
+            crate::api::course::repo::recompute_progress_for_lesson(
+                &mut *tx,
+                user_id,
+                i64::from(lesson_id),
+            )
+            .await?;
+            crate::api::certificate::service::issue_for_completed(&mut tx, Some(user_id), None)
+                .await?;
+
             tx.commit().await?;
 
             Ok(())
@@ -419,6 +441,7 @@ pub async fn admin_bulk_update_lesson_progress(
                     AppError::NotFound => "Lesson progress not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -676,6 +699,7 @@ pub async fn admin_bulk_create_lesson_items(
                     AppError::NotFound => "Lesson not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1083,6 +1107,7 @@ pub async fn admin_bulk_update_lesson_items(
                     AppError::NotFound => "Lesson item not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1346,6 +1371,9 @@ pub async fn admin_create_lesson(
         .filter(|v| !v.is_empty());
     let lesson_state = req.lesson_state.unwrap_or(LessonState::Ready);
     let lesson_access = req.lesson_access.unwrap_or(LessonAccess::Public);
+    if lesson_state == LessonState::Open {
+        TranslationService::ensure_open_on_create_allowed(&st.db, ContentType::Lesson).await?;
+    }
 
     crate::api::admin::user::repo::write_audit_log(
         st,
@@ -1470,6 +1498,10 @@ pub async fn admin_bulk_create_lessons(
                 .filter(|v| !v.is_empty());
             let lesson_state = item.lesson_state.unwrap_or(LessonState::Ready);
             let lesson_access = item.lesson_access.unwrap_or(LessonAccess::Public);
+            if lesson_state == LessonState::Open {
+                TranslationService::ensure_open_on_create_allowed(&st.db, ContentType::Lesson)
+                    .await?;
+            }
 
             let mut tx = st.db.begin().await?;
 
@@ -1536,6 +1568,7 @@ pub async fn admin_bulk_create_lessons(
                 let msg = match e {
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1570,7 +1603,7 @@ pub async fn admin_bulk_update_lessons(
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
 ) -> AppResult<(bool, LessonBulkUpdateRes)> {
-    check_admin_rbac(&st.db, actor_user_id).await?;
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
 
     if let Err(e) = req.validate() {
         return Err(AppError::BadRequest(e.to_string()));
@@ -1620,6 +1653,23 @@ pub async fn admin_bulk_update_lessons(
                 .await?
                 .ok_or(AppError::NotFound)?;
 
+            // 일괄 수정은 정책 우회 불가 — 미충족 항목은 422 로 실패 처리
+            if item.lesson_state == Some(LessonState::Open)
+                && before.lesson_state != LessonState::Open
+            {
+                TranslationService::enforce_publish_policy(
+                    st,
+                    actor_user_id,
+                    actor_auth,
+                    ContentType::Lesson,
+                    lesson_id as i64,
+                    false,
+                    None,
+                    None,
+                )
+                .await?;
+            }
+
             if let Some(ref idx) = item.lesson_idx {
                 let trimmed = idx.trim();
                 if trimmed.is_empty() {
@@ -1685,6 +1735,16 @@ pub async fn admin_bulk_update_lessons(
             )
             .await?;
 
+            if item.lesson_state.is_some() {
+                cancel_on_manual_change(
+                    &mut tx,
+                    PublishTarget::Lesson,
+                    i64::from(lesson_id),
+                    actor_user_id,
+                )
+                .await?;
+            }
+
             tx.commit().await?;
 
             Ok(after)
@@ -1706,6 +1766,7 @@ pub async fn admin_bulk_update_lessons(
                     AppError::NotFound => "Lesson not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1740,7 +1801,7 @@ pub async fn admin_update_lesson(
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
 ) -> AppResult<AdminLessonRes> {
-    check_admin_rbac(&st.db, actor_user_id).await?;
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
 
     crate::api::admin::user::repo::write_audit_log(
         st,
@@ -1775,6 +1836,21 @@ pub async fn admin_update_lesson(
         .await?
         .ok_or(AppError::NotFound)?;
 
+    // 공개 전환 시 번역 커버리지 정책 (HYMN force_publish 우회 가능)
+    if req.lesson_state == Some(LessonState::Open) && before.lesson_state != LessonState::Open {
+        TranslationService::enforce_publish_policy(
+            st,
+            actor_user_id,
+            actor_auth,
+            ContentType::Lesson,
+            lesson_id as i64,
+            req.force_publish.unwrap_or(false),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+    }
+
     if let Some(ref idx) = req.lesson_idx {
         let trimmed = idx.trim();
         if trimmed.is_empty() {
@@ -1840,6 +1916,17 @@ pub async fn admin_update_lesson(
     )
     .await?;
 
+    // 수동 state 변경은 예약 공개/비공개보다 우선 — pending 예약 명시 취소
+    if req.lesson_state.is_some() {
+        cancel_on_manual_change(
+            &mut tx,
+            PublishTarget::Lesson,
+            i64::from(lesson_id),
+            actor_user_id,
+        )
+        .await?;
+    }
+
     tx.commit().await?;
 
     Ok(after)
@@ -1,12 +1,16 @@
+pub mod course;
 pub mod ebook;
 pub mod email;
 pub mod guide;
 pub mod header_utils;
 pub mod ip_guard;
 pub mod lesson;
+pub mod org;
 pub mod payment;
 pub mod role_guard;
 pub mod router;
+pub mod schedule;
+pub mod security;
 pub mod study;
 pub mod textbook;
 pub mod translation;
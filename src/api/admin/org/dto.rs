@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{OrgLicenseScope, OrgSsoProtocol};
+
+// =============================================================================
+// 기관
+// =============================================================================
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct AdminOrgListReq {
+    pub page: Option<i64>,
+    pub size: Option<i64>,
+    /// 기관명 검색
+    pub q: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AdminOrgSummary {
+    pub org_id: i64,
+    pub org_name: String,
+    pub license_count: i64,
+    /// 현재 유효기간 안의 라이선스 좌석 합계
+    pub current_seat_count: i64,
+    /// 현재 유효기간 안의 라이선스에서 수락 완료된 좌석
+    pub current_seats_active: i64,
+    pub admin_count: i64,
+    pub org_created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminOrgMeta {
+    pub total_count: i64,
+    pub total_pages: i64,
+    pub current_page: i64,
+    pub per_page: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminOrgListRes {
+    pub items: Vec<AdminOrgSummary>,
+    pub meta: AdminOrgMeta,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AdminCreateOrgReq {
+    #[validate(length(min = 1, max = 200))]
+    pub org_name: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AdminUpdateOrgReq {
+    #[validate(length(min = 1, max = 200))]
+    pub org_name: String,
+}
+
+/// 기관 관리자 지정 (가입된 사용자)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AdminAddOrgAdminReq {
+    pub user_id: i64,
+}
+
+// =============================================================================
+// 라이선스
+// =============================================================================
+
+/// 좌석 라이선스 발급
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AdminCreateLicenseReq {
+    pub license_scope: OrgLicenseScope,
+    /// scope=course 일 때 필수, scope=subscription 이면 비워야 함
+    pub course_id: Option<i32>,
+    #[validate(range(min = 1, max = 100000))]
+    pub seat_count: i32,
+    /// 생략 시 지금부터
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub valid_from: Option<DateTime<Utc>>,
+    #[schema(value_type = String, format = "date-time")]
+    pub valid_until: DateTime<Utc>,
+    /// 연결할 교재 일괄 주문 (선택)
+    pub textbook_order_id: Option<i64>,
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// 라이선스 변경 — 좌석 수 / 종료일 / 메모 (지정한 필드만)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AdminUpdateLicenseReq {
+    /// 점유 중인 좌석(수락 + 대기 초대) 수보다 작게 줄일 수 없음
+    #[validate(range(min = 1, max = 100000))]
+    pub seat_count: Option<i32>,
+    /// 변경 시 좌석으로 부여된 수강권 만료일도 함께 맞춰짐
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub valid_until: Option<DateTime<Utc>>,
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+// =============================================================================
+// SSO
+// =============================================================================
+
+/// 기관 SSO 설정 등록/교체 (SSO 전용 강제 여부는 기관 관리자가 별도로 설정)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AdminUpsertOrgSsoReq {
+    pub protocol: OrgSsoProtocol,
+    /// 생략 시 true
+    pub enabled: Option<bool>,
+    /// 처음 로그인한 IdP 사용자 자동 가입 (생략 시 true)
+    pub jit_enabled: Option<bool>,
+    /// 로그인 discovery 에 쓰는 이메일 도메인 (예: "snu.ac.kr"). 기존 목록을 교체
+    #[validate(length(min = 1, max = 20))]
+    pub domains: Vec<String>,
+    /// protocol=oidc 일 때 필수
+    #[validate(url, length(max = 2000))]
+    pub oidc_issuer: Option<String>,
+    /// protocol=oidc 일 때 필수
+    #[validate(length(min = 1, max = 255))]
+    pub oidc_client_id: Option<String>,
+    /// 생략 시 기존 값 유지 (public client 면 비워 둠)
+    #[validate(length(min = 1, max = 1000))]
+    pub oidc_client_secret: Option<String>,
+    /// protocol=saml 일 때 필수 — IdP 메타데이터 XML 원문
+    #[validate(length(min = 1, max = 1000000))]
+    pub saml_metadata_xml: Option<String>,
+    /// users 필드 → IdP 속성/클레임 이름 (email, name, nickname, birthday, gender, language, country)
+    #[serde(default)]
+    pub attribute_map: BTreeMap<String, String>,
+}
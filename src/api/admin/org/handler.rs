@@ -0,0 +1,348 @@
+use crate::extract::AppJson;
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use validator::Validate;
+
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::api::org::dto::{OrgDetailRes, OrgLicenseRes, OrgSsoRes};
+use crate::{
+    api::auth::extractor::AuthUser,
+    error::{AppError, AppResult},
+    state::AppState,
+};
+
+use super::{
+    dto::{
+        AdminAddOrgAdminReq, AdminCreateLicenseReq, AdminCreateOrgReq, AdminOrgListReq,
+        AdminOrgListRes, AdminUpdateLicenseReq, AdminUpdateOrgReq, AdminUpsertOrgSsoReq,
+    },
+    service::AdminOrgService,
+};
+
+// =============================================================================
+// 기관
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/admin/orgs",
+    tag = "admin_org",
+    params(AdminOrgListReq),
+    responses(
+        (status = 200, description = "Organization list with current seat totals", body = AdminOrgListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn list_orgs(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(params): Query<AdminOrgListReq>,
+) -> AppResult<Json<AdminOrgListRes>> {
+    let res = AdminOrgService::list_orgs(&st, auth_user.sub, params).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/orgs",
+    tag = "admin_org",
+    request_body = AdminCreateOrgReq,
+    responses(
+        (status = 201, description = "Organization created", body = OrgDetailRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn create_org(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    AppJson(req): AppJson<AdminCreateOrgReq>,
+) -> AppResult<(StatusCode, Json<OrgDetailRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    let res = AdminOrgService::create_org(&st, auth_user.sub, req, ip, ua).await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/orgs/{org_id}",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    responses(
+        (status = 200, description = "Organization with licenses and admins", body = OrgDetailRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn get_org(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(org_id): Path<i64>,
+) -> AppResult<Json<OrgDetailRes>> {
+    let res = AdminOrgService::get_org(&st, auth_user.sub, org_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/admin/orgs/{org_id}",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    request_body = AdminUpdateOrgReq,
+    responses(
+        (status = 200, description = "Organization updated", body = OrgDetailRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn update_org(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(org_id): Path<i64>,
+    AppJson(req): AppJson<AdminUpdateOrgReq>,
+) -> AppResult<Json<OrgDetailRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    let res = AdminOrgService::update_org(&st, auth_user.sub, org_id, req, ip, ua).await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// 기관 관리자
+// =============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/admin/orgs/{org_id}/admins",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    request_body = AdminAddOrgAdminReq,
+    responses(
+        (status = 201, description = "Org admin added", body = OrgDetailRes),
+        (status = 400, description = "User not found", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Already an org admin", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn add_admin(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(org_id): Path<i64>,
+    AppJson(req): AppJson<AdminAddOrgAdminReq>,
+) -> AppResult<(StatusCode, Json<OrgDetailRes>)> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    let res = AdminOrgService::add_admin(&st, auth_user.sub, org_id, req, ip, ua).await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/orgs/{org_id}/admins/{user_id}",
+    tag = "admin_org",
+    params(
+        ("org_id" = i64, Path, description = "Organization ID"),
+        ("user_id" = i64, Path, description = "Org admin user ID")
+    ),
+    responses(
+        (status = 204, description = "Org admin removed"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Not an org admin", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn remove_admin(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((org_id, user_id)): Path<(i64, i64)>,
+) -> AppResult<StatusCode> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    AdminOrgService::remove_admin(&st, auth_user.sub, org_id, user_id, ip, ua).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// =============================================================================
+// 라이선스
+// =============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/admin/orgs/{org_id}/licenses",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    request_body = AdminCreateLicenseReq,
+    responses(
+        (status = 201, description = "Seat license issued", body = OrgLicenseRes),
+        (status = 400, description = "Invalid scope, window, course or textbook order", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn create_license(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(org_id): Path<i64>,
+    AppJson(req): AppJson<AdminCreateLicenseReq>,
+) -> AppResult<(StatusCode, Json<OrgLicenseRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    let res = AdminOrgService::create_license(&st, auth_user.sub, org_id, req, ip, ua).await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/admin/orgs/{org_id}/licenses/{license_id}",
+    tag = "admin_org",
+    params(
+        ("org_id" = i64, Path, description = "Organization ID"),
+        ("license_id" = i64, Path, description = "License ID")
+    ),
+    request_body = AdminUpdateLicenseReq,
+    responses(
+        (status = 200, description = "License updated", body = OrgLicenseRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "License not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Seat count below seats in use", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn update_license(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((org_id, license_id)): Path<(i64, i64)>,
+    AppJson(req): AppJson<AdminUpdateLicenseReq>,
+) -> AppResult<Json<OrgLicenseRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    let res = AdminOrgService::update_license(&st, auth_user.sub, org_id, license_id, req, ip, ua)
+        .await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// SSO
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/admin/orgs/{org_id}/sso",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    responses(
+        (status = 200, description = "Organization SSO configuration (secrets omitted)", body = OrgSsoRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "SSO configuration not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn get_sso(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(org_id): Path<i64>,
+) -> AppResult<Json<OrgSsoRes>> {
+    let res = AdminOrgService::get_sso(&st, auth_user.sub, org_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    put,
+    path = "/admin/orgs/{org_id}/sso",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    request_body = AdminUpsertOrgSsoReq,
+    responses(
+        (status = 200, description = "SSO configuration saved", body = OrgSsoRes),
+        (status = 400, description = "Invalid domain, attribute map, issuer or metadata", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Domain already used by another organization", body = crate::error::ErrorBody),
+        (status = 502, description = "OIDC issuer discovery failed", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn upsert_sso(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(org_id): Path<i64>,
+    AppJson(req): AppJson<AdminUpsertOrgSsoReq>,
+) -> AppResult<Json<OrgSsoRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    let res = AdminOrgService::upsert_sso(&st, auth_user.sub, org_id, req, ip, ua).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/orgs/{org_id}/sso",
+    tag = "admin_org",
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    responses(
+        (status = 204, description = "SSO configuration and domains removed"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "SSO configuration not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn delete_sso(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(org_id): Path<i64>,
+) -> AppResult<StatusCode> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+
+    AdminOrgService::delete_sso(&st, auth_user.sub, org_id, ip, ua).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
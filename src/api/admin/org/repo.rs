@@ -0,0 +1,284 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+
+use super::dto::AdminOrgSummary;
+use crate::error::AppResult;
+use crate::types::{OrgLicenseScope, OrgSsoProtocol};
+
+/// 기관 목록 (기관명 검색) — 좌석 합계는 현재 유효기간 안의 라이선스 기준
+pub async fn list_orgs(
+    pool: &PgPool,
+    page: i64,
+    size: i64,
+    q: Option<&str>,
+) -> AppResult<(i64, Vec<AdminOrgSummary>)> {
+    let total = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM organization WHERE ($1::text IS NULL OR org_name ILIKE '%' || $1 || '%')",
+    )
+    .bind(q)
+    .fetch_one(pool)
+    .await?;
+
+    let items = sqlx::query_as::<_, AdminOrgSummary>(
+        r#"
+        SELECT
+            o.org_id,
+            o.org_name,
+            (SELECT COUNT(*) FROM org_license l WHERE l.org_id = o.org_id) AS license_count,
+            (SELECT COALESCE(SUM(l.license_seat_count), 0)::bigint FROM org_license l
+              WHERE l.org_id = o.org_id
+                AND l.license_valid_from <= NOW() AND l.license_valid_until > NOW()) AS current_seat_count,
+            (SELECT COUNT(*) FROM org_seat s
+              JOIN org_license l ON l.license_id = s.license_id
+              WHERE l.org_id = o.org_id AND s.seat_state = 'active'
+                AND l.license_valid_from <= NOW() AND l.license_valid_until > NOW()) AS current_seats_active,
+            (SELECT COUNT(*) FROM org_admin a WHERE a.org_id = o.org_id) AS admin_count,
+            o.org_created_at
+        FROM organization o
+        WHERE ($1::text IS NULL OR o.org_name ILIKE '%' || $1 || '%')
+        ORDER BY o.org_created_at DESC, o.org_id DESC
+        LIMIT $2 OFFSET $3
+        "#,
+    )
+    .bind(q)
+    .bind(size)
+    .bind((page - 1) * size)
+    .fetch_all(pool)
+    .await?;
+
+    Ok((total, items))
+}
+
+pub async fn insert_org(pool: &PgPool, name: &str, created_by_user_id: i64) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO organization (org_name, org_created_by_user_id)
+        VALUES ($1, $2)
+        RETURNING org_id
+        "#,
+    )
+    .bind(name)
+    .bind(created_by_user_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+pub async fn update_org_name(pool: &PgPool, org_id: i64, name: &str) -> AppResult<bool> {
+    let res = sqlx::query(
+        "UPDATE organization SET org_name = $2, org_updated_at = NOW() WHERE org_id = $1",
+    )
+    .bind(org_id)
+    .bind(name)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// 기관 관리자 추가 — 이미 관리자면 false
+pub async fn insert_org_admin(
+    pool: &PgPool,
+    org_id: i64,
+    user_id: i64,
+    added_by_user_id: i64,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        INSERT INTO org_admin (org_id, user_id, org_admin_added_by_user_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (org_id, user_id) DO NOTHING
+        "#,
+    )
+    .bind(org_id)
+    .bind(user_id)
+    .bind(added_by_user_id)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn delete_org_admin(pool: &PgPool, org_id: i64, user_id: i64) -> AppResult<bool> {
+    let res = sqlx::query("DELETE FROM org_admin WHERE org_id = $1 AND user_id = $2")
+        .bind(org_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn course_exists(pool: &PgPool, course_id: i32) -> AppResult<bool> {
+    let exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM course WHERE course_id = $1)")
+            .bind(course_id)
+            .fetch_one(pool)
+            .await?;
+    Ok(exists)
+}
+
+pub async fn textbook_order_exists(pool: &PgPool, order_id: i64) -> AppResult<bool> {
+    let exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM textbook WHERE order_id = $1)")
+            .bind(order_id)
+            .fetch_one(pool)
+            .await?;
+    Ok(exists)
+}
+
+pub struct NewLicense<'a> {
+    pub org_id: i64,
+    pub scope: OrgLicenseScope,
+    pub course_id: Option<i32>,
+    pub seat_count: i32,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+    pub textbook_order_id: Option<i64>,
+    pub note: Option<&'a str>,
+    pub created_by_user_id: i64,
+}
+
+pub async fn insert_license(pool: &PgPool, license: &NewLicense<'_>) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO org_license
+            (org_id, license_scope, course_id, license_seat_count,
+             license_valid_from, license_valid_until, textbook_order_id,
+             license_note, license_created_by_user_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING license_id
+        "#,
+    )
+    .bind(license.org_id)
+    .bind(license.scope)
+    .bind(license.course_id)
+    .bind(license.seat_count)
+    .bind(license.valid_from)
+    .bind(license.valid_until)
+    .bind(license.textbook_order_id)
+    .bind(license.note)
+    .bind(license.created_by_user_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+/// 좌석 수 / 종료일 / 메모 변경 (None 필드는 유지)
+pub async fn update_license(
+    conn: &mut PgConnection,
+    license_id: i64,
+    seat_count: Option<i32>,
+    valid_until: Option<DateTime<Utc>>,
+    note: Option<&str>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE org_license
+        SET license_seat_count  = COALESCE($2, license_seat_count),
+            license_valid_until = COALESCE($3, license_valid_until),
+            license_note        = COALESCE($4, license_note),
+            license_updated_at  = NOW()
+        WHERE license_id = $1
+        "#,
+    )
+    .bind(license_id)
+    .bind(seat_count)
+    .bind(valid_until)
+    .bind(note)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+// =============================================================================
+// SSO
+// =============================================================================
+
+pub struct NewOrgSso<'a> {
+    pub org_id: i64,
+    pub protocol: OrgSsoProtocol,
+    pub enabled: bool,
+    pub jit_enabled: bool,
+    pub oidc_issuer: Option<&'a str>,
+    pub oidc_client_id: Option<&'a str>,
+    /// 암호문
+    pub oidc_client_secret: Option<&'a str>,
+    pub saml_metadata_xml: Option<&'a str>,
+    pub saml_idp_entity_id: Option<&'a str>,
+    pub attribute_map: &'a serde_json::Value,
+    pub updated_by_user_id: i64,
+}
+
+/// SSO 설정 등록/교체 (sso_enforced 는 기관 관리자 설정이라 유지)
+pub async fn upsert_sso(conn: &mut PgConnection, sso: &NewOrgSso<'_>) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO org_sso
+            (org_id, sso_protocol, sso_enabled, sso_jit_enabled,
+             oidc_issuer, oidc_client_id, oidc_client_secret,
+             saml_metadata_xml, saml_idp_entity_id, sso_attribute_map, sso_updated_by_user_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        ON CONFLICT (org_id) DO UPDATE SET
+            sso_protocol           = EXCLUDED.sso_protocol,
+            sso_enabled            = EXCLUDED.sso_enabled,
+            sso_jit_enabled        = EXCLUDED.sso_jit_enabled,
+            oidc_issuer            = EXCLUDED.oidc_issuer,
+            oidc_client_id         = EXCLUDED.oidc_client_id,
+            oidc_client_secret     = EXCLUDED.oidc_client_secret,
+            saml_metadata_xml      = EXCLUDED.saml_metadata_xml,
+            saml_idp_entity_id     = EXCLUDED.saml_idp_entity_id,
+            sso_attribute_map      = EXCLUDED.sso_attribute_map,
+            sso_updated_by_user_id = EXCLUDED.sso_updated_by_user_id,
+            sso_updated_at         = NOW()
+        "#,
+    )
+    .bind(sso.org_id)
+    .bind(sso.protocol)
+    .bind(sso.enabled)
+    .bind(sso.jit_enabled)
+    .bind(sso.oidc_issuer)
+    .bind(sso.oidc_client_id)
+    .bind(sso.oidc_client_secret)
+    .bind(sso.saml_metadata_xml)
+    .bind(sso.saml_idp_entity_id)
+    .bind(sso.attribute_map)
+    .bind(sso.updated_by_user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// 기관 SSO 도메인 교체. 다른 기관이 쓰는 도메인이 있으면 그 도메인을 반환 (호출자가 롤백)
+pub async fn replace_sso_domains(
+    conn: &mut PgConnection,
+    org_id: i64,
+    domains: &[String],
+) -> AppResult<Option<String>> {
+    sqlx::query("DELETE FROM org_sso_domain WHERE org_id = $1")
+        .bind(org_id)
+        .execute(&mut *conn)
+        .await?;
+    for domain in domains {
+        let res = sqlx::query(
+            r#"
+            INSERT INTO org_sso_domain (sso_domain, org_id)
+            VALUES ($1, $2)
+            ON CONFLICT (sso_domain) DO NOTHING
+            "#,
+        )
+        .bind(domain)
+        .bind(org_id)
+        .execute(&mut *conn)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Ok(Some(domain.clone()));
+        }
+    }
+    Ok(None)
+}
+
+pub async fn delete_sso(pool: &PgPool, org_id: i64) -> AppResult<bool> {
+    let res = sqlx::query("DELETE FROM org_sso WHERE org_id = $1")
+        .bind(org_id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected() > 0)
+}
@@ -0,0 +1,30 @@
+use axum::{
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn admin_org_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(handler::list_orgs).post(handler::create_org))
+        .route(
+            "/{org_id}",
+            get(handler::get_org).patch(handler::update_org),
+        )
+        .route("/{org_id}/admins", post(handler::add_admin))
+        .route("/{org_id}/admins/{user_id}", delete(handler::remove_admin))
+        .route("/{org_id}/licenses", post(handler::create_license))
+        .route(
+            "/{org_id}/licenses/{license_id}",
+            patch(handler::update_license),
+        )
+        .route(
+            "/{org_id}/sso",
+            get(handler::get_sso)
+                .put(handler::upsert_sso)
+                .delete(handler::delete_sso),
+        )
+}
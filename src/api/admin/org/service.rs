@@ -0,0 +1,628 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+
+use super::dto::{
+    AdminAddOrgAdminReq, AdminCreateLicenseReq, AdminCreateOrgReq, AdminOrgListReq,
+    AdminOrgListRes, AdminOrgMeta, AdminUpdateLicenseReq, AdminUpdateOrgReq, AdminUpsertOrgSsoReq,
+};
+use super::repo::{self, NewLicense, NewOrgSso};
+use crate::api::auth::sso::{normalize_sso_domain, validate_attribute_map};
+use crate::api::org::dto::{OrgDetailRes, OrgLicenseRes, OrgSsoRes};
+use crate::api::org::repo as org_repo;
+use crate::api::org::service::load_sso;
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::external::{oidc, saml::IdpMetadata};
+use crate::state::AppState;
+use crate::types::{OrgLicenseScope, OrgSsoProtocol, UserAuth};
+
+const SSO_CLIENT_SECRET_AAD: &str = "org_sso.oidc_client_secret";
+
+/// 라이선스 범위와 코스 지정 조합 검증
+fn validate_license_scope(scope: OrgLicenseScope, course_id: Option<i32>) -> AppResult<()> {
+    match (scope, course_id) {
+        (OrgLicenseScope::Course, None) => Err(AppError::BadRequest(
+            "course_id is required for course licenses".into(),
+        )),
+        (OrgLicenseScope::Subscription, Some(_)) => Err(AppError::BadRequest(
+            "course_id must be empty for subscription licenses".into(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// 유효기간 검증 = 시작 < 종료 + 종료가 미래
+fn validate_license_window(
+    now: DateTime<Utc>,
+    valid_from: DateTime<Utc>,
+    valid_until: DateTime<Utc>,
+) -> AppResult<()> {
+    if valid_until <= valid_from {
+        return Err(AppError::BadRequest(
+            "valid_until must be later than valid_from".into(),
+        ));
+    }
+    if valid_until <= now {
+        return Err(AppError::BadRequest(
+            "valid_until must be in the future".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// SSO 도메인 정규화 + 중복 제거 (입력 순서 유지)
+fn normalize_sso_domains(raw: &[String]) -> AppResult<Vec<String>> {
+    let mut domains: Vec<String> = Vec::with_capacity(raw.len());
+    for domain in raw {
+        let domain = normalize_sso_domain(domain)?;
+        if !domains.contains(&domain) {
+            domains.push(domain);
+        }
+    }
+    Ok(domains)
+}
+
+pub struct AdminOrgService;
+
+impl AdminOrgService {
+    // =========================================================================
+    // RBAC 검증 — 좌석 라이선스는 판매 계약이므로 HYMN/admin 만
+    // =========================================================================
+
+    async fn check_admin_rbac(pool: &sqlx::PgPool, actor_user_id: i64) -> AppResult<UserAuth> {
+        let actor = crate::api::user::repo::find_user(pool, actor_user_id)
+            .await?
+            .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+
+        match actor.user_auth {
+            UserAuth::Hymn | UserAuth::Admin => Ok(actor.user_auth),
+            _ => Err(AppError::Forbidden("Forbidden".to_string())),
+        }
+    }
+
+    // =========================================================================
+    // 감사 로그 헬퍼
+    // =========================================================================
+
+    #[allow(clippy::too_many_arguments)]
+    async fn audit_log(
+        st: &AppState,
+        actor_id: i64,
+        action_type: &str,
+        target_table: &str,
+        target_id: Option<i64>,
+        details: &serde_json::Value,
+        ip: Option<IpAddr>,
+        ua: Option<&str>,
+    ) -> AppResult<()> {
+        crate::api::admin::user::repo::write_audit_log(
+            st,
+            actor_id,
+            action_type,
+            target_table,
+            target_id,
+            details,
+            ip,
+            ua,
+        )
+        .await
+    }
+
+    async fn detail(st: &AppState, org_id: i64) -> AppResult<OrgDetailRes> {
+        let org = org_repo::find_org(&st.db, org_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        Ok(OrgDetailRes {
+            licenses: org_repo::find_licenses(&st.db, org_id).await?,
+            admins: org_repo::find_admins(&st.db, org_id).await?,
+            org,
+        })
+    }
+
+    // =========================================================================
+    // 기관
+    // =========================================================================
+
+    pub async fn list_orgs(
+        st: &AppState,
+        actor_user_id: i64,
+        req: AdminOrgListReq,
+    ) -> AppResult<AdminOrgListRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        let page = req.page.unwrap_or(1).max(1);
+        let size = req.size.unwrap_or(20).clamp(1, 100);
+        let q = req.q.as_deref().map(str::trim).filter(|s| !s.is_empty());
+
+        let (total_count, items) = repo::list_orgs(&st.db, page, size, q).await?;
+        let total_pages = if total_count == 0 {
+            0
+        } else {
+            (total_count + size - 1) / size
+        };
+
+        Ok(AdminOrgListRes {
+            items,
+            meta: AdminOrgMeta {
+                total_count,
+                total_pages,
+                current_page: page,
+                per_page: size,
+            },
+        })
+    }
+
+    pub async fn create_org(
+        st: &AppState,
+        actor_user_id: i64,
+        req: AdminCreateOrgReq,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<OrgDetailRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        let name = req.org_name.trim();
+        if name.is_empty() {
+            return Err(AppError::BadRequest("org_name is required".into()));
+        }
+        let org_id = repo::insert_org(&st.db, name, actor_user_id).await?;
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "CREATE_ORG",
+            "organization",
+            Some(org_id),
+            &serde_json::json!({ "org_name": name }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+
+        Self::detail(st, org_id).await
+    }
+
+    pub async fn get_org(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+    ) -> AppResult<OrgDetailRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+        Self::detail(st, org_id).await
+    }
+
+    pub async fn update_org(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        req: AdminUpdateOrgReq,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<OrgDetailRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        let name = req.org_name.trim();
+        if name.is_empty() {
+            return Err(AppError::BadRequest("org_name must not be empty".into()));
+        }
+        let before = org_repo::find_org(&st.db, org_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        repo::update_org_name(&st.db, org_id, name).await?;
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "UPDATE_ORG",
+            "organization",
+            Some(org_id),
+            &serde_json::json!({
+                "before": { "org_name": before.org_name },
+                "org_name": name,
+            }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+
+        Self::detail(st, org_id).await
+    }
+
+    // =========================================================================
+    // 기관 관리자
+    // =========================================================================
+
+    pub async fn add_admin(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        req: AdminAddOrgAdminReq,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<OrgDetailRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        org_repo::find_org(&st.db, org_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        crate::api::user::repo::find_user(&st.db, req.user_id)
+            .await?
+            .ok_or_else(|| AppError::BadRequest("User not found".into()))?;
+        if !repo::insert_org_admin(&st.db, org_id, req.user_id, actor_user_id).await? {
+            return Err(AppError::Conflict("ORG_409_ALREADY_ADMIN".into()));
+        }
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "ADD_ORG_ADMIN",
+            "org_admin",
+            Some(org_id),
+            &serde_json::json!({ "org_id": org_id, "user_id": req.user_id }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+
+        Self::detail(st, org_id).await
+    }
+
+    pub async fn remove_admin(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        user_id: i64,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<()> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        if !repo::delete_org_admin(&st.db, org_id, user_id).await? {
+            return Err(AppError::NotFound);
+        }
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "REMOVE_ORG_ADMIN",
+            "org_admin",
+            Some(org_id),
+            &serde_json::json!({ "org_id": org_id, "user_id": user_id }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // 라이선스
+    // =========================================================================
+
+    pub async fn create_license(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        req: AdminCreateLicenseReq,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<OrgLicenseRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        org_repo::find_org(&st.db, org_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        validate_license_scope(req.license_scope, req.course_id)?;
+        let now = Utc::now();
+        let valid_from = req.valid_from.unwrap_or(now);
+        validate_license_window(now, valid_from, req.valid_until)?;
+        if let Some(course_id) = req.course_id {
+            if !repo::course_exists(&st.db, course_id).await? {
+                return Err(AppError::BadRequest("Course not found".into()));
+            }
+        }
+        if let Some(order_id) = req.textbook_order_id {
+            if !repo::textbook_order_exists(&st.db, order_id).await? {
+                return Err(AppError::BadRequest("Textbook order not found".into()));
+            }
+        }
+
+        let license_id = repo::insert_license(
+            &st.db,
+            &NewLicense {
+                org_id,
+                scope: req.license_scope,
+                course_id: req.course_id,
+                seat_count: req.seat_count,
+                valid_from,
+                valid_until: req.valid_until,
+                textbook_order_id: req.textbook_order_id,
+                note: req.note.as_deref(),
+                created_by_user_id: actor_user_id,
+            },
+        )
+        .await?;
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "CREATE_ORG_LICENSE",
+            "org_license",
+            Some(license_id),
+            &serde_json::json!({
+                "org_id": org_id,
+                "license_scope": req.license_scope,
+                "course_id": req.course_id,
+                "seat_count": req.seat_count,
+                "valid_from": valid_from.to_rfc3339(),
+                "valid_until": req.valid_until.to_rfc3339(),
+                "textbook_order_id": req.textbook_order_id,
+            }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+
+        org_repo::find_license(&st.db, org_id, license_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 좌석 수 / 종료일 / 메모 변경 — 종료일 변경은 좌석 수강권 만료일에도 반영
+    pub async fn update_license(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        license_id: i64,
+        req: AdminUpdateLicenseReq,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<OrgLicenseRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        if req.seat_count.is_none() && req.valid_until.is_none() && req.note.is_none() {
+            return Err(AppError::BadRequest("No fields to update".into()));
+        }
+
+        let mut tx = st.db.begin().await?;
+        let before = org_repo::lock_license(&mut tx, org_id, license_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if let Some(valid_until) = req.valid_until {
+            validate_license_window(Utc::now(), before.license_valid_from, valid_until)?;
+        }
+        if let Some(seat_count) = req.seat_count {
+            let occupied = org_repo::count_occupied_seats(&mut tx, license_id).await?;
+            if i64::from(seat_count) < occupied {
+                return Err(AppError::Conflict("ORG_409_SEATS_IN_USE".into()));
+            }
+        }
+        repo::update_license(
+            &mut tx,
+            license_id,
+            req.seat_count,
+            req.valid_until,
+            req.note.as_deref(),
+        )
+        .await?;
+        tx.commit().await?;
+
+        let courses_synced = match req.valid_until {
+            Some(valid_until) if valid_until != before.license_valid_until => {
+                org_repo::sync_seat_course_expiry(&st.db, license_id, valid_until).await?
+            }
+            _ => 0,
+        };
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "UPDATE_ORG_LICENSE",
+            "org_license",
+            Some(license_id),
+            &serde_json::json!({
+                "org_id": org_id,
+                "before": {
+                    "seat_count": before.license_seat_count,
+                    "valid_until": before.license_valid_until.to_rfc3339(),
+                },
+                "seat_count": req.seat_count,
+                "valid_until": req.valid_until.map(|t| t.to_rfc3339()),
+                "note_changed": req.note.is_some(),
+                "courses_synced": courses_synced,
+            }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+
+        org_repo::find_license(&st.db, org_id, license_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    // =========================================================================
+    // SSO
+    // =========================================================================
+
+    pub async fn get_sso(st: &AppState, actor_user_id: i64, org_id: i64) -> AppResult<OrgSsoRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+        load_sso(st, org_id).await
+    }
+
+    /// SSO 설정 등록/교체 — OIDC 는 issuer discovery 로, SAML 은 메타데이터 파싱으로 미리 검증
+    pub async fn upsert_sso(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        req: AdminUpsertOrgSsoReq,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<OrgSsoRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        org_repo::find_org(&st.db, org_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let domains = normalize_sso_domains(&req.domains)?;
+        validate_attribute_map(&req.attribute_map)?;
+        let existing = org_repo::find_sso(&st.db, org_id).await?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let (oidc_issuer, oidc_client_id, oidc_client_secret, saml_idp_entity_id) =
+            match req.protocol {
+                OrgSsoProtocol::Oidc => {
+                    let (Some(issuer), Some(client_id)) =
+                        (req.oidc_issuer.as_deref(), req.oidc_client_id.as_deref())
+                    else {
+                        return Err(AppError::BadRequest(
+                            "oidc_issuer and oidc_client_id are required for OIDC".into(),
+                        ));
+                    };
+                    let issuer = issuer.trim_end_matches('/');
+                    oidc::discover(issuer).await?;
+                    let secret = match req.oidc_client_secret.as_deref() {
+                        Some(secret) => Some(crypto.encrypt(secret, SSO_CLIENT_SECRET_AAD)?),
+                        None => existing
+                            .as_ref()
+                            .filter(|e| e.sso_protocol == OrgSsoProtocol::Oidc)
+                            .and_then(|e| e.oidc_client_secret.clone()),
+                    };
+                    (Some(issuer), Some(client_id), secret, None)
+                }
+                OrgSsoProtocol::Saml => {
+                    let xml = req.saml_metadata_xml.as_deref().ok_or_else(|| {
+                        AppError::BadRequest("saml_metadata_xml is required for SAML".into())
+                    })?;
+                    let metadata = IdpMetadata::parse(xml)?;
+                    (None, None, None, Some(metadata.entity_id))
+                }
+            };
+        let saml_metadata_xml = match req.protocol {
+            OrgSsoProtocol::Saml => req.saml_metadata_xml.as_deref(),
+            OrgSsoProtocol::Oidc => None,
+        };
+        let attribute_map = serde_json::to_value(&req.attribute_map)
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        let mut tx = st.db.begin().await?;
+        repo::upsert_sso(
+            &mut tx,
+            &NewOrgSso {
+                org_id,
+                protocol: req.protocol,
+                enabled: req.enabled.unwrap_or(true),
+                jit_enabled: req.jit_enabled.unwrap_or(true),
+                oidc_issuer,
+                oidc_client_id,
+                oidc_client_secret: oidc_client_secret.as_deref(),
+                saml_metadata_xml,
+                saml_idp_entity_id: saml_idp_entity_id.as_deref(),
+                attribute_map: &attribute_map,
+                updated_by_user_id: actor_user_id,
+            },
+        )
+        .await?;
+        if let Some(taken) = repo::replace_sso_domains(&mut tx, org_id, &domains).await? {
+            return Err(AppError::Conflict(format!(
+                "ORG_409_SSO_DOMAIN_TAKEN: {}",
+                taken
+            )));
+        }
+        tx.commit().await?;
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "UPSERT_ORG_SSO",
+            "org_sso",
+            Some(org_id),
+            &serde_json::json!({
+                "org_id": org_id,
+                "protocol": req.protocol,
+                "enabled": req.enabled.unwrap_or(true),
+                "jit_enabled": req.jit_enabled.unwrap_or(true),
+                "domains": domains,
+                "oidc_issuer": oidc_issuer,
+                "saml_idp_entity_id": saml_idp_entity_id,
+                "client_secret_changed": req.oidc_client_secret.is_some(),
+                "created": existing.is_none(),
+            }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+
+        load_sso(st, org_id).await
+    }
+
+    pub async fn delete_sso(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        ip: Option<IpAddr>,
+        ua: Option<String>,
+    ) -> AppResult<()> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        if !repo::delete_sso(&st.db, org_id).await? {
+            return Err(AppError::NotFound);
+        }
+
+        Self::audit_log(
+            st,
+            actor_user_id,
+            "DELETE_ORG_SSO",
+            "org_sso",
+            Some(org_id),
+            &serde_json::json!({ "org_id": org_id }),
+            ip,
+            ua.as_deref(),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn license_scope_requires_matching_course() {
+        assert!(validate_license_scope(OrgLicenseScope::Course, Some(3)).is_ok());
+        assert!(validate_license_scope(OrgLicenseScope::Subscription, None).is_ok());
+        assert!(validate_license_scope(OrgLicenseScope::Course, None).is_err());
+        assert!(validate_license_scope(OrgLicenseScope::Subscription, Some(3)).is_err());
+    }
+
+    #[test]
+    fn license_window_must_be_ordered_and_end_in_future() {
+        let now = Utc::now();
+        assert!(validate_license_window(now, now, now + Duration::days(365)).is_ok());
+        // 미래 시작도 허용 (학기 시작 전 발급)
+        assert!(
+            validate_license_window(now, now + Duration::days(10), now + Duration::days(100))
+                .is_ok()
+        );
+        assert!(validate_license_window(now, now, now).is_err());
+        assert!(
+            validate_license_window(now, now - Duration::days(30), now - Duration::days(1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn sso_domains_are_normalized_and_deduplicated() {
+        let domains = normalize_sso_domains(&[
+            "SNU.ac.kr".to_string(),
+            "@snu.ac.kr".to_string(),
+            "mail.snu.ac.kr".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(domains, vec!["snu.ac.kr", "mail.snu.ac.kr"]);
+        assert!(normalize_sso_domains(&["gmail.com".to_string()]).is_err());
+    }
+}
@@ -268,7 +268,7 @@ impl AdminPaymentRepo {
     // 수동 수강권
     // =========================================================================
 
-    /// 수동 부여 수강권 목록 (활성 코스가 있지만 활성 구독이 없는 사용자)
+    /// 수동 부여 수강권 목록 (활성 코스가 있지만 활성 구독이 없는 사용자, 기관 좌석 수강권 제외)
     pub async fn list_manual_grants(
         pool: &PgPool,
         page: i64,
@@ -281,6 +281,7 @@ impl AdminPaymentRepo {
             LEFT JOIN payment_subscription s ON uc.user_id = s.user_id
                 AND s.status IN ('trialing', 'active', 'past_due')
             WHERE uc.user_course_active = true
+            AND uc.user_course_org_seat_id IS NULL
             AND s.subscription_id IS NULL
             "#,
         )
@@ -297,6 +298,7 @@ impl AdminPaymentRepo {
             LEFT JOIN payment_subscription s ON uc.user_id = s.user_id
                 AND s.status IN ('trialing', 'active', 'past_due')
             WHERE uc.user_course_active = true
+            AND uc.user_course_org_seat_id IS NULL
             AND s.subscription_id IS NULL
             GROUP BY uc.user_id, u.user_email
             ORDER BY uc.user_id DESC
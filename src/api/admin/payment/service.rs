@@ -473,11 +473,14 @@ impl AdminPaymentService {
 
         let revoked =
             crate::api::payment::repo::PaymentRepo::revoke_all_courses(&st.db, user_id).await?;
+        let seat_restored =
+            crate::api::org::service::restore_seat_entitlements(st, user_id).await?;
 
         tracing::info!(
             admin_id = actor_user_id,
             user_id = user_id,
             courses_revoked = revoked,
+            seat_courses_restored = seat_restored,
             "Admin revoked courses manually"
         );
 
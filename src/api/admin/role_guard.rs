@@ -2,7 +2,7 @@
 //!
 //! Admin 라우트에 대한 역할 기반 접근 제어 미들웨어
 //! - HYMN, admin만 접근 허용
-//! - manager, learner는 403 Forbidden
+//! - manager, learner는 403 Forbidden (manager 는 `/classes` 사용)
 
 use axum::{
     body::Body,
@@ -25,7 +25,7 @@ use crate::types::UserAuth;
 /// - admin: Admin 영역 전체 접근
 ///
 /// # 차단 역할
-/// - manager: 403 Forbidden (차후 class 기능 구현 시 별도 영역 제공)
+/// - manager: 403 Forbidden (클래스 관리는 `/classes` 영역에서 자기 클래스 범위로만)
 /// - learner: 403 Forbidden
 ///
 /// # 사용법
@@ -49,7 +49,7 @@ pub async fn admin_role_guard(
     };
 
     // JWT 디코딩 및 검증
-    let claims = match jwt::decode_token(&token, &state.cfg.jwt_secret) {
+    let claims = match jwt::decode_token(&token, &state.cfg.jwt_keyring) {
         Ok(c) => c,
         Err(e) => {
             tracing::warn!("Admin role guard: JWT decode failed: {}", e);
@@ -72,10 +72,10 @@ pub async fn admin_role_guard(
             tracing::warn!(
                 user_id = claims.sub,
                 role = ?claims.role,
-                "Admin access denied: Manager role not allowed (class feature not implemented yet)"
+                "Admin access denied: Manager role not allowed (use /classes)"
             );
             AppError::Forbidden(
-                "Access denied: Manager role requires class-based access (coming soon)".into(),
+                "Access denied: Manager role is limited to class management (/classes)".into(),
             )
             .into_response()
         }
@@ -2,11 +2,15 @@ use axum::Router;
 
 use crate::state::AppState;
 
+use super::course::router::admin_course_router;
 use super::ebook::router::admin_ebook_router;
 use super::email::router::admin_email_router;
 use super::guide::router::admin_guide_router;
 use super::lesson::router::admin_lesson_router;
+use super::org::router::admin_org_router;
 use super::payment::router::admin_payment_router;
+use super::schedule::router::admin_schedule_router;
+use super::security::router::admin_security_router;
 use super::study::router::admin_study_router;
 use super::textbook::router::admin_textbook_router;
 use super::translation::router::admin_translation_router;
@@ -26,15 +30,18 @@ pub fn admin_router() -> Router<AppState> {
         .nest("/users", admin_user_router())
         .nest("/logins/stats", admin_login_stats_router())
         .nest("/lessons", admin_lesson_router())
+        .nest("/courses", admin_course_router())
         .nest("/videos", admin_video_router())
         .nest("/studies", admin_study_router())
         .nest("/guides", admin_guide_router())
+        .nest("/schedules", admin_schedule_router())
         .nest("/email", admin_email_router())
         .nest("/translations", admin_translation_router())
         .nest("/upgrade", admin_upgrade_router())
         .nest("/payment", admin_payment_router())
+        .nest("/orgs", admin_org_router())
+        .nest("/security", admin_security_router())
         .nest("/textbook", admin_textbook_router())
         .nest("/ebook", admin_ebook_router())
-    // .nest("/courses", admin_course_router()) // 차후 확장
     // .nest("/reports", admin_report_router())
 }
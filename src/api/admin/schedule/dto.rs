@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{AdminAction, PublishScheduleStatus, PublishTarget};
+
+// ==========================================
+// 요청
+// ==========================================
+
+/// 대상 콘텐츠의 예약 설정 — 기존 pending 예약은 모두 교체됨
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct PublishScheduleSetReq {
+    /// 공개 시각 (없으면 공개 예약 없음)
+    pub publish_at: Option<DateTime<Utc>>,
+    /// 비공개 시각 (없으면 비공개 예약 없음)
+    pub unpublish_at: Option<DateTime<Utc>>,
+    /// 번역 커버리지 정책 우회 (HYMN 전용, publish 실행 시 적용)
+    pub force_publish: Option<bool>,
+}
+
+/// 예약 캘린더 조회 — 기본: 지금부터 30일, pending 만
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PublishCalendarQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub target_type: Option<PublishTarget>,
+    pub status: Option<PublishScheduleStatus>,
+}
+
+// ==========================================
+// 응답
+// ==========================================
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct PublishScheduleRes {
+    pub schedule_id: i64,
+    pub target_type: PublishTarget,
+    pub target_id: i64,
+    /// 대상 콘텐츠 idx (삭제된 대상이면 null)
+    pub target_idx: Option<String>,
+    pub target_title: Option<String>,
+    pub schedule_action: AdminAction,
+    pub scheduled_at: DateTime<Utc>,
+    pub schedule_status: PublishScheduleStatus,
+    pub force_publish: bool,
+    pub created_by_user_id: i64,
+    pub schedule_created_at: DateTime<Utc>,
+    pub executed_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub cancelled_by_user_id: Option<i64>,
+    pub cancel_reason: Option<String>,
+    pub failure_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublishScheduleListRes {
+    pub target_type: PublishTarget,
+    pub target_id: i64,
+    pub items: Vec<PublishScheduleRes>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublishCalendarRes {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub items: Vec<PublishScheduleRes>,
+}
@@ -0,0 +1,138 @@
+use super::dto::{
+    PublishCalendarQuery, PublishCalendarRes, PublishScheduleListRes, PublishScheduleRes,
+    PublishScheduleSetReq,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::api::auth::extractor::AuthUser;
+use crate::error::AppResult;
+use crate::extract::AppJson;
+use crate::types::PublishTarget;
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    Json,
+};
+
+/// 예약 공개/비공개 캘린더
+#[utoipa::path(
+    get,
+    path = "/admin/schedules",
+    tag = "admin_schedule",
+    params(PublishCalendarQuery),
+    responses(
+        (status = 200, description = "Scheduled state changes in range", body = PublishCalendarRes),
+        (status = 400, description = "Invalid range", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_publish_calendar(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(query): Query<PublishCalendarQuery>,
+) -> AppResult<Json<PublishCalendarRes>> {
+    let res = super::service::calendar(&st, auth_user.sub, query).await?;
+    Ok(Json(res))
+}
+
+/// 콘텐츠 1건의 예약 이력
+#[utoipa::path(
+    get,
+    path = "/admin/schedules/{target_type}/{target_id}",
+    tag = "admin_schedule",
+    params(
+        ("target_type" = PublishTarget, Path, description = "video | study | lesson | guide | course"),
+        ("target_id" = i64, Path, description = "Target content ID")
+    ),
+    responses(
+        (status = 200, description = "Schedules for target", body = PublishScheduleListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Target not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_target_schedules(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path((target_type, target_id)): Path<(PublishTarget, i64)>,
+) -> AppResult<Json<PublishScheduleListRes>> {
+    let res = super::service::list_for_target(&st, auth_user.sub, target_type, target_id).await?;
+    Ok(Json(res))
+}
+
+/// 콘텐츠 예약 공개/비공개 설정 (기존 pending 예약 교체)
+#[utoipa::path(
+    put,
+    path = "/admin/schedules/{target_type}/{target_id}",
+    tag = "admin_schedule",
+    params(
+        ("target_type" = PublishTarget, Path, description = "video | study | lesson | guide | course"),
+        ("target_id" = i64, Path, description = "Target content ID")
+    ),
+    request_body = PublishScheduleSetReq,
+    responses(
+        (status = 200, description = "Schedules saved", body = PublishScheduleListRes),
+        (status = 400, description = "Invalid schedule times", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden (force_publish requires HYMN)", body = crate::error::ErrorBody),
+        (status = 404, description = "Target not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_set_target_schedule(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((target_type, target_id)): Path<(PublishTarget, i64)>,
+    AppJson(req): AppJson<PublishScheduleSetReq>,
+) -> AppResult<Json<PublishScheduleListRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res = super::service::set_schedule(
+        &st,
+        auth_user.sub,
+        target_type,
+        target_id,
+        req,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+/// 예약 취소
+#[utoipa::path(
+    delete,
+    path = "/admin/schedules/{schedule_id}",
+    tag = "admin_schedule",
+    params(
+        ("schedule_id" = i64, Path, description = "Schedule ID")
+    ),
+    responses(
+        (status = 200, description = "Schedule cancelled", body = PublishScheduleRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Schedule not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Schedule is not pending", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_cancel_schedule(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(schedule_id): Path<i64>,
+) -> AppResult<Json<PublishScheduleRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res =
+        super::service::cancel_schedule(&st, auth_user.sub, schedule_id, ip_address, user_agent)
+            .await?;
+    Ok(Json(res))
+}
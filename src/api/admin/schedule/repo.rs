@@ -0,0 +1,381 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use super::dto::PublishScheduleRes;
+use crate::error::AppResult;
+use crate::types::{AdminAction, PublishScheduleStatus, PublishTarget};
+
+/// 대상 콘텐츠 테이블/컬럼 (SQL 조립용 고정 문자열)
+pub struct TargetTable {
+    pub table: &'static str,
+    pub id_col: &'static str,
+    pub state_col: &'static str,
+    pub state_enum: &'static str,
+    pub updated_at_col: &'static str,
+}
+
+pub fn target_table(target: PublishTarget) -> TargetTable {
+    match target {
+        PublishTarget::Video => TargetTable {
+            table: "video",
+            id_col: "video_id",
+            state_col: "video_state",
+            state_enum: "video_state_enum",
+            updated_at_col: "video_updated_at",
+        },
+        PublishTarget::Study => TargetTable {
+            table: "study",
+            id_col: "study_id",
+            state_col: "study_state",
+            state_enum: "study_state_enum",
+            updated_at_col: "study_updated_at",
+        },
+        PublishTarget::Lesson => TargetTable {
+            table: "lesson",
+            id_col: "lesson_id",
+            state_col: "lesson_state",
+            state_enum: "lesson_state_enum",
+            updated_at_col: "lesson_updated_at",
+        },
+        PublishTarget::Guide => TargetTable {
+            table: "guide",
+            id_col: "guide_id",
+            state_col: "guide_state",
+            state_enum: "guide_state_enum",
+            updated_at_col: "guide_updated_at",
+        },
+        PublishTarget::Course => TargetTable {
+            table: "course",
+            id_col: "course_id",
+            state_col: "course_state",
+            state_enum: "course_state_enum",
+            updated_at_col: "course_updated_at",
+        },
+    }
+}
+
+const SCHEDULE_SELECT: &str = r#"
+    SELECT
+        s.schedule_id,
+        s.target_type,
+        s.target_id,
+        t.idx AS target_idx,
+        t.title AS target_title,
+        s.schedule_action,
+        s.scheduled_at,
+        s.schedule_status,
+        s.force_publish,
+        s.created_by_user_id,
+        s.schedule_created_at,
+        s.executed_at,
+        s.cancelled_at,
+        s.cancelled_by_user_id,
+        s.cancel_reason,
+        s.failure_reason
+    FROM content_publish_schedule s
+    LEFT JOIN LATERAL (
+        SELECT v.video_idx AS idx, v.video_title::text AS title
+        FROM video v WHERE s.target_type = 'video' AND v.video_id = s.target_id
+        UNION ALL
+        SELECT st.study_idx, st.study_title::text
+        FROM study st WHERE s.target_type = 'study' AND st.study_id = s.target_id
+        UNION ALL
+        SELECT l.lesson_idx, l.lesson_title::text
+        FROM lesson l WHERE s.target_type = 'lesson' AND l.lesson_id = s.target_id
+        UNION ALL
+        SELECT g.guide_idx, g.title_ko::text
+        FROM guide g WHERE s.target_type = 'guide' AND g.guide_id = s.target_id
+        UNION ALL
+        SELECT c.course_idx, c.course_title::text
+        FROM course c WHERE s.target_type = 'course' AND c.course_id = s.target_id
+    ) t ON true
+"#;
+
+pub async fn target_exists(db: &PgPool, target: PublishTarget, target_id: i64) -> AppResult<bool> {
+    let t = target_table(target);
+    let sql = format!(
+        "SELECT EXISTS(SELECT 1 FROM {} WHERE {} = $1)",
+        t.table, t.id_col
+    );
+    let exists = sqlx::query_scalar::<_, bool>(&sql)
+        .bind(target_id)
+        .fetch_one(db)
+        .await?;
+    Ok(exists)
+}
+
+/// 예약 캘린더 (scheduled_at 오름차순)
+pub async fn find_calendar(
+    db: &PgPool,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    target: Option<PublishTarget>,
+    status: PublishScheduleStatus,
+) -> AppResult<Vec<PublishScheduleRes>> {
+    let sql = format!(
+        r#"{SCHEDULE_SELECT}
+        WHERE s.scheduled_at >= $1 AND s.scheduled_at < $2
+          AND ($3::publish_target_enum IS NULL OR s.target_type = $3)
+          AND s.schedule_status = $4
+        ORDER BY s.scheduled_at ASC, s.schedule_id ASC
+        LIMIT 1000"#
+    );
+    let rows = sqlx::query_as::<_, PublishScheduleRes>(&sql)
+        .bind(from)
+        .bind(to)
+        .bind(target)
+        .bind(status)
+        .fetch_all(db)
+        .await?;
+    Ok(rows)
+}
+
+/// 대상 1건의 예약 이력 (최근 생성순)
+pub async fn find_by_target(
+    executor: impl sqlx::PgExecutor<'_>,
+    target: PublishTarget,
+    target_id: i64,
+) -> AppResult<Vec<PublishScheduleRes>> {
+    let sql = format!(
+        r#"{SCHEDULE_SELECT}
+        WHERE s.target_type = $1 AND s.target_id = $2
+        ORDER BY s.schedule_created_at DESC, s.schedule_id DESC
+        LIMIT 100"#
+    );
+    let rows = sqlx::query_as::<_, PublishScheduleRes>(&sql)
+        .bind(target)
+        .bind(target_id)
+        .fetch_all(executor)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_by_id(
+    executor: impl sqlx::PgExecutor<'_>,
+    schedule_id: i64,
+) -> AppResult<Option<PublishScheduleRes>> {
+    let sql = format!("{SCHEDULE_SELECT} WHERE s.schedule_id = $1");
+    let row = sqlx::query_as::<_, PublishScheduleRes>(&sql)
+        .bind(schedule_id)
+        .fetch_optional(executor)
+        .await?;
+    Ok(row)
+}
+
+pub async fn insert_schedule_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    target: PublishTarget,
+    target_id: i64,
+    action: AdminAction,
+    scheduled_at: DateTime<Utc>,
+    force_publish: bool,
+    created_by_user_id: i64,
+) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO content_publish_schedule (
+            target_type, target_id, schedule_action, scheduled_at, force_publish, created_by_user_id
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING schedule_id
+        "#,
+    )
+    .bind(target)
+    .bind(target_id)
+    .bind(action)
+    .bind(scheduled_at)
+    .bind(force_publish)
+    .bind(created_by_user_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(id)
+}
+
+/// 대상의 pending 예약 전부 취소 — 취소된 schedule_id 반환
+///
+/// 관리자가 state 를 직접 바꾼 경우(`manual_state_change`)와 예약 교체(`replaced`)에서 사용.
+pub async fn cancel_pending_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    target: PublishTarget,
+    target_id: i64,
+    cancelled_by_user_id: i64,
+    reason: &str,
+) -> AppResult<Vec<i64>> {
+    let ids = sqlx::query_scalar::<_, i64>(
+        r#"
+        UPDATE content_publish_schedule
+        SET schedule_status = 'cancelled',
+            cancelled_at = NOW(),
+            cancelled_by_user_id = $3,
+            cancel_reason = $4
+        WHERE target_type = $1 AND target_id = $2 AND schedule_status = 'pending'
+        RETURNING schedule_id
+        "#,
+    )
+    .bind(target)
+    .bind(target_id)
+    .bind(cancelled_by_user_id)
+    .bind(reason)
+    .fetch_all(&mut **tx)
+    .await?;
+    Ok(ids)
+}
+
+/// 예약 1건 취소 (pending 일 때만)
+pub async fn cancel_schedule(
+    db: &PgPool,
+    schedule_id: i64,
+    cancelled_by_user_id: i64,
+    reason: &str,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE content_publish_schedule
+        SET schedule_status = 'cancelled',
+            cancelled_at = NOW(),
+            cancelled_by_user_id = $2,
+            cancel_reason = $3
+        WHERE schedule_id = $1 AND schedule_status = 'pending'
+        "#,
+    )
+    .bind(schedule_id)
+    .bind(cancelled_by_user_id)
+    .bind(reason)
+    .execute(db)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+// ==========================================
+// 실행 (jobs::publish_scheduler)
+// ==========================================
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DueScheduleRow {
+    pub schedule_id: i64,
+    pub target_type: PublishTarget,
+    pub target_id: i64,
+    pub schedule_action: AdminAction,
+    pub force_publish: bool,
+    pub created_by_user_id: i64,
+}
+
+/// 실행 시각이 지난 pending 1건 잠금 (다중 인스턴스 동시 실행 시 SKIP LOCKED 로 분산)
+pub async fn claim_due_tx(tx: &mut Transaction<'_, Postgres>) -> AppResult<Option<DueScheduleRow>> {
+    let row = sqlx::query_as::<_, DueScheduleRow>(
+        r#"
+        SELECT schedule_id, target_type, target_id, schedule_action, force_publish, created_by_user_id
+        FROM content_publish_schedule
+        WHERE schedule_status = 'pending' AND scheduled_at <= NOW()
+        ORDER BY scheduled_at ASC, schedule_id ASC
+        LIMIT 1
+        FOR UPDATE SKIP LOCKED
+        "#,
+    )
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(row)
+}
+
+pub async fn finish_schedule_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    schedule_id: i64,
+    status: PublishScheduleStatus,
+    failure_reason: Option<&str>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE content_publish_schedule
+        SET schedule_status = $2,
+            executed_at = NOW(),
+            failure_reason = $3
+        WHERE schedule_id = $1
+        "#,
+    )
+    .bind(schedule_id)
+    .bind(status)
+    .bind(failure_reason)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// 대상 현재 state (행 잠금)
+pub async fn find_target_state_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    target: PublishTarget,
+    target_id: i64,
+) -> AppResult<Option<String>> {
+    let t = target_table(target);
+    let sql = format!(
+        "SELECT {}::text FROM {} WHERE {} = $1 FOR UPDATE",
+        t.state_col, t.table, t.id_col
+    );
+    let state = sqlx::query_scalar::<_, String>(&sql)
+        .bind(target_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+    Ok(state)
+}
+
+pub async fn update_target_state_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    target: PublishTarget,
+    target_id: i64,
+    state: &str,
+    updated_by_user_id: i64,
+) -> AppResult<()> {
+    let t = target_table(target);
+    let sql = format!(
+        "UPDATE {} SET {} = CAST($2 AS {}), updated_by_user_id = $3, {} = NOW() WHERE {} = $1",
+        t.table, t.state_col, t.state_enum, t.updated_at_col, t.id_col
+    );
+    sqlx::query(&sql)
+        .bind(target_id)
+        .bind(state)
+        .bind(updated_by_user_id)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/// 콘텐츠별 관리자 변경 로그 (guide 는 전용 로그 테이블이 없어 감사 로그만 남김)
+pub async fn create_content_log_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    target: PublishTarget,
+    target_id: i64,
+    admin_user_id: i64,
+    action: AdminAction,
+    before: &Value,
+    after: &Value,
+) -> AppResult<()> {
+    let sql = match target {
+        PublishTarget::Video => {
+            r#"INSERT INTO admin_video_log (admin_user_id, admin_pick_video_id, admin_video_action, admin_video_before, admin_video_after)
+               VALUES ($1, $2::int, $3, $4, $5)"#
+        }
+        PublishTarget::Study => {
+            r#"INSERT INTO admin_study_log (admin_user_id, admin_pick_study_id, admin_study_action, admin_study_before, admin_study_after)
+               VALUES ($1, $2::int, $3, $4, $5)"#
+        }
+        PublishTarget::Lesson => {
+            r#"INSERT INTO admin_lesson_log (admin_user_id, admin_pick_lesson_id, admin_lesson_action, admin_lesson_before, admin_lesson_after)
+               VALUES ($1, $2::int, $3, $4, $5)"#
+        }
+        PublishTarget::Course => {
+            r#"INSERT INTO admin_course_log (admin_user_id, admin_pick_course_id, admin_course_action, admin_course_before, admin_course_after)
+               VALUES ($1, $2::int, $3, $4, $5)"#
+        }
+        PublishTarget::Guide => return Ok(()),
+    };
+    sqlx::query(sql)
+        .bind(admin_user_id)
+        .bind(target_id)
+        .bind(action)
+        .bind(before)
+        .bind(after)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use super::handler::{
+    admin_cancel_schedule, admin_list_target_schedules, admin_publish_calendar,
+    admin_set_target_schedule,
+};
+use crate::AppState;
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+pub fn admin_schedule_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(admin_publish_calendar))
+        .route("/{schedule_id}", delete(admin_cancel_schedule))
+        .route(
+            "/{target_type}/{target_id}",
+            get(admin_list_target_schedules).put(admin_set_target_schedule),
+        )
+}
@@ -0,0 +1,338 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Postgres, Transaction};
+
+use super::dto::{
+    PublishCalendarQuery, PublishCalendarRes, PublishScheduleListRes, PublishScheduleRes,
+    PublishScheduleSetReq,
+};
+use super::repo;
+use crate::error::{AppError, AppResult};
+use crate::types::{AdminAction, PublishScheduleStatus, PublishTarget, UserAuth};
+use crate::AppState;
+
+/// 관리자가 state 를 직접 바꿔 pending 예약이 취소될 때의 사유
+pub const CANCEL_MANUAL_STATE_CHANGE: &str = "manual_state_change";
+const CANCEL_REPLACED: &str = "replaced";
+const CANCEL_BY_ADMIN: &str = "cancelled_by_admin";
+
+const CALENDAR_DEFAULT_DAYS: i64 = 30;
+const CALENDAR_MAX_DAYS: i64 = 366;
+
+async fn check_admin_rbac(pool: &sqlx::PgPool, actor_user_id: i64) -> AppResult<UserAuth> {
+    let actor = crate::api::user::repo::find_user(pool, actor_user_id)
+        .await?
+        .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+
+    match actor.user_auth {
+        UserAuth::Hymn | UserAuth::Admin | UserAuth::Manager => Ok(actor.user_auth),
+        _ => Err(AppError::Forbidden("Forbidden".to_string())),
+    }
+}
+
+/// 예약 실행 시 전환할 state 값 (course 만 active/inactive 체계)
+pub fn scheduled_state(target: PublishTarget, action: AdminAction) -> &'static str {
+    match (target, action) {
+        (PublishTarget::Course, AdminAction::Publish) => "active",
+        (PublishTarget::Course, _) => "inactive",
+        (_, AdminAction::Publish) => "open",
+        _ => "close",
+    }
+}
+
+/// 예약 시각 검증 = 최소 하나 + 미래 시각 + (둘 다 있으면) 공개 < 비공개
+fn validate_window(
+    now: DateTime<Utc>,
+    publish_at: Option<DateTime<Utc>>,
+    unpublish_at: Option<DateTime<Utc>>,
+) -> AppResult<()> {
+    if publish_at.is_none() && unpublish_at.is_none() {
+        return Err(AppError::BadRequest(
+            "publish_at or unpublish_at is required".into(),
+        ));
+    }
+    if publish_at.is_some_and(|t| t <= now) || unpublish_at.is_some_and(|t| t <= now) {
+        return Err(AppError::BadRequest(
+            "scheduled time must be in the future".into(),
+        ));
+    }
+    if let (Some(p), Some(u)) = (publish_at, unpublish_at) {
+        if p >= u {
+            return Err(AppError::BadRequest(
+                "publish_at must be earlier than unpublish_at".into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 캘린더 조회 구간 — 기본 [now, now + 30일), 최대 366일
+fn calendar_range(
+    now: DateTime<Utc>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> AppResult<(DateTime<Utc>, DateTime<Utc>)> {
+    let from = from.unwrap_or(now);
+    let to = to.unwrap_or(from + Duration::days(CALENDAR_DEFAULT_DAYS));
+    if to <= from {
+        return Err(AppError::BadRequest("to must be later than from".into()));
+    }
+    if to - from > Duration::days(CALENDAR_MAX_DAYS) {
+        return Err(AppError::BadRequest(format!(
+            "calendar range must not exceed {CALENDAR_MAX_DAYS} days"
+        )));
+    }
+    Ok((from, to))
+}
+
+/// 관리자 수동 state 변경 시 대상의 pending 예약을 명시 취소 (같은 트랜잭션)
+pub async fn cancel_on_manual_change(
+    tx: &mut Transaction<'_, Postgres>,
+    target: PublishTarget,
+    target_id: i64,
+    actor_user_id: i64,
+) -> AppResult<Vec<i64>> {
+    let cancelled = repo::cancel_pending_tx(
+        tx,
+        target,
+        target_id,
+        actor_user_id,
+        CANCEL_MANUAL_STATE_CHANGE,
+    )
+    .await?;
+    if !cancelled.is_empty() {
+        tracing::info!(
+            ?target,
+            target_id,
+            actor_user_id,
+            schedule_ids = ?cancelled,
+            "publish schedules cancelled by manual state change"
+        );
+    }
+    Ok(cancelled)
+}
+
+/// 예약 캘린더
+pub async fn calendar(
+    st: &AppState,
+    actor_user_id: i64,
+    query: PublishCalendarQuery,
+) -> AppResult<PublishCalendarRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    let (from, to) = calendar_range(Utc::now(), query.from, query.to)?;
+    let items = repo::find_calendar(
+        &st.db,
+        from,
+        to,
+        query.target_type,
+        query.status.unwrap_or(PublishScheduleStatus::Pending),
+    )
+    .await?;
+    Ok(PublishCalendarRes { from, to, items })
+}
+
+/// 대상 1건의 예약 이력
+pub async fn list_for_target(
+    st: &AppState,
+    actor_user_id: i64,
+    target: PublishTarget,
+    target_id: i64,
+) -> AppResult<PublishScheduleListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if !repo::target_exists(&st.db, target, target_id).await? {
+        return Err(AppError::NotFound);
+    }
+    let items = repo::find_by_target(&st.db, target, target_id).await?;
+    Ok(PublishScheduleListRes {
+        target_type: target,
+        target_id,
+        items,
+    })
+}
+
+/// 예약 설정 — 대상의 기존 pending 예약은 `replaced` 로 취소 후 새로 등록
+pub async fn set_schedule(
+    st: &AppState,
+    actor_user_id: i64,
+    target: PublishTarget,
+    target_id: i64,
+    req: PublishScheduleSetReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<PublishScheduleListRes> {
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
+    validate_window(Utc::now(), req.publish_at, req.unpublish_at)?;
+
+    let force_publish = req.force_publish.unwrap_or(false);
+    if force_publish && actor_auth != UserAuth::Hymn {
+        return Err(AppError::Forbidden(
+            "force_publish requires HYMN role".into(),
+        ));
+    }
+    if !repo::target_exists(&st.db, target, target_id).await? {
+        return Err(AppError::NotFound);
+    }
+
+    let mut tx = st.db.begin().await?;
+    let replaced =
+        repo::cancel_pending_tx(&mut tx, target, target_id, actor_user_id, CANCEL_REPLACED).await?;
+    let mut created = Vec::new();
+    if let Some(at) = req.publish_at {
+        created.push(
+            repo::insert_schedule_tx(
+                &mut tx,
+                target,
+                target_id,
+                AdminAction::Publish,
+                at,
+                force_publish,
+                actor_user_id,
+            )
+            .await?,
+        );
+    }
+    if let Some(at) = req.unpublish_at {
+        created.push(
+            repo::insert_schedule_tx(
+                &mut tx,
+                target,
+                target_id,
+                AdminAction::Unpublish,
+                at,
+                false,
+                actor_user_id,
+            )
+            .await?,
+        );
+    }
+    tx.commit().await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "SET_PUBLISH_SCHEDULE",
+        "content_publish_schedule",
+        Some(target_id),
+        &serde_json::json!({
+            "target_type": target,
+            "target_id": target_id,
+            "req": &req,
+            "created_schedule_ids": created,
+            "replaced_schedule_ids": replaced,
+        }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    let items = repo::find_by_target(&st.db, target, target_id).await?;
+    Ok(PublishScheduleListRes {
+        target_type: target,
+        target_id,
+        items,
+    })
+}
+
+/// 예약 1건 취소
+pub async fn cancel_schedule(
+    st: &AppState,
+    actor_user_id: i64,
+    schedule_id: i64,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<PublishScheduleRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+
+    let before = repo::find_by_id(&st.db, schedule_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    if before.schedule_status != PublishScheduleStatus::Pending
+        || !repo::cancel_schedule(&st.db, schedule_id, actor_user_id, CANCEL_BY_ADMIN).await?
+    {
+        return Err(AppError::Conflict(
+            "only pending schedules can be cancelled".into(),
+        ));
+    }
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "CANCEL_PUBLISH_SCHEDULE",
+        "content_publish_schedule",
+        Some(schedule_id),
+        &serde_json::json!({
+            "target_type": before.target_type,
+            "target_id": before.target_id,
+            "schedule_action": before.schedule_action,
+            "scheduled_at": before.scheduled_at,
+        }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    repo::find_by_id(&st.db, schedule_id)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheduled_state_maps_course_to_active_inactive() {
+        assert_eq!(
+            scheduled_state(PublishTarget::Course, AdminAction::Publish),
+            "active"
+        );
+        assert_eq!(
+            scheduled_state(PublishTarget::Course, AdminAction::Unpublish),
+            "inactive"
+        );
+        assert_eq!(
+            scheduled_state(PublishTarget::Video, AdminAction::Publish),
+            "open"
+        );
+        assert_eq!(
+            scheduled_state(PublishTarget::Guide, AdminAction::Unpublish),
+            "close"
+        );
+    }
+
+    #[test]
+    fn validate_window_requires_future_ordered_times() {
+        let now = Utc::now();
+        let h = Duration::hours(1);
+
+        assert!(validate_window(now, Some(now + h), None).is_ok());
+        assert!(validate_window(now, None, Some(now + h)).is_ok());
+        assert!(validate_window(now, Some(now + h), Some(now + h * 2)).is_ok());
+
+        for (p, u) in [
+            (None, None),
+            (Some(now - h), None),
+            (None, Some(now)),
+            (Some(now + h * 2), Some(now + h)),
+            (Some(now + h), Some(now + h)),
+        ] {
+            assert!(
+                matches!(validate_window(now, p, u), Err(AppError::BadRequest(_))),
+                "{p:?} {u:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn calendar_range_defaults_and_limits() {
+        let now = Utc::now();
+        let (from, to) = calendar_range(now, None, None).unwrap();
+        assert_eq!(from, now);
+        assert_eq!(to - from, Duration::days(CALENDAR_DEFAULT_DAYS));
+
+        assert!(calendar_range(now, Some(now), Some(now)).is_err());
+        assert!(calendar_range(now, None, Some(now + Duration::days(400))).is_err());
+        assert!(calendar_range(now, Some(now - Duration::days(7)), None).is_ok());
+    }
+}
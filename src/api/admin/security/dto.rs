@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::types::LoginRiskAction;
+
+// =============================================================================
+// 로그인 위험 이벤트
+// =============================================================================
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct AdminRiskEventListReq {
+    pub page: Option<i64>,
+    pub size: Option<i64>,
+    pub user_id: Option<i64>,
+    /// new_country | new_device | impossible_travel
+    pub reason: Option<String>,
+    pub action: Option<LoginRiskAction>,
+    /// true = 사용자가 "본인이 아닙니다" 로 세션을 폐기한 이벤트만
+    pub user_revoked: Option<bool>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AdminRiskEventItem {
+    pub risk_event_id: i64,
+    pub user_id: i64,
+    pub user_nickname: Option<String>,
+    pub session_id: Option<String>,
+    pub login_method: String,
+    /// new_country | new_device | impossible_travel
+    pub reasons: Vec<String>,
+    pub action: LoginRiskAction,
+    pub country_code: Option<String>,
+    /// 직전 로그인 국가
+    pub prev_country_code: Option<String>,
+    pub city: Option<String>,
+    pub device: Option<String>,
+    pub browser: Option<String>,
+    pub os: Option<String>,
+    /// 직전 로그인 위치와의 거리 (GeoIP City DB 가 있을 때만)
+    pub distance_km: Option<f64>,
+    pub speed_kmh: Option<f64>,
+    /// step-up 인증 완료 시각 (step_up 조치)
+    pub step_up_passed_at: Option<DateTime<Utc>>,
+    /// 사용자가 알림 메일로 전체 세션을 폐기한 시각
+    pub user_revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminRiskEventMeta {
+    pub total_count: i64,
+    pub total_pages: i64,
+    pub current_page: i64,
+    pub per_page: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminRiskEventListRes {
+    pub items: Vec<AdminRiskEventItem>,
+    pub meta: AdminRiskEventMeta,
+}
@@ -0,0 +1,33 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+
+use crate::{api::auth::extractor::AuthUser, error::AppResult, state::AppState};
+
+use super::{
+    dto::{AdminRiskEventListReq, AdminRiskEventListRes},
+    service::AdminSecurityService,
+};
+
+#[utoipa::path(
+    get,
+    path = "/admin/security/risk-events",
+    tag = "admin_security",
+    params(AdminRiskEventListReq),
+    responses(
+        (status = 200, description = "Login risk events (newest first)", body = AdminRiskEventListRes),
+        (status = 400, description = "Unknown reason filter", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn list_risk_events(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Query(params): Query<AdminRiskEventListReq>,
+) -> AppResult<Json<AdminRiskEventListRes>> {
+    let res = AdminSecurityService::list_risk_events(&st, auth_user.sub, params).await?;
+    Ok(Json(res))
+}
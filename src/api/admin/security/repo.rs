@@ -0,0 +1,76 @@
+use sqlx::PgPool;
+
+use super::dto::AdminRiskEventItem;
+use crate::error::AppResult;
+use crate::types::LoginRiskAction;
+
+pub struct RiskEventFilter<'a> {
+    pub user_id: Option<i64>,
+    pub reason: Option<&'a str>,
+    pub action: Option<LoginRiskAction>,
+    pub user_revoked: Option<bool>,
+}
+
+const RISK_EVENT_WHERE: &str = r#"
+    WHERE ($1::bigint IS NULL OR e.user_id = $1)
+      AND ($2::text IS NULL OR $2 = ANY(e.risk_reasons))
+      AND ($3::login_risk_action_enum IS NULL OR e.risk_action = $3)
+      AND ($4::boolean IS NULL OR (e.risk_user_revoked_at IS NOT NULL) = $4)
+"#;
+
+/// 위험 이벤트 목록 (최신순)
+pub async fn list_risk_events(
+    pool: &PgPool,
+    page: i64,
+    size: i64,
+    filter: &RiskEventFilter<'_>,
+) -> AppResult<(i64, Vec<AdminRiskEventItem>)> {
+    let total = sqlx::query_scalar::<_, i64>(&format!(
+        "SELECT COUNT(*) FROM login_risk_event e {RISK_EVENT_WHERE}"
+    ))
+    .bind(filter.user_id)
+    .bind(filter.reason)
+    .bind(filter.action)
+    .bind(filter.user_revoked)
+    .fetch_one(pool)
+    .await?;
+
+    let items = sqlx::query_as::<_, AdminRiskEventItem>(&format!(
+        r#"
+        SELECT
+            e.login_risk_event_id AS risk_event_id,
+            e.user_id,
+            u.user_nickname,
+            e.login_session_id::text AS session_id,
+            e.risk_login_method AS login_method,
+            e.risk_reasons AS reasons,
+            e.risk_action AS action,
+            e.risk_country::text AS country_code,
+            e.risk_prev_country::text AS prev_country_code,
+            e.risk_city AS city,
+            e.risk_device AS device,
+            e.risk_browser AS browser,
+            e.risk_os AS os,
+            e.risk_distance_km AS distance_km,
+            e.risk_speed_kmh AS speed_kmh,
+            e.risk_step_up_passed_at AS step_up_passed_at,
+            e.risk_user_revoked_at AS user_revoked_at,
+            e.risk_created_at AS created_at
+        FROM login_risk_event e
+        JOIN users u ON u.user_id = e.user_id
+        {RISK_EVENT_WHERE}
+        ORDER BY e.risk_created_at DESC, e.login_risk_event_id DESC
+        LIMIT $5 OFFSET $6
+        "#
+    ))
+    .bind(filter.user_id)
+    .bind(filter.reason)
+    .bind(filter.action)
+    .bind(filter.user_revoked)
+    .bind(size)
+    .bind((page - 1) * size)
+    .fetch_all(pool)
+    .await?;
+
+    Ok((total, items))
+}
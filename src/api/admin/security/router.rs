@@ -0,0 +1,9 @@
+use axum::{routing::get, Router};
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn admin_security_router() -> Router<AppState> {
+    Router::new().route("/risk-events", get(handler::list_risk_events))
+}
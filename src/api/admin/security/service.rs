@@ -0,0 +1,79 @@
+use super::dto::{AdminRiskEventListReq, AdminRiskEventListRes, AdminRiskEventMeta};
+use super::repo::{self, RiskEventFilter};
+use crate::error::{AppError, AppResult};
+use crate::state::AppState;
+use crate::types::UserAuth;
+
+/// risk_reasons 에 저장되는 값 (api::auth::risk::RiskReason)
+const RISK_REASONS: [&str; 3] = ["new_country", "new_device", "impossible_travel"];
+
+pub struct AdminSecurityService;
+
+impl AdminSecurityService {
+    // =========================================================================
+    // RBAC 검증 — 로그인 위치/기기 이력은 HYMN/admin 만
+    // =========================================================================
+
+    async fn check_admin_rbac(pool: &sqlx::PgPool, actor_user_id: i64) -> AppResult<UserAuth> {
+        let actor = crate::api::user::repo::find_user(pool, actor_user_id)
+            .await?
+            .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+
+        match actor.user_auth {
+            UserAuth::Hymn | UserAuth::Admin => Ok(actor.user_auth),
+            _ => Err(AppError::Forbidden("Forbidden".to_string())),
+        }
+    }
+
+    pub async fn list_risk_events(
+        st: &AppState,
+        actor_user_id: i64,
+        req: AdminRiskEventListReq,
+    ) -> AppResult<AdminRiskEventListRes> {
+        Self::check_admin_rbac(&st.db, actor_user_id).await?;
+
+        let page = req.page.unwrap_or(1).max(1);
+        let size = req.size.unwrap_or(20).clamp(1, 100);
+        let reason = req
+            .reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty());
+        if let Some(r) = reason {
+            if !RISK_REASONS.contains(&r) {
+                return Err(AppError::BadRequest(format!(
+                    "reason must be one of: {}",
+                    RISK_REASONS.join(", ")
+                )));
+            }
+        }
+
+        let (total_count, items) = repo::list_risk_events(
+            &st.db,
+            page,
+            size,
+            &RiskEventFilter {
+                user_id: req.user_id,
+                reason,
+                action: req.action,
+                user_revoked: req.user_revoked,
+            },
+        )
+        .await?;
+        let total_pages = if total_count == 0 {
+            0
+        } else {
+            (total_count + size - 1) / size
+        };
+
+        Ok(AdminRiskEventListRes {
+            items,
+            meta: AdminRiskEventMeta {
+                total_count,
+                total_pages,
+                current_page: page,
+                per_page: size,
+            },
+        })
+    }
+}
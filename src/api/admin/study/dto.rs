@@ -55,6 +55,9 @@ pub struct StudyUpdateReq {
     pub study_subtitle: Option<String>,
 
     pub study_description: Option<String>,
+
+    /// HYMN 전용 — state=open 전환 시 번역 커버리지 공개 정책 우회 (PUBLISH_OVERRIDE 감사 기록)
+    pub force_publish: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Validate, Serialize, ToSchema)]
@@ -1,8 +1,10 @@
 use std::net::IpAddr;
 use validator::Validate;
 
+use crate::api::admin::schedule::service::cancel_on_manual_change;
+use crate::api::admin::translation::service::TranslationService;
 use crate::error::{AppError, AppResult};
-use crate::types::{StudyAccess, StudyProgram, StudyState, UserAuth};
+use crate::types::{ContentType, PublishTarget, StudyAccess, StudyProgram, StudyState, UserAuth};
 use crate::AppState;
 
 use super::dto::{
@@ -161,6 +163,9 @@ pub async fn admin_create_study(
     let study_program = req.study_program.unwrap_or(StudyProgram::Tbc);
     let study_state = req.study_state.unwrap_or(StudyState::Ready);
     let study_access = req.study_access.unwrap_or(StudyAccess::Public);
+    if study_state == StudyState::Open {
+        TranslationService::ensure_open_on_create_allowed(&st.db, ContentType::Study).await?;
+    }
 
     let study_title = req
         .study_title
@@ -279,6 +284,10 @@ pub async fn admin_bulk_create_studies(
             let study_program = item.study_program.unwrap_or(StudyProgram::Tbc);
             let study_state = item.study_state.unwrap_or(StudyState::Ready);
             let study_access = item.study_access.unwrap_or(StudyAccess::Public);
+            if study_state == StudyState::Open {
+                TranslationService::ensure_open_on_create_allowed(&st.db, ContentType::Study)
+                    .await?;
+            }
 
             let study_title = item
                 .study_title
@@ -354,6 +363,7 @@ pub async fn admin_bulk_create_studies(
                 let msg = match e {
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -389,7 +399,7 @@ pub async fn admin_update_study(
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
 ) -> AppResult<AdminStudyRes> {
-    check_admin_rbac(&st.db, actor_user_id).await?;
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
 
     crate::api::admin::user::repo::write_audit_log(
         st,
@@ -427,6 +437,21 @@ pub async fn admin_update_study(
         .await?
         .ok_or(AppError::NotFound)?;
 
+    // 공개 전환 시 번역 커버리지 정책 (HYMN force_publish 우회 가능)
+    if req.study_state == Some(StudyState::Open) && before.study_state != StudyState::Open {
+        TranslationService::enforce_publish_policy(
+            st,
+            actor_user_id,
+            actor_auth,
+            ContentType::Study,
+            study_id,
+            req.force_publish.unwrap_or(false),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+    }
+
     if let Some(idx) = req.study_idx.as_deref() {
         if idx != before.study_idx
             && repo::exists_study_idx_for_update(&st.db, study_id, idx).await?
@@ -455,6 +480,11 @@ pub async fn admin_update_study(
     )
     .await?;
 
+    // 수동 state 변경은 예약 공개/비공개보다 우선 — pending 예약 명시 취소
+    if req.study_state.is_some() {
+        cancel_on_manual_change(&mut tx, PublishTarget::Study, study_id, actor_user_id).await?;
+    }
+
     tx.commit().await?;
 
     Ok(updated)
@@ -982,6 +1012,7 @@ pub async fn admin_bulk_create_task_explains(
                     AppError::NotFound => "Study task not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1117,6 +1148,7 @@ pub async fn admin_bulk_update_task_explains(
                     AppError::NotFound => "Task explain not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1240,6 +1272,7 @@ pub async fn admin_bulk_update_task_status(
                     AppError::NotFound => "Task status not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1576,6 +1609,7 @@ pub async fn admin_bulk_create_study_tasks(
                     AppError::NotFound => "Study not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1729,6 +1763,7 @@ pub async fn admin_bulk_update_study_tasks(
                     AppError::NotFound => "Study task not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -1843,7 +1878,7 @@ pub async fn admin_bulk_update_studies(
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
 ) -> AppResult<(bool, StudyBulkUpdateRes)> {
-    check_admin_rbac(&st.db, actor_user_id).await?;
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
 
     if let Err(e) = req.validate() {
         return Err(AppError::BadRequest(e.to_string()));
@@ -1884,6 +1919,7 @@ pub async fn admin_bulk_update_studies(
                 study_program: item.study_program,
                 study_state: item.study_state,
                 study_access: item.study_access,
+                force_publish: None,
             };
 
             if let Some(idx) = update_req.study_idx.as_mut() {
@@ -1906,6 +1942,23 @@ pub async fn admin_bulk_update_studies(
                 .await?
                 .ok_or(AppError::NotFound)?;
 
+            // 일괄 수정은 정책 우회 불가 — 미충족 항목은 422 로 실패 처리
+            if update_req.study_state == Some(StudyState::Open)
+                && before.study_state != StudyState::Open
+            {
+                TranslationService::enforce_publish_policy(
+                    st,
+                    actor_user_id,
+                    actor_auth,
+                    ContentType::Study,
+                    item_id,
+                    false,
+                    None,
+                    None,
+                )
+                .await?;
+            }
+
             if let Some(idx) = update_req.study_idx.as_deref() {
                 if idx != before.study_idx
                     && repo::exists_study_idx_for_update(&st.db, item_id, idx).await?
@@ -1933,6 +1986,11 @@ pub async fn admin_bulk_update_studies(
             )
             .await?;
 
+            if update_req.study_state.is_some() {
+                cancel_on_manual_change(&mut tx, PublishTarget::Study, item_id, actor_user_id)
+                    .await?;
+            }
+
             tx.commit().await?;
 
             Ok(updated)
@@ -1954,6 +2012,7 @@ pub async fn admin_bulk_update_studies(
                     AppError::NotFound => "Study not found".to_string(),
                     AppError::BadRequest(m) => m,
                     AppError::Unprocessable(m) => m,
+                    AppError::UnprocessableDetails(m, _) => m,
                     AppError::Conflict(m) => m,
                     AppError::Forbidden(_) => "Forbidden".to_string(),
                     _ => "Internal Server Error".to_string(),
@@ -79,6 +79,10 @@ pub struct TranslationRes {
     pub lang: SupportedLanguage,
     pub translated_text: String,
     pub status: TranslationStatus,
+    /// QA 오류 수 (NULL = 미검사 또는 번역문 변경 후 재검사 전)
+    pub qa_error_count: Option<i32>,
+    pub qa_warning_count: Option<i32>,
+    pub qa_checked_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -208,11 +212,181 @@ pub struct TranslationStatItem {
     pub count: i64,
 }
 
+/// QA 통계 개별 항목 (content_type × lang 별 집계)
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct TranslationQaStatItem {
+    pub content_type: ContentType,
+    pub lang: SupportedLanguage,
+    /// QA 오류가 남은 번역 수 (approved 전환 차단 대상)
+    pub error_items: i64,
+    /// 경고만 있는 번역 수
+    pub warning_items: i64,
+    /// 미검사(또는 번역문 변경 후 재검사 전) 번역 수
+    pub unchecked_items: i64,
+}
+
 /// 번역 통계 응답
 #[derive(Debug, Serialize, ToSchema)]
 pub struct TranslationStatsRes {
     pub items: Vec<TranslationStatItem>,
     pub total_translations: i64,
+    pub qa: Vec<TranslationQaStatItem>,
+}
+
+// =============================================================================
+// 용어집 (Glossary)
+// =============================================================================
+
+/// 용어집 항목
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct TranslationGlossaryRes {
+    pub glossary_id: i64,
+    pub lang: SupportedLanguage,
+    pub term_ko: String,
+    pub required_translation: String,
+    pub forbidden_variants: Vec<String>,
+    pub glossary_note: Option<String>,
+    pub glossary_created_at: DateTime<Utc>,
+    pub glossary_updated_at: DateTime<Utc>,
+}
+
+/// 용어집 목록 조회 필터
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct GlossaryListReq {
+    pub lang: Option<SupportedLanguage>,
+}
+
+/// 용어집 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GlossaryListRes {
+    pub items: Vec<TranslationGlossaryRes>,
+}
+
+/// 용어집 항목 생성 (lang + term_ko 중복 시 UPSERT)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct GlossaryCreateReq {
+    pub lang: SupportedLanguage,
+
+    #[validate(length(min = 1, max = 100))]
+    pub term_ko: String,
+
+    #[validate(length(min = 1, max = 200))]
+    pub required_translation: String,
+
+    #[serde(default)]
+    #[validate(length(max = 50))]
+    pub forbidden_variants: Vec<String>,
+
+    pub glossary_note: Option<String>,
+}
+
+/// 용어집 항목 수정
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct GlossaryUpdateReq {
+    #[validate(length(min = 1, max = 200))]
+    pub required_translation: Option<String>,
+
+    #[validate(length(max = 50))]
+    pub forbidden_variants: Option<Vec<String>>,
+
+    pub glossary_note: Option<String>,
+}
+
+// =============================================================================
+// 번역 QA (린트)
+// =============================================================================
+
+/// QA 스캔 요청 — lang 전체 또는 content_type/content_id 로 범위 축소
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TranslationQaScanReq {
+    pub lang: SupportedLanguage,
+    pub content_type: Option<ContentType>,
+    pub content_id: Option<i64>,
+}
+
+/// 번역 1건 QA 결과
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranslationQaItem {
+    pub translation_id: i64,
+    pub content_type: ContentType,
+    pub content_id: i64,
+    pub field_name: String,
+    pub lang: SupportedLanguage,
+    pub error_count: i32,
+    pub warning_count: i32,
+    pub issues: Vec<super::qa::QaIssue>,
+}
+
+/// QA 스캔 응답 (이슈가 있는 번역만 items 에 포함)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranslationQaScanRes {
+    pub checked: usize,
+    pub error_items: usize,
+    pub warning_items: usize,
+    pub items: Vec<TranslationQaItem>,
+}
+
+// =============================================================================
+// 공개(open) 전 번역 커버리지 정책
+// =============================================================================
+
+/// 콘텐츠 타입별 공개 정책
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct PublishPolicyRes {
+    pub policy_content_type: ContentType,
+    pub required_langs: Vec<SupportedLanguage>,
+    /// source-fields 의 field_name — 원문이 비어 있는 필드는 검사 제외
+    pub required_fields: Vec<String>,
+    pub min_status: TranslationStatus,
+    pub policy_enabled: bool,
+    pub policy_updated_at: DateTime<Utc>,
+}
+
+/// 공개 정책 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublishPolicyListRes {
+    pub items: Vec<PublishPolicyRes>,
+}
+
+/// 공개 정책 수정 (None 이면 변경 안 함)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PublishPolicyUpdateReq {
+    #[validate(length(max = 40))]
+    pub required_langs: Option<Vec<SupportedLanguage>>,
+
+    #[validate(length(max = 20))]
+    pub required_fields: Option<Vec<String>>,
+
+    pub min_status: Option<TranslationStatus>,
+    pub policy_enabled: Option<bool>,
+}
+
+/// 공개 커버리지 미리보기 요청
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PublishCoverageReq {
+    pub content_type: ContentType,
+    pub content_id: i64,
+}
+
+/// 정책 미충족 필드 1건
+#[derive(Debug, Clone, Serialize, ToSchema, PartialEq)]
+pub struct PublishMissingField {
+    pub content_type: ContentType,
+    pub content_id: i64,
+    pub field_name: String,
+    pub missing_langs: Vec<SupportedLanguage>,
+}
+
+/// 공개 커버리지 결과 (422 details 와 동일 구조)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublishCoverageRes {
+    pub content_type: ContentType,
+    pub content_id: i64,
+    pub policy_enabled: bool,
+    pub min_status: TranslationStatus,
+    pub required_langs: Vec<SupportedLanguage>,
+    pub satisfied: bool,
+    pub missing: Vec<PublishMissingField>,
 }
 
 // =============================================================================
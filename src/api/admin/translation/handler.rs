@@ -6,12 +6,16 @@ use axum::Json;
 use crate::api::auth::extractor::AuthUser;
 use crate::error::AppResult;
 use crate::state::AppState;
+use crate::types::ContentType;
 
 use super::dto::{
-    ContentRecordsReq, ContentRecordsRes, SourceFieldsReq, SourceFieldsRes,
-    TranslationBulkCreateReq, TranslationBulkCreateRes, TranslationCreateReq, TranslationListReq,
-    TranslationListRes, TranslationRes, TranslationSearchReq, TranslationSearchRes,
-    TranslationStatsRes, TranslationStatusReq, TranslationUpdateReq,
+    ContentRecordsReq, ContentRecordsRes, GlossaryCreateReq, GlossaryListReq, GlossaryListRes,
+    GlossaryUpdateReq, PublishCoverageReq, PublishCoverageRes, PublishPolicyListRes,
+    PublishPolicyRes, PublishPolicyUpdateReq, SourceFieldsReq, SourceFieldsRes,
+    TranslationBulkCreateReq, TranslationBulkCreateRes, TranslationCreateReq,
+    TranslationGlossaryRes, TranslationListReq, TranslationListRes, TranslationQaItem,
+    TranslationQaScanReq, TranslationQaScanRes, TranslationRes, TranslationSearchReq,
+    TranslationSearchRes, TranslationStatsRes, TranslationStatusReq, TranslationUpdateReq,
 };
 use super::service::TranslationService;
 
@@ -274,3 +278,217 @@ pub async fn admin_get_translation_stats(
     let res = TranslationService::get_translation_stats(&st.db).await?;
     Ok(Json(res))
 }
+
+// =============================================================================
+// 용어집 (Glossary)
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/admin/translations/glossary",
+    tag = "admin_translation",
+    params(GlossaryListReq),
+    responses(
+        (status = 200, description = "Glossary entries", body = GlossaryListRes),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_glossary(
+    State(st): State<AppState>,
+    AuthUser(_auth): AuthUser,
+    Query(req): Query<GlossaryListReq>,
+) -> AppResult<Json<GlossaryListRes>> {
+    let res = TranslationService::list_glossary(&st.db, req).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/translations/glossary",
+    tag = "admin_translation",
+    request_body(content = GlossaryCreateReq, content_type = "application/json"),
+    responses(
+        (status = 201, description = "Glossary entry created (or replaced)", body = TranslationGlossaryRes),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_create_glossary(
+    State(st): State<AppState>,
+    AuthUser(auth): AuthUser,
+    AppJson(req): AppJson<GlossaryCreateReq>,
+) -> AppResult<(StatusCode, Json<TranslationGlossaryRes>)> {
+    let res = TranslationService::create_glossary(&st.db, auth.sub, req).await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/admin/translations/glossary/{id}",
+    tag = "admin_translation",
+    params(("id" = i64, Path, description = "Glossary ID")),
+    request_body(content = GlossaryUpdateReq, content_type = "application/json"),
+    responses(
+        (status = 200, description = "Glossary entry updated", body = TranslationGlossaryRes),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_update_glossary(
+    State(st): State<AppState>,
+    AuthUser(auth): AuthUser,
+    Path(id): Path<i64>,
+    AppJson(req): AppJson<GlossaryUpdateReq>,
+) -> AppResult<Json<TranslationGlossaryRes>> {
+    let res = TranslationService::update_glossary(&st.db, auth.sub, id, req).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/translations/glossary/{id}",
+    tag = "admin_translation",
+    params(("id" = i64, Path, description = "Glossary ID")),
+    responses(
+        (status = 204, description = "Glossary entry deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_delete_glossary(
+    State(st): State<AppState>,
+    AuthUser(_auth): AuthUser,
+    Path(id): Path<i64>,
+) -> AppResult<StatusCode> {
+    TranslationService::delete_glossary(&st.db, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// =============================================================================
+// 번역 QA (린트)
+// =============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/admin/translations/qa/scan",
+    tag = "admin_translation",
+    request_body(content = TranslationQaScanReq, content_type = "application/json"),
+    responses(
+        (status = 200, description = "QA scan result (items with issues only)", body = TranslationQaScanRes),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_scan_translation_qa(
+    State(st): State<AppState>,
+    AuthUser(_auth): AuthUser,
+    AppJson(req): AppJson<TranslationQaScanReq>,
+) -> AppResult<Json<TranslationQaScanRes>> {
+    let res = TranslationService::scan_qa(&st.db, req).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/translations/{id}/qa",
+    tag = "admin_translation",
+    params(("id" = i64, Path, description = "Translation ID")),
+    responses(
+        (status = 200, description = "QA result for a single translation", body = TranslationQaItem),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_get_translation_qa(
+    State(st): State<AppState>,
+    AuthUser(_auth): AuthUser,
+    Path(id): Path<i64>,
+) -> AppResult<Json<TranslationQaItem>> {
+    let res = TranslationService::check_translation_qa(&st.db, id).await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// 공개(open) 전 번역 커버리지 정책
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/admin/translations/publish-policies",
+    tag = "admin_translation",
+    responses(
+        (status = 200, description = "Publish coverage policies", body = PublishPolicyListRes),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_publish_policies(
+    State(st): State<AppState>,
+    AuthUser(_auth): AuthUser,
+) -> AppResult<Json<PublishPolicyListRes>> {
+    let res = TranslationService::list_publish_policies(&st.db).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/admin/translations/publish-policies/{content_type}",
+    tag = "admin_translation",
+    params(("content_type" = ContentType, Path, description = "video | lesson | study")),
+    request_body(content = PublishPolicyUpdateReq, content_type = "application/json"),
+    responses(
+        (status = 200, description = "Publish policy updated", body = PublishPolicyRes),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "No policy for content type"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_update_publish_policy(
+    State(st): State<AppState>,
+    AuthUser(auth): AuthUser,
+    Path(content_type): Path<ContentType>,
+    AppJson(req): AppJson<PublishPolicyUpdateReq>,
+) -> AppResult<Json<PublishPolicyRes>> {
+    let res =
+        TranslationService::update_publish_policy(&st.db, auth.sub, content_type, req).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/translations/publish-coverage",
+    tag = "admin_translation",
+    params(PublishCoverageReq),
+    responses(
+        (status = 200, description = "Publish coverage for a single content", body = PublishCoverageRes),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_get_publish_coverage(
+    State(st): State<AppState>,
+    AuthUser(_auth): AuthUser,
+    Query(req): Query<PublishCoverageReq>,
+) -> AppResult<Json<PublishCoverageRes>> {
+    let res =
+        TranslationService::get_publish_coverage(&st.db, req.content_type, req.content_id).await?;
+    Ok(Json(res))
+}
@@ -1,5 +1,7 @@
 pub mod dto;
 pub mod handler;
+pub mod publish;
+pub mod qa;
 pub mod repo;
 pub mod router;
 pub mod service;
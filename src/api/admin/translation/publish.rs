@@ -0,0 +1,214 @@
+//! 공개(open) 전 번역 커버리지 판정 (순수 함수 — DB 무관)
+//!
+//! service 가 정책(`content_publish_policy`)·원문 필드·기존 번역을 모아 `evaluate_coverage`
+//! 를 호출한다. 원문이 비어 있는 필드는 번역 대상이 아니므로 검사에서 제외.
+
+use std::collections::HashSet;
+
+use crate::types::{ContentType, SupportedLanguage, TranslationStatus};
+
+use super::dto::{PublishMissingField, PublishPolicyRes, SourceFieldItem};
+
+/// 기존 번역 1건 (커버리지 판정용 최소 필드)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CoverageRow {
+    pub content_type: ContentType,
+    pub content_id: i64,
+    pub field_name: String,
+    pub lang: SupportedLanguage,
+    pub status: TranslationStatus,
+}
+
+/// 번역 상태 순위 (draft < reviewed < approved)
+fn status_rank(status: TranslationStatus) -> u8 {
+    match status {
+        TranslationStatus::Draft => 0,
+        TranslationStatus::Reviewed => 1,
+        TranslationStatus::Approved => 2,
+    }
+}
+
+/// 정책 미충족 필드 목록 — 비어 있으면 공개 가능
+pub fn evaluate_coverage(
+    policy: &PublishPolicyRes,
+    fields: &[SourceFieldItem],
+    translations: &[CoverageRow],
+) -> Vec<PublishMissingField> {
+    let min_rank = status_rank(policy.min_status);
+    let covered: HashSet<(ContentType, i64, &str, SupportedLanguage)> = translations
+        .iter()
+        .filter(|t| status_rank(t.status) >= min_rank)
+        .map(|t| (t.content_type, t.content_id, t.field_name.as_str(), t.lang))
+        .collect();
+
+    fields
+        .iter()
+        .filter(|f| policy.required_fields.iter().any(|r| r == &f.field_name))
+        .filter(|f| {
+            f.source_text
+                .as_deref()
+                .is_some_and(|s| !s.trim().is_empty())
+        })
+        .filter_map(|f| {
+            let missing_langs: Vec<SupportedLanguage> = policy
+                .required_langs
+                .iter()
+                .copied()
+                .filter(|lang| {
+                    !covered.contains(&(f.content_type, f.content_id, f.field_name.as_str(), *lang))
+                })
+                .collect();
+
+            (!missing_langs.is_empty()).then(|| PublishMissingField {
+                content_type: f.content_type,
+                content_id: f.content_id,
+                field_name: f.field_name.clone(),
+                missing_langs,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(fields: &[&str], langs: &[SupportedLanguage]) -> PublishPolicyRes {
+        PublishPolicyRes {
+            policy_content_type: ContentType::Video,
+            required_langs: langs.to_vec(),
+            required_fields: fields.iter().map(|f| f.to_string()).collect(),
+            min_status: TranslationStatus::Approved,
+            policy_enabled: true,
+            policy_updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn field(ct: ContentType, id: i64, name: &str, text: Option<&str>) -> SourceFieldItem {
+        SourceFieldItem {
+            content_type: ct,
+            content_id: id,
+            field_name: name.to_string(),
+            source_text: text.map(str::to_string),
+        }
+    }
+
+    fn row(
+        ct: ContentType,
+        id: i64,
+        name: &str,
+        lang: SupportedLanguage,
+        status: TranslationStatus,
+    ) -> CoverageRow {
+        CoverageRow {
+            content_type: ct,
+            content_id: id,
+            field_name: name.to_string(),
+            lang,
+            status,
+        }
+    }
+
+    #[test]
+    fn fully_approved_is_satisfied() {
+        let p = policy(
+            &["video_title"],
+            &[SupportedLanguage::Ja, SupportedLanguage::Vi],
+        );
+        let fields = [field(ContentType::Video, 1, "video_title", Some("인사"))];
+        let rows = [
+            row(
+                ContentType::Video,
+                1,
+                "video_title",
+                SupportedLanguage::Ja,
+                TranslationStatus::Approved,
+            ),
+            row(
+                ContentType::Video,
+                1,
+                "video_title",
+                SupportedLanguage::Vi,
+                TranslationStatus::Approved,
+            ),
+        ];
+        assert!(evaluate_coverage(&p, &fields, &rows).is_empty());
+    }
+
+    #[test]
+    fn below_min_status_counts_as_missing() {
+        let p = policy(
+            &["video_title"],
+            &[SupportedLanguage::Ja, SupportedLanguage::Vi],
+        );
+        let fields = [field(ContentType::Video, 1, "video_title", Some("인사"))];
+        let rows = [
+            row(
+                ContentType::Video,
+                1,
+                "video_title",
+                SupportedLanguage::Ja,
+                TranslationStatus::Approved,
+            ),
+            row(
+                ContentType::Video,
+                1,
+                "video_title",
+                SupportedLanguage::Vi,
+                TranslationStatus::Reviewed,
+            ),
+        ];
+        let missing = evaluate_coverage(&p, &fields, &rows);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].missing_langs, vec![SupportedLanguage::Vi]);
+    }
+
+    #[test]
+    fn empty_source_and_unlisted_fields_are_skipped() {
+        let p = policy(&["video_title", "video_subtitle"], &[SupportedLanguage::Ja]);
+        let fields = [
+            field(ContentType::Video, 1, "video_subtitle", None),
+            field(ContentType::Video, 1, "video_title", Some("  ")),
+            field(ContentType::Video, 1, "video_idx", Some("v_001")),
+        ];
+        assert!(evaluate_coverage(&p, &fields, &[]).is_empty());
+    }
+
+    #[test]
+    fn linked_content_is_checked_by_its_own_type() {
+        let p = policy(&["video_tag_title"], &[SupportedLanguage::Ja]);
+        let fields = [field(
+            ContentType::VideoTag,
+            7,
+            "video_tag_title",
+            Some("기초"),
+        )];
+        // 같은 field_name 이라도 content_type/id 가 다르면 커버리지로 인정하지 않음
+        let rows = [row(
+            ContentType::Video,
+            7,
+            "video_tag_title",
+            SupportedLanguage::Ja,
+            TranslationStatus::Approved,
+        )];
+        let missing = evaluate_coverage(&p, &fields, &rows);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].content_type, ContentType::VideoTag);
+        assert_eq!(missing[0].content_id, 7);
+    }
+
+    #[test]
+    fn draft_policy_accepts_any_status() {
+        let mut p = policy(&["video_title"], &[SupportedLanguage::Ja]);
+        p.min_status = TranslationStatus::Draft;
+        let fields = [field(ContentType::Video, 1, "video_title", Some("인사"))];
+        let rows = [row(
+            ContentType::Video,
+            1,
+            "video_title",
+            SupportedLanguage::Ja,
+            TranslationStatus::Draft,
+        )];
+        assert!(evaluate_coverage(&p, &fields, &rows).is_empty());
+    }
+}
@@ -0,0 +1,409 @@
+//! 번역 QA 린트 (순수 함수 — DB 무관)
+//!
+//! service 가 원문·용어집을 모아 `check_translation` 을 호출하고, 결과는
+//! `content_translations.qa_*` 컬럼에 적재한다. Error 가 하나라도 남으면 approved 전환 불가.
+//!
+//! 검사 항목:
+//! - 용어집: 원문에 용어가 있으면 필수 번역어 포함 + 금지 변형 미포함 (Error)
+//! - 플레이스홀더 `{name}` / `{{name}}` / `%s` 집합 불일치 (Error)
+//! - 마크업 태그 집합 불일치 (Error)
+//! - 미번역 한국어: 원문 그대로 (Error) / 한글 비율 과다 (Warning)
+//! - 길이 폭증: 원문 대비 `LENGTH_BLOWUP_RATIO` 배 초과 (Warning)
+//! - 후행 공백 (Warning)
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::types::SupportedLanguage;
+
+use super::dto::TranslationGlossaryRes;
+
+/// 원문 대비 번역문 길이 배수 상한 (한→영 평균 2~2.5배, 여유 포함)
+pub const LENGTH_BLOWUP_RATIO: f64 = 4.0;
+/// 길이 폭증 검사 최소 원문 길이 (짧은 라벨은 비율 왜곡이 커서 제외)
+const LENGTH_BLOWUP_MIN_SOURCE_CHARS: usize = 5;
+/// 비한국어 번역문에서 한글이 차지하면 경고하는 문자 비율
+const HANGUL_RATIO_WARN: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum QaSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QaIssueCode {
+    GlossaryMissing,
+    GlossaryForbidden,
+    PlaceholderMismatch,
+    MarkupMismatch,
+    UntranslatedKorean,
+    LengthBlowup,
+    TrailingWhitespace,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct QaIssue {
+    pub code: QaIssueCode,
+    pub severity: QaSeverity,
+    pub message: String,
+}
+
+impl QaIssue {
+    fn error(code: QaIssueCode, message: String) -> Self {
+        Self {
+            code,
+            severity: QaSeverity::Error,
+            message,
+        }
+    }
+
+    fn warning(code: QaIssueCode, message: String) -> Self {
+        Self {
+            code,
+            severity: QaSeverity::Warning,
+            message,
+        }
+    }
+}
+
+/// QA 대상 1건
+pub struct QaInput<'a> {
+    pub lang: SupportedLanguage,
+    /// 번역 원천 텍스트 (guide_block 은 text_en, 그 외 한국어 원문)
+    pub source_text: Option<&'a str>,
+    /// 용어집 매칭용 한국어 원문 (guide_block 은 text_ko, 그 외 source_text 와 동일)
+    pub source_ko: Option<&'a str>,
+    pub translated_text: &'a str,
+}
+
+/// 번역 1건 QA. `glossary` 는 호출 측에서 `input.lang` 항목만 넘긴다.
+pub fn check_translation(input: &QaInput<'_>, glossary: &[TranslationGlossaryRes]) -> Vec<QaIssue> {
+    let mut issues = Vec::new();
+    let translated = input.translated_text;
+
+    check_glossary(input, glossary, &mut issues);
+
+    if let Some(source) = input.source_text {
+        let src_ph = placeholders(source);
+        let tr_ph = placeholders(translated);
+        if src_ph != tr_ph {
+            issues.push(QaIssue::error(
+                QaIssueCode::PlaceholderMismatch,
+                format!("placeholders differ: source {src_ph:?}, translation {tr_ph:?}"),
+            ));
+        }
+
+        let src_tags = markup_tags(source);
+        let tr_tags = markup_tags(translated);
+        if src_tags != tr_tags {
+            issues.push(QaIssue::error(
+                QaIssueCode::MarkupMismatch,
+                format!("markup differs: source {src_tags:?}, translation {tr_tags:?}"),
+            ));
+        }
+
+        let src_len = source.trim().chars().count();
+        let tr_len = translated.trim().chars().count();
+        if src_len >= LENGTH_BLOWUP_MIN_SOURCE_CHARS
+            && tr_len as f64 > src_len as f64 * LENGTH_BLOWUP_RATIO
+        {
+            issues.push(QaIssue::warning(
+                QaIssueCode::LengthBlowup,
+                format!("translation is {tr_len} chars for a {src_len}-char source"),
+            ));
+        }
+    }
+
+    if input.lang != SupportedLanguage::Ko {
+        check_untranslated_korean(input, &mut issues);
+    }
+
+    if translated != translated.trim_end() {
+        issues.push(QaIssue::warning(
+            QaIssueCode::TrailingWhitespace,
+            "translation ends with whitespace".to_string(),
+        ));
+    }
+
+    issues
+}
+
+/// (error_count, warning_count)
+pub fn count_by_severity(issues: &[QaIssue]) -> (i32, i32) {
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == QaSeverity::Error)
+        .count() as i32;
+    (errors, issues.len() as i32 - errors)
+}
+
+fn check_glossary(
+    input: &QaInput<'_>,
+    glossary: &[TranslationGlossaryRes],
+    issues: &mut Vec<QaIssue>,
+) {
+    let translated_lower = input.translated_text.to_lowercase();
+
+    for entry in glossary.iter().filter(|g| g.lang == input.lang) {
+        let in_source = [input.source_ko, input.source_text]
+            .iter()
+            .flatten()
+            .any(|s| s.contains(entry.term_ko.as_str()));
+        if !in_source {
+            continue;
+        }
+
+        let required = entry.required_translation.to_lowercase();
+        if !translated_lower.contains(&required) {
+            issues.push(QaIssue::error(
+                QaIssueCode::GlossaryMissing,
+                format!(
+                    "'{}' must be translated as '{}'",
+                    entry.term_ko, entry.required_translation
+                ),
+            ));
+        }
+
+        // 필수 번역어 안에 금지 변형이 포함되는 경우(예: subject ⊃ subj) 오탐 방지
+        let without_required = translated_lower.replace(&required, " ");
+        for variant in &entry.forbidden_variants {
+            let variant_lower = variant.to_lowercase();
+            if !variant_lower.is_empty() && without_required.contains(&variant_lower) {
+                issues.push(QaIssue::error(
+                    QaIssueCode::GlossaryForbidden,
+                    format!(
+                        "'{}' uses forbidden variant '{}' (use '{}')",
+                        entry.term_ko, variant, entry.required_translation
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn check_untranslated_korean(input: &QaInput<'_>, issues: &mut Vec<QaIssue>) {
+    let translated = input.translated_text.trim();
+    let identical_to_ko = input
+        .source_ko
+        .map(|ko| !ko.trim().is_empty() && ko.trim() == translated)
+        .unwrap_or(false);
+
+    if identical_to_ko && hangul_count(translated) > 0 {
+        issues.push(QaIssue::error(
+            QaIssueCode::UntranslatedKorean,
+            "translation is identical to the Korean source".to_string(),
+        ));
+        return;
+    }
+
+    let letters = translated.chars().filter(|c| c.is_alphabetic()).count();
+    if letters == 0 {
+        return;
+    }
+    let ratio = hangul_count(translated) as f64 / letters as f64;
+    if ratio > HANGUL_RATIO_WARN {
+        issues.push(QaIssue::warning(
+            QaIssueCode::UntranslatedKorean,
+            format!("{:.0}% of letters are Hangul", ratio * 100.0),
+        ));
+    }
+}
+
+fn hangul_count(s: &str) -> usize {
+    s.chars()
+        .filter(|c| matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}'))
+        .count()
+}
+
+/// 플레이스홀더 멀티셋 (정렬된 Vec)
+fn placeholders(s: &str) -> Vec<String> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(r"\{\{\s*[\w.]+\s*\}\}|\{[\w.]*\}|%(?:\d+\$)?[sdf@]").expect("placeholder regex")
+    });
+    let mut found: Vec<String> = re
+        .find_iter(s)
+        .map(|m| m.as_str().replace(' ', ""))
+        .collect();
+    found.sort();
+    found
+}
+
+/// 마크업 태그 멀티셋 — 속성 무시, `b` / `/b` 형태로 정규화
+fn markup_tags(s: &str) -> Vec<String> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(r"<(/?)([a-zA-Z][a-zA-Z0-9]*)\b[^<>]*?(/?)>").expect("markup regex")
+    });
+    let mut found: Vec<String> = re
+        .captures_iter(s)
+        .map(|c| format!("{}{}{}", &c[1], c[2].to_lowercase(), &c[3]))
+        .collect();
+    found.sort();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn glossary(
+        lang: SupportedLanguage,
+        term: &str,
+        required: &str,
+        forbidden: &[&str],
+    ) -> TranslationGlossaryRes {
+        TranslationGlossaryRes {
+            glossary_id: 1,
+            lang,
+            term_ko: term.to_string(),
+            required_translation: required.to_string(),
+            forbidden_variants: forbidden.iter().map(|s| s.to_string()).collect(),
+            glossary_note: None,
+            glossary_created_at: Utc::now(),
+            glossary_updated_at: Utc::now(),
+        }
+    }
+
+    fn input<'a>(source: &'a str, translated: &'a str) -> QaInput<'a> {
+        QaInput {
+            lang: SupportedLanguage::En,
+            source_text: Some(source),
+            source_ko: Some(source),
+            translated_text: translated,
+        }
+    }
+
+    fn codes(issues: &[QaIssue]) -> Vec<QaIssueCode> {
+        issues.iter().map(|i| i.code).collect()
+    }
+
+    #[test]
+    fn clean_translation_has_no_issues() {
+        let g = [glossary(
+            SupportedLanguage::En,
+            "주어",
+            "subject",
+            &["topic"],
+        )];
+        let issues = check_translation(&input("주어를 찾으세요", "Find the subject"), &g);
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn glossary_missing_required_translation_is_error() {
+        let g = [glossary(SupportedLanguage::En, "서술어", "predicate", &[])];
+        let issues = check_translation(&input("서술어를 찾으세요", "Find the verb"), &g);
+        assert_eq!(codes(&issues), vec![QaIssueCode::GlossaryMissing]);
+        assert_eq!(issues[0].severity, QaSeverity::Error);
+    }
+
+    #[test]
+    fn glossary_forbidden_variant_is_error() {
+        let g = [glossary(
+            SupportedLanguage::En,
+            "주어",
+            "subject",
+            &["topic"],
+        )];
+        let issues = check_translation(&input("주어", "subject (topic)"), &g);
+        assert_eq!(codes(&issues), vec![QaIssueCode::GlossaryForbidden]);
+    }
+
+    #[test]
+    fn glossary_variant_inside_required_term_is_not_flagged() {
+        let g = [glossary(
+            SupportedLanguage::En,
+            "주어",
+            "subject",
+            &["subj"],
+        )];
+        let issues = check_translation(&input("주어", "Subject"), &g);
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn glossary_ignores_terms_absent_from_source_and_other_languages() {
+        let g = [
+            glossary(SupportedLanguage::En, "부사어", "adverbial", &[]),
+            glossary(SupportedLanguage::Ja, "주어", "主語", &[]),
+        ];
+        let issues = check_translation(&input("주어", "subject"), &g);
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn placeholder_mismatch_is_error() {
+        let issues = check_translation(&input("{name}님 안녕하세요", "Hello {user}"), &[]);
+        assert_eq!(codes(&issues), vec![QaIssueCode::PlaceholderMismatch]);
+    }
+
+    #[test]
+    fn placeholder_order_does_not_matter() {
+        let issues = check_translation(&input("{a} 그리고 %s", "%s and {a}"), &[]);
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn markup_mismatch_is_error() {
+        let issues = check_translation(&input("<b>주어</b>입니다", "It is the subject"), &[]);
+        assert_eq!(codes(&issues), vec![QaIssueCode::MarkupMismatch]);
+    }
+
+    #[test]
+    fn markup_attributes_are_ignored() {
+        let issues = check_translation(
+            &input(
+                "<span class=\"a\">주어</span>",
+                "<SPAN class='x'>subject</SPAN>",
+            ),
+            &[],
+        );
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn identical_korean_is_error_and_hangul_heavy_is_warning() {
+        let issues = check_translation(&input("주어입니다", "주어입니다"), &[]);
+        assert_eq!(codes(&issues), vec![QaIssueCode::UntranslatedKorean]);
+        assert_eq!(issues[0].severity, QaSeverity::Error);
+
+        let issues = check_translation(&input("주어입니다", "주어입니다 ok"), &[]);
+        assert_eq!(codes(&issues), vec![QaIssueCode::UntranslatedKorean]);
+        assert_eq!(issues[0].severity, QaSeverity::Warning);
+    }
+
+    #[test]
+    fn hangul_is_allowed_for_korean_target() {
+        let mut i = input("주어", "주어");
+        i.lang = SupportedLanguage::Ko;
+        assert!(check_translation(&i, &[]).is_empty());
+    }
+
+    #[test]
+    fn length_blowup_is_warning() {
+        let long = "a".repeat(50);
+        let issues = check_translation(&input("짧은 원문이다", &long), &[]);
+        assert_eq!(codes(&issues), vec![QaIssueCode::LengthBlowup]);
+        assert_eq!(issues[0].severity, QaSeverity::Warning);
+    }
+
+    #[test]
+    fn trailing_whitespace_is_warning() {
+        let issues = check_translation(&input("주어", "subject "), &[]);
+        assert_eq!(codes(&issues), vec![QaIssueCode::TrailingWhitespace]);
+    }
+
+    #[test]
+    fn count_by_severity_splits_errors_and_warnings() {
+        let issues = check_translation(&input("<b>주어</b>", "<i>subject</i> "), &[]);
+        assert_eq!(count_by_severity(&issues), (1, 1));
+    }
+}
@@ -6,8 +6,10 @@ use crate::error::AppResult;
 use crate::types::{ContentType, SupportedLanguage, TranslationStatus};
 
 use super::dto::{
-    ContentRecordItem, SourceFieldItem, TranslatedField, TranslationRes, TranslationSearchItem,
+    ContentRecordItem, PublishPolicyRes, SourceFieldItem, TranslatedField, TranslationGlossaryRes,
+    TranslationQaStatItem, TranslationRes, TranslationSearchItem,
 };
+use super::publish::CoverageRow;
 
 /// 번역 목록 쿼리 파라미터
 pub struct TranslationListQuery {
@@ -45,10 +47,28 @@ impl TranslationRepo {
                     THEN content_translations.status
                     ELSE 'draft'
                 END,
+                qa_error_count = CASE
+                    WHEN content_translations.translated_text = EXCLUDED.translated_text
+                    THEN content_translations.qa_error_count
+                END,
+                qa_warning_count = CASE
+                    WHEN content_translations.translated_text = EXCLUDED.translated_text
+                    THEN content_translations.qa_warning_count
+                END,
+                qa_issues = CASE
+                    WHEN content_translations.translated_text = EXCLUDED.translated_text
+                    THEN content_translations.qa_issues
+                END,
+                qa_checked_at = CASE
+                    WHEN content_translations.translated_text = EXCLUDED.translated_text
+                    THEN content_translations.qa_checked_at
+                END,
                 updated_at = NOW()
             RETURNING
                 translation_id, content_type, content_id, field_name,
-                lang, translated_text, status, created_at, updated_at
+                lang, translated_text, status,
+                qa_error_count, qa_warning_count, qa_checked_at,
+                created_at, updated_at
             "#,
         )
         .bind(content_type)
@@ -71,7 +91,9 @@ impl TranslationRepo {
             r#"
             SELECT
                 translation_id, content_type, content_id, field_name,
-                lang, translated_text, status, created_at, updated_at
+                lang, translated_text, status,
+                qa_error_count, qa_warning_count, qa_checked_at,
+                created_at, updated_at
             FROM content_translations
             WHERE translation_id = $1
             "#,
@@ -138,7 +160,9 @@ impl TranslationRepo {
             r#"
             SELECT
                 translation_id, content_type, content_id, field_name,
-                lang, translated_text, status, created_at, updated_at
+                lang, translated_text, status,
+                qa_error_count, qa_warning_count, qa_checked_at,
+                created_at, updated_at
             FROM content_translations
             WHERE (
                 CASE
@@ -181,11 +205,26 @@ impl TranslationRepo {
             SET
                 translated_text = COALESCE($2, translated_text),
                 status = COALESCE($3, status),
+                -- 번역문이 바뀌면 QA 결과 무효화 (재검사 전까지 미검사 취급)
+                qa_error_count = CASE
+                    WHEN $2 IS NULL OR $2 = translated_text THEN qa_error_count
+                END,
+                qa_warning_count = CASE
+                    WHEN $2 IS NULL OR $2 = translated_text THEN qa_warning_count
+                END,
+                qa_issues = CASE
+                    WHEN $2 IS NULL OR $2 = translated_text THEN qa_issues
+                END,
+                qa_checked_at = CASE
+                    WHEN $2 IS NULL OR $2 = translated_text THEN qa_checked_at
+                END,
                 updated_at = NOW()
             WHERE translation_id = $1
             RETURNING
                 translation_id, content_type, content_id, field_name,
-                lang, translated_text, status, created_at, updated_at
+                lang, translated_text, status,
+                qa_error_count, qa_warning_count, qa_checked_at,
+                created_at, updated_at
             "#,
         )
         .bind(translation_id)
@@ -210,7 +249,9 @@ impl TranslationRepo {
             WHERE translation_id = $1
             RETURNING
                 translation_id, content_type, content_id, field_name,
-                lang, translated_text, status, created_at, updated_at
+                lang, translated_text, status,
+                qa_error_count, qa_warning_count, qa_checked_at,
+                created_at, updated_at
             "#,
         )
         .bind(translation_id)
@@ -383,6 +424,22 @@ impl TranslationRepo {
                 .fetch_all(pool)
                 .await?
             }
+            ContentType::VideoSubtitleCue => {
+                sqlx::query_as::<_, ContentRecordItem>(
+                    r#"
+                    SELECT
+                        c.cue_id AS id,
+                        CONCAT('Video#', s.video_id, ' Cue#', c.cue_seq) AS label,
+                        LEFT(c.cue_text, 50) AS detail
+                    FROM video_subtitle s
+                    JOIN video_subtitle_cue c ON c.video_subtitle_id = s.video_subtitle_id
+                    WHERE s.lang = 'ko'
+                    ORDER BY s.video_id, c.cue_seq
+                    "#,
+                )
+                .fetch_all(pool)
+                .await?
+            }
             // VideoTag — 직접 선택하지 않음 (Video 내부에서 처리)
             _ => Vec::new(),
         };
@@ -399,166 +456,182 @@ impl TranslationRepo {
         pool: &PgPool,
         content_type: ContentType,
         content_id: i64,
+    ) -> AppResult<Vec<SourceFieldItem>> {
+        Self::find_source_fields_batch(pool, content_type, &[content_id]).await
+    }
+
+    /// 같은 content_type 의 여러 콘텐츠 원본 필드 일괄 조회 (QA 스캔 시 콘텐츠별 N+1 회피)
+    pub async fn find_source_fields_batch(
+        pool: &PgPool,
+        content_type: ContentType,
+        content_ids: &[i64],
     ) -> AppResult<Vec<SourceFieldItem>> {
         let mut fields = Vec::new();
+        if content_ids.is_empty() {
+            return Ok(fields);
+        }
+
+        let mut push = |content_type: ContentType,
+                        content_id: i64,
+                        name: &str,
+                        source_text: Option<String>| {
+            fields.push(SourceFieldItem {
+                content_type,
+                content_id,
+                field_name: name.to_string(),
+                source_text,
+            });
+        };
 
         match content_type {
             ContentType::Course => {
-                let row = sqlx::query_as::<_, CourseSourceRow>(
+                let rows = sqlx::query_as::<_, CourseSourceRow>(
                     r#"
-                    SELECT course_idx, course_title, course_subtitle, course_description
-                    FROM course WHERE course_id = $1
+                    SELECT course_id::bigint AS content_id,
+                           course_idx, course_title, course_subtitle, course_description
+                    FROM course WHERE course_id = ANY($1)
+                    ORDER BY course_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("course_idx", Some(r.course_idx)),
                         ("course_title", Some(r.course_title)),
                         ("course_subtitle", r.course_subtitle),
                         ("course_description", r.course_description),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::Course,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::Course, r.content_id, name, text);
                     }
                 }
             }
             ContentType::Video => {
                 // Q1c B: video 테이블의 title/subtitle 물리 컬럼 추가 이후 실 컬럼 매핑.
                 // Gemini 3차 리뷰 반영: video_idx 는 consumer 번역 로직 대상 아님 → 제외.
-                let row = sqlx::query_as::<_, VideoSourceRow>(
-                    r#"SELECT video_title, video_subtitle FROM video WHERE video_id = $1"#,
+                let rows = sqlx::query_as::<_, VideoSourceRow>(
+                    r#"
+                    SELECT video_id::bigint AS content_id, video_title, video_subtitle
+                    FROM video WHERE video_id = ANY($1)
+                    ORDER BY video_id
+                    "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("video_title", Some(r.video_title)),
                         ("video_subtitle", r.video_subtitle),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::Video,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::Video, r.content_id, name, text);
                     }
                 }
 
-                // video에 연결된 video_tag 필드들
+                // video에 연결된 video_tag 필드들 (여러 video 가 공유하는 태그는 한 번만)
                 let tags = sqlx::query_as::<_, VideoTagSourceRow>(
                     r#"
-                    SELECT vt.video_tag_id::bigint, vt.video_tag_key, vt.video_tag_title, vt.video_tag_subtitle
+                    SELECT DISTINCT vt.video_tag_id::bigint, vt.video_tag_key, vt.video_tag_title, vt.video_tag_subtitle
                     FROM video_tag vt
                     JOIN video_tag_map vtm ON vtm.video_tag_id = vt.video_tag_id
-                    WHERE vtm.video_id = $1
-                    ORDER BY vt.video_tag_id
+                    WHERE vtm.video_id = ANY($1)
+                    ORDER BY vt.video_tag_id::bigint
                     "#,
                 )
-                .bind(content_id)
+                .bind(content_ids)
                 .fetch_all(pool)
                 .await?;
 
                 for tag in tags {
-                    fields.push(SourceFieldItem {
-                        content_type: ContentType::VideoTag,
-                        content_id: tag.video_tag_id,
-                        field_name: "video_tag_key".to_string(),
-                        source_text: Some(tag.video_tag_key),
-                    });
-                    fields.push(SourceFieldItem {
-                        content_type: ContentType::VideoTag,
-                        content_id: tag.video_tag_id,
-                        field_name: "video_tag_title".to_string(),
-                        source_text: Some(tag.video_tag_title),
-                    });
-                    fields.push(SourceFieldItem {
-                        content_type: ContentType::VideoTag,
-                        content_id: tag.video_tag_id,
-                        field_name: "video_tag_subtitle".to_string(),
-                        source_text: tag.video_tag_subtitle,
-                    });
+                    push(
+                        ContentType::VideoTag,
+                        tag.video_tag_id,
+                        "video_tag_key",
+                        Some(tag.video_tag_key),
+                    );
+                    push(
+                        ContentType::VideoTag,
+                        tag.video_tag_id,
+                        "video_tag_title",
+                        Some(tag.video_tag_title),
+                    );
+                    push(
+                        ContentType::VideoTag,
+                        tag.video_tag_id,
+                        "video_tag_subtitle",
+                        tag.video_tag_subtitle,
+                    );
                 }
             }
             ContentType::Lesson => {
-                let row = sqlx::query_as::<_, LessonSourceRow>(
+                let rows = sqlx::query_as::<_, LessonSourceRow>(
                     r#"
-                    SELECT lesson_idx, lesson_title, lesson_subtitle, lesson_description
-                    FROM lesson WHERE lesson_id = $1
+                    SELECT lesson_id::bigint AS content_id,
+                           lesson_idx, lesson_title, lesson_subtitle, lesson_description
+                    FROM lesson WHERE lesson_id = ANY($1)
+                    ORDER BY lesson_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("lesson_idx", Some(r.lesson_idx)),
                         ("lesson_title", Some(r.lesson_title)),
                         ("lesson_subtitle", r.lesson_subtitle),
                         ("lesson_description", r.lesson_description),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::Lesson,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::Lesson, r.content_id, name, text);
                     }
                 }
             }
             ContentType::Study => {
-                let row = sqlx::query_as::<_, StudySourceRow>(
+                let rows = sqlx::query_as::<_, StudySourceRow>(
                     r#"
-                    SELECT study_idx, study_title, study_subtitle, study_description
-                    FROM study WHERE study_id = $1
+                    SELECT study_id::bigint AS content_id,
+                           study_idx, study_title, study_subtitle, study_description
+                    FROM study WHERE study_id = ANY($1)
+                    ORDER BY study_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("study_idx", Some(r.study_idx)),
                         ("study_title", Some(r.study_title)),
                         ("study_subtitle", r.study_subtitle),
                         ("study_description", r.study_description),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::Study,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::Study, r.content_id, name, text);
                     }
                 }
             }
             ContentType::StudyTaskChoice => {
-                let row = sqlx::query_as::<_, ChoiceSourceRow>(
+                let rows = sqlx::query_as::<_, ChoiceSourceRow>(
                     r#"
                     SELECT
+                        study_task_id::bigint AS content_id,
                         study_task_choice_question,
                         study_task_choice_1, study_task_choice_2,
                         study_task_choice_3, study_task_choice_4,
                         study_task_choice_answer
-                    FROM study_task_choice WHERE study_task_id = $1
+                    FROM study_task_choice WHERE study_task_id = ANY($1)
+                    ORDER BY study_task_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         (
                             "study_task_choice_question",
@@ -573,103 +646,90 @@ impl TranslationRepo {
                             Some(r.study_task_choice_answer.to_string()),
                         ),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::StudyTaskChoice,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::StudyTaskChoice, r.content_id, name, text);
                     }
                 }
             }
             ContentType::StudyTaskTyping => {
-                let row = sqlx::query_as::<_, TypingSourceRow>(
+                let rows = sqlx::query_as::<_, TypingSourceRow>(
                     r#"
-                    SELECT study_task_typing_question, study_task_typing_answer
-                    FROM study_task_typing WHERE study_task_id = $1
+                    SELECT study_task_id::bigint AS content_id,
+                           study_task_typing_question, study_task_typing_answer
+                    FROM study_task_typing WHERE study_task_id = ANY($1)
+                    ORDER BY study_task_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("study_task_typing_question", r.study_task_typing_question),
                         ("study_task_typing_answer", r.study_task_typing_answer),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::StudyTaskTyping,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::StudyTaskTyping, r.content_id, name, text);
                     }
                 }
             }
             ContentType::StudyTaskVoice => {
-                let row = sqlx::query_as::<_, VoiceSourceRow>(
+                let rows = sqlx::query_as::<_, VoiceSourceRow>(
                     r#"
-                    SELECT study_task_voice_question, study_task_voice_answer
-                    FROM study_task_voice WHERE study_task_id = $1
+                    SELECT study_task_id::bigint AS content_id,
+                           study_task_voice_question, study_task_voice_answer
+                    FROM study_task_voice WHERE study_task_id = ANY($1)
+                    ORDER BY study_task_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("study_task_voice_question", r.study_task_voice_question),
                         ("study_task_voice_answer", r.study_task_voice_answer),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::StudyTaskVoice,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::StudyTaskVoice, r.content_id, name, text);
                     }
                 }
             }
             ContentType::StudyTaskExplain => {
-                let row = sqlx::query_as::<_, ExplainSourceRow>(
+                let rows = sqlx::query_as::<_, ExplainSourceRow>(
                     r#"
-                    SELECT explain_title, explain_text
+                    SELECT study_task_id::bigint AS content_id, explain_title, explain_text
                     FROM study_explain
-                    WHERE study_task_id = $1 AND explain_lang = 'ko'
+                    WHERE study_task_id = ANY($1) AND explain_lang = 'ko'
+                    ORDER BY study_task_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         ("explain_title", r.explain_title),
                         ("explain_text", r.explain_text),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::StudyTaskExplain,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::StudyTaskExplain, r.content_id, name, text);
                     }
                 }
             }
             ContentType::StudyTaskWriting => {
-                let row = sqlx::query_as::<_, WritingSourceRow>(
+                let rows = sqlx::query_as::<_, WritingSourceRow>(
                     r#"
-                    SELECT study_task_writing_prompt, study_task_writing_answer, study_task_writing_hint
-                    FROM study_task_writing WHERE study_task_id = $1
+                    SELECT study_task_id::bigint AS content_id,
+                           study_task_writing_prompt, study_task_writing_answer, study_task_writing_hint
+                    FROM study_task_writing WHERE study_task_id = ANY($1)
+                    ORDER BY study_task_id
                     "#,
                 )
-                .bind(content_id)
-                .fetch_optional(pool)
+                .bind(content_ids)
+                .fetch_all(pool)
                 .await?;
 
-                if let Some(r) = row {
+                for r in rows {
                     for (name, text) in [
                         (
                             "study_task_writing_prompt",
@@ -681,15 +741,51 @@ impl TranslationRepo {
                         ),
                         ("study_task_writing_hint", r.study_task_writing_hint),
                     ] {
-                        fields.push(SourceFieldItem {
-                            content_type: ContentType::StudyTaskWriting,
-                            content_id,
-                            field_name: name.to_string(),
-                            source_text: text,
-                        });
+                        push(ContentType::StudyTaskWriting, r.content_id, name, text);
                     }
                 }
             }
+            ContentType::GuideBlock => {
+                // guide 블록 번역 원천 = text_en (field_name 'text' 단일)
+                let rows = sqlx::query_as::<_, GuideBlockSourceRow>(
+                    r#"
+                    SELECT guide_block_id, text_en, text_ko
+                    FROM guide_block WHERE guide_block_id = ANY($1)
+                    ORDER BY guide_block_id
+                    "#,
+                )
+                .bind(content_ids)
+                .fetch_all(pool)
+                .await?;
+
+                for r in rows {
+                    push(ContentType::GuideBlock, r.guide_block_id, "text", r.text_en);
+                }
+            }
+            ContentType::VideoSubtitleCue => {
+                // ko 자막 트랙 큐만 번역 원천 (field_name 'cue_text' 단일)
+                let rows = sqlx::query_as::<_, CueSourceRow>(
+                    r#"
+                    SELECT c.cue_id AS content_id, c.cue_text
+                    FROM video_subtitle_cue c
+                    JOIN video_subtitle s ON s.video_subtitle_id = c.video_subtitle_id
+                    WHERE c.cue_id = ANY($1) AND s.lang = 'ko'
+                    ORDER BY c.cue_id
+                    "#,
+                )
+                .bind(content_ids)
+                .fetch_all(pool)
+                .await?;
+
+                for r in rows {
+                    push(
+                        ContentType::VideoSubtitleCue,
+                        r.content_id,
+                        "cue_text",
+                        Some(r.cue_text),
+                    );
+                }
+            }
             _ => {} // VideoTag — 직접 호출되지 않음 (Video 내부에서 집계)
         }
 
@@ -746,6 +842,360 @@ impl TranslationRepo {
         Ok(rows)
     }
 
+    /// content_type × lang 별 QA 결과 집계
+    pub async fn find_qa_stats(pool: &PgPool) -> AppResult<Vec<TranslationQaStatItem>> {
+        let rows = sqlx::query_as::<_, TranslationQaStatItem>(
+            r#"
+            SELECT
+                content_type,
+                lang,
+                COUNT(*) FILTER (WHERE qa_error_count > 0) AS error_items,
+                COUNT(*) FILTER (
+                    WHERE qa_error_count = 0 AND qa_warning_count > 0
+                ) AS warning_items,
+                COUNT(*) FILTER (WHERE qa_checked_at IS NULL) AS unchecked_items
+            FROM content_translations
+            GROUP BY content_type, lang
+            ORDER BY content_type, lang
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    // =========================================================================
+    // 용어집 (Glossary)
+    // =========================================================================
+
+    /// 용어집 목록 (lang 필터 선택)
+    pub async fn find_glossary(
+        pool: &PgPool,
+        lang: Option<SupportedLanguage>,
+    ) -> AppResult<Vec<TranslationGlossaryRes>> {
+        let rows = sqlx::query_as::<_, TranslationGlossaryRes>(
+            r#"
+            SELECT
+                glossary_id, lang, term_ko, required_translation, forbidden_variants,
+                glossary_note, glossary_created_at, glossary_updated_at
+            FROM translation_glossary
+            WHERE ($1::supported_language_enum IS NULL OR lang = $1)
+            ORDER BY lang, term_ko
+            "#,
+        )
+        .bind(lang)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// 용어집 항목 UPSERT (lang + term_ko 기준)
+    pub async fn upsert_glossary(
+        pool: &PgPool,
+        lang: SupportedLanguage,
+        term_ko: &str,
+        required_translation: &str,
+        forbidden_variants: &[String],
+        glossary_note: Option<&str>,
+        actor_user_id: i64,
+    ) -> AppResult<TranslationGlossaryRes> {
+        let row = sqlx::query_as::<_, TranslationGlossaryRes>(
+            r#"
+            INSERT INTO translation_glossary
+                (lang, term_ko, required_translation, forbidden_variants,
+                 glossary_note, updated_by_user_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (lang, term_ko)
+            DO UPDATE SET
+                required_translation = EXCLUDED.required_translation,
+                forbidden_variants = EXCLUDED.forbidden_variants,
+                glossary_note = EXCLUDED.glossary_note,
+                updated_by_user_id = EXCLUDED.updated_by_user_id,
+                glossary_updated_at = NOW()
+            RETURNING
+                glossary_id, lang, term_ko, required_translation, forbidden_variants,
+                glossary_note, glossary_created_at, glossary_updated_at
+            "#,
+        )
+        .bind(lang)
+        .bind(term_ko)
+        .bind(required_translation)
+        .bind(forbidden_variants)
+        .bind(glossary_note)
+        .bind(actor_user_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// 용어집 항목 수정
+    pub async fn update_glossary(
+        pool: &PgPool,
+        glossary_id: i64,
+        required_translation: Option<&str>,
+        forbidden_variants: Option<&[String]>,
+        glossary_note: Option<&str>,
+        actor_user_id: i64,
+    ) -> AppResult<Option<TranslationGlossaryRes>> {
+        let row = sqlx::query_as::<_, TranslationGlossaryRes>(
+            r#"
+            UPDATE translation_glossary
+            SET
+                required_translation = COALESCE($2, required_translation),
+                forbidden_variants = COALESCE($3, forbidden_variants),
+                glossary_note = COALESCE($4, glossary_note),
+                updated_by_user_id = $5,
+                glossary_updated_at = NOW()
+            WHERE glossary_id = $1
+            RETURNING
+                glossary_id, lang, term_ko, required_translation, forbidden_variants,
+                glossary_note, glossary_created_at, glossary_updated_at
+            "#,
+        )
+        .bind(glossary_id)
+        .bind(required_translation)
+        .bind(forbidden_variants)
+        .bind(glossary_note)
+        .bind(actor_user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// 용어집 항목 삭제
+    pub async fn delete_glossary(pool: &PgPool, glossary_id: i64) -> AppResult<bool> {
+        let result = sqlx::query(r#"DELETE FROM translation_glossary WHERE glossary_id = $1"#)
+            .bind(glossary_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // =========================================================================
+    // 번역 QA
+    // =========================================================================
+
+    /// QA 대상 번역 조회 (lang 필수, content_type/content_id 로 범위 축소)
+    pub async fn find_qa_targets(
+        pool: &PgPool,
+        lang: SupportedLanguage,
+        content_type: Option<ContentType>,
+        content_id: Option<i64>,
+    ) -> AppResult<Vec<QaTargetRow>> {
+        let rows = sqlx::query_as::<_, QaTargetRow>(
+            r#"
+            SELECT translation_id, content_type, content_id, field_name, lang, translated_text, status
+            FROM content_translations
+            WHERE lang = $1
+              AND ($2::content_type_enum IS NULL OR content_type = $2)
+              AND ($3::bigint IS NULL OR content_id = $3)
+            ORDER BY content_type, content_id, field_name
+            "#,
+        )
+        .bind(lang)
+        .bind(content_type)
+        .bind(content_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// 번역 1건의 QA 대상 행
+    pub async fn find_qa_target_by_id(
+        pool: &PgPool,
+        translation_id: i64,
+    ) -> AppResult<Option<QaTargetRow>> {
+        let row = sqlx::query_as::<_, QaTargetRow>(
+            r#"
+            SELECT translation_id, content_type, content_id, field_name, lang, translated_text, status
+            FROM content_translations
+            WHERE translation_id = $1
+            "#,
+        )
+        .bind(translation_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// guide 블록 원문 일괄 조회 (14k 블록 스캔 시 N+1 회피)
+    /// 반환: guide_block_id → (text_en, text_ko)
+    pub async fn find_guide_block_sources(
+        pool: &PgPool,
+        block_ids: &[i64],
+    ) -> AppResult<HashMap<i64, (Option<String>, Option<String>)>> {
+        if block_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = sqlx::query_as::<_, GuideBlockSourceRow>(
+            r#"
+            SELECT guide_block_id, text_en, text_ko
+            FROM guide_block
+            WHERE guide_block_id = ANY($1)
+            "#,
+        )
+        .bind(block_ids)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.guide_block_id, (r.text_en, r.text_ko)))
+            .collect())
+    }
+
+    /// QA 결과 일괄 적재 (UNNEST — 언어 전체 스캔 시 행별 UPDATE 회피)
+    pub async fn save_qa_results(
+        pool: &PgPool,
+        translation_ids: &[i64],
+        error_counts: &[i32],
+        warning_counts: &[i32],
+        issues: &[serde_json::Value],
+    ) -> AppResult<()> {
+        if translation_ids.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE content_translations ct
+            SET qa_error_count = r.error_count,
+                qa_warning_count = r.warning_count,
+                qa_issues = r.issues,
+                qa_checked_at = NOW()
+            FROM UNNEST($1::bigint[], $2::int[], $3::int[], $4::jsonb[])
+                AS r(translation_id, error_count, warning_count, issues)
+            WHERE ct.translation_id = r.translation_id
+            "#,
+        )
+        .bind(translation_ids)
+        .bind(error_counts)
+        .bind(warning_counts)
+        .bind(issues)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // 공개(open) 전 번역 커버리지 정책
+    // =========================================================================
+
+    /// 공개 정책 전체
+    pub async fn find_publish_policies(pool: &PgPool) -> AppResult<Vec<PublishPolicyRes>> {
+        let rows = sqlx::query_as::<_, PublishPolicyRes>(
+            r#"
+            SELECT
+                policy_content_type, required_langs, required_fields,
+                min_status, policy_enabled, policy_updated_at
+            FROM content_publish_policy
+            ORDER BY policy_content_type
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// 콘텐츠 타입별 공개 정책 (없으면 정책 미적용)
+    pub async fn find_publish_policy(
+        pool: &PgPool,
+        content_type: ContentType,
+    ) -> AppResult<Option<PublishPolicyRes>> {
+        let row = sqlx::query_as::<_, PublishPolicyRes>(
+            r#"
+            SELECT
+                policy_content_type, required_langs, required_fields,
+                min_status, policy_enabled, policy_updated_at
+            FROM content_publish_policy
+            WHERE policy_content_type = $1
+            "#,
+        )
+        .bind(content_type)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// 공개 정책 수정 (None 이면 기존 값 유지)
+    pub async fn update_publish_policy(
+        pool: &PgPool,
+        content_type: ContentType,
+        required_langs: Option<&[SupportedLanguage]>,
+        required_fields: Option<&[String]>,
+        min_status: Option<TranslationStatus>,
+        policy_enabled: Option<bool>,
+        actor_user_id: i64,
+    ) -> AppResult<Option<PublishPolicyRes>> {
+        let row = sqlx::query_as::<_, PublishPolicyRes>(
+            r#"
+            UPDATE content_publish_policy
+            SET
+                required_langs = COALESCE($2, required_langs),
+                required_fields = COALESCE($3, required_fields),
+                min_status = COALESCE($4, min_status),
+                policy_enabled = COALESCE($5, policy_enabled),
+                updated_by_user_id = $6,
+                policy_updated_at = NOW()
+            WHERE policy_content_type = $1
+            RETURNING
+                policy_content_type, required_langs, required_fields,
+                min_status, policy_enabled, policy_updated_at
+            "#,
+        )
+        .bind(content_type)
+        .bind(required_langs)
+        .bind(required_fields)
+        .bind(min_status)
+        .bind(policy_enabled)
+        .bind(actor_user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// 커버리지 판정용 번역 조회 — (content_type, content_id) 쌍 × 언어
+    pub async fn find_coverage_rows(
+        pool: &PgPool,
+        contents: &[(ContentType, i64)],
+        langs: &[SupportedLanguage],
+    ) -> AppResult<Vec<CoverageRow>> {
+        if contents.is_empty() || langs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (types, ids): (Vec<ContentType>, Vec<i64>) = contents.iter().copied().unzip();
+
+        let rows = sqlx::query_as::<_, CoverageRow>(
+            r#"
+            SELECT ct.content_type, ct.content_id, ct.field_name, ct.lang, ct.status
+            FROM content_translations ct
+            JOIN UNNEST($1::content_type_enum[], $2::bigint[]) AS c(content_type, content_id)
+              ON c.content_type = ct.content_type AND c.content_id = ct.content_id
+            WHERE ct.lang = ANY($3)
+            "#,
+        )
+        .bind(&types)
+        .bind(&ids)
+        .bind(langs)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
     // =========================================================================
     // 공용 번역 조회 (기존 도메인 API에서 fallback 패턴으로 사용)
     // =========================================================================
@@ -806,6 +1256,18 @@ impl TranslationRepo {
     }
 }
 
+/// QA 대상 번역 행
+#[derive(Debug, sqlx::FromRow)]
+pub struct QaTargetRow {
+    pub translation_id: i64,
+    pub content_type: ContentType,
+    pub content_id: i64,
+    pub field_name: String,
+    pub lang: SupportedLanguage,
+    pub translated_text: String,
+    pub status: TranslationStatus,
+}
+
 /// 내부 쿼리용 행
 #[derive(Debug, sqlx::FromRow)]
 struct TranslationRow {
@@ -821,6 +1283,7 @@ struct TranslationRow {
 
 #[derive(Debug, sqlx::FromRow)]
 struct VideoSourceRow {
+    content_id: i64,
     video_title: String,
     video_subtitle: Option<String>,
 }
@@ -835,6 +1298,7 @@ struct VideoTagSourceRow {
 
 #[derive(Debug, sqlx::FromRow)]
 struct LessonSourceRow {
+    content_id: i64,
     lesson_idx: String,
     lesson_title: String,
     lesson_subtitle: Option<String>,
@@ -843,6 +1307,7 @@ struct LessonSourceRow {
 
 #[derive(Debug, sqlx::FromRow)]
 struct StudySourceRow {
+    content_id: i64,
     study_idx: String,
     study_title: String,
     study_subtitle: Option<String>,
@@ -851,6 +1316,7 @@ struct StudySourceRow {
 
 #[derive(Debug, sqlx::FromRow)]
 struct ChoiceSourceRow {
+    content_id: i64,
     study_task_choice_question: String,
     study_task_choice_1: String,
     study_task_choice_2: String,
@@ -861,32 +1327,50 @@ struct ChoiceSourceRow {
 
 #[derive(Debug, sqlx::FromRow)]
 struct TypingSourceRow {
+    content_id: i64,
     study_task_typing_question: Option<String>,
     study_task_typing_answer: Option<String>,
 }
 
 #[derive(Debug, sqlx::FromRow)]
 struct VoiceSourceRow {
+    content_id: i64,
     study_task_voice_question: Option<String>,
     study_task_voice_answer: Option<String>,
 }
 
 #[derive(Debug, sqlx::FromRow)]
 struct ExplainSourceRow {
+    content_id: i64,
     explain_title: Option<String>,
     explain_text: Option<String>,
 }
 
 #[derive(Debug, sqlx::FromRow)]
 struct CourseSourceRow {
+    content_id: i64,
     course_idx: String,
     course_title: String,
     course_subtitle: Option<String>,
     course_description: Option<String>,
 }
 
+#[derive(Debug, sqlx::FromRow)]
+struct GuideBlockSourceRow {
+    guide_block_id: i64,
+    text_en: Option<String>,
+    text_ko: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CueSourceRow {
+    content_id: i64,
+    cue_text: String,
+}
+
 #[derive(Debug, sqlx::FromRow)]
 struct WritingSourceRow {
+    content_id: i64,
     study_task_writing_prompt: String,
     study_task_writing_answer: String,
     study_task_writing_hint: Option<String>,
@@ -6,9 +6,12 @@ use axum::{
 use crate::state::AppState;
 
 use super::handler::{
-    admin_bulk_create_translations, admin_create_translation, admin_delete_translation,
-    admin_get_source_fields, admin_get_translation, admin_get_translation_stats,
-    admin_list_content_records, admin_list_translations, admin_search_translations,
+    admin_bulk_create_translations, admin_create_glossary, admin_create_translation,
+    admin_delete_glossary, admin_delete_translation, admin_get_publish_coverage,
+    admin_get_source_fields, admin_get_translation, admin_get_translation_qa,
+    admin_get_translation_stats, admin_list_content_records, admin_list_glossary,
+    admin_list_publish_policies, admin_list_translations, admin_scan_translation_qa,
+    admin_search_translations, admin_update_glossary, admin_update_publish_policy,
     admin_update_translation, admin_update_translation_status,
 };
 
@@ -23,6 +26,21 @@ pub fn admin_translation_router() -> Router<AppState> {
         .route("/source-fields", get(admin_get_source_fields))
         .route("/search", get(admin_search_translations))
         .route("/stats", get(admin_get_translation_stats))
+        .route(
+            "/glossary",
+            get(admin_list_glossary).post(admin_create_glossary),
+        )
+        .route(
+            "/glossary/{id}",
+            patch(admin_update_glossary).delete(admin_delete_glossary),
+        )
+        .route("/qa/scan", post(admin_scan_translation_qa))
+        .route("/publish-policies", get(admin_list_publish_policies))
+        .route(
+            "/publish-policies/{content_type}",
+            patch(admin_update_publish_policy),
+        )
+        .route("/publish-coverage", get(admin_get_publish_coverage))
         .route(
             "/{id}",
             get(admin_get_translation)
@@ -30,4 +48,5 @@ pub fn admin_translation_router() -> Router<AppState> {
                 .delete(admin_delete_translation),
         )
         .route("/{id}/status", patch(admin_update_translation_status))
+        .route("/{id}/qa", get(admin_get_translation_qa))
 }
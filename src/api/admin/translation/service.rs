@@ -1,16 +1,26 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+
 use sqlx::PgPool;
 use validator::Validate;
 
 use crate::error::{AppError, AppResult};
+use crate::state::AppState;
+use crate::types::{ContentType, SupportedLanguage, TranslationStatus, UserAuth};
 
 use super::dto::{
-    ContentRecordsReq, ContentRecordsRes, SourceFieldsReq, SourceFieldsRes,
-    TranslationBulkCreateReq, TranslationBulkCreateRes, TranslationBulkItemResult,
-    TranslationCreateReq, TranslationListMeta, TranslationListReq, TranslationListRes,
-    TranslationRes, TranslationSearchReq, TranslationSearchRes, TranslationStatsRes,
-    TranslationStatusReq, TranslationUpdateReq,
+    ContentRecordsReq, ContentRecordsRes, GlossaryCreateReq, GlossaryListReq, GlossaryListRes,
+    GlossaryUpdateReq, PublishCoverageRes, PublishPolicyListRes, PublishPolicyRes,
+    PublishPolicyUpdateReq, SourceFieldsReq, SourceFieldsRes, TranslationBulkCreateReq,
+    TranslationBulkCreateRes, TranslationBulkItemResult, TranslationCreateReq,
+    TranslationGlossaryRes, TranslationListMeta, TranslationListReq, TranslationListRes,
+    TranslationQaItem, TranslationQaScanReq, TranslationQaScanRes, TranslationRes,
+    TranslationSearchReq, TranslationSearchRes, TranslationStatsRes, TranslationStatusReq,
+    TranslationUpdateReq,
 };
-use super::repo::TranslationRepo;
+use super::publish;
+use super::qa::{self, QaInput};
+use super::repo::{QaTargetRow, TranslationRepo};
 
 pub struct TranslationService;
 
@@ -144,6 +154,9 @@ impl TranslationService {
     }
 
     /// 번역 수정
+    ///
+    /// 수정 후 approved 로 남는 번역은 모두 QA 를 통과해야 한다 — approved 번역의 문구만
+    /// 바꾸는 요청도 승인 게이트를 거친다.
     pub async fn update_translation(
         pool: &PgPool,
         translation_id: i64,
@@ -151,14 +164,28 @@ impl TranslationService {
     ) -> AppResult<TranslationRes> {
         req.validate().map_err(AppError::Validation)?;
 
-        TranslationRepo::update_one(
+        let mut target = TranslationRepo::find_qa_target_by_id(pool, translation_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let status_after = req.status.unwrap_or(target.status);
+        let changes_approved = req.status.is_some() || req.translated_text.is_some();
+        if status_after == TranslationStatus::Approved && changes_approved {
+            if let Some(text) = &req.translated_text {
+                target.translated_text = text.clone();
+            }
+            Self::ensure_qa_passes(pool, target).await?;
+        }
+
+        let res = TranslationRepo::update_one(
             pool,
             translation_id,
             req.translated_text.as_deref(),
             req.status,
         )
         .await?
-        .ok_or(AppError::NotFound)
+        .ok_or(AppError::NotFound)?;
+
+        Ok(res)
     }
 
     /// 번역 상태 변경
@@ -167,6 +194,13 @@ impl TranslationService {
         translation_id: i64,
         req: TranslationStatusReq,
     ) -> AppResult<TranslationRes> {
+        if req.status == TranslationStatus::Approved {
+            let target = TranslationRepo::find_qa_target_by_id(pool, translation_id)
+                .await?
+                .ok_or(AppError::NotFound)?;
+            Self::ensure_qa_passes(pool, target).await?;
+        }
+
         TranslationRepo::update_status(pool, translation_id, req.status)
             .await?
             .ok_or(AppError::NotFound)
@@ -217,9 +251,462 @@ impl TranslationService {
     pub async fn get_translation_stats(pool: &PgPool) -> AppResult<TranslationStatsRes> {
         let items = TranslationRepo::find_translation_stats(pool).await?;
         let total_translations: i64 = items.iter().map(|i| i.count).sum();
+        let qa = TranslationRepo::find_qa_stats(pool).await?;
         Ok(TranslationStatsRes {
             items,
             total_translations,
+            qa,
+        })
+    }
+
+    // =========================================================================
+    // 용어집 (Glossary)
+    // =========================================================================
+
+    /// 용어집 목록
+    pub async fn list_glossary(pool: &PgPool, req: GlossaryListReq) -> AppResult<GlossaryListRes> {
+        let items = TranslationRepo::find_glossary(pool, req.lang).await?;
+        Ok(GlossaryListRes { items })
+    }
+
+    /// 용어집 항목 생성 (lang + term_ko UPSERT)
+    pub async fn create_glossary(
+        pool: &PgPool,
+        actor_user_id: i64,
+        req: GlossaryCreateReq,
+    ) -> AppResult<TranslationGlossaryRes> {
+        req.validate().map_err(AppError::Validation)?;
+
+        let term_ko = req.term_ko.trim();
+        let required = req.required_translation.trim();
+        if term_ko.is_empty() || required.is_empty() {
+            return Err(AppError::BadRequest(
+                "term_ko and required_translation must not be blank".into(),
+            ));
+        }
+        let forbidden = normalize_variants(&req.forbidden_variants);
+
+        TranslationRepo::upsert_glossary(
+            pool,
+            req.lang,
+            term_ko,
+            required,
+            &forbidden,
+            req.glossary_note.as_deref(),
+            actor_user_id,
+        )
+        .await
+    }
+
+    /// 용어집 항목 수정
+    pub async fn update_glossary(
+        pool: &PgPool,
+        actor_user_id: i64,
+        glossary_id: i64,
+        req: GlossaryUpdateReq,
+    ) -> AppResult<TranslationGlossaryRes> {
+        req.validate().map_err(AppError::Validation)?;
+
+        let required = req.required_translation.as_deref().map(str::trim);
+        if required == Some("") {
+            return Err(AppError::BadRequest(
+                "required_translation must not be blank".into(),
+            ));
+        }
+        let forbidden = req.forbidden_variants.as_deref().map(normalize_variants);
+
+        TranslationRepo::update_glossary(
+            pool,
+            glossary_id,
+            required,
+            forbidden.as_deref(),
+            req.glossary_note.as_deref(),
+            actor_user_id,
+        )
+        .await?
+        .ok_or(AppError::NotFound)
+    }
+
+    /// 용어집 항목 삭제
+    pub async fn delete_glossary(pool: &PgPool, glossary_id: i64) -> AppResult<()> {
+        let deleted = TranslationRepo::delete_glossary(pool, glossary_id).await?;
+        if !deleted {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // 번역 QA (린트)
+    // =========================================================================
+
+    /// 언어 전체 (또는 단일 콘텐츠) QA 스캔 — 결과 적재 후 이슈 있는 항목만 반환
+    pub async fn scan_qa(
+        pool: &PgPool,
+        req: TranslationQaScanReq,
+    ) -> AppResult<TranslationQaScanRes> {
+        if req.content_id.is_some() && req.content_type.is_none() {
+            return Err(AppError::BadRequest(
+                "content_id requires content_type".into(),
+            ));
+        }
+
+        let targets =
+            TranslationRepo::find_qa_targets(pool, req.lang, req.content_type, req.content_id)
+                .await?;
+        let checked = targets.len();
+        let results = Self::run_qa(pool, req.lang, targets).await?;
+
+        let items: Vec<TranslationQaItem> = results
+            .into_iter()
+            .filter(|r| !r.issues.is_empty())
+            .collect();
+        let error_items = items.iter().filter(|r| r.error_count > 0).count();
+
+        Ok(TranslationQaScanRes {
+            checked,
+            error_items,
+            warning_items: items.len() - error_items,
+            items,
+        })
+    }
+
+    /// 번역 1건 QA (결과 적재)
+    pub async fn check_translation_qa(
+        pool: &PgPool,
+        translation_id: i64,
+    ) -> AppResult<TranslationQaItem> {
+        let target = TranslationRepo::find_qa_target_by_id(pool, translation_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let lang = target.lang;
+        Self::run_qa(pool, lang, vec![target])
+            .await?
+            .pop()
+            .ok_or(AppError::NotFound)
+    }
+
+    /// approved 전환 가드 — QA 오류가 남아 있으면 422
+    async fn ensure_qa_passes(pool: &PgPool, target: QaTargetRow) -> AppResult<()> {
+        let lang = target.lang;
+        let glossary = TranslationRepo::find_glossary(pool, Some(lang)).await?;
+        let sources = Self::load_sources(pool, std::slice::from_ref(&target)).await?;
+        let issues = check_with_sources(&target, &sources, &glossary);
+        let errors: Vec<String> = issues
+            .iter()
+            .filter(|i| i.severity == qa::QaSeverity::Error)
+            .map(|i| i.message.clone())
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(AppError::Unprocessable(format!(
+                "Translation has {} QA error(s) and cannot be approved: {}",
+                errors.len(),
+                errors.join("; ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// QA 실행 + 결과 적재 (대상은 모두 같은 lang)
+    async fn run_qa(
+        pool: &PgPool,
+        lang: SupportedLanguage,
+        targets: Vec<QaTargetRow>,
+    ) -> AppResult<Vec<TranslationQaItem>> {
+        let glossary = TranslationRepo::find_glossary(pool, Some(lang)).await?;
+        let sources = Self::load_sources(pool, &targets).await?;
+
+        let mut ids = Vec::with_capacity(targets.len());
+        let mut error_counts = Vec::with_capacity(targets.len());
+        let mut warning_counts = Vec::with_capacity(targets.len());
+        let mut issue_values = Vec::with_capacity(targets.len());
+        let mut items = Vec::with_capacity(targets.len());
+
+        for target in targets {
+            let issues = check_with_sources(&target, &sources, &glossary);
+            let (error_count, warning_count) = qa::count_by_severity(&issues);
+
+            ids.push(target.translation_id);
+            error_counts.push(error_count);
+            warning_counts.push(warning_count);
+            issue_values.push(serde_json::to_value(&issues).unwrap_or(serde_json::Value::Null));
+
+            items.push(TranslationQaItem {
+                translation_id: target.translation_id,
+                content_type: target.content_type,
+                content_id: target.content_id,
+                field_name: target.field_name,
+                lang: target.lang,
+                error_count,
+                warning_count,
+                issues,
+            });
+        }
+
+        TranslationRepo::save_qa_results(pool, &ids, &error_counts, &warning_counts, &issue_values)
+            .await?;
+
+        Ok(items)
+    }
+
+    /// QA 원문 로드 — guide 블록은 원문 쌍 일괄 조회, 그 외는 content_type 별 source-fields 일괄 조회
+    async fn load_sources(pool: &PgPool, targets: &[QaTargetRow]) -> AppResult<SourceMap> {
+        let mut sources: SourceMap = HashMap::new();
+
+        let block_ids: Vec<i64> = targets
+            .iter()
+            .filter(|t| t.content_type == ContentType::GuideBlock)
+            .map(|t| t.content_id)
+            .collect();
+        for (block_id, (text_en, text_ko)) in
+            TranslationRepo::find_guide_block_sources(pool, &block_ids).await?
+        {
+            sources.insert(
+                (ContentType::GuideBlock, block_id, "text".to_string()),
+                QaSource {
+                    source_text: text_en,
+                    source_ko: text_ko,
+                },
+            );
+        }
+
+        let mut contents: HashMap<ContentType, HashSet<i64>> = HashMap::new();
+        for t in targets
+            .iter()
+            .filter(|t| t.content_type != ContentType::GuideBlock)
+        {
+            contents
+                .entry(t.content_type)
+                .or_default()
+                .insert(t.content_id);
+        }
+
+        for (content_type, ids) in contents {
+            let ids: Vec<i64> = ids.into_iter().collect();
+            for field in TranslationRepo::find_source_fields_batch(pool, content_type, &ids).await?
+            {
+                // Video 원문 조회는 연결된 VideoTag 필드도 함께 돌려줌 — 키에 실제 타입 사용
+                sources.insert(
+                    (field.content_type, field.content_id, field.field_name),
+                    QaSource {
+                        source_ko: field.source_text.clone(),
+                        source_text: field.source_text,
+                    },
+                );
+            }
+        }
+
+        Ok(sources)
+    }
+
+    // =========================================================================
+    // 공개(open) 전 번역 커버리지 정책
+    // =========================================================================
+
+    /// 공개 정책 목록
+    pub async fn list_publish_policies(pool: &PgPool) -> AppResult<PublishPolicyListRes> {
+        let items = TranslationRepo::find_publish_policies(pool).await?;
+        Ok(PublishPolicyListRes { items })
+    }
+
+    /// 공개 정책 수정
+    pub async fn update_publish_policy(
+        pool: &PgPool,
+        actor_user_id: i64,
+        content_type: ContentType,
+        req: PublishPolicyUpdateReq,
+    ) -> AppResult<PublishPolicyRes> {
+        req.validate().map_err(AppError::Validation)?;
+
+        let required_fields = req.required_fields.as_deref().map(normalize_variants);
+        let required_langs = req.required_langs.map(|langs| {
+            let mut out: Vec<SupportedLanguage> = Vec::with_capacity(langs.len());
+            for lang in langs {
+                if lang != SupportedLanguage::Ko && !out.contains(&lang) {
+                    out.push(lang);
+                }
+            }
+            out
+        });
+
+        TranslationRepo::update_publish_policy(
+            pool,
+            content_type,
+            required_langs.as_deref(),
+            required_fields.as_deref(),
+            req.min_status,
+            req.policy_enabled,
+            actor_user_id,
+        )
+        .await?
+        .ok_or(AppError::NotFound)
+    }
+
+    /// 콘텐츠 1건의 공개 커버리지 판정 (정책 없음/비활성 → satisfied)
+    pub async fn get_publish_coverage(
+        pool: &PgPool,
+        content_type: ContentType,
+        content_id: i64,
+    ) -> AppResult<PublishCoverageRes> {
+        let Some(policy) = TranslationRepo::find_publish_policy(pool, content_type).await? else {
+            return Ok(PublishCoverageRes {
+                content_type,
+                content_id,
+                policy_enabled: false,
+                min_status: TranslationStatus::Approved,
+                required_langs: Vec::new(),
+                satisfied: true,
+                missing: Vec::new(),
+            });
+        };
+
+        let missing = if policy.policy_enabled {
+            let fields =
+                TranslationRepo::find_source_fields(pool, content_type, content_id).await?;
+            let contents: Vec<(ContentType, i64)> = fields
+                .iter()
+                .map(|f| (f.content_type, f.content_id))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+            let rows = TranslationRepo::find_coverage_rows(pool, &contents, &policy.required_langs)
+                .await?;
+            publish::evaluate_coverage(&policy, &fields, &rows)
+        } else {
+            Vec::new()
+        };
+
+        Ok(PublishCoverageRes {
+            content_type,
+            content_id,
+            policy_enabled: policy.policy_enabled,
+            min_status: policy.min_status,
+            required_langs: policy.required_langs,
+            satisfied: missing.is_empty(),
+            missing,
         })
     }
+
+    /// 생성 시 공개 가드 — admin video/lesson/study 생성(일괄 포함)에서 state 가 open 일 때 호출
+    ///
+    /// 새 콘텐츠에는 번역이 없으므로 정책이 켜져 있으면 open 으로 바로 만들 수 없다 (422).
+    /// open 이 아닌 상태로 만든 뒤 번역을 채워 수정으로 공개한다 (커버리지 / force_publish 판정은 수정 경로).
+    pub async fn ensure_open_on_create_allowed(
+        pool: &PgPool,
+        content_type: ContentType,
+    ) -> AppResult<()> {
+        let Some(policy) = TranslationRepo::find_publish_policy(pool, content_type).await? else {
+            return Ok(());
+        };
+        if !policy.policy_enabled
+            || policy.required_langs.is_empty()
+            || policy.required_fields.is_empty()
+        {
+            return Ok(());
+        }
+
+        Err(AppError::UnprocessableDetails(
+            "Translation coverage policy is enabled: create as non-open, add translations, then publish"
+                .into(),
+            serde_json::to_value(&policy).unwrap_or(serde_json::Value::Null),
+        ))
+    }
+
+    /// 공개 전환 가드 — admin video/lesson/study 수정에서 state 가 open 으로 바뀔 때 호출
+    ///
+    /// 미충족 시 422 (details = `PublishCoverageRes`). `force_publish` 는 HYMN 전용이며
+    /// 실제로 정책을 우회한 경우에만 PUBLISH_OVERRIDE 감사 로그를 남긴다.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn enforce_publish_policy(
+        st: &AppState,
+        actor_user_id: i64,
+        actor_auth: UserAuth,
+        content_type: ContentType,
+        content_id: i64,
+        force_publish: bool,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<&str>,
+    ) -> AppResult<()> {
+        if force_publish && actor_auth != UserAuth::Hymn {
+            return Err(AppError::Forbidden(
+                "force_publish requires HYMN role".into(),
+            ));
+        }
+
+        let report = Self::get_publish_coverage(&st.db, content_type, content_id).await?;
+        if report.satisfied {
+            return Ok(());
+        }
+
+        let details = serde_json::to_value(&report).unwrap_or(serde_json::Value::Null);
+
+        if force_publish {
+            let target_table = match content_type {
+                ContentType::Video => "video",
+                ContentType::Lesson => "lesson",
+                ContentType::Study => "study",
+                _ => "content",
+            };
+            crate::api::admin::user::repo::write_audit_log(
+                st,
+                actor_user_id,
+                "PUBLISH_OVERRIDE",
+                target_table,
+                Some(content_id),
+                &details,
+                ip_address,
+                user_agent,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        Err(AppError::UnprocessableDetails(
+            format!(
+                "Translation coverage policy not met: {} field(s) missing required languages",
+                report.missing.len()
+            ),
+            details,
+        ))
+    }
+}
+
+/// QA 원문 쌍 (번역 원천 + 용어집 매칭용 한국어)
+struct QaSource {
+    source_text: Option<String>,
+    source_ko: Option<String>,
+}
+
+type SourceMap = HashMap<(ContentType, i64, String), QaSource>;
+
+fn check_with_sources(
+    target: &QaTargetRow,
+    sources: &SourceMap,
+    glossary: &[TranslationGlossaryRes],
+) -> Vec<qa::QaIssue> {
+    let source = sources.get(&(
+        target.content_type,
+        target.content_id,
+        target.field_name.clone(),
+    ));
+    let input = QaInput {
+        lang: target.lang,
+        source_text: source.and_then(|s| s.source_text.as_deref()),
+        source_ko: source.and_then(|s| s.source_ko.as_deref()),
+        translated_text: &target.translated_text,
+    };
+    qa::check_translation(&input, glossary)
+}
+
+/// 금지 변형 정규화 — trim + 빈 값 제거 + 중복 제거
+fn normalize_variants(variants: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(variants.len());
+    for v in variants {
+        let v = v.trim();
+        if !v.is_empty() && !out.iter().any(|o| o == v) {
+            out.push(v.to_string());
+        }
+    }
+    out
 }
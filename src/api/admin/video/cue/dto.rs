@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::types::StudyTaskKind;
+
+// ==========================================
+// 요청
+// ==========================================
+
+/// 큐 포인트 생성 요청
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct VideoCuePointCreateReq {
+    pub study_task_id: i32,
+    /// 일시정지 시점 (ms, 영상 길이 이내)
+    #[validate(range(min = 0))]
+    #[schema(example = 95000)]
+    pub cue_time_ms: i32,
+    /// true 면 풀지 않고 건너뛰기 허용 (기본 false)
+    pub cue_skippable: Option<bool>,
+}
+
+/// 큐 포인트 수정 요청 (없는 필드는 변경 안 함)
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct VideoCuePointUpdateReq {
+    pub study_task_id: Option<i32>,
+    #[validate(range(min = 0))]
+    pub cue_time_ms: Option<i32>,
+    pub cue_skippable: Option<bool>,
+}
+
+// ==========================================
+// 응답
+// ==========================================
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminVideoCuePointRes {
+    pub cue_point_id: i64,
+    pub video_id: i64,
+    pub study_task_id: i32,
+    pub study_id: i32,
+    pub study_task_kind: StudyTaskKind,
+    pub cue_time_ms: i32,
+    pub cue_skippable: bool,
+    pub updated_by_user_id: Option<i64>,
+    pub cue_created_at: DateTime<Utc>,
+    pub cue_updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminVideoCuePointListRes {
+    pub video_id: i64,
+    pub cue_points: Vec<AdminVideoCuePointRes>,
+}
@@ -0,0 +1,155 @@
+use super::super::handler::{extract_client_ip, extract_user_agent};
+use super::dto::{
+    AdminVideoCuePointListRes, AdminVideoCuePointRes, VideoCuePointCreateReq,
+    VideoCuePointUpdateReq,
+};
+use crate::api::auth::extractor::AuthUser;
+use crate::error::AppResult;
+use crate::extract::AppJson;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+
+/// 영상 퀴즈 큐 포인트 목록
+#[utoipa::path(
+    get,
+    path = "/admin/videos/{video_id}/cues",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID")
+    ),
+    responses(
+        (status = 200, description = "Cue points", body = AdminVideoCuePointListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_cue_points(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(video_id): Path<i64>,
+) -> AppResult<Json<AdminVideoCuePointListRes>> {
+    let res = super::service::list_cue_points(&st, auth_user.sub, video_id).await?;
+    Ok(Json(res))
+}
+
+/// 큐 포인트 생성
+#[utoipa::path(
+    post,
+    path = "/admin/videos/{video_id}/cues",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID")
+    ),
+    request_body = VideoCuePointCreateReq,
+    responses(
+        (status = 201, description = "Cue point created", body = AdminVideoCuePointRes),
+        (status = 400, description = "Invalid time or study task", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Cue point already exists at this time", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_create_cue_point(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(video_id): Path<i64>,
+    AppJson(req): AppJson<VideoCuePointCreateReq>,
+) -> AppResult<(StatusCode, Json<AdminVideoCuePointRes>)> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res =
+        super::service::create_cue_point(&st, auth_user.sub, video_id, req, ip_address, user_agent)
+            .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+/// 큐 포인트 수정
+#[utoipa::path(
+    patch,
+    path = "/admin/videos/{video_id}/cues/{cue_point_id}",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        ("cue_point_id" = i64, Path, description = "Cue point ID")
+    ),
+    request_body = VideoCuePointUpdateReq,
+    responses(
+        (status = 200, description = "Cue point updated", body = AdminVideoCuePointRes),
+        (status = 400, description = "Invalid time or study task", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Cue point not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Cue point already exists at this time", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_update_cue_point(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((video_id, cue_point_id)): Path<(i64, i64)>,
+    AppJson(req): AppJson<VideoCuePointUpdateReq>,
+) -> AppResult<Json<AdminVideoCuePointRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res = super::service::update_cue_point(
+        &st,
+        auth_user.sub,
+        video_id,
+        cue_point_id,
+        req,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+/// 큐 포인트 삭제
+#[utoipa::path(
+    delete,
+    path = "/admin/videos/{video_id}/cues/{cue_point_id}",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        ("cue_point_id" = i64, Path, description = "Cue point ID")
+    ),
+    responses(
+        (status = 204, description = "Cue point deleted"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Cue point not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_delete_cue_point(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((video_id, cue_point_id)): Path<(i64, i64)>,
+) -> AppResult<StatusCode> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    super::service::delete_cue_point(
+        &st,
+        auth_user.sub,
+        video_id,
+        cue_point_id,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,131 @@
+use sqlx::PgPool;
+
+use super::dto::{AdminVideoCuePointRes, VideoCuePointUpdateReq};
+use crate::error::AppResult;
+
+const CUE_SELECT: &str = r#"
+    SELECT
+        c.cue_point_id,
+        c.video_id::bigint AS video_id,
+        c.study_task_id,
+        t.study_id,
+        t.study_task_kind,
+        c.cue_time_ms,
+        c.cue_skippable,
+        c.updated_by_user_id,
+        c.cue_created_at,
+        c.cue_updated_at
+    FROM video_cue_point c
+    JOIN study_task t ON t.study_task_id = c.study_task_id
+"#;
+
+/// 비디오 길이 (존재 확인 겸용) — 비디오 없으면 None, 길이 미상이면 Some(None)
+pub async fn find_video_duration(db: &PgPool, video_id: i64) -> AppResult<Option<Option<i32>>> {
+    let row = sqlx::query_scalar::<_, Option<i32>>(
+        r#"SELECT video_duration FROM video WHERE video_id = $1"#,
+    )
+    .bind(video_id)
+    .fetch_optional(db)
+    .await?;
+    Ok(row)
+}
+
+pub async fn exists_study_task(db: &PgPool, study_task_id: i32) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"SELECT EXISTS(SELECT 1 FROM study_task WHERE study_task_id = $1)"#,
+    )
+    .bind(study_task_id)
+    .fetch_one(db)
+    .await?;
+    Ok(exists)
+}
+
+pub async fn find_cue_points(db: &PgPool, video_id: i64) -> AppResult<Vec<AdminVideoCuePointRes>> {
+    let sql = format!("{CUE_SELECT} WHERE c.video_id = $1 ORDER BY c.cue_time_ms");
+    let rows = sqlx::query_as::<_, AdminVideoCuePointRes>(&sql)
+        .bind(video_id)
+        .fetch_all(db)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_cue_point(
+    db: &PgPool,
+    video_id: i64,
+    cue_point_id: i64,
+) -> AppResult<Option<AdminVideoCuePointRes>> {
+    let sql = format!("{CUE_SELECT} WHERE c.video_id = $1 AND c.cue_point_id = $2");
+    let row = sqlx::query_as::<_, AdminVideoCuePointRes>(&sql)
+        .bind(video_id)
+        .bind(cue_point_id)
+        .fetch_optional(db)
+        .await?;
+    Ok(row)
+}
+
+pub async fn create_cue_point(
+    db: &PgPool,
+    video_id: i64,
+    study_task_id: i32,
+    cue_time_ms: i32,
+    cue_skippable: bool,
+    actor_user_id: i64,
+) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO video_cue_point (
+            video_id, study_task_id, cue_time_ms, cue_skippable, updated_by_user_id
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING cue_point_id
+        "#,
+    )
+    .bind(video_id)
+    .bind(study_task_id)
+    .bind(cue_time_ms)
+    .bind(cue_skippable)
+    .bind(actor_user_id)
+    .fetch_one(db)
+    .await?;
+    Ok(id)
+}
+
+/// 부분 수정 — 대상 없으면 false
+pub async fn update_cue_point(
+    db: &PgPool,
+    video_id: i64,
+    cue_point_id: i64,
+    req: &VideoCuePointUpdateReq,
+    actor_user_id: i64,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE video_cue_point
+        SET study_task_id = COALESCE($3, study_task_id),
+            cue_time_ms = COALESCE($4, cue_time_ms),
+            cue_skippable = COALESCE($5, cue_skippable),
+            updated_by_user_id = $6,
+            cue_updated_at = NOW()
+        WHERE video_id = $1 AND cue_point_id = $2
+        "#,
+    )
+    .bind(video_id)
+    .bind(cue_point_id)
+    .bind(req.study_task_id)
+    .bind(req.cue_time_ms)
+    .bind(req.cue_skippable)
+    .bind(actor_user_id)
+    .execute(db)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn delete_cue_point(db: &PgPool, video_id: i64, cue_point_id: i64) -> AppResult<bool> {
+    let res =
+        sqlx::query(r#"DELETE FROM video_cue_point WHERE video_id = $1 AND cue_point_id = $2"#)
+            .bind(video_id)
+            .bind(cue_point_id)
+            .execute(db)
+            .await?;
+    Ok(res.rows_affected() > 0)
+}
@@ -0,0 +1,18 @@
+use super::handler::{
+    admin_create_cue_point, admin_delete_cue_point, admin_list_cue_points, admin_update_cue_point,
+};
+use crate::AppState;
+use axum::{
+    routing::{get, patch},
+    Router,
+};
+
+/// 특정 비디오의 퀴즈 큐 포인트 라우터 (/{video_id}/cues 하위)
+pub fn admin_cue_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(admin_list_cue_points).post(admin_create_cue_point))
+        .route(
+            "/{cue_point_id}",
+            patch(admin_update_cue_point).delete(admin_delete_cue_point),
+        )
+}
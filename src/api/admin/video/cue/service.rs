@@ -0,0 +1,217 @@
+use std::net::IpAddr;
+
+use validator::Validate;
+
+use super::dto::{
+    AdminVideoCuePointListRes, AdminVideoCuePointRes, VideoCuePointCreateReq,
+    VideoCuePointUpdateReq,
+};
+use crate::api::admin::video::service::{check_admin_rbac, is_unique_violation};
+use crate::error::{AppError, AppResult};
+use crate::AppState;
+
+/// 큐 시점이 영상 길이 이내인지 (길이 미상/0 이면 통과)
+fn check_cue_time(duration_sec: Option<i32>, cue_time_ms: Option<i32>) -> AppResult<()> {
+    if let (Some(duration_sec), Some(time_ms)) = (duration_sec.filter(|d| *d > 0), cue_time_ms) {
+        if i64::from(time_ms) > i64::from(duration_sec) * 1000 {
+            return Err(AppError::BadRequest(format!(
+                "cue_time_ms exceeds video duration ({duration_sec}s)"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// 비디오 존재 + 큐 시점이 영상 길이 이내인지
+async fn ensure_cue_time(st: &AppState, video_id: i64, cue_time_ms: Option<i32>) -> AppResult<()> {
+    let duration = super::repo::find_video_duration(&st.db, video_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    check_cue_time(duration, cue_time_ms)
+}
+
+async fn ensure_study_task(st: &AppState, study_task_id: Option<i32>) -> AppResult<()> {
+    if let Some(task_id) = study_task_id {
+        if !super::repo::exists_study_task(&st.db, task_id).await? {
+            return Err(AppError::BadRequest(format!(
+                "study_task {task_id} not found"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn map_conflict(e: AppError) -> AppError {
+    if is_unique_violation(&e) {
+        AppError::Conflict("cue point already exists at this time".into())
+    } else {
+        e
+    }
+}
+
+/// 큐 포인트 목록 (시간순)
+pub async fn list_cue_points(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+) -> AppResult<AdminVideoCuePointListRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    super::repo::find_video_duration(&st.db, video_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    let cue_points = super::repo::find_cue_points(&st.db, video_id).await?;
+    Ok(AdminVideoCuePointListRes {
+        video_id,
+        cue_points,
+    })
+}
+
+pub async fn create_cue_point(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    req: VideoCuePointCreateReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminVideoCuePointRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    ensure_cue_time(st, video_id, Some(req.cue_time_ms)).await?;
+    ensure_study_task(st, Some(req.study_task_id)).await?;
+
+    let cue_point_id = super::repo::create_cue_point(
+        &st.db,
+        video_id,
+        req.study_task_id,
+        req.cue_time_ms,
+        req.cue_skippable.unwrap_or(false),
+        actor_user_id,
+    )
+    .await
+    .map_err(map_conflict)?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "CREATE_VIDEO_CUE",
+        "video_cue_point",
+        Some(cue_point_id),
+        &serde_json::json!({ "video_id": video_id, "req": req }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    super::repo::find_cue_point(&st.db, video_id, cue_point_id)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+pub async fn update_cue_point(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    cue_point_id: i64,
+    req: VideoCuePointUpdateReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<AdminVideoCuePointRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    if req.study_task_id.is_none() && req.cue_time_ms.is_none() && req.cue_skippable.is_none() {
+        return Err(AppError::BadRequest("no fields to update".into()));
+    }
+    ensure_cue_time(st, video_id, req.cue_time_ms).await?;
+    ensure_study_task(st, req.study_task_id).await?;
+
+    let updated =
+        super::repo::update_cue_point(&st.db, video_id, cue_point_id, &req, actor_user_id)
+            .await
+            .map_err(map_conflict)?;
+    if !updated {
+        return Err(AppError::NotFound);
+    }
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "UPDATE_VIDEO_CUE",
+        "video_cue_point",
+        Some(cue_point_id),
+        &serde_json::json!({ "video_id": video_id, "req": req }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    super::repo::find_cue_point(&st.db, video_id, cue_point_id)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+/// 삭제 — 해당 큐에서 제출된 답안 로그는 영상 컨텍스트만 해제 (NULL)
+pub async fn delete_cue_point(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    cue_point_id: i64,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<()> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+
+    if !super::repo::delete_cue_point(&st.db, video_id, cue_point_id).await? {
+        return Err(AppError::NotFound);
+    }
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "DELETE_VIDEO_CUE",
+        "video_cue_point",
+        Some(cue_point_id),
+        &serde_json::json!({ "video_id": video_id }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_cue_time_within_duration() {
+        assert!(check_cue_time(Some(60), Some(0)).is_ok());
+        assert!(check_cue_time(Some(60), Some(59_999)).is_ok());
+        // 영상 끝 시점 = 허용
+        assert!(check_cue_time(Some(60), Some(60_000)).is_ok());
+    }
+
+    #[test]
+    fn test_check_cue_time_rejects_beyond_duration() {
+        assert!(matches!(
+            check_cue_time(Some(60), Some(60_001)),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_cue_time_unknown_duration_passes() {
+        assert!(check_cue_time(None, Some(i32::MAX)).is_ok());
+        assert!(check_cue_time(Some(0), Some(1_000_000)).is_ok());
+    }
+
+    #[test]
+    fn test_check_cue_time_without_time_passes() {
+        // 수정 요청에서 cue_time_ms 미지정
+        assert!(check_cue_time(Some(60), None).is_ok());
+    }
+}
@@ -6,6 +6,8 @@ use std::str::FromStr;
 use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationError};
 
+use crate::types::VideoHostKind;
+
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone, Copy, PartialEq, Eq, Type)]
 #[sqlx(type_name = "TEXT")]
 #[serde(rename_all = "snake_case")]
@@ -132,10 +134,29 @@ pub struct VideoCreateReq {
     #[schema(example = "tag_rust_basic")]
     pub video_tag_key: Option<String>,
 
-    // 3. video URL
+    // 3. video host / source
+    /// 비디오 호스트 (기본 vimeo). 선택한 호스트의 원본 필드가 필수
+    #[schema(example = "vimeo")]
+    pub video_host: Option<VideoHostKind>,
+
+    /// vimeo 호스트 원본 URL
     #[validate(url, length(max = 1024))]
     #[schema(example = "https://vimeo.com/123456789")]
-    pub video_url_vimeo: String,
+    pub video_url_vimeo: Option<String>,
+
+    /// hls 호스트 원본 — HLS_ROOT_DIR 기준 패키징 디렉터리 (master.m3u8 + enc.key)
+    #[validate(length(max = 255), custom(function = "validate_hls_path"))]
+    #[schema(example = "course1/lesson-01")]
+    pub video_hls_path: Option<String>,
+}
+
+// video_hls_path 유효성 검증 함수 (경로 탈출/숨김 파일 차단)
+fn validate_hls_path(path: &str) -> Result<(), validator::ValidationError> {
+    if crate::external::hls::is_safe_rel_path(path.trim()) {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("invalid_hls_path"))
+    }
 }
 
 // video_access 유효성 검증 함수
@@ -196,6 +217,9 @@ pub struct VideoUpdateReq {
         custom(function = "validate_not_empty_string")
     )]
     pub video_idx: Option<String>,
+
+    /// HYMN 전용 — state=open 전환 시 번역 커버리지 공개 정책 우회 (PUBLISH_OVERRIDE 감사 기록)
+    pub force_publish: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
@@ -228,6 +252,7 @@ impl From<VideoTagUpdateReq> for VideoUpdateReq {
             video_access: None,
             video_state: None,
             video_idx: None,
+            force_publish: None,
         }
     }
 }
@@ -323,6 +348,10 @@ pub struct AdminVideoRes {
     /// 영상 썸네일 URL (Vimeo API에서 가져옴)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video_thumbnail: Option<String>,
+    pub video_host: VideoHostKind,
+    /// hls 호스트 패키징 디렉터리
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_hls_path: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -474,6 +503,86 @@ pub struct VimeoUploadTicketRes {
     pub upload_link: String,
 }
 
+// ==========================================
+// Vimeo 메타데이터 동기화
+// ==========================================
+
+/// 수동 재동기화 요청 — `video_ids` 생략 시 전체 비디오 (백그라운드 실행)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VimeoResyncReq {
+    #[validate(length(min = 1, max = 50))]
+    pub video_ids: Option<Vec<i64>>,
+}
+
+/// 동기화 실행 리포트
+#[derive(Debug, Clone, Serialize, ToSchema, FromRow)]
+pub struct VimeoSyncRunRes {
+    pub run_id: i64,
+    /// schedule | manual
+    pub run_trigger: String,
+    pub run_full: bool,
+    pub triggered_by_user_id: Option<i64>,
+    pub run_started_at: DateTime<Utc>,
+    /// None = 실행 중
+    pub run_finished_at: Option<DateTime<Utc>>,
+    pub checked_count: i32,
+    pub updated_count: i32,
+    pub not_found_count: i32,
+    pub private_count: i32,
+    pub failed_count: i32,
+}
+
+/// 비디오별 동기화 결과
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VimeoSyncItemResult {
+    pub video_id: i64,
+    /// ok | not_found | private | error
+    pub sync_status: String,
+    /// 갱신된 필드 (duration | thumbnail | title | description)
+    pub changed_fields: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 수동 재동기화 응답 (전체 실행은 `items` 비어 있음 — 리포트로 확인)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VimeoResyncRes {
+    pub run: VimeoSyncRunRes,
+    pub items: Vec<VimeoSyncItemResult>,
+}
+
+/// 동기화 이상 비디오 (not_found / private / error)
+#[derive(Debug, Clone, Serialize, ToSchema, FromRow)]
+pub struct VimeoSyncFlaggedItem {
+    pub video_id: i64,
+    pub video_idx: String,
+    pub video_url_vimeo: String,
+    pub video_state: String,
+    pub sync_status: String,
+    pub sync_error: Option<String>,
+    pub last_synced_at: DateTime<Utc>,
+    pub last_ok_at: Option<DateTime<Utc>>,
+}
+
+/// 동기화 리포트
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VimeoSyncReportRes {
+    /// 가장 최근 실행
+    pub last_run: Option<VimeoSyncRunRes>,
+    pub flagged: Vec<VimeoSyncFlaggedItem>,
+}
+
+/// 동기화 대상 (현재 DB 값 + 마지막 Vimeo 스냅샷)
+#[derive(Debug, Clone, FromRow)]
+pub struct VimeoSyncTarget {
+    pub video_id: i64,
+    pub video_url_vimeo: String,
+    pub video_duration: Option<i32>,
+    pub video_thumbnail: Option<String>,
+    pub vimeo_name: Option<String>,
+    pub vimeo_description: Option<String>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct VideoBulkUpdateItemResult {
     pub id: i64,
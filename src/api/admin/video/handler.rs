@@ -10,8 +10,8 @@ use utoipa::ToSchema;
 use crate::api::admin::video::dto::{
     AdminVideoListReq, AdminVideoListRes, AdminVideoRes, VideoBulkCreateReq, VideoBulkCreateRes,
     VideoBulkUpdateReq, VideoBulkUpdateRes, VideoCreateReq, VideoTagBulkUpdateReq,
-    VideoTagUpdateReq, VideoUpdateReq, VimeoPreviewReq, VimeoPreviewRes, VimeoUploadTicketReq,
-    VimeoUploadTicketRes,
+    VideoTagUpdateReq, VideoUpdateReq, VimeoPreviewReq, VimeoPreviewRes, VimeoResyncReq,
+    VimeoResyncRes, VimeoSyncReportRes, VimeoUploadTicketReq, VimeoUploadTicketRes,
 };
 use crate::api::auth::extractor::AuthUser;
 #[allow(unused_imports)] // Used in return type
@@ -19,7 +19,7 @@ use crate::error::{AppError, AppResult};
 use crate::AppState;
 use std::net::IpAddr;
 
-fn extract_client_ip(headers: &HeaderMap) -> Option<IpAddr> {
+pub(super) fn extract_client_ip(headers: &HeaderMap) -> Option<IpAddr> {
     let forwarded = headers
         .get("x-forwarded-for")
         .and_then(|v| v.to_str().ok())
@@ -35,7 +35,7 @@ fn extract_client_ip(headers: &HeaderMap) -> Option<IpAddr> {
     ip_str.parse().ok()
 }
 
-fn extract_user_agent(headers: &HeaderMap) -> Option<String> {
+pub(super) fn extract_user_agent(headers: &HeaderMap) -> Option<String> {
     headers
         .get(USER_AGENT)
         .and_then(|v| v.to_str().ok())
@@ -350,6 +350,9 @@ pub async fn admin_update_video_tags(
         (status = 200, description = "Vimeo metadata preview", body = VimeoPreviewRes),
         (status = 400, description = "Invalid URL or Vimeo error", body = crate::error::ErrorBody),
         (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Vimeo video is private", body = crate::error::ErrorBody),
+        (status = 404, description = "Vimeo video not found", body = crate::error::ErrorBody),
+        (status = 429, description = "Vimeo API rate limited", body = crate::error::ErrorBody),
     ),
     security(("bearerAuth" = []))
 )]
@@ -383,3 +386,58 @@ pub async fn admin_create_vimeo_upload_ticket(
     let res = super::service::admin_create_vimeo_upload_ticket(&st, auth_user.sub, req).await?;
     Ok(Json(res))
 }
+
+/// Vimeo 메타데이터 동기화 리포트 (최근 실행 + 404/비공개/오류 비디오)
+#[utoipa::path(
+    get,
+    path = "/admin/videos/vimeo/sync-report",
+    tag = "admin_video",
+    responses(
+        (status = 200, description = "Sync report", body = VimeoSyncReportRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_get_vimeo_sync_report(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> AppResult<Json<VimeoSyncReportRes>> {
+    let res = super::service::admin_get_vimeo_sync_report(&st, auth_user.sub).await?;
+    Ok(Json(res))
+}
+
+/// Vimeo 메타데이터 수동 재동기화 (video_ids 생략 시 전체 — 202 백그라운드)
+#[utoipa::path(
+    post,
+    path = "/admin/videos/vimeo/resync",
+    tag = "admin_video",
+    request_body = VimeoResyncReq,
+    responses(
+        (status = 200, description = "Selected videos resynced", body = VimeoResyncRes),
+        (status = 202, description = "Full resync started", body = VimeoResyncRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 409, description = "Full sync already running", body = crate::error::ErrorBody),
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_resync_vimeo(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    AppJson(req): AppJson<VimeoResyncReq>,
+) -> AppResult<(StatusCode, Json<VimeoResyncRes>)> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let (background, res) =
+        super::service::admin_resync_vimeo(&st, auth_user.sub, req, ip_address, user_agent).await?;
+    let status = if background {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, Json(res)))
+}
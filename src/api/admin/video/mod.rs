@@ -1,6 +1,8 @@
+pub mod cue;
 pub mod dto;
 pub mod handler;
 pub mod repo;
 pub mod router;
 pub mod service;
 pub mod stats;
+pub mod subtitle;
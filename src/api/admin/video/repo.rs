@@ -1,7 +1,9 @@
 use crate::api::admin::video::dto::{
-    AdminVideoRes, VideoAccess, VideoCreateReq, VideoState, VideoUpdateReq,
+    AdminVideoRes, VideoAccess, VideoCreateReq, VideoState, VideoUpdateReq, VimeoSyncFlaggedItem,
+    VimeoSyncRunRes, VimeoSyncTarget,
 };
 use crate::error::AppResult;
+use crate::types::VideoHostKind;
 use serde_json::Value;
 use sqlx::{PgPool, Postgres, QueryBuilder, Row, Transaction};
 use std::str::FromStr; // [수정] Row 추가
@@ -57,7 +59,9 @@ pub async fn admin_list_videos(
             v.video_created_at AS created_at,
             v.video_updated_at AS updated_at,
             v.video_duration AS video_duration,
-            v.video_thumbnail AS video_thumbnail
+            v.video_thumbnail AS video_thumbnail,
+            v.video_host AS video_host,
+            v.video_hls_path AS video_hls_path
         FROM video v
         LEFT JOIN video_tag_map m ON v.video_id = m.video_id
         LEFT JOIN video_tag t ON m.video_tag_id = t.video_tag_id
@@ -79,7 +83,7 @@ pub async fn admin_list_videos(
         list_builder.push(")");
     }
 
-    list_builder.push(" GROUP BY v.video_id, v.video_idx, v.video_url_vimeo, v.video_state, v.video_access, v.video_title, v.video_subtitle, v.updated_by_user_id, v.video_created_at, v.video_updated_at, v.video_duration, v.video_thumbnail ");
+    list_builder.push(" GROUP BY v.video_id, v.video_idx, v.video_url_vimeo, v.video_state, v.video_access, v.video_title, v.video_subtitle, v.updated_by_user_id, v.video_created_at, v.video_updated_at, v.video_duration, v.video_thumbnail, v.video_host, v.video_hls_path ");
 
     let order_by = match sort {
         "id" => "v.video_id",
@@ -128,6 +132,8 @@ pub async fn admin_list_videos(
                 updated_at: row.try_get("updated_at").unwrap_or_default(),
                 video_duration: row.try_get("video_duration").ok(),
                 video_thumbnail: row.try_get("video_thumbnail").ok(),
+                video_host: row.try_get("video_host").unwrap_or_default(),
+                video_hls_path: row.try_get("video_hls_path").ok().flatten(),
             }
         })
         .collect();
@@ -155,7 +161,9 @@ pub async fn admin_get_video(pool: &PgPool, video_id: i64) -> AppResult<Option<A
             v.video_created_at AS created_at,
             v.video_updated_at AS updated_at,
             v.video_duration AS video_duration,
-            v.video_thumbnail AS video_thumbnail
+            v.video_thumbnail AS video_thumbnail,
+            v.video_host AS video_host,
+            v.video_hls_path AS video_hls_path
         FROM video v
         LEFT JOIN video_tag_map m ON v.video_id = m.video_id
         LEFT JOIN video_tag t ON m.video_tag_id = t.video_tag_id
@@ -167,7 +175,7 @@ pub async fn admin_get_video(pool: &PgPool, video_id: i64) -> AppResult<Option<A
             GROUP BY video_id
         ) stats ON stats.video_id = v.video_id
         WHERE v.video_id = $1
-        GROUP BY v.video_id, v.video_idx, v.video_url_vimeo, v.video_state, v.video_access, v.video_title, v.video_subtitle, v.updated_by_user_id, v.video_created_at, v.video_updated_at, v.video_duration, v.video_thumbnail
+        GROUP BY v.video_id, v.video_idx, v.video_url_vimeo, v.video_state, v.video_access, v.video_title, v.video_subtitle, v.updated_by_user_id, v.video_created_at, v.video_updated_at, v.video_duration, v.video_thumbnail, v.video_host, v.video_hls_path
         "#,
     )
     .bind(video_id)
@@ -196,6 +204,8 @@ pub async fn admin_get_video(pool: &PgPool, video_id: i64) -> AppResult<Option<A
                 updated_at: row.try_get("updated_at").unwrap_or_default(),
                 video_duration: row.try_get("video_duration").ok(),
                 video_thumbnail: row.try_get("video_thumbnail").ok(),
+                video_host: row.try_get("video_host").unwrap_or_default(),
+                video_hls_path: row.try_get("video_hls_path").ok().flatten(),
             }))
         }
         None => Ok(None),
@@ -249,6 +259,13 @@ pub async fn admin_create_video(
             .map(str::trim)
             .filter(|s| !s.is_empty()));
 
+    // 선택 호스트의 원본 식별자만 저장 (필수 여부는 service 에서 검증)
+    let video_host = req.video_host.unwrap_or_default();
+    let (url_vimeo, hls_path) = match video_host {
+        VideoHostKind::Vimeo => (req.video_url_vimeo.as_deref().map(str::trim), None),
+        VideoHostKind::Hls => (None, req.video_hls_path.as_deref().map(str::trim)),
+    };
+
     // 1. VIDEO 테이블 Insert
     let video_row = sqlx::query(
         r#"
@@ -259,25 +276,30 @@ pub async fn admin_create_video(
             video_access,
             video_url_vimeo,
             video_title,
-            video_subtitle
+            video_subtitle,
+            video_host,
+            video_hls_path
         )
-        VALUES ($1, $2, $3::video_state_enum, $4::video_access_enum, $5, $6, $7)
+        VALUES ($1, $2, $3::video_state_enum, $4::video_access_enum, $5, $6, $7, $8, $9)
         RETURNING
             video_id::bigint,
             video_created_at,
             video_updated_at,
             video_state::text,
             video_access::text,
-            video_url_vimeo
+            video_url_vimeo,
+            video_hls_path
         "#,
     )
     .bind(actor_user_id)
     .bind(video_idx)
     .bind(video_state)
     .bind(&req.video_access)
-    .bind(req.video_url_vimeo.trim())
+    .bind(url_vimeo)
     .bind(video_title)
     .bind(video_subtitle)
+    .bind(video_host)
+    .bind(hls_path)
     .fetch_one(&mut **tx)
     .await?;
 
@@ -286,7 +308,8 @@ pub async fn admin_create_video(
     let updated_at = video_row.try_get("video_updated_at")?;
     let v_state: String = video_row.try_get("video_state")?;
     let video_access: String = video_row.try_get("video_access")?;
-    let video_url: String = video_row.try_get("video_url_vimeo")?;
+    let video_url: Option<String> = video_row.try_get("video_url_vimeo")?;
+    let video_hls_path: Option<String> = video_row.try_get("video_hls_path")?;
 
     // 2. VIDEO_TAG 테이블 Insert
     let tag_row = sqlx::query(
@@ -328,7 +351,7 @@ pub async fn admin_create_video(
     Ok(AdminVideoRes {
         id: video_id,
         title,
-        url: Some(video_url),
+        url: video_url,
         description,
         views: 0,
         video_state: VideoState::from_str(&v_state).unwrap_or(VideoState::Ready),
@@ -343,9 +366,42 @@ pub async fn admin_create_video(
         updated_at,
         video_duration: None,  // Vimeo 동기화 후 업데이트됨
         video_thumbnail: None, // Vimeo 동기화 후 업데이트됨
+        video_host,
+        video_hls_path,
     })
 }
 
+/// 현재 video_state (text) — 없으면 None
+pub async fn find_video_state(pool: &PgPool, video_id: i64) -> AppResult<Option<String>> {
+    let state = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT video_state::text
+        FROM video
+        WHERE video_id = $1
+        "#,
+    )
+    .bind(video_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(state)
+}
+
+pub async fn find_video_host(pool: &PgPool, video_id: i64) -> AppResult<Option<VideoHostKind>> {
+    let host = sqlx::query_scalar::<_, VideoHostKind>(
+        r#"
+        SELECT video_host
+        FROM video
+        WHERE video_id = $1
+        "#,
+    )
+    .bind(video_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(host)
+}
+
 pub async fn exists_video_idx_for_update(
     tx: &mut Transaction<'_, Postgres>,
     video_id: i64,
@@ -495,7 +551,7 @@ pub async fn admin_update_video(
     builder.push_bind(video_id);
 
     // RETURNING
-    builder.push(" RETURNING video_id::bigint, video_created_at, video_updated_at, video_state::text, video_access::text, video_url_vimeo, video_idx, updated_by_user_id");
+    builder.push(" RETURNING video_id::bigint, video_created_at, video_updated_at, video_state::text, video_access::text, video_url_vimeo, video_host, video_hls_path, video_idx, updated_by_user_id");
 
     let video_row = builder.build().fetch_one(&mut **tx).await?;
 
@@ -504,7 +560,9 @@ pub async fn admin_update_video(
     let updated_at = video_row.try_get("video_updated_at")?;
     let v_state: String = video_row.try_get("video_state")?;
     let v_access: String = video_row.try_get("video_access")?;
-    let v_url: String = video_row.try_get("video_url_vimeo")?;
+    let v_url: Option<String> = video_row.try_get("video_url_vimeo")?;
+    let v_host: VideoHostKind = video_row.try_get("video_host")?;
+    let v_hls_path: Option<String> = video_row.try_get("video_hls_path")?;
     let v_idx: String = video_row.try_get("video_idx")?;
     let v_updated_by: Option<i64> = video_row.try_get("updated_by_user_id").ok();
 
@@ -594,7 +652,7 @@ pub async fn admin_update_video(
     Ok(AdminVideoRes {
         id: v_id,
         title: row.try_get("title")?,
-        url: Some(v_url),
+        url: v_url,
         description: row.try_get("description").ok(),
         views: row.try_get("views")?,
         video_state: VideoState::from_str(&v_state).unwrap_or(VideoState::Ready),
@@ -608,6 +666,8 @@ pub async fn admin_update_video(
         updated_at,
         video_duration: row.try_get("video_duration").ok(),
         video_thumbnail: row.try_get("video_thumbnail").ok(),
+        video_host: v_host,
+        video_hls_path: v_hls_path,
     })
 }
 
@@ -730,6 +790,191 @@ pub async fn update_vimeo_meta(
     Ok(())
 }
 
+// =========================================================================
+// Vimeo 메타데이터 동기화 (jobs::vimeo_sync)
+// =========================================================================
+
+const SYNC_RUN_COLUMNS: &str =
+    "run_id, run_trigger, run_full, triggered_by_user_id, run_started_at, \
+     run_finished_at, checked_count, updated_count, not_found_count, private_count, failed_count";
+
+/// 동기화 대상 — 오래 동기화 안 된 순. `video_ids` 지정 시 해당 비디오만
+pub async fn find_vimeo_sync_targets(
+    pool: &PgPool,
+    video_ids: Option<&[i64]>,
+) -> AppResult<Vec<VimeoSyncTarget>> {
+    let rows = sqlx::query_as::<_, VimeoSyncTarget>(
+        r#"
+        SELECT
+            v.video_id::bigint AS video_id,
+            v.video_url_vimeo,
+            v.video_duration,
+            v.video_thumbnail,
+            s.vimeo_name,
+            s.vimeo_description
+        FROM video v
+        LEFT JOIN video_vimeo_sync s ON s.video_id = v.video_id
+        WHERE v.video_host = 'vimeo'
+          AND ($1::bigint[] IS NULL OR v.video_id = ANY($1))
+        ORDER BY s.last_synced_at ASC NULLS FIRST, v.video_id ASC
+        "#,
+    )
+    .bind(video_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 실행 기록 생성 (전체 실행 중복 방지는 호출부의 Redis 락이 담당)
+pub async fn create_vimeo_sync_run(
+    pool: &PgPool,
+    trigger: &str,
+    full: bool,
+    actor_user_id: Option<i64>,
+) -> AppResult<VimeoSyncRunRes> {
+    let sql = format!(
+        r#"
+        INSERT INTO vimeo_sync_run (run_trigger, run_full, triggered_by_user_id)
+        VALUES ($1, $2, $3)
+        RETURNING {SYNC_RUN_COLUMNS}
+        "#
+    );
+    let row = sqlx::query_as::<_, VimeoSyncRunRes>(&sql)
+        .bind(trigger)
+        .bind(full)
+        .bind(actor_user_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 실행 종료 + 집계 기록
+pub async fn finish_vimeo_sync_run(
+    pool: &PgPool,
+    run_id: i64,
+    counts: [i32; 5],
+) -> AppResult<VimeoSyncRunRes> {
+    let [checked, updated, not_found, private, failed] = counts;
+    let sql = format!(
+        r#"
+        UPDATE vimeo_sync_run
+        SET run_finished_at = NOW(),
+            checked_count = $2,
+            updated_count = $3,
+            not_found_count = $4,
+            private_count = $5,
+            failed_count = $6
+        WHERE run_id = $1
+        RETURNING {SYNC_RUN_COLUMNS}
+        "#
+    );
+    let row = sqlx::query_as::<_, VimeoSyncRunRes>(&sql)
+        .bind(run_id)
+        .bind(checked)
+        .bind(updated)
+        .bind(not_found)
+        .bind(private)
+        .bind(failed)
+        .fetch_one(pool)
+        .await?;
+    Ok(row)
+}
+
+/// duration/thumbnail 갱신 (제목 변경 없음 — 제목까지 바뀌면 `update_vimeo_meta`)
+pub async fn update_vimeo_media(
+    tx: &mut Transaction<'_, Postgres>,
+    video_id: i64,
+    duration: i32,
+    thumbnail_url: Option<&str>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE video
+        SET video_duration = $2,
+            video_thumbnail = $3
+        WHERE video_id = $1
+        "#,
+    )
+    .bind(video_id)
+    .bind(duration)
+    .bind(thumbnail_url)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// 동기화 결과 기록. ok 일 때만 Vimeo 스냅샷(name/description)·last_ok_at 갱신
+pub async fn upsert_vimeo_sync_status(
+    executor: impl sqlx::PgExecutor<'_>,
+    video_id: i64,
+    sync_status: &str,
+    snapshot: Option<(&str, Option<&str>)>,
+    sync_error: Option<&str>,
+) -> AppResult<()> {
+    let is_ok = snapshot.is_some();
+    let (name, description) = snapshot.unzip();
+    sqlx::query(
+        r#"
+        INSERT INTO video_vimeo_sync (
+            video_id, sync_status, vimeo_name, vimeo_description, sync_error,
+            last_synced_at, last_ok_at
+        )
+        VALUES ($1, $2, $3, $4, $5, NOW(), CASE WHEN $6 THEN NOW() END)
+        ON CONFLICT (video_id) DO UPDATE SET
+            sync_status = EXCLUDED.sync_status,
+            vimeo_name = CASE WHEN $6 THEN EXCLUDED.vimeo_name ELSE video_vimeo_sync.vimeo_name END,
+            vimeo_description = CASE WHEN $6 THEN EXCLUDED.vimeo_description
+                                     ELSE video_vimeo_sync.vimeo_description END,
+            sync_error = EXCLUDED.sync_error,
+            last_synced_at = NOW(),
+            last_ok_at = COALESCE(EXCLUDED.last_ok_at, video_vimeo_sync.last_ok_at)
+        "#,
+    )
+    .bind(video_id)
+    .bind(sync_status)
+    .bind(name)
+    .bind(description.flatten())
+    .bind(sync_error)
+    .bind(is_ok)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_last_vimeo_sync_run(pool: &PgPool) -> AppResult<Option<VimeoSyncRunRes>> {
+    let sql = format!(
+        "SELECT {SYNC_RUN_COLUMNS} FROM vimeo_sync_run ORDER BY run_started_at DESC LIMIT 1"
+    );
+    let row = sqlx::query_as::<_, VimeoSyncRunRes>(&sql)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+pub async fn find_flagged_vimeo_syncs(pool: &PgPool) -> AppResult<Vec<VimeoSyncFlaggedItem>> {
+    let rows = sqlx::query_as::<_, VimeoSyncFlaggedItem>(
+        r#"
+        SELECT
+            v.video_id::bigint AS video_id,
+            v.video_idx,
+            v.video_url_vimeo,
+            v.video_state::text AS video_state,
+            s.sync_status,
+            s.sync_error,
+            s.last_synced_at,
+            s.last_ok_at
+        FROM video_vimeo_sync s
+        JOIN video v ON v.video_id = s.video_id
+        WHERE s.sync_status <> 'ok'
+          AND v.video_host = 'vimeo'
+        ORDER BY s.last_synced_at DESC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,12 +4,15 @@ use axum::{
     Router,
 };
 
+use super::cue::router::admin_cue_router;
 use super::handler::{
     admin_bulk_create_videos, admin_bulk_update_video_tags, admin_bulk_update_videos,
     admin_create_video, admin_create_vimeo_upload_ticket, admin_get_video, admin_get_vimeo_preview,
-    admin_list_videos, admin_update_video, admin_update_video_tags,
+    admin_get_vimeo_sync_report, admin_list_videos, admin_resync_vimeo, admin_update_video,
+    admin_update_video_tags,
 };
 use super::stats::router::{admin_global_stats_router, admin_stats_router};
+use super::subtitle::router::admin_subtitle_router;
 
 pub fn admin_video_router() -> Router<AppState> {
     Router::new()
@@ -24,6 +27,8 @@ pub fn admin_video_router() -> Router<AppState> {
             "/vimeo/upload-ticket",
             post(admin_create_vimeo_upload_ticket),
         )
+        .route("/vimeo/sync-report", get(admin_get_vimeo_sync_report))
+        .route("/vimeo/resync", post(admin_resync_vimeo))
         // 전체 통계 대시보드
         .nest("/stats", admin_global_stats_router())
         // B2: 조회/업데이트
@@ -33,4 +38,6 @@ pub fn admin_video_router() -> Router<AppState> {
         )
         .route("/{video_id}/tags", patch(admin_update_video_tags))
         .nest("/{video_id}/stats", admin_stats_router())
+        .nest("/{video_id}/subtitles", admin_subtitle_router())
+        .nest("/{video_id}/cues", admin_cue_router())
 }
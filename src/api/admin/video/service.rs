@@ -1,14 +1,16 @@
 use super::repo;
+use crate::api::admin::schedule::service::cancel_on_manual_change;
+use crate::api::admin::translation::service::TranslationService;
 use crate::api::admin::video::dto::{
     AdminVideoListReq, AdminVideoListRes, AdminVideoRes, Pagination, VideoBulkCreateReq,
     VideoBulkCreateRes, VideoBulkItemError, VideoBulkItemResult, VideoBulkSummary,
     VideoBulkUpdateItemResult, VideoBulkUpdateReq, VideoBulkUpdateRes, VideoCreateReq,
-    VideoTagBulkUpdateReq, VideoTagUpdateReq, VideoUpdateReq, VimeoPreviewRes,
-    VimeoUploadTicketReq, VimeoUploadTicketRes,
+    VideoTagBulkUpdateReq, VideoTagUpdateReq, VideoUpdateReq, VimeoPreviewRes, VimeoResyncReq,
+    VimeoResyncRes, VimeoSyncReportRes, VimeoUploadTicketReq, VimeoUploadTicketRes,
 };
 use crate::error::{AppError, AppResult};
 use crate::external::vimeo::VimeoClient;
-use crate::types::UserAuth;
+use crate::types::{ContentType, PublishTarget, UserAuth, VideoHostKind};
 use crate::AppState;
 use sqlx::{Postgres, Transaction};
 use std::net::IpAddr;
@@ -17,7 +19,7 @@ use validator::Validate;
 
 const PG_UNIQUE_VIOLATION: &str = "23505";
 
-fn is_unique_violation(err: &AppError) -> bool {
+pub(super) fn is_unique_violation(err: &AppError) -> bool {
     if let AppError::Sqlx(sqlx::Error::Database(db)) = err {
         db.code().as_deref() == Some(PG_UNIQUE_VIOLATION)
     } else {
@@ -25,64 +27,103 @@ fn is_unique_violation(err: &AppError) -> bool {
     }
 }
 
-/// Vimeo URL에서 메타데이터를 가져와 DB에 저장
-async fn sync_vimeo_meta(
+/// 호스트에서 메타데이터를 가져와 DB에 저장
+///
+/// Vimeo API 실패는 로그만 남기고 생성/수정 진행, HLS 패키징 오류(재생목록/키 누락)는 거부.
+async fn sync_host_meta(
     st: &AppState,
     tx: &mut Transaction<'_, Postgres>,
     video_id: i64,
-    vimeo_url: &str,
+    host: VideoHostKind,
+    source: &str,
 ) -> AppResult<()> {
-    // 1. Access Token 확인
-    let access_token = match &st.cfg.vimeo_access_token {
-        Some(token) if !token.is_empty() => token.clone(),
-        _ => {
-            tracing::warn!("Vimeo access token not configured, skipping metadata sync");
+    let meta = match st.video_hosts.get(host)?.fetch_meta(source).await {
+        Ok(Some(meta)) => meta,
+        Ok(None) => return Ok(()),
+        Err(e) if host == VideoHostKind::Vimeo => {
+            tracing::error!("Failed to fetch Vimeo metadata: {:?}", e);
             return Ok(());
         }
+        Err(e) => return Err(e),
     };
 
-    // 2. Vimeo Video ID 추출
-    let vimeo_video_id = match VimeoClient::extract_video_id(vimeo_url) {
-        Some(id) => id,
-        None => {
-            tracing::warn!("Could not extract Vimeo video ID from URL: {}", vimeo_url);
-            return Ok(());
+    match (host, meta.title.as_deref()) {
+        (VideoHostKind::Vimeo, Some(title)) => {
+            repo::update_vimeo_meta(
+                tx,
+                video_id,
+                meta.duration,
+                meta.thumbnail_url.as_deref(),
+                title,
+                meta.description.as_deref(),
+            )
+            .await?;
+            // 주기 동기화(jobs::vimeo_sync) 의 제목 변경 판단 기준 스냅샷
+            repo::upsert_vimeo_sync_status(
+                &mut **tx,
+                video_id,
+                "ok",
+                Some((title, meta.description.as_deref())),
+                None,
+            )
+            .await?;
         }
-    };
-
-    // 3. Vimeo API 호출
-    let client = VimeoClient::new(access_token)?;
-    let meta = match client.get_video_meta(&vimeo_video_id).await {
-        Ok(m) => m,
-        Err(e) => {
-            tracing::error!("Failed to fetch Vimeo metadata: {:?}", e);
-            // Vimeo API 실패해도 영상 생성/수정은 진행
-            return Ok(());
+        _ => {
+            repo::update_vimeo_media(tx, video_id, meta.duration, meta.thumbnail_url.as_deref())
+                .await?;
         }
-    };
-
-    // 4. DB 업데이트
-    repo::update_vimeo_meta(
-        tx,
-        video_id,
-        meta.duration,
-        meta.thumbnail_url.as_deref(),
-        &meta.name,
-        meta.description.as_deref(),
-    )
-    .await?;
+    }
 
     tracing::info!(
-        "Synced Vimeo metadata for video_id={}: duration={}, title={}",
+        "Synced {:?} metadata for video_id={}: duration={}",
+        host,
         video_id,
-        meta.duration,
-        meta.name
+        meta.duration
     );
 
     Ok(())
 }
 
-async fn check_admin_rbac(pool: &sqlx::PgPool, actor_user_id: i64) -> AppResult<UserAuth> {
+/// 생성 요청의 호스트 + 원본 식별자 (선택 호스트의 필드 필수)
+fn create_source(req: &VideoCreateReq) -> AppResult<(VideoHostKind, &str)> {
+    let host = req.video_host.unwrap_or_default();
+    let source = match host {
+        VideoHostKind::Vimeo => req.video_url_vimeo.as_deref(),
+        VideoHostKind::Hls => req.video_hls_path.as_deref(),
+    }
+    .map(str::trim)
+    .filter(|s| !s.is_empty())
+    .ok_or_else(|| {
+        AppError::BadRequest(match host {
+            VideoHostKind::Vimeo => "video_url_vimeo is required for vimeo host".into(),
+            VideoHostKind::Hls => "video_hls_path is required for hls host".into(),
+        })
+    })?;
+    Ok((host, source))
+}
+
+/// hls 비디오에는 Vimeo URL 지정 불가 (호스트 전환 미지원)
+async fn ensure_vimeo_url_updatable(
+    st: &AppState,
+    video_id: i64,
+    req: &VideoUpdateReq,
+) -> AppResult<()> {
+    if req.video_url_vimeo.is_none() {
+        return Ok(());
+    }
+    match repo::find_video_host(&st.db, video_id).await? {
+        Some(VideoHostKind::Vimeo) => Ok(()),
+        Some(VideoHostKind::Hls) => Err(AppError::BadRequest(
+            "video_url_vimeo cannot be set on hls video".into(),
+        )),
+        None => Err(AppError::NotFound),
+    }
+}
+
+pub(super) async fn check_admin_rbac(
+    pool: &sqlx::PgPool,
+    actor_user_id: i64,
+) -> AppResult<UserAuth> {
     let actor = crate::api::user::repo::find_user(pool, actor_user_id)
         .await?
         .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
@@ -163,6 +204,107 @@ pub async fn admin_create_vimeo_upload_ticket(
     })
 }
 
+/// Vimeo 동기화 리포트 (최근 실행 + 이상 비디오)
+pub async fn admin_get_vimeo_sync_report(
+    st: &AppState,
+    actor_user_id: i64,
+) -> AppResult<VimeoSyncReportRes> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+
+    let last_run = repo::find_last_vimeo_sync_run(&st.db).await?;
+    let flagged = repo::find_flagged_vimeo_syncs(&st.db).await?;
+    Ok(VimeoSyncReportRes { last_run, flagged })
+}
+
+/// Vimeo 수동 재동기화
+///
+/// `video_ids` 지정 시 즉시 실행 후 비디오별 결과 반환, 생략 시 전체 실행을 백그라운드로 시작
+/// (반환값 bool = 백그라운드 실행 여부).
+pub async fn admin_resync_vimeo(
+    st: &AppState,
+    actor_user_id: i64,
+    req: VimeoResyncReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<(bool, VimeoResyncRes)> {
+    check_admin_rbac(&st.db, actor_user_id).await?;
+
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+
+    let access_token = st
+        .cfg
+        .vimeo_access_token
+        .as_ref()
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| AppError::BadRequest("Vimeo access token not configured".into()))?;
+    let client = VimeoClient::new(access_token.clone())?;
+    let pace = std::time::Duration::from_millis(st.cfg.vimeo_sync_request_interval_ms);
+
+    let video_ids = req.video_ids.map(|mut ids| {
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    });
+
+    let run = crate::jobs::vimeo_sync::start_run(
+        &st.db,
+        &st.redis,
+        "manual",
+        Some(actor_user_id),
+        video_ids.as_deref(),
+    )
+    .await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "RESYNC_VIMEO",
+        "video",
+        None,
+        &serde_json::json!({ "run_id": run.run_id, "video_ids": video_ids }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    match video_ids {
+        Some(ids) => {
+            let (run, items) = crate::jobs::vimeo_sync::execute_run(
+                &st.db,
+                &st.redis,
+                &client,
+                pace,
+                run,
+                Some(&ids),
+            )
+            .await?;
+            Ok((false, VimeoResyncRes { run, items }))
+        }
+        None => {
+            let db = st.db.clone();
+            let redis = st.redis.clone();
+            let started = run.clone();
+            tokio::spawn(async move {
+                if let Err(e) =
+                    crate::jobs::vimeo_sync::execute_run(&db, &redis, &client, pace, started, None)
+                        .await
+                {
+                    tracing::warn!(error = %e, "manual vimeo resync failed");
+                }
+            });
+            Ok((
+                true,
+                VimeoResyncRes {
+                    run,
+                    items: Vec::new(),
+                },
+            ))
+        }
+    }
+}
+
 pub async fn admin_create_video(
     st: &AppState,
     actor_user_id: i64,
@@ -175,6 +317,12 @@ pub async fn admin_create_video(
     if let Err(e) = req.validate() {
         return Err(AppError::BadRequest(e.to_string()));
     }
+    let (host, source) = create_source(&req)?;
+    // HLS 는 생성 전에 호스트 활성 여부 확인 (미설정 시 503)
+    st.video_hosts.get(host)?;
+    if req.video_state.as_deref() == Some("open") {
+        TranslationService::ensure_open_on_create_allowed(&st.db, ContentType::Video).await?;
+    }
 
     crate::api::admin::user::repo::write_audit_log(
         st,
@@ -208,8 +356,8 @@ pub async fn admin_create_video(
         Err(e) => return Err(e),
     };
 
-    // Vimeo 메타데이터 동기화
-    sync_vimeo_meta(st, &mut tx, created.id, &req.video_url_vimeo).await?;
+    // 호스트 메타데이터 동기화 (duration/thumbnail)
+    sync_host_meta(st, &mut tx, created.id, host, source).await?;
 
     let after = serde_json::to_value(&created).unwrap_or_default();
     repo::create_video_log_tx(
@@ -266,6 +414,11 @@ pub async fn admin_bulk_create_videos(
             if let Err(e) = item.validate() {
                 return Err(AppError::BadRequest(e.to_string()));
             }
+            create_source(&item)?;
+            if item.video_state.as_deref() == Some("open") {
+                TranslationService::ensure_open_on_create_allowed(&st.db, ContentType::Video)
+                    .await?;
+            }
 
             let (video_idx, tag_key) = build_video_keys(&item);
 
@@ -307,6 +460,7 @@ pub async fn admin_bulk_create_videos(
                 let (status, msg) = match e {
                     AppError::BadRequest(m) => (400, m),
                     AppError::Unprocessable(m) => (422, m),
+                    AppError::UnprocessableDetails(m, _) => (422, m),
                     AppError::Conflict(m) => (409, m),
                     AppError::Forbidden(_) => (403, "Forbidden".to_string()),
                     _ => (500, "Internal Server Error".to_string()),
@@ -472,7 +626,7 @@ pub async fn admin_bulk_update_videos(
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
 ) -> AppResult<(bool, VideoBulkUpdateRes)> {
-    check_admin_rbac(&st.db, actor_user_id).await?;
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
 
     if let Err(e) = req.validate() {
         return Err(AppError::BadRequest(e.to_string()));
@@ -514,6 +668,7 @@ pub async fn admin_bulk_update_videos(
                 video_access: item.video_access.clone(),
                 video_state: item.video_state.clone(),
                 video_idx: item.video_idx.clone(),
+                force_publish: None,
             };
 
             let has_any = update_req.video_tag_title.is_some()
@@ -528,25 +683,28 @@ pub async fn admin_bulk_update_videos(
                 return Err(AppError::BadRequest("no fields to update".into()));
             }
 
-            let mut tx = st.db.begin().await?;
-
-            let exists = sqlx::query_scalar::<_, bool>(
-                r#"
-                SELECT EXISTS(
-                    SELECT 1
-                    FROM video
-                    WHERE video_id = $1
+            let current_state = repo::find_video_state(&st.db, item.id)
+                .await?
+                .ok_or(AppError::NotFound)?;
+            ensure_vimeo_url_updatable(st, item.id, &update_req).await?;
+
+            // 일괄 수정은 정책 우회 불가 — 미충족 항목은 422 로 실패 처리
+            if update_req.video_state.as_deref() == Some("open") && current_state != "open" {
+                TranslationService::enforce_publish_policy(
+                    st,
+                    actor_user_id,
+                    actor_auth,
+                    ContentType::Video,
+                    item.id,
+                    false,
+                    None,
+                    None,
                 )
-                "#,
-            )
-            .bind(item.id)
-            .fetch_one(&mut *tx)
-            .await?;
-
-            if !exists {
-                return Err(AppError::NotFound);
+                .await?;
             }
 
+            let mut tx = st.db.begin().await?;
+
             if let Some(video_idx) = update_req.video_idx.as_deref() {
                 if repo::exists_video_idx_for_update(&mut tx, item.id, video_idx.trim()).await? {
                     return Err(AppError::Conflict("video_idx already exists".into()));
@@ -570,6 +728,11 @@ pub async fn admin_bulk_update_videos(
                 Err(e) => return Err(e),
             };
 
+            if update_req.video_state.is_some() {
+                cancel_on_manual_change(&mut tx, PublishTarget::Video, item.id, actor_user_id)
+                    .await?;
+            }
+
             tx.commit().await?;
 
             Ok(updated)
@@ -592,6 +755,7 @@ pub async fn admin_bulk_update_videos(
                     AppError::NotFound => (404, "Video not found".to_string()),
                     AppError::BadRequest(m) => (400, m),
                     AppError::Unprocessable(m) => (422, m),
+                    AppError::UnprocessableDetails(m, _) => (422, m),
                     AppError::Conflict(m) => (409, m),
                     AppError::Forbidden(_) => (403, "Forbidden".to_string()),
                     _ => (500, "Internal Server Error".to_string()),
@@ -688,6 +852,7 @@ pub async fn admin_bulk_update_video_tags(
                 video_access: None,
                 video_state: None,
                 video_idx: None,
+                force_publish: None,
             };
 
             let has_any = update_req.video_tag_title.is_some()
@@ -756,6 +921,7 @@ pub async fn admin_bulk_update_video_tags(
                     AppError::NotFound => (404, "Video not found".to_string()),
                     AppError::BadRequest(m) => (400, m),
                     AppError::Unprocessable(m) => (422, m),
+                    AppError::UnprocessableDetails(m, _) => (422, m),
                     AppError::Conflict(m) => (409, m),
                     AppError::Forbidden(_) => (403, "Forbidden".to_string()),
                     _ => (500, "Internal Server Error".to_string()),
@@ -898,7 +1064,7 @@ pub async fn admin_update_video(
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
 ) -> AppResult<AdminVideoRes> {
-    check_admin_rbac(&st.db, actor_user_id).await?;
+    let actor_auth = check_admin_rbac(&st.db, actor_user_id).await?;
 
     if let Err(e) = req.validate() {
         return Err(AppError::BadRequest(e.to_string()));
@@ -927,21 +1093,24 @@ pub async fn admin_update_video(
         return Err(AppError::BadRequest("no fields to update".into()));
     }
 
-    let exists = sqlx::query_scalar::<_, bool>(
-        r#"
-        SELECT EXISTS(
-            SELECT 1
-            FROM video
-            WHERE video_id = $1
+    let current_state = repo::find_video_state(&st.db, video_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    ensure_vimeo_url_updatable(st, video_id, &req).await?;
+
+    // 공개 전환 시 번역 커버리지 정책 (HYMN force_publish 우회 가능)
+    if req.video_state.as_deref() == Some("open") && current_state != "open" {
+        TranslationService::enforce_publish_policy(
+            st,
+            actor_user_id,
+            actor_auth,
+            ContentType::Video,
+            video_id,
+            req.force_publish.unwrap_or(false),
+            ip_address,
+            user_agent.as_deref(),
         )
-        "#,
-    )
-    .bind(video_id)
-    .fetch_one(&st.db)
-    .await?;
-
-    if !exists {
-        return Err(AppError::NotFound);
+        .await?;
     }
 
     let mut tx = st.db.begin().await?;
@@ -970,7 +1139,7 @@ pub async fn admin_update_video(
 
     // Vimeo URL 변경 시 메타데이터 동기화
     if let Some(ref vimeo_url) = req.video_url_vimeo {
-        sync_vimeo_meta(st, &mut tx, video_id, vimeo_url).await?;
+        sync_host_meta(st, &mut tx, video_id, VideoHostKind::Vimeo, vimeo_url).await?;
     }
 
     let after = serde_json::to_value(&updated).unwrap_or_default();
@@ -985,6 +1154,11 @@ pub async fn admin_update_video(
     )
     .await?;
 
+    // 수동 state 변경은 예약 공개/비공개보다 우선 — pending 예약 명시 취소
+    if req.video_state.is_some() {
+        cancel_on_manual_change(&mut tx, PublishTarget::Video, video_id, actor_user_id).await?;
+    }
+
     tx.commit().await?;
 
     Ok(updated)
@@ -96,3 +96,81 @@ pub struct AggregateDailyStatsRes {
     pub to_date: chrono::NaiveDate,
     pub items: Vec<DailyStatItem>,
 }
+
+// ==========================================
+// 시청 유지율 히트맵 (video_watch_range)
+// ==========================================
+
+/// 히트맵 조회 쿼리
+#[derive(Debug, Clone, Deserialize, ToSchema, IntoParams)]
+pub struct WatchHeatmapQuery {
+    /// 버킷 크기 (초, 기본값: 1). 버킷 수가 3600 을 넘으면 자동으로 커짐
+    pub bucket_sec: Option<i32>,
+}
+
+/// 히트맵 버킷 `[start_sec, end_sec)`
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WatchHeatmapBucket {
+    pub start_sec: i32,
+    pub end_sec: i32,
+    /// 해당 구간을 시청한 사용자 수
+    pub viewers: i64,
+    /// viewers / total_viewers (0.0 ~ 1.0)
+    pub retention_rate: f64,
+}
+
+/// 히트맵 응답
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WatchHeatmapRes {
+    pub video_id: i64,
+    /// 영상 길이 (미상이면 시청 구간 최댓값)
+    pub duration_sec: i32,
+    pub bucket_sec: i32,
+    /// 구간 기록이 있는 사용자 수
+    pub total_viewers: i64,
+    pub buckets: Vec<WatchHeatmapBucket>,
+}
+
+// ==========================================
+// 퀴즈 큐 포인트별 정답률
+// ==========================================
+
+/// 큐 포인트 집계 행 (영상 컨텍스트로 제출된 답안 기준)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct VideoCueStatRow {
+    pub cue_point_id: i64,
+    pub cue_time_ms: i32,
+    pub study_task_id: i32,
+    pub learners: i64,
+    pub attempts: i64,
+    pub correct_attempts: i64,
+    pub first_try_correct: i64,
+    pub solved_learners: i64,
+}
+
+/// 큐 포인트별 통계
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VideoCueStatItem {
+    pub cue_point_id: i64,
+    pub cue_time_ms: i32,
+    pub study_task_id: i32,
+    /// 이 큐에서 한 번이라도 제출한 사용자 수
+    pub learners: i64,
+    pub attempts: i64,
+    pub correct_attempts: i64,
+    /// 첫 제출에서 틀린 사용자 비율 (0.0 ~ 1.0)
+    pub first_try_fail_rate: f64,
+    /// 끝내 맞히지 못한 사용자 수
+    pub unsolved_learners: i64,
+    /// 사용자당 평균 제출 횟수
+    pub avg_attempts: f64,
+}
+
+/// 큐 포인트 통계 응답 (시간순)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VideoCueStatsRes {
+    pub video_id: i64,
+    pub items: Vec<VideoCueStatItem>,
+    /// first_try_fail_rate 가 가장 높은 큐 (제출 없으면 None)
+    pub hardest_cue_point_id: Option<i64>,
+}
@@ -1,6 +1,6 @@
 use super::dto::{
     AggregateDailyStatsRes, DailyStatsQuery, DailyStatsRes, StatsSummaryRes, TopVideosQuery,
-    TopVideosRes,
+    TopVideosRes, VideoCueStatsRes, WatchHeatmapQuery, WatchHeatmapRes,
 };
 use crate::api::auth::extractor::AuthUser;
 use crate::error::AppError;
@@ -39,6 +39,53 @@ pub async fn admin_get_video_daily_stats(
     Ok(Json(res))
 }
 
+/// 비디오 시청 유지율 히트맵 (구간 시청 기록 기반)
+#[utoipa::path(
+    get,
+    path = "/admin/videos/{video_id}/stats/heatmap",
+    tag = "admin_video_stats",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        WatchHeatmapQuery
+    ),
+    responses(
+        (status = 200, description = "OK", body = WatchHeatmapRes),
+        (status = 400, description = "Invalid bucket size", body = crate::error::ErrorBody),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_get_video_watch_heatmap(
+    State(st): State<AppState>,
+    Path(video_id): Path<i64>,
+    Query(q): Query<WatchHeatmapQuery>,
+) -> Result<Json<WatchHeatmapRes>, AppError> {
+    let res = super::service::get_watch_heatmap(&st, video_id, q).await?;
+    Ok(Json(res))
+}
+
+/// 퀴즈 큐 포인트별 정답률 (첫 제출 오답률, 미해결 학습자)
+#[utoipa::path(
+    get,
+    path = "/admin/videos/{video_id}/stats/cues",
+    tag = "admin_video_stats",
+    params(
+        ("video_id" = i64, Path, description = "Video ID")
+    ),
+    responses(
+        (status = 200, description = "OK", body = VideoCueStatsRes),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_get_video_cue_stats(
+    State(st): State<AppState>,
+    Path(video_id): Path<i64>,
+) -> Result<Json<VideoCueStatsRes>, AppError> {
+    let res = super::service::get_cue_stats(&st, video_id).await?;
+    Ok(Json(res))
+}
+
 // ==========================================
 // 신규: 전체 통계 대시보드용
 // ==========================================
@@ -1,4 +1,4 @@
-use super::dto::{DailyStatItem, TopVideoItem};
+use super::dto::{DailyStatItem, TopVideoItem, VideoCueStatRow};
 use crate::error::AppResult;
 use sqlx::{PgPool, Row};
 
@@ -190,3 +190,119 @@ pub async fn fetch_aggregate_daily_stats(
 
     Ok(items)
 }
+
+// ==========================================
+// 시청 유지율 히트맵
+// ==========================================
+
+/// (video_duration, 최대 시청 지점, 구간 기록 사용자 수) — 비디오 없으면 None
+pub async fn fetch_watch_extent(
+    db: &PgPool,
+    video_id: i64,
+) -> AppResult<Option<(Option<i32>, i32, i64)>> {
+    let row = sqlx::query_as::<_, (Option<i32>, i32, i64)>(
+        r#"
+        SELECT
+            v.video_duration,
+            COALESCE((SELECT MAX(r.range_end_sec) FROM video_watch_range r
+                       WHERE r.video_id = v.video_id), 0) AS max_end_sec,
+            (SELECT COUNT(DISTINCT r.user_id) FROM video_watch_range r
+              WHERE r.video_id = v.video_id) AS total_viewers
+        FROM video v
+        WHERE v.video_id = $1
+        "#,
+    )
+    .bind(video_id)
+    .fetch_optional(db)
+    .await?;
+    Ok(row)
+}
+
+/// 버킷별 시청자 수 (버킷 제로필, 0..bucket_count)
+pub async fn fetch_watch_heatmap(
+    db: &PgPool,
+    video_id: i64,
+    bucket_sec: i32,
+    bucket_count: i32,
+) -> AppResult<Vec<(i32, i64)>> {
+    let rows = sqlx::query_as::<_, (i32, i64)>(
+        r#"
+        WITH buckets AS (
+            SELECT generate_series(0, $3 - 1) AS bucket
+        ),
+        hits AS (
+            SELECT b.bucket, COUNT(DISTINCT r.user_id) AS viewers
+            FROM video_watch_range r
+            CROSS JOIN LATERAL generate_series(
+                r.range_start_sec / $2,
+                (r.range_end_sec - 1) / $2
+            ) AS b(bucket)
+            WHERE r.video_id = $1
+            GROUP BY b.bucket
+        )
+        SELECT bk.bucket, COALESCE(h.viewers, 0) AS viewers
+        FROM buckets bk
+        LEFT JOIN hits h ON h.bucket = bk.bucket
+        ORDER BY bk.bucket ASC
+        "#,
+    )
+    .bind(video_id)
+    .bind(bucket_sec)
+    .bind(bucket_count)
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
+
+// ==========================================
+// 퀴즈 큐 포인트별 정답률
+// ==========================================
+
+pub async fn exists_video(db: &PgPool, video_id: i64) -> AppResult<bool> {
+    let exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM video WHERE video_id = $1)")
+            .bind(video_id)
+            .fetch_one(db)
+            .await?;
+    Ok(exists)
+}
+
+/// 큐 포인트별 집계 — 사용자별 첫 제출은 큐 컨텍스트 안에서의 첫 로그 기준
+pub async fn fetch_cue_stats(db: &PgPool, video_id: i64) -> AppResult<Vec<VideoCueStatRow>> {
+    let rows = sqlx::query_as::<_, VideoCueStatRow>(
+        r#"
+        WITH attempts AS (
+            SELECT
+                l.video_cue_point_id,
+                l.user_id,
+                COALESCE(l.study_task_is_correct_log, FALSE) AS is_correct,
+                ROW_NUMBER() OVER (
+                    PARTITION BY l.video_cue_point_id, l.user_id
+                    ORDER BY l.study_task_created_at_log, l.study_task_log_id
+                ) AS attempt_no
+            FROM study_task_log l
+            JOIN video_cue_point c ON c.cue_point_id = l.video_cue_point_id
+            WHERE c.video_id = $1
+              AND l.study_task_action_log = 'finish'
+        )
+        SELECT
+            c.cue_point_id,
+            c.cue_time_ms,
+            c.study_task_id,
+            COUNT(DISTINCT a.user_id) AS learners,
+            COUNT(a.user_id) AS attempts,
+            COUNT(*) FILTER (WHERE a.is_correct) AS correct_attempts,
+            COUNT(*) FILTER (WHERE a.attempt_no = 1 AND a.is_correct) AS first_try_correct,
+            COUNT(DISTINCT a.user_id) FILTER (WHERE a.is_correct) AS solved_learners
+        FROM video_cue_point c
+        LEFT JOIN attempts a ON a.video_cue_point_id = c.cue_point_id
+        WHERE c.video_id = $1
+        GROUP BY c.cue_point_id, c.cue_time_ms, c.study_task_id
+        ORDER BY c.cue_time_ms
+        "#,
+    )
+    .bind(video_id)
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
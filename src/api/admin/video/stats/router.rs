@@ -1,13 +1,16 @@
 use super::handler::{
     admin_get_aggregate_daily_stats, admin_get_stats_summary, admin_get_top_videos,
-    admin_get_video_daily_stats,
+    admin_get_video_cue_stats, admin_get_video_daily_stats, admin_get_video_watch_heatmap,
 };
 use crate::AppState;
 use axum::{routing::get, Router};
 
 /// 특정 비디오의 통계 라우터 (/{video_id}/stats 하위)
 pub fn admin_stats_router() -> Router<AppState> {
-    Router::new().route("/daily", get(admin_get_video_daily_stats))
+    Router::new()
+        .route("/daily", get(admin_get_video_daily_stats))
+        .route("/heatmap", get(admin_get_video_watch_heatmap))
+        .route("/cues", get(admin_get_video_cue_stats))
 }
 
 /// 전체 통계 대시보드 라우터 (/stats 하위)
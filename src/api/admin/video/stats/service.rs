@@ -1,6 +1,7 @@
 use super::dto::{
     AggregateDailyStatsRes, DailyStatsQuery, DailyStatsRes, StatsSummaryRes, TopVideosQuery,
-    TopVideosRes,
+    TopVideosRes, VideoCueStatItem, VideoCueStatRow, VideoCueStatsRes, WatchHeatmapBucket,
+    WatchHeatmapQuery, WatchHeatmapRes,
 };
 use crate::error::{AppError, AppResult};
 use crate::AppState;
@@ -107,3 +108,177 @@ pub async fn get_aggregate_daily_stats(
         items,
     })
 }
+
+// ==========================================
+// 시청 유지율 히트맵
+// ==========================================
+
+/// 히트맵 최대 버킷 수
+const MAX_HEATMAP_BUCKETS: i32 = 3600;
+
+/// 특정 비디오 시청 유지율 히트맵
+pub async fn get_watch_heatmap(
+    st: &AppState,
+    video_id: i64,
+    q: WatchHeatmapQuery,
+) -> AppResult<WatchHeatmapRes> {
+    let requested = q.bucket_sec.unwrap_or(1);
+    if requested < 1 {
+        return Err(AppError::BadRequest("bucket_sec must be >= 1".into()));
+    }
+
+    let (duration, max_end_sec, total_viewers) = super::repo::fetch_watch_extent(&st.db, video_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let duration_sec = duration.filter(|d| *d > 0).unwrap_or(max_end_sec);
+
+    // 긴 영상은 버킷 수 상한에 맞춰 버킷 크기 확대 (ceil)
+    let min_bucket = (duration_sec + MAX_HEATMAP_BUCKETS - 1) / MAX_HEATMAP_BUCKETS;
+    let bucket_sec = requested.max(min_bucket).max(1);
+    let bucket_count = (duration_sec + bucket_sec - 1) / bucket_sec;
+
+    let rows = if bucket_count > 0 {
+        super::repo::fetch_watch_heatmap(&st.db, video_id, bucket_sec, bucket_count).await?
+    } else {
+        Vec::new()
+    };
+
+    let buckets = rows
+        .into_iter()
+        .map(|(bucket, viewers)| WatchHeatmapBucket {
+            start_sec: bucket * bucket_sec,
+            end_sec: ((bucket + 1) * bucket_sec).min(duration_sec),
+            viewers,
+            retention_rate: if total_viewers > 0 {
+                viewers as f64 / total_viewers as f64
+            } else {
+                0.0
+            },
+        })
+        .collect();
+
+    Ok(WatchHeatmapRes {
+        video_id,
+        duration_sec,
+        bucket_sec,
+        total_viewers,
+        buckets,
+    })
+}
+
+/// 집계 행 → 응답 항목 (제출 없는 큐는 비율 0)
+fn to_cue_stat_item(row: VideoCueStatRow) -> VideoCueStatItem {
+    let (first_try_fail_rate, avg_attempts) = if row.learners > 0 {
+        let learners = row.learners as f64;
+        (
+            (row.learners - row.first_try_correct) as f64 / learners,
+            row.attempts as f64 / learners,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+    VideoCueStatItem {
+        cue_point_id: row.cue_point_id,
+        cue_time_ms: row.cue_time_ms,
+        study_task_id: row.study_task_id,
+        learners: row.learners,
+        attempts: row.attempts,
+        correct_attempts: row.correct_attempts,
+        first_try_fail_rate,
+        unsolved_learners: row.learners - row.solved_learners,
+        avg_attempts,
+    }
+}
+
+/// 첫 제출 오답률이 가장 높은 큐 (제출 있는 큐만)
+fn hardest_cue_point(items: &[VideoCueStatItem]) -> Option<i64> {
+    items
+        .iter()
+        .filter(|i| i.learners > 0)
+        .max_by(|a, b| a.first_try_fail_rate.total_cmp(&b.first_try_fail_rate))
+        .map(|i| i.cue_point_id)
+}
+
+/// 퀴즈 큐 포인트별 정답률 — 학습자가 막히는 지점 파악용
+pub async fn get_cue_stats(st: &AppState, video_id: i64) -> AppResult<VideoCueStatsRes> {
+    if !super::repo::exists_video(&st.db, video_id).await? {
+        return Err(AppError::NotFound);
+    }
+
+    let items: Vec<VideoCueStatItem> = super::repo::fetch_cue_stats(&st.db, video_id)
+        .await?
+        .into_iter()
+        .map(to_cue_stat_item)
+        .collect();
+    let hardest_cue_point_id = hardest_cue_point(&items);
+
+    Ok(VideoCueStatsRes {
+        video_id,
+        items,
+        hardest_cue_point_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(
+        cue_point_id: i64,
+        learners: i64,
+        attempts: i64,
+        first_ok: i64,
+        solved: i64,
+    ) -> VideoCueStatRow {
+        VideoCueStatRow {
+            cue_point_id,
+            cue_time_ms: (cue_point_id as i32) * 1000,
+            study_task_id: 1,
+            learners,
+            attempts,
+            correct_attempts: solved,
+            first_try_correct: first_ok,
+            solved_learners: solved,
+        }
+    }
+
+    #[test]
+    fn test_to_cue_stat_item_rates() {
+        // 4명 / 제출 10회 / 첫 제출 정답 1명 / 끝내 정답 3명
+        let item = to_cue_stat_item(row(1, 4, 10, 1, 3));
+        assert_eq!(item.learners, 4);
+        assert!((item.first_try_fail_rate - 0.75).abs() < f64::EPSILON);
+        assert!((item.avg_attempts - 2.5).abs() < f64::EPSILON);
+        assert_eq!(item.unsolved_learners, 1);
+    }
+
+    #[test]
+    fn test_to_cue_stat_item_no_submissions() {
+        let item = to_cue_stat_item(row(1, 0, 0, 0, 0));
+        assert_eq!(item.first_try_fail_rate, 0.0);
+        assert_eq!(item.avg_attempts, 0.0);
+        assert_eq!(item.unsolved_learners, 0);
+    }
+
+    #[test]
+    fn test_hardest_cue_point_picks_highest_first_try_fail_rate() {
+        let items: Vec<VideoCueStatItem> = vec![
+            row(1, 4, 4, 4, 4), // 0.0
+            row(2, 4, 9, 1, 3), // 0.75
+            row(3, 2, 3, 1, 2), // 0.5
+        ]
+        .into_iter()
+        .map(to_cue_stat_item)
+        .collect();
+        assert_eq!(hardest_cue_point(&items), Some(2));
+    }
+
+    #[test]
+    fn test_hardest_cue_point_ignores_cues_without_submissions() {
+        let items: Vec<VideoCueStatItem> = vec![row(1, 0, 0, 0, 0), row(2, 0, 0, 0, 0)]
+            .into_iter()
+            .map(to_cue_stat_item)
+            .collect();
+        assert_eq!(hardest_cue_point(&items), None);
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::api::video::subtitle::SubtitleFormat;
+use crate::types::SupportedLanguage;
+
+// ==========================================
+// 요청
+// ==========================================
+
+/// 자막 업로드 (트랙 교체) 요청 — SRT/VTT 본문을 JSON 문자열로 전달
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SubtitleUploadReq {
+    /// 생략 시 `WEBVTT` 헤더 유무로 판별
+    pub format: Option<SubtitleFormat>,
+    #[validate(length(min = 1, max = 2_000_000))]
+    pub content: String,
+    /// 플레이어 표시명 (예: "한국어", "English (CC)")
+    #[validate(length(max = 100))]
+    pub label: Option<String>,
+    /// 저장 후 Vimeo 텍스트 트랙으로 바로 push
+    pub push_to_vimeo: Option<bool>,
+}
+
+// ==========================================
+// 응답
+// ==========================================
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminSubtitleTrackRes {
+    pub video_subtitle_id: i64,
+    pub video_id: i64,
+    pub lang: SupportedLanguage,
+    pub subtitle_label: Option<String>,
+    pub subtitle_source_format: String,
+    pub cue_count: i64,
+    pub vimeo_texttrack_uri: Option<String>,
+    pub vimeo_pushed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_by_user_id: Option<i64>,
+    pub subtitle_updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminSubtitleListRes {
+    pub video_id: i64,
+    /// 업로드된 트랙
+    pub tracks: Vec<AdminSubtitleTrackRes>,
+    /// 업로드 트랙 없이 ko 큐 번역(approved)으로 제공되는 언어
+    pub translated_langs: Vec<SupportedLanguage>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, sqlx::FromRow)]
+pub struct AdminSubtitleCueRes {
+    /// ko 트랙 큐는 번역 content_id (content_type = video_subtitle_cue)
+    pub cue_id: i64,
+    pub cue_seq: i32,
+    pub cue_start_ms: i32,
+    pub cue_end_ms: i32,
+    pub cue_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AdminSubtitleDetailRes {
+    pub track: AdminSubtitleTrackRes,
+    pub cues: Vec<AdminSubtitleCueRes>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubtitleUploadRes {
+    pub track: AdminSubtitleTrackRes,
+    /// ko 트랙 재업로드로 제거된 큐 수 (해당 번역도 삭제)
+    pub removed_cues: i64,
+    /// ko 큐 원문 변경으로 draft 로 되돌린 번역 수
+    pub reset_translations: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SubtitleVimeoPushRes {
+    pub video_id: i64,
+    pub lang: SupportedLanguage,
+    pub vimeo_texttrack_uri: String,
+}
@@ -0,0 +1,181 @@
+use super::super::handler::{extract_client_ip, extract_user_agent};
+use super::dto::{
+    AdminSubtitleDetailRes, AdminSubtitleListRes, SubtitleUploadReq, SubtitleUploadRes,
+    SubtitleVimeoPushRes,
+};
+use crate::api::auth::extractor::AuthUser;
+use crate::error::AppResult;
+use crate::extract::AppJson;
+use crate::types::SupportedLanguage;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+
+/// 비디오 자막 트랙 목록
+#[utoipa::path(
+    get,
+    path = "/admin/videos/{video_id}/subtitles",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID")
+    ),
+    responses(
+        (status = 200, description = "Subtitle tracks", body = AdminSubtitleListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_list_subtitles(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(video_id): Path<i64>,
+) -> AppResult<Json<AdminSubtitleListRes>> {
+    let res = super::service::list_subtitles(&st, auth_user.sub, video_id).await?;
+    Ok(Json(res))
+}
+
+/// 자막 트랙 상세 (큐 포함)
+#[utoipa::path(
+    get,
+    path = "/admin/videos/{video_id}/subtitles/{lang}",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        ("lang" = String, Path, description = "자막 언어 (ko, en, zh-CN ...)")
+    ),
+    responses(
+        (status = 200, description = "Subtitle track", body = AdminSubtitleDetailRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Track not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_get_subtitle(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path((video_id, lang)): Path<(i64, SupportedLanguage)>,
+) -> AppResult<Json<AdminSubtitleDetailRes>> {
+    let res = super::service::get_subtitle(&st, auth_user.sub, video_id, lang).await?;
+    Ok(Json(res))
+}
+
+/// 자막 업로드 (SRT/VTT, 기존 트랙 교체)
+#[utoipa::path(
+    put,
+    path = "/admin/videos/{video_id}/subtitles/{lang}",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        ("lang" = String, Path, description = "자막 언어 (ko, en, zh-CN ...)")
+    ),
+    request_body = SubtitleUploadReq,
+    responses(
+        (status = 200, description = "Subtitle uploaded", body = SubtitleUploadRes),
+        (status = 400, description = "Invalid subtitle file", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody),
+        (status = 502, description = "Vimeo push failed", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_upload_subtitle(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((video_id, lang)): Path<(i64, SupportedLanguage)>,
+    AppJson(req): AppJson<SubtitleUploadReq>,
+) -> AppResult<Json<SubtitleUploadRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res = super::service::upload_subtitle(
+        &st,
+        auth_user.sub,
+        video_id,
+        lang,
+        req,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+/// 자막 트랙 삭제
+#[utoipa::path(
+    delete,
+    path = "/admin/videos/{video_id}/subtitles/{lang}",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        ("lang" = String, Path, description = "자막 언어 (ko, en, zh-CN ...)")
+    ),
+    responses(
+        (status = 204, description = "Subtitle deleted"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Track not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_delete_subtitle(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((video_id, lang)): Path<(i64, SupportedLanguage)>,
+) -> AppResult<StatusCode> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    super::service::delete_subtitle(&st, auth_user.sub, video_id, lang, ip_address, user_agent)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 자막 트랙 Vimeo push (업로드 트랙 또는 번역 트랙)
+#[utoipa::path(
+    post,
+    path = "/admin/videos/{video_id}/subtitles/{lang}/vimeo",
+    tag = "admin_video",
+    params(
+        ("video_id" = i64, Path, description = "Video ID"),
+        ("lang" = String, Path, description = "자막 언어 (ko, en, zh-CN ...)")
+    ),
+    responses(
+        (status = 200, description = "Pushed to Vimeo", body = SubtitleVimeoPushRes),
+        (status = 400, description = "Vimeo not configured or invalid URL", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Forbidden", body = crate::error::ErrorBody),
+        (status = 404, description = "Video not found", body = crate::error::ErrorBody),
+        (status = 422, description = "Track not available", body = crate::error::ErrorBody),
+        (status = 502, description = "Vimeo API error", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn admin_push_subtitle_vimeo(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path((video_id, lang)): Path<(i64, SupportedLanguage)>,
+) -> AppResult<Json<SubtitleVimeoPushRes>> {
+    let ip_address = extract_client_ip(&headers);
+    let user_agent = extract_user_agent(&headers);
+
+    let res = super::service::push_subtitle_to_vimeo(
+        &st,
+        auth_user.sub,
+        video_id,
+        lang,
+        ip_address,
+        user_agent,
+    )
+    .await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use sqlx::{PgPool, Postgres, Transaction};
+
+use super::dto::{AdminSubtitleCueRes, AdminSubtitleTrackRes};
+use crate::api::video::subtitle::{SubtitleCue, SubtitleFormat};
+use crate::error::AppResult;
+use crate::types::SupportedLanguage;
+
+const TRACK_SELECT: &str = r#"
+    SELECT
+        s.video_subtitle_id,
+        s.video_id::bigint AS video_id,
+        s.lang,
+        s.subtitle_label,
+        s.subtitle_source_format,
+        (SELECT COUNT(*) FROM video_subtitle_cue c
+          WHERE c.video_subtitle_id = s.video_subtitle_id) AS cue_count,
+        vm.vimeo_texttrack_uri,
+        vm.pushed_at AS vimeo_pushed_at,
+        s.updated_by_user_id,
+        s.subtitle_updated_at
+    FROM video_subtitle s
+    LEFT JOIN video_subtitle_vimeo vm ON vm.video_id = s.video_id AND vm.lang = s.lang
+"#;
+
+/// 비디오 Vimeo URL (비디오 존재 확인 겸용 — hls 비디오는 `Some(None)`)
+pub async fn find_video_url_vimeo(db: &PgPool, video_id: i64) -> AppResult<Option<Option<String>>> {
+    let url = sqlx::query_scalar::<_, Option<String>>(
+        r#"SELECT CASE WHEN video_host = 'vimeo' THEN video_url_vimeo END FROM video WHERE video_id = $1"#,
+    )
+    .bind(video_id)
+    .fetch_optional(db)
+    .await?;
+    Ok(url)
+}
+
+pub async fn find_tracks(db: &PgPool, video_id: i64) -> AppResult<Vec<AdminSubtitleTrackRes>> {
+    let sql = format!("{TRACK_SELECT} WHERE s.video_id = $1 ORDER BY (s.lang = 'ko') DESC, s.lang");
+    let rows = sqlx::query_as::<_, AdminSubtitleTrackRes>(&sql)
+        .bind(video_id)
+        .fetch_all(db)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_track(
+    db: &PgPool,
+    video_id: i64,
+    lang: SupportedLanguage,
+) -> AppResult<Option<AdminSubtitleTrackRes>> {
+    let sql = format!("{TRACK_SELECT} WHERE s.video_id = $1 AND s.lang = $2");
+    let row = sqlx::query_as::<_, AdminSubtitleTrackRes>(&sql)
+        .bind(video_id)
+        .bind(lang)
+        .fetch_optional(db)
+        .await?;
+    Ok(row)
+}
+
+pub async fn find_cues(db: &PgPool, video_subtitle_id: i64) -> AppResult<Vec<AdminSubtitleCueRes>> {
+    let rows = sqlx::query_as::<_, AdminSubtitleCueRes>(
+        r#"
+        SELECT cue_id, cue_seq, cue_start_ms, cue_end_ms, cue_text
+        FROM video_subtitle_cue
+        WHERE video_subtitle_id = $1
+        ORDER BY cue_seq
+        "#,
+    )
+    .bind(video_subtitle_id)
+    .fetch_all(db)
+    .await?;
+    Ok(rows)
+}
+
+/// 트랙 upsert → video_subtitle_id
+pub async fn upsert_track_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    video_id: i64,
+    lang: SupportedLanguage,
+    label: Option<&str>,
+    format: SubtitleFormat,
+    actor_user_id: i64,
+) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO video_subtitle (
+            video_id, lang, subtitle_label, subtitle_source_format, updated_by_user_id
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (video_id, lang) DO UPDATE SET
+            subtitle_label = COALESCE(EXCLUDED.subtitle_label, video_subtitle.subtitle_label),
+            subtitle_source_format = EXCLUDED.subtitle_source_format,
+            updated_by_user_id = EXCLUDED.updated_by_user_id,
+            subtitle_updated_at = NOW()
+        RETURNING video_subtitle_id
+        "#,
+    )
+    .bind(video_id)
+    .bind(lang)
+    .bind(label)
+    .bind(format.as_str())
+    .bind(actor_user_id)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(id)
+}
+
+/// 큐 교체 — cue_seq 기준 upsert 로 cue_id 유지, 초과분 삭제
+///
+/// 반환: (원문이 바뀐 cue_id, 삭제된 cue_id)
+pub async fn replace_cues_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    video_subtitle_id: i64,
+    cues: &[SubtitleCue],
+) -> AppResult<(Vec<i64>, Vec<i64>)> {
+    let existing: HashMap<i32, (i64, String)> = sqlx::query_as::<_, (i32, i64, String)>(
+        r#"
+        SELECT cue_seq, cue_id, cue_text
+        FROM video_subtitle_cue
+        WHERE video_subtitle_id = $1
+        "#,
+    )
+    .bind(video_subtitle_id)
+    .fetch_all(&mut **tx)
+    .await?
+    .into_iter()
+    .map(|(seq, id, text)| (seq, (id, text)))
+    .collect();
+
+    let seqs: Vec<i32> = (1..=cues.len() as i32).collect();
+    let changed: Vec<i64> = seqs
+        .iter()
+        .zip(cues)
+        .filter_map(|(seq, cue)| {
+            existing
+                .get(seq)
+                .filter(|(_, text)| *text != cue.text)
+                .map(|(id, _)| *id)
+        })
+        .collect();
+
+    let starts: Vec<i32> = cues.iter().map(|c| c.start_ms).collect();
+    let ends: Vec<i32> = cues.iter().map(|c| c.end_ms).collect();
+    let texts: Vec<&str> = cues.iter().map(|c| c.text.as_str()).collect();
+
+    sqlx::query(
+        r#"
+        INSERT INTO video_subtitle_cue (
+            video_subtitle_id, cue_seq, cue_start_ms, cue_end_ms, cue_text
+        )
+        SELECT $1, t.seq, t.start_ms, t.end_ms, t.text
+        FROM UNNEST($2::int[], $3::int[], $4::int[], $5::text[])
+            AS t(seq, start_ms, end_ms, text)
+        ON CONFLICT (video_subtitle_id, cue_seq) DO UPDATE SET
+            cue_start_ms = EXCLUDED.cue_start_ms,
+            cue_end_ms = EXCLUDED.cue_end_ms,
+            cue_text = EXCLUDED.cue_text
+        "#,
+    )
+    .bind(video_subtitle_id)
+    .bind(&seqs)
+    .bind(&starts)
+    .bind(&ends)
+    .bind(&texts)
+    .execute(&mut **tx)
+    .await?;
+
+    let removed = sqlx::query_scalar::<_, i64>(
+        r#"
+        DELETE FROM video_subtitle_cue
+        WHERE video_subtitle_id = $1 AND cue_seq > $2
+        RETURNING cue_id
+        "#,
+    )
+    .bind(video_subtitle_id)
+    .bind(cues.len() as i32)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    Ok((changed, removed))
+}
+
+/// 트랙 삭제 (큐 CASCADE) → 삭제된 cue_id 목록, 트랙 없으면 None
+pub async fn delete_track_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    video_id: i64,
+    lang: SupportedLanguage,
+) -> AppResult<Option<Vec<i64>>> {
+    let subtitle_id = sqlx::query_scalar::<_, i64>(
+        r#"SELECT video_subtitle_id FROM video_subtitle WHERE video_id = $1 AND lang = $2"#,
+    )
+    .bind(video_id)
+    .bind(lang)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    let Some(subtitle_id) = subtitle_id else {
+        return Ok(None);
+    };
+
+    let cue_ids = sqlx::query_scalar::<_, i64>(
+        r#"SELECT cue_id FROM video_subtitle_cue WHERE video_subtitle_id = $1"#,
+    )
+    .bind(subtitle_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    sqlx::query(r#"DELETE FROM video_subtitle WHERE video_subtitle_id = $1"#)
+        .bind(subtitle_id)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(Some(cue_ids))
+}
+
+/// 큐 번역 삭제 (ko 큐 제거 시)
+pub async fn delete_cue_translations_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    cue_ids: &[i64],
+) -> AppResult<u64> {
+    if cue_ids.is_empty() {
+        return Ok(0);
+    }
+    let res = sqlx::query(
+        r#"
+        DELETE FROM content_translations
+        WHERE content_type = 'video_subtitle_cue'
+          AND content_id = ANY($1)
+        "#,
+    )
+    .bind(cue_ids)
+    .execute(&mut **tx)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+/// 큐 번역 draft 복귀 (ko 큐 원문 변경 시 재검수 대상)
+pub async fn reset_cue_translations_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    cue_ids: &[i64],
+) -> AppResult<u64> {
+    if cue_ids.is_empty() {
+        return Ok(0);
+    }
+    let res = sqlx::query(
+        r#"
+        UPDATE content_translations
+        SET status = 'draft', updated_at = NOW()
+        WHERE content_type = 'video_subtitle_cue'
+          AND content_id = ANY($1)
+          AND status <> 'draft'
+        "#,
+    )
+    .bind(cue_ids)
+    .execute(&mut **tx)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+pub async fn find_vimeo_track_uri(
+    db: &PgPool,
+    video_id: i64,
+    lang: SupportedLanguage,
+) -> AppResult<Option<String>> {
+    let uri = sqlx::query_scalar::<_, String>(
+        r#"SELECT vimeo_texttrack_uri FROM video_subtitle_vimeo WHERE video_id = $1 AND lang = $2"#,
+    )
+    .bind(video_id)
+    .bind(lang)
+    .fetch_optional(db)
+    .await?;
+    Ok(uri)
+}
+
+pub async fn upsert_vimeo_track(
+    db: &PgPool,
+    video_id: i64,
+    lang: SupportedLanguage,
+    uri: &str,
+    actor_user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO video_subtitle_vimeo (video_id, lang, vimeo_texttrack_uri, pushed_by_user_id)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (video_id, lang) DO UPDATE SET
+            vimeo_texttrack_uri = EXCLUDED.vimeo_texttrack_uri,
+            pushed_by_user_id = EXCLUDED.pushed_by_user_id,
+            pushed_at = NOW()
+        "#,
+    )
+    .bind(video_id)
+    .bind(lang)
+    .bind(uri)
+    .bind(actor_user_id)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+pub async fn delete_vimeo_track(
+    db: &PgPool,
+    video_id: i64,
+    lang: SupportedLanguage,
+) -> AppResult<()> {
+    sqlx::query(r#"DELETE FROM video_subtitle_vimeo WHERE video_id = $1 AND lang = $2"#)
+        .bind(video_id)
+        .bind(lang)
+        .execute(db)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use super::handler::{
+    admin_delete_subtitle, admin_get_subtitle, admin_list_subtitles, admin_push_subtitle_vimeo,
+    admin_upload_subtitle,
+};
+use crate::AppState;
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+/// 특정 비디오의 자막 라우터 (/{video_id}/subtitles 하위)
+pub fn admin_subtitle_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(admin_list_subtitles))
+        .route(
+            "/{lang}",
+            get(admin_get_subtitle)
+                .put(admin_upload_subtitle)
+                .delete(admin_delete_subtitle),
+        )
+        .route("/{lang}/vimeo", post(admin_push_subtitle_vimeo))
+}
@@ -0,0 +1,258 @@
+use std::net::IpAddr;
+
+use validator::Validate;
+
+use super::dto::{
+    AdminSubtitleDetailRes, AdminSubtitleListRes, SubtitleUploadReq, SubtitleUploadRes,
+    SubtitleVimeoPushRes,
+};
+use crate::api::video::repo::VideoRepo;
+use crate::api::video::service::VideoService;
+use crate::api::video::subtitle::{lang_code, parse_subtitle};
+use crate::error::{AppError, AppResult};
+use crate::external::vimeo::VimeoClient;
+use crate::types::SupportedLanguage;
+use crate::AppState;
+
+/// 비디오 존재 확인 — Vimeo URL 반환 (hls 비디오는 None)
+async fn ensure_video(st: &AppState, video_id: i64) -> AppResult<Option<String>> {
+    super::repo::find_video_url_vimeo(&st.db, video_id)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+fn vimeo_client(st: &AppState) -> AppResult<VimeoClient> {
+    let access_token = st
+        .cfg
+        .vimeo_access_token
+        .as_ref()
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| AppError::BadRequest("Vimeo access token not configured".into()))?;
+    VimeoClient::new(access_token.clone())
+}
+
+/// 트랙 목록 (업로드 트랙 + 번역 트랙 제공 언어)
+pub async fn list_subtitles(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+) -> AppResult<AdminSubtitleListRes> {
+    super::super::service::check_admin_rbac(&st.db, actor_user_id).await?;
+    ensure_video(st, video_id).await?;
+
+    let tracks = super::repo::find_tracks(&st.db, video_id).await?;
+    let translated_langs = if tracks.iter().any(|t| t.lang == SupportedLanguage::Ko) {
+        VideoRepo::find_translated_subtitle_langs(&st.db, video_id)
+            .await?
+            .into_iter()
+            .filter(|lang| !tracks.iter().any(|t| t.lang == *lang))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(AdminSubtitleListRes {
+        video_id,
+        tracks,
+        translated_langs,
+    })
+}
+
+/// 업로드 트랙 상세 (큐 포함)
+pub async fn get_subtitle(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    lang: SupportedLanguage,
+) -> AppResult<AdminSubtitleDetailRes> {
+    super::super::service::check_admin_rbac(&st.db, actor_user_id).await?;
+
+    let track = super::repo::find_track(&st.db, video_id, lang)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let cues = super::repo::find_cues(&st.db, track.video_subtitle_id).await?;
+    Ok(AdminSubtitleDetailRes { track, cues })
+}
+
+/// SRT/VTT 업로드 — 트랙 교체
+///
+/// ko 트랙은 번역 원천이므로 cue_seq 기준으로 cue_id 를 유지하고,
+/// 원문이 바뀐 큐의 번역은 draft 로 되돌리며 제거된 큐의 번역은 삭제한다.
+pub async fn upload_subtitle(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    lang: SupportedLanguage,
+    req: SubtitleUploadReq,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<SubtitleUploadRes> {
+    super::super::service::check_admin_rbac(&st.db, actor_user_id).await?;
+
+    if let Err(e) = req.validate() {
+        return Err(AppError::BadRequest(e.to_string()));
+    }
+    ensure_video(st, video_id).await?;
+
+    let (format, cues) = parse_subtitle(&req.content, req.format).map_err(AppError::BadRequest)?;
+    let label = req
+        .label
+        .as_deref()
+        .map(str::trim)
+        .filter(|l| !l.is_empty());
+
+    let mut tx = st.db.begin().await?;
+    let subtitle_id =
+        super::repo::upsert_track_tx(&mut tx, video_id, lang, label, format, actor_user_id).await?;
+    let (changed, removed) = super::repo::replace_cues_tx(&mut tx, subtitle_id, &cues).await?;
+
+    let reset_translations = if lang == SupportedLanguage::Ko {
+        super::repo::delete_cue_translations_tx(&mut tx, &removed).await?;
+        super::repo::reset_cue_translations_tx(&mut tx, &changed).await?
+    } else {
+        0
+    };
+    tx.commit().await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "UPLOAD_SUBTITLE",
+        "video",
+        Some(video_id),
+        &serde_json::json!({
+            "lang": lang,
+            "format": format,
+            "cue_count": cues.len(),
+            "removed_cues": removed.len(),
+            "reset_translations": reset_translations,
+        }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    if req.push_to_vimeo.unwrap_or(false) {
+        push_subtitle_to_vimeo(st, actor_user_id, video_id, lang, ip_address, user_agent).await?;
+    }
+
+    let track = super::repo::find_track(&st.db, video_id, lang)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    Ok(SubtitleUploadRes {
+        track,
+        removed_cues: removed.len() as i64,
+        reset_translations: reset_translations as i64,
+    })
+}
+
+/// 업로드 트랙 삭제 — ko 트랙이면 큐 번역도 삭제 (번역 트랙도 더 이상 제공되지 않음)
+pub async fn delete_subtitle(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    lang: SupportedLanguage,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<()> {
+    super::super::service::check_admin_rbac(&st.db, actor_user_id).await?;
+
+    let mut tx = st.db.begin().await?;
+    let cue_ids = super::repo::delete_track_tx(&mut tx, video_id, lang)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let deleted_translations = if lang == SupportedLanguage::Ko {
+        super::repo::delete_cue_translations_tx(&mut tx, &cue_ids).await?
+    } else {
+        0
+    };
+    tx.commit().await?;
+
+    // Vimeo 에 push 된 트랙은 best-effort 정리 (실패해도 로컬 삭제는 유지)
+    if let Some(uri) = super::repo::find_vimeo_track_uri(&st.db, video_id, lang).await? {
+        match vimeo_client(st) {
+            Ok(client) => {
+                if let Err(e) = client.delete_text_track(&uri).await {
+                    tracing::warn!(video_id, uri = %uri, error = %e, "vimeo texttrack delete failed");
+                }
+            }
+            Err(e) => tracing::warn!(video_id, error = %e, "vimeo texttrack delete skipped"),
+        }
+        super::repo::delete_vimeo_track(&st.db, video_id, lang).await?;
+    }
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "DELETE_SUBTITLE",
+        "video",
+        Some(video_id),
+        &serde_json::json!({
+            "lang": lang,
+            "cue_count": cue_ids.len(),
+            "deleted_translations": deleted_translations,
+        }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// 트랙을 Vimeo 텍스트 트랙으로 push (업로드 트랙 또는 번역 트랙) — 기존 push 트랙은 교체
+pub async fn push_subtitle_to_vimeo(
+    st: &AppState,
+    actor_user_id: i64,
+    video_id: i64,
+    lang: SupportedLanguage,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+) -> AppResult<SubtitleVimeoPushRes> {
+    super::super::service::check_admin_rbac(&st.db, actor_user_id).await?;
+
+    let url = ensure_video(st, video_id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest("Video is not hosted on Vimeo".into()))?;
+    let vimeo_video_id = VimeoClient::extract_video_id(&url)
+        .ok_or_else(|| AppError::BadRequest("Invalid Vimeo URL".into()))?;
+    let vtt = VideoService::render_subtitle_track(&st.db, video_id, lang)
+        .await?
+        .ok_or_else(|| {
+            AppError::Unprocessable("subtitle track not available for this language".into())
+        })?;
+
+    let client = vimeo_client(st)?;
+    if let Some(old_uri) = super::repo::find_vimeo_track_uri(&st.db, video_id, lang).await? {
+        client.delete_text_track(&old_uri).await?;
+    }
+
+    let code = lang_code(lang);
+    let name = super::repo::find_track(&st.db, video_id, lang)
+        .await?
+        .and_then(|t| t.subtitle_label)
+        .unwrap_or_else(|| code.clone());
+    let uri = client
+        .upload_text_track(&vimeo_video_id, &code, &name, &vtt)
+        .await?;
+    super::repo::upsert_vimeo_track(&st.db, video_id, lang, &uri, actor_user_id).await?;
+
+    crate::api::admin::user::repo::write_audit_log(
+        st,
+        actor_user_id,
+        "PUSH_SUBTITLE_VIMEO",
+        "video",
+        Some(video_id),
+        &serde_json::json!({ "lang": lang, "vimeo_texttrack_uri": uri }),
+        ip_address,
+        user_agent.as_deref(),
+    )
+    .await?;
+
+    Ok(SubtitleVimeoPushRes {
+        video_id,
+        lang,
+        vimeo_texttrack_uri: uri,
+    })
+}
@@ -0,0 +1,187 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{AssignmentItemType, AssignmentLatePolicy};
+
+// =============================================================================
+// 요청
+// =============================================================================
+
+/// 과제 항목 — guide 는 sentence_no 범위 지정 가능 (생략 시 단원 전체)
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AssignmentItemReq {
+    pub item_type: AssignmentItemType,
+    /// study_id / lesson_id / video_id / guide_id
+    #[validate(range(min = 1))]
+    pub target_id: i64,
+    #[validate(range(min = 1))]
+    pub sentence_start: Option<i32>,
+    #[validate(range(min = 1))]
+    pub sentence_end: Option<i32>,
+}
+
+/// 과제 생성 — classroom_id 와 user_ids 중 최소 하나 필요 (둘 다 주면 합집합)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateAssignmentReq {
+    pub classroom_id: Option<i64>,
+    #[validate(length(max = 500))]
+    pub user_ids: Option<Vec<i64>>,
+    #[validate(length(min = 1, max = 200))]
+    pub assignment_title: String,
+    #[validate(length(max = 4000))]
+    pub assignment_description: Option<String>,
+    /// 공개 시각 (생략 시 즉시)
+    pub open_at: Option<DateTime<Utc>>,
+    pub due_at: DateTime<Utc>,
+    /// 기본 allow
+    pub late_policy: Option<AssignmentLatePolicy>,
+    /// allow 일 때 지각 인정 마감 (생략 시 무기한)
+    pub late_until: Option<DateTime<Utc>>,
+    #[validate(length(min = 1, max = 50), nested)]
+    pub items: Vec<AssignmentItemReq>,
+}
+
+/// 과제 수정 — 일정/정책/설명만 (항목·대상 변경은 새 과제로)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateAssignmentReq {
+    #[validate(length(min = 1, max = 200))]
+    pub assignment_title: Option<String>,
+    #[validate(length(max = 4000))]
+    pub assignment_description: Option<String>,
+    pub open_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub late_policy: Option<AssignmentLatePolicy>,
+    pub late_until: Option<DateTime<Utc>>,
+    /// true 면 late_until 제거 (무기한)
+    pub clear_late_until: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AssignmentListQuery {
+    /// 특정 클래스의 과제만
+    pub classroom_id: Option<i64>,
+}
+
+/// 추적 화면 조회 옵션
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AssignmentTrackingQuery {
+    /// true 면 실명 포함 (복호화 감사 로그 기록)
+    pub include_names: Option<bool>,
+}
+
+// =============================================================================
+// 응답
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct AssignmentItemRes {
+    pub assignment_item_id: i64,
+    pub item_seq: i32,
+    pub item_type: AssignmentItemType,
+    pub target_id: i64,
+    pub target_idx: Option<String>,
+    pub target_title: Option<String>,
+    pub sentence_start: Option<i32>,
+    pub sentence_end: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssignmentRes {
+    pub assignment_id: i64,
+    pub classroom_id: Option<i64>,
+    pub classroom_name: Option<String>,
+    pub created_by_user_id: i64,
+    pub assignment_title: String,
+    pub assignment_description: Option<String>,
+    pub assignment_open_at: DateTime<Utc>,
+    pub assignment_due_at: DateTime<Utc>,
+    pub assignment_late_policy: AssignmentLatePolicy,
+    pub assignment_late_until: Option<DateTime<Utc>>,
+    /// 대상 학습자 수 (클래스 active 멤버 ∪ 지정 학습자)
+    pub assignee_count: i64,
+    pub assignment_created_at: DateTime<Utc>,
+    pub assignment_updated_at: DateTime<Utc>,
+    pub items: Vec<AssignmentItemRes>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssignmentListRes {
+    pub items: Vec<AssignmentRes>,
+}
+
+/// 학습자별 과제 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentStatus {
+    NotStarted,
+    InProgress,
+    /// 마감 전 완료
+    Completed,
+    /// 마감 후 완료 (allow 정책)
+    Late,
+    /// 인정 기한이 지났는데 미완료 (deny 정책의 마감 후 완료 포함)
+    Missed,
+}
+
+/// 항목별 진행 — total/done 단위: study = task, guide = 문장, video/lesson = 1
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssignmentItemProgress {
+    pub assignment_item_id: i64,
+    pub total: i64,
+    pub done: i64,
+    pub completed: bool,
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+/// 추적 화면의 학습자 1명
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssignmentLearnerStatus {
+    pub user_id: i64,
+    pub nickname: String,
+    /// 실명 — include_names 일 때만 채움
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub status: AssignmentStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub items_completed: i64,
+    pub items_total: i64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    pub items: Vec<AssignmentItemProgress>,
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct AssignmentTrackingSummary {
+    pub assignees: i64,
+    pub not_started: i64,
+    pub in_progress: i64,
+    pub completed: i64,
+    pub late: i64,
+    pub missed: i64,
+}
+
+/// manager 추적 화면
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssignmentTrackingRes {
+    pub assignment: AssignmentRes,
+    pub summary: AssignmentTrackingSummary,
+    pub learners: Vec<AssignmentLearnerStatus>,
+}
+
+/// 학습자 본인 과제 1건
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MyAssignmentRes {
+    pub assignment: AssignmentRes,
+    pub status: AssignmentStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub items: Vec<AssignmentItemProgress>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MyAssignmentListRes {
+    pub items: Vec<MyAssignmentRes>,
+}
@@ -0,0 +1,229 @@
+use super::{
+    dto::{
+        AssignmentListQuery, AssignmentListRes, AssignmentRes, AssignmentTrackingQuery,
+        AssignmentTrackingRes, CreateAssignmentReq, MyAssignmentListRes, UpdateAssignmentReq,
+    },
+    service::AssignmentService,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::extract::AppJson;
+use crate::{
+    api::auth::extractor::AuthUser,
+    error::{AppError, AppResult},
+    state::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use validator::Validate;
+
+// =============================================================================
+// manager
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/assignments",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    params(AssignmentListQuery),
+    responses(
+        (status = 200, description = "Managed assignments (own/own-classroom for managers, all for HYMN/admin)", body = AssignmentListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_assignments(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Query(query): Query<AssignmentListQuery>,
+) -> AppResult<Json<AssignmentListRes>> {
+    let res = AssignmentService::list_managed(&st, claims.sub, query).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/assignments",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    request_body = CreateAssignmentReq,
+    responses(
+        (status = 201, description = "Assignment created", body = AssignmentRes),
+        (status = 400, description = "Validation error / invalid assignee or target", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn create_assignment(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    headers: HeaderMap,
+    AppJson(req): AppJson<CreateAssignmentReq>,
+) -> AppResult<(StatusCode, Json<AssignmentRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = AssignmentService::create(
+        &st,
+        claims.sub,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/assignments/{assignment_id}",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    params(
+        ("assignment_id" = i64, Path, description = "Assignment ID")
+    ),
+    responses(
+        (status = 200, description = "Assignment detail", body = AssignmentRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Assignment not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_assignment(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(assignment_id): Path<i64>,
+) -> AppResult<Json<AssignmentRes>> {
+    let res = AssignmentService::get(&st, claims.sub, assignment_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/assignments/{assignment_id}",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    params(
+        ("assignment_id" = i64, Path, description = "Assignment ID")
+    ),
+    request_body = UpdateAssignmentReq,
+    responses(
+        (status = 200, description = "Assignment updated", body = AssignmentRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Assignment not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn update_assignment(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(assignment_id): Path<i64>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<UpdateAssignmentReq>,
+) -> AppResult<Json<AssignmentRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = AssignmentService::update(
+        &st,
+        claims.sub,
+        assignment_id,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/assignments/{assignment_id}",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    params(
+        ("assignment_id" = i64, Path, description = "Assignment ID")
+    ),
+    responses(
+        (status = 204, description = "Assignment deleted"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Assignment not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn delete_assignment(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(assignment_id): Path<i64>,
+    headers: HeaderMap,
+) -> AppResult<StatusCode> {
+    AssignmentService::delete(
+        &st,
+        claims.sub,
+        assignment_id,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/assignments/{assignment_id}/tracking",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    params(
+        ("assignment_id" = i64, Path, description = "Assignment ID"),
+        AssignmentTrackingQuery
+    ),
+    responses(
+        (status = 200, description = "Per-learner status (not_started/in_progress/completed/late/missed) with item progress (real names only with include_names, audited)", body = AssignmentTrackingRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Assignment not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn assignment_tracking(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(assignment_id): Path<i64>,
+    Query(query): Query<AssignmentTrackingQuery>,
+    headers: HeaderMap,
+) -> AppResult<Json<AssignmentTrackingRes>> {
+    let res = AssignmentService::tracking(
+        &st,
+        claims.sub,
+        assignment_id,
+        query.include_names.unwrap_or(false),
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// learner
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/assignments/me",
+    tag = "Assignment",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "My open assignments with status", body = MyAssignmentListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_assignments(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<MyAssignmentListRes>> {
+    let res = AssignmentService::list_mine(&st, claims.sub).await?;
+    Ok(Json(res))
+}
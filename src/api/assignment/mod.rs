@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod handler;
+pub mod repo;
+pub mod router;
+pub mod service;
@@ -0,0 +1,577 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+
+use super::dto::AssignmentItemRes;
+use crate::error::AppResult;
+use crate::types::{AssignmentItemType, AssignmentLatePolicy};
+
+#[derive(Debug, FromRow)]
+pub struct AssignmentRow {
+    pub assignment_id: i64,
+    pub classroom_id: Option<i64>,
+    pub classroom_name: Option<String>,
+    pub classroom_owner_user_id: Option<i64>,
+    pub created_by_user_id: i64,
+    pub assignment_title: String,
+    pub assignment_description: Option<String>,
+    pub assignment_open_at: DateTime<Utc>,
+    pub assignment_due_at: DateTime<Utc>,
+    pub assignment_late_policy: AssignmentLatePolicy,
+    pub assignment_late_until: Option<DateTime<Utc>>,
+    pub assignee_count: i64,
+    pub assignment_created_at: DateTime<Utc>,
+    pub assignment_updated_at: DateTime<Utc>,
+}
+
+/// 대상 학습자 = 클래스 active 멤버 ∪ 지정 학습자 (`a` = assignment 별칭)
+const ASSIGNEES_OF_A: &str = r#"
+    SELECT cm.user_id FROM classroom_member cm
+    WHERE cm.classroom_id = a.classroom_id AND cm.member_state = 'active'
+    UNION
+    SELECT aa.user_id FROM assignment_assignee aa
+    WHERE aa.assignment_id = a.assignment_id
+"#;
+
+fn assignment_select() -> String {
+    format!(
+        r#"
+        SELECT
+            a.assignment_id,
+            a.classroom_id,
+            c.classroom_name,
+            c.owner_user_id AS classroom_owner_user_id,
+            a.created_by_user_id,
+            a.assignment_title,
+            a.assignment_description,
+            a.assignment_open_at,
+            a.assignment_due_at,
+            a.assignment_late_policy,
+            a.assignment_late_until,
+            (SELECT COUNT(*) FROM ({ASSIGNEES_OF_A}) t) AS assignee_count,
+            a.assignment_created_at,
+            a.assignment_updated_at
+        FROM assignment a
+        LEFT JOIN classroom c ON c.classroom_id = a.classroom_id
+        "#
+    )
+}
+
+pub async fn find_by_id(pool: &PgPool, assignment_id: i64) -> AppResult<Option<AssignmentRow>> {
+    let sql = format!("{} WHERE a.assignment_id = $1", assignment_select());
+    let row = sqlx::query_as::<_, AssignmentRow>(&sql)
+        .bind(assignment_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 여러 과제 일괄 조회 (성적부 계산용)
+pub async fn find_by_ids(pool: &PgPool, assignment_ids: &[i64]) -> AppResult<Vec<AssignmentRow>> {
+    let sql = format!("{} WHERE a.assignment_id = ANY($1)", assignment_select());
+    let rows = sqlx::query_as::<_, AssignmentRow>(&sql)
+        .bind(assignment_ids)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 관리 과제 목록 — manager_user_id 지정 시 본인이 만들었거나 본인 클래스의 과제만
+pub async fn find_managed(
+    pool: &PgPool,
+    manager_user_id: Option<i64>,
+    classroom_id: Option<i64>,
+) -> AppResult<Vec<AssignmentRow>> {
+    let sql = format!(
+        r#"{}
+        WHERE ($1::bigint IS NULL OR a.created_by_user_id = $1 OR c.owner_user_id = $1)
+          AND ($2::bigint IS NULL OR a.classroom_id = $2)
+        ORDER BY a.assignment_due_at DESC, a.assignment_id DESC
+        "#,
+        assignment_select()
+    );
+    let rows = sqlx::query_as::<_, AssignmentRow>(&sql)
+        .bind(manager_user_id)
+        .bind(classroom_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 학습자 본인에게 공개된 과제 (open_at 도달분)
+pub async fn find_for_learner(
+    pool: &PgPool,
+    user_id: i64,
+    now: DateTime<Utc>,
+) -> AppResult<Vec<AssignmentRow>> {
+    let sql = format!(
+        r#"{}
+        WHERE a.assignment_open_at <= $2
+          AND $1 IN ({ASSIGNEES_OF_A})
+        ORDER BY a.assignment_due_at, a.assignment_id
+        "#,
+        assignment_select()
+    );
+    let rows = sqlx::query_as::<_, AssignmentRow>(&sql)
+        .bind(user_id)
+        .bind(now)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+#[derive(Debug, FromRow)]
+pub struct AssignmentItemRow {
+    pub assignment_id: i64,
+    #[sqlx(flatten)]
+    pub item: AssignmentItemRes,
+}
+
+/// 과제 항목 (+ 대상 콘텐츠 idx/제목)
+pub async fn find_items(
+    pool: &PgPool,
+    assignment_ids: &[i64],
+) -> AppResult<Vec<AssignmentItemRow>> {
+    let rows = sqlx::query_as::<_, AssignmentItemRow>(
+        r#"
+        SELECT
+            i.assignment_id,
+            i.assignment_item_id,
+            i.item_seq,
+            i.item_type,
+            i.target_id,
+            COALESCE(s.study_idx, l.lesson_idx, v.video_idx, g.guide_idx) AS target_idx,
+            COALESCE(s.study_title, l.lesson_title, v.video_title, g.title_ko) AS target_title,
+            i.sentence_start,
+            i.sentence_end
+        FROM assignment_item i
+        LEFT JOIN study s  ON i.item_type = 'study'  AND s.study_id  = i.target_id
+        LEFT JOIN lesson l ON i.item_type = 'lesson' AND l.lesson_id = i.target_id
+        LEFT JOIN video v  ON i.item_type = 'video'  AND v.video_id  = i.target_id
+        LEFT JOIN guide g  ON i.item_type = 'guide'  AND g.guide_id  = i.target_id
+        WHERE i.assignment_id = ANY($1)
+        ORDER BY i.assignment_id, i.item_seq
+        "#,
+    )
+    .bind(assignment_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn target_exists(
+    pool: &PgPool,
+    item_type: AssignmentItemType,
+    target_id: i64,
+) -> AppResult<bool> {
+    let sql = match item_type {
+        AssignmentItemType::Study => "SELECT EXISTS (SELECT 1 FROM study WHERE study_id = $1)",
+        AssignmentItemType::Lesson => "SELECT EXISTS (SELECT 1 FROM lesson WHERE lesson_id = $1)",
+        AssignmentItemType::Video => "SELECT EXISTS (SELECT 1 FROM video WHERE video_id = $1)",
+        AssignmentItemType::Guide => "SELECT EXISTS (SELECT 1 FROM guide WHERE guide_id = $1)",
+    };
+    let exists = sqlx::query_scalar::<_, bool>(sql)
+        .bind(target_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(exists)
+}
+
+/// owner 의 클래스 active 멤버 중 user_ids 에 포함된 인원 수
+pub async fn count_members_of_owner(
+    pool: &PgPool,
+    owner_user_id: i64,
+    user_ids: &[i64],
+) -> AppResult<i64> {
+    let count = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(DISTINCT cm.user_id)
+        FROM classroom_member cm
+        JOIN classroom c ON c.classroom_id = cm.classroom_id
+        WHERE c.owner_user_id = $1 AND cm.member_state = 'active' AND cm.user_id = ANY($2)
+        "#,
+    )
+    .bind(owner_user_id)
+    .bind(user_ids)
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+pub async fn count_existing_users(pool: &PgPool, user_ids: &[i64]) -> AppResult<i64> {
+    let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM users WHERE user_id = ANY($1)")
+        .bind(user_ids)
+        .fetch_one(pool)
+        .await?;
+    Ok(count)
+}
+
+pub struct NewAssignment<'a> {
+    pub classroom_id: Option<i64>,
+    pub created_by_user_id: i64,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub open_at: DateTime<Utc>,
+    pub due_at: DateTime<Utc>,
+    pub late_policy: AssignmentLatePolicy,
+    pub late_until: Option<DateTime<Utc>>,
+}
+
+pub async fn insert_assignment_tx(
+    conn: &mut PgConnection,
+    a: &NewAssignment<'_>,
+) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO assignment
+            (classroom_id, created_by_user_id, assignment_title, assignment_description,
+             assignment_open_at, assignment_due_at, assignment_late_policy, assignment_late_until)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING assignment_id
+        "#,
+    )
+    .bind(a.classroom_id)
+    .bind(a.created_by_user_id)
+    .bind(a.title)
+    .bind(a.description)
+    .bind(a.open_at)
+    .bind(a.due_at)
+    .bind(a.late_policy)
+    .bind(a.late_until)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(id)
+}
+
+pub async fn insert_item_tx(
+    conn: &mut PgConnection,
+    assignment_id: i64,
+    item_seq: i32,
+    item_type: AssignmentItemType,
+    target_id: i64,
+    sentence_range: Option<(i32, i32)>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO assignment_item
+            (assignment_id, item_seq, item_type, target_id, sentence_start, sentence_end)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(assignment_id)
+    .bind(item_seq)
+    .bind(item_type)
+    .bind(target_id)
+    .bind(sentence_range.map(|r| r.0))
+    .bind(sentence_range.map(|r| r.1))
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub async fn insert_assignees_tx(
+    conn: &mut PgConnection,
+    assignment_id: i64,
+    user_ids: &[i64],
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO assignment_assignee (assignment_id, user_id)
+        SELECT $1, UNNEST($2::bigint[])
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(assignment_id)
+    .bind(user_ids)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub struct AssignmentSchedule<'a> {
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub open_at: DateTime<Utc>,
+    pub due_at: DateTime<Utc>,
+    pub late_policy: AssignmentLatePolicy,
+    pub late_until: Option<DateTime<Utc>>,
+}
+
+/// 일정/정책 전체 덮어쓰기 (병합·검증은 service 에서)
+pub async fn update_assignment(
+    pool: &PgPool,
+    assignment_id: i64,
+    s: &AssignmentSchedule<'_>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE assignment
+        SET assignment_title       = $2,
+            assignment_description = $3,
+            assignment_open_at     = $4,
+            assignment_due_at      = $5,
+            assignment_late_policy = $6,
+            assignment_late_until  = $7,
+            assignment_updated_at  = NOW()
+        WHERE assignment_id = $1
+        "#,
+    )
+    .bind(assignment_id)
+    .bind(s.title)
+    .bind(s.description)
+    .bind(s.open_at)
+    .bind(s.due_at)
+    .bind(s.late_policy)
+    .bind(s.late_until)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 마감이 미뤄지면 리마인더를 다시 보낼 수 있도록 발송 기록 초기화
+pub async fn clear_reminders(pool: &PgPool, assignment_id: i64) -> AppResult<()> {
+    sqlx::query("DELETE FROM assignment_reminder WHERE assignment_id = $1")
+        .bind(assignment_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_assignment(pool: &PgPool, assignment_id: i64) -> AppResult<()> {
+    sqlx::query("DELETE FROM assignment WHERE assignment_id = $1")
+        .bind(assignment_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// =============================================================================
+// 진행/제출 — 기존 상태 테이블에서 파생
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct AssigneeRow {
+    pub user_id: i64,
+    pub user_nickname: String,
+    pub user_name_enc: String,
+}
+
+pub async fn find_assignees(pool: &PgPool, assignment_id: i64) -> AppResult<Vec<AssigneeRow>> {
+    let sql = format!(
+        r#"
+        SELECT u.user_id, u.user_nickname, u.user_name AS user_name_enc
+        FROM assignment a
+        JOIN LATERAL ({ASSIGNEES_OF_A}) t ON true
+        JOIN users u ON u.user_id = t.user_id
+        WHERE a.assignment_id = $1
+        ORDER BY u.user_id
+        "#
+    );
+    let rows = sqlx::query_as::<_, AssigneeRow>(&sql)
+        .bind(assignment_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// (학습자, 항목) 별 진행 — user_id 지정 시 해당 학습자만 (대상자가 아니면 빈 결과)
+#[derive(Debug, FromRow)]
+pub struct ItemProgressRow {
+    pub assignment_id: i64,
+    pub user_id: i64,
+    pub assignment_item_id: i64,
+    pub total: i64,
+    pub done: i64,
+    pub last_at: Option<DateTime<Utc>>,
+}
+
+pub async fn find_item_progress(
+    pool: &PgPool,
+    assignment_id: i64,
+    user_id: Option<i64>,
+) -> AppResult<Vec<ItemProgressRow>> {
+    find_item_progress_many(pool, &[assignment_id], user_id).await
+}
+
+/// 여러 과제의 (학습자, 항목) 별 진행을 한 번에 — 과제별 조회 반복(N+1) 방지
+pub async fn find_item_progress_many(
+    pool: &PgPool,
+    assignment_ids: &[i64],
+    user_id: Option<i64>,
+) -> AppResult<Vec<ItemProgressRow>> {
+    // 항목 종류별 LATERAL 분기 — 집계 쿼리의 HAVING 으로 해당 종류 1행만 남김
+    let sql = format!(
+        r#"
+        SELECT a.assignment_id, t.user_id, i.assignment_item_id, p.total, p.done, p.last_at
+        FROM assignment a
+        JOIN LATERAL ({ASSIGNEES_OF_A}) t ON true
+        JOIN assignment_item i ON i.assignment_id = a.assignment_id
+        CROSS JOIN LATERAL (
+            SELECT 1::bigint AS total,
+                   LEAST(COUNT(*) FILTER (WHERE vl.video_completed_log), 1) AS done,
+                   MAX(vl.video_last_watched_at_log) AS last_at
+            FROM video_log vl
+            WHERE vl.user_id = t.user_id AND vl.video_id = i.target_id
+            HAVING i.item_type = 'video'
+            UNION ALL
+            SELECT 1::bigint,
+                   LEAST(COUNT(*) FILTER (WHERE lp.lesson_progress_percent >= 100), 1),
+                   MAX(lp.lesson_progress_last_progress_at)
+            FROM lesson_progress lp
+            WHERE lp.user_id = t.user_id AND lp.lesson_id = i.target_id
+            HAVING i.item_type = 'lesson'
+            UNION ALL
+            SELECT COUNT(DISTINCT st.study_task_id),
+                   COUNT(DISTINCT st.study_task_id) FILTER (WHERE sts.study_task_status_is_solved),
+                   MAX(sts.study_task_status_last_attempt_at)
+            FROM study_task st
+            LEFT JOIN study_task_status sts
+                   ON sts.study_task_id = st.study_task_id AND sts.user_id = t.user_id
+            WHERE st.study_id = i.target_id
+            HAVING i.item_type = 'study'
+            UNION ALL
+            SELECT COUNT(DISTINCT gs.guide_sentence_id),
+                   COUNT(DISTINCT gs.guide_sentence_id) FILTER (WHERE gss.guide_sentence_status_is_solved),
+                   MAX(gss.guide_sentence_status_last_attempt_at)
+            FROM guide_sentence gs
+            LEFT JOIN guide_sentence_status gss
+                   ON gss.guide_sentence_id = gs.guide_sentence_id AND gss.user_id = t.user_id
+            WHERE gs.guide_id = i.target_id
+              AND (i.sentence_start IS NULL OR gs.sentence_no BETWEEN i.sentence_start AND i.sentence_end)
+            HAVING i.item_type = 'guide'
+        ) p
+        WHERE a.assignment_id = ANY($1)
+          AND ($2::bigint IS NULL OR t.user_id = $2)
+        ORDER BY a.assignment_id, t.user_id, i.item_seq
+        "#
+    );
+    let rows = sqlx::query_as::<_, ItemProgressRow>(&sql)
+        .bind(assignment_ids)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SubmissionRow {
+    pub assignment_id: i64,
+    pub user_id: i64,
+    pub submission_completed_at: DateTime<Utc>,
+    pub submission_is_late: bool,
+}
+
+pub async fn find_submissions(
+    pool: &PgPool,
+    assignment_id: i64,
+    user_id: Option<i64>,
+) -> AppResult<Vec<SubmissionRow>> {
+    find_submissions_many(pool, &[assignment_id], user_id).await
+}
+
+pub async fn find_submissions_many(
+    pool: &PgPool,
+    assignment_ids: &[i64],
+    user_id: Option<i64>,
+) -> AppResult<Vec<SubmissionRow>> {
+    let rows = sqlx::query_as::<_, SubmissionRow>(
+        r#"
+        SELECT assignment_id, user_id, submission_completed_at, submission_is_late
+        FROM assignment_submission
+        WHERE assignment_id = ANY($1) AND ($2::bigint IS NULL OR user_id = $2)
+        "#,
+    )
+    .bind(assignment_ids)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 최초 완료 기록 (이미 있으면 유지)
+pub async fn insert_submission(
+    pool: &PgPool,
+    assignment_id: i64,
+    user_id: i64,
+    completed_at: DateTime<Utc>,
+    is_late: bool,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO assignment_submission
+            (assignment_id, user_id, submission_completed_at, submission_is_late)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (assignment_id, user_id) DO NOTHING
+        "#,
+    )
+    .bind(assignment_id)
+    .bind(user_id)
+    .bind(completed_at)
+    .bind(is_late)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// =============================================================================
+// 리마인더 (jobs::assignment_reminder)
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct ReminderCandidateRow {
+    pub assignment_id: i64,
+    pub assignment_title: String,
+    pub assignment_due_at: DateTime<Utc>,
+    pub user_id: i64,
+    pub user_email_enc: String,
+}
+
+/// 마감이 [now, window_end] 안에 있고 아직 리마인더/완료 기록이 없는 (과제, 학습자)
+pub async fn find_reminder_candidates(
+    pool: &PgPool,
+    now: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    limit: i64,
+) -> AppResult<Vec<ReminderCandidateRow>> {
+    let sql = format!(
+        r#"
+        SELECT a.assignment_id, a.assignment_title, a.assignment_due_at,
+               u.user_id, u.user_email AS user_email_enc
+        FROM assignment a
+        JOIN LATERAL ({ASSIGNEES_OF_A}) t ON true
+        JOIN users u ON u.user_id = t.user_id
+        WHERE a.assignment_open_at <= $1
+          AND a.assignment_due_at > $1
+          AND a.assignment_due_at <= $2
+          AND u.user_state = true
+          AND NOT EXISTS (SELECT 1 FROM assignment_reminder r
+                          WHERE r.assignment_id = a.assignment_id AND r.user_id = u.user_id)
+          AND NOT EXISTS (SELECT 1 FROM assignment_submission s
+                          WHERE s.assignment_id = a.assignment_id AND s.user_id = u.user_id)
+        ORDER BY a.assignment_due_at, a.assignment_id, u.user_id
+        LIMIT $3
+        "#
+    );
+    let rows = sqlx::query_as::<_, ReminderCandidateRow>(&sql)
+        .bind(now)
+        .bind(window_end)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 리마인더 발송 기록 선점 — 이미 기록돼 있으면 false (중복 발송 방지)
+pub async fn claim_reminder(pool: &PgPool, assignment_id: i64, user_id: i64) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        INSERT INTO assignment_reminder (assignment_id, user_id)
+        VALUES ($1, $2)
+        ON CONFLICT (assignment_id, user_id) DO NOTHING
+        "#,
+    )
+    .bind(assignment_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
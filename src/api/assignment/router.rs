@@ -0,0 +1,22 @@
+use super::handler;
+use crate::state::AppState;
+use axum::routing::get;
+
+pub fn assignment_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(
+            "/assignments",
+            get(handler::list_assignments).post(handler::create_assignment),
+        )
+        .route("/assignments/me", get(handler::my_assignments))
+        .route(
+            "/assignments/{assignment_id}",
+            get(handler::get_assignment)
+                .patch(handler::update_assignment)
+                .delete(handler::delete_assignment),
+        )
+        .route(
+            "/assignments/{assignment_id}/tracking",
+            get(handler::assignment_tracking),
+        )
+}
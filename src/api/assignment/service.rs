@@ -0,0 +1,855 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+
+use super::{
+    dto::{
+        AssignmentItemProgress, AssignmentItemReq, AssignmentItemRes, AssignmentLearnerStatus,
+        AssignmentListQuery, AssignmentListRes, AssignmentRes, AssignmentStatus,
+        AssignmentTrackingRes, AssignmentTrackingSummary, CreateAssignmentReq, MyAssignmentListRes,
+        MyAssignmentRes, UpdateAssignmentReq,
+    },
+    repo::{
+        self, AssignmentRow, AssignmentSchedule, ItemProgressRow, NewAssignment, SubmissionRow,
+    },
+};
+use crate::api::admin::user::repo::write_audit_log;
+use crate::api::classroom::service::{check_manager_rbac, load_managed};
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::state::AppState;
+use crate::types::{AssignmentItemType, AssignmentLatePolicy, UserAuth};
+
+/// 과제 관리 권한 — manager 는 본인이 만든 과제 또는 본인 클래스의 과제만
+fn can_manage_assignment(actor_auth: UserAuth, actor_user_id: i64, row: &AssignmentRow) -> bool {
+    match actor_auth {
+        UserAuth::Hymn | UserAuth::Admin => true,
+        UserAuth::Manager => {
+            row.created_by_user_id == actor_user_id
+                || row.classroom_owner_user_id == Some(actor_user_id)
+        }
+        UserAuth::Learner => false,
+    }
+}
+
+/// 일정/지각 정책 검증 — open < due, late_until 은 allow 에서만 + due 이후
+pub fn validate_schedule(
+    open_at: DateTime<Utc>,
+    due_at: DateTime<Utc>,
+    late_policy: AssignmentLatePolicy,
+    late_until: Option<DateTime<Utc>>,
+) -> AppResult<()> {
+    if due_at <= open_at {
+        return Err(AppError::BadRequest("due_at must be after open_at".into()));
+    }
+    if let Some(until) = late_until {
+        if late_policy != AssignmentLatePolicy::Allow {
+            return Err(AppError::BadRequest(
+                "late_until is only allowed with late_policy=allow".into(),
+            ));
+        }
+        if until <= due_at {
+            return Err(AppError::BadRequest(
+                "late_until must be after due_at".into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 항목 sentence 범위 검증 — guide 전용, 시작/끝 모두 지정 + start <= end
+pub fn item_sentence_range(item: &AssignmentItemReq) -> AppResult<Option<(i32, i32)>> {
+    match (item.sentence_start, item.sentence_end) {
+        (None, None) => Ok(None),
+        (Some(start), Some(end)) if item.item_type == AssignmentItemType::Guide => {
+            if start > end {
+                return Err(AppError::BadRequest(
+                    "sentence_start must be <= sentence_end".into(),
+                ));
+            }
+            Ok(Some((start, end)))
+        }
+        (Some(_), Some(_)) => Err(AppError::BadRequest(
+            "Sentence range is only allowed for guide items".into(),
+        )),
+        _ => Err(AppError::BadRequest(
+            "sentence_start and sentence_end must be given together".into(),
+        )),
+    }
+}
+
+/// 학습자 1명의 과제 상태 판정 결과
+#[derive(Debug, PartialEq, Eq)]
+pub struct Evaluation {
+    pub status: AssignmentStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// 이번 판정에서 처음 완료가 감지돼 submission 으로 고정할 값 (completed_at, is_late)
+    pub record: Option<(DateTime<Utc>, bool)>,
+}
+
+/// 항목 진행 + 기록된 submission 으로 상태 판정
+///
+/// - 기록된 submission 이 있으면 그대로 (이후 재학습으로 바뀌지 않음)
+/// - 전 항목 완료: 완료 시각(항목별 마지막 활동 중 최댓값) ≤ due → completed,
+///   allow 이고 late_until 이내 → late, 그 외 → missed
+/// - 미완료: 인정 기한(deny = due, allow = late_until)이 지났으면 missed,
+///   활동이 있으면 in_progress, 없으면 not_started
+pub fn evaluate(
+    items: &[AssignmentItemProgress],
+    due_at: DateTime<Utc>,
+    late_policy: AssignmentLatePolicy,
+    late_until: Option<DateTime<Utc>>,
+    submission: Option<&SubmissionRow>,
+    now: DateTime<Utc>,
+) -> Evaluation {
+    if let Some(sub) = submission {
+        return Evaluation {
+            status: if sub.submission_is_late {
+                AssignmentStatus::Late
+            } else {
+                AssignmentStatus::Completed
+            },
+            completed_at: Some(sub.submission_completed_at),
+            record: None,
+        };
+    }
+
+    let all_done = !items.is_empty() && items.iter().all(|i| i.completed);
+    if all_done {
+        let completed_at = items
+            .iter()
+            .filter_map(|i| i.last_activity_at)
+            .max()
+            .unwrap_or(now);
+        let late_ok = late_policy == AssignmentLatePolicy::Allow
+            && late_until.is_none_or(|until| completed_at <= until);
+        let (status, record) = if completed_at <= due_at {
+            (AssignmentStatus::Completed, Some((completed_at, false)))
+        } else if late_ok {
+            (AssignmentStatus::Late, Some((completed_at, true)))
+        } else {
+            (AssignmentStatus::Missed, None)
+        };
+        return Evaluation {
+            status,
+            completed_at: record.map(|r| r.0),
+            record,
+        };
+    }
+
+    let deadline = match late_policy {
+        AssignmentLatePolicy::Deny => Some(due_at),
+        AssignmentLatePolicy::Allow => late_until,
+    };
+    let status = if deadline.is_some_and(|d| now > d) {
+        AssignmentStatus::Missed
+    } else if items
+        .iter()
+        .any(|i| i.done > 0 || i.last_activity_at.is_some())
+    {
+        AssignmentStatus::InProgress
+    } else {
+        AssignmentStatus::NotStarted
+    };
+    Evaluation {
+        status,
+        completed_at: None,
+        record: None,
+    }
+}
+
+fn to_item_progress(row: &ItemProgressRow) -> AssignmentItemProgress {
+    AssignmentItemProgress {
+        assignment_item_id: row.assignment_item_id,
+        total: row.total,
+        done: row.done,
+        completed: row.total > 0 && row.done >= row.total,
+        last_activity_at: row.last_at,
+    }
+}
+
+fn to_res(row: AssignmentRow, items: Vec<AssignmentItemRes>) -> AssignmentRes {
+    AssignmentRes {
+        assignment_id: row.assignment_id,
+        classroom_id: row.classroom_id,
+        classroom_name: row.classroom_name,
+        created_by_user_id: row.created_by_user_id,
+        assignment_title: row.assignment_title,
+        assignment_description: row.assignment_description,
+        assignment_open_at: row.assignment_open_at,
+        assignment_due_at: row.assignment_due_at,
+        assignment_late_policy: row.assignment_late_policy,
+        assignment_late_until: row.assignment_late_until,
+        assignee_count: row.assignee_count,
+        assignment_created_at: row.assignment_created_at,
+        assignment_updated_at: row.assignment_updated_at,
+        items,
+    }
+}
+
+/// 과제 목록에 항목을 붙여 응답으로 변환
+async fn with_items(st: &AppState, rows: Vec<AssignmentRow>) -> AppResult<Vec<AssignmentRes>> {
+    let ids: Vec<i64> = rows.iter().map(|r| r.assignment_id).collect();
+    let mut items: HashMap<i64, Vec<AssignmentItemRes>> = HashMap::new();
+    for row in repo::find_items(&st.db, &ids).await? {
+        items.entry(row.assignment_id).or_default().push(row.item);
+    }
+    Ok(rows
+        .into_iter()
+        .map(|r| {
+            let its = items.remove(&r.assignment_id).unwrap_or_default();
+            to_res(r, its)
+        })
+        .collect())
+}
+
+async fn load_res(st: &AppState, assignment_id: i64) -> AppResult<AssignmentRes> {
+    let row = repo::find_by_id(&st.db, assignment_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    with_items(st, vec![row])
+        .await?
+        .pop()
+        .ok_or(AppError::NotFound)
+}
+
+/// 관리 대상 과제 로드 — 권한 밖 과제는 404
+async fn load_managed_assignment(
+    st: &AppState,
+    actor_user_id: i64,
+    assignment_id: i64,
+) -> AppResult<AssignmentRow> {
+    let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+    repo::find_by_id(&st.db, assignment_id)
+        .await?
+        .filter(|a| can_manage_assignment(actor_auth, actor_user_id, a))
+        .ok_or(AppError::NotFound)
+}
+
+/// 학습자 1명의 항목 진행을 과제 항목 순서대로 판정하고, 첫 완료면 submission 고정
+async fn evaluate_learner(
+    st: &AppState,
+    assignment: &AssignmentRes,
+    user_id: i64,
+    progress: &[&ItemProgressRow],
+    submission: Option<&SubmissionRow>,
+    now: DateTime<Utc>,
+) -> AppResult<(Evaluation, Vec<AssignmentItemProgress>)> {
+    let items: Vec<AssignmentItemProgress> =
+        progress.iter().map(|row| to_item_progress(row)).collect();
+    let eval = evaluate(
+        &items,
+        assignment.assignment_due_at,
+        assignment.assignment_late_policy,
+        assignment.assignment_late_until,
+        submission,
+        now,
+    );
+    if let Some((completed_at, is_late)) = eval.record {
+        repo::insert_submission(
+            &st.db,
+            assignment.assignment_id,
+            user_id,
+            completed_at,
+            is_late,
+        )
+        .await?;
+    }
+    Ok((eval, items))
+}
+
+/// 학습자가 과제를 완료(또는 지각 완료)했는지 — 리마인더 대상 판정용
+pub async fn is_learner_done(st: &AppState, assignment_id: i64, user_id: i64) -> AppResult<bool> {
+    let progress = repo::find_item_progress(&st.db, assignment_id, Some(user_id)).await?;
+    Ok(!progress.is_empty()
+        && progress
+            .iter()
+            .all(|row| row.total > 0 && row.done >= row.total))
+}
+
+/// 과제 대상 학습자별 현재 상태 (조회 전용, submission 기록 없음) — 성적부 계산용
+///
+/// assignment_id → (user_id → 상태). 과제 수와 무관하게 쿼리 3회.
+pub async fn learner_statuses_many(
+    st: &AppState,
+    assignment_ids: &[i64],
+) -> AppResult<HashMap<i64, HashMap<i64, AssignmentStatus>>> {
+    if assignment_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let rows = repo::find_by_ids(&st.db, assignment_ids).await?;
+    let mut progress: HashMap<(i64, i64), Vec<AssignmentItemProgress>> = HashMap::new();
+    for p in repo::find_item_progress_many(&st.db, assignment_ids, None).await? {
+        progress
+            .entry((p.assignment_id, p.user_id))
+            .or_default()
+            .push(to_item_progress(&p));
+    }
+    let submissions: HashMap<(i64, i64), SubmissionRow> =
+        repo::find_submissions_many(&st.db, assignment_ids, None)
+            .await?
+            .into_iter()
+            .map(|s| ((s.assignment_id, s.user_id), s))
+            .collect();
+
+    let schedules: HashMap<i64, &AssignmentRow> =
+        rows.iter().map(|r| (r.assignment_id, r)).collect();
+    let now = Utc::now();
+    let mut out: HashMap<i64, HashMap<i64, AssignmentStatus>> = HashMap::new();
+    for ((assignment_id, user_id), items) in progress {
+        let Some(row) = schedules.get(&assignment_id) else {
+            continue;
+        };
+        let eval = evaluate(
+            &items,
+            row.assignment_due_at,
+            row.assignment_late_policy,
+            row.assignment_late_until,
+            submissions.get(&(assignment_id, user_id)),
+            now,
+        );
+        out.entry(assignment_id)
+            .or_default()
+            .insert(user_id, eval.status);
+    }
+    Ok(out)
+}
+
+pub struct AssignmentService;
+
+impl AssignmentService {
+    // =========================================================================
+    // manager
+    // =========================================================================
+
+    /// 과제 생성 — 클래스 전체 및/또는 지정 학습자 대상
+    pub async fn create(
+        st: &AppState,
+        actor_user_id: i64,
+        req: CreateAssignmentReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<AssignmentRes> {
+        let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+
+        let title = req.assignment_title.trim();
+        if title.is_empty() {
+            return Err(AppError::BadRequest("assignment_title is required".into()));
+        }
+
+        if let Some(classroom_id) = req.classroom_id {
+            load_managed(st, actor_user_id, classroom_id).await?;
+        }
+
+        let mut user_ids = req.user_ids.clone().unwrap_or_default();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+        if req.classroom_id.is_none() && user_ids.is_empty() {
+            return Err(AppError::BadRequest(
+                "classroom_id or user_ids is required".into(),
+            ));
+        }
+        if !user_ids.is_empty() {
+            // manager 는 본인 클래스의 active 학습자만 지정 가능
+            let found = match actor_auth {
+                UserAuth::Manager => {
+                    repo::count_members_of_owner(&st.db, actor_user_id, &user_ids).await?
+                }
+                _ => repo::count_existing_users(&st.db, &user_ids).await?,
+            };
+            if found != user_ids.len() as i64 {
+                return Err(AppError::BadRequest(
+                    "ASSIGNMENT_400_INVALID_ASSIGNEE".into(),
+                ));
+            }
+        }
+
+        let now = Utc::now();
+        let open_at = req.open_at.unwrap_or(now);
+        let late_policy = req.late_policy.unwrap_or(AssignmentLatePolicy::Allow);
+        validate_schedule(open_at, req.due_at, late_policy, req.late_until)?;
+
+        let mut ranges = Vec::with_capacity(req.items.len());
+        for item in &req.items {
+            ranges.push(item_sentence_range(item)?);
+            if !repo::target_exists(&st.db, item.item_type, item.target_id).await? {
+                return Err(AppError::BadRequest(format!(
+                    "ASSIGNMENT_400_TARGET_NOT_FOUND: {:?} {}",
+                    item.item_type, item.target_id
+                )));
+            }
+        }
+
+        let mut tx = st.db.begin().await?;
+        let assignment_id = repo::insert_assignment_tx(
+            &mut tx,
+            &NewAssignment {
+                classroom_id: req.classroom_id,
+                created_by_user_id: actor_user_id,
+                title,
+                description: req.assignment_description.as_deref(),
+                open_at,
+                due_at: req.due_at,
+                late_policy,
+                late_until: req.late_until,
+            },
+        )
+        .await?;
+        for (idx, (item, range)) in req.items.iter().zip(ranges).enumerate() {
+            repo::insert_item_tx(
+                &mut tx,
+                assignment_id,
+                idx as i32 + 1,
+                item.item_type,
+                item.target_id,
+                range,
+            )
+            .await?;
+        }
+        if !user_ids.is_empty() {
+            repo::insert_assignees_tx(&mut tx, assignment_id, &user_ids).await?;
+        }
+        tx.commit().await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "CREATE_ASSIGNMENT",
+            "assignment",
+            Some(assignment_id),
+            &serde_json::json!({
+                "classroom_id": req.classroom_id,
+                "user_ids": user_ids,
+                "due_at": req.due_at,
+                "item_count": req.items.len(),
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        load_res(st, assignment_id).await
+    }
+
+    /// 관리 과제 목록 — manager 는 본인이 만든 과제 + 본인 클래스 과제, HYMN/admin 은 전체
+    pub async fn list_managed(
+        st: &AppState,
+        actor_user_id: i64,
+        query: AssignmentListQuery,
+    ) -> AppResult<AssignmentListRes> {
+        let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+        let manager = match actor_auth {
+            UserAuth::Manager => Some(actor_user_id),
+            _ => None,
+        };
+        let rows = repo::find_managed(&st.db, manager, query.classroom_id).await?;
+        Ok(AssignmentListRes {
+            items: with_items(st, rows).await?,
+        })
+    }
+
+    pub async fn get(
+        st: &AppState,
+        actor_user_id: i64,
+        assignment_id: i64,
+    ) -> AppResult<AssignmentRes> {
+        load_managed_assignment(st, actor_user_id, assignment_id).await?;
+        load_res(st, assignment_id).await
+    }
+
+    /// 과제 수정 — 일정/정책/설명 (마감 변경 시 리마인더 재발송 가능하도록 초기화)
+    pub async fn update(
+        st: &AppState,
+        actor_user_id: i64,
+        assignment_id: i64,
+        req: UpdateAssignmentReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<AssignmentRes> {
+        let before = load_managed_assignment(st, actor_user_id, assignment_id).await?;
+
+        let clear_late_until = req.clear_late_until.unwrap_or(false);
+        if req.assignment_title.is_none()
+            && req.assignment_description.is_none()
+            && req.open_at.is_none()
+            && req.due_at.is_none()
+            && req.late_policy.is_none()
+            && req.late_until.is_none()
+            && !clear_late_until
+        {
+            return Err(AppError::BadRequest("No fields to update".into()));
+        }
+        if clear_late_until && req.late_until.is_some() {
+            return Err(AppError::BadRequest(
+                "late_until and clear_late_until are mutually exclusive".into(),
+            ));
+        }
+
+        let title = req
+            .assignment_title
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or(&before.assignment_title);
+        if title.is_empty() {
+            return Err(AppError::BadRequest(
+                "assignment_title must not be empty".into(),
+            ));
+        }
+        let description = req
+            .assignment_description
+            .as_deref()
+            .or(before.assignment_description.as_deref());
+        let open_at = req.open_at.unwrap_or(before.assignment_open_at);
+        let due_at = req.due_at.unwrap_or(before.assignment_due_at);
+        let late_policy = req.late_policy.unwrap_or(before.assignment_late_policy);
+        let late_until = if clear_late_until {
+            None
+        } else {
+            req.late_until.or(before.assignment_late_until)
+        };
+        // deny 로 바꾸면 기존 late_until 은 의미가 없으므로 정리
+        let late_until = match late_policy {
+            AssignmentLatePolicy::Deny if req.late_until.is_none() => None,
+            _ => late_until,
+        };
+        validate_schedule(open_at, due_at, late_policy, late_until)?;
+
+        repo::update_assignment(
+            &st.db,
+            assignment_id,
+            &AssignmentSchedule {
+                title,
+                description,
+                open_at,
+                due_at,
+                late_policy,
+                late_until,
+            },
+        )
+        .await?;
+        if due_at > before.assignment_due_at {
+            repo::clear_reminders(&st.db, assignment_id).await?;
+        }
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "UPDATE_ASSIGNMENT",
+            "assignment",
+            Some(assignment_id),
+            &serde_json::json!({
+                "before": {
+                    "open_at": before.assignment_open_at,
+                    "due_at": before.assignment_due_at,
+                    "late_policy": before.assignment_late_policy,
+                    "late_until": before.assignment_late_until,
+                },
+                "open_at": open_at,
+                "due_at": due_at,
+                "late_policy": late_policy,
+                "late_until": late_until,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        load_res(st, assignment_id).await
+    }
+
+    pub async fn delete(
+        st: &AppState,
+        actor_user_id: i64,
+        assignment_id: i64,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        let before = load_managed_assignment(st, actor_user_id, assignment_id).await?;
+        repo::delete_assignment(&st.db, assignment_id).await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "DELETE_ASSIGNMENT",
+            "assignment",
+            Some(assignment_id),
+            &serde_json::json!({
+                "assignment_title": before.assignment_title,
+                "classroom_id": before.classroom_id,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// 추적 화면 — 대상 학습자별 상태/항목 진행 + 상태별 집계 (실명은 include_names 일 때만, 감사 로그)
+    pub async fn tracking(
+        st: &AppState,
+        actor_user_id: i64,
+        assignment_id: i64,
+        include_names: bool,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<AssignmentTrackingRes> {
+        load_managed_assignment(st, actor_user_id, assignment_id).await?;
+        let assignment = load_res(st, assignment_id).await?;
+
+        let assignees = repo::find_assignees(&st.db, assignment_id).await?;
+        if include_names {
+            write_audit_log(
+                st,
+                actor_user_id,
+                "DECRYPT_ASSIGNMENT_NAMES",
+                "assignment",
+                Some(assignment_id),
+                &serde_json::json!({ "learner_count": assignees.len() }),
+                ip_address,
+                user_agent.as_deref(),
+            )
+            .await?;
+        }
+        let progress = repo::find_item_progress(&st.db, assignment_id, None).await?;
+        let mut by_user: HashMap<i64, Vec<&ItemProgressRow>> = HashMap::new();
+        for row in &progress {
+            by_user.entry(row.user_id).or_default().push(row);
+        }
+        let submissions: HashMap<i64, SubmissionRow> =
+            repo::find_submissions(&st.db, assignment_id, None)
+                .await?
+                .into_iter()
+                .map(|s| (s.user_id, s))
+                .collect();
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let now = Utc::now();
+        let mut summary = AssignmentTrackingSummary {
+            assignees: assignees.len() as i64,
+            ..Default::default()
+        };
+        let mut learners = Vec::with_capacity(assignees.len());
+        for a in assignees {
+            let rows = by_user.get(&a.user_id).map(Vec::as_slice).unwrap_or(&[]);
+            let (eval, items) = evaluate_learner(
+                st,
+                &assignment,
+                a.user_id,
+                rows,
+                submissions.get(&a.user_id),
+                now,
+            )
+            .await?;
+            match eval.status {
+                AssignmentStatus::NotStarted => summary.not_started += 1,
+                AssignmentStatus::InProgress => summary.in_progress += 1,
+                AssignmentStatus::Completed => summary.completed += 1,
+                AssignmentStatus::Late => summary.late += 1,
+                AssignmentStatus::Missed => summary.missed += 1,
+            }
+            learners.push(AssignmentLearnerStatus {
+                user_id: a.user_id,
+                nickname: a.user_nickname,
+                name: include_names
+                    .then(|| crypto.decrypt(&a.user_name_enc, "users.user_name"))
+                    .transpose()?,
+                status: eval.status,
+                completed_at: eval.completed_at,
+                items_completed: items.iter().filter(|i| i.completed).count() as i64,
+                items_total: items.len() as i64,
+                last_activity_at: items.iter().filter_map(|i| i.last_activity_at).max(),
+                items,
+            });
+        }
+
+        Ok(AssignmentTrackingRes {
+            assignment,
+            summary,
+            learners,
+        })
+    }
+
+    // =========================================================================
+    // learner
+    // =========================================================================
+
+    /// 내 과제 목록 (공개 시각 도달분) + 상태
+    pub async fn list_mine(st: &AppState, user_id: i64) -> AppResult<MyAssignmentListRes> {
+        let now = Utc::now();
+        let rows = repo::find_for_learner(&st.db, user_id, now).await?;
+        let assignments = with_items(st, rows).await?;
+
+        let mut items = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            let progress =
+                repo::find_item_progress(&st.db, assignment.assignment_id, Some(user_id)).await?;
+            let rows: Vec<&ItemProgressRow> = progress.iter().collect();
+            let submission =
+                repo::find_submissions(&st.db, assignment.assignment_id, Some(user_id))
+                    .await?
+                    .into_iter()
+                    .next();
+            let (eval, progress_items) =
+                evaluate_learner(st, &assignment, user_id, &rows, submission.as_ref(), now).await?;
+            items.push(MyAssignmentRes {
+                assignment,
+                status: eval.status,
+                completed_at: eval.completed_at,
+                items: progress_items,
+            });
+        }
+        Ok(MyAssignmentListRes { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn t(h: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap() + Duration::hours(h)
+    }
+
+    fn item(done: i64, total: i64, last: Option<i64>) -> AssignmentItemProgress {
+        AssignmentItemProgress {
+            assignment_item_id: 1,
+            total,
+            done,
+            completed: total > 0 && done >= total,
+            last_activity_at: last.map(t),
+        }
+    }
+
+    #[test]
+    fn completed_before_due() {
+        let items = [item(3, 3, Some(5)), item(1, 1, Some(8))];
+        let e = evaluate(&items, t(10), AssignmentLatePolicy::Deny, None, None, t(20));
+        assert_eq!(e.status, AssignmentStatus::Completed);
+        assert_eq!(e.completed_at, Some(t(8)));
+        assert_eq!(e.record, Some((t(8), false)));
+    }
+
+    #[test]
+    fn completed_after_due_allow_is_late() {
+        let items = [item(1, 1, Some(12))];
+        let e = evaluate(
+            &items,
+            t(10),
+            AssignmentLatePolicy::Allow,
+            None,
+            None,
+            t(20),
+        );
+        assert_eq!(e.status, AssignmentStatus::Late);
+        assert_eq!(e.record, Some((t(12), true)));
+    }
+
+    #[test]
+    fn completed_after_late_until_is_missed() {
+        let items = [item(1, 1, Some(30))];
+        let e = evaluate(
+            &items,
+            t(10),
+            AssignmentLatePolicy::Allow,
+            Some(t(24)),
+            None,
+            t(40),
+        );
+        assert_eq!(e.status, AssignmentStatus::Missed);
+        assert_eq!(e.record, None);
+    }
+
+    #[test]
+    fn completed_after_due_deny_is_missed() {
+        let items = [item(1, 1, Some(12))];
+        let e = evaluate(&items, t(10), AssignmentLatePolicy::Deny, None, None, t(20));
+        assert_eq!(e.status, AssignmentStatus::Missed);
+        assert_eq!(e.completed_at, None);
+    }
+
+    #[test]
+    fn incomplete_statuses() {
+        let untouched = [item(0, 3, None)];
+        let partial = [item(1, 3, Some(2))];
+        let deny = AssignmentLatePolicy::Deny;
+        assert_eq!(
+            evaluate(&untouched, t(10), deny, None, None, t(5)).status,
+            AssignmentStatus::NotStarted
+        );
+        assert_eq!(
+            evaluate(&partial, t(10), deny, None, None, t(5)).status,
+            AssignmentStatus::InProgress
+        );
+        assert_eq!(
+            evaluate(&partial, t(10), deny, None, None, t(11)).status,
+            AssignmentStatus::Missed
+        );
+        // allow + 무기한 이면 마감이 지나도 아직 missed 아님
+        assert_eq!(
+            evaluate(
+                &partial,
+                t(10),
+                AssignmentLatePolicy::Allow,
+                None,
+                None,
+                t(100)
+            )
+            .status,
+            AssignmentStatus::InProgress
+        );
+    }
+
+    #[test]
+    fn recorded_submission_is_sticky() {
+        let sub = SubmissionRow {
+            assignment_id: 1,
+            user_id: 1,
+            submission_completed_at: t(12),
+            submission_is_late: true,
+        };
+        // 재학습 중이라 현재는 미완료여도 기록된 상태 유지
+        let items = [item(0, 1, None)];
+        let e = evaluate(
+            &items,
+            t(10),
+            AssignmentLatePolicy::Allow,
+            None,
+            Some(&sub),
+            t(20),
+        );
+        assert_eq!(e.status, AssignmentStatus::Late);
+        assert_eq!(e.completed_at, Some(t(12)));
+        assert_eq!(e.record, None);
+    }
+
+    #[test]
+    fn schedule_validation() {
+        let allow = AssignmentLatePolicy::Allow;
+        assert!(validate_schedule(t(0), t(10), allow, None).is_ok());
+        assert!(validate_schedule(t(10), t(10), allow, None).is_err());
+        assert!(validate_schedule(t(0), t(10), allow, Some(t(10))).is_err());
+        assert!(validate_schedule(t(0), t(10), allow, Some(t(11))).is_ok());
+        assert!(validate_schedule(t(0), t(10), AssignmentLatePolicy::Deny, Some(t(11))).is_err());
+    }
+
+    #[test]
+    fn sentence_range_rules() {
+        let req = |item_type, start, end| AssignmentItemReq {
+            item_type,
+            target_id: 1,
+            sentence_start: start,
+            sentence_end: end,
+        };
+        let guide = AssignmentItemType::Guide;
+        assert_eq!(item_sentence_range(&req(guide, None, None)).unwrap(), None);
+        assert_eq!(
+            item_sentence_range(&req(guide, Some(2), Some(5))).unwrap(),
+            Some((2, 5))
+        );
+        assert!(item_sentence_range(&req(guide, Some(5), Some(2))).is_err());
+        assert!(item_sentence_range(&req(guide, Some(2), None)).is_err());
+        assert!(item_sentence_range(&req(AssignmentItemType::Study, Some(1), Some(2))).is_err());
+    }
+}
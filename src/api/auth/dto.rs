@@ -187,7 +187,7 @@ pub struct LogoutRes {
 // Google OAuth DTOs
 // =====================================================================
 
-/// Google OAuth 시작 응답 (인증 URL 반환)
+/// 소셜 로그인 시작 응답 (인증 URL 반환 — Google / 카카오 / 네이버 공용)
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[schema(example = json!({
@@ -197,7 +197,7 @@ pub struct GoogleAuthUrlRes {
     pub auth_url: String,
 }
 
-/// Google OAuth 콜백 쿼리 파라미터
+/// OAuth 콜백 쿼리 파라미터 (Google / 카카오 / 네이버 공용)
 #[derive(Deserialize, Validate)]
 pub struct GoogleCallbackQuery {
     /// Authorization Code (성공 시)
@@ -213,6 +213,22 @@ pub struct GoogleCallbackQuery {
     pub error_description: Option<String>,
 }
 
+/// 기관 SSO 로그인 discovery — 이메일 도메인으로 기관 IdP 를 찾는다
+#[derive(Deserialize)]
+pub struct SsoDiscoverQuery {
+    pub email: String,
+}
+
+/// SAML ACS (HTTP-POST 바인딩)
+#[derive(Deserialize)]
+pub struct SamlAcsForm {
+    #[serde(rename = "SAMLResponse")]
+    pub saml_response: String,
+    /// discovery 때 발급한 state
+    #[serde(rename = "RelayState", default)]
+    pub relay_state: String,
+}
+
 // =====================================================================
 // Find Password DTOs (비밀번호 찾기 - 본인 확인 + 코드 발송)
 // =====================================================================
@@ -376,7 +392,7 @@ pub struct MfaChallengeRes {
     pub user_id: i64,
 }
 
-/// MFA 로그인 요청 (2단계 인증 — TOTP 코드 또는 백업 코드)
+/// MFA 로그인 요청 (2단계 인증 — TOTP 코드, 백업 코드 또는 패스키)
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[schema(example = json!({
@@ -386,8 +402,11 @@ pub struct MfaChallengeRes {
 pub struct MfaLoginReq {
     #[validate(length(min = 1))]
     pub mfa_token: String,
+    /// TOTP 6자리 또는 백업 코드 8자리 (passkey 사용 시 생략)
     #[validate(length(min = 6, max = 8))]
-    pub code: String,
+    pub code: Option<String>,
+    /// TOTP 대신 패스키로 2차 인증 (`/auth/mfa/passkey/options` 로 받은 챌린지에 대한 응답)
+    pub passkey: Option<PasskeyAssertionCredential>,
 }
 
 /// MFA 설정 시작 응답 (QR 코드 + 비밀키)
@@ -436,7 +455,7 @@ pub struct MfaDisableRes {
 }
 
 // =============================================================================
-// 모바일 OAuth (Google/Apple)
+// 모바일 OAuth (Google/Apple/카카오/네이버)
 // =============================================================================
 
 /// 모바일 Google OAuth 요청 (ID token 직접 검증)
@@ -457,6 +476,258 @@ pub struct AppleMobileLoginReq {
     pub user_name: Option<String>,
 }
 
+/// 모바일 카카오 로그인 요청 (카카오 SDK OIDC ID token 직접 검증)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct KakaoMobileLoginReq {
+    #[validate(length(min = 1, message = "id_token is required"))]
+    pub id_token: String,
+}
+
+/// 모바일 네이버 로그인 요청 (Authorization Code 방식)
+///
+/// 앱이 `GET /auth/naver` 로 받은 인증 URL 을 인앱 브라우저로 열고, 리다이렉트에서 가로챈
+/// code / state 를 그대로 전달한다. 네이버 access token 은 발급 앱을 확인할 수 없어 받지 않는다.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NaverMobileLoginReq {
+    #[validate(length(min = 1, message = "code is required"))]
+    pub code: String,
+    /// `GET /auth/naver` 로 발급된 state (일회용)
+    #[validate(length(min = 1, message = "state is required"))]
+    pub state: String,
+}
+
+// =============================================================================
+// 패스키 (WebAuthn)
+// =============================================================================
+// credential 필드는 브라우저 PublicKeyCredential.toJSON() 형식 (camelCase, base64url) 그대로 받는다.
+
+/// 등록 응답 (AuthenticatorAttestationResponse)
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyAttestationResponse {
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    pub attestation_object: String,
+    #[serde(default)]
+    pub transports: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyRegistrationCredential {
+    pub id: String,
+    pub raw_id: String,
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub response: PasskeyAttestationResponse,
+}
+
+/// 인증 응답 (AuthenticatorAssertionResponse)
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyAssertionResponse {
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub signature: String,
+    pub user_handle: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyAssertionCredential {
+    pub id: String,
+    pub raw_id: String,
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub response: PasskeyAssertionResponse,
+}
+
+/// 등록/인증 옵션 — public_key 를 navigator.credentials.create()/get() 에 전달
+/// (challenge / user.id / credential id 는 base64url, 클라이언트에서 디코딩)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyOptionsRes {
+    /// 완료 요청에 그대로 돌려보낼 세레모니 ID (MFA 는 mfa_token 을 사용하므로 없음)
+    pub ceremony_id: Option<String>,
+    #[schema(value_type = Object)]
+    pub public_key: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyRegisterReq {
+    #[validate(length(min = 1))]
+    pub ceremony_id: String,
+    /// 표시 이름 (생략 시 "패스키 N")
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    pub credential: PasskeyRegistrationCredential,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyLoginReq {
+    #[validate(length(min = 1))]
+    pub ceremony_id: String,
+    pub credential: PasskeyAssertionCredential,
+}
+
+/// MFA 2차 인증용 패스키 옵션 요청
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyMfaOptionsReq {
+    #[validate(length(min = 1))]
+    pub mfa_token: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyRenameReq {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyRes {
+    pub passkey_id: i64,
+    pub name: String,
+    pub transports: Vec<String>,
+    /// 동기화 가능한 패스키 (iCloud 키체인 / Google 비밀번호 관리자 등)
+    pub backup_eligible: bool,
+    pub backed_up: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PasskeyListRes {
+    pub items: Vec<PasskeyRes>,
+}
+
+// =============================================================================
+// 로그인 위험 평가 (step-up / "본인이 아닙니다")
+// =============================================================================
+
+/// step-up 로그인 완료 요청 — 로그인 응답 `AUTH_403_STEP_UP_REQUIRED:{step_up_token}` 의 토큰 +
+/// 메일로 받은 6자리 코드
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct StepUpLoginReq {
+    #[validate(length(min = 1))]
+    pub step_up_token: String,
+    #[validate(length(equal = 6))]
+    pub code: String,
+}
+
+/// 알림 메일 "본인이 아닙니다" 링크의 토큰
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RiskRevokeReq {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+// =============================================================================
+// 비밀번호 없는 로그인 (매직 링크 / 일회용 코드)
+// =============================================================================
+
+/// 비밀번호 없는 로그인 전달 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordlessMethod {
+    /// 로그인 링크 (`{FRONTEND_URL}/login/magic?token=...`)
+    Link,
+    /// 6자리 코드
+    Code,
+}
+
+/// 매직 링크 / 로그인 코드 메일 요청
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({
+    "email": "user@example.com",
+    "method": "code"
+}))]
+pub struct PasswordlessRequestReq {
+    #[validate(email)]
+    pub email: String,
+    pub method: PasswordlessMethod,
+}
+
+/// 매직 링크 / 로그인 코드 요청 응답 (계정 존재 여부와 무관하게 동일)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({
+    "message": "If the account exists, a sign-in email has been sent.",
+    "expires_in": 600
+}))]
+pub struct PasswordlessRequestRes {
+    pub message: String,
+    /// 링크/코드 유효시간 (초)
+    pub expires_in: i64,
+}
+
+/// 비밀번호 없는 로그인 완료 — 링크의 `token` 또는 `email` + 메일로 받은 `code`
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({
+    "email": "user@example.com",
+    "code": "123456"
+}))]
+pub struct PasswordlessVerifyReq {
+    #[validate(length(min = 1))]
+    pub token: Option<String>,
+    #[validate(email)]
+    pub email: Option<String>,
+    #[validate(length(equal = 6))]
+    pub code: Option<String>,
+}
+
+// =============================================================================
+// 재인증 (보안 설정 변경 전 step-up)
+// =============================================================================
+
+/// 재인증 수단
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReauthMethod {
+    /// 현재 비밀번호
+    Password,
+    /// TOTP 코드 또는 백업 코드 (MFA 활성 계정)
+    Totp,
+    /// `POST /auth/reauth/email-code` 로 받은 6자리 코드
+    EmailCode,
+}
+
+/// 재인증 요청 — 성공하면 현재 세션에 `REAUTH_TTL_SEC` 동안 보안 설정 변경 권한 부여
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({
+    "method": "totp",
+    "code": "123456"
+}))]
+pub struct ReauthReq {
+    pub method: ReauthMethod,
+    #[validate(length(min = 1, max = 72))]
+    pub password: Option<String>,
+    #[validate(length(min = 1, max = 16))]
+    pub code: Option<String>,
+}
+
+/// 재인증 / 확인 코드 발송 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({ "expires_in": 300 }))]
+pub struct ReauthRes {
+    /// 재인증(또는 발송된 코드) 유효시간 (초)
+    pub expires_in: i64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,7 +25,6 @@ where
     ) -> impl core::future::Future<Output = Result<Self, Self::Rejection>> + Send {
         // AppState는 소유값으로 얻어와 async move 에 캡쳐 (세션 검증에 Redis 필요)
         let app_state = AppState::from_ref(state);
-        let secret = app_state.cfg.jwt_secret.clone();
 
         // 헤더는 참조이므로 미리 복사(cloned)해서 소유값으로 만든 뒤 async move로 넘김
         let auth_header = parts.headers.get(AUTHORIZATION).cloned();
@@ -40,7 +39,7 @@ where
                 })?;
 
             // jwt.rs에 정의된 중앙 검증 로직 사용
-            let claims = jwt::decode_token(&token, &secret)
+            let claims = jwt::decode_token(&token, &app_state.cfg.jwt_keyring)
                 .map_err(|_| AppError::Unauthorized("Invalid token".into()))?;
 
             // 2.1 세션 폐기 검증 (fail-open + 관찰성)
@@ -63,7 +62,6 @@ where
         state: &S,
     ) -> impl core::future::Future<Output = Result<Self, Self::Rejection>> + Send {
         let app_state = AppState::from_ref(state);
-        let secret = app_state.cfg.jwt_secret.clone();
         let auth_header = parts.headers.get(AUTHORIZATION).cloned();
 
         async move {
@@ -79,7 +77,7 @@ where
                 AppError::Unauthorized("Missing or invalid Authorization header".into())
             })?;
 
-            let claims = jwt::decode_token(token, &secret)
+            let claims = jwt::decode_token(token, &app_state.cfg.jwt_keyring)
                 .map_err(|_| AppError::Unauthorized("Invalid token".into()))?;
 
             // 2.1 세션 폐기 검증 (fail-open + 관찰성). 토큰이 있는데 폐기된
@@ -1,7 +1,7 @@
 use crate::extract::AppJson;
 use axum::{
-    extract::State,
-    http::{HeaderMap, StatusCode},
+    extract::{Form, Path, State},
+    http::{header, HeaderMap, StatusCode},
     Json,
 };
 use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
@@ -13,14 +13,19 @@ use axum::response::IntoResponse;
 
 use crate::api::auth::dto::{
     AppleMobileLoginReq, FindIdReq, FindIdRes, FindPasswordReq, FindPasswordRes, GoogleAuthUrlRes,
-    GoogleCallbackQuery, GoogleMobileLoginReq, LoginMobileRes, LoginReq, LoginRes, LogoutAllReq,
-    LogoutRes, MfaChallengeRes, MfaDisableReq, MfaDisableRes, MfaLoginReq, MfaSetupRes,
-    MfaVerifySetupReq, MfaVerifySetupRes, RefreshReq, RequestResetReq, RequestResetRes,
-    ResendVerificationReq, ResendVerificationRes, ResetPwReq, ResetPwRes, VerifyEmailReq,
-    VerifyEmailRes, VerifyResetReq, VerifyResetRes,
+    GoogleCallbackQuery, GoogleMobileLoginReq, KakaoMobileLoginReq, LoginMobileRes, LoginReq,
+    LoginRes, LogoutAllReq, LogoutRes, MfaChallengeRes, MfaDisableReq, MfaDisableRes, MfaLoginReq,
+    MfaSetupRes, MfaVerifySetupReq, MfaVerifySetupRes, NaverMobileLoginReq, PasskeyListRes,
+    PasskeyLoginReq, PasskeyMfaOptionsReq, PasskeyOptionsRes, PasskeyRegisterReq, PasskeyRenameReq,
+    PasskeyRes, PasswordlessRequestReq, PasswordlessRequestRes, PasswordlessVerifyReq, ReauthReq,
+    ReauthRes, RefreshReq, RequestResetReq, RequestResetRes, ResendVerificationReq,
+    ResendVerificationRes, ResetPwReq, ResetPwRes, RiskRevokeReq, SamlAcsForm, SsoDiscoverQuery,
+    StepUpLoginReq, VerifyEmailReq, VerifyEmailRes, VerifyResetReq, VerifyResetRes,
 };
 use crate::api::auth::extractor::AuthUser;
-use crate::api::auth::service::{AuthService, LoginOutcome, OAuthLoginOutcome};
+use crate::api::auth::service::{
+    AuthService, LoginOutcome, OAuthLoginOutcome, OAuthProvider, SSO_PROVIDER,
+};
 use crate::error::AppError;
 use crate::state::AppState;
 
@@ -526,7 +531,7 @@ pub async fn resend_verification(
 }
 
 // =========================================================================
-// Google OAuth Handlers
+// 소셜 로그인 (웹 리다이렉트) Handlers — Google / 카카오 / 네이버
 // =========================================================================
 
 /// Google OAuth 시작 - 인증 URL 반환
@@ -542,7 +547,7 @@ pub async fn resend_verification(
 pub async fn google_auth_start(
     State(st): State<AppState>,
 ) -> Result<Json<GoogleAuthUrlRes>, AppError> {
-    let auth_url = AuthService::google_auth_start(&st).await?;
+    let auth_url = AuthService::oauth_auth_start(&st, OAuthProvider::Google).await?;
     Ok(Json(GoogleAuthUrlRes { auth_url }))
 }
 
@@ -569,6 +574,176 @@ pub async fn google_auth_callback(
     jar: CookieJar,
     Query(query): Query<GoogleCallbackQuery>,
 ) -> Result<(CookieJar, Redirect), AppError> {
+    oauth_callback_redirect(&st, OAuthProvider::Google, &headers, jar, query).await
+}
+
+/// 카카오 로그인 시작 - 인증 URL 반환
+#[utoipa::path(
+    get,
+    path = "/auth/kakao",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Kakao OAuth URL", body = GoogleAuthUrlRes),
+        (status = 500, description = "Internal Server Error", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn kakao_auth_start(
+    State(st): State<AppState>,
+) -> Result<Json<GoogleAuthUrlRes>, AppError> {
+    let auth_url = AuthService::oauth_auth_start(&st, OAuthProvider::Kakao).await?;
+    Ok(Json(GoogleAuthUrlRes { auth_url }))
+}
+
+/// 카카오 로그인 콜백 처리 (Google 콜백과 같은 리다이렉트 규칙)
+#[utoipa::path(
+    get,
+    path = "/auth/kakao/callback",
+    tag = "auth",
+    params(
+        ("code" = Option<String>, Query, description = "Authorization code"),
+        ("state" = String, Query, description = "State parameter for CSRF protection"),
+        ("error" = Option<String>, Query, description = "Error code if authorization failed"),
+        ("error_description" = Option<String>, Query, description = "Error description")
+    ),
+    responses(
+        (status = 302, description = "Redirect to frontend"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn kakao_auth_callback(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<GoogleCallbackQuery>,
+) -> Result<(CookieJar, Redirect), AppError> {
+    oauth_callback_redirect(&st, OAuthProvider::Kakao, &headers, jar, query).await
+}
+
+/// 네이버 로그인 시작 - 인증 URL 반환
+#[utoipa::path(
+    get,
+    path = "/auth/naver",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Naver OAuth URL", body = GoogleAuthUrlRes),
+        (status = 500, description = "Internal Server Error", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn naver_auth_start(
+    State(st): State<AppState>,
+) -> Result<Json<GoogleAuthUrlRes>, AppError> {
+    let auth_url = AuthService::oauth_auth_start(&st, OAuthProvider::Naver).await?;
+    Ok(Json(GoogleAuthUrlRes { auth_url }))
+}
+
+/// 네이버 로그인 콜백 처리 (Google 콜백과 같은 리다이렉트 규칙)
+#[utoipa::path(
+    get,
+    path = "/auth/naver/callback",
+    tag = "auth",
+    params(
+        ("code" = Option<String>, Query, description = "Authorization code"),
+        ("state" = String, Query, description = "State parameter for CSRF protection"),
+        ("error" = Option<String>, Query, description = "Error code if authorization failed"),
+        ("error_description" = Option<String>, Query, description = "Error description")
+    ),
+    responses(
+        (status = 302, description = "Redirect to frontend"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn naver_auth_callback(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<GoogleCallbackQuery>,
+) -> Result<(CookieJar, Redirect), AppError> {
+    oauth_callback_redirect(&st, OAuthProvider::Naver, &headers, jar, query).await
+}
+
+/// 소셜 계정 연결을 시작한 브라우저 바인딩 쿠키 이름
+pub const OAUTH_LINK_COOKIE: &str = "ak_oauth_link";
+
+/// 소셜 계정 연결 바인딩 쿠키 (`ttl_secs` 가 음수면 만료 쿠키).
+/// provider → 콜백은 cross-site 이동이라 설정과 무관하게 SameSite=Lax.
+/// 단 기관 SAML ACS 는 IdP 의 cross-site POST 라 Lax 쿠키가 실리지 않음 → Secure 환경에서는 None
+pub fn oauth_link_cookie(st: &AppState, value: String, ttl_secs: i64) -> Cookie<'static> {
+    let mut cookie = Cookie::new(OAUTH_LINK_COOKIE, value);
+    cookie.set_path("/");
+    cookie.set_http_only(true);
+    cookie.set_secure(st.cfg.refresh_cookie_secure);
+    cookie.set_same_site(if st.cfg.refresh_cookie_secure {
+        SameSite::None
+    } else {
+        SameSite::Lax
+    });
+    cookie.set_expires(OffsetDateTime::now_utc() + Duration::seconds(ttl_secs));
+
+    if let Some(domain) = &st.cfg.refresh_cookie_domain {
+        cookie.set_domain(domain.clone());
+    }
+    cookie
+}
+
+/// 계정 설정에서 시작한 연결(link intent) 조회 (일회용).
+/// 연결을 시작한 브라우저(바인딩 쿠키)가 아니면 Err — None 이면 일반 로그인
+async fn take_link_intent(
+    st: &AppState,
+    state: &str,
+    jar: &CookieJar,
+) -> Option<Result<i64, String>> {
+    let binding = jar.get(OAUTH_LINK_COOKIE).map(|c| c.value().to_string());
+    match AuthService::take_oauth_link_intent(st, state, binding.as_deref()).await {
+        Ok(None) => None,
+        Ok(Some(user_id)) => Some(Ok(user_id)),
+        Err(e) => Some(Err(e.to_string())),
+    }
+}
+
+/// 연결 결과 → 프론트엔드 설정 화면 URL
+fn link_redirect_url(st: &AppState, provider: &str, result: Result<(), String>) -> String {
+    match result {
+        Ok(()) => format!(
+            "{}/settings/security?linked={}",
+            st.cfg.frontend_url, provider
+        ),
+        Err(desc) => format!(
+            "{}/settings/security?error=link_failed&error_description={}",
+            st.cfg.frontend_url,
+            urlencoding::encode(&desc)
+        ),
+    }
+}
+
+/// 콜백 공통 — 계정 연결(link intent)이면 설정 화면, 로그인이면 로그인 화면으로 리다이렉트
+async fn oauth_callback_redirect(
+    st: &AppState,
+    provider: OAuthProvider,
+    headers: &HeaderMap,
+    jar: CookieJar,
+    query: GoogleCallbackQuery,
+) -> Result<(CookieJar, Redirect), AppError> {
+    // 계정 설정에서 시작한 연결(link intent) — 로그인 대신 연결하고 설정 화면으로 돌려보낸다
+    if let Some(link) = take_link_intent(st, &query.state, &jar).await {
+        let jar = jar.add(oauth_link_cookie(st, String::new(), -86_400));
+        let result = match (link, query.error, query.code) {
+            (Err(desc), _, _) => Err(desc),
+            (Ok(_), Some(error), _) => Err(format!(
+                "{}: {}",
+                error,
+                query.error_description.unwrap_or_default()
+            )),
+            (Ok(user_id), None, Some(code)) => {
+                AuthService::oauth_link_callback(st, provider, &code, &query.state, user_id)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            (Ok(_), None, None) => Err("Missing authorization code".to_string()),
+        };
+        let url = link_redirect_url(st, provider.as_str(), result);
+        return Ok((jar, Redirect::temporary(&url)));
+    }
+
     // 에러 처리 (사용자 취소 등)
     if let Some(error) = query.error {
         let desc = query.error_description.unwrap_or_default();
@@ -586,13 +761,14 @@ pub async fn google_auth_callback(
         .code
         .ok_or_else(|| AppError::BadRequest("Missing authorization code".into()))?;
 
-    let ip = extract_client_ip(&headers);
-    let ua = extract_user_agent(&headers);
-    let parsed_ua = parse_user_agent(&headers);
+    let ip = extract_client_ip(headers);
+    let ua = extract_user_agent(headers);
+    let parsed_ua = parse_user_agent(headers);
 
     // OAuth 콜백 처리
     let result =
-        AuthService::google_auth_callback(&st, &code, &query.state, ip, ua, parsed_ua).await;
+        AuthService::oauth_auth_callback(st, provider, &code, &query.state, ip, ua, parsed_ua)
+            .await;
 
     match result {
         Ok(OAuthLoginOutcome::Success(s)) => {
@@ -622,6 +798,196 @@ pub async fn google_auth_callback(
     }
 }
 
+// =========================================================================
+// 기관 SSO (OIDC / SAML)
+// =========================================================================
+
+/// SSO 결과 → 프론트엔드 로그인 화면 URL (소셜 콜백과 같은 쿼리 규칙)
+fn sso_login_redirect_url(st: &AppState, result: &Result<OAuthLoginOutcome, AppError>) -> String {
+    match result {
+        Ok(OAuthLoginOutcome::Success(s)) => format!(
+            "{}/login?login=success&user_id={}&is_new_user={}",
+            st.cfg.frontend_url, s.login_res.user_id, s.is_new_user
+        ),
+        Ok(OAuthLoginOutcome::MfaChallenge { mfa_token, user_id }) => format!(
+            "{}/login?mfa_required=true&mfa_token={}&user_id={}",
+            st.cfg.frontend_url, mfa_token, user_id
+        ),
+        Err(e) => format!(
+            "{}/login?error=sso_failed&error_description={}",
+            st.cfg.frontend_url,
+            urlencoding::encode(&e.to_string())
+        ),
+    }
+}
+
+fn sso_login_redirect(
+    st: &AppState,
+    jar: CookieJar,
+    result: Result<OAuthLoginOutcome, AppError>,
+) -> (CookieJar, String) {
+    let url = sso_login_redirect_url(st, &result);
+    match result {
+        Ok(OAuthLoginOutcome::Success(s)) => (jar.add(s.cookie), url),
+        _ => (jar, url),
+    }
+}
+
+/// 로그인 discovery — 이메일 도메인의 기관 IdP 로 리다이렉트
+/// SSO 가 없는 도메인은 프론트엔드 로그인 화면으로 돌려보낸다 (error=sso_not_configured)
+#[utoipa::path(
+    get,
+    path = "/auth/sso/discover",
+    tag = "auth",
+    params(("email" = String, Query, description = "Email address whose domain selects the organization IdP")),
+    responses(
+        (status = 307, description = "Redirect to the organization IdP, or to the frontend login page if SSO is not configured")
+    )
+)]
+pub async fn sso_discover(
+    State(st): State<AppState>,
+    Query(query): Query<SsoDiscoverQuery>,
+) -> Redirect {
+    match AuthService::sso_discover(&st, &query.email).await {
+        Ok(url) => Redirect::temporary(&url),
+        Err(AppError::NotFound) | Err(AppError::BadRequest(_)) => Redirect::temporary(&format!(
+            "{}/login?error=sso_not_configured",
+            st.cfg.frontend_url
+        )),
+        Err(e) => Redirect::temporary(&format!(
+            "{}/login?error=sso_failed&error_description={}",
+            st.cfg.frontend_url,
+            urlencoding::encode(&e.to_string())
+        )),
+    }
+}
+
+/// 기관 OIDC 콜백
+#[utoipa::path(
+    get,
+    path = "/auth/sso/oidc/callback",
+    tag = "auth",
+    params(
+        ("code" = Option<String>, Query, description = "Authorization code"),
+        ("state" = String, Query, description = "State issued by SSO discovery"),
+        ("error" = Option<String>, Query, description = "Error code if authorization failed"),
+        ("error_description" = Option<String>, Query, description = "Error description")
+    ),
+    responses(
+        (status = 307, description = "Redirect to frontend")
+    )
+)]
+pub async fn sso_oidc_callback(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<GoogleCallbackQuery>,
+) -> (CookieJar, Redirect) {
+    // 계정 설정에서 시작한 기관 SSO 연결
+    if let Some(link) = take_link_intent(&st, &query.state, &jar).await {
+        let jar = jar.add(oauth_link_cookie(&st, String::new(), -86_400));
+        let result = match (link, query.error, query.code) {
+            (Err(desc), _, _) => Err(desc),
+            (Ok(_), Some(error), _) => Err(format!(
+                "{}: {}",
+                error,
+                query.error_description.unwrap_or_default()
+            )),
+            (Ok(user_id), None, Some(code)) => {
+                AuthService::sso_oidc_link_callback(&st, &code, &query.state, user_id)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            (Ok(_), None, None) => Err("Missing authorization code".to_string()),
+        };
+        let url = link_redirect_url(&st, SSO_PROVIDER, result);
+        return (jar, Redirect::temporary(&url));
+    }
+
+    let result = match (query.error, query.code) {
+        (Some(error), _) => Err(AppError::Unauthorized(format!(
+            "{}: {}",
+            error,
+            query.error_description.unwrap_or_default()
+        ))),
+        (None, Some(code)) => {
+            let ip = extract_client_ip(&headers);
+            let ua = extract_user_agent(&headers);
+            let parsed_ua = parse_user_agent(&headers);
+            AuthService::sso_oidc_callback(&st, &code, &query.state, ip, ua, parsed_ua).await
+        }
+        (None, None) => Err(AppError::BadRequest("Missing authorization code".into())),
+    };
+    let (jar, url) = sso_login_redirect(&st, jar, result);
+    (jar, Redirect::temporary(&url))
+}
+
+/// SAML Assertion Consumer Service (HTTP-POST 바인딩).
+/// IdP 가 브라우저로 POST 하므로 303 으로 프론트엔드 GET 리다이렉트
+#[utoipa::path(
+    post,
+    path = "/auth/sso/saml/acs",
+    tag = "auth",
+    request_body(content_type = "application/x-www-form-urlencoded", description = "SAMLResponse and RelayState posted by the IdP"),
+    responses(
+        (status = 303, description = "Redirect to frontend")
+    )
+)]
+pub async fn sso_saml_acs(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Form(form): Form<SamlAcsForm>,
+) -> (CookieJar, Redirect) {
+    // 계정 설정에서 시작한 기관 SSO 연결
+    if let Some(link) = take_link_intent(&st, &form.relay_state, &jar).await {
+        let jar = jar.add(oauth_link_cookie(&st, String::new(), -86_400));
+        let result = match link {
+            Ok(user_id) => {
+                AuthService::sso_saml_link_acs(&st, &form.saml_response, &form.relay_state, user_id)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            Err(desc) => Err(desc),
+        };
+        let url = link_redirect_url(&st, SSO_PROVIDER, result);
+        return (jar, Redirect::to(&url));
+    }
+
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+    let result = AuthService::sso_saml_acs(
+        &st,
+        &form.saml_response,
+        &form.relay_state,
+        ip,
+        ua,
+        parsed_ua,
+    )
+    .await;
+    let (jar, url) = sso_login_redirect(&st, jar, result);
+    (jar, Redirect::to(&url))
+}
+
+/// IdP 등록용 SAML SP 메타데이터
+#[utoipa::path(
+    get,
+    path = "/auth/sso/saml/metadata",
+    tag = "auth",
+    responses(
+        (status = 200, description = "SAML service provider metadata", content_type = "application/samlmetadata+xml", body = String),
+        (status = 503, description = "SSO_PUBLIC_BASE_URL not configured", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn sso_saml_metadata(State(st): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    let xml = AuthService::sso_saml_metadata(&st)?;
+    Ok((
+        [(header::CONTENT_TYPE, "application/samlmetadata+xml")],
+        xml,
+    ))
+}
+
 // =========================================================================
 // MFA Handlers
 // =========================================================================
@@ -723,9 +1089,29 @@ pub async fn mfa_disable(
 }
 
 // =============================================================================
-// 모바일 OAuth (Google/Apple) + MFA 모바일
+// 모바일 OAuth (Google/Apple/카카오/네이버) + MFA 모바일
 // =============================================================================
 
+/// 모바일 OAuth 결과 → JSON 응답 (세션 토큰 또는 MFA 챌린지)
+fn oauth_mobile_response(outcome: OAuthLoginOutcome) -> axum::response::Response {
+    match outcome {
+        OAuthLoginOutcome::Success(s) => Json(LoginMobileRes {
+            user_id: s.login_res.user_id,
+            access: s.login_res.access,
+            session_id: s.login_res.session_id,
+            refresh_token: s.refresh_token,
+            refresh_expires_in: s.ttl,
+        })
+        .into_response(),
+        OAuthLoginOutcome::MfaChallenge { mfa_token, user_id } => Json(MfaChallengeRes {
+            mfa_required: true,
+            mfa_token,
+            user_id,
+        })
+        .into_response(),
+    }
+}
+
 /// 모바일 Google OAuth 로그인 (ID token 직접 검증)
 #[utoipa::path(
     post,
@@ -747,22 +1133,8 @@ pub async fn google_mobile_login(
     let ua = extract_user_agent(&headers);
     let parsed_ua = parse_user_agent(&headers);
 
-    match AuthService::google_mobile_login(&st, req, ip, ua, parsed_ua).await? {
-        OAuthLoginOutcome::Success(s) => Ok(Json(LoginMobileRes {
-            user_id: s.login_res.user_id,
-            access: s.login_res.access,
-            session_id: s.login_res.session_id,
-            refresh_token: s.refresh_token,
-            refresh_expires_in: s.ttl,
-        })
-        .into_response()),
-        OAuthLoginOutcome::MfaChallenge { mfa_token, user_id } => Ok(Json(MfaChallengeRes {
-            mfa_required: true,
-            mfa_token,
-            user_id,
-        })
-        .into_response()),
-    }
+    let outcome = AuthService::google_mobile_login(&st, req, ip, ua, parsed_ua).await?;
+    Ok(oauth_mobile_response(outcome))
 }
 
 /// 모바일 Apple OAuth 로그인 (ID token 직접 검증)
@@ -786,22 +1158,60 @@ pub async fn apple_mobile_login(
     let ua = extract_user_agent(&headers);
     let parsed_ua = parse_user_agent(&headers);
 
-    match AuthService::apple_mobile_login(&st, req, ip, ua, parsed_ua).await? {
-        OAuthLoginOutcome::Success(s) => Ok(Json(LoginMobileRes {
-            user_id: s.login_res.user_id,
-            access: s.login_res.access,
-            session_id: s.login_res.session_id,
-            refresh_token: s.refresh_token,
-            refresh_expires_in: s.ttl,
-        })
-        .into_response()),
-        OAuthLoginOutcome::MfaChallenge { mfa_token, user_id } => Ok(Json(MfaChallengeRes {
-            mfa_required: true,
-            mfa_token,
-            user_id,
-        })
-        .into_response()),
-    }
+    let outcome = AuthService::apple_mobile_login(&st, req, ip, ua, parsed_ua).await?;
+    Ok(oauth_mobile_response(outcome))
+}
+
+/// 모바일 카카오 로그인 (카카오 SDK ID token 직접 검증)
+#[utoipa::path(
+    post,
+    path = "/auth/kakao-mobile",
+    tag = "auth",
+    request_body = KakaoMobileLoginReq,
+    responses(
+        (status = 200, description = "Kakao login successful (mobile)", body = LoginMobileRes),
+        (status = 400, description = "Bad request (이메일 미동의 신규 가입 등)", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 409, description = "미인증 이메일로 기존 계정 연결 시도 (OAUTH_EMAIL_NOT_VERIFIED)", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn kakao_mobile_login(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<KakaoMobileLoginReq>,
+) -> Result<axum::response::Response, AppError> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+
+    let outcome = AuthService::kakao_mobile_login(&st, req, ip, ua, parsed_ua).await?;
+    Ok(oauth_mobile_response(outcome))
+}
+
+/// 모바일 네이버 로그인 (`GET /auth/naver` 로 시작한 Authorization Code 교환)
+#[utoipa::path(
+    post,
+    path = "/auth/naver-mobile",
+    tag = "auth",
+    request_body = NaverMobileLoginReq,
+    responses(
+        (status = 200, description = "Naver login successful (mobile)", body = LoginMobileRes),
+        (status = 400, description = "Bad request (이메일 미동의 신규 가입 등)", body = crate::error::ErrorBody),
+        (status = 401, description = "Unknown or reused state (AUTH_401_INVALID_OAUTH_STATE)", body = crate::error::ErrorBody),
+        (status = 409, description = "같은 이메일의 기존 계정 존재 — 계정 설정에서 연결 (OAUTH_EMAIL_NOT_VERIFIED)", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn naver_mobile_login(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<NaverMobileLoginReq>,
+) -> Result<axum::response::Response, AppError> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+
+    let outcome = AuthService::naver_mobile_login(&st, req, ip, ua, parsed_ua).await?;
+    Ok(oauth_mobile_response(outcome))
 }
 
 /// 모바일 MFA 2단계 인증 (refresh_token을 JSON body로 반환)
@@ -833,3 +1243,470 @@ pub async fn mfa_login_mobile(
         refresh_expires_in: ttl,
     }))
 }
+
+// =============================================================================
+// 재인증 (보안 설정 변경 전 step-up)
+// =============================================================================
+
+/// 재인증 메일 코드 발송 (현재 세션 전용)
+#[utoipa::path(
+    post,
+    path = "/auth/reauth/email-code",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Code sent", body = ReauthRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many requests", body = crate::error::ErrorBody),
+        (status = 503, description = "Email service unavailable", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn reauth_email_code(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<ReauthRes>, AppError> {
+    let res = AuthService::reauth_email_code(&st, auth_user.sub, &auth_user.session_id).await?;
+    Ok(Json(res))
+}
+
+/// 재인증 (비밀번호 / TOTP·백업 코드 / 메일 코드) — 패스키 등록, 소셜 계정 연결, 비밀번호 설정 전 필수
+#[utoipa::path(
+    post,
+    path = "/auth/reauth",
+    tag = "auth",
+    request_body = ReauthReq,
+    responses(
+        (status = 200, description = "Re-authenticated for this session", body = ReauthRes),
+        (status = 400, description = "Method unavailable or missing field", body = crate::error::ErrorBody),
+        (status = 401, description = "Invalid credentials or code expired", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn reauth(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    AppJson(req): AppJson<ReauthReq>,
+) -> Result<Json<ReauthRes>, AppError> {
+    let res = AuthService::reauth(&st, auth_user.sub, &auth_user.session_id, req).await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// 패스키 (WebAuthn)
+// =============================================================================
+
+/// 패스키 등록 옵션 발급 (navigator.credentials.create 용) — 먼저 `POST /auth/reauth` 필요
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/register/options",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Registration options issued", body = PasskeyOptionsRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Re-authentication required", body = crate::error::ErrorBody),
+        (status = 409, description = "Passkey limit reached", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn passkey_register_options(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<PasskeyOptionsRes>, AppError> {
+    let res =
+        AuthService::passkey_register_options(&st, auth_user.sub, &auth_user.session_id).await?;
+    Ok(Json(res))
+}
+
+/// 패스키 등록 완료
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/register",
+    tag = "auth",
+    request_body = PasskeyRegisterReq,
+    responses(
+        (status = 201, description = "Passkey registered", body = PasskeyRes),
+        (status = 400, description = "Invalid attestation response", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized or challenge expired", body = crate::error::ErrorBody),
+        (status = 403, description = "Re-authentication required", body = crate::error::ErrorBody),
+        (status = 409, description = "Already registered or limit reached", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn passkey_register(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    AppJson(req): AppJson<PasskeyRegisterReq>,
+) -> Result<(StatusCode, Json<PasskeyRes>), AppError> {
+    let res = AuthService::passkey_register(&st, auth_user.sub, &auth_user.session_id, req).await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+/// 내 패스키 목록
+#[utoipa::path(
+    get,
+    path = "/auth/passkeys",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Registered passkeys", body = PasskeyListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn list_passkeys(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> Result<Json<PasskeyListRes>, AppError> {
+    let res = AuthService::list_passkeys(&st, auth_user.sub).await?;
+    Ok(Json(res))
+}
+
+/// 패스키 이름 변경
+#[utoipa::path(
+    patch,
+    path = "/auth/passkeys/{passkey_id}",
+    tag = "auth",
+    params(("passkey_id" = i64, Path, description = "Passkey ID")),
+    request_body = PasskeyRenameReq,
+    responses(
+        (status = 200, description = "Passkey renamed", body = PasskeyRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Not found", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn rename_passkey(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(passkey_id): Path<i64>,
+    AppJson(req): AppJson<PasskeyRenameReq>,
+) -> Result<Json<PasskeyRes>, AppError> {
+    let res = AuthService::rename_passkey(&st, auth_user.sub, passkey_id, req).await?;
+    Ok(Json(res))
+}
+
+/// 패스키 삭제
+#[utoipa::path(
+    delete,
+    path = "/auth/passkeys/{passkey_id}",
+    tag = "auth",
+    params(("passkey_id" = i64, Path, description = "Passkey ID")),
+    responses(
+        (status = 204, description = "Passkey deleted"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Re-authentication required", body = crate::error::ErrorBody),
+        (status = 404, description = "Not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Last remaining login method", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn delete_passkey(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(passkey_id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    AuthService::delete_passkey(&st, auth_user.sub, &auth_user.session_id, passkey_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 패스키 로그인 옵션 발급 (navigator.credentials.get 용, discoverable credential)
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/login/options",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Authentication options issued", body = PasskeyOptionsRes),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passkey_login_options(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<PasskeyOptionsRes>, AppError> {
+    let ip = extract_client_ip(&headers);
+    let res = AuthService::passkey_login_options(&st, &ip).await?;
+    Ok(Json(res))
+}
+
+/// 패스키 로그인 (비밀번호 없는 1차 로그인)
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/login",
+    tag = "auth",
+    request_body = PasskeyLoginReq,
+    responses(
+        (status = 200, description = "Passkey login successful", body = LoginRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 401, description = "Assertion rejected or challenge expired", body = crate::error::ErrorBody),
+        (status = 403, description = "Account disabled or email not verified", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passkey_login(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    AppJson(req): AppJson<PasskeyLoginReq>,
+) -> Result<(CookieJar, Json<LoginRes>), AppError> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+
+    let (login_res, cookie, _, _refresh_token) =
+        AuthService::passkey_login(&st, req, ip, ua, parsed_ua).await?;
+    let jar = jar.add(cookie);
+
+    Ok((jar, Json(login_res)))
+}
+
+/// 모바일 패스키 로그인 (refresh token 을 JSON body 로 반환)
+#[utoipa::path(
+    post,
+    path = "/auth/passkeys/login-mobile",
+    tag = "auth",
+    request_body = PasskeyLoginReq,
+    responses(
+        (status = 200, description = "Passkey login successful (mobile)", body = LoginMobileRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 401, description = "Assertion rejected or challenge expired", body = crate::error::ErrorBody),
+        (status = 403, description = "Account disabled or email not verified", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passkey_login_mobile(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<PasskeyLoginReq>,
+) -> Result<Json<LoginMobileRes>, AppError> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+
+    let (login_res, _cookie, ttl, refresh_token) =
+        AuthService::passkey_login(&st, req, ip, ua, parsed_ua).await?;
+
+    Ok(Json(LoginMobileRes {
+        user_id: login_res.user_id,
+        access: login_res.access,
+        session_id: login_res.session_id,
+        refresh_token,
+        refresh_expires_in: ttl,
+    }))
+}
+
+/// MFA 2차 인증용 패스키 옵션 (mfa_token 사용자의 패스키만 허용)
+#[utoipa::path(
+    post,
+    path = "/auth/mfa/passkey/options",
+    tag = "auth",
+    request_body = PasskeyMfaOptionsReq,
+    responses(
+        (status = 200, description = "Authentication options issued", body = PasskeyOptionsRes),
+        (status = 400, description = "No passkey registered", body = crate::error::ErrorBody),
+        (status = 401, description = "MFA token expired", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passkey_mfa_options(
+    State(st): State<AppState>,
+    AppJson(req): AppJson<PasskeyMfaOptionsReq>,
+) -> Result<Json<PasskeyOptionsRes>, AppError> {
+    let res = AuthService::passkey_mfa_options(&st, &req.mfa_token).await?;
+    Ok(Json(res))
+}
+
+/// JWT 서명 공개키 집합 (RFC 7517). 현재 + 퇴역(검증 전용) 키를 kid 와 함께 게시한다.
+/// HS256 모드(비대칭 키 미설정)에서는 keys 가 빈 배열.
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    tag = "auth",
+    responses(
+        (status = 200, description = "JSON Web Key Set for access token verification", content_type = "application/json")
+    )
+)]
+pub async fn jwks(State(st): State<AppState>) -> impl IntoResponse {
+    // 키 로테이션 시 새 kid 가 검증 측에 빨리 전파되도록 짧게 캐시
+    (
+        [(axum::http::header::CACHE_CONTROL, "public, max-age=300")],
+        Json(st.cfg.jwt_keyring.jwks().clone()),
+    )
+}
+
+// =============================================================================
+// 로그인 위험 평가 (step-up / "본인이 아닙니다")
+// =============================================================================
+
+/// 위험 로그인 step-up 완료 (로그인 응답 `AUTH_403_STEP_UP_REQUIRED:{token}` + 메일 코드)
+#[utoipa::path(
+    post,
+    path = "/auth/login/step-up",
+    tag = "auth",
+    request_body = StepUpLoginReq,
+    responses(
+        (status = 200, description = "Step-up verified, session issued", body = LoginRes),
+        (status = 401, description = "Invalid code or expired token", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn login_step_up(
+    State(st): State<AppState>,
+    jar: CookieJar,
+    AppJson(req): AppJson<StepUpLoginReq>,
+) -> Result<(CookieJar, Json<LoginRes>), AppError> {
+    let (login_res, cookie, _, _refresh_token) = AuthService::login_step_up(&st, req).await?;
+    let jar = jar.add(cookie);
+
+    Ok((jar, Json(login_res)))
+}
+
+/// 모바일 step-up 완료 (refresh_token을 JSON body로 반환)
+#[utoipa::path(
+    post,
+    path = "/auth/login/step-up-mobile",
+    tag = "auth",
+    request_body = StepUpLoginReq,
+    responses(
+        (status = 200, description = "Step-up verified, session issued (mobile)", body = LoginMobileRes),
+        (status = 401, description = "Invalid code or expired token", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn login_step_up_mobile(
+    State(st): State<AppState>,
+    AppJson(req): AppJson<StepUpLoginReq>,
+) -> Result<Json<LoginMobileRes>, AppError> {
+    let (login_res, _cookie, ttl, refresh_token) = AuthService::login_step_up(&st, req).await?;
+
+    Ok(Json(LoginMobileRes {
+        user_id: login_res.user_id,
+        access: login_res.access,
+        session_id: login_res.session_id,
+        refresh_token,
+        refresh_expires_in: ttl,
+    }))
+}
+
+/// 위험 로그인 알림 메일의 "본인이 아닙니다" — 모든 기기 로그아웃
+#[utoipa::path(
+    post,
+    path = "/auth/risk/revoke",
+    tag = "auth",
+    request_body = RiskRevokeReq,
+    responses(
+        (status = 200, description = "All sessions revoked", body = LogoutRes),
+        (status = 401, description = "Invalid or expired link", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn risk_revoke(
+    State(st): State<AppState>,
+    AppJson(req): AppJson<RiskRevokeReq>,
+) -> Result<Json<LogoutRes>, AppError> {
+    let res = AuthService::risk_revoke(&st, req).await?;
+    Ok(Json(res))
+}
+
+// =========================================================================
+// 비밀번호 없는 로그인 (이메일 매직 링크 / 6자리 코드)
+// =========================================================================
+
+/// 로그인 링크 또는 로그인 코드 메일 요청 (계정 존재 여부와 무관하게 동일 응답)
+#[utoipa::path(
+    post,
+    path = "/auth/passwordless/request",
+    tag = "auth",
+    request_body = PasswordlessRequestReq,
+    responses(
+        (status = 200, description = "Request accepted", body = PasswordlessRequestRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 429, description = "Too Many Requests", body = crate::error::ErrorBody),
+        (status = 503, description = "Email service not configured", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passwordless_request(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<PasswordlessRequestReq>,
+) -> Result<Json<PasswordlessRequestRes>, AppError> {
+    let ip = extract_client_ip(&headers);
+    let res = AuthService::passwordless_request(&st, req, ip).await?;
+    Ok(Json(res))
+}
+
+/// 매직 링크 토큰 또는 이메일 + 코드로 로그인 (MFA 사용 계정은 MFA 챌린지 반환)
+#[utoipa::path(
+    post,
+    path = "/auth/passwordless/verify",
+    tag = "auth",
+    request_body = PasswordlessVerifyReq,
+    responses(
+        (status = 200, description = "Login successful or MFA challenge", body = LoginRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 401, description = "Invalid or expired link/code", body = crate::error::ErrorBody),
+        (status = 403, description = "Account disabled or step-up required", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passwordless_verify(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    AppJson(req): AppJson<PasswordlessVerifyReq>,
+) -> Result<axum::response::Response, AppError> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+
+    match AuthService::passwordless_login(&st, req, ip, ua, parsed_ua).await? {
+        LoginOutcome::Success(s) => {
+            let jar = jar.add(s.cookie);
+            Ok((jar, Json(s.login_res)).into_response())
+        }
+        LoginOutcome::MfaChallenge { mfa_token, user_id } => Ok(Json(MfaChallengeRes {
+            mfa_required: true,
+            mfa_token,
+            user_id,
+        })
+        .into_response()),
+    }
+}
+
+/// 모바일 비밀번호 없는 로그인 (refresh_token을 JSON body로 반환)
+#[utoipa::path(
+    post,
+    path = "/auth/passwordless/verify-mobile",
+    tag = "auth",
+    request_body = PasswordlessVerifyReq,
+    responses(
+        (status = 200, description = "Login successful or MFA challenge (mobile)", body = LoginMobileRes),
+        (status = 400, description = "Bad request", body = crate::error::ErrorBody),
+        (status = 401, description = "Invalid or expired link/code", body = crate::error::ErrorBody),
+        (status = 403, description = "Account disabled or step-up required", body = crate::error::ErrorBody),
+        (status = 429, description = "Too many attempts", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn passwordless_verify_mobile(
+    State(st): State<AppState>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<PasswordlessVerifyReq>,
+) -> Result<axum::response::Response, AppError> {
+    let ip = extract_client_ip(&headers);
+    let ua = extract_user_agent(&headers);
+    let parsed_ua = parse_user_agent(&headers);
+
+    match AuthService::passwordless_login(&st, req, ip, ua, parsed_ua).await? {
+        LoginOutcome::Success(s) => Ok(Json(LoginMobileRes {
+            user_id: s.login_res.user_id,
+            access: s.login_res.access,
+            session_id: s.login_res.session_id,
+            refresh_token: s.refresh_token,
+            refresh_expires_in: s.ttl,
+        })
+        .into_response()),
+        LoginOutcome::MfaChallenge { mfa_token, user_id } => Ok(Json(MfaChallengeRes {
+            mfa_required: true,
+            mfa_token,
+            user_id,
+        })
+        .into_response()),
+    }
+}
@@ -1,4 +1,15 @@
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use std::collections::HashMap;
+use std::fmt;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
 use serde::{Deserialize, Serialize};
 use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
 use uuid::Uuid;
@@ -7,6 +18,9 @@ use crate::api::auth::dto::AccessTokenRes;
 use crate::error::{AppError, AppResult};
 use crate::types::UserAuth;
 
+/// RS256 키 최소 길이 (bits)
+const MIN_RSA_BITS: usize = 2048;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: i64,           // User ID
@@ -18,13 +32,170 @@ pub struct Claims {
     pub iss: String,        // Issuer
 }
 
+// =============================================================================
+// 서명 키링
+// =============================================================================
+
+/// JWT 서명/검증 키링
+///
+/// - 비대칭 모드: `JWT_SIGNING_KEY_V{n}` (PKCS#8 PEM) 에서 로딩. Ed25519 → EdDSA, RSA → RS256.
+///   신규 토큰은 현재 버전 키로 서명하고 `kid` 헤더에 키 ID 를 싣는다. 나머지(퇴역) 키는
+///   검증 전용으로 남아 기존 토큰이 만료될 때까지 유효하며, 공개키는 JWKS 로 게시된다.
+/// - HS256 모드: 비대칭 키 미설정 시 `JWT_SECRET` 단일 키 (기존 동작, `kid` 없음).
+///
+/// 검증 알고리즘은 토큰 헤더가 아니라 `kid` 로 찾은 키의 알고리즘으로 고정한다 (alg confusion 차단).
+#[derive(Clone)]
+pub struct JwtKeyring {
+    signing_kid: Option<String>,
+    signing_alg: Algorithm,
+    signing_key: EncodingKey,
+    verifying: HashMap<String, (Algorithm, DecodingKey)>,
+    /// kid 없는 HS256 토큰 검증 키 (HS256 모드 또는 비대칭 전환 과도기)
+    legacy_hs256: Option<DecodingKey>,
+    jwks: JwkSet,
+}
+
+impl JwtKeyring {
+    /// `JWT_SECRET` 기반 HS256 단일 키링 (JWKS 비어 있음)
+    pub fn hs256(secret: &str) -> Self {
+        Self {
+            signing_kid: None,
+            signing_alg: Algorithm::HS256,
+            signing_key: EncodingKey::from_secret(secret.as_bytes()),
+            verifying: HashMap::new(),
+            legacy_hs256: Some(DecodingKey::from_secret(secret.as_bytes())),
+            jwks: JwkSet { keys: Vec::new() },
+        }
+    }
+
+    /// 버전별 PEM 개인키로 비대칭 키링 생성
+    ///
+    /// - `keys`: (버전, PKCS#8 PEM). kid = `v{버전}`
+    /// - `current_version`: 신규 토큰 서명에 사용할 버전 (keys 에 있어야 함)
+    /// - `legacy_secret`: Some 이면 kid 없는 HS256 토큰도 검증 (HS256 → 비대칭 전환 과도기용)
+    pub fn from_pem_keys(
+        keys: Vec<(u8, String)>,
+        current_version: u8,
+        legacy_secret: Option<&str>,
+    ) -> Result<Self, String> {
+        let mut signing: Option<(String, Algorithm, EncodingKey)> = None;
+        let mut verifying = HashMap::new();
+        let mut jwks = JwkSet { keys: Vec::new() };
+
+        for (version, pem) in keys {
+            let kid = format!("v{version}");
+            let (alg, encoding_key, jwk) = parse_signing_pem(&kid, &pem)
+                .map_err(|e| format!("JWT_SIGNING_KEY_V{version}: {e}"))?;
+            let decoding_key = DecodingKey::from_jwk(&jwk)
+                .map_err(|e| format!("JWT_SIGNING_KEY_V{version}: invalid public key: {e}"))?;
+
+            if version == current_version {
+                signing = Some((kid.clone(), alg, encoding_key));
+            }
+            verifying.insert(kid, (alg, decoding_key));
+            jwks.keys.push(jwk);
+        }
+
+        let (kid, alg, key) = signing.ok_or_else(|| {
+            format!("current JWT signing key version {current_version} is not configured")
+        })?;
+
+        Ok(Self {
+            signing_kid: Some(kid),
+            signing_alg: alg,
+            signing_key: key,
+            verifying,
+            legacy_hs256: legacy_secret.map(|s| DecodingKey::from_secret(s.as_bytes())),
+            jwks,
+        })
+    }
+
+    /// 게시용 공개키 집합 (`/.well-known/jwks.json`). HS256 모드에서는 비어 있다.
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+
+    /// 신규 토큰 서명 키 ID (HS256 모드는 None)
+    pub fn signing_kid(&self) -> Option<&str> {
+        self.signing_kid.as_deref()
+    }
+
+    /// 비대칭 키링인데 kid 없는 HS256 토큰도 받는 전환 과도기 상태인지 (기동 경고용)
+    pub fn accepts_legacy_hs256(&self) -> bool {
+        self.signing_kid.is_some() && self.legacy_hs256.is_some()
+    }
+}
+
+impl fmt::Debug for JwtKeyring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut kids: Vec<&String> = self.verifying.keys().collect();
+        kids.sort();
+        f.debug_struct("JwtKeyring")
+            .field("signing_kid", &self.signing_kid)
+            .field("signing_alg", &self.signing_alg)
+            .field("verifying_kids", &kids)
+            .field("legacy_hs256", &self.legacy_hs256.is_some())
+            .finish()
+    }
+}
+
+/// PKCS#8 PEM 개인키 → (알고리즘, 서명 키, 공개 JWK)
+fn parse_signing_pem(kid: &str, pem: &str) -> Result<(Algorithm, EncodingKey, Jwk), String> {
+    use ed25519_dalek::pkcs8::DecodePrivateKey as _;
+    use rsa::pkcs1::DecodeRsaPrivateKey as _;
+    use rsa::traits::PublicKeyParts as _;
+
+    let common = |alg: KeyAlgorithm| CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(alg),
+        key_id: Some(kid.to_string()),
+        ..Default::default()
+    };
+
+    if let Ok(sk) = ed25519_dalek::SigningKey::from_pkcs8_pem(pem) {
+        let encoding_key = EncodingKey::from_ed_pem(pem.as_bytes())
+            .map_err(|e| format!("invalid Ed25519 key: {e}"))?;
+        let jwk = Jwk {
+            common: common(KeyAlgorithm::EdDSA),
+            algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: URL_SAFE_NO_PAD.encode(sk.verifying_key().as_bytes()),
+            }),
+        };
+        return Ok((Algorithm::EdDSA, encoding_key, jwk));
+    }
+
+    let rsa_key = <rsa::RsaPrivateKey as rsa::pkcs8::DecodePrivateKey>::from_pkcs8_pem(pem)
+        .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(pem))
+        .map_err(|_| "expected an Ed25519 or RSA private key in PEM format".to_string())?;
+    if rsa_key.size() * 8 < MIN_RSA_BITS {
+        return Err(format!("RSA key must be at least {MIN_RSA_BITS} bits"));
+    }
+    let encoding_key =
+        EncodingKey::from_rsa_pem(pem.as_bytes()).map_err(|e| format!("invalid RSA key: {e}"))?;
+    let jwk = Jwk {
+        common: common(KeyAlgorithm::RS256),
+        algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n: URL_SAFE_NO_PAD.encode(rsa_key.n().to_bytes_be()),
+            e: URL_SAFE_NO_PAD.encode(rsa_key.e().to_bytes_be()),
+        }),
+    };
+    Ok((Algorithm::RS256, encoding_key, jwk))
+}
+
+// =============================================================================
+// 발급 / 검증
+// =============================================================================
+
 /// create_token 반환값: (AccessTokenRes, jti)
 pub fn create_token(
     user_id: i64,
     session_id: &str,
     role: UserAuth,
     ttl_minutes: i64,
-    secret: &str,
+    keyring: &JwtKeyring,
 ) -> AppResult<(AccessTokenRes, String)> {
     let now = OffsetDateTime::now_utc();
     let duration = Duration::minutes(ttl_minutes);
@@ -41,12 +212,11 @@ pub fn create_token(
         iss: "amk".to_string(),
     };
 
-    let token = encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_bytes()),
-    )
-    .map_err(|e| AppError::Internal(format!("Failed to encode JWT: {}", e)))?;
+    let mut header = Header::new(keyring.signing_alg);
+    header.kid = keyring.signing_kid.clone();
+
+    let token = encode(&header, &claims, &keyring.signing_key)
+        .map_err(|e| AppError::Internal(format!("Failed to encode JWT: {}", e)))?;
 
     // ISO 8601 포맷 문자열 생성 (프론트엔드 편의용)
     let expires_at_str = expires_in_dt
@@ -64,17 +234,31 @@ pub fn create_token(
     ))
 }
 
-pub fn decode_token(token: &str, secret: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+pub fn decode_token(
+    token: &str,
+    keyring: &JwtKeyring,
+) -> Result<Claims, jsonwebtoken::errors::Error> {
+    // kid 로 검증 키 선택. kid 없으면 레거시 HS256 키 (허용된 경우만).
+    // 알려지지 않은 kid = 폐기된 키 → 거부.
+    let header = decode_header(token)?;
+    let (alg, key) = match header.kid.as_deref() {
+        Some(kid) => keyring
+            .verifying
+            .get(kid)
+            .map(|(alg, key)| (*alg, key))
+            .ok_or(ErrorKind::InvalidToken)?,
+        None => keyring
+            .legacy_hs256
+            .as_ref()
+            .map(|key| (Algorithm::HS256, key))
+            .ok_or(ErrorKind::InvalidToken)?,
+    };
+
     // 2.2: 알고리즘 명시(alg confusion 차단) + issuer 강제.
     // Validation::default() 는 alg/iss 미검증 → 다른 용도 토큰 혼용 여지.
-    let mut validation = Validation::new(Algorithm::HS256);
+    let mut validation = Validation::new(alg);
     validation.set_issuer(&["amk"]);
-    decode(
-        token,
-        &DecodingKey::from_secret(secret.as_bytes()),
-        &validation,
-    )
-    .map(|data| data.claims)
+    decode(token, key, &validation).map(|data| data.claims)
 }
 
 #[cfg(test)]
@@ -83,16 +267,30 @@ mod tests {
 
     const TEST_SECRET: &str = "unit-test-secret-do-not-use-in-prod";
 
+    fn hs_keyring() -> JwtKeyring {
+        JwtKeyring::hs256(TEST_SECRET)
+    }
+
+    /// 고정 시드 Ed25519 키 → PKCS#8 PEM
+    fn ed25519_pem(seed: u8) -> String {
+        use ed25519_dalek::pkcs8::EncodePrivateKey;
+        use rsa::pkcs8::LineEnding;
+        ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
+            .to_pkcs8_pem(LineEnding::LF)
+            .expect("pem")
+            .to_string()
+    }
+
     #[test]
     fn test_create_decode_roundtrip_preserves_claims() {
         let (token_res, jti) =
-            create_token(42, "sess-abc", UserAuth::Learner, 30, TEST_SECRET).expect("create");
+            create_token(42, "sess-abc", UserAuth::Learner, 30, &hs_keyring()).expect("create");
 
         assert_eq!(token_res.token_type, "Bearer");
         assert_eq!(token_res.expires_in, 30 * 60);
         assert!(!token_res.access_token.is_empty());
 
-        let claims = decode_token(&token_res.access_token, TEST_SECRET).expect("decode");
+        let claims = decode_token(&token_res.access_token, &hs_keyring()).expect("decode");
         assert_eq!(claims.sub, 42);
         assert_eq!(claims.session_id, "sess-abc");
         assert_eq!(claims.role, UserAuth::Learner);
@@ -104,14 +302,17 @@ mod tests {
     #[test]
     fn test_decode_token_rejects_wrong_secret() {
         let (token_res, _) =
-            create_token(1, "s", UserAuth::Admin, 30, TEST_SECRET).expect("create");
-        let result = decode_token(&token_res.access_token, "different-secret");
+            create_token(1, "s", UserAuth::Admin, 30, &hs_keyring()).expect("create");
+        let result = decode_token(
+            &token_res.access_token,
+            &JwtKeyring::hs256("different-secret"),
+        );
         assert!(result.is_err(), "wrong secret must fail signature check");
     }
 
     #[test]
     fn test_decode_token_rejects_malformed_input() {
-        let result = decode_token("not.a.jwt", TEST_SECRET);
+        let result = decode_token("not.a.jwt", &hs_keyring());
         assert!(result.is_err(), "malformed token must error");
     }
 
@@ -120,8 +321,8 @@ mod tests {
         // ttl_minutes=0 = 즉시 만료. jsonwebtoken Validation default leeway=60s 이므로
         // 충분히 과거로 가야 한다 → 음수 ttl 사용
         let (token_res, _) =
-            create_token(1, "s", UserAuth::Learner, -120, TEST_SECRET).expect("create");
-        let result = decode_token(&token_res.access_token, TEST_SECRET);
+            create_token(1, "s", UserAuth::Learner, -120, &hs_keyring()).expect("create");
+        let result = decode_token(&token_res.access_token, &hs_keyring());
         assert!(result.is_err(), "expired token must fail validation");
     }
 
@@ -144,7 +345,7 @@ mod tests {
             &EncodingKey::from_secret(TEST_SECRET.as_bytes()),
         )
         .expect("encode");
-        let result = decode_token(&token, TEST_SECRET);
+        let result = decode_token(&token, &hs_keyring());
         assert!(
             result.is_err(),
             "iss != amk 토큰은 서명 유효해도 거부되어야 함"
@@ -153,22 +354,22 @@ mod tests {
 
     #[test]
     fn test_create_token_generates_unique_jti_per_call() {
-        let (_, jti1) = create_token(1, "s", UserAuth::Learner, 30, TEST_SECRET).expect("create");
-        let (_, jti2) = create_token(1, "s", UserAuth::Learner, 30, TEST_SECRET).expect("create");
+        let (_, jti1) = create_token(1, "s", UserAuth::Learner, 30, &hs_keyring()).expect("create");
+        let (_, jti2) = create_token(1, "s", UserAuth::Learner, 30, &hs_keyring()).expect("create");
         assert_ne!(jti1, jti2, "jti must be unique per token");
     }
 
     #[test]
     fn test_create_token_ttl_minutes_matches_expires_in_seconds() {
         let (token_res, _) =
-            create_token(1, "s", UserAuth::Learner, 90, TEST_SECRET).expect("create");
+            create_token(1, "s", UserAuth::Learner, 90, &hs_keyring()).expect("create");
         assert_eq!(token_res.expires_in, 5400, "90 min = 5400 sec");
     }
 
     #[test]
     fn test_create_token_includes_rfc3339_expires_at() {
         let (token_res, _) =
-            create_token(1, "s", UserAuth::Learner, 30, TEST_SECRET).expect("create");
+            create_token(1, "s", UserAuth::Learner, 30, &hs_keyring()).expect("create");
         // RFC3339 = "YYYY-MM-DDTHH:MM:SS...Z" 형식
         assert!(
             token_res.expires_at.contains('T') && token_res.expires_at.ends_with('Z'),
@@ -176,4 +377,115 @@ mod tests {
             token_res.expires_at
         );
     }
+
+    #[test]
+    fn test_eddsa_token_carries_kid_and_roundtrips() {
+        let keyring = JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1))], 1, None).expect("ring");
+        let (token_res, _) = create_token(7, "s", UserAuth::Learner, 30, &keyring).expect("create");
+
+        let header = decode_header(&token_res.access_token).expect("header");
+        assert_eq!(header.alg, Algorithm::EdDSA);
+        assert_eq!(header.kid.as_deref(), Some("v1"));
+
+        let claims = decode_token(&token_res.access_token, &keyring).expect("decode");
+        assert_eq!(claims.sub, 7);
+    }
+
+    #[test]
+    fn test_rotation_keeps_retired_key_verifiable() {
+        let old_ring = JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1))], 1, None).expect("ring");
+        let (old_token, _) = create_token(1, "s", UserAuth::Learner, 30, &old_ring).expect("old");
+
+        // v2 추가 + 현재 버전 전환 → 신규 토큰은 v2, v1 토큰은 만료까지 유효
+        let new_ring =
+            JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1)), (2, ed25519_pem(2))], 2, None)
+                .expect("ring");
+        let (new_token, _) = create_token(1, "s", UserAuth::Learner, 30, &new_ring).expect("new");
+        assert_eq!(
+            decode_header(&new_token.access_token)
+                .unwrap()
+                .kid
+                .as_deref(),
+            Some("v2")
+        );
+        assert!(decode_token(&old_token.access_token, &new_ring).is_ok());
+        assert!(decode_token(&new_token.access_token, &new_ring).is_ok());
+        assert_eq!(new_ring.jwks().keys.len(), 2);
+
+        // v1 제거 후에는 v1 토큰 거부
+        let pruned = JwtKeyring::from_pem_keys(vec![(2, ed25519_pem(2))], 2, None).expect("ring");
+        assert!(decode_token(&old_token.access_token, &pruned).is_err());
+    }
+
+    #[test]
+    fn test_legacy_hs256_tokens_only_accepted_when_enabled() {
+        let (legacy, _) = create_token(1, "s", UserAuth::Learner, 30, &hs_keyring()).expect("hs");
+
+        let with_legacy =
+            JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1))], 1, Some(TEST_SECRET))
+                .expect("ring");
+        assert!(decode_token(&legacy.access_token, &with_legacy).is_ok());
+        assert!(
+            with_legacy.accepts_legacy_hs256(),
+            "전환 과도기 = 기동 경고 대상"
+        );
+
+        let strict = JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1))], 1, None).expect("ring");
+        assert!(decode_token(&legacy.access_token, &strict).is_err());
+        assert!(!strict.accepts_legacy_hs256());
+        assert!(
+            !hs_keyring().accepts_legacy_hs256(),
+            "HS256 단일 모드는 과도기 아님"
+        );
+    }
+
+    #[test]
+    fn test_hs256_token_with_asymmetric_kid_is_rejected() {
+        // alg confusion: 공개키(JWKS x 값)를 HMAC 비밀로 쓴 위조 토큰
+        let keyring = JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1))], 1, None).expect("ring");
+        let AlgorithmParameters::OctetKeyPair(params) = &keyring.jwks().keys[0].algorithm else {
+            panic!("expected OKP jwk");
+        };
+        let now = OffsetDateTime::now_utc();
+        let claims = Claims {
+            sub: 1,
+            session_id: "s".into(),
+            role: UserAuth::Admin,
+            jti: Uuid::new_v4().to_string(),
+            exp: (now + Duration::minutes(30)).unix_timestamp(),
+            iat: now.unix_timestamp(),
+            iss: "amk".into(),
+        };
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some("v1".into());
+        let forged = encode(
+            &header,
+            &claims,
+            &EncodingKey::from_secret(params.x.as_bytes()),
+        )
+        .expect("encode");
+        assert!(decode_token(&forged, &keyring).is_err());
+    }
+
+    #[test]
+    fn test_from_pem_keys_rejects_missing_current_version_and_bad_pem() {
+        assert!(JwtKeyring::from_pem_keys(vec![(1, ed25519_pem(1))], 2, None).is_err());
+        assert!(JwtKeyring::from_pem_keys(vec![(1, "not a pem".into())], 1, None).is_err());
+    }
+
+    #[test]
+    fn test_jwks_publishes_public_parameters_only() {
+        let keyring = JwtKeyring::from_pem_keys(vec![(3, ed25519_pem(3))], 3, None).expect("ring");
+        let json = serde_json::to_value(keyring.jwks()).expect("json");
+        let key = &json["keys"][0];
+        assert_eq!(key["kid"], "v3");
+        assert_eq!(key["alg"], "EdDSA");
+        assert_eq!(key["kty"], "OKP");
+        assert_eq!(key["use"], "sig");
+        assert!(
+            key.get("d").is_none(),
+            "private key material must not be published"
+        );
+        assert!(hs_keyring().jwks().keys.is_empty());
+    }
 }
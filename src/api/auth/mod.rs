@@ -5,6 +5,9 @@ pub mod handler;
 pub mod jwt;
 pub mod password;
 pub mod repo;
+pub mod risk;
 pub mod router;
 pub mod service;
 pub mod session;
+pub mod sso;
+pub mod webauthn;
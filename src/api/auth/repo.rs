@@ -1,5 +1,7 @@
+use crate::api::auth::dto::PasskeyRes;
+use crate::api::auth::risk::{LastLogin, LoginHistory};
 use crate::error::AppResult;
-use crate::types::UserAuth;
+use crate::types::{LoginRiskAction, UserAuth};
 use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Postgres, Transaction};
 
@@ -100,6 +102,8 @@ impl AuthRepo {
         country_code: Option<&str>,
         asn: Option<i64>,
         org: Option<&str>,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
     ) -> AppResult<()> {
         sqlx::query(
             r#"
@@ -118,6 +122,7 @@ impl AuthRepo {
                 login_expire_at,
                 login_active_at,
                 login_country, login_asn, login_org,
+                login_latitude, login_longitude,
                 login_revoked_reason
             )
             VALUES (
@@ -142,6 +147,7 @@ impl AuthRepo {
                 NOW() + make_interval(secs => $9),
                 NOW(),
                 COALESCE($10, 'LC'), COALESCE($11, 0), COALESCE($12, 'local'),
+                $13, $14,
                 'none'
             )
         "#,
@@ -158,6 +164,8 @@ impl AuthRepo {
         .bind(country_code)
         .bind(asn)
         .bind(org)
+        .bind(latitude)
+        .bind(longitude)
         .execute(&mut **tx)
         .await?;
 
@@ -851,6 +859,8 @@ impl AuthRepo {
         country_code: Option<&str>,
         asn: Option<i64>,
         org: Option<&str>,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
     ) -> AppResult<()> {
         sqlx::query(
             r#"
@@ -869,6 +879,7 @@ impl AuthRepo {
                 login_expire_at,
                 login_active_at,
                 login_country, login_asn, login_org,
+                login_latitude, login_longitude,
                 login_revoked_reason
             )
             VALUES (
@@ -893,6 +904,7 @@ impl AuthRepo {
                 NOW() + make_interval(secs => $10),
                 NOW(),
                 COALESCE($11, 'LC'), COALESCE($12, 0), COALESCE($13, 'local'),
+                $14, $15,
                 'none'
             )
         "#,
@@ -910,6 +922,8 @@ impl AuthRepo {
         .bind(country_code)
         .bind(asn)
         .bind(org)
+        .bind(latitude)
+        .bind(longitude)
         .execute(&mut **tx)
         .await?;
 
@@ -1215,6 +1229,401 @@ impl AuthRepo {
         .await?;
         Ok(())
     }
+
+    // ---------------------------------------------------------------------
+    // Passkey (WebAuthn)
+    // ---------------------------------------------------------------------
+
+    /// 패스키 로그인용 사용자 조회 (user_id 기준)
+    pub async fn find_user_login_info_by_id(
+        pool: &PgPool,
+        user_id: i64,
+    ) -> AppResult<Option<UserLoginInfo>> {
+        let row = sqlx::query_as::<_, UserLoginInfo>(
+            r#"
+            SELECT
+                user_id,
+                user_email,
+                user_password,
+                user_state,
+                user_auth,
+                user_check_email,
+                user_mfa_enabled
+            FROM users
+            WHERE user_id = $1
+        "#,
+        )
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn find_passkeys(pool: &PgPool, user_id: i64) -> AppResult<Vec<PasskeyRes>> {
+        let rows = sqlx::query_as::<_, PasskeyRes>(&format!(
+            "{PASSKEY_SELECT} WHERE user_id = $1 ORDER BY passkey_created_at, passkey_id"
+        ))
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// allowCredentials / excludeCredentials 용 (credential ID, transports)
+    pub async fn find_passkey_credentials(
+        pool: &PgPool,
+        user_id: i64,
+    ) -> AppResult<Vec<(String, Vec<String>)>> {
+        let rows = sqlx::query_as::<_, (String, Vec<String>)>(
+            "SELECT passkey_credential_id, passkey_transports FROM user_passkey WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 패스키 저장 — credential ID 가 이미 등록돼 있으면 None
+    pub async fn insert_passkey(
+        pool: &PgPool,
+        passkey: &NewPasskey<'_>,
+    ) -> AppResult<Option<PasskeyRes>> {
+        let row = sqlx::query_as::<_, PasskeyRes>(&format!(
+            r#"
+            WITH inserted AS (
+                INSERT INTO user_passkey (
+                    user_id, passkey_credential_id, passkey_public_key, passkey_alg,
+                    passkey_sign_count, passkey_name, passkey_transports, passkey_aaguid,
+                    passkey_backup_eligible, passkey_backed_up
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT (passkey_credential_id) DO NOTHING
+                RETURNING *
+            )
+            {}
+            "#,
+            PASSKEY_SELECT.replace("FROM user_passkey", "FROM inserted")
+        ))
+        .bind(passkey.user_id)
+        .bind(passkey.credential_id)
+        .bind(passkey.public_key)
+        .bind(passkey.alg)
+        .bind(passkey.sign_count)
+        .bind(passkey.name)
+        .bind(passkey.transports)
+        .bind(passkey.aaguid)
+        .bind(passkey.backup_eligible)
+        .bind(passkey.backed_up)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn find_passkey_by_credential_id(
+        pool: &PgPool,
+        credential_id: &str,
+    ) -> AppResult<Option<PasskeyAuthRow>> {
+        let row = sqlx::query_as::<_, PasskeyAuthRow>(
+            r#"
+            SELECT passkey_id, user_id, passkey_public_key, passkey_sign_count
+            FROM user_passkey
+            WHERE passkey_credential_id = $1
+        "#,
+        )
+        .bind(credential_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// 인증 성공 후 카운터 / 백업 상태 / 최근 사용 시각 갱신
+    pub async fn update_passkey_usage(
+        pool: &PgPool,
+        passkey_id: i64,
+        sign_count: i64,
+        backed_up: bool,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE user_passkey
+            SET passkey_sign_count = $2,
+                passkey_backed_up = $3,
+                passkey_last_used_at = NOW()
+            WHERE passkey_id = $1
+        "#,
+        )
+        .bind(passkey_id)
+        .bind(sign_count)
+        .bind(backed_up)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn rename_passkey(
+        pool: &PgPool,
+        user_id: i64,
+        passkey_id: i64,
+        name: &str,
+    ) -> AppResult<Option<PasskeyRes>> {
+        let row = sqlx::query_as::<_, PasskeyRes>(&format!(
+            r#"
+            WITH updated AS (
+                UPDATE user_passkey SET passkey_name = $3
+                WHERE passkey_id = $2 AND user_id = $1
+                RETURNING *
+            )
+            {}
+            "#,
+            PASSKEY_SELECT.replace("FROM user_passkey", "FROM updated")
+        ))
+        .bind(user_id)
+        .bind(passkey_id)
+        .bind(name)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// 삭제된 패스키 이름 반환 (없으면 None)
+    pub async fn delete_passkey_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: i64,
+        passkey_id: i64,
+    ) -> AppResult<Option<String>> {
+        let name = sqlx::query_scalar::<_, String>(
+            "DELETE FROM user_passkey WHERE passkey_id = $2 AND user_id = $1 RETURNING passkey_name",
+        )
+        .bind(user_id)
+        .bind(passkey_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+        Ok(name)
+    }
+
+    // ---------------------------------------------------------------------
+    // Login Risk
+    // ---------------------------------------------------------------------
+
+    /// 위험 평가 비교 이력 — 최근 `history_days` 일 로그인 세션 (상태 무관)
+    pub async fn find_login_risk_history(
+        pool: &PgPool,
+        user_id: i64,
+        history_days: i64,
+    ) -> AppResult<LoginHistory> {
+        let families: Vec<LoginFamilyRow> = sqlx::query_as(
+            r#"
+            SELECT DISTINCT
+                NULLIF(login_country, 'LC') AS country,
+                lower(COALESCE(login_browser, '')) AS browser,
+                lower(COALESCE(login_os, '')) AS os
+            FROM login
+            WHERE user_id = $1
+              AND login_begin_at > NOW() - make_interval(days => $2::int)
+            "#,
+        )
+        .bind(user_id)
+        .bind(history_days)
+        .fetch_all(pool)
+        .await?;
+
+        let last: Option<LastLoginRow> = sqlx::query_as(
+            r#"
+            SELECT
+                NULLIF(login_country, 'LC') AS country,
+                login_latitude,
+                login_longitude,
+                login_begin_at
+            FROM login
+            WHERE user_id = $1
+            ORDER BY login_begin_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        let mut history = LoginHistory {
+            has_history: last.is_some(),
+            last: last.map(|r| LastLogin {
+                country: r.country.map(|c| c.trim().to_string()),
+                latitude: r.login_latitude,
+                longitude: r.login_longitude,
+                at: r.login_begin_at,
+            }),
+            ..Default::default()
+        };
+        for row in families {
+            if let Some(country) = row.country.map(|c| c.trim().to_string()) {
+                if !history.countries.contains(&country) {
+                    history.countries.push(country);
+                }
+            }
+            if !row.browser.is_empty() || !row.os.is_empty() {
+                history.ua_families.push((row.browser, row.os));
+            }
+        }
+        Ok(history)
+    }
+
+    pub async fn insert_login_risk_event(
+        pool: &PgPool,
+        ev: &NewLoginRiskEvent<'_>,
+    ) -> AppResult<i64> {
+        let id: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO login_risk_event (
+                user_id, login_session_id, risk_login_method, risk_reasons, risk_action,
+                risk_country, risk_prev_country, risk_city,
+                risk_device, risk_browser, risk_os,
+                risk_distance_km, risk_speed_kmh,
+                risk_step_up_passed_at
+            )
+            VALUES (
+                $1, CAST($2 AS uuid), $3, $4, $5,
+                $6, $7, $8,
+                $9, $10, $11,
+                $12, $13,
+                CASE WHEN $14 THEN NOW() END
+            )
+            RETURNING login_risk_event_id
+            "#,
+        )
+        .bind(ev.user_id)
+        .bind(ev.session_id)
+        .bind(ev.login_method)
+        .bind(ev.reasons)
+        .bind(ev.action)
+        .bind(ev.country)
+        .bind(ev.prev_country)
+        .bind(ev.city)
+        .bind(ev.device)
+        .bind(ev.browser)
+        .bind(ev.os)
+        .bind(ev.distance_km)
+        .bind(ev.speed_kmh)
+        .bind(ev.step_up_passed)
+        .fetch_one(pool)
+        .await?;
+        Ok(id)
+    }
+
+    /// step-up 인증 완료 — 발급된 세션 연결
+    pub async fn mark_login_risk_step_up_passed(
+        pool: &PgPool,
+        risk_event_id: i64,
+        session_id: &str,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE login_risk_event
+            SET risk_step_up_passed_at = NOW(),
+                login_session_id = CAST($2 AS uuid)
+            WHERE login_risk_event_id = $1
+            "#,
+        )
+        .bind(risk_event_id)
+        .bind(session_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// "본인이 아닙니다" — 사용자가 알림 링크로 전체 세션을 폐기함
+    pub async fn mark_login_risk_user_revoked(
+        pool: &PgPool,
+        risk_event_id: i64,
+        user_id: i64,
+    ) -> AppResult<bool> {
+        let res = sqlx::query(
+            r#"
+            UPDATE login_risk_event
+            SET risk_user_revoked_at = NOW()
+            WHERE login_risk_event_id = $1
+              AND user_id = $2
+              AND risk_user_revoked_at IS NULL
+            "#,
+        )
+        .bind(risk_event_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+}
+
+// =========================================================================
+// Login Risk Data Model
+// =========================================================================
+
+#[derive(Debug, sqlx::FromRow)]
+struct LoginFamilyRow {
+    country: Option<String>,
+    browser: String,
+    os: String,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct LastLoginRow {
+    country: Option<String>,
+    login_latitude: Option<f64>,
+    login_longitude: Option<f64>,
+    login_begin_at: DateTime<Utc>,
+}
+
+pub struct NewLoginRiskEvent<'a> {
+    pub user_id: i64,
+    pub session_id: Option<&'a str>,
+    pub login_method: &'a str,
+    pub reasons: &'a [String],
+    pub action: LoginRiskAction,
+    pub country: Option<&'a str>,
+    pub prev_country: Option<&'a str>,
+    pub city: Option<&'a str>,
+    pub device: Option<&'a str>,
+    pub browser: Option<&'a str>,
+    pub os: Option<&'a str>,
+    pub distance_km: Option<f64>,
+    pub speed_kmh: Option<f64>,
+    pub step_up_passed: bool,
+}
+
+// =========================================================================
+// Passkey Data Model
+// =========================================================================
+
+const PASSKEY_SELECT: &str = r#"
+    SELECT
+        passkey_id,
+        passkey_name AS name,
+        passkey_transports AS transports,
+        passkey_backup_eligible AS backup_eligible,
+        passkey_backed_up AS backed_up,
+        passkey_created_at AS created_at,
+        passkey_last_used_at AS last_used_at
+    FROM user_passkey
+"#;
+
+pub struct NewPasskey<'a> {
+    pub user_id: i64,
+    pub credential_id: &'a str,
+    pub public_key: &'a [u8],
+    pub alg: i32,
+    pub sign_count: i64,
+    pub name: &'a str,
+    pub transports: &'a [String],
+    pub aaguid: Option<uuid::Uuid>,
+    pub backup_eligible: bool,
+    pub backed_up: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PasskeyAuthRow {
+    pub passkey_id: i64,
+    pub user_id: i64,
+    pub passkey_public_key: Vec<u8>,
+    pub passkey_sign_count: i64,
 }
 
 // =========================================================================
@@ -0,0 +1,290 @@
+//! 로그인 위험 평가 (순수 로직).
+//!
+//! 로그인 성공 직전, 사용자의 최근 로그인 이력과 이번 로그인을 비교해 위험 신호를 뽑는다.
+//! 조치(기록/알림/step-up)와 저장은 `AuthService` 가 담당한다.
+//!
+//! - new_country: 이력에 없는 국가 (GeoIP 미조회 'LC' 는 비교 대상 아님)
+//! - new_device: 이력에 없는 브라우저+OS 조합 (버전 무시 — woothee family 이름 기준)
+//! - impossible_travel: 직전 로그인 위치에서 상한 속도를 넘는 이동.
+//!   City DB 위치는 수십 km 오차가 있으므로 `MIN_TRAVEL_KM` 미만 이동은 무시한다.
+//!
+//! 첫 로그인(이력 없음)은 비교 대상이 없으므로 신호를 내지 않는다.
+
+use chrono::{DateTime, Utc};
+
+/// 이 거리 미만의 이동은 GeoIP 위치 오차로 보고 속도를 따지지 않는다
+pub const MIN_TRAVEL_KM: f64 = 300.0;
+
+/// 경과 시간 하한 (시간) — 동시 로그인에서 0 으로 나누지 않도록 1분으로 본다
+const MIN_ELAPSED_HOURS: f64 = 1.0 / 60.0;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// 위험 신호 종류 (DB `risk_reasons` 에 문자열로 저장)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskReason {
+    NewCountry,
+    NewDevice,
+    ImpossibleTravel,
+}
+
+impl RiskReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RiskReason::NewCountry => "new_country",
+            RiskReason::NewDevice => "new_device",
+            RiskReason::ImpossibleTravel => "impossible_travel",
+        }
+    }
+
+    /// 알림 메일 표시용
+    pub fn label(self) -> &'static str {
+        match self {
+            RiskReason::NewCountry => "처음 로그인한 국가",
+            RiskReason::NewDevice => "처음 사용하는 기기/브라우저",
+            RiskReason::ImpossibleTravel => "직전 로그인 위치에서 비정상적으로 빠른 이동",
+        }
+    }
+}
+
+/// 직전 로그인 위치
+#[derive(Debug, Clone)]
+pub struct LastLogin {
+    pub country: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub at: DateTime<Utc>,
+}
+
+/// 비교 대상 이력 (최근 `LOGIN_RISK_HISTORY_DAYS` 일)
+#[derive(Debug, Clone, Default)]
+pub struct LoginHistory {
+    /// 이력 존재 여부 (false = 첫 로그인)
+    pub has_history: bool,
+    pub countries: Vec<String>,
+    /// (browser, os) 소문자
+    pub ua_families: Vec<(String, String)>,
+    pub last: Option<LastLogin>,
+}
+
+/// 이번 로그인
+#[derive(Debug, Clone)]
+pub struct LoginAttempt<'a> {
+    pub country: Option<&'a str>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub browser: Option<&'a str>,
+    pub os: Option<&'a str>,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RiskAssessment {
+    pub reasons: Vec<RiskReason>,
+    pub prev_country: Option<String>,
+    pub distance_km: Option<f64>,
+    pub speed_kmh: Option<f64>,
+}
+
+impl RiskAssessment {
+    pub fn is_risky(&self) -> bool {
+        !self.reasons.is_empty()
+    }
+
+    pub fn reason_codes(&self) -> Vec<String> {
+        self.reasons
+            .iter()
+            .map(|r| r.as_str().to_string())
+            .collect()
+    }
+}
+
+/// 'LC' = GeoIP 미조회 기본값 (insert_login_record_tx)
+fn known_country(code: Option<&str>) -> Option<&str> {
+    code.filter(|c| !c.is_empty() && *c != "LC")
+}
+
+/// UA family 키 — 둘 다 없으면 판정 불가(None)
+pub fn ua_family(browser: Option<&str>, os: Option<&str>) -> Option<(String, String)> {
+    if browser.is_none() && os.is_none() {
+        return None;
+    }
+    Some((
+        browser.unwrap_or("").to_lowercase(),
+        os.unwrap_or("").to_lowercase(),
+    ))
+}
+
+/// 두 좌표 사이 대권 거리 (km, haversine)
+pub fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// 이력 대비 위험 신호 판정
+pub fn assess(
+    history: &LoginHistory,
+    attempt: &LoginAttempt,
+    max_travel_kmh: f64,
+) -> RiskAssessment {
+    let mut out = RiskAssessment {
+        prev_country: history.last.as_ref().and_then(|l| l.country.clone()),
+        ..Default::default()
+    };
+    if !history.has_history {
+        return out;
+    }
+
+    if let Some(country) = known_country(attempt.country) {
+        if !history.countries.iter().any(|c| c == country) {
+            out.reasons.push(RiskReason::NewCountry);
+        }
+    }
+
+    if let Some(family) = ua_family(attempt.browser, attempt.os) {
+        if !history.ua_families.contains(&family) {
+            out.reasons.push(RiskReason::NewDevice);
+        }
+    }
+
+    if let (Some(last), Some(lat), Some(lon)) = (&history.last, attempt.latitude, attempt.longitude)
+    {
+        if let (Some(prev_lat), Some(prev_lon)) = (last.latitude, last.longitude) {
+            let distance = haversine_km(prev_lat, prev_lon, lat, lon);
+            if distance >= MIN_TRAVEL_KM {
+                let elapsed_hours =
+                    ((attempt.at - last.at).num_seconds() as f64 / 3600.0).max(MIN_ELAPSED_HOURS);
+                let speed = distance / elapsed_hours;
+                out.distance_km = Some(distance);
+                out.speed_kmh = Some(speed);
+                if speed > max_travel_kmh {
+                    out.reasons.push(RiskReason::ImpossibleTravel);
+                }
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const SEOUL: (f64, f64) = (37.5665, 126.978);
+    const BUSAN: (f64, f64) = (35.1796, 129.0756);
+    const LONDON: (f64, f64) = (51.5074, -0.1278);
+
+    fn history(at: DateTime<Utc>) -> LoginHistory {
+        LoginHistory {
+            has_history: true,
+            countries: vec!["KR".into()],
+            ua_families: vec![("chrome".into(), "mac osx".into())],
+            last: Some(LastLogin {
+                country: Some("KR".into()),
+                latitude: Some(SEOUL.0),
+                longitude: Some(SEOUL.1),
+                at,
+            }),
+        }
+    }
+
+    fn attempt<'a>(
+        country: &'a str,
+        (lat, lon): (f64, f64),
+        browser: &'a str,
+        at: DateTime<Utc>,
+    ) -> LoginAttempt<'a> {
+        LoginAttempt {
+            country: Some(country),
+            latitude: Some(lat),
+            longitude: Some(lon),
+            browser: Some(browser),
+            os: Some("Mac OSX"),
+            at,
+        }
+    }
+
+    #[test]
+    fn test_haversine_seoul_london() {
+        let d = haversine_km(SEOUL.0, SEOUL.1, LONDON.0, LONDON.1);
+        assert!((8800.0..8900.0).contains(&d), "seoul-london: {d}");
+    }
+
+    #[test]
+    fn test_familiar_login_has_no_reasons() {
+        let now = Utc::now();
+        let h = history(now - Duration::days(1));
+        let a = assess(&h, &attempt("KR", SEOUL, "Chrome", now), 900.0);
+        assert!(!a.is_risky(), "{:?}", a.reasons);
+    }
+
+    #[test]
+    fn test_first_login_is_never_risky() {
+        let now = Utc::now();
+        let a = assess(
+            &LoginHistory::default(),
+            &attempt("GB", LONDON, "Firefox", now),
+            900.0,
+        );
+        assert!(!a.is_risky());
+    }
+
+    #[test]
+    fn test_new_country_and_device() {
+        let now = Utc::now();
+        let h = history(now - Duration::days(30));
+        let a = assess(&h, &attempt("GB", LONDON, "Firefox", now), 900.0);
+        assert_eq!(
+            a.reasons,
+            vec![RiskReason::NewCountry, RiskReason::NewDevice]
+        );
+        assert_eq!(a.prev_country.as_deref(), Some("KR"));
+    }
+
+    #[test]
+    fn test_impossible_travel_flags_fast_hop() {
+        let now = Utc::now();
+        // 서울 → 런던 1시간 = ~8850 km/h
+        let h = history(now - Duration::hours(1));
+        let a = assess(&h, &attempt("GB", LONDON, "Chrome", now), 900.0);
+        assert!(a.reasons.contains(&RiskReason::ImpossibleTravel));
+        assert!(a.speed_kmh.unwrap() > 8000.0);
+    }
+
+    #[test]
+    fn test_short_hop_within_error_margin_is_ignored() {
+        let now = Utc::now();
+        // 서울 → 부산 (~325 km) 20시간 뒤 = 정상 속도
+        let h = history(now - Duration::hours(20));
+        let a = assess(&h, &attempt("KR", BUSAN, "Chrome", now), 900.0);
+        assert!(!a.reasons.contains(&RiskReason::ImpossibleTravel));
+        // 동시 로그인이라도 MIN_TRAVEL_KM 미만이면 속도 계산 안 함
+        let near = (SEOUL.0 + 0.5, SEOUL.1);
+        let a = assess(&history(now), &attempt("KR", near, "Chrome", now), 900.0);
+        assert!(a.speed_kmh.is_none() && !a.is_risky());
+    }
+
+    #[test]
+    fn test_unknown_geo_and_ua_are_not_flagged() {
+        let now = Utc::now();
+        let h = history(now - Duration::hours(1));
+        let a = assess(
+            &h,
+            &LoginAttempt {
+                country: Some("LC"),
+                latitude: None,
+                longitude: None,
+                browser: None,
+                os: None,
+                at: now,
+            },
+            900.0,
+        );
+        assert!(!a.is_risky());
+    }
+}
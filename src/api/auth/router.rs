@@ -1,7 +1,7 @@
 use super::handler;
 use crate::state::AppState;
 use axum::{
-    routing::{get, post},
+    routing::{get, patch, post},
     Router,
 };
 
@@ -9,14 +9,19 @@ pub fn auth_router() -> Router<AppState> {
     Router::new()
         // 세션/토큰 관련
         .route("/login", post(handler::login))
+        .route("/login/step-up", post(handler::login_step_up))
+        .route("/login/step-up-mobile", post(handler::login_step_up_mobile))
         .route("/logout", post(handler::logout))
         .route("/logout/all", post(handler::logout_all)) // 모든 기기 로그아웃
+        .route("/risk/revoke", post(handler::risk_revoke)) // 위험 로그인 알림 "본인이 아닙니다"
         .route("/refresh", post(handler::refresh))
         // 모바일 (쿠키 대신 JSON body로 refresh token 전달)
         .route("/login-mobile", post(handler::login_mobile))
         .route("/refresh-mobile", post(handler::refresh_mobile))
         .route("/google-mobile", post(handler::google_mobile_login))
         .route("/apple-mobile", post(handler::apple_mobile_login))
+        .route("/kakao-mobile", post(handler::kakao_mobile_login))
+        .route("/naver-mobile", post(handler::naver_mobile_login))
         // 계정 찾기/복구
         .route("/find-id", post(handler::find_id))
         .route("/find-password", post(handler::find_password))
@@ -24,16 +29,56 @@ pub fn auth_router() -> Router<AppState> {
         // 비밀번호 재설정 (이메일 인증 기반)
         .route("/request-reset", post(handler::request_reset))
         .route("/verify-reset", post(handler::verify_reset))
+        // 비밀번호 없는 로그인 (이메일 매직 링크 / 6자리 코드)
+        .route("/passwordless/request", post(handler::passwordless_request))
+        .route("/passwordless/verify", post(handler::passwordless_verify))
+        .route(
+            "/passwordless/verify-mobile",
+            post(handler::passwordless_verify_mobile),
+        )
         // 회원가입 이메일 인증
         .route("/verify-email", post(handler::verify_email))
         .route("/resend-verification", post(handler::resend_verification))
         // Google OAuth
         .route("/google", get(handler::google_auth_start))
         .route("/google/callback", get(handler::google_auth_callback))
+        // 카카오 (OIDC) / 네이버 (OAuth2)
+        .route("/kakao", get(handler::kakao_auth_start))
+        .route("/kakao/callback", get(handler::kakao_auth_callback))
+        .route("/naver", get(handler::naver_auth_start))
+        .route("/naver/callback", get(handler::naver_auth_callback))
+        // 기관 SSO (이메일 도메인 discovery → OIDC / SAML)
+        .route("/sso/discover", get(handler::sso_discover))
+        .route("/sso/oidc/callback", get(handler::sso_oidc_callback))
+        .route("/sso/saml/acs", post(handler::sso_saml_acs))
+        .route("/sso/saml/metadata", get(handler::sso_saml_metadata))
         // MFA (Multi-Factor Authentication)
         .route("/mfa/setup", post(handler::mfa_setup))
         .route("/mfa/verify-setup", post(handler::mfa_verify_setup))
         .route("/mfa/login", post(handler::mfa_login))
         .route("/mfa/login-mobile", post(handler::mfa_login_mobile))
         .route("/mfa/disable", post(handler::mfa_disable))
+        .route("/mfa/passkey/options", post(handler::passkey_mfa_options))
+        // 패스키 (WebAuthn) — 등록/관리 + 비밀번호 없는 로그인
+        .route("/reauth", post(handler::reauth))
+        .route("/reauth/email-code", post(handler::reauth_email_code))
+        .route("/passkeys", get(handler::list_passkeys))
+        .route(
+            "/passkeys/register/options",
+            post(handler::passkey_register_options),
+        )
+        .route("/passkeys/register", post(handler::passkey_register))
+        .route(
+            "/passkeys/{passkey_id}",
+            patch(handler::rename_passkey).delete(handler::delete_passkey),
+        )
+        .route(
+            "/passkeys/login/options",
+            post(handler::passkey_login_options),
+        )
+        .route("/passkeys/login", post(handler::passkey_login))
+        .route(
+            "/passkeys/login-mobile",
+            post(handler::passkey_login_mobile),
+        )
 }
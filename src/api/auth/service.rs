@@ -6,6 +6,7 @@ use axum_extra::extract::cookie::{Cookie, SameSite};
 use base64::engine::{general_purpose::URL_SAFE_NO_PAD, Engine};
 use rand::Rng;
 use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::sync::OnceLock;
 use time::OffsetDateTime;
@@ -19,15 +20,38 @@ use crate::crypto::CryptoService;
 use crate::external::email::EmailTemplate;
 
 use crate::{
-    api::auth::{dto::*, jwt, repo::AuthRepo},
+    api::auth::{
+        dto::*,
+        jwt,
+        repo::{AuthRepo, NewLoginRiskEvent, NewPasskey},
+        risk, sso, webauthn,
+    },
+    api::org::{repo as org_repo, repo::OrgSsoRow, service::sso_attribute_map},
     api::user::repo as user_repo,
     config::Config,
     error::{AppError, AppResult},
     external::google::{GoogleOAuthClient, GoogleUserInfo},
+    external::ipgeo::GeoLocation,
+    external::kakao::{KakaoOAuthClient, KAKAO_ISSUER},
+    external::naver::{NaverOAuthClient, NAVER_API_BASE_URL, NAVER_AUTH_BASE_URL},
+    external::oidc::{self, AuthFlowState, OidcClient, OidcClientConfig},
+    external::saml::{self, IdpMetadata, SamlServiceProvider},
     state::AppState,
-    types::UserAuth,
+    types::{LoginRiskAction, OrgSsoProtocol, UserAuth},
 };
 
+/// 사용자당 등록 가능한 패스키 수
+const MAX_PASSKEYS_PER_USER: usize = 10;
+
+/// 위험 로그인 알림 메일의 "본인이 아닙니다" 링크 유효 기간
+const RISK_REVOKE_TTL_DAYS: i64 = 7;
+
+/// 기관 SSO — users_oauth.oauth_provider / login_method 값
+pub const SSO_PROVIDER: &str = "sso";
+
+/// 기관 SSO 인가 요청 ~ 콜백(ACS) 사이 Redis 키 (state / RelayState 일회용)
+const SSO_FLOW_PREFIX: &str = "ak:sso_flow:";
+
 /// Config 의 token/jwks URL override 가 있으면 적용, 없으면 production URL.
 /// test 환경에서 wiremock 주입 path.
 fn build_google_client(
@@ -51,6 +75,103 @@ fn build_google_client(
     }
 }
 
+/// 웹 로그인용 Google 클라이언트 (client secret / redirect URI 필수)
+fn google_web_client(cfg: &Config) -> AppResult<GoogleOAuthClient> {
+    let client_id = cfg
+        .google_client_id
+        .as_ref()
+        .ok_or_else(|| AppError::Internal("GOOGLE_CLIENT_ID not configured".into()))?;
+    let client_secret = cfg
+        .google_client_secret
+        .as_ref()
+        .ok_or_else(|| AppError::Internal("GOOGLE_CLIENT_SECRET not configured".into()))?;
+    let redirect_uri = cfg
+        .google_redirect_uri
+        .as_ref()
+        .ok_or_else(|| AppError::Internal("GOOGLE_REDIRECT_URI not configured".into()))?;
+    build_google_client(
+        cfg,
+        client_id.clone(),
+        client_secret.clone(),
+        redirect_uri.clone(),
+    )
+}
+
+/// 카카오 클라이언트 (issuer discovery, test 시 issuer override 적용).
+/// `web` = Authorization Code 흐름 (redirect URI 필수), 아니면 모바일 SDK ID token 검증 전용
+async fn build_kakao_client(cfg: &Config, web: bool) -> AppResult<KakaoOAuthClient> {
+    let client_id = cfg
+        .kakao_client_id
+        .clone()
+        .ok_or_else(|| AppError::Internal("KAKAO_CLIENT_ID not configured".into()))?;
+    let redirect_uri = if web {
+        Some(
+            cfg.kakao_redirect_uri
+                .clone()
+                .ok_or_else(|| AppError::Internal("KAKAO_REDIRECT_URI not configured".into()))?,
+        )
+    } else {
+        None
+    };
+    // SDK 로그인 토큰의 aud = 네이티브 앱 키 (웹 흐름은 REST API 키)
+    let audiences = if web {
+        Vec::new()
+    } else {
+        cfg.kakao_native_app_key
+            .iter()
+            .cloned()
+            .chain(std::iter::once(client_id.clone()))
+            .collect()
+    };
+    let issuer = cfg.kakao_issuer_override.as_deref().unwrap_or(KAKAO_ISSUER);
+    KakaoOAuthClient::discover(
+        issuer,
+        client_id,
+        cfg.kakao_client_secret.clone(),
+        redirect_uri,
+        audiences,
+    )
+    .await
+}
+
+/// 네이버 클라이언트 (test 시 URL override 적용).
+/// 웹 / 모바일 모두 Authorization Code 흐름 — client secret / redirect URI 필수
+fn build_naver_client(cfg: &Config) -> AppResult<NaverOAuthClient> {
+    let client_id = cfg
+        .naver_client_id
+        .clone()
+        .ok_or_else(|| AppError::Internal("NAVER_CLIENT_ID not configured".into()))?;
+    let client_secret = cfg
+        .naver_client_secret
+        .clone()
+        .ok_or_else(|| AppError::Internal("NAVER_CLIENT_SECRET not configured".into()))?;
+    let redirect_uri = cfg
+        .naver_redirect_uri
+        .clone()
+        .ok_or_else(|| AppError::Internal("NAVER_REDIRECT_URI not configured".into()))?;
+    NaverOAuthClient::with_urls(
+        client_id,
+        Some(client_secret),
+        Some(redirect_uri),
+        cfg.naver_auth_url_override
+            .as_deref()
+            .unwrap_or(NAVER_AUTH_BASE_URL),
+        cfg.naver_api_url_override
+            .as_deref()
+            .unwrap_or(NAVER_API_BASE_URL),
+    )
+}
+
+/// 소셜 계정에서 이메일을 받지 못해 새 계정을 만들 수 없을 때의 안내
+fn missing_oauth_email_message(provider: &str) -> &'static str {
+    match provider {
+        "apple" => "Apple 계정에서 이메일을 가져올 수 없습니다. Apple ID 설정 > 로그인 및 보안 > Apple로 로그인에서 Amazing Korean을 제거한 후 다시 시도해주세요.",
+        "kakao" => "카카오 계정 이메일 제공에 동의해야 가입할 수 있습니다. 카카오 로그인 동의 화면에서 이메일을 선택해주세요.",
+        "naver" => "네이버 계정 이메일 제공에 동의해야 가입할 수 있습니다. 네이버 로그인 동의 화면에서 이메일을 선택해주세요.",
+        _ => "소셜 계정에서 이메일을 가져올 수 없습니다.",
+    }
+}
+
 /// 로그인 결과 (일반 성공 vs MFA 챌린지)
 pub struct LoginSuccess {
     pub login_res: LoginRes,
@@ -64,7 +185,7 @@ pub enum LoginOutcome {
     MfaChallenge { mfa_token: String, user_id: i64 },
 }
 
-/// Provider 무관 OAuth 사용자 정보 (Google/Apple 공통)
+/// Provider 무관 OAuth 사용자 정보 (Google/Apple/카카오/네이버 공통)
 #[derive(Debug, Clone)]
 pub struct OAuthUserInfo {
     pub sub: String,
@@ -86,6 +207,39 @@ impl From<GoogleUserInfo> for OAuthUserInfo {
     }
 }
 
+/// 웹 리다이렉트(Authorization Code) 로그인 공급자. Apple 은 모바일 ID token 전용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OAuthProvider {
+    Google,
+    Kakao,
+    Naver,
+}
+
+impl OAuthProvider {
+    /// users_oauth.oauth_provider / login_method 값
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthProvider::Google => "google",
+            OAuthProvider::Kakao => "kakao",
+            OAuthProvider::Naver => "naver",
+        }
+    }
+
+    pub fn parse(provider: &str) -> Option<Self> {
+        match provider {
+            "google" => Some(OAuthProvider::Google),
+            "kakao" => Some(OAuthProvider::Kakao),
+            "naver" => Some(OAuthProvider::Naver),
+            _ => None,
+        }
+    }
+
+    /// 네이버는 PKCE 미지원
+    fn supports_pkce(self) -> bool {
+        !matches!(self, OAuthProvider::Naver)
+    }
+}
+
 /// OAuth 로그인 결과 (일반 성공 vs MFA 챌린지)
 pub struct OAuthLoginSuccess {
     pub login_res: LoginRes,
@@ -100,6 +254,39 @@ pub enum OAuthLoginOutcome {
     MfaChallenge { mfa_token: String, user_id: i64 },
 }
 
+/// 세션 발급 경로의 인증 강도 — 위험 로그인 step-up 필요 여부 판단
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginAssurance {
+    /// 1차 인증만 (비밀번호 / 소셜)
+    Single,
+    /// MFA 통과 또는 사용자 검증(UV) 패스키 로그인 — step-up 이미 충족
+    SecondFactor,
+    /// 비밀번호 없는 이메일 로그인 (매직 링크 / 코드) — step-up 메일 코드와 같은 요소라 재확인 안 함
+    EmailPossession,
+    /// 위험 로그인 step-up 코드 확인 완료 (평가 생략, 챌린지 때 만든 이벤트에 세션 연결)
+    StepUp { risk_event_id: i64 },
+}
+
+/// 기관 SSO 요청 ~ 응답 사이에 Redis 에 보관하는 값
+#[derive(Debug, Serialize, Deserialize)]
+struct SsoFlowState {
+    org_id: i64,
+    protocol: OrgSsoProtocol,
+    /// OIDC nonce / PKCE verifier
+    #[serde(default)]
+    oidc: Option<AuthFlowState>,
+    /// SAML AuthnRequest ID (응답의 InResponseTo 와 비교)
+    #[serde(default)]
+    saml_request_id: Option<String>,
+}
+
+/// 세션 발급 후 기록할 위험 평가 결과
+struct PendingLoginRisk {
+    assessment: risk::RiskAssessment,
+    action: LoginRiskAction,
+    step_up_passed: bool,
+}
+
 pub struct AuthService;
 
 impl AuthService {
@@ -651,7 +838,42 @@ impl AuthService {
             )));
         }
 
-        // [Step 3-C] MFA 체크 (Admin/HYMN MFA 활성화 시 챌린지 반환)
+        // [Step 3-C] 기관 SSO 전용 도메인 — 비밀번호 로그인 차단 (기관 IdP 로 로그인해야 함)
+        if Self::is_sso_required(st, &email, user_info.user_auth).await? {
+            let login_ip_log_enc_fail = crypto.encrypt(&login_ip, "login_log.login_ip_log")?;
+            let fail_session = Uuid::new_v4().to_string();
+            let mut tx = st.db.begin().await?;
+            if let Err(e) = AuthRepo::insert_login_log_tx(
+                &mut tx,
+                user_info.user_id,
+                "login",
+                false,
+                &fail_session,
+                "",
+                &login_ip_log_enc_fail,
+                Some(parsed_ua.device.as_str()),
+                parsed_ua.browser.as_deref(),
+                parsed_ua.os.as_deref(),
+                user_agent.as_deref(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("sso_required"),
+                None,
+            )
+            .await
+            {
+                warn!(error = %e, "Failed to insert login failure log");
+            }
+            if let Err(e) = tx.commit().await {
+                warn!(error = %e, "Failed to commit login failure log transaction");
+            }
+            return Err(AppError::Forbidden("AUTH_403_SSO_REQUIRED".into()));
+        }
+
+        // [Step 3-D] MFA 체크 (Admin/HYMN MFA 활성화 시 챌린지 반환)
         if user_info.user_mfa_enabled {
             let mfa_token = Uuid::new_v4().to_string();
             let pending_data = serde_json::json!({
@@ -700,7 +922,7 @@ impl AuthService {
             &session_id,
             user_info.user_auth,
             st.cfg.jwt_access_ttl_min,
-            &st.cfg.jwt_secret,
+            &st.cfg.jwt_keyring,
         )?;
 
         // Access token SHA-256 hash (audit log용)
@@ -712,6 +934,20 @@ impl AuthService {
         // [Step 5] IP Geolocation (best-effort, non-blocking)
         let geo = st.ipgeo.lookup(&login_ip).await;
 
+        // [Step 5-B] 로그인 위험 평가 (step_up 조치면 여기서 코드 챌린지 반환)
+        let login_risk = Self::evaluate_login_risk(
+            st,
+            user_info.user_id,
+            user_info.user_auth,
+            "email",
+            &geo,
+            &parsed_ua,
+            &login_ip,
+            user_agent.as_deref(),
+            LoginAssurance::Single,
+        )
+        .await?;
+
         let login_ip_enc = crypto.encrypt(&login_ip, "login.login_ip")?;
         let login_ip_log_enc = crypto.encrypt(&login_ip, "login_log.login_ip_log")?;
 
@@ -737,6 +973,8 @@ impl AuthService {
             geo.country_code.as_deref(),
             geo.asn,
             geo.org.as_deref(),
+            geo.latitude,
+            geo.longitude,
         )
         .await?;
 
@@ -798,6 +1036,20 @@ impl AuthService {
         Self::cleanup_evicted_sessions_redis(&mut redis_conn, user_info.user_id, &evicted_sessions)
             .await;
 
+        // 5. 위험 이벤트 기록 / 알림 (best-effort)
+        if let Some(pending) = login_risk {
+            Self::record_login_risk(
+                st,
+                user_info.user_id,
+                &session_id,
+                "email",
+                &geo,
+                &parsed_ua,
+                pending,
+            )
+            .await;
+        }
+
         let mut refresh_cookie = Cookie::new(
             st.cfg.refresh_cookie_name.clone(),
             refresh_token_value.clone(),
@@ -946,7 +1198,7 @@ impl AuthService {
             &session_id,
             user.user_auth,
             st.cfg.jwt_access_ttl_min,
-            &st.cfg.jwt_secret,
+            &st.cfg.jwt_keyring,
         )?;
         let access_hash: String = Sha256::digest(access_token_res.access_token.as_bytes())
             .iter()
@@ -1070,7 +1322,7 @@ impl AuthService {
     }
 
     /// 이메일 마스킹 (test@example.com → te***@example.com)
-    fn mask_email(email: &str) -> String {
+    pub(crate) fn mask_email(email: &str) -> String {
         let parts: Vec<&str> = email.splitn(2, '@').collect();
         if parts.len() != 2 {
             return "***".to_string();
@@ -1392,6 +1644,72 @@ impl AuthService {
         Ok(LogoutRes { ok: true })
     }
 
+    /// 단일 세션 폐기 (사용자 self-service — `/users/me/sessions/{session_id}`)
+    ///
+    /// 본인 소유의 활성 세션만 대상 (타인/종료된 세션 = 404). DB 를 'revoked' 로 바꾸고
+    /// 로그아웃 로그를 남긴 뒤 Redis `ak:session`/`ak:refresh`/`ak:user_sessions` 를 정리한다
+    /// (fail-closed — 폐기된 기기의 다음 요청이 즉시 401).
+    pub async fn revoke_session(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        if Uuid::parse_str(session_id).is_err() {
+            return Err(AppError::NotFound);
+        }
+
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+
+        let mut tx = st.db.begin().await?;
+        let record = AuthRepo::find_login_by_session_id_tx(&mut tx, session_id)
+            .await?
+            .filter(|r| r.user_id == user_id && r.state == "active")
+            .ok_or(AppError::NotFound)?;
+
+        let ip_plain =
+            crypto.decrypt(record.login_ip.as_deref().unwrap_or(""), "login.login_ip")?;
+        let login_ip_log_enc = crypto.encrypt(&ip_plain, "login_log.login_ip_log")?;
+
+        AuthRepo::update_login_state_by_session_tx(
+            &mut tx,
+            session_id,
+            "revoked",
+            Some("user_revoked"),
+        )
+        .await?;
+        AuthRepo::insert_logout_log_tx(
+            &mut tx,
+            user_id,
+            session_id,
+            &record.refresh_hash,
+            &login_ip_log_enc,
+            user_agent.as_deref(),
+        )
+        .await?;
+        tx.commit().await?;
+
+        let _: () = redis_conn
+            .del(&[
+                format!("ak:refresh:{}", record.refresh_hash),
+                format!("ak:session:{}", session_id),
+            ])
+            .await
+            .map_err(|e| AppError::Internal(format!("redis del(batch) failed: {e}")))?;
+        let _: () = redis_conn
+            .srem(format!("ak:user_sessions:{}", user_id), session_id)
+            .await
+            .map_err(|e| AppError::Internal(format!("redis srem failed: {e}")))?;
+
+        info!(user_id, session_id = %session_id, "Session revoked by user");
+        Ok(())
+    }
+
     // =========================================================================
     // Password Reset (이메일 인증 기반)
     // =========================================================================
@@ -1898,55 +2216,105 @@ impl AuthService {
     }
 
     // =========================================================================
-    // Google OAuth
+    // 소셜 로그인 (Google / 카카오 / 네이버 웹 + 모바일, Apple 모바일)
     // =========================================================================
 
     /// Google OAuth 인증 URL 생성
     pub async fn google_auth_start(st: &AppState) -> AppResult<String> {
-        // Google OAuth 설정 확인
-        let client_id = st
-            .cfg
-            .google_client_id
-            .as_ref()
-            .ok_or_else(|| AppError::Internal("GOOGLE_CLIENT_ID not configured".into()))?;
-        let client_secret = st
-            .cfg
-            .google_client_secret
-            .as_ref()
-            .ok_or_else(|| AppError::Internal("GOOGLE_CLIENT_SECRET not configured".into()))?;
-        let redirect_uri = st
-            .cfg
-            .google_redirect_uri
-            .as_ref()
-            .ok_or_else(|| AppError::Internal("GOOGLE_REDIRECT_URI not configured".into()))?;
+        Self::oauth_auth_start(st, OAuthProvider::Google).await
+    }
+
+    /// 웹 소셜 로그인 인증 URL 생성
+    pub async fn oauth_auth_start(st: &AppState, provider: OAuthProvider) -> AppResult<String> {
+        Self::oauth_auth_url(st, provider, None).await
+    }
 
-        // State와 Nonce 생성 (CSRF/Replay 방지)
+    /// 인증 URL 생성 — state / nonce / PKCE verifier 를 Redis 에 일회용으로 저장.
+    /// `link_intent` 가 있으면 콜백을 로그인 대신 계정 연결(link intent)로 처리
+    async fn oauth_auth_url(
+        st: &AppState,
+        provider: OAuthProvider,
+        link_intent: Option<String>,
+    ) -> AppResult<String> {
+        // 설정 확인이 먼저 (미설정이면 state 를 남기지 않음)
         let state = Uuid::new_v4().to_string();
-        let nonce = Uuid::new_v4().to_string();
+        let flow = AuthFlowState::new(provider.as_str(), provider.supports_pkce());
+        let auth_url = match provider {
+            OAuthProvider::Google => google_web_client(&st.cfg)?.build_auth_url(&state, &flow),
+            OAuthProvider::Kakao => build_kakao_client(&st.cfg, true)
+                .await?
+                .build_auth_url(&state, &flow),
+            OAuthProvider::Naver => build_naver_client(&st.cfg)?.build_auth_url(&state, &flow),
+        };
 
-        // Redis에 state -> nonce 저장
-        let mut redis_conn = st
-            .redis
-            .get()
-            .await
-            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let ttl = st.cfg.oauth_state_ttl_sec as u64;
+        oidc::save_flow_state(&st.redis, &state, &flow, ttl).await?;
 
-        let state_key = format!("ak:oauth_state:{}", state);
-        let _: () = redis_conn
-            .set_ex(&state_key, &nonce, st.cfg.oauth_state_ttl_sec as u64)
-            .await
-            .map_err(|e| AppError::Internal(e.to_string()))?;
+        if let Some(intent) = link_intent {
+            Self::save_link_intent(st, &state, intent).await?;
+        }
 
-        // Auth URL 생성
-        let client = GoogleOAuthClient::new(
-            client_id.clone(),
-            client_secret.clone(),
-            redirect_uri.clone(),
-        )?;
+        Ok(auth_url)
+    }
+
+    /// 콜백 공통 — state 검증(일회용) → code 교환 → ID token / 프로필 검증 → 사용자 정보
+    async fn oauth_callback_user_info(
+        st: &AppState,
+        provider: OAuthProvider,
+        code: &str,
+        state: &str,
+    ) -> AppResult<OAuthUserInfo> {
+        match provider {
+            OAuthProvider::Google => {
+                let client = google_web_client(&st.cfg)?;
+                let flow = oidc::take_flow_state(&st.redis, state, provider.as_str()).await?;
+
+                // Authorization Code → Token 교환 (test 시 URL override 적용)
+                let token = client
+                    .exchange_code(code, flow.pkce_verifier.as_deref())
+                    .await?;
+                let id_token = token.id_token.ok_or_else(|| {
+                    AppError::External("Google token response missing id_token".into())
+                })?;
+
+                // ID Token 검증 + Nonce / Audience 확인
+                let claims = client.decode_id_token(&id_token).await?;
+                if claims.nonce.as_deref() != Some(flow.nonce.as_str()) {
+                    return Err(AppError::Unauthorized("AUTH_401_INVALID_NONCE".into()));
+                }
+                if claims.aud != client.client_id() {
+                    return Err(AppError::Unauthorized("AUTH_401_INVALID_AUDIENCE".into()));
+                }
+                Ok(client.extract_user_info(&claims).into())
+            }
+            OAuthProvider::Kakao => {
+                let client = build_kakao_client(&st.cfg, true).await?;
+                let flow = oidc::take_flow_state(&st.redis, state, provider.as_str()).await?;
 
-        let auth_url = client.build_auth_url(&state, &nonce);
+                let token = client
+                    .exchange_code(code, flow.pkce_verifier.as_deref())
+                    .await?;
+                let id_token = token.id_token.ok_or_else(|| {
+                    AppError::External("Kakao token response missing id_token".into())
+                })?;
 
-        Ok(auth_url)
+                // aud 는 verify 단계에서 REST API 키로 검증
+                let claims = client.decode_id_token(&id_token).await?;
+                if claims.nonce.as_deref() != Some(flow.nonce.as_str()) {
+                    return Err(AppError::Unauthorized("AUTH_401_INVALID_NONCE".into()));
+                }
+                Ok(client.extract_user_info(&claims))
+            }
+            OAuthProvider::Naver => {
+                let client = build_naver_client(&st.cfg)?;
+                oidc::take_flow_state(&st.redis, state, provider.as_str()).await?;
+
+                // 네이버는 ID token 이 없어 access token 으로 프로필 API 를 조회한다
+                let token = client.exchange_code(code, state).await?;
+                let profile = client.fetch_profile(&token.access_token).await?;
+                Ok(client.extract_user_info(&profile))
+            }
+        }
     }
 
     /// Google OAuth 콜백 처리
@@ -1958,121 +2326,38 @@ impl AuthService {
         user_agent: Option<String>,
         parsed_ua: crate::api::auth::handler::ParsedUa,
     ) -> AppResult<OAuthLoginOutcome> {
-        // Google OAuth 설정 확인
-        let client_id = st
-            .cfg
-            .google_client_id
-            .as_ref()
-            .ok_or_else(|| AppError::Internal("GOOGLE_CLIENT_ID not configured".into()))?;
-        let client_secret = st
-            .cfg
-            .google_client_secret
-            .as_ref()
-            .ok_or_else(|| AppError::Internal("GOOGLE_CLIENT_SECRET not configured".into()))?;
-        let redirect_uri = st
-            .cfg
-            .google_redirect_uri
-            .as_ref()
-            .ok_or_else(|| AppError::Internal("GOOGLE_REDIRECT_URI not configured".into()))?;
-
-        // [Step 1] State 검증 (CSRF 방지)
-        let mut redis_conn = st
-            .redis
-            .get()
-            .await
-            .map_err(|e| AppError::Internal(e.to_string()))?;
-
-        let state_key = format!("ak:oauth_state:{}", state);
-        let stored_nonce: Option<String> = redis_conn
-            .get(&state_key)
-            .await
-            .map_err(|e| AppError::Internal(e.to_string()))?;
-
-        let Some(nonce) = stored_nonce else {
-            return Err(AppError::Unauthorized(
-                "AUTH_401_INVALID_OAUTH_STATE".into(),
-            ));
-        };
-
-        // State 사용 후 즉시 삭제 (일회용)
-        let _: () = redis_conn.del(&state_key).await.unwrap_or(());
-
-        // [Step 2] Authorization Code → Token 교환 (test 시 URL override 적용)
-        let client = build_google_client(
-            &st.cfg,
-            client_id.clone(),
-            client_secret.clone(),
-            redirect_uri.clone(),
-        )?;
-
-        let token_response = client.exchange_code(code).await?;
-
-        // [Step 3] ID Token 검증 및 사용자 정보 추출
-        let claims = client.decode_id_token(&token_response.id_token).await?;
-
-        // Nonce 검증
-        if claims.nonce.as_deref() != Some(&nonce) {
-            return Err(AppError::Unauthorized("AUTH_401_INVALID_NONCE".into()));
-        }
-
-        // Audience 검증
-        if claims.aud != *client_id {
-            return Err(AppError::Unauthorized("AUTH_401_INVALID_AUDIENCE".into()));
-        }
-
-        let user_info: OAuthUserInfo = client.extract_user_info(&claims).into();
-
-        // [Step 4] 사용자 조회 또는 생성
-        let (user_id, user_auth, is_new_user) =
-            Self::find_or_create_oauth_user(st, &user_info, "google").await?;
-
-        // [Step 5] MFA 체크 (기존 사용자 + MFA 활성화 시 챌린지 반환)
-        if !is_new_user {
-            let mfa_enabled = AuthRepo::find_user_mfa_enabled(&st.db, user_id).await?;
-            if mfa_enabled {
-                let mfa_token = Uuid::new_v4().to_string();
-                let pending_data = serde_json::json!({
-                    "user_id": user_id,
-                    "user_auth": format!("{:?}", user_auth),
-                    "login_ip": login_ip,
-                    "user_agent": user_agent,
-                    "device": parsed_ua.device,
-                    "browser": parsed_ua.browser,
-                    "os": parsed_ua.os,
-                    "login_method": "google"
-                });
-                let mut redis_conn = st
-                    .redis
-                    .get()
-                    .await
-                    .map_err(|e| AppError::Internal(e.to_string()))?;
-                let mfa_key = format!("ak:mfa_pending:{}", mfa_token);
-                let _: () = redis_conn
-                    .set_ex(
-                        &mfa_key,
-                        pending_data.to_string(),
-                        st.cfg.mfa_token_ttl_sec as u64,
-                    )
-                    .await
-                    .map_err(|e| AppError::Internal(e.to_string()))?;
-
-                return Ok(OAuthLoginOutcome::MfaChallenge { mfa_token, user_id });
-            }
-        }
-
-        // [Step 6] 세션 생성 (MFA 미활성화 시)
-        let (login_res, cookie, refresh_ttl, refresh_token) = Self::create_oauth_session(
-            st, user_id, user_auth, "google", login_ip, user_agent, parsed_ua,
+        Self::oauth_auth_callback(
+            st,
+            OAuthProvider::Google,
+            code,
+            state,
+            login_ip,
+            user_agent,
+            parsed_ua,
         )
-        .await?;
+        .await
+    }
 
-        Ok(OAuthLoginOutcome::Success(Box::new(OAuthLoginSuccess {
-            login_res,
-            cookie,
-            refresh_token,
-            ttl: refresh_ttl,
-            is_new_user,
-        })))
+    /// 웹 소셜 로그인 콜백 처리
+    pub async fn oauth_auth_callback(
+        st: &AppState,
+        provider: OAuthProvider,
+        code: &str,
+        state: &str,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        let user_info = Self::oauth_callback_user_info(st, provider, code, state).await?;
+        Self::oauth_login_flow(
+            st,
+            &user_info,
+            provider.as_str(),
+            login_ip,
+            user_agent,
+            parsed_ua,
+        )
+        .await
     }
 
     /// 모바일 Google OAuth 로그인 (ID token 직접 검증)
@@ -2094,8 +2379,7 @@ impl AuthService {
         let user_info: OAuthUserInfo = client.extract_user_info(&claims).into();
 
         // 사용자 조회/생성 + MFA 체크 + 세션 생성
-        Self::oauth_mobile_login_flow(st, &user_info, "google", login_ip, user_agent, parsed_ua)
-            .await
+        Self::oauth_login_flow(st, &user_info, "google", login_ip, user_agent, parsed_ua).await
     }
 
     /// 모바일 Apple OAuth 로그인 (ID token 직접 검증)
@@ -2106,7 +2390,7 @@ impl AuthService {
         user_agent: Option<String>,
         parsed_ua: crate::api::auth::handler::ParsedUa,
     ) -> AppResult<OAuthLoginOutcome> {
-        // 싱글톤 클라이언트 재사용 — 매 요청마다 reqwest::Client 재생성 + JWKS 재-fetch 방지
+        // 싱글톤 클라이언트 재사용 — 매 요청마다 reqwest::Client 재생성 방지
         let client = st
             .apple_oauth
             .as_ref()
@@ -2115,34 +2399,79 @@ impl AuthService {
         let claims = client.decode_id_token(&req.id_token).await?;
         let user_info = client.extract_user_info(&claims, req.user_name);
 
-        // Apple 특이: 최초 인증 시에만 email 제공. email 없고 기존 유저도 없으면 생성 불가
-        if user_info.email.is_empty() {
-            let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
-            let sub_idx = crypto.blind_index_preserve_case(&user_info.sub)?;
-            let existing =
-                AuthRepo::find_oauth_by_provider_subject_idx(&st.db, "apple", &sub_idx).await?;
-            if existing.is_none() {
-                return Err(AppError::BadRequest(
-                    "Apple 계정에서 이메일을 가져올 수 없습니다. Apple ID 설정 > 로그인 및 보안 > Apple로 로그인에서 Amazing Korean을 제거한 후 다시 시도해주세요.".into()
-                ));
-            }
-        }
-
-        Self::oauth_mobile_login_flow(st, &user_info, "apple", login_ip, user_agent, parsed_ua)
-            .await
+        // Apple 특이: 최초 인증 시에만 email 제공 (없으면 기존 연결 계정만 로그인 가능)
+        Self::oauth_login_flow(st, &user_info, "apple", login_ip, user_agent, parsed_ua).await
     }
 
-    /// 모바일 OAuth 공통 로그인 흐름 (사용자 조회/생성 → MFA → 세션)
-    async fn oauth_mobile_login_flow(
+    /// 모바일 카카오 로그인 (카카오 SDK ID token 직접 검증)
+    pub async fn kakao_mobile_login(
         st: &AppState,
-        user_info: &OAuthUserInfo,
-        provider: &str,
+        req: KakaoMobileLoginReq,
         login_ip: String,
         user_agent: Option<String>,
         parsed_ua: crate::api::auth::handler::ParsedUa,
     ) -> AppResult<OAuthLoginOutcome> {
-        let (user_id, user_auth, is_new_user) =
+        let client = build_kakao_client(&st.cfg, false).await?;
+        let claims = client.decode_id_token(&req.id_token).await?;
+        let user_info = client.extract_user_info(&claims);
+
+        Self::oauth_login_flow(st, &user_info, "kakao", login_ip, user_agent, parsed_ua).await
+    }
+
+    /// 모바일 네이버 로그인 (Authorization Code → 토큰 교환 → 프로필 API)
+    ///
+    /// 네이버는 access token 의 발급 앱(client_id)을 확인할 수단이 없어 SDK 토큰을 받지 않는다.
+    /// 서버가 발급한 state 와 우리 client_secret 으로 code 를 교환해야 토큰이 이 앱 것임이 보장된다.
+    pub async fn naver_mobile_login(
+        st: &AppState,
+        req: NaverMobileLoginReq,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        let user_info =
+            Self::oauth_callback_user_info(st, OAuthProvider::Naver, &req.code, &req.state).await?;
+        Self::oauth_login_flow(st, &user_info, "naver", login_ip, user_agent, parsed_ua).await
+    }
+
+    /// OAuth 공통 로그인 흐름 (사용자 조회/생성 → MFA → 세션)
+    async fn oauth_login_flow(
+        st: &AppState,
+        user_info: &OAuthUserInfo,
+        provider: &str,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        let (user_id, user_auth, is_new_user) =
             Self::find_or_create_oauth_user(st, user_info, provider).await?;
+        Self::oauth_session_or_mfa(
+            st,
+            user_id,
+            user_auth,
+            is_new_user,
+            provider,
+            login_ip,
+            user_agent,
+            parsed_ua,
+        )
+        .await
+    }
+
+    /// 사용자 확정 이후 공통 — MFA 사용자면 챌린지, 아니면 세션 발급 (소셜 / 기관 SSO)
+    #[allow(clippy::too_many_arguments)]
+    async fn oauth_session_or_mfa(
+        st: &AppState,
+        user_id: i64,
+        user_auth: UserAuth,
+        is_new_user: bool,
+        login_method: &str,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        // 완료할 수 없는 MFA 챌린지를 내주지 않도록 세션 발급 전에 한 번 더 확인
+        Self::ensure_login_method_allowed(st, user_id, user_auth, login_method).await?;
 
         // MFA 체크 (기존 사용자 + MFA 활성화 시 챌린지 반환)
         if !is_new_user {
@@ -2157,7 +2486,7 @@ impl AuthService {
                     "device": parsed_ua.device,
                     "browser": parsed_ua.browser,
                     "os": parsed_ua.os,
-                    "login_method": provider
+                    "login_method": login_method
                 });
                 let mut redis_conn = st
                     .redis
@@ -2179,7 +2508,14 @@ impl AuthService {
         }
 
         let (login_res, cookie, refresh_ttl, refresh_token) = Self::create_oauth_session(
-            st, user_id, user_auth, provider, login_ip, user_agent, parsed_ua,
+            st,
+            user_id,
+            user_auth,
+            login_method,
+            login_ip,
+            user_agent,
+            parsed_ua,
+            LoginAssurance::Single,
         )
         .await?;
 
@@ -2221,11 +2557,23 @@ impl AuthService {
             return Ok((user.id, user.user_auth, false));
         }
 
-        // 2. 이메일로 기존 계정 조회 (자동 연결)
+        // 이메일이 없으면 새 계정을 만들 수 없다 (Apple 재인증 / 이메일 미동의)
+        if user_info.email.is_empty() {
+            return Err(AppError::BadRequest(
+                missing_oauth_email_message(provider).into(),
+            ));
+        }
+
+        // 2. 이메일로 기존 계정 조회 (자동 연결) — 공급자가 인증한 이메일만
         let email_idx = crypto.blind_index(&user_info.email)?;
         let existing_user = AuthRepo::find_user_by_email_idx(&st.db, &email_idx).await?;
 
         if let Some(existing_user) = existing_user {
+            // 미인증 이메일로 연결하면 남의 계정을 가로챌 수 있음 → 기존 수단으로 로그인 후 계정 설정에서 연결
+            if !user_info.email_verified {
+                return Err(AppError::Conflict("OAUTH_EMAIL_NOT_VERIFIED".into()));
+            }
+
             // 기존 계정에 OAuth 연결
             let oauth_email_enc = crypto.encrypt(&user_info.email, "user_oauth.oauth_email")?;
             let oauth_subject_enc = crypto.encrypt(&user_info.sub, "user_oauth.oauth_subject")?;
@@ -2296,7 +2644,7 @@ impl AuthService {
             "users.user_birthday",
         )?;
 
-        // 사용자 생성 (비밀번호 없이, user_check_email = true)
+        // 사용자 생성 (비밀번호 없이, user_check_email = 공급자 이메일 인증 여부)
         let user_id = sqlx::query_scalar::<_, i64>(
             r#"
             INSERT INTO users (
@@ -2312,7 +2660,7 @@ impl AuthService {
                 $3, 'ko'::user_language_enum, 'Unknown',
                 $6, 'none'::user_gender_enum,
                 true, true,
-                true,
+                $7,
                 $4, $5
             )
             RETURNING user_id
@@ -2324,6 +2672,7 @@ impl AuthService {
         .bind(&email_idx)
         .bind(&name_idx)
         .bind(&default_birthday)
+        .bind(user_info.email_verified)
         .fetch_one(&mut *tx)
         .await?;
 
@@ -2358,6 +2707,7 @@ impl AuthService {
     }
 
     /// OAuth 세션 생성
+    #[allow(clippy::too_many_arguments)]
     async fn create_oauth_session(
         st: &AppState,
         user_id: i64,
@@ -2366,7 +2716,11 @@ impl AuthService {
         login_ip: String,
         user_agent: Option<String>,
         parsed_ua: crate::api::auth::handler::ParsedUa,
+        assurance: LoginAssurance,
     ) -> AppResult<(LoginRes, Cookie<'static>, i64, String)> {
+        // 소셜 / 패스키 / 이메일 로그인 / MFA 완료 공통 — 기관 SSO 전용 계정 차단
+        Self::ensure_login_method_allowed(st, user_id, user_auth, login_method).await?;
+
         let session_id = Uuid::new_v4().to_string();
         let (refresh_token_value, refresh_hash) =
             Self::generate_refresh_token_and_hash(&session_id);
@@ -2378,7 +2732,7 @@ impl AuthService {
             &session_id,
             user_auth,
             st.cfg.jwt_access_ttl_min,
-            &st.cfg.jwt_secret,
+            &st.cfg.jwt_keyring,
         )?;
         let access_hash: String = Sha256::digest(access_token_res.access_token.as_bytes())
             .iter()
@@ -2398,6 +2752,20 @@ impl AuthService {
         // IP Geolocation (best-effort, non-blocking)
         let geo = st.ipgeo.lookup(&login_ip).await;
 
+        // 로그인 위험 평가 (step_up 조치 + 2차 인증 없는 경로면 코드 챌린지 반환)
+        let login_risk = Self::evaluate_login_risk(
+            st,
+            user_id,
+            user_auth,
+            login_method,
+            &geo,
+            &parsed_ua,
+            &login_ip,
+            user_agent.as_deref(),
+            assurance,
+        )
+        .await?;
+
         let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
         let login_ip_enc = crypto.encrypt(&login_ip, "login.login_ip")?;
         let login_ip_log_enc = crypto.encrypt(&login_ip, "login_log.login_ip_log")?;
@@ -2424,6 +2792,8 @@ impl AuthService {
             geo.country_code.as_deref(),
             geo.asn,
             geo.org.as_deref(),
+            geo.latitude,
+            geo.longitude,
         )
         .await?;
 
@@ -2488,6 +2858,26 @@ impl AuthService {
         // 4. 관리자 세션 v2: 이 로그인이 in-tx 퇴장시킨 기존 세션의 Redis 키 정리(즉시 강퇴).
         Self::cleanup_evicted_sessions_redis(&mut redis_conn, user_id, &evicted_sessions).await;
 
+        // 5. 위험 이벤트 기록 / 알림, step-up 완료 시 챌린지 이벤트에 세션 연결 (best-effort)
+        if let Some(pending) = login_risk {
+            Self::record_login_risk(
+                st,
+                user_id,
+                &session_id,
+                login_method,
+                &geo,
+                &parsed_ua,
+                pending,
+            )
+            .await;
+        } else if let LoginAssurance::StepUp { risk_event_id } = assurance {
+            if let Err(e) =
+                AuthRepo::mark_login_risk_step_up_passed(&st.db, risk_event_id, &session_id).await
+            {
+                warn!(error = %e, risk_event_id, "Failed to mark login risk step-up passed");
+            }
+        }
+
         // Cookie 생성
         let refresh_token_for_mobile = refresh_token_value.clone();
         let mut refresh_cookie =
@@ -2521,403 +2911,2622 @@ impl AuthService {
     }
 
     // =========================================================================
-    // MFA (Multi-Factor Authentication)
+    // 기관 SSO (OIDC / SAML) — 이메일 도메인 discovery + JIT 가입 + SSO 전용 강제
     // =========================================================================
 
-    /// MFA 설정 시작 — TOTP 비밀키 + QR 코드 생성
-    pub async fn mfa_setup(
+    /// 기관 SSO 전용 도메인 계정인지 (HYMN/admin 플랫폼 계정은 잠김 방지로 제외)
+    pub(crate) async fn is_sso_required(
         st: &AppState,
-        user_id: i64,
-        user_email_enc: &str,
-    ) -> AppResult<MfaSetupRes> {
-        // Rate limit: 반복 MFA 설정 요청 방지
-        let mut redis_conn = st
-            .redis
-            .get()
-            .await
-            .map_err(|e| AppError::Internal(e.to_string()))?;
-        let rl_key = format!("rl:mfa_setup:{}", user_id);
-        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
-        let _: () = redis_conn.expire(&rl_key, 3600).await?; // 5회/1시간
-        if attempts > 5 {
-            return Err(AppError::TooManyRequests(
-                "MFA_429_TOO_MANY_ATTEMPTS".into(),
-            ));
+        email: &str,
+        user_auth: UserAuth,
+    ) -> AppResult<bool> {
+        if matches!(user_auth, UserAuth::Hymn | UserAuth::Admin) {
+            return Ok(false);
         }
+        let Some(domain) = sso::email_domain(email) else {
+            return Ok(false);
+        };
+        org_repo::is_sso_enforced_domain(&st.db, &domain).await
+    }
 
-        // 이미 MFA 활성화된 경우 에러
-        let mfa_enabled = AuthRepo::find_user_mfa_enabled(&st.db, user_id).await?;
-        if mfa_enabled {
-            return Err(AppError::Conflict("MFA_ALREADY_ENABLED".into()));
+    /// 기관 SSO 가 아닌 로그인 방식으로 SSO 전용 계정의 세션을 만들지 않도록 확인
+    async fn ensure_login_method_allowed(
+        st: &AppState,
+        user_id: i64,
+        user_auth: UserAuth,
+        login_method: &str,
+    ) -> AppResult<()> {
+        if login_method == SSO_PROVIDER || matches!(user_auth, UserAuth::Hymn | UserAuth::Admin) {
+            return Ok(());
         }
-
+        let user = user_repo::find_user(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal("Login user not found".into()))?;
         let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
-        let email = crypto
-            .decrypt(user_email_enc, "users.user_email")
-            .unwrap_or_else(|_| format!("user_{}", user_id));
+        let email = crypto.decrypt(&user.email, "users.user_email")?;
+        if Self::is_sso_required(st, &email, user_auth).await? {
+            info!(user_id, login_method, "Login rejected: SSO required");
+            return Err(AppError::Forbidden("AUTH_403_SSO_REQUIRED".into()));
+        }
+        Ok(())
+    }
 
-        // TOTP 비밀키 생성
-        let secret = Secret::generate_secret();
-        let secret_base32 = secret.to_encoded().to_string();
+    /// SP 주소 (SSO_PUBLIC_BASE_URL 기준)
+    fn sso_base_url(cfg: &Config) -> AppResult<&str> {
+        cfg.sso_public_base_url.as_deref().ok_or_else(|| {
+            AppError::ServiceUnavailable("Organization SSO is not configured".into())
+        })
+    }
 
-        let totp = TOTP::new(
-            Algorithm::SHA1,
-            6,  // digits
-            1,  // skew (±1 step = 90초 허용)
-            30, // step (30초)
-            secret
-                .to_bytes()
-                .map_err(|e| AppError::Internal(format!("TOTP secret error: {}", e)))?,
-            Some("AmazingKorean".to_string()),
-            email.clone(),
-        )
-        .map_err(|e| AppError::Internal(format!("TOTP creation error: {}", e)))?;
+    fn saml_service_provider(cfg: &Config) -> AppResult<SamlServiceProvider> {
+        let base = Self::sso_base_url(cfg)?;
+        Ok(SamlServiceProvider {
+            entity_id: format!("{}/auth/sso/saml/metadata", base),
+            acs_url: format!("{}/auth/sso/saml/acs", base),
+        })
+    }
 
-        // QR 코드 data URI 생성
-        let qr_code_data_uri = totp
-            .get_qr_base64()
-            .map_err(|e| AppError::Internal(format!("QR generation error: {}", e)))?;
+    /// IdP 등록용 SP 메타데이터 XML
+    pub fn sso_saml_metadata(st: &AppState) -> AppResult<String> {
+        Ok(Self::saml_service_provider(&st.cfg)?.metadata_xml())
+    }
 
-        let otpauth_uri = totp.get_url();
+    /// 기관 OIDC 클라이언트 (issuer discovery, client secret 복호화)
+    async fn sso_oidc_client(st: &AppState, row: &OrgSsoRow) -> AppResult<OidcClient> {
+        let (Some(issuer), Some(client_id)) =
+            (row.oidc_issuer.as_deref(), row.oidc_client_id.clone())
+        else {
+            return Err(AppError::Internal(format!(
+                "Organization {} OIDC settings incomplete",
+                row.org_id
+            )));
+        };
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let client_secret = row
+            .oidc_client_secret
+            .as_deref()
+            .map(|secret| crypto.decrypt(secret, "org_sso.oidc_client_secret"))
+            .transpose()?;
+        OidcClient::discover(
+            "SSO",
+            issuer,
+            OidcClientConfig {
+                client_id,
+                client_secret,
+                redirect_uri: Some(format!(
+                    "{}/auth/sso/oidc/callback",
+                    Self::sso_base_url(&st.cfg)?
+                )),
+                scope: "openid email profile",
+                audiences: Vec::new(),
+                pkce: true,
+            },
+        )
+        .await
+    }
 
-        // 비밀키 암호화 후 DB 저장 (enabled=false 상태)
-        let encrypted_secret = crypto.encrypt(&secret_base32, "users.user_mfa_secret")?;
-        AuthRepo::update_mfa_secret(&st.db, user_id, &encrypted_secret).await?;
+    /// 활성 SSO 설정 (비활성 / 삭제된 설정이면 404)
+    async fn load_enabled_sso(st: &AppState, org_id: i64) -> AppResult<OrgSsoRow> {
+        org_repo::find_sso(&st.db, org_id)
+            .await?
+            .filter(|row| row.sso_enabled)
+            .ok_or(AppError::NotFound)
+    }
 
-        Ok(MfaSetupRes {
-            secret: secret_base32,
-            qr_code_data_uri: format!("data:image/png;base64,{}", qr_code_data_uri),
-            otpauth_uri,
-        })
+    /// 로그인 discovery — 이메일 도메인의 기관 IdP 인가 URL. 설정이 없으면 404
+    pub async fn sso_discover(st: &AppState, email: &str) -> AppResult<String> {
+        let domain =
+            sso::email_domain(email).ok_or_else(|| AppError::BadRequest("Invalid email".into()))?;
+        let row = org_repo::find_sso_by_domain(&st.db, &domain)
+            .await?
+            .filter(|row| row.sso_enabled)
+            .ok_or(AppError::NotFound)?;
+
+        let url = Self::sso_auth_url(st, &row, email.trim(), None).await?;
+        info!(org_id = row.org_id, protocol = ?row.sso_protocol, "SSO login started");
+        Ok(url)
     }
 
-    /// MFA 설정 확인 — 첫 코드 검증 후 활성화 + 백업 코드 발급
-    pub async fn mfa_verify_setup(
+    /// 기관 IdP 인가 URL — flow state 를 Redis 에 일회용으로 저장.
+    /// `link_intent` 가 있으면 콜백을 로그인 대신 계정 연결로 처리
+    async fn sso_auth_url(
         st: &AppState,
-        user_id: i64,
-        code: &str,
-    ) -> AppResult<MfaVerifySetupRes> {
-        // Rate limit: TOTP 6자리 brute-force 방지
+        row: &OrgSsoRow,
+        login_hint: &str,
+        link_intent: Option<String>,
+    ) -> AppResult<String> {
+        let state = Uuid::new_v4().to_string();
+        let (url, flow) = match row.sso_protocol {
+            OrgSsoProtocol::Oidc => {
+                let client = Self::sso_oidc_client(st, row).await?;
+                let oidc_flow = AuthFlowState::new(SSO_PROVIDER, true);
+                let url =
+                    client.authorization_url(&state, &oidc_flow, &[("login_hint", login_hint)]);
+                let flow = SsoFlowState {
+                    org_id: row.org_id,
+                    protocol: row.sso_protocol,
+                    oidc: Some(oidc_flow),
+                    saml_request_id: None,
+                };
+                (url, flow)
+            }
+            OrgSsoProtocol::Saml => {
+                let sp = Self::saml_service_provider(&st.cfg)?;
+                let idp = IdpMetadata::parse(row.saml_metadata_xml.as_deref().unwrap_or_default())?;
+                let request_id = saml::new_request_id();
+                let url = sp.authn_request_url(&idp, &request_id, &state, chrono::Utc::now())?;
+                let flow = SsoFlowState {
+                    org_id: row.org_id,
+                    protocol: row.sso_protocol,
+                    oidc: None,
+                    saml_request_id: Some(request_id),
+                };
+                (url, flow)
+            }
+        };
+
+        let value = serde_json::to_string(&flow).map_err(|e| AppError::Internal(e.to_string()))?;
         let mut redis_conn = st
             .redis
             .get()
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
-        let rl_key = format!("rl:mfa_verify_setup:{}", user_id);
-        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
         let _: () = redis_conn
-            .expire(&rl_key, st.cfg.rate_limit_mfa_window_sec)
-            .await?;
-        if attempts > st.cfg.rate_limit_mfa_max {
-            return Err(AppError::TooManyRequests(
-                "MFA_429_TOO_MANY_ATTEMPTS".into(),
-            ));
-        }
-
-        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
-
-        // DB에서 MFA secret 조회 + 복호화
-        let encrypted_secret = AuthRepo::find_mfa_secret(&st.db, user_id)
-            .await?
-            .ok_or_else(|| AppError::BadRequest("MFA_SETUP_NOT_STARTED".into()))?;
-        let secret_base32 = crypto.decrypt(&encrypted_secret, "users.user_mfa_secret")?;
-
-        // TOTP 검증
-        let secret_bytes = Secret::Encoded(secret_base32)
-            .to_bytes()
-            .map_err(|e| AppError::Internal(format!("TOTP secret decode error: {}", e)))?;
-        let totp = TOTP::new(Algorithm::SHA1, 6, 1, 30, secret_bytes, None, String::new())
-            .map_err(|e| AppError::Internal(format!("TOTP creation error: {}", e)))?;
+            .set_ex(
+                format!("{}{}", SSO_FLOW_PREFIX, state),
+                value,
+                st.cfg.oauth_state_ttl_sec as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
 
-        if !totp
-            .check_current(code)
-            .map_err(|e| AppError::Internal(format!("TOTP check error: {}", e)))?
-        {
-            return Err(AppError::Unauthorized("MFA_INVALID_CODE".into()));
+        if let Some(intent) = link_intent {
+            Self::save_link_intent(st, &state, intent).await?;
         }
-
-        // 백업 코드 10개 생성 (8자 영숫자) — rng를 블록 내에서 드롭하여 Send 보장
-        let backup_codes: Vec<String> = {
-            let mut rng = rand::thread_rng();
-            (0..10)
-                .map(|_| {
-                    (0..8)
-                        .map(|_| {
-                            let idx: u32 = rng.gen_range(0..36);
-                            if idx < 10 {
-                                (b'0' + idx as u8) as char
-                            } else {
-                                (b'a' + (idx - 10) as u8) as char
-                            }
-                        })
-                        .collect()
-                })
-                .collect()
-        };
-
-        // 백업 코드 해시 → JSON → 암호화
-        let backup_hashes: Vec<String> = backup_codes
-            .iter()
-            .map(|c| {
-                let hash = Sha256::digest(c.as_bytes());
-                URL_SAFE_NO_PAD.encode(hash)
-            })
-            .collect();
-        let hashes_json =
-            serde_json::to_string(&backup_hashes).map_err(|e| AppError::Internal(e.to_string()))?;
-        let encrypted_backup = crypto.encrypt(&hashes_json, "users.user_mfa_backup_codes")?;
-
-        // MFA 활성화
-        AuthRepo::enable_mfa(&st.db, user_id, &encrypted_backup).await?;
-
-        info!("MFA enabled for user {}", user_id);
-
-        Ok(MfaVerifySetupRes {
-            enabled: true,
-            backup_codes,
-        })
+        Ok(url)
     }
 
-    /// MFA 로그인 (2단계 인증 — TOTP 또는 백업 코드)
-    pub async fn mfa_login(
+    /// state 조회 + 삭제 (GETDEL, 일회용). 없거나 다른 프로토콜이면 AUTH_401_INVALID_SSO_STATE
+    async fn take_sso_flow(
         st: &AppState,
-        req: MfaLoginReq,
-        login_ip: String,
-    ) -> AppResult<(LoginRes, Cookie<'static>, i64, String)> {
+        state: &str,
+        protocol: OrgSsoProtocol,
+    ) -> AppResult<SsoFlowState> {
         let mut redis_conn = st
             .redis
             .get()
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
+        let raw: Option<String> = redis_conn
+            .get_del(format!("{}{}", SSO_FLOW_PREFIX, state))
+            .await?;
 
-        // [Step 1] Redis에서 MFA pending 데이터 조회 + 삭제 (일회용)
-        let mfa_key = format!("ak:mfa_pending:{}", req.mfa_token);
-        let pending_json: Option<String> = redis_conn
-            .get(&mfa_key)
-            .await
+        raw.and_then(|raw| serde_json::from_str::<SsoFlowState>(&raw).ok())
+            .filter(|flow| flow.protocol == protocol)
+            .ok_or_else(|| AppError::Unauthorized("AUTH_401_INVALID_SSO_STATE".into()))
+    }
+
+    /// OIDC 콜백 — code 교환 → ID token(서명 / iss / aud / nonce) 검증 → 로그인
+    pub async fn sso_oidc_callback(
+        st: &AppState,
+        code: &str,
+        state: &str,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        let (row, profile) = Self::sso_oidc_profile(st, code, state).await?;
+        Self::sso_login_flow(st, &row, &profile, login_ip, user_agent, parsed_ua).await
+    }
+
+    /// OIDC 콜백 공통 — 검증된 기관 설정 + 프로필
+    async fn sso_oidc_profile(
+        st: &AppState,
+        code: &str,
+        state: &str,
+    ) -> AppResult<(OrgSsoRow, sso::SsoProfile)> {
+        let flow = Self::take_sso_flow(st, state, OrgSsoProtocol::Oidc).await?;
+        let oidc_flow = flow
+            .oidc
+            .ok_or_else(|| AppError::Unauthorized("AUTH_401_INVALID_SSO_STATE".into()))?;
+        let row = Self::load_enabled_sso(st, flow.org_id).await?;
+        let client = Self::sso_oidc_client(st, &row).await?;
+
+        let token = client
+            .exchange_code(code, oidc_flow.pkce_verifier.as_deref(), &[])
+            .await?;
+        let id_token = token
+            .id_token
+            .as_deref()
+            .ok_or_else(|| AppError::External("SSO token response missing id_token".into()))?;
+        let mut claims: serde_json::Map<String, serde_json::Value> =
+            client.verify_id_token(id_token).await?;
+        if claims.get("nonce").and_then(|v| v.as_str()) != Some(oidc_flow.nonce.as_str()) {
+            return Err(AppError::Unauthorized("AUTH_401_INVALID_NONCE".into()));
+        }
+        let subject = claims
+            .get("sub")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| AppError::External("SSO ID token missing sub".into()))?;
+
+        // ID token 에 프로필이 없는 IdP — userinfo 로 보충 (ID token 값 우선, sub 는 같아야 함)
+        if !claims.contains_key("email") && !token.access_token.is_empty() {
+            let userinfo: serde_json::Map<String, serde_json::Value> =
+                client.fetch_userinfo(&token.access_token).await?;
+            if userinfo.get("sub").and_then(|v| v.as_str()) == Some(subject.as_str()) {
+                for (key, value) in userinfo {
+                    claims.entry(key).or_insert(value);
+                }
+            }
+        }
+        if claims.get("email_verified").and_then(|v| v.as_bool()) == Some(false) {
+            return Err(AppError::Unauthorized("SSO_401_EMAIL_NOT_VERIFIED".into()));
+        }
+
+        let profile = sso::map_profile(
+            OrgSsoProtocol::Oidc,
+            &subject,
+            &sso::claims_to_attributes(&claims),
+            &sso_attribute_map(&row),
+        )?;
+        Ok((row, profile))
+    }
+
+    /// SAML ACS — RelayState 로 요청을 찾고 서명된 응답을 검증 → 로그인
+    pub async fn sso_saml_acs(
+        st: &AppState,
+        saml_response: &str,
+        relay_state: &str,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        let (row, profile) = Self::sso_saml_profile(st, saml_response, relay_state).await?;
+        Self::sso_login_flow(st, &row, &profile, login_ip, user_agent, parsed_ua).await
+    }
+
+    /// SAML ACS 공통 — 검증된 기관 설정 + 프로필
+    async fn sso_saml_profile(
+        st: &AppState,
+        saml_response: &str,
+        relay_state: &str,
+    ) -> AppResult<(OrgSsoRow, sso::SsoProfile)> {
+        let flow = Self::take_sso_flow(st, relay_state, OrgSsoProtocol::Saml).await?;
+        let request_id = flow
+            .saml_request_id
+            .ok_or_else(|| AppError::Unauthorized("AUTH_401_INVALID_SSO_STATE".into()))?;
+        let row = Self::load_enabled_sso(st, flow.org_id).await?;
+        let idp = IdpMetadata::parse(row.saml_metadata_xml.as_deref().unwrap_or_default())?;
+        let assertion = Self::saml_service_provider(&st.cfg)?.validate_response(
+            &idp,
+            saml_response,
+            &request_id,
+            chrono::Utc::now(),
+        )?;
+
+        let profile = sso::map_profile(
+            OrgSsoProtocol::Saml,
+            &assertion.name_id,
+            &assertion.attributes,
+            &sso_attribute_map(&row),
+        )?;
+        Ok((row, profile))
+    }
+
+    /// 기관 SSO 공통 — 이메일 도메인 확인 → 사용자 조회/연결/JIT 가입 → MFA / 세션
+    async fn sso_login_flow(
+        st: &AppState,
+        row: &OrgSsoRow,
+        profile: &sso::SsoProfile,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<OAuthLoginOutcome> {
+        Self::ensure_sso_email_in_org(st, row, profile).await?;
+
+        let (user_id, user_auth, is_new_user) =
+            Self::find_or_create_sso_user(st, row, profile).await?;
+        Self::oauth_session_or_mfa(
+            st,
+            user_id,
+            user_auth,
+            is_new_user,
+            SSO_PROVIDER,
+            login_ip,
+            user_agent,
+            parsed_ua,
+        )
+        .await
+    }
+
+    /// IdP 가 다른 기관 도메인의 이메일을 내주면 그 계정을 가로챌 수 있다
+    async fn ensure_sso_email_in_org(
+        st: &AppState,
+        row: &OrgSsoRow,
+        profile: &sso::SsoProfile,
+    ) -> AppResult<()> {
+        let domains = org_repo::find_sso_domains(&st.db, row.org_id).await?;
+        let in_domain =
+            sso::email_domain(&profile.email).is_some_and(|domain| domains.contains(&domain));
+        if !in_domain {
+            warn!(
+                org_id = row.org_id,
+                "SSO email outside organization domains"
+            );
+            return Err(AppError::Forbidden("SSO_403_DOMAIN_MISMATCH".into()));
+        }
+        Ok(())
+    }
+
+    /// SSO 사용자 조회 → 같은 이메일 계정에 연결 → JIT 가입 (기관 설정이 허용할 때)
+    /// 반환: (user_id, user_auth, is_new_user)
+    async fn find_or_create_sso_user(
+        st: &AppState,
+        row: &OrgSsoRow,
+        profile: &sso::SsoProfile,
+    ) -> AppResult<(i64, UserAuth, bool)> {
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+
+        // 1. 연결된 SSO 계정 — subject 는 기관 IdP 안에서만 유일하므로 기관 ID 를 붙여 색인
+        let subject_key = format!("{}:{}", row.org_id, profile.subject);
+        let subject_idx = crypto.blind_index_preserve_case(&subject_key)?;
+        if let Some(oauth) =
+            AuthRepo::find_oauth_by_provider_subject_idx(&st.db, SSO_PROVIDER, &subject_idx).await?
+        {
+            AuthRepo::update_oauth_last_login(&st.db, oauth.user_oauth_id).await?;
+            let user = user_repo::find_user(&st.db, oauth.user_id)
+                .await?
+                .ok_or_else(|| AppError::Internal("SSO linked user not found".into()))?;
+            info!(
+                "SSO login: existing user {} via org {}",
+                user.id, row.org_id
+            );
+            return Ok((user.id, user.user_auth, false));
+        }
+
+        let subject_enc = crypto.encrypt(&subject_key, "user_oauth.oauth_subject")?;
+        let oauth_email_enc = crypto.encrypt(&profile.email, "user_oauth.oauth_email")?;
+
+        // 2. 같은 이메일의 기존 계정에 연결 — 기관 도메인 이메일은 IdP 가 소유를 보증.
+        //    운영 권한 계정은 IdP 주장만으로 연결하지 않는다 (기존 수단으로 로그인 후 계정 설정에서 연결)
+        let email_idx = crypto.blind_index(&profile.email)?;
+        if let Some(existing_user) = AuthRepo::find_user_by_email_idx(&st.db, &email_idx).await? {
+            if matches!(
+                existing_user.user_auth,
+                UserAuth::Hymn | UserAuth::Admin | UserAuth::Manager
+            ) {
+                warn!(
+                    user_id = existing_user.user_id,
+                    org_id = row.org_id,
+                    "SSO auto-link refused for privileged account"
+                );
+                return Err(AppError::Conflict("SSO_409_LINK_REQUIRED".into()));
+            }
+            let mut tx = st.db.begin().await?;
+            AuthRepo::insert_oauth_link_tx(
+                &mut tx,
+                existing_user.user_id,
+                SSO_PROVIDER,
+                &subject_enc,
+                Some(oauth_email_enc.as_str()),
+                profile.name.as_deref(),
+                None,
+                &subject_idx,
+            )
+            .await?;
+            tx.commit().await?;
+
+            if !existing_user.user_check_email {
+                AuthRepo::update_user_check_email(&st.db, existing_user.user_id, true).await?;
+            }
+            info!(
+                "SSO account linked to existing user: {} (org {})",
+                existing_user.user_id, row.org_id
+            );
+            return Ok((existing_user.user_id, existing_user.user_auth, false));
+        }
+
+        // 3. JIT 가입 — 약관 동의는 기록하지 않는다 (첫 로그인 후 본인이 직접 동의)
+        if !row.sso_jit_enabled {
+            return Err(AppError::Forbidden("SSO_403_NOT_PROVISIONED".into()));
+        }
+
+        let nickname: String = profile
+            .nickname
+            .clone()
+            .or_else(|| profile.name.clone())
+            .unwrap_or_else(|| {
+                profile
+                    .email
+                    .split('@')
+                    .next()
+                    .unwrap_or("User")
+                    .to_string()
+            })
+            .chars()
+            .take(100)
+            .collect();
+        let name = profile.name.clone().unwrap_or_else(|| nickname.clone());
+        let country: String = profile
+            .country
+            .as_deref()
+            .unwrap_or("Unknown")
+            .chars()
+            .take(50)
+            .collect();
+
+        // 회원가입과 같은 PII 암호화
+        let email_enc = crypto.encrypt(&profile.email, "users.user_email")?;
+        let name_enc = crypto.encrypt(&name, "users.user_name")?;
+        let name_idx = crypto.blind_index(&name)?;
+        // IdP 가 생년월일을 주지 않으면 비워 둔다 (가입일로 채우지 않음)
+        let birthday_enc = profile
+            .birthday
+            .map(|b| crypto.encrypt(&b.format("%Y-%m-%d").to_string(), "users.user_birthday"))
+            .transpose()?;
+
+        let mut tx = st.db.begin().await?;
+        let user_id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO users (
+                user_email, user_password, user_name,
+                user_nickname, user_language, user_country,
+                user_birthday, user_gender,
+                user_terms_service, user_terms_personal,
+                user_check_email,
+                user_email_idx, user_name_idx
+            )
+            VALUES (
+                $1, NULL, $2,
+                $3, $4::user_language_enum, $5,
+                $6, $7,
+                false, false,
+                true,
+                $8, $9
+            )
+            RETURNING user_id
+        "#,
+        )
+        .bind(&email_enc)
+        .bind(&name_enc)
+        .bind(&nickname)
+        .bind(profile.language.as_deref().unwrap_or("ko"))
+        .bind(&country)
+        .bind(&birthday_enc)
+        .bind(profile.gender)
+        .bind(&email_idx)
+        .bind(&name_idx)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        AuthRepo::insert_oauth_link_tx(
+            &mut tx,
+            user_id,
+            SSO_PROVIDER,
+            &subject_enc,
+            Some(oauth_email_enc.as_str()),
+            profile.name.as_deref(),
+            None,
+            &subject_idx,
+        )
+        .await?;
+
+        user_repo::insert_user_log_after_tx(&mut tx, &crypto, None, user_id, "signup", true)
+            .await?;
+
+        tx.commit().await?;
+
+        info!("New SSO user provisioned: {} (org {})", user_id, row.org_id);
+        Ok((user_id, UserAuth::Learner, true))
+    }
+
+    // =========================================================================
+    // 소셜 계정 연결 (로그인 상태 — 계정 설정)
+    // =========================================================================
+
+    /// 소셜 계정 연결 시작 — 같은 로그인 콜백(`/auth/{provider}/callback`)이 로그인 대신 연결로 처리.
+    /// 반환 = (인증 URL, 브라우저 바인딩 값). 바인딩 값은 쿠키로만 내려가고 Redis 에는 해시만 저장 —
+    /// 다른 브라우저로 넘어간 인증 URL 은 연결되지 않는다 (link CSRF 방지)
+    pub async fn oauth_link_start(
+        st: &AppState,
+        provider: OAuthProvider,
+        user_id: i64,
+    ) -> AppResult<(String, String)> {
+        let (intent, binding) = Self::new_link_intent(user_id);
+        let auth_url = Self::oauth_auth_url(st, provider, Some(intent)).await?;
+        Ok((auth_url, binding))
+    }
+
+    /// link intent 값 (`ak:oauth_link:{state}` 에 저장할 JSON) + 브라우저 바인딩 원문
+    fn new_link_intent(user_id: i64) -> (String, String) {
+        let binding = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+        let intent = serde_json::json!({
+            "user_id": user_id,
+            "binding_hash": hex::encode(Sha256::digest(&binding)),
+        });
+        (intent.to_string(), binding)
+    }
+
+    /// link intent 저장 (state 와 같은 TTL). 소셜 / 기관 SSO 연결 공용
+    async fn save_link_intent(st: &AppState, state: &str, intent: String) -> AppResult<()> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let _: () = redis_conn
+            .set_ex(
+                format!("ak:oauth_link:{}", state),
+                intent,
+                st.cfg.oauth_state_ttl_sec as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 콜백 state 의 link intent 조회 + 삭제 (GETDEL, 일회용). None = 일반 로그인 콜백.
+    /// 연결을 시작한 브라우저의 바인딩 쿠키와 맞지 않으면 403 (intent 는 이미 소모됨)
+    pub async fn take_oauth_link_intent(
+        st: &AppState,
+        state: &str,
+        binding: Option<&str>,
+    ) -> AppResult<Option<i64>> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let intent: Option<String> = redis_conn
+            .get_del(format!("ak:oauth_link:{}", state))
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let Some(intent) = intent else {
+            return Ok(None);
+        };
+
+        let intent: serde_json::Value = serde_json::from_str(&intent)
+            .map_err(|_| AppError::Forbidden("OAUTH_LINK_BROWSER_MISMATCH".into()))?;
+        let expected = intent["binding_hash"].as_str().unwrap_or_default();
+        let bound = binding.is_some_and(|b| {
+            Self::constant_time_eq(
+                hex::encode(Sha256::digest(b)).as_bytes(),
+                expected.as_bytes(),
+            )
+        });
+        if !bound {
+            warn!(
+                state,
+                "OAuth link callback from a browser that did not start the link"
+            );
+            return Err(AppError::Forbidden("OAUTH_LINK_BROWSER_MISMATCH".into()));
+        }
+        intent["user_id"]
+            .as_i64()
+            .map(Some)
+            .ok_or_else(|| AppError::Forbidden("OAUTH_LINK_BROWSER_MISMATCH".into()))
+    }
+
+    /// 소셜 계정 연결 콜백 (state / nonce / audience 검증은 로그인 콜백과 동일)
+    pub async fn oauth_link_callback(
+        st: &AppState,
+        provider: OAuthProvider,
+        code: &str,
+        state: &str,
+        user_id: i64,
+    ) -> AppResult<()> {
+        let user_info = Self::oauth_callback_user_info(st, provider, code, state).await?;
+        Self::link_oauth_identity(st, user_id, &user_info, provider.as_str()).await
+    }
+
+    /// 모바일 계정 연결 — 앱이 받은 ID token 을 직접 검증 (google / apple / kakao).
+    /// 네이버는 ID token 이 없어 웹 연결만 지원
+    pub async fn link_identity_with_id_token(
+        st: &AppState,
+        user_id: i64,
+        provider: &str,
+        id_token: &str,
+    ) -> AppResult<()> {
+        let user_info = match provider {
+            "google" => {
+                let client_id = st.cfg.google_mobile_client_id.as_ref().ok_or_else(|| {
+                    AppError::Internal("GOOGLE_MOBILE_CLIENT_ID not configured".into())
+                })?;
+                let client =
+                    build_google_client(&st.cfg, client_id.clone(), String::new(), String::new())?;
+                let claims = client.decode_id_token(id_token).await?;
+                client.extract_user_info(&claims).into()
+            }
+            "apple" => {
+                let client = st
+                    .apple_oauth
+                    .as_ref()
+                    .ok_or_else(|| AppError::Internal("APPLE_CLIENT_ID not configured".into()))?;
+                let claims = client.decode_id_token(id_token).await?;
+                client.extract_user_info(&claims, None)
+            }
+            "kakao" => {
+                let client = build_kakao_client(&st.cfg, false).await?;
+                let claims = client.decode_id_token(id_token).await?;
+                client.extract_user_info(&claims)
+            }
+            _ => return Err(AppError::BadRequest("OAUTH_PROVIDER_NOT_SUPPORTED".into())),
+        };
+        Self::link_oauth_identity(st, user_id, &user_info, provider).await
+    }
+
+    /// 기관 SSO 연결 시작 — 계정 이메일 도메인의 기관 IdP 로 보낸다 (같은 SSO 콜백이 연결로 처리).
+    /// 반환 = (인가 URL, 브라우저 바인딩 값). 기관 SSO 가 없는 도메인이면 404
+    pub async fn sso_link_start(st: &AppState, user_id: i64) -> AppResult<(String, String)> {
+        let user = user_repo::find_user(&st.db, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto.decrypt(&user.email, "users.user_email")?;
+        let domain = sso::email_domain(&email).ok_or(AppError::NotFound)?;
+        let row = org_repo::find_sso_by_domain(&st.db, &domain)
+            .await?
+            .filter(|row| row.sso_enabled)
+            .ok_or(AppError::NotFound)?;
+
+        let (intent, binding) = Self::new_link_intent(user_id);
+        let url = Self::sso_auth_url(st, &row, &email, Some(intent)).await?;
+        info!(user_id, org_id = row.org_id, "SSO link started");
+        Ok((url, binding))
+    }
+
+    /// 기관 SSO 연결 콜백 (OIDC)
+    pub async fn sso_oidc_link_callback(
+        st: &AppState,
+        code: &str,
+        state: &str,
+        user_id: i64,
+    ) -> AppResult<()> {
+        let (row, profile) = Self::sso_oidc_profile(st, code, state).await?;
+        Self::link_sso_identity(st, user_id, &row, &profile).await
+    }
+
+    /// 기관 SSO 연결 콜백 (SAML ACS)
+    pub async fn sso_saml_link_acs(
+        st: &AppState,
+        saml_response: &str,
+        relay_state: &str,
+        user_id: i64,
+    ) -> AppResult<()> {
+        let (row, profile) = Self::sso_saml_profile(st, saml_response, relay_state).await?;
+        Self::link_sso_identity(st, user_id, &row, &profile).await
+    }
+
+    /// 검증된 기관 SSO 계정을 로그인 사용자에 연결 — IdP 이메일이 계정 이메일과 같아야 한다
+    async fn link_sso_identity(
+        st: &AppState,
+        user_id: i64,
+        row: &OrgSsoRow,
+        profile: &sso::SsoProfile,
+    ) -> AppResult<()> {
+        Self::ensure_sso_email_in_org(st, row, profile).await?;
+        let user = user_repo::find_user(&st.db, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto.decrypt(&user.email, "users.user_email")?;
+        if email.trim().to_lowercase() != profile.email {
+            return Err(AppError::Forbidden("SSO_403_EMAIL_MISMATCH".into()));
+        }
+
+        // subject 색인은 로그인과 같은 "{org_id}:{subject}"
+        let user_info = OAuthUserInfo {
+            sub: format!("{}:{}", row.org_id, profile.subject),
+            email: profile.email.clone(),
+            email_verified: true,
+            name: profile.name.clone(),
+            picture: None,
+        };
+        Self::link_oauth_identity(st, user_id, &user_info, SSO_PROVIDER).await
+    }
+
+    /// 검증된 소셜 계정을 로그인 사용자에 연결. provider 당 1개 (다른 계정은 해제 후 연결)
+    async fn link_oauth_identity(
+        st: &AppState,
+        user_id: i64,
+        user_info: &OAuthUserInfo,
+        provider: &str,
+    ) -> AppResult<()> {
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let sub_idx = crypto.blind_index_preserve_case(&user_info.sub)?;
+
+        if let Some(existing) =
+            AuthRepo::find_oauth_by_provider_subject_idx(&st.db, provider, &sub_idx).await?
+        {
+            let code = if existing.user_id == user_id {
+                "OAUTH_IDENTITY_ALREADY_LINKED"
+            } else {
+                "OAUTH_IDENTITY_LINKED_TO_OTHER_ACCOUNT"
+            };
+            return Err(AppError::Conflict(code.into()));
+        }
+        let providers = AuthRepo::find_oauth_providers_by_user_id(&st.db, user_id).await?;
+        if providers.iter().any(|p| p == provider) {
+            return Err(AppError::Conflict("OAUTH_PROVIDER_ALREADY_LINKED".into()));
+        }
+
+        // Apple 은 최초 동의 때만, 카카오/네이버는 이메일 동의 시에만 email 제공 — 없으면 NULL
+        let oauth_email_enc = if user_info.email.is_empty() {
+            None
+        } else {
+            Some(crypto.encrypt(&user_info.email, "user_oauth.oauth_email")?)
+        };
+        let oauth_subject_enc = crypto.encrypt(&user_info.sub, "user_oauth.oauth_subject")?;
+
+        let mut tx = st.db.begin().await?;
+        AuthRepo::insert_oauth_link_tx(
+            &mut tx,
+            user_id,
+            provider,
+            &oauth_subject_enc,
+            oauth_email_enc.as_deref(),
+            user_info.name.as_deref(),
+            user_info.picture.as_deref(),
+            &sub_idx,
+        )
+        .await?;
+        user_repo::insert_user_log_after_tx(
+            &mut tx,
+            &crypto,
+            Some(user_id),
+            user_id,
+            "update",
+            true,
+        )
+        .await?;
+        tx.commit().await?;
+
+        info!(
+            user_id,
+            provider, "OAuth identity linked from account settings"
+        );
+        let email = (!user_info.email.is_empty()).then_some(user_info.email.as_str());
+        crate::api::user::service::UserService::send_security_notice(
+            st,
+            user_id,
+            "소셜 계정 연결",
+            &crate::api::user::service::UserService::identity_label(provider, email),
+        )
+        .await;
+        Ok(())
+    }
+
+    // =========================================================================
+    // MFA (Multi-Factor Authentication)
+    // =========================================================================
+
+    /// MFA 설정 시작 — TOTP 비밀키 + QR 코드 생성
+    pub async fn mfa_setup(
+        st: &AppState,
+        user_id: i64,
+        user_email_enc: &str,
+    ) -> AppResult<MfaSetupRes> {
+        // Rate limit: 반복 MFA 설정 요청 방지
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let rl_key = format!("rl:mfa_setup:{}", user_id);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn.expire(&rl_key, 3600).await?; // 5회/1시간
+        if attempts > 5 {
+            return Err(AppError::TooManyRequests(
+                "MFA_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        // 이미 MFA 활성화된 경우 에러
+        let mfa_enabled = AuthRepo::find_user_mfa_enabled(&st.db, user_id).await?;
+        if mfa_enabled {
+            return Err(AppError::Conflict("MFA_ALREADY_ENABLED".into()));
+        }
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto
+            .decrypt(user_email_enc, "users.user_email")
+            .unwrap_or_else(|_| format!("user_{}", user_id));
+
+        // TOTP 비밀키 생성
+        let secret = Secret::generate_secret();
+        let secret_base32 = secret.to_encoded().to_string();
+
+        let totp = TOTP::new(
+            Algorithm::SHA1,
+            6,  // digits
+            1,  // skew (±1 step = 90초 허용)
+            30, // step (30초)
+            secret
+                .to_bytes()
+                .map_err(|e| AppError::Internal(format!("TOTP secret error: {}", e)))?,
+            Some("AmazingKorean".to_string()),
+            email.clone(),
+        )
+        .map_err(|e| AppError::Internal(format!("TOTP creation error: {}", e)))?;
+
+        // QR 코드 data URI 생성
+        let qr_code_data_uri = totp
+            .get_qr_base64()
+            .map_err(|e| AppError::Internal(format!("QR generation error: {}", e)))?;
+
+        let otpauth_uri = totp.get_url();
+
+        // 비밀키 암호화 후 DB 저장 (enabled=false 상태)
+        let encrypted_secret = crypto.encrypt(&secret_base32, "users.user_mfa_secret")?;
+        AuthRepo::update_mfa_secret(&st.db, user_id, &encrypted_secret).await?;
+
+        Ok(MfaSetupRes {
+            secret: secret_base32,
+            qr_code_data_uri: format!("data:image/png;base64,{}", qr_code_data_uri),
+            otpauth_uri,
+        })
+    }
+
+    /// MFA 설정 확인 — 첫 코드 검증 후 활성화 + 백업 코드 발급
+    pub async fn mfa_verify_setup(
+        st: &AppState,
+        user_id: i64,
+        code: &str,
+    ) -> AppResult<MfaVerifySetupRes> {
+        // Rate limit: TOTP 6자리 brute-force 방지
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let rl_key = format!("rl:mfa_verify_setup:{}", user_id);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn
+            .expire(&rl_key, st.cfg.rate_limit_mfa_window_sec)
+            .await?;
+        if attempts > st.cfg.rate_limit_mfa_max {
+            return Err(AppError::TooManyRequests(
+                "MFA_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+
+        // DB에서 MFA secret 조회 + 복호화
+        let encrypted_secret = AuthRepo::find_mfa_secret(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::BadRequest("MFA_SETUP_NOT_STARTED".into()))?;
+        let secret_base32 = crypto.decrypt(&encrypted_secret, "users.user_mfa_secret")?;
+
+        // TOTP 검증
+        let secret_bytes = Secret::Encoded(secret_base32)
+            .to_bytes()
+            .map_err(|e| AppError::Internal(format!("TOTP secret decode error: {}", e)))?;
+        let totp = TOTP::new(Algorithm::SHA1, 6, 1, 30, secret_bytes, None, String::new())
+            .map_err(|e| AppError::Internal(format!("TOTP creation error: {}", e)))?;
+
+        if !totp
+            .check_current(code)
+            .map_err(|e| AppError::Internal(format!("TOTP check error: {}", e)))?
+        {
+            return Err(AppError::Unauthorized("MFA_INVALID_CODE".into()));
+        }
+
+        // 백업 코드 10개 생성 (8자 영숫자) — rng를 블록 내에서 드롭하여 Send 보장
+        let backup_codes: Vec<String> = {
+            let mut rng = rand::thread_rng();
+            (0..10)
+                .map(|_| {
+                    (0..8)
+                        .map(|_| {
+                            let idx: u32 = rng.gen_range(0..36);
+                            if idx < 10 {
+                                (b'0' + idx as u8) as char
+                            } else {
+                                (b'a' + (idx - 10) as u8) as char
+                            }
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+
+        // 백업 코드 해시 → JSON → 암호화
+        let backup_hashes: Vec<String> = backup_codes
+            .iter()
+            .map(|c| {
+                let hash = Sha256::digest(c.as_bytes());
+                URL_SAFE_NO_PAD.encode(hash)
+            })
+            .collect();
+        let hashes_json =
+            serde_json::to_string(&backup_hashes).map_err(|e| AppError::Internal(e.to_string()))?;
+        let encrypted_backup = crypto.encrypt(&hashes_json, "users.user_mfa_backup_codes")?;
+
+        // MFA 활성화
+        AuthRepo::enable_mfa(&st.db, user_id, &encrypted_backup).await?;
+
+        info!("MFA enabled for user {}", user_id);
+
+        Ok(MfaVerifySetupRes {
+            enabled: true,
+            backup_codes,
+        })
+    }
+
+    /// MFA 로그인 (2단계 인증 — TOTP, 백업 코드 또는 패스키)
+    pub async fn mfa_login(
+        st: &AppState,
+        req: MfaLoginReq,
+        login_ip: String,
+    ) -> AppResult<(LoginRes, Cookie<'static>, i64, String)> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        // [Step 1] Redis에서 MFA pending 데이터 조회 + 삭제 (일회용)
+        let mfa_key = format!("ak:mfa_pending:{}", req.mfa_token);
+        let pending_json: Option<String> = redis_conn
+            .get(&mfa_key)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        let Some(pending_json) = pending_json else {
+            return Err(AppError::Unauthorized("MFA_TOKEN_EXPIRED".into()));
+        };
+
+        // 즉시 삭제 (일회용)
+        let _: () = redis_conn.del(&mfa_key).await.unwrap_or(());
+
+        let pending: serde_json::Value = serde_json::from_str(&pending_json)
+            .map_err(|e| AppError::Internal(format!("MFA pending parse error: {}", e)))?;
+
+        let user_id = pending["user_id"]
+            .as_i64()
+            .ok_or_else(|| AppError::Internal("MFA pending missing user_id".into()))?;
+        let user_auth_str = pending["user_auth"].as_str().unwrap_or("Learner");
+        let user_auth: UserAuth = match user_auth_str {
+            "HYMN" => UserAuth::Hymn,
+            "Admin" => UserAuth::Admin,
+            "Manager" => UserAuth::Manager,
+            _ => UserAuth::Learner,
+        };
+        let pending_ip = pending["login_ip"].as_str().unwrap_or("").to_string();
+        let pending_ua = pending["user_agent"].as_str().map(|s| s.to_string());
+        let pending_device = pending["device"].as_str().unwrap_or("other").to_string();
+        let pending_browser = pending["browser"].as_str().map(|s| s.to_string());
+        let pending_os = pending["os"].as_str().map(|s| s.to_string());
+        let pending_method = pending["login_method"]
+            .as_str()
+            .unwrap_or("login")
+            .to_string();
+
+        // [Step 2] Rate limit: MFA 코드 검증
+        let rl_key = format!("rl:mfa:{}:{}", user_id, login_ip);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn
+            .expire(&rl_key, st.cfg.rate_limit_mfa_window_sec)
+            .await?;
+        if attempts > st.cfg.rate_limit_mfa_max {
+            return Err(AppError::TooManyRequests(
+                "MFA_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        // [Step 3] 패스키, TOTP 코드 또는 백업 코드 검증
+        if let Some(credential) = &req.passkey {
+            // 패스키 챌린지도 일회용 (mfa_token 기준으로 발급됨)
+            let challenge_key = format!("ak:webauthn:mfa:{}", req.mfa_token);
+            let challenge: Option<String> = redis_conn
+                .get_del(&challenge_key)
+                .await
+                .map_err(|e| AppError::Internal(e.to_string()))?;
+            let challenge = challenge
+                .ok_or_else(|| AppError::Unauthorized("PASSKEY_CHALLENGE_EXPIRED".into()))?;
+
+            Self::verify_passkey_assertion(st, credential, &challenge, false, Some(user_id))
+                .await?;
+        } else {
+            let code = req
+                .code
+                .as_deref()
+                .ok_or_else(|| AppError::BadRequest("MFA_CODE_REQUIRED".into()))?;
+
+            if !Self::verify_totp_or_backup_code(st, user_id, code).await? {
+                return Err(AppError::Unauthorized("MFA_INVALID_CODE".into()));
+            }
+        }
+
+        // [Step 4] 코드 검증 성공 → Rate limit 초기화
+        let _: () = redis_conn.del(&rl_key).await.unwrap_or(());
+
+        // [Step 5] 세션 생성 (pending 데이터 기반)
+        let parsed_ua = crate::api::auth::handler::ParsedUa {
+            os: pending_os,
+            browser: pending_browser,
+            device: pending_device,
+        };
+
+        // 세션 생성 (pending_method에 저장된 login_method 그대로 사용)
+        let (login_res, cookie, ttl, refresh_token) = Self::create_oauth_session(
+            st,
+            user_id,
+            user_auth,
+            &pending_method,
+            pending_ip,
+            pending_ua,
+            parsed_ua,
+            LoginAssurance::SecondFactor,
+        )
+        .await?;
+        Ok((login_res, cookie, ttl, refresh_token))
+    }
+
+    /// TOTP 코드(6자리) 또는 백업 코드(8자, 일치 시 소모) 검증 — MFA 로그인 / 재인증 공용
+    async fn verify_totp_or_backup_code(
+        st: &AppState,
+        user_id: i64,
+        code: &str,
+    ) -> AppResult<bool> {
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let encrypted_secret = AuthRepo::find_mfa_secret(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal("MFA secret not found".into()))?;
+        let secret_base32 = crypto.decrypt(&encrypted_secret, "users.user_mfa_secret")?;
+
+        let secret_bytes = Secret::Encoded(secret_base32)
+            .to_bytes()
+            .map_err(|e| AppError::Internal(format!("TOTP secret decode error: {}", e)))?;
+        let totp = TOTP::new(Algorithm::SHA1, 6, 1, 30, secret_bytes, None, String::new())
+            .map_err(|e| AppError::Internal(format!("TOTP creation error: {}", e)))?;
+
+        if code.len() == 6 && code.chars().all(|c| c.is_ascii_digit()) {
+            // TOTP 코드 (6자리 숫자)
+            let valid = totp
+                .check_current(code)
+                .map_err(|e| AppError::Internal(format!("TOTP check error: {}", e)))?;
+            if valid {
+                return Ok(true);
+            }
+        }
+
+        // 백업 코드 시도 (8자 영숫자)
+        Self::try_backup_code(st, user_id, code, &crypto).await
+    }
+
+    /// 백업 코드 검증 (일치 시 해당 코드 해시 제거)
+    async fn try_backup_code(
+        st: &AppState,
+        user_id: i64,
+        code: &str,
+        crypto: &CryptoService<'_>,
+    ) -> AppResult<bool> {
+        let encrypted_codes = AuthRepo::find_mfa_backup_codes(&st.db, user_id).await?;
+        let Some(encrypted_codes) = encrypted_codes else {
+            return Ok(false);
+        };
+
+        let hashes_json = crypto.decrypt(&encrypted_codes, "users.user_mfa_backup_codes")?;
+        let mut hashes: Vec<String> = serde_json::from_str(&hashes_json)
+            .map_err(|e| AppError::Internal(format!("Backup codes parse error: {}", e)))?;
+
+        // 입력 코드 해시 계산
+        let input_hash = URL_SAFE_NO_PAD.encode(Sha256::digest(code.as_bytes()));
+
+        // 매치하는 해시 찾기
+        if let Some(pos) = hashes
+            .iter()
+            .position(|h| Self::constant_time_eq(h.as_bytes(), input_hash.as_bytes()))
+        {
+            // 사용된 코드 제거
+            hashes.remove(pos);
+
+            // 갱신된 해시 목록 저장
+            let updated_json =
+                serde_json::to_string(&hashes).map_err(|e| AppError::Internal(e.to_string()))?;
+            let encrypted_updated = crypto.encrypt(&updated_json, "users.user_mfa_backup_codes")?;
+            AuthRepo::update_mfa_backup_codes(&st.db, user_id, &encrypted_updated).await?;
+
+            info!(
+                "MFA backup code used for user {} ({} remaining)",
+                user_id,
+                hashes.len()
+            );
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// 사용자의 모든 세션을 무효화한다 — DB login 행을 revoked(reason)로, Redis 의 모든
+    /// ak:refresh / ak:session 키와 ak:user_sessions SET 을 일괄 삭제. mfa_disable 과
+    /// 계정 비활성화(ban/탈퇴, admin_update_user) 경로가 공유한다.
+    pub async fn invalidate_all_sessions(
+        st: &AppState,
+        user_id: i64,
+        reason: &str,
+    ) -> AppResult<()> {
+        let mut tx = st.db.begin().await?;
+        let sessions = AuthRepo::find_user_sessions_with_refresh_tx(&mut tx, user_id).await?;
+        AuthRepo::update_login_state_by_user_tx(&mut tx, user_id, "revoked", Some(reason)).await?;
+        tx.commit().await?;
+
+        // Redis 세션 + 리프레시 토큰 일괄 정리 (단일 DEL 명령, best-effort).
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let mut keys: Vec<String> = sessions
+            .iter()
+            .flat_map(|(sid, refresh_hash)| {
+                [
+                    format!("ak:refresh:{}", refresh_hash),
+                    format!("ak:session:{}", sid),
+                ]
+            })
+            .collect();
+        keys.push(format!("ak:user_sessions:{}", user_id));
+        let _: () = redis_conn.del(keys).await.unwrap_or(());
+        Ok(())
+    }
+
+    /// MFA 비활성화 (HYMN 전용 — 다른 사용자의 MFA 해제)
+    pub async fn mfa_disable(
+        st: &AppState,
+        auth_user_id: i64,
+        auth_user_auth: UserAuth,
+        target_user_id: i64,
+    ) -> AppResult<MfaDisableRes> {
+        // Rate limit: 반복 MFA 비활성화 시도 방지
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let rl_key = format!("rl:mfa_disable:{}", auth_user_id);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn.expire(&rl_key, 3600).await?; // 5회/1시간
+        if attempts > 5 {
+            return Err(AppError::TooManyRequests(
+                "MFA_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        // HYMN만 가능
+        if auth_user_auth != UserAuth::Hymn {
+            return Err(AppError::Forbidden("MFA_DISABLE_HYMN_ONLY".into()));
+        }
+
+        // 자기 자신 비활성화 불가
+        if auth_user_id == target_user_id {
+            return Err(AppError::BadRequest("MFA_CANNOT_DISABLE_SELF".into()));
+        }
+
+        // 대상 사용자 MFA 비활성화
+        AuthRepo::disable_mfa(&st.db, target_user_id).await?;
+
+        // 대상 사용자의 모든 세션 무효화 (보안) — 계정 비활성화/탈퇴와 공유하는 헬퍼.
+        Self::invalidate_all_sessions(st, target_user_id, "mfa_disabled").await?;
+
+        info!(
+            "MFA disabled for user {} by HYMN user {}",
+            target_user_id, auth_user_id
+        );
+
+        Ok(MfaDisableRes {
+            message: format!(
+                "MFA disabled for user {}. All sessions invalidated.",
+                target_user_id
+            ),
+        })
+    }
+
+    // =========================================================================
+    // 재인증 (보안 설정 변경 전 step-up)
+    // =========================================================================
+
+    /// 재인증 메일 코드 발송 — 현재 세션에 묶인 6자리 코드 (HMAC 해시로 저장)
+    pub async fn reauth_email_code(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+    ) -> AppResult<ReauthRes> {
+        let email_sender = st
+            .email
+            .as_ref()
+            .ok_or_else(|| AppError::ServiceUnavailable("Email service not configured".into()))?;
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let rl_key = format!("rl:reauth_code:{}", user_id);
+        let sends: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn.expire(&rl_key, 3600).await?; // 5회/1시간
+        if sends > 5 {
+            return Err(AppError::TooManyRequests(
+                "REAUTH_429_TOO_MANY_REQUESTS".into(),
+            ));
+        }
+
+        let user = AuthRepo::find_user_login_info_by_id(&st.db, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto.decrypt(&user.user_email, "users.user_email")?;
+
+        let code = Self::generate_verification_code();
+        let code_hash = crate::api::user::service::UserService::hmac_verification_code(
+            &st.cfg.hmac_key,
+            session_id,
+            &code,
+        );
+        let ttl_sec = st.cfg.verification_code_ttl_sec;
+        let code_key = format!("ak:reauth_code:{}", session_id);
+        let _: () = redis_conn
+            .set_ex(&code_key, &code_hash, ttl_sec as u64)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        if let Err(e) = crate::external::email::send_templated(
+            email_sender.as_ref(),
+            &email,
+            EmailTemplate::ReauthCode {
+                code,
+                expires_in_min: (ttl_sec / 60) as i32,
+            },
+        )
+        .await
+        {
+            let _: () = redis_conn.del(&code_key).await.unwrap_or(());
+            return Err(e);
+        }
+
+        Ok(ReauthRes {
+            expires_in: ttl_sec,
+        })
+    }
+
+    /// 재인증 — 비밀번호 / TOTP(백업 코드) / 메일 코드 중 하나로 본인 재확인 후
+    /// `ak:reauth:{session_id}` 에 `REAUTH_TTL_SEC` 동안 유효한 표식을 남긴다
+    pub async fn reauth(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        req: ReauthReq,
+    ) -> AppResult<ReauthRes> {
+        req.validate().map_err(|_| AppError::ValidationGeneric)?;
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        // 사용자당 시도 횟수 제한 (MFA 코드 검증과 같은 한도)
+        let rl_key = format!("rl:reauth:{}", user_id);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn
+            .expire(&rl_key, st.cfg.rate_limit_mfa_window_sec)
+            .await?;
+        if attempts > st.cfg.rate_limit_mfa_max {
+            let _: () = redis_conn
+                .del(format!("ak:reauth_code:{}", session_id))
+                .await
+                .unwrap_or(());
+            return Err(AppError::TooManyRequests(
+                "REAUTH_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        let user = AuthRepo::find_user_login_info_by_id(&st.db, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let verified = match req.method {
+            ReauthMethod::Password => {
+                let password = req
+                    .password
+                    .as_deref()
+                    .ok_or_else(|| AppError::BadRequest("REAUTH_PASSWORD_REQUIRED".into()))?;
+                let hash = user
+                    .user_password
+                    .as_deref()
+                    .ok_or_else(|| AppError::BadRequest("REAUTH_METHOD_UNAVAILABLE".into()))?;
+                crate::api::auth::password::verify_password(password, hash)?
+            }
+            ReauthMethod::Totp => {
+                let code = req
+                    .code
+                    .as_deref()
+                    .ok_or_else(|| AppError::BadRequest("REAUTH_CODE_REQUIRED".into()))?;
+                if !user.user_mfa_enabled {
+                    return Err(AppError::BadRequest("REAUTH_METHOD_UNAVAILABLE".into()));
+                }
+                Self::verify_totp_or_backup_code(st, user_id, code).await?
+            }
+            ReauthMethod::EmailCode => {
+                let code = req
+                    .code
+                    .as_deref()
+                    .ok_or_else(|| AppError::BadRequest("REAUTH_CODE_REQUIRED".into()))?;
+                let code_key = format!("ak:reauth_code:{}", session_id);
+                let expected: Option<String> = redis_conn
+                    .get(&code_key)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                let expected =
+                    expected.ok_or_else(|| AppError::Unauthorized("REAUTH_CODE_EXPIRED".into()))?;
+                let computed = crate::api::user::service::UserService::hmac_verification_code(
+                    &st.cfg.hmac_key,
+                    session_id,
+                    code.trim(),
+                );
+                // 일회용 — 동시 요청 중 DEL 에 성공한 쪽만 통과
+                Self::constant_time_eq(computed.as_bytes(), expected.as_bytes())
+                    && redis_conn
+                        .del::<_, i64>(&code_key)
+                        .await
+                        .map_err(|e| AppError::Internal(e.to_string()))?
+                        == 1
+            }
+        };
+        if !verified {
+            return Err(AppError::Unauthorized("REAUTH_INVALID_CREDENTIALS".into()));
+        }
+
+        let _: () = redis_conn.del(&rl_key).await.unwrap_or(());
+        let _: () = redis_conn
+            .set_ex(
+                format!("ak:reauth:{}", session_id),
+                user_id,
+                st.cfg.reauth_ttl_sec as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        info!(user_id, method = ?req.method, "Re-authentication succeeded");
+
+        Ok(ReauthRes {
+            expires_in: st.cfg.reauth_ttl_sec,
+        })
+    }
+
+    /// 보안 설정 변경 가드 — 현재 세션이 `REAUTH_TTL_SEC` 안에 재인증했는지 확인
+    /// (없으면 403 `AUTH_403_REAUTH_REQUIRED` → 클라이언트는 `POST /auth/reauth` 후 재시도)
+    pub async fn require_recent_auth(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+    ) -> AppResult<()> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let marker: Option<i64> = redis_conn
+            .get(format!("ak:reauth:{}", session_id))
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        if marker != Some(user_id) {
+            return Err(AppError::Forbidden("AUTH_403_REAUTH_REQUIRED".into()));
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Passkey (WebAuthn)
+    // =========================================================================
+
+    /// 패스키 등록 옵션 발급 — 재인증 필수. 챌린지는 Redis 에 세레모니 ID 로 저장 (일회용)
+    pub async fn passkey_register_options(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+    ) -> AppResult<PasskeyOptionsRes> {
+        Self::require_recent_auth(st, user_id, session_id).await?;
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let rl_key = format!("rl:passkey_register:{}", user_id);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn.expire(&rl_key, 3600).await?; // 10회/1시간
+        if attempts > 10 {
+            return Err(AppError::TooManyRequests(
+                "PASSKEY_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        let user = user_repo::find_user(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal("User not found".into()))?;
+        let existing = AuthRepo::find_passkey_credentials(&st.db, user_id).await?;
+        if existing.len() >= MAX_PASSKEYS_PER_USER {
+            return Err(AppError::Conflict("PASSKEY_LIMIT_REACHED".into()));
+        }
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto
+            .decrypt(&user.email, "users.user_email")
+            .unwrap_or_else(|_| format!("user_{}", user_id));
+
+        let ceremony_id = Uuid::new_v4().to_string();
+        let challenge = webauthn::generate_challenge();
+        let exclude: Vec<String> = existing.into_iter().map(|(id, _)| id).collect();
+        let public_key = webauthn::creation_options(
+            &st.cfg.webauthn_rp_id,
+            &st.cfg.webauthn_rp_name,
+            user_id,
+            &email,
+            user.nickname.as_deref().unwrap_or(&email),
+            &challenge,
+            &exclude,
+            st.cfg.webauthn_challenge_ttl_sec * 1000,
+        );
+
+        let pending = serde_json::json!({ "user_id": user_id, "challenge": challenge });
+        let _: () = redis_conn
+            .set_ex(
+                format!("ak:webauthn:reg:{}", ceremony_id),
+                pending.to_string(),
+                st.cfg.webauthn_challenge_ttl_sec as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        Ok(PasskeyOptionsRes {
+            ceremony_id: Some(ceremony_id),
+            public_key,
+        })
+    }
+
+    /// 패스키 등록 완료 — 재인증 재확인 + clientData / authenticator data 검증 후 공개키 저장,
+    /// 보안 설정 변경 알림 메일 발송
+    pub async fn passkey_register(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        req: PasskeyRegisterReq,
+    ) -> AppResult<PasskeyRes> {
+        req.validate().map_err(|_| AppError::ValidationGeneric)?;
+        Self::require_recent_auth(st, user_id, session_id).await?;
+        let pending = Self::take_webauthn_pending(st, "reg", &req.ceremony_id).await?;
+        if pending["user_id"].as_i64() != Some(user_id) {
+            return Err(AppError::Unauthorized("PASSKEY_CHALLENGE_EXPIRED".into()));
+        }
+        let challenge = pending["challenge"].as_str().unwrap_or_default();
+
+        let credential = &req.credential;
+        let to_bad_request = |e: webauthn::WebauthnError| AppError::BadRequest(e.code().into());
+        if credential.credential_type != "public-key" || credential.id != credential.raw_id {
+            return Err(to_bad_request(webauthn::WebauthnError::Malformed));
+        }
+        let client_data_json = webauthn::b64url_decode(&credential.response.client_data_json)
+            .map_err(to_bad_request)?;
+        webauthn::verify_client_data(
+            &client_data_json,
+            webauthn::Ceremony::Create,
+            challenge,
+            &st.cfg.webauthn_origins,
+        )
+        .map_err(to_bad_request)?;
+
+        let attestation_object = webauthn::b64url_decode(&credential.response.attestation_object)
+            .map_err(to_bad_request)?;
+        let auth_data_bytes =
+            webauthn::attestation_auth_data(&attestation_object).map_err(to_bad_request)?;
+        let auth_data =
+            webauthn::AuthenticatorData::parse(&auth_data_bytes).map_err(to_bad_request)?;
+        auth_data
+            .verify(&st.cfg.webauthn_rp_id, false)
+            .map_err(to_bad_request)?;
+        let attested = auth_data
+            .attested
+            .as_ref()
+            .ok_or_else(|| to_bad_request(webauthn::WebauthnError::Malformed))?;
+
+        let credential_id = URL_SAFE_NO_PAD.encode(&attested.credential_id);
+        if webauthn::b64url_decode(&credential.raw_id).map_err(to_bad_request)?
+            != attested.credential_id
+        {
+            return Err(to_bad_request(webauthn::WebauthnError::Malformed));
+        }
+        let alg = webauthn::cose_key_algorithm(&attested.public_key).map_err(to_bad_request)?;
+
+        let existing = AuthRepo::find_passkey_credentials(&st.db, user_id).await?;
+        if existing.len() >= MAX_PASSKEYS_PER_USER {
+            return Err(AppError::Conflict("PASSKEY_LIMIT_REACHED".into()));
+        }
+        let name = req
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("패스키 {}", existing.len() + 1));
+        let aaguid = Some(uuid::Uuid::from_bytes(attested.aaguid)).filter(|u| !u.is_nil());
+
+        let saved = AuthRepo::insert_passkey(
+            &st.db,
+            &NewPasskey {
+                user_id,
+                credential_id: &credential_id,
+                public_key: &attested.public_key,
+                alg: alg as i32,
+                sign_count: i64::from(auth_data.sign_count),
+                name: &name,
+                transports: &credential.response.transports,
+                aaguid,
+                backup_eligible: auth_data.backup_eligible(),
+                backed_up: auth_data.backed_up(),
+            },
+        )
+        .await?
+        .ok_or_else(|| AppError::Conflict("PASSKEY_ALREADY_REGISTERED".into()))?;
+
+        info!(
+            "Passkey {} registered for user {} (alg {})",
+            saved.passkey_id, user_id, alg
+        );
+        crate::api::user::service::UserService::send_security_notice(
+            st,
+            user_id,
+            "패스키 등록",
+            &saved.name,
+        )
+        .await;
+        Ok(saved)
+    }
+
+    pub async fn list_passkeys(st: &AppState, user_id: i64) -> AppResult<PasskeyListRes> {
+        let items = AuthRepo::find_passkeys(&st.db, user_id).await?;
+        Ok(PasskeyListRes { items })
+    }
+
+    pub async fn rename_passkey(
+        st: &AppState,
+        user_id: i64,
+        passkey_id: i64,
+        req: PasskeyRenameReq,
+    ) -> AppResult<PasskeyRes> {
+        req.validate().map_err(|_| AppError::ValidationGeneric)?;
+        let name = req.name.trim();
+        if name.is_empty() {
+            return Err(AppError::BadRequest("PASSKEY_NAME_REQUIRED".into()));
+        }
+        AuthRepo::rename_passkey(&st.db, user_id, passkey_id, name)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 패스키 삭제 — 재인증 필요, 마지막 로그인 수단이면 거부 (소셜 계정 연결 해제와 같은 규칙),
+    /// 보안 설정 변경 알림 메일 발송
+    pub async fn delete_passkey(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        passkey_id: i64,
+    ) -> AppResult<()> {
+        Self::require_recent_auth(st, user_id, session_id).await?;
+        let mut tx = st.db.begin().await?;
+        let counts = user_repo::lock_login_method_counts_tx(&mut tx, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let name = AuthRepo::delete_passkey_tx(&mut tx, user_id, passkey_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if counts.total() <= 1 {
+            return Err(AppError::Conflict("LAST_LOGIN_METHOD".into()));
+        }
+        tx.commit().await?;
+        info!("Passkey {} deleted by user {}", passkey_id, user_id);
+        crate::api::user::service::UserService::send_security_notice(
+            st,
+            user_id,
+            "패스키 삭제",
+            &name,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// 패스키 로그인 옵션 발급 — allowCredentials 없이 discoverable credential 로 계정 선택
+    pub async fn passkey_login_options(
+        st: &AppState,
+        login_ip: &str,
+    ) -> AppResult<PasskeyOptionsRes> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let rl_key = format!("rl:passkey_login:{}", login_ip);
+        let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+        let _: () = redis_conn
+            .expire(&rl_key, st.cfg.rate_limit_login_window_sec)
+            .await?;
+        if attempts > st.cfg.rate_limit_login_max {
+            return Err(AppError::TooManyRequests(
+                "AUTH_429_TOO_MANY_ATTEMPTS".into(),
+            ));
+        }
+
+        let ceremony_id = Uuid::new_v4().to_string();
+        let challenge = webauthn::generate_challenge();
+        let public_key = webauthn::request_options(
+            &st.cfg.webauthn_rp_id,
+            &challenge,
+            &[],
+            "required",
+            st.cfg.webauthn_challenge_ttl_sec * 1000,
+        );
+
+        let pending = serde_json::json!({ "challenge": challenge });
+        let _: () = redis_conn
+            .set_ex(
+                format!("ak:webauthn:auth:{}", ceremony_id),
+                pending.to_string(),
+                st.cfg.webauthn_challenge_ttl_sec as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        Ok(PasskeyOptionsRes {
+            ceremony_id: Some(ceremony_id),
+            public_key,
+        })
+    }
+
+    /// 패스키 로그인 (비밀번호 없는 1차 로그인)
+    ///
+    /// user verification (생체/PIN) 필수 — 소지 + 인증기 잠금 해제로 이미 다중 요소이므로
+    /// TOTP MFA 챌린지는 생략한다.
+    pub async fn passkey_login(
+        st: &AppState,
+        req: PasskeyLoginReq,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<(LoginRes, Cookie<'static>, i64, String)> {
+        req.validate().map_err(|_| AppError::ValidationGeneric)?;
+        let pending = Self::take_webauthn_pending(st, "auth", &req.ceremony_id).await?;
+        let challenge = pending["challenge"].as_str().unwrap_or_default();
+
+        let user_id =
+            Self::verify_passkey_assertion(st, &req.credential, challenge, true, None).await?;
+
+        let user_info = AuthRepo::find_user_login_info_by_id(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("PASSKEY_NOT_RECOGNIZED".into()))?;
+        if !user_info.user_state {
+            return Err(AppError::Forbidden("ACCOUNT_DISABLED".to_string()));
+        }
+        if !user_info.user_check_email {
+            let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+            let decrypted_email = crypto
+                .decrypt(&user_info.user_email, "users.user_email")
+                .unwrap_or_default();
+            return Err(AppError::Forbidden(format!(
+                "AUTH_403_EMAIL_NOT_VERIFIED:{}",
+                decrypted_email
+            )));
+        }
+
+        // 로그인 성공 → IP rate limit 초기화
+        if let Ok(mut redis_conn) = st.redis.get().await {
+            let _: () = redis_conn
+                .del(format!("rl:passkey_login:{}", login_ip))
+                .await
+                .unwrap_or(());
+        }
+
+        Self::create_oauth_session(
+            st,
+            user_id,
+            user_info.user_auth,
+            "passkey",
+            login_ip,
+            user_agent,
+            parsed_ua,
+            LoginAssurance::SecondFactor,
+        )
+        .await
+    }
+
+    /// MFA 2차 인증용 패스키 옵션 — mfa_token 의 사용자 패스키만 허용
+    pub async fn passkey_mfa_options(
+        st: &AppState,
+        mfa_token: &str,
+    ) -> AppResult<PasskeyOptionsRes> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
 
+        // pending 은 mfa_login 에서 소비되므로 여기서는 조회만
+        let mfa_key = format!("ak:mfa_pending:{}", mfa_token);
+        let pending_json: Option<String> = redis_conn
+            .get(&mfa_key)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
         let Some(pending_json) = pending_json else {
             return Err(AppError::Unauthorized("MFA_TOKEN_EXPIRED".into()));
         };
-
-        // 즉시 삭제 (일회용)
-        let _: () = redis_conn.del(&mfa_key).await.unwrap_or(());
-
         let pending: serde_json::Value = serde_json::from_str(&pending_json)
             .map_err(|e| AppError::Internal(format!("MFA pending parse error: {}", e)))?;
-
         let user_id = pending["user_id"]
             .as_i64()
             .ok_or_else(|| AppError::Internal("MFA pending missing user_id".into()))?;
-        let user_auth_str = pending["user_auth"].as_str().unwrap_or("Learner");
-        let user_auth: UserAuth = match user_auth_str {
-            "HYMN" => UserAuth::Hymn,
-            "Admin" => UserAuth::Admin,
-            "Manager" => UserAuth::Manager,
-            _ => UserAuth::Learner,
+
+        let credentials = AuthRepo::find_passkey_credentials(&st.db, user_id).await?;
+        if credentials.is_empty() {
+            return Err(AppError::BadRequest("PASSKEY_NOT_REGISTERED".into()));
+        }
+
+        let challenge = webauthn::generate_challenge();
+        let public_key = webauthn::request_options(
+            &st.cfg.webauthn_rp_id,
+            &challenge,
+            &credentials,
+            "preferred",
+            st.cfg.webauthn_challenge_ttl_sec * 1000,
+        );
+        let _: () = redis_conn
+            .set_ex(
+                format!("ak:webauthn:mfa:{}", mfa_token),
+                &challenge,
+                st.cfg.mfa_token_ttl_sec as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        Ok(PasskeyOptionsRes {
+            ceremony_id: None,
+            public_key,
+        })
+    }
+
+    /// Redis 에서 세레모니 상태 조회 + 삭제 (일회용, GETDEL 로 원자적)
+    async fn take_webauthn_pending(
+        st: &AppState,
+        kind: &str,
+        ceremony_id: &str,
+    ) -> AppResult<serde_json::Value> {
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let key = format!("ak:webauthn:{}:{}", kind, ceremony_id);
+        let pending_json: Option<String> = redis_conn
+            .get_del(&key)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        let pending_json = pending_json
+            .ok_or_else(|| AppError::Unauthorized("PASSKEY_CHALLENGE_EXPIRED".into()))?;
+        serde_json::from_str(&pending_json)
+            .map_err(|e| AppError::Internal(format!("WebAuthn pending parse error: {}", e)))
+    }
+
+    /// 패스키 assertion 검증 후 사용자 ID 반환 (카운터 / 최근 사용 시각 갱신)
+    ///
+    /// expected_user_id 가 있으면 (MFA) 해당 사용자의 패스키만 허용.
+    async fn verify_passkey_assertion(
+        st: &AppState,
+        credential: &PasskeyAssertionCredential,
+        challenge: &str,
+        require_uv: bool,
+        expected_user_id: Option<i64>,
+    ) -> AppResult<i64> {
+        let to_unauthorized = |e: webauthn::WebauthnError| AppError::Unauthorized(e.code().into());
+        let not_recognized = || AppError::Unauthorized("PASSKEY_NOT_RECOGNIZED".into());
+
+        if credential.credential_type != "public-key" || credential.id != credential.raw_id {
+            return Err(to_unauthorized(webauthn::WebauthnError::Malformed));
+        }
+        // 저장 형식 (base64url, padding 없음) 으로 정규화
+        let credential_id = URL_SAFE_NO_PAD
+            .encode(webauthn::b64url_decode(&credential.raw_id).map_err(to_unauthorized)?);
+
+        let passkey = AuthRepo::find_passkey_by_credential_id(&st.db, &credential_id)
+            .await?
+            .ok_or_else(not_recognized)?;
+        if expected_user_id.is_some_and(|id| id != passkey.user_id) {
+            return Err(not_recognized());
+        }
+        if let Some(handle) = credential.response.user_handle.as_deref() {
+            if !handle.is_empty() && webauthn::parse_user_handle(handle) != Some(passkey.user_id) {
+                return Err(not_recognized());
+            }
+        }
+
+        let client_data_json = webauthn::b64url_decode(&credential.response.client_data_json)
+            .map_err(to_unauthorized)?;
+        webauthn::verify_client_data(
+            &client_data_json,
+            webauthn::Ceremony::Get,
+            challenge,
+            &st.cfg.webauthn_origins,
+        )
+        .map_err(to_unauthorized)?;
+
+        let auth_data_bytes = webauthn::b64url_decode(&credential.response.authenticator_data)
+            .map_err(to_unauthorized)?;
+        let auth_data =
+            webauthn::AuthenticatorData::parse(&auth_data_bytes).map_err(to_unauthorized)?;
+        auth_data
+            .verify(&st.cfg.webauthn_rp_id, require_uv)
+            .map_err(to_unauthorized)?;
+
+        let signature =
+            webauthn::b64url_decode(&credential.response.signature).map_err(to_unauthorized)?;
+        webauthn::verify_assertion_signature(
+            &passkey.passkey_public_key,
+            &auth_data_bytes,
+            &client_data_json,
+            &signature,
+        )
+        .map_err(to_unauthorized)?;
+
+        if !webauthn::sign_count_advanced(passkey.passkey_sign_count, auth_data.sign_count) {
+            warn!(
+                "Passkey {} sign count regressed ({} -> {}) — possible cloned authenticator",
+                passkey.passkey_id, passkey.passkey_sign_count, auth_data.sign_count
+            );
+            return Err(AppError::Unauthorized("PASSKEY_COUNTER_REGRESSION".into()));
+        }
+
+        AuthRepo::update_passkey_usage(
+            &st.db,
+            passkey.passkey_id,
+            i64::from(auth_data.sign_count),
+            auth_data.backed_up(),
+        )
+        .await?;
+
+        Ok(passkey.user_id)
+    }
+
+    // =========================================================================
+    // 비밀번호 없는 로그인 (이메일 매직 링크 / 6자리 일회용 코드)
+    // =========================================================================
+
+    /// 매직 링크 / 로그인 코드 메일 발송.
+    ///
+    /// 계정 존재 여부를 드러내지 않도록 발송 여부와 무관하게 같은 응답을 준다.
+    /// 활성 + 이메일 인증 완료 계정에만 실제로 발송한다.
+    pub async fn passwordless_request(
+        st: &AppState,
+        req: PasswordlessRequestReq,
+        client_ip: String,
+    ) -> AppResult<PasswordlessRequestRes> {
+        if let Err(e) = req.validate() {
+            tracing::debug!(error = %e, "Validation failed (auth endpoint)");
+            return Err(AppError::ValidationGeneric);
+        }
+        let email = req.email.trim().to_lowercase();
+        let ttl_sec = st.cfg.passwordless_login_ttl_sec;
+        let res = PasswordlessRequestRes {
+            message: "If the account exists, a sign-in email has been sent.".to_string(),
+            expires_in: ttl_sec,
         };
-        let pending_ip = pending["login_ip"].as_str().unwrap_or("").to_string();
-        let pending_ua = pending["user_agent"].as_str().map(|s| s.to_string());
-        let pending_device = pending["device"].as_str().unwrap_or("other").to_string();
-        let pending_browser = pending["browser"].as_str().map(|s| s.to_string());
-        let pending_os = pending["os"].as_str().map(|s| s.to_string());
-        let pending_method = pending["login_method"]
-            .as_str()
-            .unwrap_or("login")
-            .to_string();
 
-        // [Step 2] Rate limit: MFA 코드 검증
-        let rl_key = format!("rl:mfa:{}:{}", user_id, login_ip);
+        // [Step 1] Rate Limiting (blind index + IP, 그리고 IP 무관 이메일 단위 —
+        // IP 를 바꿔 가며 한 사람의 메일함을 채우는 것 차단)
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let idx = crypto.blind_index(&email)?;
+        let rl_key = format!("rl:pwless:{}:{}", idx, client_ip);
+        let rl_email_key = format!("rl:pwless_email:{}", idx);
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
         let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
         let _: () = redis_conn
-            .expire(&rl_key, st.cfg.rate_limit_mfa_window_sec)
+            .expire(&rl_key, st.cfg.rate_limit_email_window_sec)
             .await?;
-        if attempts > st.cfg.rate_limit_mfa_max {
+        let email_attempts: i64 = redis_conn.incr(&rl_email_key, 1).await?;
+        let _: () = redis_conn
+            .expire(&rl_email_key, st.cfg.rate_limit_email_window_sec)
+            .await?;
+        if attempts > st.cfg.rate_limit_email_max || email_attempts > st.cfg.rate_limit_email_max {
             return Err(AppError::TooManyRequests(
-                "MFA_429_TOO_MANY_ATTEMPTS".into(),
+                "AUTH_429_TOO_MANY_LOGIN_EMAILS".into(),
             ));
         }
 
-        // [Step 3] TOTP 코드 또는 백업 코드 검증
-        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
-        let encrypted_secret = AuthRepo::find_mfa_secret(&st.db, user_id)
-            .await?
-            .ok_or_else(|| AppError::Internal("MFA secret not found".into()))?;
-        let secret_base32 = crypto.decrypt(&encrypted_secret, "users.user_mfa_secret")?;
+        // [Step 2] 이메일 클라이언트 확인 (설정 수준이라 계정 존재 여부와 무관)
+        let email_sender = st
+            .email
+            .as_ref()
+            .ok_or_else(|| AppError::ServiceUnavailable("Email service not configured".into()))?;
+
+        // [Step 3] 발송 대상 확인 — 없음/비활성/미인증이면 발송 없이 동일 응답
+        let user = AuthRepo::find_user_by_email_idx(&st.db, &idx).await?;
+        let Some(user) = user.filter(|u| u.user_state && u.user_check_email) else {
+            info!("Passwordless login requested for unknown or ineligible account");
+            return Ok(res);
+        };
+        // 기관 SSO 전용 도메인 계정은 메일을 보내지 않는다 (응답은 동일)
+        if Self::is_sso_required(st, &email, user.user_auth).await? {
+            info!(
+                user_id = user.user_id,
+                "Passwordless login skipped: SSO required"
+            );
+            return Ok(res);
+        }
+
+        // [Step 4] 토큰/코드 생성 및 Redis 저장 (원문은 메일에만)
+        let expires_in_min = (ttl_sec / 60) as i32;
+        let (store_key, template) = match req.method {
+            PasswordlessMethod::Link => {
+                let token = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+                let link_key = format!("ak:pwless_link:{}", hex::encode(Sha256::digest(&token)));
+                let _: () = redis_conn
+                    .set_ex(&link_key, user.user_id, ttl_sec as u64)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                let login_url = format!(
+                    "{}/login/magic?token={}",
+                    st.cfg.frontend_url.trim_end_matches('/'),
+                    token
+                );
+                (
+                    link_key,
+                    EmailTemplate::MagicLinkLogin {
+                        login_url,
+                        expires_in_min,
+                    },
+                )
+            }
+            PasswordlessMethod::Code => {
+                let code = Self::generate_verification_code();
+                let code_key = format!("ak:pwless_code:{}", idx);
+                let code_hash = crate::api::user::service::UserService::hmac_verification_code(
+                    &st.cfg.hmac_key,
+                    &email,
+                    &code,
+                );
+                // 시도 횟수(rl:pwless_code)는 코드를 새로 받아도 초기화하지 않는다
+                let _: () = redis_conn
+                    .set_ex(&code_key, &code_hash, ttl_sec as u64)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                (
+                    code_key,
+                    EmailTemplate::LoginCode {
+                        code,
+                        expires_in_min,
+                    },
+                )
+            }
+        };
+
+        // [Step 5] 이메일 발송 (실패 시 토큰 폐기 + rate limit 롤백)
+        if let Err(e) =
+            crate::external::email::send_templated(email_sender.as_ref(), &email, template).await
+        {
+            let _: () = redis_conn.del(&store_key).await.unwrap_or(());
+            let _: () = redis_conn.decr(&rl_key, 1).await.unwrap_or(());
+            let _: () = redis_conn.decr(&rl_email_key, 1).await.unwrap_or(());
+            return Err(e);
+        }
+
+        info!(
+            user_id = user.user_id,
+            ip = %client_ip,
+            method = ?req.method,
+            "Passwordless login email sent"
+        );
+        Ok(res)
+    }
+
+    /// 비밀번호 없는 로그인 완료 — 링크 토큰 또는 이메일 + 코드 확인 (일회용).
+    ///
+    /// 세션 발급은 다른 로그인과 같은 `create_oauth_session` (동시 세션 제한 / refresh / 위험 평가).
+    /// MFA 사용 계정은 `login` 과 같이 MFA 챌린지를 반환한다.
+    pub async fn passwordless_login(
+        st: &AppState,
+        req: PasswordlessVerifyReq,
+        login_ip: String,
+        user_agent: Option<String>,
+        parsed_ua: crate::api::auth::handler::ParsedUa,
+    ) -> AppResult<LoginOutcome> {
+        if let Err(e) = req.validate() {
+            tracing::debug!(error = %e, "Validation failed (auth endpoint)");
+            return Err(AppError::ValidationGeneric);
+        }
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        // [Step 1] 링크 토큰 / 코드 확인 → user 조회
+        let (user, login_method) = match (&req.token, &req.email, &req.code) {
+            (Some(token), _, _) => {
+                let link_key = format!(
+                    "ak:pwless_link:{}",
+                    hex::encode(Sha256::digest(token.as_bytes()))
+                );
+                // 일회용 — GETDEL 로 조회와 삭제를 원자적으로
+                let user_id: Option<i64> = redis_conn
+                    .get_del(&link_key)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                let Some(user_id) = user_id else {
+                    return Err(AppError::Unauthorized("PASSWORDLESS_LINK_EXPIRED".into()));
+                };
+
+                let user = AuthRepo::find_user_login_info_by_id(&st.db, user_id)
+                    .await?
+                    .ok_or_else(|| AppError::Unauthorized("PASSWORDLESS_LINK_EXPIRED".into()))?;
+                (user, "email_link")
+            }
+            (None, Some(email), Some(code)) => {
+                let email = email.trim().to_lowercase();
+                let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+                let idx = crypto.blind_index(&email)?;
+                let code_key = format!("ak:pwless_code:{}", idx);
+
+                // 코드(= 이메일)당 시도 횟수 제한 — 초과 시 코드 폐기 (IP 분산 대입 차단)
+                let rl_key = format!("rl:pwless_code:{}", idx);
+                let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
+                let _: () = redis_conn
+                    .expire(&rl_key, st.cfg.passwordless_login_ttl_sec)
+                    .await?;
+                if attempts > st.cfg.rate_limit_mfa_max {
+                    let _: () = redis_conn.del(&code_key).await.unwrap_or(());
+                    return Err(AppError::TooManyRequests(
+                        "AUTH_429_TOO_MANY_CODE_ATTEMPTS".into(),
+                    ));
+                }
+
+                // 일회용 — 남은 TTL 과 함께 GETDEL (MULTI) 로 꺼내 동시 요청 중 하나만 코드를 받는다.
+                // 틀린 코드면 남은 TTL 로 되돌린다 (NX — 그사이 새로 발급된 코드는 덮지 않음)
+                let (ttl_ms, stored_hash): (i64, Option<String>) = redis::pipe()
+                    .atomic()
+                    .pttl(&code_key)
+                    .get_del(&code_key)
+                    .query_async(&mut redis_conn)
+                    .await
+                    .map_err(|e| AppError::Internal(e.to_string()))?;
+                let Some(expected_hash) = stored_hash else {
+                    return Err(AppError::Unauthorized(
+                        "AUTH_401_INVALID_OR_EXPIRED_CODE".into(),
+                    ));
+                };
+                let computed_hash = crate::api::user::service::UserService::hmac_verification_code(
+                    &st.cfg.hmac_key,
+                    &email,
+                    code.trim(),
+                );
+                if !Self::constant_time_eq(computed_hash.as_bytes(), expected_hash.as_bytes()) {
+                    if ttl_ms > 0 {
+                        let _: () = redis::cmd("SET")
+                            .arg(&code_key)
+                            .arg(&expected_hash)
+                            .arg("PX")
+                            .arg(ttl_ms)
+                            .arg("NX")
+                            .query_async(&mut redis_conn)
+                            .await
+                            .unwrap_or(());
+                    }
+                    return Err(AppError::Unauthorized(
+                        "AUTH_401_INVALID_OR_EXPIRED_CODE".into(),
+                    ));
+                }
+                let _: () = redis_conn.del(&rl_key).await.unwrap_or(());
+
+                let user = AuthRepo::find_user_by_email_idx(&st.db, &idx)
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::Unauthorized("AUTH_401_INVALID_OR_EXPIRED_CODE".into())
+                    })?;
+                (user, "email_code")
+            }
+            _ => {
+                return Err(AppError::BadRequest(
+                    "PASSWORDLESS_TOKEN_OR_CODE_REQUIRED".into(),
+                ))
+            }
+        };
+
+        // [Step 2] 발송 이후 계정 상태 변경 재확인
+        if !user.user_state {
+            return Err(AppError::Forbidden("ACCOUNT_DISABLED".to_string()));
+        }
+
+        // [Step 3] MFA 체크 (login 과 동일 — mfa_login 이 login_method 를 이어받음)
+        if user.user_mfa_enabled {
+            let mfa_token = Uuid::new_v4().to_string();
+            let pending_data = serde_json::json!({
+                "user_id": user.user_id,
+                "user_auth": format!("{:?}", user.user_auth),
+                "login_ip": login_ip,
+                "user_agent": user_agent,
+                "device": parsed_ua.device,
+                "browser": parsed_ua.browser,
+                "os": parsed_ua.os,
+                "login_method": login_method
+            });
+            let _: () = redis_conn
+                .set_ex(
+                    format!("ak:mfa_pending:{}", mfa_token),
+                    pending_data.to_string(),
+                    st.cfg.mfa_token_ttl_sec as u64,
+                )
+                .await
+                .map_err(|e| AppError::Internal(e.to_string()))?;
+
+            return Ok(LoginOutcome::MfaChallenge {
+                mfa_token,
+                user_id: user.user_id,
+            });
+        }
+
+        // [Step 4] 세션 발급
+        let (login_res, cookie, ttl, refresh_token) = Self::create_oauth_session(
+            st,
+            user.user_id,
+            user.user_auth,
+            login_method,
+            login_ip,
+            user_agent,
+            parsed_ua,
+            LoginAssurance::EmailPossession,
+        )
+        .await?;
+
+        Ok(LoginOutcome::Success(Box::new(LoginSuccess {
+            login_res,
+            cookie,
+            refresh_token,
+            ttl,
+        })))
+    }
 
-        let secret_bytes = Secret::Encoded(secret_base32)
-            .to_bytes()
-            .map_err(|e| AppError::Internal(format!("TOTP secret decode error: {}", e)))?;
-        let totp = TOTP::new(Algorithm::SHA1, 6, 1, 30, secret_bytes, None, String::new())
-            .map_err(|e| AppError::Internal(format!("TOTP creation error: {}", e)))?;
+    // =========================================================================
+    // 로그인 위험 평가 (새 국가 / 새 기기 / 불가능한 이동 속도)
+    // =========================================================================
 
-        let code_valid = if req.code.len() == 6 && req.code.chars().all(|c| c.is_ascii_digit()) {
-            // TOTP 코드 (6자리 숫자)
-            totp.check_current(&req.code)
-                .map_err(|e| AppError::Internal(format!("TOTP check error: {}", e)))?
-        } else {
-            false
+    /// 세션 발급 전 위험 평가.
+    ///
+    /// 평가 자체는 best-effort (이력 조회 실패 시 로그인 진행). `step_up` 조치 + 2차 인증 없는
+    /// 로그인이면 메일 인증 코드를 보내고 `AUTH_403_STEP_UP_REQUIRED:{step_up_token}` 을 반환한다.
+    #[allow(clippy::too_many_arguments)]
+    async fn evaluate_login_risk(
+        st: &AppState,
+        user_id: i64,
+        user_auth: UserAuth,
+        login_method: &str,
+        geo: &GeoLocation,
+        parsed_ua: &crate::api::auth::handler::ParsedUa,
+        login_ip: &str,
+        user_agent: Option<&str>,
+        assurance: LoginAssurance,
+    ) -> AppResult<Option<PendingLoginRisk>> {
+        // step-up 완료 로그인은 챌린지 시점에 이미 평가·기록됨
+        if matches!(assurance, LoginAssurance::StepUp { .. }) {
+            return Ok(None);
+        }
+        let Some(mut action) = st.cfg.login_risk_action else {
+            return Ok(None);
         };
 
-        if !code_valid {
-            // 백업 코드 시도 (8자 영숫자)
-            let backup_valid = Self::try_backup_code(st, user_id, &req.code, &crypto).await?;
-            if !backup_valid {
-                return Err(AppError::Unauthorized("MFA_INVALID_CODE".into()));
+        let history = match AuthRepo::find_login_risk_history(
+            &st.db,
+            user_id,
+            st.cfg.login_risk_history_days,
+        )
+        .await
+        {
+            Ok(h) => h,
+            Err(e) => {
+                warn!(error = %e, user_id, "Login risk history lookup failed");
+                return Ok(None);
             }
+        };
+        let assessment = risk::assess(
+            &history,
+            &risk::LoginAttempt {
+                country: geo.country_code.as_deref(),
+                latitude: geo.latitude,
+                longitude: geo.longitude,
+                browser: parsed_ua.browser.as_deref(),
+                os: parsed_ua.os.as_deref(),
+                at: chrono::Utc::now(),
+            },
+            st.cfg.login_risk_max_travel_kmh,
+        );
+        if !assessment.is_risky() {
+            return Ok(None);
         }
 
-        // [Step 4] 코드 검증 성공 → Rate limit 초기화
-        let _: () = redis_conn.del(&rl_key).await.unwrap_or(());
-
-        // [Step 5] 세션 생성 (pending 데이터 기반)
-        let parsed_ua = crate::api::auth::handler::ParsedUa {
-            os: pending_os,
-            browser: pending_browser,
-            device: pending_device,
-        };
+        if action == LoginRiskAction::StepUp && assurance == LoginAssurance::Single {
+            if st.email.is_some() {
+                return Err(Self::issue_login_step_up(
+                    st,
+                    user_id,
+                    user_auth,
+                    login_method,
+                    geo,
+                    parsed_ua,
+                    login_ip,
+                    user_agent,
+                    &assessment,
+                )
+                .await?);
+            }
+            // 코드를 보낼 수단이 없으면 잠금 대신 기록만
+            warn!(user_id, "Login risk step-up skipped: email not configured");
+            action = LoginRiskAction::Log;
+        }
 
-        // 세션 생성 (pending_method에 저장된 login_method 그대로 사용)
-        let (login_res, cookie, ttl, refresh_token) = Self::create_oauth_session(
-            st,
-            user_id,
-            user_auth,
-            &pending_method,
-            pending_ip,
-            pending_ua,
-            parsed_ua,
-        )
-        .await?;
-        Ok((login_res, cookie, ttl, refresh_token))
+        Ok(Some(PendingLoginRisk {
+            assessment,
+            step_up_passed: action == LoginRiskAction::StepUp,
+            action,
+        }))
     }
 
-    /// 백업 코드 검증 (일치 시 해당 코드 해시 제거)
-    async fn try_backup_code(
+    /// step-up 챌린지 발급 — 이벤트 기록 + 코드 메일. 반환값 = 클라이언트에 돌려줄 에러
+    #[allow(clippy::too_many_arguments)]
+    async fn issue_login_step_up(
         st: &AppState,
         user_id: i64,
-        code: &str,
-        crypto: &CryptoService<'_>,
-    ) -> AppResult<bool> {
-        let encrypted_codes = AuthRepo::find_mfa_backup_codes(&st.db, user_id).await?;
-        let Some(encrypted_codes) = encrypted_codes else {
-            return Ok(false);
-        };
+        user_auth: UserAuth,
+        login_method: &str,
+        geo: &GeoLocation,
+        parsed_ua: &crate::api::auth::handler::ParsedUa,
+        login_ip: &str,
+        user_agent: Option<&str>,
+        assessment: &risk::RiskAssessment,
+    ) -> AppResult<AppError> {
+        let email_sender = st
+            .email
+            .as_ref()
+            .ok_or_else(|| AppError::ServiceUnavailable("Email service not configured".into()))?;
+        let user = AuthRepo::find_user_login_info_by_id(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal("user not found for step-up".into()))?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto.decrypt(&user.user_email, "users.user_email")?;
 
-        let hashes_json = crypto.decrypt(&encrypted_codes, "users.user_mfa_backup_codes")?;
-        let mut hashes: Vec<String> = serde_json::from_str(&hashes_json)
-            .map_err(|e| AppError::Internal(format!("Backup codes parse error: {}", e)))?;
+        let reasons = assessment.reason_codes();
+        let risk_event_id = AuthRepo::insert_login_risk_event(
+            &st.db,
+            &NewLoginRiskEvent {
+                user_id,
+                session_id: None,
+                login_method,
+                reasons: &reasons,
+                action: LoginRiskAction::StepUp,
+                country: geo.country_code.as_deref(),
+                prev_country: assessment.prev_country.as_deref(),
+                city: geo.city.as_deref(),
+                device: Some(parsed_ua.device.as_str()),
+                browser: parsed_ua.browser.as_deref(),
+                os: parsed_ua.os.as_deref(),
+                distance_km: assessment.distance_km,
+                speed_kmh: assessment.speed_kmh,
+                step_up_passed: false,
+            },
+        )
+        .await?;
 
-        // 입력 코드 해시 계산
-        let input_hash = URL_SAFE_NO_PAD.encode(Sha256::digest(code.as_bytes()));
+        let step_up_token = Uuid::new_v4().to_string();
+        let code = Self::generate_verification_code();
+        let code_hash = crate::api::user::service::UserService::hmac_verification_code(
+            &st.cfg.hmac_key,
+            &step_up_token,
+            &code,
+        );
+        let pending_data = serde_json::json!({
+            "user_id": user_id,
+            "user_auth": format!("{:?}", user_auth),
+            "login_ip": login_ip,
+            "user_agent": user_agent,
+            "device": parsed_ua.device,
+            "browser": parsed_ua.browser,
+            "os": parsed_ua.os,
+            "login_method": login_method,
+            "code_hash": code_hash,
+            "risk_event_id": risk_event_id
+        });
+        let ttl_sec = st.cfg.verification_code_ttl_sec;
+        let step_up_key = format!("ak:login_step_up:{}", step_up_token);
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let _: () = redis_conn
+            .set_ex(&step_up_key, pending_data.to_string(), ttl_sec as u64)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
 
-        // 매치하는 해시 찾기
-        if let Some(pos) = hashes
-            .iter()
-            .position(|h| Self::constant_time_eq(h.as_bytes(), input_hash.as_bytes()))
+        if let Err(e) = crate::external::email::send_templated(
+            email_sender.as_ref(),
+            &email,
+            EmailTemplate::LoginStepUpCode {
+                code,
+                location: risk_location_label(geo),
+                device: risk_device_label(parsed_ua),
+                expires_in_min: (ttl_sec / 60) as i32,
+            },
+        )
+        .await
         {
-            // 사용된 코드 제거
-            hashes.remove(pos);
+            let _: () = redis_conn.del(&step_up_key).await.unwrap_or(());
+            return Err(e);
+        }
 
-            // 갱신된 해시 목록 저장
-            let updated_json =
-                serde_json::to_string(&hashes).map_err(|e| AppError::Internal(e.to_string()))?;
-            let encrypted_updated = crypto.encrypt(&updated_json, "users.user_mfa_backup_codes")?;
-            AuthRepo::update_mfa_backup_codes(&st.db, user_id, &encrypted_updated).await?;
+        info!(user_id, risk_event_id, reasons = ?reasons, "Login risk step-up challenge issued");
+        Ok(AppError::Forbidden(format!(
+            "AUTH_403_STEP_UP_REQUIRED:{}",
+            step_up_token
+        )))
+    }
 
-            info!(
-                "MFA backup code used for user {} ({} remaining)",
+    /// 세션 발급 후 위험 이벤트 기록 (+ alert 조치면 알림 메일). 실패해도 로그인은 유지
+    async fn record_login_risk(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        login_method: &str,
+        geo: &GeoLocation,
+        parsed_ua: &crate::api::auth::handler::ParsedUa,
+        pending: PendingLoginRisk,
+    ) {
+        let reasons = pending.assessment.reason_codes();
+        let risk_event_id = match AuthRepo::insert_login_risk_event(
+            &st.db,
+            &NewLoginRiskEvent {
                 user_id,
-                hashes.len()
-            );
-            Ok(true)
-        } else {
-            Ok(false)
+                session_id: Some(session_id),
+                login_method,
+                reasons: &reasons,
+                action: pending.action,
+                country: geo.country_code.as_deref(),
+                prev_country: pending.assessment.prev_country.as_deref(),
+                city: geo.city.as_deref(),
+                device: Some(parsed_ua.device.as_str()),
+                browser: parsed_ua.browser.as_deref(),
+                os: parsed_ua.os.as_deref(),
+                distance_km: pending.assessment.distance_km,
+                speed_kmh: pending.assessment.speed_kmh,
+                step_up_passed: pending.step_up_passed,
+            },
+        )
+        .await
+        {
+            Ok(id) => id,
+            Err(e) => {
+                warn!(error = %e, user_id, "Failed to record login risk event");
+                return;
+            }
+        };
+        info!(user_id, risk_event_id, reasons = ?reasons, action = ?pending.action, "Risky login detected");
+
+        if pending.action != LoginRiskAction::Alert {
+            return;
+        }
+        if let Err(e) =
+            Self::send_login_risk_alert(st, user_id, risk_event_id, geo, parsed_ua, &pending).await
+        {
+            warn!(error = %e, user_id, risk_event_id, "Failed to send login risk alert");
         }
     }
 
-    /// 사용자의 모든 세션을 무효화한다 — DB login 행을 revoked(reason)로, Redis 의 모든
-    /// ak:refresh / ak:session 키와 ak:user_sessions SET 을 일괄 삭제. mfa_disable 과
-    /// 계정 비활성화(ban/탈퇴, admin_update_user) 경로가 공유한다.
-    pub async fn invalidate_all_sessions(
+    async fn send_login_risk_alert(
         st: &AppState,
         user_id: i64,
-        reason: &str,
+        risk_event_id: i64,
+        geo: &GeoLocation,
+        parsed_ua: &crate::api::auth::handler::ParsedUa,
+        pending: &PendingLoginRisk,
     ) -> AppResult<()> {
-        let mut tx = st.db.begin().await?;
-        let sessions = AuthRepo::find_user_sessions_with_refresh_tx(&mut tx, user_id).await?;
-        AuthRepo::update_login_state_by_user_tx(&mut tx, user_id, "revoked", Some(reason)).await?;
-        tx.commit().await?;
+        let Some(email_sender) = st.email.as_ref() else {
+            return Ok(());
+        };
+        let user = AuthRepo::find_user_login_info_by_id(&st.db, user_id)
+            .await?
+            .ok_or_else(|| AppError::Internal("user not found for risk alert".into()))?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email = crypto.decrypt(&user.user_email, "users.user_email")?;
 
-        // Redis 세션 + 리프레시 토큰 일괄 정리 (단일 DEL 명령, best-effort).
+        // 링크 토큰 원문은 메일에만, Redis 에는 해시 키로 저장 (일회용)
+        let token = URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>());
+        let revoke_key = format!("ak:risk_revoke:{}", hex::encode(Sha256::digest(&token)));
+        let payload = serde_json::json!({ "user_id": user_id, "risk_event_id": risk_event_id });
         let mut redis_conn = st
             .redis
             .get()
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
-        let mut keys: Vec<String> = sessions
-            .iter()
-            .flat_map(|(sid, refresh_hash)| {
-                [
-                    format!("ak:refresh:{}", refresh_hash),
-                    format!("ak:session:{}", sid),
-                ]
-            })
-            .collect();
-        keys.push(format!("ak:user_sessions:{}", user_id));
-        let _: () = redis_conn.del(keys).await.unwrap_or(());
-        Ok(())
+        let _: () = redis_conn
+            .set_ex(
+                &revoke_key,
+                payload.to_string(),
+                (RISK_REVOKE_TTL_DAYS * 86_400) as u64,
+            )
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        crate::external::email::send_templated(
+            email_sender.as_ref(),
+            &email,
+            EmailTemplate::LoginRiskAlert {
+                signed_in_at: chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string(),
+                location: risk_location_label(geo),
+                device: risk_device_label(parsed_ua),
+                reasons: pending
+                    .assessment
+                    .reasons
+                    .iter()
+                    .map(|r| r.label().to_string())
+                    .collect(),
+                revoke_url: format!(
+                    "{}/security/revoke?token={}",
+                    st.cfg.frontend_url.trim_end_matches('/'),
+                    token
+                ),
+                expires_in_days: RISK_REVOKE_TTL_DAYS as i32,
+            },
+        )
+        .await
     }
 
-    /// MFA 비활성화 (HYMN 전용 — 다른 사용자의 MFA 해제)
-    pub async fn mfa_disable(
+    /// step-up 로그인 완료 — 메일 코드 확인 후 세션 발급
+    pub async fn login_step_up(
         st: &AppState,
-        auth_user_id: i64,
-        auth_user_auth: UserAuth,
-        target_user_id: i64,
-    ) -> AppResult<MfaDisableRes> {
-        // Rate limit: 반복 MFA 비활성화 시도 방지
+        req: StepUpLoginReq,
+    ) -> AppResult<(LoginRes, Cookie<'static>, i64, String)> {
+        req.validate().map_err(|_| AppError::ValidationGeneric)?;
         let mut redis_conn = st
             .redis
             .get()
             .await
             .map_err(|e| AppError::Internal(e.to_string()))?;
-        let rl_key = format!("rl:mfa_disable:{}", auth_user_id);
+
+        let step_up_key = format!("ak:login_step_up:{}", req.step_up_token);
+        let pending_json: Option<String> = redis_conn
+            .get(&step_up_key)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let Some(pending_json) = pending_json else {
+            return Err(AppError::Unauthorized("STEP_UP_TOKEN_EXPIRED".into()));
+        };
+
+        // 토큰당 시도 횟수 제한 — 초과 시 챌린지 폐기 (처음부터 다시 로그인)
+        let rl_key = format!("rl:step_up:{}", req.step_up_token);
         let attempts: i64 = redis_conn.incr(&rl_key, 1).await?;
-        let _: () = redis_conn.expire(&rl_key, 3600).await?; // 5회/1시간
-        if attempts > 5 {
+        let _: () = redis_conn
+            .expire(&rl_key, st.cfg.verification_code_ttl_sec)
+            .await?;
+        if attempts > st.cfg.rate_limit_mfa_max {
+            let _: () = redis_conn.del(&step_up_key).await.unwrap_or(());
             return Err(AppError::TooManyRequests(
-                "MFA_429_TOO_MANY_ATTEMPTS".into(),
+                "STEP_UP_429_TOO_MANY_ATTEMPTS".into(),
             ));
         }
 
-        // HYMN만 가능
-        if auth_user_auth != UserAuth::Hymn {
-            return Err(AppError::Forbidden("MFA_DISABLE_HYMN_ONLY".into()));
+        let pending: serde_json::Value = serde_json::from_str(&pending_json)
+            .map_err(|e| AppError::Internal(format!("step-up pending parse error: {}", e)))?;
+        let expected_hash = pending["code_hash"].as_str().unwrap_or_default();
+        let computed_hash = crate::api::user::service::UserService::hmac_verification_code(
+            &st.cfg.hmac_key,
+            &req.step_up_token,
+            req.code.trim(),
+        );
+        if !Self::constant_time_eq(computed_hash.as_bytes(), expected_hash.as_bytes()) {
+            return Err(AppError::Unauthorized("STEP_UP_INVALID_CODE".into()));
         }
 
-        // 자기 자신 비활성화 불가
-        if auth_user_id == target_user_id {
-            return Err(AppError::BadRequest("MFA_CANNOT_DISABLE_SELF".into()));
-        }
+        // 일회용
+        let _: () = redis_conn.del(&step_up_key).await.unwrap_or(());
+        let _: () = redis_conn.del(&rl_key).await.unwrap_or(());
 
-        // 대상 사용자 MFA 비활성화
-        AuthRepo::disable_mfa(&st.db, target_user_id).await?;
+        let user_id = pending["user_id"]
+            .as_i64()
+            .ok_or_else(|| AppError::Internal("step-up pending missing user_id".into()))?;
+        let risk_event_id = pending["risk_event_id"]
+            .as_i64()
+            .ok_or_else(|| AppError::Internal("step-up pending missing risk_event_id".into()))?;
+        let user_auth = match pending["user_auth"].as_str().unwrap_or("Learner") {
+            "HYMN" => UserAuth::Hymn,
+            "Admin" => UserAuth::Admin,
+            "Manager" => UserAuth::Manager,
+            _ => UserAuth::Learner,
+        };
+        let parsed_ua = crate::api::auth::handler::ParsedUa {
+            os: pending["os"].as_str().map(|s| s.to_string()),
+            browser: pending["browser"].as_str().map(|s| s.to_string()),
+            device: pending["device"].as_str().unwrap_or("other").to_string(),
+        };
 
-        // 대상 사용자의 모든 세션 무효화 (보안) — 계정 비활성화/탈퇴와 공유하는 헬퍼.
-        Self::invalidate_all_sessions(st, target_user_id, "mfa_disabled").await?;
+        Self::create_oauth_session(
+            st,
+            user_id,
+            user_auth,
+            pending["login_method"].as_str().unwrap_or("email"),
+            pending["login_ip"].as_str().unwrap_or("").to_string(),
+            pending["user_agent"].as_str().map(|s| s.to_string()),
+            parsed_ua,
+            LoginAssurance::StepUp { risk_event_id },
+        )
+        .await
+    }
 
-        info!(
-            "MFA disabled for user {} by HYMN user {}",
-            target_user_id, auth_user_id
+    /// 알림 메일 "본인이 아닙니다" — 전체 세션 폐기 (토큰 일회용)
+    pub async fn risk_revoke(st: &AppState, req: RiskRevokeReq) -> AppResult<LogoutRes> {
+        req.validate().map_err(|_| AppError::ValidationGeneric)?;
+        let mut redis_conn = st
+            .redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+
+        let revoke_key = format!(
+            "ak:risk_revoke:{}",
+            hex::encode(Sha256::digest(req.token.as_bytes()))
         );
+        // 일회용 — GETDEL 로 조회와 삭제를 원자적으로 (동시 요청 중 하나만 통과)
+        let payload: Option<String> = redis_conn
+            .get_del(&revoke_key)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let Some(payload) = payload else {
+            return Err(AppError::Unauthorized("RISK_REVOKE_TOKEN_EXPIRED".into()));
+        };
 
-        Ok(MfaDisableRes {
-            message: format!(
-                "MFA disabled for user {}. All sessions invalidated.",
-                target_user_id
-            ),
-        })
+        let payload: serde_json::Value = serde_json::from_str(&payload)
+            .map_err(|e| AppError::Internal(format!("risk revoke payload parse error: {}", e)))?;
+        let user_id = payload["user_id"]
+            .as_i64()
+            .ok_or_else(|| AppError::Internal("risk revoke payload missing user_id".into()))?;
+        let risk_event_id = payload["risk_event_id"].as_i64().unwrap_or_default();
+
+        Self::invalidate_all_sessions(st, user_id, "user_reported_risk").await?;
+        AuthRepo::mark_login_risk_user_revoked(&st.db, risk_event_id, user_id).await?;
+
+        warn!(
+            user_id,
+            risk_event_id, "User reported risky login — all sessions revoked"
+        );
+        Ok(LogoutRes { ok: true })
+    }
+}
+
+/// 알림/코드 메일 위치 표시 ("Seoul, KR" / "KR" / "알 수 없음")
+fn risk_location_label(geo: &GeoLocation) -> String {
+    match (geo.city.as_deref(), geo.country_code.as_deref()) {
+        (Some(city), Some(country)) => format!("{city}, {country}"),
+        (None, Some(country)) if country != "LC" => country.to_string(),
+        _ => "알 수 없음".to_string(),
+    }
+}
+
+/// 알림/코드 메일 기기 표시 ("Chrome / Mac OSX (desktop)")
+fn risk_device_label(ua: &crate::api::auth::handler::ParsedUa) -> String {
+    let family = [ua.browser.as_deref(), ua.os.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" / ");
+    if family.is_empty() {
+        ua.device.clone()
+    } else {
+        format!("{family} ({})", ua.device)
     }
 }
 
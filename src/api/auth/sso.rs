@@ -0,0 +1,348 @@
+//! 기관 SSO 순수 로직 — 이메일 도메인 / IdP 속성 매핑.
+//!
+//! IdP 가 준 속성(OIDC 클레임 또는 SAML Attribute)을 `users` 필드로 옮긴다.
+//! 기관 설정 `sso_attribute_map` 에 필드 → 속성 이름을 지정할 수 있고, 지정하지 않은 필드는
+//! 프로토콜별 표준 이름(OIDC 표준 클레임 / eduPerson·inetOrgPerson OID / Azure AD 클레임)을 차례로 찾는다.
+//!
+//! 조회·가입·세션 발급은 `AuthService` 가 담당한다.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDate;
+
+use crate::error::{AppError, AppResult};
+use crate::types::{OrgSsoProtocol, UserGender};
+
+/// 매핑 가능한 `users` 필드
+pub const SSO_ATTRIBUTE_FIELDS: [&str; 7] = [
+    "email", "name", "nickname", "birthday", "gender", "language", "country",
+];
+
+/// 개인 메일 도메인 — 기관 SSO 도메인으로 등록할 수 없다 (남의 계정 가로채기 방지)
+const PUBLIC_EMAIL_DOMAINS: [&str; 14] = [
+    "gmail.com",
+    "googlemail.com",
+    "naver.com",
+    "daum.net",
+    "hanmail.net",
+    "kakao.com",
+    "nate.com",
+    "outlook.com",
+    "hotmail.com",
+    "live.com",
+    "yahoo.com",
+    "icloud.com",
+    "me.com",
+    "proton.me",
+];
+
+/// 지원 언어 (`user_language_enum`)
+const SUPPORTED_LANGUAGES: [&str; 2] = ["ko", "en"];
+
+/// 필드별 표준 속성 이름 (앞에서부터 첫 값 사용)
+fn default_attribute_names(protocol: OrgSsoProtocol, field: &str) -> &'static [&'static str] {
+    match (protocol, field) {
+        (OrgSsoProtocol::Oidc, "email") => &["email"],
+        (OrgSsoProtocol::Oidc, "name") => &["name"],
+        (OrgSsoProtocol::Oidc, "nickname") => &["nickname", "preferred_username"],
+        (OrgSsoProtocol::Oidc, "birthday") => &["birthdate"],
+        (OrgSsoProtocol::Oidc, "gender") => &["gender"],
+        (OrgSsoProtocol::Oidc, "language") => &["locale"],
+        (OrgSsoProtocol::Saml, "email") => &[
+            "urn:oid:0.9.2342.19200300.100.1.3",
+            "mail",
+            "email",
+            "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/emailaddress",
+        ],
+        (OrgSsoProtocol::Saml, "name") => &[
+            "urn:oid:2.16.840.1.113730.3.1.241",
+            "displayName",
+            "cn",
+            "http://schemas.microsoft.com/identity/claims/displayname",
+            "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/name",
+        ],
+        (OrgSsoProtocol::Saml, "nickname") => &["eduPersonNickname", "uid"],
+        (OrgSsoProtocol::Saml, "language") => &["preferredLanguage"],
+        (OrgSsoProtocol::Saml, "country") => &["c", "countryName"],
+        _ => &[],
+    }
+}
+
+/// IdP 속성에서 얻은 사용자 정보 (JIT 가입 / 로그인 공용)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsoProfile {
+    /// IdP 고유 식별자 (OIDC sub / SAML NameID)
+    pub subject: String,
+    /// 소문자 정규화
+    pub email: String,
+    pub name: Option<String>,
+    pub nickname: Option<String>,
+    pub birthday: Option<NaiveDate>,
+    pub gender: UserGender,
+    /// 지원 언어(ko/en)일 때만
+    pub language: Option<String>,
+    pub country: Option<String>,
+}
+
+/// 이메일의 도메인 (소문자). 형식이 아니면 None
+pub fn email_domain(email: &str) -> Option<String> {
+    let (local, domain) = email.trim().rsplit_once('@')?;
+    let domain = domain.trim().to_lowercase();
+    if local.is_empty() || !domain.contains('.') {
+        return None;
+    }
+    Some(domain)
+}
+
+/// SSO 도메인 정규화 — 소문자, 앞의 '@' 제거. 형식 오류 / 개인 메일 도메인이면 400
+pub fn normalize_sso_domain(raw: &str) -> AppResult<String> {
+    let domain = raw
+        .trim()
+        .trim_start_matches('@')
+        .trim_end_matches('.')
+        .to_lowercase();
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if domain.len() > 253 || domain.split('.').count() < 2 || !domain.split('.').all(valid_label) {
+        return Err(AppError::BadRequest(format!("Invalid SSO domain: {}", raw)));
+    }
+    if PUBLIC_EMAIL_DOMAINS.contains(&domain.as_str()) {
+        return Err(AppError::BadRequest(format!(
+            "Public email domain cannot be used for SSO: {}",
+            domain
+        )));
+    }
+    Ok(domain)
+}
+
+/// 기관 설정의 속성 매핑 검증 (알 수 없는 필드 / 빈 속성 이름 거부)
+pub fn validate_attribute_map(map: &BTreeMap<String, String>) -> AppResult<()> {
+    for (field, attribute) in map {
+        if !SSO_ATTRIBUTE_FIELDS.contains(&field.as_str()) {
+            return Err(AppError::BadRequest(format!(
+                "Unknown attribute_map field: {} (allowed: {})",
+                field,
+                SSO_ATTRIBUTE_FIELDS.join(", ")
+            )));
+        }
+        if attribute.trim().is_empty() || attribute.len() > 256 {
+            return Err(AppError::BadRequest(format!(
+                "Invalid attribute name for {}",
+                field
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn parse_gender(value: &str) -> UserGender {
+    match value.trim().to_lowercase().as_str() {
+        "male" | "m" | "1" => UserGender::Male,
+        "female" | "f" | "2" => UserGender::Female,
+        "other" | "x" => UserGender::Other,
+        _ => UserGender::None,
+    }
+}
+
+/// "ko-KR" / "ko_KR" / "en" → 지원 언어 코드
+fn parse_language(value: &str) -> Option<String> {
+    let primary = value
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+    SUPPORTED_LANGUAGES
+        .contains(&primary.as_str())
+        .then_some(primary)
+}
+
+/// IdP 속성 → 사용자 정보. 이메일은 필수 (매핑 결과가 없고 subject 가 이메일이면 subject 사용)
+pub fn map_profile(
+    protocol: OrgSsoProtocol,
+    subject: &str,
+    attributes: &HashMap<String, Vec<String>>,
+    attribute_map: &BTreeMap<String, String>,
+) -> AppResult<SsoProfile> {
+    let value = |field: &str| -> Option<String> {
+        let configured = attribute_map.get(field).map(String::as_str);
+        configured
+            .into_iter()
+            .chain(default_attribute_names(protocol, field).iter().copied())
+            .find_map(|name| {
+                attributes
+                    .get(name)
+                    .and_then(|values| values.iter().map(|v| v.trim()).find(|v| !v.is_empty()))
+                    .map(str::to_string)
+            })
+    };
+
+    let email = value("email")
+        .or_else(|| email_domain(subject).map(|_| subject.to_string()))
+        .map(|e| e.trim().to_lowercase())
+        .filter(|e| email_domain(e).is_some())
+        .ok_or_else(|| {
+            AppError::BadRequest("SSO_400_MISSING_EMAIL: IdP did not release an email".into())
+        })?;
+
+    Ok(SsoProfile {
+        subject: subject.to_string(),
+        email,
+        name: value("name"),
+        nickname: value("nickname"),
+        birthday: value("birthday")
+            .and_then(|b| NaiveDate::parse_from_str(b.get(..10).unwrap_or(&b), "%Y-%m-%d").ok()),
+        gender: value("gender")
+            .map(|g| parse_gender(&g))
+            .unwrap_or(UserGender::None),
+        language: value("language").and_then(|l| parse_language(&l)),
+        country: value("country"),
+    })
+}
+
+/// OIDC 클레임(JSON) → 속성 맵 (문자열 / 숫자 / 불리언 / 문자열 배열만)
+pub fn claims_to_attributes(
+    claims: &serde_json::Map<String, serde_json::Value>,
+) -> HashMap<String, Vec<String>> {
+    let scalar = |v: &serde_json::Value| match v {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    };
+    claims
+        .iter()
+        .filter_map(|(k, v)| {
+            let values: Vec<String> = match v {
+                serde_json::Value::Array(items) => items.iter().filter_map(scalar).collect(),
+                other => scalar(other).into_iter().collect(),
+            };
+            (!values.is_empty()).then(|| (k.clone(), values))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs(pairs: &[(&str, &str)]) -> HashMap<String, Vec<String>> {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+        for (k, v) in pairs {
+            map.entry(k.to_string()).or_default().push(v.to_string());
+        }
+        map
+    }
+
+    #[test]
+    fn test_email_domain() {
+        assert_eq!(
+            email_domain("Kim@Univ.AC.kr").as_deref(),
+            Some("univ.ac.kr")
+        );
+        assert_eq!(
+            email_domain("a@b@univ.ac.kr").as_deref(),
+            Some("univ.ac.kr")
+        );
+        assert_eq!(email_domain("no-at-sign"), None);
+        assert_eq!(email_domain("@univ.ac.kr"), None);
+        assert_eq!(email_domain("kim@localhost"), None);
+    }
+
+    #[test]
+    fn test_normalize_sso_domain() {
+        assert_eq!(normalize_sso_domain(" @Univ.AC.kr ").unwrap(), "univ.ac.kr");
+        assert!(normalize_sso_domain("univ").is_err());
+        assert!(normalize_sso_domain("bad_domain.kr").is_err());
+        assert!(normalize_sso_domain("-univ.ac.kr").is_err());
+        assert!(normalize_sso_domain("Gmail.com").is_err());
+        assert!(normalize_sso_domain("naver.com").is_err());
+    }
+
+    #[test]
+    fn test_validate_attribute_map() {
+        let mut map = BTreeMap::new();
+        map.insert("email".to_string(), "studentMail".to_string());
+        assert!(validate_attribute_map(&map).is_ok());
+        map.insert("password".to_string(), "x".to_string());
+        assert!(validate_attribute_map(&map).is_err());
+    }
+
+    #[test]
+    fn test_map_profile_saml_defaults() {
+        let attributes = attrs(&[
+            (
+                "urn:oid:0.9.2342.19200300.100.1.3",
+                "Kim.Student@Univ.ac.kr",
+            ),
+            ("displayName", "김학생"),
+            ("preferredLanguage", "ko-KR"),
+        ]);
+        let profile =
+            map_profile(OrgSsoProtocol::Saml, "stu-1", &attributes, &BTreeMap::new()).unwrap();
+        assert_eq!(profile.email, "kim.student@univ.ac.kr");
+        assert_eq!(profile.name.as_deref(), Some("김학생"));
+        assert_eq!(profile.language.as_deref(), Some("ko"));
+        assert_eq!(profile.gender, UserGender::None);
+        assert_eq!(profile.birthday, None);
+    }
+
+    #[test]
+    fn test_map_profile_configured_map_wins() {
+        let attributes = attrs(&[
+            ("email", "alias@univ.ac.kr"),
+            ("studentMail", "kim@univ.ac.kr"),
+            ("birthdate", "2001-03-04"),
+            ("gender", "female"),
+            ("locale", "ja"),
+        ]);
+        let mut map = BTreeMap::new();
+        map.insert("email".to_string(), "studentMail".to_string());
+        let profile = map_profile(OrgSsoProtocol::Oidc, "sub-1", &attributes, &map).unwrap();
+        assert_eq!(profile.email, "kim@univ.ac.kr");
+        assert_eq!(profile.birthday, NaiveDate::from_ymd_opt(2001, 3, 4));
+        assert_eq!(profile.gender, UserGender::Female);
+        assert_eq!(profile.language, None); // 미지원 언어
+    }
+
+    #[test]
+    fn test_map_profile_email_fallback_and_missing() {
+        let profile = map_profile(
+            OrgSsoProtocol::Saml,
+            "Kim@Univ.ac.kr",
+            &HashMap::new(),
+            &BTreeMap::new(),
+        )
+        .unwrap();
+        assert_eq!(profile.email, "kim@univ.ac.kr");
+
+        let err = map_profile(
+            OrgSsoProtocol::Saml,
+            "stu-1",
+            &HashMap::new(),
+            &BTreeMap::new(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(_)));
+    }
+
+    #[test]
+    fn test_claims_to_attributes() {
+        let claims = serde_json::json!({
+            "email": "kim@univ.ac.kr",
+            "email_verified": true,
+            "groups": ["students", "cs"],
+            "address": { "country": "KR" }
+        });
+        let attributes = claims_to_attributes(claims.as_object().unwrap());
+        assert_eq!(attributes["email"], vec!["kim@univ.ac.kr"]);
+        assert_eq!(attributes["email_verified"], vec!["true"]);
+        assert_eq!(attributes["groups"], vec!["students", "cs"]);
+        assert!(!attributes.contains_key("address"));
+    }
+}
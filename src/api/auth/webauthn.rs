@@ -0,0 +1,593 @@
+//! WebAuthn (패스키) 등록/인증 응답 검증.
+//!
+//! attestation 은 `"none"` 으로 요청하므로 attestation statement 는 검증하지 않고
+//! authenticator data (rpIdHash / flags / counter / 공개키) 만 신뢰한다.
+//! 지원 알고리즘: ES256 (-7), RS256 (-257).
+
+use std::io::Cursor;
+
+use base64::engine::{general_purpose::URL_SAFE_NO_PAD, Engine};
+use ciborium::value::Value as Cbor;
+use p256::ecdsa::signature::Verifier;
+use rand::RngCore;
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+pub const COSE_ALG_ES256: i64 = -7;
+pub const COSE_ALG_RS256: i64 = -257;
+
+const FLAG_UP: u8 = 0x01;
+const FLAG_UV: u8 = 0x04;
+const FLAG_BE: u8 = 0x08;
+const FLAG_BS: u8 = 0x10;
+const FLAG_AT: u8 = 0x40;
+
+/// 검증 실패 사유 — 서비스에서 에러 코드로 매핑
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebauthnError {
+    Malformed,
+    TypeMismatch,
+    ChallengeMismatch,
+    OriginMismatch,
+    RpIdMismatch,
+    UserNotPresent,
+    UserNotVerified,
+    UnsupportedAlgorithm,
+    BadSignature,
+}
+
+impl WebauthnError {
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Malformed => "PASSKEY_MALFORMED_RESPONSE",
+            Self::TypeMismatch => "PASSKEY_TYPE_MISMATCH",
+            Self::ChallengeMismatch => "PASSKEY_CHALLENGE_MISMATCH",
+            Self::OriginMismatch => "PASSKEY_ORIGIN_MISMATCH",
+            Self::RpIdMismatch => "PASSKEY_RP_ID_MISMATCH",
+            Self::UserNotPresent => "PASSKEY_USER_NOT_PRESENT",
+            Self::UserNotVerified => "PASSKEY_USER_NOT_VERIFIED",
+            Self::UnsupportedAlgorithm => "PASSKEY_UNSUPPORTED_ALGORITHM",
+            Self::BadSignature => "PASSKEY_BAD_SIGNATURE",
+        }
+    }
+}
+
+/// clientDataJSON.type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ceremony {
+    Create,
+    Get,
+}
+
+impl Ceremony {
+    fn client_data_type(self) -> &'static str {
+        match self {
+            Self::Create => "webauthn.create",
+            Self::Get => "webauthn.get",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ty: String,
+    challenge: String,
+    origin: String,
+}
+
+/// 32바이트 랜덤 챌린지 (base64url, padding 없음)
+pub fn generate_challenge() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn b64url_decode(s: &str) -> Result<Vec<u8>, WebauthnError> {
+    URL_SAFE_NO_PAD
+        .decode(s.trim_end_matches('='))
+        .map_err(|_| WebauthnError::Malformed)
+}
+
+pub fn rp_id_hash(rp_id: &str) -> [u8; 32] {
+    Sha256::digest(rp_id.as_bytes()).into()
+}
+
+/// clientDataJSON 의 type / challenge / origin 검증
+pub fn verify_client_data(
+    client_data_json: &[u8],
+    ceremony: Ceremony,
+    expected_challenge: &str,
+    allowed_origins: &[String],
+) -> Result<(), WebauthnError> {
+    let client_data: ClientData =
+        serde_json::from_slice(client_data_json).map_err(|_| WebauthnError::Malformed)?;
+
+    if client_data.ty != ceremony.client_data_type() {
+        return Err(WebauthnError::TypeMismatch);
+    }
+    if b64url_decode(&client_data.challenge)? != b64url_decode(expected_challenge)? {
+        return Err(WebauthnError::ChallengeMismatch);
+    }
+    let origin = client_data.origin.trim_end_matches('/');
+    if !allowed_origins
+        .iter()
+        .any(|o| o.trim_end_matches('/') == origin)
+    {
+        return Err(WebauthnError::OriginMismatch);
+    }
+    Ok(())
+}
+
+/// 등록 시 authenticator data 에 포함된 자격증명
+#[derive(Debug, Clone)]
+pub struct AttestedCredential {
+    pub aaguid: [u8; 16],
+    pub credential_id: Vec<u8>,
+    /// COSE_Key 원본 바이트 (DB 에 그대로 저장)
+    pub public_key: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthenticatorData {
+    pub rp_id_hash: [u8; 32],
+    pub flags: u8,
+    pub sign_count: u32,
+    pub attested: Option<AttestedCredential>,
+}
+
+impl AuthenticatorData {
+    pub fn parse(bytes: &[u8]) -> Result<Self, WebauthnError> {
+        if bytes.len() < 37 {
+            return Err(WebauthnError::Malformed);
+        }
+        let mut rp_id_hash = [0u8; 32];
+        rp_id_hash.copy_from_slice(&bytes[..32]);
+        let flags = bytes[32];
+        let sign_count = u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]);
+
+        let attested = if flags & FLAG_AT != 0 {
+            let rest = &bytes[37..];
+            if rest.len() < 18 {
+                return Err(WebauthnError::Malformed);
+            }
+            let mut aaguid = [0u8; 16];
+            aaguid.copy_from_slice(&rest[..16]);
+            let id_len = u16::from_be_bytes([rest[16], rest[17]]) as usize;
+            let rest = &rest[18..];
+            if rest.len() < id_len {
+                return Err(WebauthnError::Malformed);
+            }
+            let credential_id = rest[..id_len].to_vec();
+            let key_bytes = &rest[id_len..];
+
+            // COSE_Key 길이는 CBOR 를 한 번 읽어서 소비한 바이트 수로 판단 (뒤에 extensions 가 올 수 있음)
+            let mut cursor = Cursor::new(key_bytes);
+            let _: Cbor =
+                ciborium::de::from_reader(&mut cursor).map_err(|_| WebauthnError::Malformed)?;
+            let key_len = cursor.position() as usize;
+
+            Some(AttestedCredential {
+                aaguid,
+                credential_id,
+                public_key: key_bytes[..key_len].to_vec(),
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+            attested,
+        })
+    }
+
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_UP != 0
+    }
+
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_UV != 0
+    }
+
+    pub fn backup_eligible(&self) -> bool {
+        self.flags & FLAG_BE != 0
+    }
+
+    pub fn backed_up(&self) -> bool {
+        self.flags & FLAG_BS != 0
+    }
+
+    /// rpIdHash / UP / (필요 시) UV 검증
+    pub fn verify(&self, rp_id: &str, require_uv: bool) -> Result<(), WebauthnError> {
+        if self.rp_id_hash != rp_id_hash(rp_id) {
+            return Err(WebauthnError::RpIdMismatch);
+        }
+        if !self.user_present() {
+            return Err(WebauthnError::UserNotPresent);
+        }
+        if require_uv && !self.user_verified() {
+            return Err(WebauthnError::UserNotVerified);
+        }
+        Ok(())
+    }
+}
+
+/// attestationObject (CBOR) 에서 authData 추출 — fmt / attStmt 는 검증하지 않음
+pub fn attestation_auth_data(attestation_object: &[u8]) -> Result<Vec<u8>, WebauthnError> {
+    let value: Cbor =
+        ciborium::de::from_reader(attestation_object).map_err(|_| WebauthnError::Malformed)?;
+    let Cbor::Map(entries) = value else {
+        return Err(WebauthnError::Malformed);
+    };
+    entries
+        .into_iter()
+        .find_map(|(k, v)| match (k, v) {
+            (Cbor::Text(k), Cbor::Bytes(b)) if k == "authData" => Some(b),
+            _ => None,
+        })
+        .ok_or(WebauthnError::Malformed)
+}
+
+enum CoseKey {
+    Es256(p256::ecdsa::VerifyingKey),
+    Rs256(rsa::RsaPublicKey),
+}
+
+fn cose_int(entries: &[(Cbor, Cbor)], label: i64) -> Option<&Cbor> {
+    entries.iter().find_map(|(k, v)| match k {
+        Cbor::Integer(i) if i128::from(*i) == i128::from(label) => Some(v),
+        _ => None,
+    })
+}
+
+fn cose_i64(entries: &[(Cbor, Cbor)], label: i64) -> Option<i64> {
+    match cose_int(entries, label)? {
+        Cbor::Integer(i) => i64::try_from(i128::from(*i)).ok(),
+        _ => None,
+    }
+}
+
+fn cose_bytes(entries: &[(Cbor, Cbor)], label: i64) -> Option<&[u8]> {
+    match cose_int(entries, label)? {
+        Cbor::Bytes(b) => Some(b.as_slice()),
+        _ => None,
+    }
+}
+
+fn parse_cose_key(bytes: &[u8]) -> Result<CoseKey, WebauthnError> {
+    let value: Cbor = ciborium::de::from_reader(bytes).map_err(|_| WebauthnError::Malformed)?;
+    let Cbor::Map(entries) = value else {
+        return Err(WebauthnError::Malformed);
+    };
+
+    let kty = cose_i64(&entries, 1).ok_or(WebauthnError::Malformed)?;
+    let alg = cose_i64(&entries, 3).ok_or(WebauthnError::Malformed)?;
+    match (kty, alg) {
+        // EC2 / P-256
+        (2, COSE_ALG_ES256) => {
+            if cose_i64(&entries, -1) != Some(1) {
+                return Err(WebauthnError::UnsupportedAlgorithm);
+            }
+            let x = cose_bytes(&entries, -2).ok_or(WebauthnError::Malformed)?;
+            let y = cose_bytes(&entries, -3).ok_or(WebauthnError::Malformed)?;
+            if x.len() != 32 || y.len() != 32 {
+                return Err(WebauthnError::Malformed);
+            }
+            let mut sec1 = Vec::with_capacity(65);
+            sec1.push(0x04);
+            sec1.extend_from_slice(x);
+            sec1.extend_from_slice(y);
+            let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(&sec1)
+                .map_err(|_| WebauthnError::Malformed)?;
+            Ok(CoseKey::Es256(key))
+        }
+        // RSA
+        (3, COSE_ALG_RS256) => {
+            let n = cose_bytes(&entries, -1).ok_or(WebauthnError::Malformed)?;
+            let e = cose_bytes(&entries, -2).ok_or(WebauthnError::Malformed)?;
+            let key = rsa::RsaPublicKey::new(
+                rsa::BigUint::from_bytes_be(n),
+                rsa::BigUint::from_bytes_be(e),
+            )
+            .map_err(|_| WebauthnError::Malformed)?;
+            Ok(CoseKey::Rs256(key))
+        }
+        _ => Err(WebauthnError::UnsupportedAlgorithm),
+    }
+}
+
+/// 등록 시 공개키 검증 — 지원 알고리즘이면 COSE alg 반환
+pub fn cose_key_algorithm(cose_key: &[u8]) -> Result<i64, WebauthnError> {
+    Ok(match parse_cose_key(cose_key)? {
+        CoseKey::Es256(_) => COSE_ALG_ES256,
+        CoseKey::Rs256(_) => COSE_ALG_RS256,
+    })
+}
+
+/// 인증 서명 검증 — 서명 대상은 authenticatorData || SHA-256(clientDataJSON)
+pub fn verify_assertion_signature(
+    cose_key: &[u8],
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+    signature: &[u8],
+) -> Result<(), WebauthnError> {
+    let mut message = authenticator_data.to_vec();
+    message.extend_from_slice(&Sha256::digest(client_data_json));
+
+    match parse_cose_key(cose_key)? {
+        CoseKey::Es256(key) => {
+            let sig = p256::ecdsa::Signature::from_der(signature)
+                .map_err(|_| WebauthnError::BadSignature)?;
+            key.verify(&message, &sig)
+                .map_err(|_| WebauthnError::BadSignature)
+        }
+        CoseKey::Rs256(key) => {
+            let key = rsa::pkcs1v15::VerifyingKey::<Sha256>::new(key);
+            let sig = rsa::pkcs1v15::Signature::try_from(signature)
+                .map_err(|_| WebauthnError::BadSignature)?;
+            key.verify(&message, &sig)
+                .map_err(|_| WebauthnError::BadSignature)
+        }
+    }
+}
+
+/// 서명 카운터 검증 — 둘 다 0 이면 카운터 미지원 인증기 (동기화 패스키), 그 외엔 증가해야 함
+pub fn sign_count_advanced(stored: i64, received: u32) -> bool {
+    (stored == 0 && received == 0) || i64::from(received) > stored
+}
+
+/// WebAuthn user handle — 사용자 ID 의 10진 문자열 바이트
+pub fn user_handle(user_id: i64) -> String {
+    URL_SAFE_NO_PAD.encode(user_id.to_string())
+}
+
+pub fn parse_user_handle(handle: &str) -> Option<i64> {
+    let bytes = b64url_decode(handle).ok()?;
+    std::str::from_utf8(&bytes).ok()?.parse().ok()
+}
+
+/// navigator.credentials.create() 의 publicKey 옵션
+#[allow(clippy::too_many_arguments)]
+pub fn creation_options(
+    rp_id: &str,
+    rp_name: &str,
+    user_id: i64,
+    user_name: &str,
+    user_display_name: &str,
+    challenge: &str,
+    exclude_credential_ids: &[String],
+    timeout_ms: i64,
+) -> serde_json::Value {
+    json!({
+        "rp": { "id": rp_id, "name": rp_name },
+        "user": {
+            "id": user_handle(user_id),
+            "name": user_name,
+            "displayName": user_display_name,
+        },
+        "challenge": challenge,
+        "pubKeyCredParams": [
+            { "type": "public-key", "alg": COSE_ALG_ES256 },
+            { "type": "public-key", "alg": COSE_ALG_RS256 },
+        ],
+        "timeout": timeout_ms,
+        "attestation": "none",
+        "excludeCredentials": exclude_credential_ids
+            .iter()
+            .map(|id| json!({ "type": "public-key", "id": id }))
+            .collect::<Vec<_>>(),
+        "authenticatorSelection": {
+            "residentKey": "required",
+            "requireResidentKey": true,
+            "userVerification": "preferred",
+        },
+    })
+}
+
+/// navigator.credentials.get() 의 publicKey 옵션 — allow 가 비어 있으면 discoverable 로그인
+pub fn request_options(
+    rp_id: &str,
+    challenge: &str,
+    allow_credentials: &[(String, Vec<String>)],
+    user_verification: &str,
+    timeout_ms: i64,
+) -> serde_json::Value {
+    json!({
+        "rpId": rp_id,
+        "challenge": challenge,
+        "timeout": timeout_ms,
+        "userVerification": user_verification,
+        "allowCredentials": allow_credentials
+            .iter()
+            .map(|(id, transports)| {
+                if transports.is_empty() {
+                    json!({ "type": "public-key", "id": id })
+                } else {
+                    json!({ "type": "public-key", "id": id, "transports": transports })
+                }
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    const RP_ID: &str = "amazingkorean.net";
+    const ORIGIN: &str = "https://amazingkorean.net";
+
+    fn cose_es256(key: &SigningKey) -> Vec<u8> {
+        let point = key.verifying_key().to_encoded_point(false);
+        let map = Cbor::Map(vec![
+            (Cbor::Integer(1.into()), Cbor::Integer(2.into())),
+            (Cbor::Integer(3.into()), Cbor::Integer((-7).into())),
+            (Cbor::Integer((-1).into()), Cbor::Integer(1.into())),
+            (
+                Cbor::Integer((-2).into()),
+                Cbor::Bytes(point.x().unwrap().to_vec()),
+            ),
+            (
+                Cbor::Integer((-3).into()),
+                Cbor::Bytes(point.y().unwrap().to_vec()),
+            ),
+        ]);
+        let mut out = Vec::new();
+        ciborium::ser::into_writer(&map, &mut out).unwrap();
+        out
+    }
+
+    fn auth_data(flags: u8, sign_count: u32, attested: Option<(&[u8], &[u8])>) -> Vec<u8> {
+        let mut out = rp_id_hash(RP_ID).to_vec();
+        out.push(flags);
+        out.extend_from_slice(&sign_count.to_be_bytes());
+        if let Some((cred_id, cose)) = attested {
+            out.extend_from_slice(&[7u8; 16]);
+            out.extend_from_slice(&(cred_id.len() as u16).to_be_bytes());
+            out.extend_from_slice(cred_id);
+            out.extend_from_slice(cose);
+        }
+        out
+    }
+
+    fn client_data(ty: &str, challenge: &str, origin: &str) -> Vec<u8> {
+        serde_json::to_vec(&json!({ "type": ty, "challenge": challenge, "origin": origin }))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_client_data_checks_type_challenge_and_origin() {
+        let challenge = generate_challenge();
+        let origins = vec![format!("{ORIGIN}/")];
+
+        let ok = client_data("webauthn.get", &challenge, ORIGIN);
+        assert_eq!(
+            verify_client_data(&ok, Ceremony::Get, &challenge, &origins),
+            Ok(())
+        );
+        assert_eq!(
+            verify_client_data(&ok, Ceremony::Create, &challenge, &origins),
+            Err(WebauthnError::TypeMismatch)
+        );
+        assert_eq!(
+            verify_client_data(&ok, Ceremony::Get, &generate_challenge(), &origins),
+            Err(WebauthnError::ChallengeMismatch)
+        );
+        let phishing = client_data("webauthn.get", &challenge, "https://amazingkorean.net.evil");
+        assert_eq!(
+            verify_client_data(&phishing, Ceremony::Get, &challenge, &origins),
+            Err(WebauthnError::OriginMismatch)
+        );
+    }
+
+    #[test]
+    fn test_parse_registration_auth_data_and_attestation_object() {
+        let key = SigningKey::random(&mut rand::rngs::OsRng);
+        let cose = cose_es256(&key);
+        let data = auth_data(FLAG_UP | FLAG_UV | FLAG_AT, 0, Some((b"cred-1", &cose)));
+
+        let att = Cbor::Map(vec![
+            (Cbor::Text("fmt".into()), Cbor::Text("none".into())),
+            (Cbor::Text("attStmt".into()), Cbor::Map(vec![])),
+            (Cbor::Text("authData".into()), Cbor::Bytes(data.clone())),
+        ]);
+        let mut att_bytes = Vec::new();
+        ciborium::ser::into_writer(&att, &mut att_bytes).unwrap();
+
+        let extracted = attestation_auth_data(&att_bytes).unwrap();
+        assert_eq!(extracted, data);
+
+        let parsed = AuthenticatorData::parse(&extracted).unwrap();
+        assert!(parsed.user_present() && parsed.user_verified());
+        assert!(!parsed.backup_eligible());
+        assert_eq!(parsed.verify(RP_ID, true), Ok(()));
+        assert_eq!(
+            parsed.verify("other.example", true),
+            Err(WebauthnError::RpIdMismatch)
+        );
+
+        let cred = parsed.attested.unwrap();
+        assert_eq!(cred.credential_id, b"cred-1");
+        assert_eq!(cred.public_key, cose);
+        assert_eq!(cose_key_algorithm(&cred.public_key), Ok(COSE_ALG_ES256));
+    }
+
+    #[test]
+    fn test_require_uv_rejects_presence_only_assertion() {
+        let data = AuthenticatorData::parse(&auth_data(FLAG_UP, 1, None)).unwrap();
+        assert_eq!(data.verify(RP_ID, false), Ok(()));
+        assert_eq!(
+            data.verify(RP_ID, true),
+            Err(WebauthnError::UserNotVerified)
+        );
+        let data = AuthenticatorData::parse(&auth_data(0, 1, None)).unwrap();
+        assert_eq!(
+            data.verify(RP_ID, false),
+            Err(WebauthnError::UserNotPresent)
+        );
+    }
+
+    #[test]
+    fn test_es256_assertion_signature() {
+        let key = SigningKey::random(&mut rand::rngs::OsRng);
+        let cose = cose_es256(&key);
+        let data = auth_data(FLAG_UP | FLAG_UV, 5, None);
+        let cdj = client_data("webauthn.get", &generate_challenge(), ORIGIN);
+
+        let mut message = data.clone();
+        message.extend_from_slice(&Sha256::digest(&cdj));
+        let sig: Signature = key.sign(&message);
+        let der = sig.to_der();
+
+        assert_eq!(
+            verify_assertion_signature(&cose, &data, &cdj, der.as_bytes()),
+            Ok(())
+        );
+
+        let tampered = auth_data(FLAG_UP | FLAG_UV, 6, None);
+        assert_eq!(
+            verify_assertion_signature(&cose, &tampered, &cdj, der.as_bytes()),
+            Err(WebauthnError::BadSignature)
+        );
+
+        let other = cose_es256(&SigningKey::random(&mut rand::rngs::OsRng));
+        assert_eq!(
+            verify_assertion_signature(&other, &data, &cdj, der.as_bytes()),
+            Err(WebauthnError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn test_sign_count_and_user_handle() {
+        assert!(sign_count_advanced(0, 0));
+        assert!(sign_count_advanced(3, 4));
+        assert!(!sign_count_advanced(4, 4));
+        assert!(!sign_count_advanced(4, 0));
+
+        assert_eq!(parse_user_handle(&user_handle(42)), Some(42));
+        assert_eq!(parse_user_handle("!!"), None);
+    }
+
+    #[test]
+    fn test_request_options_lists_allowed_credentials() {
+        let opts = request_options(
+            RP_ID,
+            "abc",
+            &[
+                ("id1".into(), vec!["internal".into()]),
+                ("id2".into(), vec![]),
+            ],
+            "required",
+            60000,
+        );
+        assert_eq!(opts["rpId"], RP_ID);
+        assert_eq!(opts["allowCredentials"][0]["transports"][0], "internal");
+        assert!(opts["allowCredentials"][1].get("transports").is_none());
+        assert_eq!(opts["userVerification"], "required");
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 수료증 상태 (공개 검증 응답)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CertificateStatus {
+    Valid,
+    Revoked,
+}
+
+/// 내 수료증
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MyCertificateRes {
+    pub certificate_id: i64,
+    pub certificate_code: String,
+    pub course_id: i64,
+    pub course_title: String,
+    pub certificate_issued_at: DateTime<Utc>,
+    pub certificate_revoked_at: Option<DateTime<Utc>>,
+    /// 공개 검증 페이지 URL (QR 코드에 들어가는 값)
+    pub verify_url: String,
+}
+
+/// 내 수료증 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MyCertificateListRes {
+    pub items: Vec<MyCertificateRes>,
+}
+
+/// 수료증 공개 검증 응답 — 이름은 마스킹, 사용자/코스 ID 등 내부 식별자는 노출하지 않음
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CertificateVerifyRes {
+    pub certificate_code: String,
+    pub status: CertificateStatus,
+    /// 마스킹된 수료자 이름 (예: 홍**, J*** S****)
+    pub holder_name: String,
+    pub course_title: String,
+    pub certificate_issued_at: DateTime<Utc>,
+    pub certificate_revoked_at: Option<DateTime<Utc>>,
+}
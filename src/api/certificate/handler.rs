@@ -0,0 +1,86 @@
+use super::{
+    dto::{CertificateVerifyRes, MyCertificateListRes},
+    service::CertificateService,
+};
+use crate::{api::auth::extractor::AuthUser, error::AppResult, state::AppState};
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    Json,
+};
+
+#[utoipa::path(
+    get,
+    path = "/certificates/me",
+    tag = "Certificate",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "My certificates", body = MyCertificateListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_certificates(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<MyCertificateListRes>> {
+    let res = CertificateService::list_mine(&st, claims.sub).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/certificates/me/{code}/image",
+    tag = "Certificate",
+    security(("bearerAuth" = [])),
+    params(
+        ("code" = String, Path, description = "Certificate verification code")
+    ),
+    responses(
+        (status = 200, description = "Certificate image (image/png binary)", content_type = "image/png"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Certificate not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Certificate revoked", body = crate::error::ErrorBody),
+        (status = 503, description = "Certificate font not configured", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_certificate_image(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(code): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    let png = CertificateService::render_mine(&st, claims.sub, &code).await?;
+    let disposition = format!(
+        "inline; filename=\"certificate-{}.png\"",
+        code.to_ascii_uppercase()
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/png".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        png,
+    ))
+}
+
+/// 공개 수료증 검증 (인증 불필요) — 마스킹된 이름/코스/발급일/상태만
+#[utoipa::path(
+    get,
+    path = "/certificates/{code}",
+    tag = "Certificate",
+    params(
+        ("code" = String, Path, description = "Certificate verification code")
+    ),
+    responses(
+        (status = 200, description = "Certificate verification result", body = CertificateVerifyRes),
+        (status = 404, description = "Certificate not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn verify_certificate(
+    State(st): State<AppState>,
+    Path(code): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    let res = CertificateService::verify(&st, &code).await?;
+    Ok(([(header::CACHE_CONTROL, "no-store")], Json(res)))
+}
@@ -0,0 +1,273 @@
+use ab_glyph::{Font, FontArc, PxScale, ScaleFont};
+use chrono::{DateTime, Utc};
+use image::{DynamicImage, Rgba, RgbaImage};
+use imageproc::drawing::{draw_filled_rect_mut, draw_hollow_rect_mut, draw_text_mut};
+use imageproc::rect::Rect;
+use qrcodegen::{QrCode, QrCodeEcc};
+use std::sync::OnceLock;
+
+use crate::error::{AppError, AppResult};
+
+/// 수료증 폰트 (한 번만 로드, 프로세스 수명과 동일)
+static CERTIFICATE_FONT: OnceLock<Option<FontArc>> = OnceLock::new();
+
+const WIDTH: u32 = 1600;
+const HEIGHT: u32 = 1131; // A4 가로 비율
+const MARGIN_X: i32 = 140;
+const QR_BOX: u32 = 220;
+
+const NAVY: Rgba<u8> = Rgba([27, 45, 79, 255]);
+const GRAY: Rgba<u8> = Rgba([110, 110, 110, 255]);
+const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+/// 수료증 폰트 초기화 (서버 시작 시 호출, 경로 미설정이면 이미지 발급 비활성)
+pub fn init_font(font_path: Option<&str>) {
+    let font = font_path.and_then(|path| {
+        let font = std::fs::read(path)
+            .ok()
+            .and_then(|bytes| FontArc::try_from_vec(bytes).ok());
+        if font.is_none() {
+            tracing::warn!("Certificate font not found at '{path}'. Certificate images disabled.");
+        }
+        font
+    });
+
+    let _ = CERTIFICATE_FONT.set(font);
+}
+
+/// 수료증에 들어가는 값
+pub struct CertificateContent<'a> {
+    pub holder_name: &'a str,
+    pub course_title: &'a str,
+    pub issued_at: DateTime<Utc>,
+    pub code: &'a str,
+    pub verify_url: &'a str,
+}
+
+/// 수료증 PNG 렌더링
+pub fn render_png(content: &CertificateContent<'_>) -> AppResult<Vec<u8>> {
+    let font = CERTIFICATE_FONT
+        .get()
+        .and_then(|f| f.as_ref())
+        .ok_or_else(|| AppError::ServiceUnavailable("CERTIFICATE_RENDER_UNAVAILABLE".into()))?;
+
+    let mut img = RgbaImage::from_pixel(WIDTH, HEIGHT, WHITE);
+
+    // 이중 테두리
+    for inset in 30..42 {
+        draw_hollow_rect_mut(
+            &mut img,
+            Rect::at(inset, inset).of_size(WIDTH - 2 * inset as u32, HEIGHT - 2 * inset as u32),
+            NAVY,
+        );
+    }
+    draw_hollow_rect_mut(
+        &mut img,
+        Rect::at(56, 56).of_size(WIDTH - 112, HEIGHT - 112),
+        NAVY,
+    );
+
+    let max_text_width = (WIDTH as i32 - 2 * MARGIN_X) as f32;
+    draw_centered(
+        &mut img,
+        font,
+        "CERTIFICATE OF COMPLETION",
+        64.0,
+        max_text_width,
+        140,
+        NAVY,
+    );
+    draw_centered(&mut img, font, "수료증", 40.0, max_text_width, 225, GRAY);
+    draw_centered(
+        &mut img,
+        font,
+        "This certifies that",
+        28.0,
+        max_text_width,
+        330,
+        GRAY,
+    );
+    draw_centered(
+        &mut img,
+        font,
+        content.holder_name,
+        72.0,
+        max_text_width,
+        385,
+        BLACK,
+    );
+    draw_centered(
+        &mut img,
+        font,
+        "has successfully completed the course",
+        28.0,
+        max_text_width,
+        505,
+        GRAY,
+    );
+    draw_centered(
+        &mut img,
+        font,
+        content.course_title,
+        48.0,
+        max_text_width,
+        560,
+        NAVY,
+    );
+
+    // 하단 좌측: 발급일 / 번호 / 검증 URL, 우측: QR
+    let qr_x = WIDTH - MARGIN_X as u32 - QR_BOX;
+    let qr_y = 760;
+    let info_width = (qr_x as i32 - MARGIN_X - 40) as f32;
+    let issued = format!("Issued on {}", content.issued_at.format("%Y-%m-%d"));
+    let number = format!("Certificate No. {}", content.code);
+    let verify = format!("Verify at {}", content.verify_url);
+    draw_left(&mut img, font, &issued, 28.0, info_width, 800, BLACK);
+    draw_left(&mut img, font, &number, 28.0, info_width, 850, BLACK);
+    draw_left(&mut img, font, &verify, 20.0, info_width, 905, GRAY);
+
+    draw_qr(&mut img, content.verify_url, qr_x, qr_y, QR_BOX)?;
+
+    draw_centered(
+        &mut img,
+        font,
+        "Amazing Korean",
+        30.0,
+        max_text_width,
+        1010,
+        NAVY,
+    );
+
+    let mut buf = std::io::Cursor::new(Vec::new());
+    DynamicImage::ImageRgba8(img)
+        .write_to(&mut buf, image::ImageFormat::Png)
+        .map_err(|e| AppError::Internal(format!("Failed to encode certificate: {e}")))?;
+    Ok(buf.into_inner())
+}
+
+/// 텍스트 폭 (px)
+fn text_width(font: &FontArc, scale: PxScale, text: &str) -> f32 {
+    let scaled = font.as_scaled(scale);
+    text.chars()
+        .fold(0.0, |acc, c| acc + scaled.h_advance(font.glyph_id(c)))
+}
+
+/// 긴 코스명/이름은 최대 폭에 맞춰 글자 크기를 줄임
+fn fit_scale(font: &FontArc, text: &str, size: f32, max_width: f32) -> PxScale {
+    let scale = PxScale::from(size);
+    let width = text_width(font, scale, text);
+    if width <= max_width || width <= 0.0 {
+        scale
+    } else {
+        PxScale::from(size * max_width / width)
+    }
+}
+
+fn draw_centered(
+    img: &mut RgbaImage,
+    font: &FontArc,
+    text: &str,
+    size: f32,
+    max_width: f32,
+    y: i32,
+    color: Rgba<u8>,
+) {
+    let scale = fit_scale(font, text, size, max_width);
+    let width = text_width(font, scale, text).ceil() as i32;
+    let x = (WIDTH as i32 - width) / 2;
+    draw_text_mut(img, color, x, y, scale, font, text);
+}
+
+fn draw_left(
+    img: &mut RgbaImage,
+    font: &FontArc,
+    text: &str,
+    size: f32,
+    max_width: f32,
+    y: i32,
+    color: Rgba<u8>,
+) {
+    let scale = fit_scale(font, text, size, max_width);
+    draw_text_mut(img, color, MARGIN_X, y, scale, font, text);
+}
+
+/// 검증 URL QR 코드를 (x, y) 기준 box_size 정사각형 안에 그림 (quiet zone 4 모듈 포함)
+fn draw_qr(img: &mut RgbaImage, text: &str, x: u32, y: u32, box_size: u32) -> AppResult<()> {
+    let qr = QrCode::encode_text(text, QrCodeEcc::Medium)
+        .map_err(|e| AppError::Internal(format!("Failed to encode QR: {e:?}")))?;
+
+    let modules = qr.size() as u32 + 8;
+    let module_px = (box_size / modules).max(1);
+    let offset = (box_size - module_px * modules) / 2 + 4 * module_px;
+
+    draw_filled_rect_mut(
+        img,
+        Rect::at(x as i32, y as i32).of_size(box_size, box_size),
+        WHITE,
+    );
+    for my in 0..qr.size() {
+        for mx in 0..qr.size() {
+            if qr.get_module(mx, my) {
+                let px = x + offset + mx as u32 * module_px;
+                let py = y + offset + my as u32 * module_px;
+                draw_filled_rect_mut(
+                    img,
+                    Rect::at(px as i32, py as i32).of_size(module_px, module_px),
+                    BLACK,
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_finder_pattern_is_dark_and_quiet_zone_is_white() {
+        let mut img = RgbaImage::from_pixel(300, 300, Rgba([200, 0, 0, 255]));
+        draw_qr(
+            &mut img,
+            "https://example.com/certificates/AMK-2345-6789-ABCD",
+            40,
+            40,
+            QR_BOX,
+        )
+        .unwrap();
+
+        let qr = QrCode::encode_text(
+            "https://example.com/certificates/AMK-2345-6789-ABCD",
+            QrCodeEcc::Medium,
+        )
+        .unwrap();
+        let modules = qr.size() as u32 + 8;
+        let module_px = QR_BOX / modules;
+        let offset = (QR_BOX - module_px * modules) / 2 + 4 * module_px;
+
+        // 좌상단 finder pattern 첫 모듈은 검정
+        assert_eq!(*img.get_pixel(40 + offset, 40 + offset), BLACK);
+        // quiet zone 은 흰색
+        assert_eq!(*img.get_pixel(41, 41), WHITE);
+        // box 바깥은 그대로
+        assert_eq!(*img.get_pixel(10, 10), Rgba([200, 0, 0, 255]));
+    }
+
+    #[test]
+    fn render_without_font_is_unavailable() {
+        // 테스트 프로세스에서는 init_font 를 호출하지 않음
+        let content = CertificateContent {
+            holder_name: "홍길동",
+            course_title: "발음",
+            issued_at: Utc::now(),
+            code: "AMK-2345-6789-ABCD",
+            verify_url: "https://example.com/certificates/AMK-2345-6789-ABCD",
+        };
+        assert!(matches!(
+            render_png(&content),
+            Err(AppError::ServiceUnavailable(_))
+        ));
+    }
+}
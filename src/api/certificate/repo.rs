@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection};
+
+use crate::error::AppResult;
+
+#[derive(Debug, FromRow)]
+pub struct CertificateRow {
+    pub certificate_id: i64,
+    pub certificate_code: String,
+    pub user_id: i64,
+    pub course_id: i64,
+    pub course_title: String,
+    pub certificate_issued_at: DateTime<Utc>,
+    pub certificate_revoked_at: Option<DateTime<Utc>>,
+}
+
+/// 검증/렌더링용 — 수료자 이름 암호문 포함
+#[derive(Debug, FromRow)]
+pub struct CertificateHolderRow {
+    pub certificate_id: i64,
+    pub certificate_code: String,
+    pub user_id: i64,
+    pub user_name_enc: String,
+    pub course_title: String,
+    pub certificate_issued_at: DateTime<Utc>,
+    pub certificate_revoked_at: Option<DateTime<Utc>>,
+}
+
+/// 수료증 미발급 상태의 100% 수강 건
+#[derive(Debug, FromRow)]
+pub struct CompletedEnrollmentRow {
+    pub user_course_id: i64,
+    pub user_id: i64,
+    pub course_id: i32,
+}
+
+/// 진도율 100% 이면서 수료증(폐기 포함)이 없는 수강 건
+pub async fn find_uncertified_completions(
+    conn: &mut PgConnection,
+    user_id: Option<i64>,
+    course_id: Option<i64>,
+) -> AppResult<Vec<CompletedEnrollmentRow>> {
+    let rows = sqlx::query_as::<_, CompletedEnrollmentRow>(
+        r#"
+        SELECT uc.user_course_id, uc.user_id, uc.course_id
+        FROM users_course uc
+        WHERE uc.user_course_progress_percent >= 100
+          AND ($1::bigint IS NULL OR uc.user_id = $1)
+          AND ($2::bigint IS NULL OR uc.course_id = $2)
+          AND NOT EXISTS (
+              SELECT 1 FROM course_certificate cc
+              WHERE cc.user_id = uc.user_id AND cc.course_id = uc.course_id
+          )
+        "#,
+    )
+    .bind(user_id)
+    .bind(course_id)
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(rows)
+}
+
+/// 수료증 발급 — 동시 발급 경쟁 시 (user_id, course_id) 충돌은 무시
+pub async fn insert_certificate(
+    conn: &mut PgConnection,
+    code: &str,
+    row: &CompletedEnrollmentRow,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        INSERT INTO course_certificate (certificate_code, user_id, course_id, user_course_id)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id, course_id) DO NOTHING
+        "#,
+    )
+    .bind(code)
+    .bind(row.user_id)
+    .bind(row.course_id)
+    .bind(row.user_course_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+const CERTIFICATE_SELECT: &str = r#"
+    SELECT
+        cc.certificate_id,
+        cc.certificate_code,
+        cc.user_id,
+        cc.course_id::bigint AS course_id,
+        c.course_title,
+        cc.certificate_issued_at,
+        cc.certificate_revoked_at
+    FROM course_certificate cc
+    JOIN course c ON c.course_id = cc.course_id
+"#;
+
+pub async fn find_by_user(
+    executor: impl sqlx::PgExecutor<'_>,
+    user_id: i64,
+) -> AppResult<Vec<CertificateRow>> {
+    let sql = format!(
+        "{CERTIFICATE_SELECT} WHERE cc.user_id = $1 ORDER BY cc.certificate_issued_at DESC"
+    );
+    let rows = sqlx::query_as::<_, CertificateRow>(&sql)
+        .bind(user_id)
+        .fetch_all(executor)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_holder_by_code(
+    executor: impl sqlx::PgExecutor<'_>,
+    code: &str,
+) -> AppResult<Option<CertificateHolderRow>> {
+    let row = sqlx::query_as::<_, CertificateHolderRow>(
+        r#"
+        SELECT
+            cc.certificate_id,
+            cc.certificate_code,
+            cc.user_id,
+            u.user_name AS user_name_enc,
+            c.course_title,
+            cc.certificate_issued_at,
+            cc.certificate_revoked_at
+        FROM course_certificate cc
+        JOIN users u ON u.user_id = cc.user_id
+        JOIN course c ON c.course_id = cc.course_id
+        WHERE cc.certificate_code = $1
+        "#,
+    )
+    .bind(code)
+    .fetch_optional(executor)
+    .await?;
+    Ok(row)
+}
@@ -0,0 +1,13 @@
+use super::handler;
+use crate::state::AppState;
+use axum::routing::get;
+
+pub fn certificate_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/certificates/me", get(handler::my_certificates))
+        .route(
+            "/certificates/me/{code}/image",
+            get(handler::my_certificate_image),
+        )
+        .route("/certificates/{code}", get(handler::verify_certificate))
+}
@@ -0,0 +1,218 @@
+use rand::Rng;
+use sqlx::PgConnection;
+
+use super::{
+    dto::{CertificateStatus, CertificateVerifyRes, MyCertificateListRes, MyCertificateRes},
+    render::{self, CertificateContent},
+    repo,
+};
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::state::AppState;
+
+/// 검증 코드 문자 (0/O, 1/I 등 헷갈리는 문자 제외, 32자 → 문자당 5비트)
+const CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+const CODE_PREFIX: &str = "AMK";
+const CODE_LEN: usize = 12;
+
+/// 검증 코드 생성: AMK-XXXX-XXXX-XXXX (60비트)
+pub fn generate_code() -> String {
+    let mut rng = rand::thread_rng();
+    let body: String = (0..CODE_LEN)
+        .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+        .collect();
+    format_code(&body)
+}
+
+fn format_code(body: &str) -> String {
+    format!(
+        "{CODE_PREFIX}-{}-{}-{}",
+        &body[0..4],
+        &body[4..8],
+        &body[8..12]
+    )
+}
+
+/// 사용자 입력 코드 정규화 — 대소문자/하이픈/공백/접두어 생략 허용, 형식이 틀리면 None
+pub fn normalize_code(input: &str) -> Option<String> {
+    let compact: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_ascii_uppercase();
+    let body = compact.strip_prefix(CODE_PREFIX).unwrap_or(&compact);
+
+    if body.len() == CODE_LEN && body.bytes().all(|b| CODE_ALPHABET.contains(&b)) {
+        Some(format_code(body))
+    } else {
+        None
+    }
+}
+
+/// 공개 검증용 이름 마스킹 — 단어마다 첫 글자만 노출 (홍길동 → 홍**, John Smith → J*** S****)
+pub fn mask_name(name: &str) -> String {
+    name.split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => {
+                    let rest = chars.count();
+                    format!("{first}{}", "*".repeat(rest))
+                }
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn verify_url(frontend_url: &str, code: &str) -> String {
+    format!("{}/certificates/{code}", frontend_url.trim_end_matches('/'))
+}
+
+/// 진도율 100% 도달한 수강 건에 수료증 발급 (진도 재계산 직후 호출)
+///
+/// 이미 발급(또는 폐기)된 (user_id, course_id) 는 건너뜀. 반환값은 새로 발급한 건수.
+pub async fn issue_for_completed(
+    conn: &mut PgConnection,
+    user_id: Option<i64>,
+    course_id: Option<i64>,
+) -> AppResult<u64> {
+    let pending = repo::find_uncertified_completions(conn, user_id, course_id).await?;
+
+    let mut issued = 0;
+    for row in &pending {
+        if repo::insert_certificate(conn, &generate_code(), row).await? {
+            issued += 1;
+            tracing::info!(
+                user_id = row.user_id,
+                course_id = row.course_id,
+                "Course certificate issued"
+            );
+        }
+    }
+    Ok(issued)
+}
+
+pub struct CertificateService;
+
+impl CertificateService {
+    /// 내 수료증 목록
+    pub async fn list_mine(st: &AppState, user_id: i64) -> AppResult<MyCertificateListRes> {
+        let rows = repo::find_by_user(&st.db, user_id).await?;
+        let items = rows
+            .into_iter()
+            .map(|r| MyCertificateRes {
+                verify_url: verify_url(&st.cfg.frontend_url, &r.certificate_code),
+                certificate_id: r.certificate_id,
+                certificate_code: r.certificate_code,
+                course_id: r.course_id,
+                course_title: r.course_title,
+                certificate_issued_at: r.certificate_issued_at,
+                certificate_revoked_at: r.certificate_revoked_at,
+            })
+            .collect();
+        Ok(MyCertificateListRes { items })
+    }
+
+    /// 공개 검증 — 마스킹된 이름/코스명/발급일/상태만 반환
+    pub async fn verify(st: &AppState, code: &str) -> AppResult<CertificateVerifyRes> {
+        let code = normalize_code(code).ok_or(AppError::NotFound)?;
+        let row = repo::find_holder_by_code(&st.db, &code)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let name = crypto.decrypt(&row.user_name_enc, "users.user_name")?;
+
+        Ok(CertificateVerifyRes {
+            certificate_code: row.certificate_code,
+            status: if row.certificate_revoked_at.is_some() {
+                CertificateStatus::Revoked
+            } else {
+                CertificateStatus::Valid
+            },
+            holder_name: mask_name(&name),
+            course_title: row.course_title,
+            certificate_issued_at: row.certificate_issued_at,
+            certificate_revoked_at: row.certificate_revoked_at,
+        })
+    }
+
+    /// 내 수료증 PNG — 본인 것만, 폐기된 수료증은 발급 불가
+    pub async fn render_mine(st: &AppState, user_id: i64, code: &str) -> AppResult<Vec<u8>> {
+        let code = normalize_code(code).ok_or(AppError::NotFound)?;
+        let row = repo::find_holder_by_code(&st.db, &code)
+            .await?
+            .filter(|r| r.user_id == user_id)
+            .ok_or(AppError::NotFound)?;
+        if row.certificate_revoked_at.is_some() {
+            return Err(AppError::Conflict("CERTIFICATE_REVOKED".into()));
+        }
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let name = crypto.decrypt(&row.user_name_enc, "users.user_name")?;
+        let url = verify_url(&st.cfg.frontend_url, &row.certificate_code);
+
+        tracing::info!(
+            user_id,
+            certificate_id = row.certificate_id,
+            "Certificate image rendered"
+        );
+
+        render::render_png(&CertificateContent {
+            holder_name: &name,
+            course_title: &row.course_title,
+            issued_at: row.certificate_issued_at,
+            code: &row.certificate_code,
+            verify_url: &url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_code_round_trips_through_normalize() {
+        for _ in 0..50 {
+            let code = generate_code();
+            assert_eq!(code.len(), CODE_PREFIX.len() + CODE_LEN + 3);
+            assert_eq!(normalize_code(&code).as_deref(), Some(code.as_str()));
+        }
+    }
+
+    #[test]
+    fn normalize_accepts_loose_input() {
+        let expected = Some("AMK-2345-6789-ABCD".to_string());
+        assert_eq!(normalize_code("amk-2345-6789-abcd"), expected);
+        assert_eq!(normalize_code("23456789ABCD"), expected);
+        assert_eq!(normalize_code(" AMK 2345 6789 ABCD "), expected);
+    }
+
+    #[test]
+    fn normalize_rejects_bad_input() {
+        assert_eq!(normalize_code(""), None);
+        assert_eq!(normalize_code("AMK-2345-6789"), None);
+        // 0, 1, O, I 는 알파벳에 없음
+        assert_eq!(normalize_code("AMK-0000-1111-OOII"), None);
+        assert_eq!(normalize_code("AMK-2345-6789-ABCD-EF"), None);
+    }
+
+    #[test]
+    fn mask_name_keeps_first_char_per_word() {
+        assert_eq!(mask_name("홍길동"), "홍**");
+        assert_eq!(mask_name("John Smith"), "J*** S****");
+        assert_eq!(mask_name("A"), "A");
+        assert_eq!(mask_name("  "), "");
+    }
+
+    #[test]
+    fn verify_url_trims_trailing_slash() {
+        assert_eq!(
+            verify_url("https://amazingkorean.net/", "AMK-2345-6789-ABCD"),
+            "https://amazingkorean.net/certificates/AMK-2345-6789-ABCD"
+        );
+    }
+}
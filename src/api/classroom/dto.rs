@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+// =============================================================================
+// 클래스 (manager)
+// =============================================================================
+
+/// 클래스 생성 요청
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateClassroomReq {
+    #[validate(length(min = 1, max = 120))]
+    pub classroom_name: String,
+    #[validate(length(max = 2000))]
+    pub classroom_description: Option<String>,
+}
+
+/// 클래스 수정 요청 — 지정한 필드만 변경
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateClassroomReq {
+    #[validate(length(min = 1, max = 120))]
+    pub classroom_name: Option<String>,
+    #[validate(length(max = 2000))]
+    pub classroom_description: Option<String>,
+    /// true 면 참여 코드 재발급 (이전 코드는 즉시 무효)
+    pub regenerate_join_code: Option<bool>,
+}
+
+/// 클래스 상세
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ClassroomRes {
+    pub classroom_id: i64,
+    pub owner_user_id: i64,
+    pub owner_nickname: String,
+    pub classroom_name: String,
+    pub classroom_description: Option<String>,
+    /// 학습자가 입력하는 참여 코드 (XXXX-XXXX)
+    pub classroom_join_code: String,
+    /// active 멤버 수
+    pub member_count: i64,
+    pub classroom_created_at: DateTime<Utc>,
+    pub classroom_updated_at: DateTime<Utc>,
+}
+
+/// 내가 관리하는 클래스 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassroomListRes {
+    pub items: Vec<ClassroomRes>,
+}
+
+// =============================================================================
+// 초대 / 참여
+// =============================================================================
+
+/// 이메일 초대 요청
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ClassroomInviteReq {
+    #[validate(email)]
+    pub email: String,
+}
+
+/// 이메일 초대 1건
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassroomInviteRes {
+    pub invite_id: i64,
+    pub email: String,
+    pub invite_created_at: DateTime<Utc>,
+    pub invite_expires_at: DateTime<Utc>,
+    pub invite_accepted_at: Option<DateTime<Utc>>,
+}
+
+/// 클래스 초대 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassroomInviteListRes {
+    pub classroom_id: i64,
+    pub items: Vec<ClassroomInviteRes>,
+}
+
+/// 참여 코드로 클래스 참여
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct JoinClassroomReq {
+    #[validate(length(min = 1, max = 32))]
+    pub join_code: String,
+}
+
+/// 이메일 초대 수락 (초대 메일 링크의 토큰)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AcceptClassroomInviteReq {
+    #[validate(length(min = 1, max = 128))]
+    pub token: String,
+}
+
+/// 학습자 관점의 참여 클래스
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct JoinedClassroomRes {
+    pub classroom_id: i64,
+    pub classroom_name: String,
+    pub classroom_description: Option<String>,
+    pub owner_nickname: String,
+    pub member_joined_at: DateTime<Utc>,
+}
+
+/// 내가 참여 중인 클래스 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct JoinedClassroomListRes {
+    pub items: Vec<JoinedClassroomRes>,
+}
+
+// =============================================================================
+// 학습자 진도
+// =============================================================================
+
+/// 학습자 목록/진도 조회 옵션
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ClassroomLearnerQuery {
+    /// true 면 실명 포함 (복호화 감사 로그 기록)
+    pub include_names: Option<bool>,
+}
+
+/// 학습자 진도 요약 (비디오/레슨/학습/가이드)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LearnerProgressSummary {
+    pub videos_watched: i64,
+    pub videos_completed: i64,
+    /// 실제 시청 구간 합계 (초, 중복 시청 제외)
+    pub video_watched_sec: i64,
+    pub lessons_started: i64,
+    pub lessons_completed: i64,
+    /// 시작한 레슨의 평균 진도율 (%)
+    pub lesson_avg_percent: i32,
+    pub study_tasks_attempted: i64,
+    pub study_tasks_solved: i64,
+    pub guide_sentences_attempted: i64,
+    pub guide_sentences_solved: i64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+/// 클래스 학습자 1명
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassroomLearnerRes {
+    pub user_id: i64,
+    pub nickname: String,
+    /// 실명 — include_names 일 때만 채움
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub member_joined_at: DateTime<Utc>,
+    pub progress: LearnerProgressSummary,
+}
+
+/// 클래스 학습자 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassroomLearnerListRes {
+    pub classroom_id: i64,
+    pub items: Vec<ClassroomLearnerRes>,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LearnerVideoProgress {
+    pub video_id: i32,
+    pub video_idx: String,
+    pub video_title: String,
+    pub progress_percent: Option<i32>,
+    pub completed: bool,
+    pub watched_sec: i32,
+    pub last_watched_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LearnerLessonProgress {
+    pub lesson_id: i32,
+    pub lesson_idx: String,
+    pub lesson_title: String,
+    pub progress_percent: i32,
+    pub last_progress_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LearnerStudyProgress {
+    pub study_id: i32,
+    pub study_idx: String,
+    pub study_title: Option<String>,
+    pub task_total: i64,
+    pub task_attempted: i64,
+    pub task_solved: i64,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LearnerGuideProgress {
+    pub guide_id: i64,
+    pub guide_idx: String,
+    pub guide_title: Option<String>,
+    pub sentence_total: i64,
+    pub sentence_attempted: i64,
+    pub sentence_solved: i64,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// 학습자 1명의 콘텐츠별 진도 상세
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassroomLearnerProgressRes {
+    pub classroom_id: i64,
+    pub learner: ClassroomLearnerRes,
+    pub videos: Vec<LearnerVideoProgress>,
+    pub lessons: Vec<LearnerLessonProgress>,
+    pub studies: Vec<LearnerStudyProgress>,
+    pub guides: Vec<LearnerGuideProgress>,
+}
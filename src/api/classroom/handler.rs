@@ -0,0 +1,381 @@
+use super::{
+    dto::{
+        AcceptClassroomInviteReq, ClassroomInviteListRes, ClassroomInviteReq, ClassroomInviteRes,
+        ClassroomLearnerListRes, ClassroomLearnerProgressRes, ClassroomLearnerQuery,
+        ClassroomListRes, ClassroomRes, CreateClassroomReq, JoinClassroomReq,
+        JoinedClassroomListRes, JoinedClassroomRes, UpdateClassroomReq,
+    },
+    service::ClassroomService,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::extract::AppJson;
+use crate::{
+    api::auth::extractor::AuthUser,
+    error::{AppError, AppResult},
+    state::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use validator::Validate;
+
+// =============================================================================
+// manager
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/classes",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "Managed classrooms (own classrooms for managers, all for HYMN/admin)", body = ClassroomListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_classrooms(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<ClassroomListRes>> {
+    let res = ClassroomService::list_managed(&st, claims.sub).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/classes",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    request_body = CreateClassroomReq,
+    responses(
+        (status = 201, description = "Classroom created", body = ClassroomRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn create_classroom(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    headers: HeaderMap,
+    AppJson(req): AppJson<CreateClassroomReq>,
+) -> AppResult<(StatusCode, Json<ClassroomRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = ClassroomService::create(
+        &st,
+        claims.sub,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/classes/{classroom_id}",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID")
+    ),
+    responses(
+        (status = 200, description = "Classroom detail", body = ClassroomRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_classroom(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(classroom_id): Path<i64>,
+) -> AppResult<Json<ClassroomRes>> {
+    let res = ClassroomService::get(&st, claims.sub, classroom_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/classes/{classroom_id}",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID")
+    ),
+    request_body = UpdateClassroomReq,
+    responses(
+        (status = 200, description = "Classroom updated", body = ClassroomRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn update_classroom(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(classroom_id): Path<i64>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<UpdateClassroomReq>,
+) -> AppResult<Json<ClassroomRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = ClassroomService::update(
+        &st,
+        claims.sub,
+        classroom_id,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/classes/{classroom_id}/invites",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID")
+    ),
+    responses(
+        (status = 200, description = "Email invites of the classroom", body = ClassroomInviteListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_invites(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(classroom_id): Path<i64>,
+) -> AppResult<Json<ClassroomInviteListRes>> {
+    let res = ClassroomService::list_invites(&st, claims.sub, classroom_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/classes/{classroom_id}/invites",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID")
+    ),
+    request_body = ClassroomInviteReq,
+    responses(
+        (status = 201, description = "Invite email sent", body = ClassroomInviteRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Already an active member", body = crate::error::ErrorBody),
+        (status = 503, description = "Email service not configured", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn invite_learner(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(classroom_id): Path<i64>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<ClassroomInviteReq>,
+) -> AppResult<(StatusCode, Json<ClassroomInviteRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = ClassroomService::invite(
+        &st,
+        claims.sub,
+        classroom_id,
+        &req.email,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/classes/{classroom_id}/learners",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID"),
+        ClassroomLearnerQuery
+    ),
+    responses(
+        (status = 200, description = "Active learners with progress summary (real names only with include_names, audited)", body = ClassroomLearnerListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_learners(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(classroom_id): Path<i64>,
+    Query(query): Query<ClassroomLearnerQuery>,
+    headers: HeaderMap,
+) -> AppResult<Json<ClassroomLearnerListRes>> {
+    let res = ClassroomService::list_learners(
+        &st,
+        claims.sub,
+        classroom_id,
+        query.include_names.unwrap_or(false),
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/classes/{classroom_id}/learners/{user_id}/progress",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID"),
+        ("user_id" = i64, Path, description = "Learner user ID"),
+        ClassroomLearnerQuery
+    ),
+    responses(
+        (status = 200, description = "Learner progress across videos, lessons, studies and guides (real name only with include_names, audited)", body = ClassroomLearnerProgressRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom or learner not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn learner_progress(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path((classroom_id, user_id)): Path<(i64, i64)>,
+    Query(query): Query<ClassroomLearnerQuery>,
+    headers: HeaderMap,
+) -> AppResult<Json<ClassroomLearnerProgressRes>> {
+    let res = ClassroomService::learner_progress(
+        &st,
+        claims.sub,
+        classroom_id,
+        user_id,
+        query.include_names.unwrap_or(false),
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/classes/{classroom_id}/learners/{user_id}",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    params(
+        ("classroom_id" = i64, Path, description = "Classroom ID"),
+        ("user_id" = i64, Path, description = "Learner user ID")
+    ),
+    responses(
+        (status = 204, description = "Learner removed"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom or learner not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn remove_learner(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path((classroom_id, user_id)): Path<(i64, i64)>,
+    headers: HeaderMap,
+) -> AppResult<StatusCode> {
+    ClassroomService::remove_learner(
+        &st,
+        claims.sub,
+        classroom_id,
+        user_id,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// =============================================================================
+// 학습자
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/classes/joined",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "Classrooms I joined", body = JoinedClassroomListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_joined(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<JoinedClassroomListRes>> {
+    let res = ClassroomService::list_joined(&st, claims.sub).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/classes/join",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    request_body = JoinClassroomReq,
+    responses(
+        (status = 200, description = "Joined classroom", body = JoinedClassroomRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Removed from the classroom", body = crate::error::ErrorBody),
+        (status = 404, description = "Invalid join code", body = crate::error::ErrorBody),
+        (status = 409, description = "Owner cannot join own classroom", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn join_classroom(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    AppJson(req): AppJson<JoinClassroomReq>,
+) -> AppResult<Json<JoinedClassroomRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = ClassroomService::join_by_code(&st, claims.sub, &req.join_code).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/classes/invites/accept",
+    tag = "Classroom",
+    security(("bearerAuth" = [])),
+    request_body = AcceptClassroomInviteReq,
+    responses(
+        (status = 200, description = "Invite accepted", body = JoinedClassroomRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Invite was sent to a different email", body = crate::error::ErrorBody),
+        (status = 404, description = "Invite not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Invite already used or expired", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn accept_invite(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    AppJson(req): AppJson<AcceptClassroomInviteReq>,
+) -> AppResult<Json<JoinedClassroomRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = ClassroomService::accept_invite(&st, claims.sub, &req.token).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,536 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+
+use super::dto::{
+    ClassroomRes, JoinedClassroomRes, LearnerGuideProgress, LearnerLessonProgress,
+    LearnerStudyProgress, LearnerVideoProgress,
+};
+use crate::error::AppResult;
+use crate::types::ClassroomMemberState;
+
+const CLASSROOM_SELECT: &str = r#"
+    SELECT
+        c.classroom_id,
+        c.owner_user_id,
+        u.user_nickname AS owner_nickname,
+        c.classroom_name,
+        c.classroom_description,
+        c.classroom_join_code,
+        (SELECT COUNT(*) FROM classroom_member cm
+          WHERE cm.classroom_id = c.classroom_id AND cm.member_state = 'active') AS member_count,
+        c.classroom_created_at,
+        c.classroom_updated_at
+    FROM classroom c
+    JOIN users u ON u.user_id = c.owner_user_id
+"#;
+
+/// 클래스 생성 — 참여 코드 충돌 시 None (호출 측에서 새 코드로 재시도)
+pub async fn insert_classroom(
+    pool: &PgPool,
+    owner_user_id: i64,
+    name: &str,
+    description: Option<&str>,
+    join_code: &str,
+) -> AppResult<Option<i64>> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO classroom (owner_user_id, classroom_name, classroom_description, classroom_join_code)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (classroom_join_code) DO NOTHING
+        RETURNING classroom_id
+        "#,
+    )
+    .bind(owner_user_id)
+    .bind(name)
+    .bind(description)
+    .bind(join_code)
+    .fetch_optional(pool)
+    .await?;
+    Ok(id)
+}
+
+pub async fn find_by_id(pool: &PgPool, classroom_id: i64) -> AppResult<Option<ClassroomRes>> {
+    let sql = format!("{CLASSROOM_SELECT} WHERE c.classroom_id = $1");
+    let row = sqlx::query_as::<_, ClassroomRes>(&sql)
+        .bind(classroom_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+pub async fn find_by_join_code(pool: &PgPool, join_code: &str) -> AppResult<Option<ClassroomRes>> {
+    let sql = format!("{CLASSROOM_SELECT} WHERE c.classroom_join_code = $1");
+    let row = sqlx::query_as::<_, ClassroomRes>(&sql)
+        .bind(join_code)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 관리 클래스 목록 — owner_user_id 가 None 이면 전체 (HYMN/admin)
+pub async fn find_managed(
+    pool: &PgPool,
+    owner_user_id: Option<i64>,
+) -> AppResult<Vec<ClassroomRes>> {
+    let sql = format!(
+        "{CLASSROOM_SELECT} WHERE ($1::bigint IS NULL OR c.owner_user_id = $1) ORDER BY c.classroom_created_at DESC"
+    );
+    let rows = sqlx::query_as::<_, ClassroomRes>(&sql)
+        .bind(owner_user_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 이름/설명/참여 코드 변경 (None 필드는 유지)
+pub async fn update_classroom(
+    pool: &PgPool,
+    classroom_id: i64,
+    name: Option<&str>,
+    description: Option<&str>,
+    join_code: Option<&str>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE classroom
+        SET classroom_name        = COALESCE($2, classroom_name),
+            classroom_description = COALESCE($3, classroom_description),
+            classroom_join_code   = COALESCE($4, classroom_join_code),
+            classroom_updated_at  = NOW()
+        WHERE classroom_id = $1
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(name)
+    .bind(description)
+    .bind(join_code)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// =============================================================================
+// 멤버
+// =============================================================================
+
+pub async fn find_member_state(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<Option<ClassroomMemberState>> {
+    let state = sqlx::query_scalar::<_, ClassroomMemberState>(
+        "SELECT member_state FROM classroom_member WHERE classroom_id = $1 AND user_id = $2",
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(state)
+}
+
+/// 멤버 등록 — 제거됐던 멤버는 active 로 복귀 (참여 시각 갱신)
+pub async fn upsert_active_member(
+    conn: &mut PgConnection,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO classroom_member (classroom_id, user_id)
+        VALUES ($1, $2)
+        ON CONFLICT (classroom_id, user_id) DO UPDATE
+        SET member_state              = 'active',
+            member_joined_at          = CASE WHEN classroom_member.member_state = 'removed'
+                                             THEN NOW() ELSE classroom_member.member_joined_at END,
+            member_removed_at         = NULL,
+            member_removed_by_user_id = NULL
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// active 멤버 제거 — 대상이 active 멤버가 아니면 false
+pub async fn remove_member(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+    removed_by_user_id: i64,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE classroom_member
+        SET member_state              = 'removed',
+            member_removed_at         = NOW(),
+            member_removed_by_user_id = $3
+        WHERE classroom_id = $1 AND user_id = $2 AND member_state = 'active'
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .bind(removed_by_user_id)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn find_joined(pool: &PgPool, user_id: i64) -> AppResult<Vec<JoinedClassroomRes>> {
+    let rows = sqlx::query_as::<_, JoinedClassroomRes>(
+        r#"
+        SELECT c.classroom_id, c.classroom_name, c.classroom_description,
+               u.user_nickname AS owner_nickname, cm.member_joined_at
+        FROM classroom_member cm
+        JOIN classroom c ON c.classroom_id = cm.classroom_id
+        JOIN users u ON u.user_id = c.owner_user_id
+        WHERE cm.user_id = $1 AND cm.member_state = 'active'
+        ORDER BY cm.member_joined_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn find_joined_one(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<Option<JoinedClassroomRes>> {
+    let row = sqlx::query_as::<_, JoinedClassroomRes>(
+        r#"
+        SELECT c.classroom_id, c.classroom_name, c.classroom_description,
+               u.user_nickname AS owner_nickname, cm.member_joined_at
+        FROM classroom_member cm
+        JOIN classroom c ON c.classroom_id = cm.classroom_id
+        JOIN users u ON u.user_id = c.owner_user_id
+        WHERE cm.classroom_id = $1 AND cm.user_id = $2 AND cm.member_state = 'active'
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+// =============================================================================
+// 이메일 초대
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct InviteRow {
+    pub invite_id: i64,
+    pub classroom_id: i64,
+    pub invite_email: String,
+    pub invite_email_idx: String,
+    pub invite_created_at: DateTime<Utc>,
+    pub invite_expires_at: DateTime<Utc>,
+    pub invite_accepted_at: Option<DateTime<Utc>>,
+}
+
+const INVITE_SELECT: &str = r#"
+    SELECT invite_id, classroom_id, invite_email, invite_email_idx,
+           invite_created_at, invite_expires_at, invite_accepted_at
+    FROM classroom_invite
+"#;
+
+pub struct NewInvite<'a> {
+    pub classroom_id: i64,
+    pub email_enc: &'a str,
+    pub email_idx: &'a str,
+    pub token_hash: &'a str,
+    pub invited_by_user_id: i64,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub async fn insert_invite(pool: &PgPool, invite: &NewInvite<'_>) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO classroom_invite
+            (classroom_id, invite_email, invite_email_idx, invite_token_hash,
+             invited_by_user_id, invite_expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING invite_id
+        "#,
+    )
+    .bind(invite.classroom_id)
+    .bind(invite.email_enc)
+    .bind(invite.email_idx)
+    .bind(invite.token_hash)
+    .bind(invite.invited_by_user_id)
+    .bind(invite.expires_at)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+pub async fn find_invites(pool: &PgPool, classroom_id: i64) -> AppResult<Vec<InviteRow>> {
+    let sql = format!("{INVITE_SELECT} WHERE classroom_id = $1 ORDER BY invite_created_at DESC");
+    let rows = sqlx::query_as::<_, InviteRow>(&sql)
+        .bind(classroom_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 토큰 해시로 초대 조회 (수락 경합 방지를 위해 행 잠금)
+pub async fn find_invite_by_token_hash_for_update(
+    conn: &mut PgConnection,
+    token_hash: &str,
+) -> AppResult<Option<InviteRow>> {
+    let sql = format!("{INVITE_SELECT} WHERE invite_token_hash = $1 FOR UPDATE");
+    let row = sqlx::query_as::<_, InviteRow>(&sql)
+        .bind(token_hash)
+        .fetch_optional(&mut *conn)
+        .await?;
+    Ok(row)
+}
+
+pub async fn mark_invite_accepted(
+    conn: &mut PgConnection,
+    invite_id: i64,
+    user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE classroom_invite
+        SET invite_accepted_at = NOW(), invite_accepted_user_id = $2
+        WHERE invite_id = $1
+        "#,
+    )
+    .bind(invite_id)
+    .bind(user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub async fn find_user_email_idx(pool: &PgPool, user_id: i64) -> AppResult<Option<String>> {
+    let idx =
+        sqlx::query_scalar::<_, String>("SELECT user_email_idx FROM users WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(idx)
+}
+
+/// 초대 이메일의 사용자가 이미 active 멤버인지
+pub async fn is_active_member_by_email_idx(
+    pool: &PgPool,
+    classroom_id: i64,
+    email_idx: &str,
+) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM classroom_member cm
+            JOIN users u ON u.user_id = cm.user_id
+            WHERE cm.classroom_id = $1 AND cm.member_state = 'active'
+              AND u.user_email_idx = $2
+        )
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(email_idx)
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
+
+// =============================================================================
+// 학습자 진도 — 모든 쿼리가 classroom_member(active) 를 경유 (클래스 밖 학습자 조회 불가)
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct LearnerSummaryRow {
+    pub user_id: i64,
+    pub user_nickname: String,
+    pub user_name_enc: String,
+    pub member_joined_at: DateTime<Utc>,
+    pub videos_watched: i64,
+    pub videos_completed: i64,
+    pub video_watched_sec: i64,
+    pub lessons_started: i64,
+    pub lessons_completed: i64,
+    pub lesson_avg_percent: i32,
+    pub study_tasks_attempted: i64,
+    pub study_tasks_solved: i64,
+    pub guide_sentences_attempted: i64,
+    pub guide_sentences_solved: i64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+/// 클래스 학습자별 진도 요약 — user_id 지정 시 해당 학습자 1명만
+pub async fn find_learner_summaries(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: Option<i64>,
+) -> AppResult<Vec<LearnerSummaryRow>> {
+    let rows = sqlx::query_as::<_, LearnerSummaryRow>(
+        r#"
+        SELECT
+            cm.user_id,
+            u.user_nickname,
+            u.user_name AS user_name_enc,
+            cm.member_joined_at,
+            v.videos_watched,
+            v.videos_completed,
+            v.video_watched_sec,
+            l.lessons_started,
+            l.lessons_completed,
+            l.lesson_avg_percent,
+            s.study_tasks_attempted,
+            s.study_tasks_solved,
+            g.guide_sentences_attempted,
+            g.guide_sentences_solved,
+            GREATEST(v.last_at, l.last_at, s.last_at, g.last_at) AS last_activity_at
+        FROM classroom_member cm
+        JOIN users u ON u.user_id = cm.user_id
+        CROSS JOIN LATERAL (
+            SELECT COUNT(DISTINCT video_id) AS videos_watched,
+                   COUNT(DISTINCT video_id) FILTER (WHERE video_completed_log) AS videos_completed,
+                   COALESCE(SUM(video_watched_sec), 0)::bigint AS video_watched_sec,
+                   MAX(video_last_watched_at_log) AS last_at
+            FROM video_log WHERE user_id = cm.user_id
+        ) v
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS lessons_started,
+                   COUNT(*) FILTER (WHERE lesson_progress_percent >= 100) AS lessons_completed,
+                   COALESCE(ROUND(AVG(lesson_progress_percent)), 0)::int AS lesson_avg_percent,
+                   MAX(lesson_progress_last_progress_at) AS last_at
+            FROM lesson_progress WHERE user_id = cm.user_id
+        ) l
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS study_tasks_attempted,
+                   COUNT(*) FILTER (WHERE study_task_status_is_solved) AS study_tasks_solved,
+                   MAX(study_task_status_last_attempt_at) AS last_at
+            FROM study_task_status WHERE user_id = cm.user_id
+        ) s
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS guide_sentences_attempted,
+                   COUNT(*) FILTER (WHERE guide_sentence_status_is_solved) AS guide_sentences_solved,
+                   MAX(guide_sentence_status_last_attempt_at) AS last_at
+            FROM guide_sentence_status WHERE user_id = cm.user_id
+        ) g
+        WHERE cm.classroom_id = $1
+          AND cm.member_state = 'active'
+          AND ($2::bigint IS NULL OR cm.user_id = $2)
+        ORDER BY cm.member_joined_at, cm.user_id
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn find_learner_videos(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<Vec<LearnerVideoProgress>> {
+    let rows = sqlx::query_as::<_, LearnerVideoProgress>(
+        r#"
+        SELECT vl.video_id, v.video_idx, v.video_title,
+               vl.video_progress_log AS progress_percent,
+               vl.video_completed_log AS completed,
+               vl.video_watched_sec AS watched_sec,
+               vl.video_last_watched_at_log AS last_watched_at
+        FROM classroom_member cm
+        JOIN video_log vl ON vl.user_id = cm.user_id
+        JOIN video v ON v.video_id = vl.video_id
+        WHERE cm.classroom_id = $1 AND cm.user_id = $2 AND cm.member_state = 'active'
+        ORDER BY vl.video_last_watched_at_log DESC NULLS LAST, vl.video_id
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn find_learner_lessons(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<Vec<LearnerLessonProgress>> {
+    let rows = sqlx::query_as::<_, LearnerLessonProgress>(
+        r#"
+        SELECT lp.lesson_id, l.lesson_idx, l.lesson_title,
+               lp.lesson_progress_percent AS progress_percent,
+               lp.lesson_progress_last_progress_at AS last_progress_at
+        FROM classroom_member cm
+        JOIN lesson_progress lp ON lp.user_id = cm.user_id
+        JOIN lesson l ON l.lesson_id = lp.lesson_id
+        WHERE cm.classroom_id = $1 AND cm.user_id = $2 AND cm.member_state = 'active'
+        ORDER BY lp.lesson_progress_last_progress_at DESC NULLS LAST, lp.lesson_id
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn find_learner_studies(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<Vec<LearnerStudyProgress>> {
+    let rows = sqlx::query_as::<_, LearnerStudyProgress>(
+        r#"
+        SELECT s.study_id, s.study_idx, s.study_title,
+               (SELECT COUNT(*) FROM study_task t WHERE t.study_id = s.study_id) AS task_total,
+               COUNT(*) AS task_attempted,
+               COUNT(*) FILTER (WHERE sts.study_task_status_is_solved) AS task_solved,
+               MAX(sts.study_task_status_last_attempt_at) AS last_attempt_at
+        FROM classroom_member cm
+        JOIN study_task_status sts ON sts.user_id = cm.user_id
+        JOIN study_task st ON st.study_task_id = sts.study_task_id
+        JOIN study s ON s.study_id = st.study_id
+        WHERE cm.classroom_id = $1 AND cm.user_id = $2 AND cm.member_state = 'active'
+        GROUP BY s.study_id, s.study_idx, s.study_title
+        ORDER BY last_attempt_at DESC NULLS LAST, s.study_id
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn find_learner_guides(
+    pool: &PgPool,
+    classroom_id: i64,
+    user_id: i64,
+) -> AppResult<Vec<LearnerGuideProgress>> {
+    let rows = sqlx::query_as::<_, LearnerGuideProgress>(
+        r#"
+        SELECT g.guide_id, g.guide_idx, g.title_ko AS guide_title,
+               (SELECT COUNT(*) FROM guide_sentence t WHERE t.guide_id = g.guide_id) AS sentence_total,
+               COUNT(*) AS sentence_attempted,
+               COUNT(*) FILTER (WHERE gss.guide_sentence_status_is_solved) AS sentence_solved,
+               MAX(gss.guide_sentence_status_last_attempt_at) AS last_attempt_at
+        FROM classroom_member cm
+        JOIN guide_sentence_status gss ON gss.user_id = cm.user_id
+        JOIN guide_sentence gs ON gs.guide_sentence_id = gss.guide_sentence_id
+        JOIN guide g ON g.guide_id = gs.guide_id
+        WHERE cm.classroom_id = $1 AND cm.user_id = $2 AND cm.member_state = 'active'
+        GROUP BY g.guide_id, g.guide_idx, g.title_ko
+        ORDER BY last_attempt_at DESC NULLS LAST, g.guide_id
+        "#,
+    )
+    .bind(classroom_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
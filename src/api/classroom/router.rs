@@ -0,0 +1,31 @@
+use super::handler;
+use crate::state::AppState;
+use axum::routing::{delete, get, post};
+
+pub fn classroom_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(
+            "/",
+            get(handler::list_classrooms).post(handler::create_classroom),
+        )
+        .route("/joined", get(handler::list_joined))
+        .route("/join", post(handler::join_classroom))
+        .route("/invites/accept", post(handler::accept_invite))
+        .route(
+            "/{classroom_id}",
+            get(handler::get_classroom).patch(handler::update_classroom),
+        )
+        .route(
+            "/{classroom_id}/invites",
+            get(handler::list_invites).post(handler::invite_learner),
+        )
+        .route("/{classroom_id}/learners", get(handler::list_learners))
+        .route(
+            "/{classroom_id}/learners/{user_id}",
+            delete(handler::remove_learner),
+        )
+        .route(
+            "/{classroom_id}/learners/{user_id}/progress",
+            get(handler::learner_progress),
+        )
+}
@@ -0,0 +1,649 @@
+use std::net::IpAddr;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{Duration, Utc};
+use rand::{Rng, RngCore};
+use sha2::{Digest, Sha256};
+
+use super::{
+    dto::{
+        ClassroomInviteListRes, ClassroomInviteRes, ClassroomLearnerListRes,
+        ClassroomLearnerProgressRes, ClassroomLearnerRes, ClassroomListRes, ClassroomRes,
+        CreateClassroomReq, JoinedClassroomListRes, JoinedClassroomRes, LearnerProgressSummary,
+        UpdateClassroomReq,
+    },
+    repo::{self, LearnerSummaryRow, NewInvite},
+};
+use crate::api::admin::user::repo::write_audit_log;
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::external::email::{send_templated, EmailTemplate};
+use crate::state::AppState;
+use crate::types::{ClassroomMemberState, UserAuth};
+
+/// 참여 코드 문자 (0/O, 1/I 등 헷갈리는 문자 제외)
+const JOIN_CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+const JOIN_CODE_LEN: usize = 8;
+/// 참여 코드 충돌 시 재생성 횟수 (32^8 공간이라 사실상 1회)
+const JOIN_CODE_ATTEMPTS: usize = 5;
+
+const INVITE_TTL_DAYS: i64 = 7;
+const INVITE_EMAIL_AAD: &str = "classroom_invite.invite_email";
+
+/// 참여 코드 생성: XXXX-XXXX
+pub fn generate_join_code() -> String {
+    let mut rng = rand::thread_rng();
+    let body: String = (0..JOIN_CODE_LEN)
+        .map(|_| JOIN_CODE_ALPHABET[rng.gen_range(0..JOIN_CODE_ALPHABET.len())] as char)
+        .collect();
+    format!("{}-{}", &body[0..4], &body[4..8])
+}
+
+/// 사용자 입력 참여 코드 정규화 — 대소문자/하이픈/공백 무시, 형식이 틀리면 None
+pub fn normalize_join_code(input: &str) -> Option<String> {
+    let body: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_ascii_uppercase();
+
+    if body.len() == JOIN_CODE_LEN && body.bytes().all(|b| JOIN_CODE_ALPHABET.contains(&b)) {
+        Some(format!("{}-{}", &body[0..4], &body[4..8]))
+    } else {
+        None
+    }
+}
+
+/// 초대 토큰 원문 생성 (메일 링크에만 포함, DB 에는 해시만 저장)
+fn generate_invite_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn hash_invite_token(token: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+/// 클래스 관리 권한 — manager 는 자기 클래스만, HYMN/admin 은 전체
+pub(crate) fn can_manage(actor_auth: UserAuth, actor_user_id: i64, owner_user_id: i64) -> bool {
+    match actor_auth {
+        UserAuth::Hymn | UserAuth::Admin => true,
+        UserAuth::Manager => actor_user_id == owner_user_id,
+        UserAuth::Learner => false,
+    }
+}
+
+pub(crate) async fn check_manager_rbac(
+    pool: &sqlx::PgPool,
+    actor_user_id: i64,
+) -> AppResult<UserAuth> {
+    let actor = crate::api::user::repo::find_user(pool, actor_user_id)
+        .await?
+        .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+
+    match actor.user_auth {
+        UserAuth::Hymn | UserAuth::Admin | UserAuth::Manager => Ok(actor.user_auth),
+        _ => Err(AppError::Forbidden("Forbidden".to_string())),
+    }
+}
+
+/// 관리 대상 클래스 로드 — 권한 밖 클래스는 존재 여부를 노출하지 않도록 404
+pub(crate) async fn load_managed(
+    st: &AppState,
+    actor_user_id: i64,
+    classroom_id: i64,
+) -> AppResult<ClassroomRes> {
+    let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+    repo::find_by_id(&st.db, classroom_id)
+        .await?
+        .filter(|c| can_manage(actor_auth, actor_user_id, c.owner_user_id))
+        .ok_or(AppError::NotFound)
+}
+
+/// 실명은 `crypto` 가 주어질 때만 복호화 (호출 측이 감사 로그를 먼저 남긴다)
+fn to_learner(
+    crypto: Option<&CryptoService<'_>>,
+    row: LearnerSummaryRow,
+) -> AppResult<ClassroomLearnerRes> {
+    Ok(ClassroomLearnerRes {
+        user_id: row.user_id,
+        nickname: row.user_nickname,
+        name: crypto
+            .map(|c| c.decrypt(&row.user_name_enc, "users.user_name"))
+            .transpose()?,
+        member_joined_at: row.member_joined_at,
+        progress: LearnerProgressSummary {
+            videos_watched: row.videos_watched,
+            videos_completed: row.videos_completed,
+            video_watched_sec: row.video_watched_sec,
+            lessons_started: row.lessons_started,
+            lessons_completed: row.lessons_completed,
+            lesson_avg_percent: row.lesson_avg_percent,
+            study_tasks_attempted: row.study_tasks_attempted,
+            study_tasks_solved: row.study_tasks_solved,
+            guide_sentences_attempted: row.guide_sentences_attempted,
+            guide_sentences_solved: row.guide_sentences_solved,
+            last_activity_at: row.last_activity_at,
+        },
+    })
+}
+
+pub struct ClassroomService;
+
+impl ClassroomService {
+    // =========================================================================
+    // manager
+    // =========================================================================
+
+    /// 클래스 생성 (요청자가 owner)
+    pub async fn create(
+        st: &AppState,
+        actor_user_id: i64,
+        req: CreateClassroomReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<ClassroomRes> {
+        check_manager_rbac(&st.db, actor_user_id).await?;
+
+        let name = req.classroom_name.trim();
+        if name.is_empty() {
+            return Err(AppError::BadRequest("classroom_name is required".into()));
+        }
+
+        let mut classroom_id = None;
+        for _ in 0..JOIN_CODE_ATTEMPTS {
+            classroom_id = repo::insert_classroom(
+                &st.db,
+                actor_user_id,
+                name,
+                req.classroom_description.as_deref(),
+                &generate_join_code(),
+            )
+            .await?;
+            if classroom_id.is_some() {
+                break;
+            }
+        }
+        let classroom_id = classroom_id
+            .ok_or_else(|| AppError::Internal("Failed to allocate classroom join code".into()))?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "CREATE_CLASSROOM",
+            "classroom",
+            Some(classroom_id),
+            &serde_json::json!({ "classroom_name": name }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        repo::find_by_id(&st.db, classroom_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 관리 클래스 목록 — manager 는 자기 클래스, HYMN/admin 은 전체
+    pub async fn list_managed(st: &AppState, actor_user_id: i64) -> AppResult<ClassroomListRes> {
+        let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+        let owner = match actor_auth {
+            UserAuth::Manager => Some(actor_user_id),
+            _ => None,
+        };
+        let items = repo::find_managed(&st.db, owner).await?;
+        Ok(ClassroomListRes { items })
+    }
+
+    pub async fn get(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+    ) -> AppResult<ClassroomRes> {
+        load_managed(st, actor_user_id, classroom_id).await
+    }
+
+    /// 클래스 수정 / 참여 코드 재발급
+    pub async fn update(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+        req: UpdateClassroomReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<ClassroomRes> {
+        let before = load_managed(st, actor_user_id, classroom_id).await?;
+
+        let name = req.classroom_name.as_deref().map(str::trim);
+        if name.is_some_and(str::is_empty) {
+            return Err(AppError::BadRequest(
+                "classroom_name must not be empty".into(),
+            ));
+        }
+        let regenerate = req.regenerate_join_code.unwrap_or(false);
+        if name.is_none() && req.classroom_description.is_none() && !regenerate {
+            return Err(AppError::BadRequest("No fields to update".into()));
+        }
+
+        let mut updated = false;
+        for _ in 0..JOIN_CODE_ATTEMPTS {
+            let join_code = regenerate.then(generate_join_code);
+            match repo::update_classroom(
+                &st.db,
+                classroom_id,
+                name,
+                req.classroom_description.as_deref(),
+                join_code.as_deref(),
+            )
+            .await
+            {
+                Ok(()) => {
+                    updated = true;
+                    break;
+                }
+                Err(e) if regenerate && is_unique_violation(&e) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        if !updated {
+            return Err(AppError::Internal(
+                "Failed to allocate classroom join code".into(),
+            ));
+        }
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "UPDATE_CLASSROOM",
+            "classroom",
+            Some(classroom_id),
+            &serde_json::json!({
+                "before": { "classroom_name": before.classroom_name },
+                "classroom_name": name,
+                "description_changed": req.classroom_description.is_some(),
+                "join_code_regenerated": regenerate,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        repo::find_by_id(&st.db, classroom_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 이메일 초대 발송
+    pub async fn invite(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+        email: &str,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<ClassroomInviteRes> {
+        let classroom = load_managed(st, actor_user_id, classroom_id).await?;
+        let email_sender = st
+            .email
+            .as_ref()
+            .ok_or_else(|| AppError::ServiceUnavailable("Email service not configured".into()))?;
+
+        let email = email.trim().to_lowercase();
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let email_idx = crypto.blind_index(&email)?;
+        if repo::is_active_member_by_email_idx(&st.db, classroom_id, &email_idx).await? {
+            return Err(AppError::Conflict("CLASSROOM_409_ALREADY_MEMBER".into()));
+        }
+
+        let token = generate_invite_token();
+        let expires_at = Utc::now() + Duration::days(INVITE_TTL_DAYS);
+        let email_enc = crypto.encrypt(&email, INVITE_EMAIL_AAD)?;
+        let invite_id = repo::insert_invite(
+            &st.db,
+            &NewInvite {
+                classroom_id,
+                email_enc: &email_enc,
+                email_idx: &email_idx,
+                token_hash: &hash_invite_token(&token),
+                invited_by_user_id: actor_user_id,
+                expires_at,
+            },
+        )
+        .await?;
+
+        let invited_by = crate::api::user::repo::find_user(&st.db, actor_user_id)
+            .await?
+            .and_then(|u| u.nickname)
+            .unwrap_or_default();
+        send_templated(
+            email_sender.as_ref(),
+            &email,
+            EmailTemplate::ClassroomInvite {
+                invite_url: format!(
+                    "{}/classes/join?invite={}",
+                    st.cfg.frontend_url.trim_end_matches('/'),
+                    token
+                ),
+                classroom_name: classroom.classroom_name.clone(),
+                invited_by,
+                expires_in_days: INVITE_TTL_DAYS as i32,
+            },
+        )
+        .await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "INVITE_CLASSROOM_LEARNER",
+            "classroom_invite",
+            Some(invite_id),
+            &serde_json::json!({ "classroom_id": classroom_id }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        tracing::info!(
+            actor_user_id,
+            classroom_id,
+            invite_id,
+            "Classroom invite sent"
+        );
+
+        Ok(ClassroomInviteRes {
+            invite_id,
+            email,
+            invite_created_at: Utc::now(),
+            invite_expires_at: expires_at,
+            invite_accepted_at: None,
+        })
+    }
+
+    /// 클래스 초대 목록
+    pub async fn list_invites(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+    ) -> AppResult<ClassroomInviteListRes> {
+        load_managed(st, actor_user_id, classroom_id).await?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let items = repo::find_invites(&st.db, classroom_id)
+            .await?
+            .into_iter()
+            .map(|r| {
+                Ok(ClassroomInviteRes {
+                    invite_id: r.invite_id,
+                    email: crypto.decrypt(&r.invite_email, INVITE_EMAIL_AAD)?,
+                    invite_created_at: r.invite_created_at,
+                    invite_expires_at: r.invite_expires_at,
+                    invite_accepted_at: r.invite_accepted_at,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        Ok(ClassroomInviteListRes {
+            classroom_id,
+            items,
+        })
+    }
+
+    /// 클래스 학습자 + 진도 요약 — 실명은 include_names 일 때만 (감사 로그)
+    pub async fn list_learners(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+        include_names: bool,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<ClassroomLearnerListRes> {
+        load_managed(st, actor_user_id, classroom_id).await?;
+        let rows = repo::find_learner_summaries(&st.db, classroom_id, None).await?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        if include_names {
+            write_audit_log(
+                st,
+                actor_user_id,
+                "DECRYPT_CLASSROOM_NAMES",
+                "classroom",
+                Some(classroom_id),
+                &serde_json::json!({ "learner_count": rows.len() }),
+                ip_address,
+                user_agent.as_deref(),
+            )
+            .await?;
+        }
+        let items = rows
+            .into_iter()
+            .map(|row| to_learner(include_names.then_some(&crypto), row))
+            .collect::<AppResult<Vec<_>>>()?;
+        Ok(ClassroomLearnerListRes {
+            classroom_id,
+            items,
+        })
+    }
+
+    /// 학습자 1명의 콘텐츠별 진도 — 클래스 active 멤버가 아니면 404
+    pub async fn learner_progress(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+        user_id: i64,
+        include_names: bool,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<ClassroomLearnerProgressRes> {
+        load_managed(st, actor_user_id, classroom_id).await?;
+        let row = repo::find_learner_summaries(&st.db, classroom_id, Some(user_id))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(AppError::NotFound)?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        if include_names {
+            write_audit_log(
+                st,
+                actor_user_id,
+                "DECRYPT_CLASSROOM_NAMES",
+                "classroom",
+                Some(classroom_id),
+                &serde_json::json!({ "user_id": user_id }),
+                ip_address,
+                user_agent.as_deref(),
+            )
+            .await?;
+        }
+        let learner = to_learner(include_names.then_some(&crypto), row)?;
+
+        Ok(ClassroomLearnerProgressRes {
+            classroom_id,
+            learner,
+            videos: repo::find_learner_videos(&st.db, classroom_id, user_id).await?,
+            lessons: repo::find_learner_lessons(&st.db, classroom_id, user_id).await?,
+            studies: repo::find_learner_studies(&st.db, classroom_id, user_id).await?,
+            guides: repo::find_learner_guides(&st.db, classroom_id, user_id).await?,
+        })
+    }
+
+    /// 학습자 제거 — 제거된 학습자는 참여 코드로 재참여 불가 (이메일 초대로만 복귀)
+    pub async fn remove_learner(
+        st: &AppState,
+        actor_user_id: i64,
+        classroom_id: i64,
+        user_id: i64,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        load_managed(st, actor_user_id, classroom_id).await?;
+        if !repo::remove_member(&st.db, classroom_id, user_id, actor_user_id).await? {
+            return Err(AppError::NotFound);
+        }
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "REMOVE_CLASSROOM_LEARNER",
+            "classroom_member",
+            Some(classroom_id),
+            &serde_json::json!({ "classroom_id": classroom_id, "user_id": user_id }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        tracing::info!(
+            actor_user_id,
+            classroom_id,
+            user_id,
+            "Classroom learner removed"
+        );
+        Ok(())
+    }
+
+    // =========================================================================
+    // 학습자
+    // =========================================================================
+
+    /// 참여 코드로 클래스 참여 (이미 참여 중이면 그대로 반환)
+    pub async fn join_by_code(
+        st: &AppState,
+        user_id: i64,
+        join_code: &str,
+    ) -> AppResult<JoinedClassroomRes> {
+        let code = normalize_join_code(join_code).ok_or(AppError::NotFound)?;
+        let classroom = repo::find_by_join_code(&st.db, &code)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if classroom.owner_user_id == user_id {
+            return Err(AppError::Conflict("CLASSROOM_409_OWNER_CANNOT_JOIN".into()));
+        }
+
+        match repo::find_member_state(&st.db, classroom.classroom_id, user_id).await? {
+            Some(ClassroomMemberState::Active) => {}
+            Some(ClassroomMemberState::Removed) => {
+                return Err(AppError::Forbidden("CLASSROOM_403_REMOVED".into()));
+            }
+            None => {
+                let mut conn = st.db.acquire().await?;
+                repo::upsert_active_member(&mut conn, classroom.classroom_id, user_id).await?;
+                tracing::info!(
+                    user_id,
+                    classroom_id = classroom.classroom_id,
+                    "Classroom joined by code"
+                );
+            }
+        }
+
+        repo::find_joined_one(&st.db, classroom.classroom_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 이메일 초대 수락 — 로그인 계정 이메일이 초대 이메일과 같아야 함
+    pub async fn accept_invite(
+        st: &AppState,
+        user_id: i64,
+        token: &str,
+    ) -> AppResult<JoinedClassroomRes> {
+        let user_email_idx = repo::find_user_email_idx(&st.db, user_id)
+            .await?
+            .ok_or(AppError::Unauthorized("User not found".into()))?;
+
+        let mut tx = st.db.begin().await?;
+        let invite = repo::find_invite_by_token_hash_for_update(&mut tx, &hash_invite_token(token))
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if invite.invite_accepted_at.is_some() {
+            return Err(AppError::Conflict("CLASSROOM_409_INVITE_USED".into()));
+        }
+        if invite.invite_expires_at <= Utc::now() {
+            return Err(AppError::Conflict("CLASSROOM_409_INVITE_EXPIRED".into()));
+        }
+        if invite.invite_email_idx != user_email_idx {
+            return Err(AppError::Forbidden(
+                "CLASSROOM_403_INVITE_EMAIL_MISMATCH".into(),
+            ));
+        }
+
+        repo::upsert_active_member(&mut tx, invite.classroom_id, user_id).await?;
+        repo::mark_invite_accepted(&mut tx, invite.invite_id, user_id).await?;
+        tx.commit().await?;
+
+        tracing::info!(
+            user_id,
+            classroom_id = invite.classroom_id,
+            invite_id = invite.invite_id,
+            "Classroom invite accepted"
+        );
+
+        repo::find_joined_one(&st.db, invite.classroom_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 내가 참여 중인 클래스
+    pub async fn list_joined(st: &AppState, user_id: i64) -> AppResult<JoinedClassroomListRes> {
+        let items = repo::find_joined(&st.db, user_id).await?;
+        Ok(JoinedClassroomListRes { items })
+    }
+}
+
+const PG_UNIQUE_VIOLATION: &str = "23505";
+
+fn is_unique_violation(err: &AppError) -> bool {
+    if let AppError::Sqlx(sqlx::Error::Database(db)) = err {
+        db.code().as_deref() == Some(PG_UNIQUE_VIOLATION)
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_code_round_trips_through_normalize() {
+        for _ in 0..50 {
+            let code = generate_join_code();
+            assert_eq!(code.len(), JOIN_CODE_LEN + 1);
+            assert_eq!(normalize_join_code(&code).as_deref(), Some(code.as_str()));
+        }
+    }
+
+    #[test]
+    fn normalize_join_code_accepts_loose_and_rejects_bad_input() {
+        let expected = Some("ABCD-2345".to_string());
+        assert_eq!(normalize_join_code("abcd-2345"), expected);
+        assert_eq!(normalize_join_code(" ABCD 2345 "), expected);
+        assert_eq!(normalize_join_code("ABCD2345"), expected);
+
+        assert_eq!(normalize_join_code(""), None);
+        assert_eq!(normalize_join_code("ABCD-234"), None);
+        assert_eq!(normalize_join_code("ABCD-01OI"), None);
+    }
+
+    #[test]
+    fn manager_only_manages_own_classrooms() {
+        assert!(can_manage(UserAuth::Manager, 7, 7));
+        assert!(!can_manage(UserAuth::Manager, 7, 8));
+        assert!(can_manage(UserAuth::Admin, 7, 8));
+        assert!(can_manage(UserAuth::Hymn, 7, 8));
+        assert!(!can_manage(UserAuth::Learner, 7, 7));
+    }
+
+    #[test]
+    fn invite_token_hash_is_stable_and_distinct() {
+        let token = generate_invite_token();
+        assert_eq!(
+            hash_invite_token(&token),
+            hash_invite_token(&format!(" {token} "))
+        );
+        assert_ne!(
+            hash_invite_token(&token),
+            hash_invite_token(&generate_invite_token())
+        );
+        assert_ne!(hash_invite_token(&token), token);
+    }
+}
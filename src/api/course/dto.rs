@@ -1,9 +1,11 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
 use crate::api::admin::translation::dto::TranslationMeta;
-use crate::types::SupportedLanguage;
+use crate::api::lesson::dto::LessonItemRes;
+use crate::types::{LessonAccess, SupportedLanguage};
 
 /// 코스 목록 조회 필터
 #[derive(Debug, Deserialize, IntoParams)]
@@ -52,3 +54,44 @@ pub struct CreateCourseReq {
 pub struct CreateCourseRes {
     pub course_id: i64,
 }
+
+/// 커리큘럼 레슨 노드 (레슨 + 아이템 트리)
+#[derive(Serialize, ToSchema)]
+pub struct CourseLessonNode {
+    pub lesson_id: i64,
+    pub seq: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub lesson_access: LessonAccess,
+    /// 내 레슨 진도율 (비로그인/미시작이면 null)
+    pub progress_percent: Option<i32>,
+    pub items: Vec<LessonItemRes>,
+}
+
+/// 수강 정보 (users_course)
+#[derive(Serialize, sqlx::FromRow, ToSchema)]
+pub struct CourseEnrollmentRes {
+    pub course_id: i64,
+    pub user_course_active: bool,
+    pub user_course_start_at: DateTime<Utc>,
+    pub user_course_expire_at: Option<DateTime<Utc>>,
+    pub user_course_progress_percent: i32,
+    pub user_course_last_lesson_id: Option<i32>,
+    pub user_course_last_progress_at: Option<DateTime<Utc>>,
+}
+
+/// 코스 커리큘럼 응답 — 레슨 트리 + (로그인 시) 내 수강 정보
+#[derive(Serialize, ToSchema)]
+pub struct CourseCurriculumRes {
+    pub course: CourseListItem,
+    pub lessons: Vec<CourseLessonNode>,
+    pub enrollment: Option<CourseEnrollmentRes>,
+    /// 번역 메타 (Q1c A) — 코스 제목/부제 + 레슨 제목/설명 기준
+    pub translation_meta: TranslationMeta,
+}
+
+/// 내 수강 목록 응답
+#[derive(Serialize, ToSchema)]
+pub struct MyCourseListRes {
+    pub items: Vec<CourseEnrollmentRes>,
+}
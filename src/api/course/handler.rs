@@ -1,10 +1,13 @@
 use super::{
-    dto::{CourseDetailRes, CourseListQuery, CourseListRes, CreateCourseReq, CreateCourseRes},
+    dto::{
+        CourseCurriculumRes, CourseDetailRes, CourseEnrollmentRes, CourseListQuery, CourseListRes,
+        CreateCourseReq, CreateCourseRes, MyCourseListRes,
+    },
     service::CourseService,
 };
 use crate::extract::AppJson;
 use crate::{
-    api::auth::extractor::AuthUser,
+    api::auth::extractor::{AuthUser, OptionalAuthUser},
     error::{AppError, AppResult},
     state::AppState,
 };
@@ -83,3 +86,69 @@ pub async fn get_by_id(
     let res = CourseService::get_by_id(&st, id, query.lang).await?;
     Ok(Json(res))
 }
+
+#[utoipa::path(
+    get,
+    path = "/courses/{id}/curriculum",
+    tag = "Course",
+    params(
+        ("id" = i64, Path, description = "Course ID"),
+        CourseListQuery
+    ),
+    responses(
+        (status = 200, description = "Course lesson tree (with my progress when logged in)", body = CourseCurriculumRes),
+        (status = 404, description = "Course not found or not published", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_curriculum(
+    State(st): State<AppState>,
+    OptionalAuthUser(auth): OptionalAuthUser,
+    Path(id): Path<i64>,
+    Query(query): Query<CourseListQuery>,
+) -> AppResult<Json<CourseCurriculumRes>> {
+    let user_id = auth.map(|a| a.0.sub);
+    let res = CourseService::get_curriculum(&st, id, user_id, query.lang).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/courses/{id}/enroll",
+    tag = "Course",
+    security(("bearerAuth" = [])),
+    params(
+        ("id" = i64, Path, description = "Course ID")
+    ),
+    responses(
+        (status = 200, description = "Enrolled (or already enrolled)", body = CourseEnrollmentRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Paid course without active subscription", body = crate::error::ErrorBody),
+        (status = 404, description = "Course not found or not active", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn enroll(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(id): Path<i64>,
+) -> AppResult<Json<CourseEnrollmentRes>> {
+    let res = CourseService::enroll(&st, claims.sub, id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/courses/me",
+    tag = "Course",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "My active enrollments", body = MyCourseListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_courses(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<MyCourseListRes>> {
+    let res = CourseService::my_courses(&st, claims.sub).await?;
+    Ok(Json(res))
+}
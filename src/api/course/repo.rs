@@ -1,5 +1,7 @@
-use super::dto::CourseListItem;
+use super::dto::{CourseEnrollmentRes, CourseListItem};
 use crate::error::AppResult;
+use crate::types::{LessonAccess, LessonItemKind};
+use chrono::{DateTime, Utc};
 use sqlx::{FromRow, PgPool};
 
 #[derive(FromRow)]
@@ -9,7 +11,8 @@ struct InsertedId {
 
 pub async fn list(pool: &PgPool) -> AppResult<Vec<CourseListItem>> {
     let rows = sqlx::query_as::<_, CourseListItem>(
-        r#"SELECT course_id, course_title, course_subtitle, course_price, course_type, course_state
+        r#"SELECT course_id::bigint AS course_id, course_title, course_subtitle, course_price,
+                  course_type::text AS course_type, course_state::text AS course_state
            FROM course
            WHERE course_state <> 'deleted'
            ORDER BY course_id DESC"#,
@@ -21,7 +24,8 @@ pub async fn list(pool: &PgPool) -> AppResult<Vec<CourseListItem>> {
 
 pub async fn find_by_id(pool: &PgPool, id: i64) -> AppResult<Option<CourseListItem>> {
     let row = sqlx::query_as::<_, CourseListItem>(
-        r#"SELECT course_id, course_title, course_subtitle, course_price, course_type, course_state
+        r#"SELECT course_id::bigint AS course_id, course_title, course_subtitle, course_price,
+                  course_type::text AS course_type, course_state::text AS course_state
            FROM course
            WHERE course_id = $1"#,
     )
@@ -51,3 +55,247 @@ pub async fn create(
     .await?;
     Ok(rec.course_id)
 }
+
+// =========================================================================
+// Curriculum / Enrollment
+// =========================================================================
+
+/// 수강 신청 판단용 코스 정보
+#[derive(Debug, FromRow)]
+pub struct CourseEnrollTarget {
+    pub course_price: i32,
+    pub course_discount_price: Option<i32>,
+    pub course_valid_days: Option<i32>,
+}
+
+/// 수강 신청 가능한 (active) 코스 조회
+pub async fn find_enroll_target(
+    pool: &PgPool,
+    course_id: i64,
+) -> AppResult<Option<CourseEnrollTarget>> {
+    let row = sqlx::query_as::<_, CourseEnrollTarget>(
+        r#"SELECT course_price, course_discount_price, course_valid_days
+           FROM course
+           WHERE course_id = $1 AND course_state = 'active'"#,
+    )
+    .bind(course_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+const ENROLLMENT_SELECT: &str = r#"
+    SELECT
+        course_id::bigint AS course_id,
+        user_course_active,
+        user_course_start_at,
+        user_course_expire_at,
+        user_course_progress_percent,
+        user_course_last_lesson_id,
+        user_course_last_progress_at
+    FROM users_course
+"#;
+
+pub async fn find_enrollment(
+    pool: &PgPool,
+    user_id: i64,
+    course_id: i64,
+) -> AppResult<Option<CourseEnrollmentRes>> {
+    let sql = format!("{ENROLLMENT_SELECT} WHERE user_id = $1 AND course_id = $2");
+    let row = sqlx::query_as::<_, CourseEnrollmentRes>(&sql)
+        .bind(user_id)
+        .bind(course_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 내 수강 목록 (활성 + 미만료)
+pub async fn find_active_enrollments(
+    pool: &PgPool,
+    user_id: i64,
+) -> AppResult<Vec<CourseEnrollmentRes>> {
+    let sql = format!(
+        "{ENROLLMENT_SELECT}
+         WHERE user_id = $1
+           AND user_course_active = true
+           AND (user_course_expire_at IS NULL OR user_course_expire_at > NOW())
+         ORDER BY user_course_last_progress_at DESC NULLS LAST, user_course_start_at DESC"
+    );
+    let rows = sqlx::query_as::<_, CourseEnrollmentRes>(&sql)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 수강권 부여 (UPSERT) — 재신청 시 기간/시작일 갱신, 진도는 유지
+pub async fn upsert_enrollment(
+    pool: &PgPool,
+    user_id: i64,
+    course_id: i64,
+    expire_at: Option<DateTime<Utc>>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO users_course (user_id, course_id, user_course_active, user_course_expire_at)
+        VALUES ($1, $2, true, $3)
+        ON CONFLICT (user_id, course_id) DO UPDATE SET
+            user_course_active = true,
+            user_course_start_at = NOW(),
+            user_course_expire_at = EXCLUDED.user_course_expire_at,
+            user_course_org_seat_id = NULL,
+            user_course_updated_at = NOW()
+        "#,
+    )
+    .bind(user_id)
+    .bind(course_id)
+    .bind(expire_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 커리큘럼 레슨 목록 (open 레슨만, 순서대로) + 사용자 레슨 진도
+pub async fn find_curriculum_lessons(
+    pool: &PgPool,
+    course_id: i64,
+    user_id: Option<i64>,
+) -> AppResult<Vec<CourseLessonRow>> {
+    let rows = sqlx::query_as::<_, CourseLessonRow>(
+        r#"
+        SELECT
+            l.lesson_id::bigint AS lesson_id,
+            cl.course_lesson_seq AS seq,
+            l.lesson_title AS title,
+            l.lesson_description AS description,
+            l.lesson_access,
+            lp.lesson_progress_percent AS progress_percent
+        FROM course_lesson cl
+        JOIN lesson l ON l.lesson_id = cl.lesson_id
+        LEFT JOIN lesson_progress lp
+               ON lp.lesson_id = cl.lesson_id AND lp.user_id = $2
+        WHERE cl.course_id = $1
+          AND l.lesson_state = 'open'
+        ORDER BY cl.course_lesson_seq ASC
+        "#,
+    )
+    .bind(course_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 여러 레슨의 아이템 일괄 조회 (lesson_id, seq 순)
+pub async fn find_items_for_lessons(
+    pool: &PgPool,
+    lesson_ids: &[i64],
+) -> AppResult<Vec<CourseLessonItemRow>> {
+    let rows = sqlx::query_as::<_, CourseLessonItemRow>(
+        r#"
+        SELECT
+            lesson_id::bigint AS lesson_id,
+            lesson_item_seq AS seq,
+            lesson_item_kind AS kind,
+            video_id::bigint AS video_id,
+            study_task_id::bigint AS task_id
+        FROM lesson_item
+        WHERE lesson_id = ANY($1)
+        ORDER BY lesson_id, lesson_item_seq ASC
+        "#,
+    )
+    .bind(lesson_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 코스 진도율 — 공개 레슨 진도 ∪ 취소되지 않은 라이브 회차 출석(100/0) 의 평균 (`uc` = users_course 별칭)
+const COURSE_PROGRESS_OF_UC: &str = r#"
+    SELECT COALESCE(ROUND(AVG(p.percent))::int, 0)
+    FROM (
+        SELECT COALESCE(lp.lesson_progress_percent, 0) AS percent
+        FROM course_lesson cl
+        JOIN lesson l ON l.lesson_id = cl.lesson_id AND l.lesson_state = 'open'
+        LEFT JOIN lesson_progress lp
+               ON lp.lesson_id = cl.lesson_id AND lp.user_id = uc.user_id
+        WHERE cl.course_id = uc.course_id
+        UNION ALL
+        SELECT CASE WHEN la.attended THEN 100 ELSE 0 END
+        FROM live_session ls
+        LEFT JOIN live_attendance la
+               ON la.live_session_id = ls.live_session_id AND la.user_id = uc.user_id
+        WHERE ls.course_id = uc.course_id AND ls.session_state = 'scheduled'
+    ) p
+"#;
+
+/// 레슨 진도 변경 시 해당 레슨을 포함한 사용자 코스들의 진도율 재계산
+///
+/// 진도율 = `COURSE_PROGRESS_OF_UC` (진도 없는 레슨·미출석 회차는 0)
+pub async fn recompute_progress_for_lesson(
+    executor: impl sqlx::PgExecutor<'_>,
+    user_id: i64,
+    lesson_id: i64,
+) -> AppResult<u64> {
+    let sql = format!(
+        r#"
+        UPDATE users_course uc
+        SET user_course_progress_percent = ({COURSE_PROGRESS_OF_UC}),
+            user_course_last_lesson_id = $2,
+            user_course_last_progress_at = NOW(),
+            user_course_updated_at = NOW()
+        WHERE uc.user_id = $1
+          AND uc.course_id IN (SELECT course_id FROM course_lesson WHERE lesson_id = $2)
+        "#
+    );
+    let res = sqlx::query(&sql)
+        .bind(user_id)
+        .bind(lesson_id)
+        .execute(executor)
+        .await?;
+    Ok(res.rows_affected())
+}
+
+/// 코스 진도율 재계산 — 커리큘럼·라이브 회차 변경 시 수강생 전원(user_id = None),
+/// 수강 신청·출석 기록 시 본인만
+pub async fn recompute_progress_for_course(
+    executor: impl sqlx::PgExecutor<'_>,
+    course_id: i64,
+    user_id: Option<i64>,
+) -> AppResult<u64> {
+    let sql = format!(
+        r#"
+        UPDATE users_course uc
+        SET user_course_progress_percent = ({COURSE_PROGRESS_OF_UC}),
+            user_course_updated_at = NOW()
+        WHERE uc.course_id = $1
+          AND ($2::bigint IS NULL OR uc.user_id = $2)
+        "#
+    );
+    let res = sqlx::query(&sql)
+        .bind(course_id)
+        .bind(user_id)
+        .execute(executor)
+        .await?;
+    Ok(res.rows_affected())
+}
+
+#[derive(Debug, FromRow)]
+pub struct CourseLessonRow {
+    pub lesson_id: i64,
+    pub seq: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub lesson_access: LessonAccess,
+    pub progress_percent: Option<i32>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct CourseLessonItemRow {
+    pub lesson_id: i64,
+    pub seq: i32,
+    pub kind: LessonItemKind,
+    pub video_id: Option<i64>,
+    pub task_id: Option<i64>,
+}
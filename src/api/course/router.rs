@@ -1,9 +1,12 @@
 use super::handler;
 use crate::state::AppState;
-use axum::routing::{get /*post*/};
+use axum::routing::{get, post};
 
 pub fn course_router() -> axum::Router<AppState> {
     axum::Router::new()
         .route("/courses", get(handler::list).post(handler::create))
+        .route("/courses/me", get(handler::my_courses))
         .route("/courses/{id}", get(handler::get_by_id)) // ← 추가
+        .route("/courses/{id}/curriculum", get(handler::get_curriculum))
+        .route("/courses/{id}/enroll", post(handler::enroll))
 }
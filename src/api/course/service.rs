@@ -1,9 +1,20 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+
 use super::{
-    dto::{CourseDetailRes, CourseListRes},
-    repo,
+    dto::{
+        CourseCurriculumRes, CourseDetailRes, CourseEnrollmentRes, CourseLessonNode, CourseListRes,
+        MyCourseListRes,
+    },
+    repo::{self, CourseEnrollTarget},
 };
 use crate::api::admin::translation::dto::TranslationMeta;
 use crate::api::admin::translation::repo::TranslationRepo;
+use crate::api::certificate::service::issue_for_completed;
+use crate::api::lesson::dto::LessonItemRes;
+use crate::api::org::repo as org_repo;
+use crate::api::payment::repo::PaymentRepo;
 use crate::error::{AppError, AppResult};
 use crate::state::AppState;
 use crate::types::{ContentType, SupportedLanguage};
@@ -119,4 +130,269 @@ impl CourseService {
     ) -> AppResult<i64> {
         repo::create(&state.db, title, price, ctype, subtitle).await
     }
+
+    /// 코스 커리큘럼 (레슨 트리) — 로그인 시 레슨 진도 + 수강 정보 포함
+    ///
+    /// 공개(active) 코스만 노출. 비공개(inactive) 코스는 수강 기록이 있는 사용자에게만 보이고,
+    /// 삭제된 코스는 항상 404.
+    pub async fn get_curriculum(
+        state: &AppState,
+        course_id: i64,
+        user_id: Option<i64>,
+        lang: Option<SupportedLanguage>,
+    ) -> AppResult<CourseCurriculumRes> {
+        let enrollment = match user_id {
+            Some(uid) => repo::find_enrollment(&state.db, uid, course_id).await?,
+            None => None,
+        };
+        let mut course = repo::find_by_id(&state.db, course_id)
+            .await?
+            .filter(|c| {
+                c.course_state == "active" || (c.course_state == "inactive" && enrollment.is_some())
+            })
+            .ok_or(AppError::NotFound)?;
+
+        let lesson_rows = repo::find_curriculum_lessons(&state.db, course_id, user_id).await?;
+        let lesson_ids: Vec<i64> = lesson_rows.iter().map(|l| l.lesson_id).collect();
+
+        let mut items_by_lesson: HashMap<i64, Vec<LessonItemRes>> = HashMap::new();
+        for item in repo::find_items_for_lessons(&state.db, &lesson_ids).await? {
+            items_by_lesson
+                .entry(item.lesson_id)
+                .or_default()
+                .push(LessonItemRes {
+                    seq: item.seq,
+                    kind: item.kind,
+                    video_id: item.video_id,
+                    task_id: item.task_id,
+                });
+        }
+
+        let mut lessons: Vec<CourseLessonNode> = lesson_rows
+            .into_iter()
+            .map(|l| CourseLessonNode {
+                items: items_by_lesson.remove(&l.lesson_id).unwrap_or_default(),
+                lesson_id: l.lesson_id,
+                seq: l.seq,
+                title: l.title,
+                description: l.description,
+                lesson_access: l.lesson_access,
+                progress_percent: l.progress_percent,
+            })
+            .collect();
+
+        let translation_meta = match lang {
+            None => TranslationMeta::not_requested(),
+            Some(SupportedLanguage::Ko) => TranslationMeta::ko_full(),
+            Some(user_lang) => {
+                let course_tr = TranslationRepo::find_translations_for_contents(
+                    &state.db,
+                    ContentType::Course,
+                    &[course.course_id],
+                    user_lang,
+                )
+                .await?;
+                let lesson_tr = TranslationRepo::find_translations_for_contents(
+                    &state.db,
+                    ContentType::Lesson,
+                    &lesson_ids,
+                    user_lang,
+                )
+                .await?;
+
+                // requested 는 source 에 값이 있는 필드만 카운트 (get_by_id 와 동일 기준)
+                let mut translated = 0usize;
+                let mut fallback = 0usize;
+                let mut requested = 1usize; // course_title 은 필수
+                if let Some(t) = course_tr.get(&(course.course_id, "course_title".to_string())) {
+                    course.course_title = t.text.clone();
+                    t.count_to(user_lang, &mut translated, &mut fallback);
+                }
+                if course.course_subtitle.is_some() {
+                    requested += 1;
+                    if let Some(t) =
+                        course_tr.get(&(course.course_id, "course_subtitle".to_string()))
+                    {
+                        course.course_subtitle = Some(t.text.clone());
+                        t.count_to(user_lang, &mut translated, &mut fallback);
+                    }
+                }
+                for lesson in lessons.iter_mut() {
+                    requested += 1; // lesson_title 은 필수
+                    if let Some(t) = lesson_tr.get(&(lesson.lesson_id, "lesson_title".to_string()))
+                    {
+                        lesson.title = t.text.clone();
+                        t.count_to(user_lang, &mut translated, &mut fallback);
+                    }
+                    if lesson.description.is_some() {
+                        requested += 1;
+                        if let Some(t) =
+                            lesson_tr.get(&(lesson.lesson_id, "lesson_description".to_string()))
+                        {
+                            lesson.description = Some(t.text.clone());
+                            t.count_to(user_lang, &mut translated, &mut fallback);
+                        }
+                    }
+                }
+                TranslationMeta::from_counts(user_lang, requested, translated, fallback)
+            }
+        };
+
+        Ok(CourseCurriculumRes {
+            course,
+            lessons,
+            enrollment,
+            translation_meta,
+        })
+    }
+
+    /// 수강 신청
+    ///
+    /// - 무료 코스(실 판매가 0): 즉시 부여, `course_valid_days` 가 있으면 그 기간만큼
+    /// - 유료 코스: 활성 구독 필요, 구독 현재 결제 주기 종료일까지 (웹훅 일괄 부여와 동일)
+    /// - 구독이 없으면 기관 좌석 (코스/구독 라이선스) 으로 부여, 라이선스 종료일까지
+    ///
+    /// 이미 유효한 수강권이 있으면 그대로 반환 (멱등).
+    pub async fn enroll(
+        state: &AppState,
+        user_id: i64,
+        course_id: i64,
+    ) -> AppResult<CourseEnrollmentRes> {
+        let target = repo::find_enroll_target(&state.db, course_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let now = Utc::now();
+        if let Some(existing) = repo::find_enrollment(&state.db, user_id, course_id).await? {
+            if is_enrollment_valid(&existing, now) {
+                return Ok(existing);
+            }
+        }
+
+        let mut conn = state.db.acquire().await?;
+        if effective_price(&target) == 0 {
+            let expire_at = free_enrollment_expiry(target.course_valid_days, now);
+            repo::upsert_enrollment(&state.db, user_id, course_id, expire_at).await?;
+        } else if let Some(subscription) =
+            PaymentRepo::get_active_subscription(&state.db, user_id).await?
+        {
+            repo::upsert_enrollment(
+                &state.db,
+                user_id,
+                course_id,
+                subscription.current_period_end,
+            )
+            .await?;
+        } else {
+            let seat = org_repo::find_seat_grant_for_course(&state.db, user_id, course_id)
+                .await?
+                .ok_or_else(|| AppError::Forbidden("SUBSCRIPTION_REQUIRED".into()))?;
+            org_repo::grant_seat_courses(
+                &mut conn,
+                seat.seat_id,
+                user_id,
+                Some(course_id as i32),
+                seat.license_valid_until,
+            )
+            .await?;
+        }
+
+        // 수강 전 이미 진행한 레슨 진도를 반영
+        repo::recompute_progress_for_course(&state.db, course_id, Some(user_id)).await?;
+        issue_for_completed(&mut conn, Some(user_id), Some(course_id)).await?;
+
+        repo::find_enrollment(&state.db, user_id, course_id)
+            .await?
+            .ok_or_else(|| AppError::Internal("enrollment not found after upsert".into()))
+    }
+
+    /// 내 수강 목록 (활성 + 미만료)
+    pub async fn my_courses(state: &AppState, user_id: i64) -> AppResult<MyCourseListRes> {
+        let items = repo::find_active_enrollments(&state.db, user_id).await?;
+        Ok(MyCourseListRes { items })
+    }
+}
+
+// =============================================================================
+// Pure helpers
+// =============================================================================
+
+/// 실 판매가 = 할인가(있으면) 우선
+fn effective_price(target: &CourseEnrollTarget) -> i32 {
+    target.course_discount_price.unwrap_or(target.course_price)
+}
+
+/// 무료 수강 만료일 — 유효기간(일) 미설정/0 이하면 무기한
+fn free_enrollment_expiry(valid_days: Option<i32>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    valid_days
+        .filter(|d| *d > 0)
+        .map(|d| now + Duration::days(i64::from(d)))
+}
+
+/// 활성 + 미만료 수강권 여부
+fn is_enrollment_valid(enrollment: &CourseEnrollmentRes, now: DateTime<Utc>) -> bool {
+    enrollment.user_course_active && enrollment.user_course_expire_at.is_none_or(|e| e > now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(price: i32, discount: Option<i32>) -> CourseEnrollTarget {
+        CourseEnrollTarget {
+            course_price: price,
+            course_discount_price: discount,
+            course_valid_days: None,
+        }
+    }
+
+    fn enrollment(active: bool, expire_at: Option<DateTime<Utc>>) -> CourseEnrollmentRes {
+        CourseEnrollmentRes {
+            course_id: 1,
+            user_course_active: active,
+            user_course_start_at: Utc::now(),
+            user_course_expire_at: expire_at,
+            user_course_progress_percent: 0,
+            user_course_last_lesson_id: None,
+            user_course_last_progress_at: None,
+        }
+    }
+
+    #[test]
+    fn test_effective_price_prefers_discount() {
+        assert_eq!(effective_price(&target(10000, None)), 10000);
+        assert_eq!(
+            effective_price(&target(10000, Some(0))),
+            0,
+            "100% 할인 = 무료"
+        );
+        assert_eq!(effective_price(&target(0, None)), 0);
+    }
+
+    #[test]
+    fn test_free_enrollment_expiry() {
+        let now = Utc::now();
+        assert_eq!(free_enrollment_expiry(None, now), None);
+        assert_eq!(free_enrollment_expiry(Some(0), now), None);
+        assert_eq!(free_enrollment_expiry(Some(-3), now), None);
+        assert_eq!(
+            free_enrollment_expiry(Some(30), now),
+            Some(now + Duration::days(30))
+        );
+    }
+
+    #[test]
+    fn test_is_enrollment_valid() {
+        let now = Utc::now();
+        assert!(is_enrollment_valid(&enrollment(true, None), now));
+        assert!(is_enrollment_valid(
+            &enrollment(true, Some(now + Duration::days(1))),
+            now
+        ));
+        assert!(!is_enrollment_valid(
+            &enrollment(true, Some(now - Duration::days(1))),
+            now
+        ));
+        assert!(!is_enrollment_valid(&enrollment(false, None), now));
+    }
 }
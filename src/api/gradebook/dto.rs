@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{GradebookExportFormat, GradebookExportStatus};
+
+// =============================================================================
+// 요청
+// =============================================================================
+
+/// 카테고리 가중치 — 항목이 없는 카테고리는 총점 계산에서 제외
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct GradebookWeights {
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub study: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub lesson: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub assignment: f64,
+    /// 지각 완료 과제 인정 비율 (0 ~ 1, 기본 0.5)
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub late_credit: f64,
+}
+
+impl Default for GradebookWeights {
+    fn default() -> Self {
+        Self {
+            study: 1.0,
+            lesson: 1.0,
+            assignment: 1.0,
+            late_credit: 0.5,
+        }
+    }
+}
+
+/// 성적부 조회 — classroom_id / course_id 중 정확히 하나
+#[derive(Debug, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GradebookQuery {
+    pub classroom_id: Option<i64>,
+    pub course_id: Option<i64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub study_weight: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub lesson_weight: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub assignment_weight: Option<f64>,
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub late_credit: Option<f64>,
+}
+
+impl GradebookQuery {
+    pub fn weights(&self) -> GradebookWeights {
+        let d = GradebookWeights::default();
+        GradebookWeights {
+            study: self.study_weight.unwrap_or(d.study),
+            lesson: self.lesson_weight.unwrap_or(d.lesson),
+            assignment: self.assignment_weight.unwrap_or(d.assignment),
+            late_credit: self.late_credit.unwrap_or(d.late_credit),
+        }
+    }
+}
+
+/// 내보내기 요청 — classroom_id / course_id 중 정확히 하나
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateGradebookExportReq {
+    pub classroom_id: Option<i64>,
+    pub course_id: Option<i64>,
+    pub format: GradebookExportFormat,
+    #[validate(nested)]
+    pub weights: Option<GradebookWeights>,
+    /// true 면 실명 포함 (복호화 감사 로그 기록)
+    pub include_names: Option<bool>,
+}
+
+// =============================================================================
+// 응답
+// =============================================================================
+
+/// 성적부 카테고리
+///
+/// 시험(exam) 카테고리는 없음 — 스키마에 시험·응시 결과 엔티티가 없고, 채점 대상 평가는
+/// 과제(assignment)로 출제한다. 시험 기능이 생기면 여기와 가중치에 카테고리를 추가한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GradebookCategory {
+    Study,
+    Lesson,
+    Assignment,
+}
+
+/// 성적부 열 (점수 1칸)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GradebookColumn {
+    pub category: GradebookCategory,
+    /// study_id / lesson_id / assignment_id
+    pub target_id: i64,
+    pub title: String,
+}
+
+/// 학습자 1행 — scores 는 columns 와 같은 순서 (0 ~ 100)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GradebookRow {
+    pub user_id: i64,
+    pub nickname: String,
+    /// 실명 — 내보내기에서 include_names 일 때만 채움
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub scores: Vec<f64>,
+    pub study_avg: Option<f64>,
+    pub lesson_avg: Option<f64>,
+    pub assignment_avg: Option<f64>,
+    /// 카테고리 가중 평균 (채점 가능한 카테고리가 없으면 null)
+    pub total: Option<f64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradebookRes {
+    pub classroom_id: Option<i64>,
+    pub course_id: Option<i64>,
+    pub weights: GradebookWeights,
+    pub columns: Vec<GradebookColumn>,
+    pub rows: Vec<GradebookRow>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradebookExportRes {
+    pub export_id: i64,
+    pub classroom_id: Option<i64>,
+    pub course_id: Option<i64>,
+    pub export_format: GradebookExportFormat,
+    pub include_names: bool,
+    pub status: GradebookExportStatus,
+    pub error: Option<String>,
+    pub row_count: Option<i32>,
+    pub file_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// 다운로드 경로 (완료 + 만료 전일 때만)
+    pub download_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradebookExportListRes {
+    pub items: Vec<GradebookExportRes>,
+}
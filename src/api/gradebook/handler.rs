@@ -0,0 +1,166 @@
+use super::{
+    dto::{
+        CreateGradebookExportReq, GradebookExportListRes, GradebookExportRes, GradebookQuery,
+        GradebookRes,
+    },
+    service::GradebookService,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::extract::AppJson;
+use crate::{
+    api::auth::extractor::AuthUser,
+    error::{AppError, AppResult},
+    state::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/gradebook",
+    tag = "Gradebook",
+    security(("bearerAuth" = [])),
+    params(GradebookQuery),
+    responses(
+        (status = 200, description = "Per-learner scores per study/lesson/assignment with weighted total (nicknames only)", body = GradebookRes),
+        (status = 400, description = "Invalid scope or weights", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required / course scope requires admin", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom or course not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn view_gradebook(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Query(query): Query<GradebookQuery>,
+) -> AppResult<Json<GradebookRes>> {
+    query
+        .validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = GradebookService::view(&st, claims.sub, query).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/gradebook/exports",
+    tag = "Gradebook",
+    security(("bearerAuth" = [])),
+    request_body = CreateGradebookExportReq,
+    responses(
+        (status = 202, description = "Export accepted (done immediately for small scopes, otherwise pending)", body = GradebookExportRes),
+        (status = 400, description = "Invalid scope or weights", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required / course scope requires admin", body = crate::error::ErrorBody),
+        (status = 404, description = "Classroom or course not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn create_export(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    headers: HeaderMap,
+    AppJson(req): AppJson<CreateGradebookExportReq>,
+) -> AppResult<(StatusCode, Json<GradebookExportRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = GradebookService::create_export(
+        &st,
+        claims.sub,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((StatusCode::ACCEPTED, Json(res)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/gradebook/exports",
+    tag = "Gradebook",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "My recent gradebook exports", body = GradebookExportListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_exports(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<GradebookExportListRes>> {
+    let res = GradebookService::list_exports(&st, claims.sub).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/gradebook/exports/{export_id}",
+    tag = "Gradebook",
+    security(("bearerAuth" = [])),
+    params(
+        ("export_id" = i64, Path, description = "Export ID")
+    ),
+    responses(
+        (status = 200, description = "Export status (download_url when ready and not expired)", body = GradebookExportRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Export not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_export(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(export_id): Path<i64>,
+) -> AppResult<Json<GradebookExportRes>> {
+    let res = GradebookService::get_export(&st, claims.sub, export_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/gradebook/exports/{export_id}/download",
+    tag = "Gradebook",
+    security(("bearerAuth" = [])),
+    params(
+        ("export_id" = i64, Path, description = "Export ID")
+    ),
+    responses(
+        (status = 200, description = "Gradebook file (text/csv or xlsx binary)"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Export not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Export not ready or expired", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn download_export(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(export_id): Path<i64>,
+    headers: HeaderMap,
+) -> AppResult<impl IntoResponse> {
+    let (content_type, file_name, file) = GradebookService::download_export(
+        &st,
+        claims.sub,
+        export_id,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file_name}\""),
+            ),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        file,
+    ))
+}
@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod handler;
+pub mod render;
+pub mod repo;
+pub mod router;
+pub mod service;
@@ -0,0 +1,239 @@
+//! 성적부 파일 렌더링 (CSV / XLSX).
+//!
+//! 열 순서: user_id, 닉네임, (실명), 항목별 점수, 카테고리 평균 3종, 총점.
+//! CSV 는 Excel 에서 한글이 깨지지 않도록 UTF-8 BOM 을 붙인다.
+//! 학습자가 입력한 문자열(닉네임·실명·제목)은 수식으로 해석되지 않도록 이스케이프한다.
+
+use rust_xlsxwriter::{Format, Workbook};
+
+use super::dto::{GradebookCategory, GradebookColumn, GradebookRow};
+use crate::error::{AppError, AppResult};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+fn category_label(category: GradebookCategory) -> &'static str {
+    match category {
+        GradebookCategory::Study => "Study",
+        GradebookCategory::Lesson => "Lesson",
+        GradebookCategory::Assignment => "Assignment",
+    }
+}
+
+fn header(columns: &[GradebookColumn], include_names: bool) -> Vec<String> {
+    let mut h = vec!["user_id".to_string(), "nickname".to_string()];
+    if include_names {
+        h.push("name".to_string());
+    }
+    h.extend(
+        columns
+            .iter()
+            .map(|c| format!("{}: {}", category_label(c.category), c.title)),
+    );
+    h.extend(
+        ["study_avg", "lesson_avg", "assignment_avg", "total"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    h
+}
+
+/// CSV 수식 주입 방지 — 스프레드시트가 수식으로 해석하는 선행 문자면 `'` 를 붙인다
+fn csv_text(value: &str) -> String {
+    match value.chars().next() {
+        Some('=' | '+' | '-' | '@' | '\t' | '\r') => format!("'{value}"),
+        _ => value.to_string(),
+    }
+}
+
+fn fmt_score(score: Option<f64>) -> String {
+    score.map(|s| format!("{s:.1}")).unwrap_or_default()
+}
+
+pub fn render_csv(
+    columns: &[GradebookColumn],
+    rows: &[GradebookRow],
+    include_names: bool,
+) -> AppResult<Vec<u8>> {
+    let mut buf = UTF8_BOM.to_vec();
+    {
+        let mut w = csv::Writer::from_writer(&mut buf);
+        let to_err = |e: csv::Error| AppError::Internal(format!("gradebook csv: {e}"));
+        w.write_record(header(columns, include_names).iter().map(|h| csv_text(h)))
+            .map_err(to_err)?;
+        for row in rows {
+            let mut rec = vec![row.user_id.to_string(), csv_text(&row.nickname)];
+            if include_names {
+                rec.push(csv_text(row.name.as_deref().unwrap_or_default()));
+            }
+            rec.extend(row.scores.iter().map(|s| fmt_score(Some(*s))));
+            rec.extend(
+                [row.study_avg, row.lesson_avg, row.assignment_avg, row.total]
+                    .into_iter()
+                    .map(fmt_score),
+            );
+            w.write_record(&rec).map_err(to_err)?;
+        }
+        w.flush()
+            .map_err(|e| AppError::Internal(format!("gradebook csv: {e}")))?;
+    }
+    Ok(buf)
+}
+
+pub fn render_xlsx(
+    columns: &[GradebookColumn],
+    rows: &[GradebookRow],
+    include_names: bool,
+) -> AppResult<Vec<u8>> {
+    let to_err = |e: rust_xlsxwriter::XlsxError| AppError::Internal(format!("gradebook xlsx: {e}"));
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Gradebook").map_err(to_err)?;
+    let bold = Format::new().set_bold();
+    let score_fmt = Format::new().set_num_format("0.0");
+
+    for (col, title) in header(columns, include_names).iter().enumerate() {
+        sheet
+            .write_string_with_format(0, col as u16, title, &bold)
+            .map_err(to_err)?;
+    }
+    sheet.set_freeze_panes(1, 0).map_err(to_err)?;
+
+    for (idx, row) in rows.iter().enumerate() {
+        let r = idx as u32 + 1;
+        let mut col: u16 = 0;
+        sheet
+            .write_number(r, col, row.user_id as f64)
+            .map_err(to_err)?;
+        col += 1;
+        sheet.write_string(r, col, &row.nickname).map_err(to_err)?;
+        col += 1;
+        if include_names {
+            sheet
+                .write_string(r, col, row.name.as_deref().unwrap_or_default())
+                .map_err(to_err)?;
+            col += 1;
+        }
+        let scores = row.scores.iter().map(|s| Some(*s)).chain([
+            row.study_avg,
+            row.lesson_avg,
+            row.assignment_avg,
+            row.total,
+        ]);
+        for score in scores {
+            if let Some(s) = score {
+                sheet
+                    .write_number_with_format(r, col, s, &score_fmt)
+                    .map_err(to_err)?;
+            }
+            col += 1;
+        }
+    }
+
+    workbook.save_to_buffer().map_err(to_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> (Vec<GradebookColumn>, Vec<GradebookRow>) {
+        let columns = vec![
+            GradebookColumn {
+                category: GradebookCategory::Study,
+                target_id: 1,
+                title: "인사, 표현".to_string(),
+            },
+            GradebookColumn {
+                category: GradebookCategory::Assignment,
+                target_id: 9,
+                title: "1주차".to_string(),
+            },
+        ];
+        let rows = vec![GradebookRow {
+            user_id: 7,
+            nickname: "kim".to_string(),
+            name: Some("김학생".to_string()),
+            scores: vec![66.666, 100.0],
+            study_avg: Some(66.666),
+            lesson_avg: None,
+            assignment_avg: Some(100.0),
+            total: Some(83.333),
+        }];
+        (columns, rows)
+    }
+
+    #[test]
+    fn csv_has_bom_quoting_and_blank_missing_category() {
+        let (columns, rows) = sample();
+        let out = render_csv(&columns, &rows, true).unwrap();
+        assert!(out.starts_with(UTF8_BOM));
+        let text = String::from_utf8(out[UTF8_BOM.len()..].to_vec()).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "user_id,nickname,name,\"Study: 인사, 표현\",Assignment: 1주차,study_avg,lesson_avg,assignment_avg,total"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "7,kim,김학생,66.7,100.0,66.7,,100.0,83.3"
+        );
+    }
+
+    #[test]
+    fn csv_without_names_omits_name_column() {
+        let (columns, rows) = sample();
+        let out = render_csv(&columns, &rows, false).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(!text.contains("김학생"));
+        assert!(!text.contains(",name,"));
+    }
+
+    #[test]
+    fn csv_escapes_formula_prefixes() {
+        let (mut columns, mut rows) = sample();
+        columns[1].title = "=1주차".to_string();
+        rows[0].nickname = "=HYPERLINK(\"http://x\")".to_string();
+        rows[0].name = Some("@SUM(A1)".to_string());
+        let mut second = rows[0].clone();
+        second.user_id = 8;
+        second.nickname = "+1".to_string();
+        second.name = Some("-2".to_string());
+        rows.push(second);
+        let mut third = rows[0].clone();
+        third.user_id = 9;
+        third.nickname = "\tcmd".to_string();
+        third.name = Some("\rcmd".to_string());
+        rows.push(third);
+
+        let out = render_csv(&columns, &rows, true).unwrap();
+        let mut reader = csv::Reader::from_reader(&out[UTF8_BOM.len()..]);
+        let headers = reader.headers().unwrap().clone();
+        // 열 제목은 "Assignment: " 접두사가 붙어 수식으로 시작하지 않음
+        assert_eq!(&headers[4], "Assignment: =1주차");
+        let cells: Vec<(String, String)> = reader
+            .records()
+            .map(|r| {
+                let r = r.unwrap();
+                (r[1].to_string(), r[2].to_string())
+            })
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                (
+                    "'=HYPERLINK(\"http://x\")".to_string(),
+                    "'@SUM(A1)".to_string()
+                ),
+                ("'+1".to_string(), "'-2".to_string()),
+                ("'\tcmd".to_string(), "'\rcmd".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn xlsx_is_zip_container() {
+        let (columns, rows) = sample();
+        let out = render_xlsx(&columns, &rows, true).unwrap();
+        assert!(out.starts_with(b"PK"), "xlsx = zip");
+    }
+}
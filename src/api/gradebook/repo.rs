@@ -0,0 +1,458 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use crate::error::AppResult;
+use crate::types::{GradebookExportFormat, GradebookExportStatus};
+
+// =============================================================================
+// 범위 (학습자 / 열)
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct GradebookLearnerRow {
+    pub user_id: i64,
+    pub user_nickname: String,
+    pub user_name_enc: String,
+}
+
+/// 클래스 active 멤버
+pub async fn find_classroom_learners(
+    pool: &PgPool,
+    classroom_id: i64,
+) -> AppResult<Vec<GradebookLearnerRow>> {
+    let rows = sqlx::query_as::<_, GradebookLearnerRow>(
+        r#"
+        SELECT u.user_id, u.user_nickname, u.user_name AS user_name_enc
+        FROM classroom_member cm
+        JOIN users u ON u.user_id = cm.user_id
+        WHERE cm.classroom_id = $1 AND cm.member_state = 'active'
+        ORDER BY u.user_nickname, u.user_id
+        "#,
+    )
+    .bind(classroom_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 코스 수강생 (active 수강권)
+pub async fn find_course_learners(
+    pool: &PgPool,
+    course_id: i64,
+) -> AppResult<Vec<GradebookLearnerRow>> {
+    let rows = sqlx::query_as::<_, GradebookLearnerRow>(
+        r#"
+        SELECT DISTINCT u.user_id, u.user_nickname, u.user_name AS user_name_enc
+        FROM users_course uc
+        JOIN users u ON u.user_id = uc.user_id
+        WHERE uc.course_id = $1 AND uc.user_course_active = true
+        ORDER BY u.user_nickname, u.user_id
+        "#,
+    )
+    .bind(course_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn count_classroom_learners(pool: &PgPool, classroom_id: i64) -> AppResult<i64> {
+    let count = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM classroom_member WHERE classroom_id = $1 AND member_state = 'active'",
+    )
+    .bind(classroom_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+pub async fn count_course_learners(pool: &PgPool, course_id: i64) -> AppResult<i64> {
+    let count = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(DISTINCT user_id) FROM users_course
+        WHERE course_id = $1 AND user_course_active = true
+        "#,
+    )
+    .bind(course_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+pub async fn course_exists(pool: &PgPool, course_id: i64) -> AppResult<bool> {
+    let exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM course WHERE course_id = $1)")
+            .bind(course_id)
+            .fetch_one(pool)
+            .await?;
+    Ok(exists)
+}
+
+#[derive(Debug, FromRow)]
+pub struct ColumnRow {
+    pub target_id: i64,
+    pub title: String,
+}
+
+/// 코스 커리큘럼 레슨 (순서대로)
+pub async fn find_course_lessons(pool: &PgPool, course_id: i64) -> AppResult<Vec<ColumnRow>> {
+    let rows = sqlx::query_as::<_, ColumnRow>(
+        r#"
+        SELECT l.lesson_id::bigint AS target_id, l.lesson_title AS title
+        FROM course_lesson cl
+        JOIN lesson l ON l.lesson_id = cl.lesson_id
+        WHERE cl.course_id = $1
+        ORDER BY cl.course_lesson_seq, cl.lesson_id
+        "#,
+    )
+    .bind(course_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 코스 레슨에 포함된 study (lesson_item → study_task 경유, 첫 등장 순)
+pub async fn find_course_studies(pool: &PgPool, course_id: i64) -> AppResult<Vec<ColumnRow>> {
+    let rows = sqlx::query_as::<_, ColumnRow>(
+        r#"
+        SELECT s.study_id::bigint AS target_id,
+               COALESCE(s.study_title, s.study_idx) AS title
+        FROM course_lesson cl
+        JOIN lesson_item li ON li.lesson_id = cl.lesson_id
+        JOIN study_task st ON st.study_task_id = li.study_task_id
+        JOIN study s ON s.study_id = st.study_id
+        WHERE cl.course_id = $1
+        GROUP BY s.study_id, s.study_title, s.study_idx
+        ORDER BY MIN(cl.course_lesson_seq), MIN(li.lesson_item_seq), s.study_id
+        "#,
+    )
+    .bind(course_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 클래스 과제 항목에 포함된 study (첫 출제 순)
+pub async fn find_classroom_studies(pool: &PgPool, classroom_id: i64) -> AppResult<Vec<ColumnRow>> {
+    let rows = sqlx::query_as::<_, ColumnRow>(
+        r#"
+        SELECT s.study_id::bigint AS target_id,
+               COALESCE(s.study_title, s.study_idx) AS title
+        FROM assignment a
+        JOIN assignment_item i ON i.assignment_id = a.assignment_id AND i.item_type = 'study'
+        JOIN study s ON s.study_id = i.target_id
+        WHERE a.classroom_id = $1
+        GROUP BY s.study_id, s.study_title, s.study_idx
+        ORDER BY MIN(a.assignment_due_at), s.study_id
+        "#,
+    )
+    .bind(classroom_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 클래스 과제 항목에 포함된 lesson (첫 출제 순)
+pub async fn find_classroom_lessons(pool: &PgPool, classroom_id: i64) -> AppResult<Vec<ColumnRow>> {
+    let rows = sqlx::query_as::<_, ColumnRow>(
+        r#"
+        SELECT l.lesson_id::bigint AS target_id, l.lesson_title AS title
+        FROM assignment a
+        JOIN assignment_item i ON i.assignment_id = a.assignment_id AND i.item_type = 'lesson'
+        JOIN lesson l ON l.lesson_id = i.target_id
+        WHERE a.classroom_id = $1
+        GROUP BY l.lesson_id, l.lesson_title
+        ORDER BY MIN(a.assignment_due_at), l.lesson_id
+        "#,
+    )
+    .bind(classroom_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 클래스 과제 (마감 순)
+pub async fn find_classroom_assignments(
+    pool: &PgPool,
+    classroom_id: i64,
+) -> AppResult<Vec<ColumnRow>> {
+    let rows = sqlx::query_as::<_, ColumnRow>(
+        r#"
+        SELECT assignment_id AS target_id, assignment_title AS title
+        FROM assignment
+        WHERE classroom_id = $1
+        ORDER BY assignment_due_at, assignment_id
+        "#,
+    )
+    .bind(classroom_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// =============================================================================
+// 점수 원천
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct StudyScoreRow {
+    pub user_id: i64,
+    pub study_id: i64,
+    pub task_total: i64,
+    pub task_solved: i64,
+}
+
+/// (학습자, study) 별 전체/해결 task 수
+pub async fn find_study_scores(
+    pool: &PgPool,
+    user_ids: &[i64],
+    study_ids: &[i64],
+) -> AppResult<Vec<StudyScoreRow>> {
+    let rows = sqlx::query_as::<_, StudyScoreRow>(
+        r#"
+        SELECT u.user_id,
+               st.study_id::bigint AS study_id,
+               COUNT(DISTINCT st.study_task_id) AS task_total,
+               COUNT(DISTINCT st.study_task_id)
+                   FILTER (WHERE sts.study_task_status_is_solved) AS task_solved
+        FROM UNNEST($1::bigint[]) AS u(user_id)
+        CROSS JOIN study_task st
+        LEFT JOIN study_task_status sts
+               ON sts.study_task_id = st.study_task_id AND sts.user_id = u.user_id
+        WHERE st.study_id = ANY($2)
+        GROUP BY u.user_id, st.study_id
+        "#,
+    )
+    .bind(user_ids)
+    .bind(study_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+#[derive(Debug, FromRow)]
+pub struct LessonScoreRow {
+    pub user_id: i64,
+    pub lesson_id: i64,
+    pub progress_percent: i32,
+}
+
+/// (학습자, lesson) 별 진도율 — 기록이 없는 조합은 결과에 없음 (0 으로 취급)
+pub async fn find_lesson_scores(
+    pool: &PgPool,
+    user_ids: &[i64],
+    lesson_ids: &[i64],
+) -> AppResult<Vec<LessonScoreRow>> {
+    let rows = sqlx::query_as::<_, LessonScoreRow>(
+        r#"
+        SELECT user_id, lesson_id::bigint AS lesson_id,
+               MAX(lesson_progress_percent) AS progress_percent
+        FROM lesson_progress
+        WHERE user_id = ANY($1) AND lesson_id = ANY($2)
+        GROUP BY user_id, lesson_id
+        "#,
+    )
+    .bind(user_ids)
+    .bind(lesson_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// =============================================================================
+// 내보내기 작업
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct ExportRow {
+    pub export_id: i64,
+    pub requested_by_user_id: i64,
+    pub classroom_id: Option<i64>,
+    pub course_id: Option<i64>,
+    pub export_format: GradebookExportFormat,
+    pub export_weights: serde_json::Value,
+    pub export_include_names: bool,
+    pub export_status: GradebookExportStatus,
+    pub export_error: Option<String>,
+    pub export_row_count: Option<i32>,
+    pub export_file_name: Option<String>,
+    pub export_created_at: DateTime<Utc>,
+    pub export_finished_at: Option<DateTime<Utc>>,
+    pub export_expires_at: Option<DateTime<Utc>>,
+    pub has_file: bool,
+}
+
+const EXPORT_SELECT: &str = r#"
+    SELECT export_id, requested_by_user_id, classroom_id, course_id::bigint AS course_id,
+           export_format, export_weights, export_include_names, export_status,
+           export_error, export_row_count, export_file_name,
+           export_created_at, export_finished_at, export_expires_at,
+           export_file IS NOT NULL AS has_file
+    FROM gradebook_export
+"#;
+
+pub struct NewExport<'a> {
+    pub requested_by_user_id: i64,
+    pub classroom_id: Option<i64>,
+    pub course_id: Option<i64>,
+    pub format: GradebookExportFormat,
+    pub weights: &'a serde_json::Value,
+    pub include_names: bool,
+}
+
+pub async fn insert_export(pool: &PgPool, e: &NewExport<'_>) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO gradebook_export
+            (requested_by_user_id, classroom_id, course_id, export_format,
+             export_weights, export_include_names)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING export_id
+        "#,
+    )
+    .bind(e.requested_by_user_id)
+    .bind(e.classroom_id)
+    .bind(e.course_id.map(|id| id as i32))
+    .bind(e.format)
+    .bind(e.weights)
+    .bind(e.include_names)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+pub async fn find_export(pool: &PgPool, export_id: i64) -> AppResult<Option<ExportRow>> {
+    let sql = format!("{EXPORT_SELECT} WHERE export_id = $1");
+    let row = sqlx::query_as::<_, ExportRow>(&sql)
+        .bind(export_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+pub async fn find_exports_by_requester(
+    pool: &PgPool,
+    user_id: i64,
+    limit: i64,
+) -> AppResult<Vec<ExportRow>> {
+    let sql = format!(
+        "{EXPORT_SELECT} WHERE requested_by_user_id = $1 ORDER BY export_created_at DESC LIMIT $2"
+    );
+    let rows = sqlx::query_as::<_, ExportRow>(&sql)
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 대기 작업 1건 선점 (running 으로 전환). 오래 멈춘 running 은 재시도 대상.
+pub async fn claim_next_export(pool: &PgPool, stale_after_sec: i64) -> AppResult<Option<i64>> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        UPDATE gradebook_export
+        SET export_status = 'running', export_started_at = NOW()
+        WHERE export_id = (
+            SELECT export_id FROM gradebook_export
+            WHERE export_status = 'pending'
+               OR (export_status = 'running'
+                   AND export_started_at < NOW() - make_interval(secs => $1))
+            ORDER BY export_id
+            FOR UPDATE SKIP LOCKED
+            LIMIT 1
+        )
+        RETURNING export_id
+        "#,
+    )
+    .bind(stale_after_sec as f64)
+    .fetch_optional(pool)
+    .await?;
+    Ok(id)
+}
+
+pub async fn mark_running(pool: &PgPool, export_id: i64) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE gradebook_export
+        SET export_status = 'running', export_started_at = NOW()
+        WHERE export_id = $1
+        "#,
+    )
+    .bind(export_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn finish_export_done(
+    pool: &PgPool,
+    export_id: i64,
+    file_name: &str,
+    file: &[u8],
+    row_count: i32,
+    expires_at: DateTime<Utc>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE gradebook_export
+        SET export_status      = 'done',
+            export_error       = NULL,
+            export_file_name   = $2,
+            export_file        = $3,
+            export_row_count   = $4,
+            export_finished_at = NOW(),
+            export_expires_at  = $5
+        WHERE export_id = $1
+        "#,
+    )
+    .bind(export_id)
+    .bind(file_name)
+    .bind(file)
+    .bind(row_count)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn finish_export_failed(pool: &PgPool, export_id: i64, error: &str) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE gradebook_export
+        SET export_status = 'failed', export_error = $2, export_finished_at = NOW()
+        WHERE export_id = $1
+        "#,
+    )
+    .bind(export_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 만료된 파일 비우기 (행은 유지) — 비운 건수 반환
+pub async fn purge_expired_files(pool: &PgPool) -> AppResult<u64> {
+    let res = sqlx::query(
+        r#"
+        UPDATE gradebook_export
+        SET export_file = NULL
+        WHERE export_file IS NOT NULL AND export_expires_at <= NOW()
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+/// 만료 전 파일 본문
+pub async fn find_export_file(pool: &PgPool, export_id: i64) -> AppResult<Option<Vec<u8>>> {
+    let file = sqlx::query_scalar::<_, Option<Vec<u8>>>(
+        r#"
+        SELECT export_file FROM gradebook_export
+        WHERE export_id = $1 AND export_expires_at > NOW()
+        "#,
+    )
+    .bind(export_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(file.flatten())
+}
@@ -0,0 +1,17 @@
+use super::handler;
+use crate::state::AppState;
+use axum::routing::get;
+
+pub fn gradebook_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/gradebook", get(handler::view_gradebook))
+        .route(
+            "/gradebook/exports",
+            get(handler::list_exports).post(handler::create_export),
+        )
+        .route("/gradebook/exports/{export_id}", get(handler::get_export))
+        .route(
+            "/gradebook/exports/{export_id}/download",
+            get(handler::download_export),
+        )
+}
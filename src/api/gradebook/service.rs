@@ -0,0 +1,561 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use chrono::{Duration, Utc};
+
+use super::{
+    dto::{
+        CreateGradebookExportReq, GradebookCategory, GradebookColumn, GradebookExportListRes,
+        GradebookExportRes, GradebookQuery, GradebookRes, GradebookRow, GradebookWeights,
+    },
+    render,
+    repo::{self, ColumnRow, ExportRow, NewExport},
+};
+use crate::api::admin::user::repo::write_audit_log;
+use crate::api::assignment::dto::AssignmentStatus;
+use crate::api::assignment::service::learner_statuses_many;
+use crate::api::classroom::service::{check_manager_rbac, load_managed};
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::state::AppState;
+use crate::types::{GradebookExportFormat, GradebookExportStatus, UserAuth};
+
+/// 내보내기 목록 최대 건수
+const EXPORT_LIST_LIMIT: i64 = 50;
+
+/// 성적부 범위 — 클래스 또는 코스 수강생
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradebookScope {
+    Classroom(i64),
+    Course(i64),
+}
+
+impl GradebookScope {
+    pub fn from_ids(classroom_id: Option<i64>, course_id: Option<i64>) -> AppResult<Self> {
+        match (classroom_id, course_id) {
+            (Some(id), None) => Ok(Self::Classroom(id)),
+            (None, Some(id)) => Ok(Self::Course(id)),
+            _ => Err(AppError::BadRequest(
+                "Exactly one of classroom_id or course_id is required".into(),
+            )),
+        }
+    }
+
+    fn ids(self) -> (Option<i64>, Option<i64>) {
+        match self {
+            Self::Classroom(id) => (Some(id), None),
+            Self::Course(id) => (None, Some(id)),
+        }
+    }
+
+    fn file_stem(self) -> String {
+        match self {
+            Self::Classroom(id) => format!("class-{id}"),
+            Self::Course(id) => format!("course-{id}"),
+        }
+    }
+}
+
+fn round1(v: f64) -> f64 {
+    (v * 10.0).round() / 10.0
+}
+
+/// 카테고리 평균 — 항목이 없으면 None
+pub fn category_average(scores: &[f64]) -> Option<f64> {
+    (!scores.is_empty()).then(|| round1(scores.iter().sum::<f64>() / scores.len() as f64))
+}
+
+/// 카테고리 가중 평균 — 평균이 없거나 가중치 0 인 카테고리는 분모에서도 제외
+pub fn weighted_total(parts: &[(f64, Option<f64>)]) -> Option<f64> {
+    let (sum, weight) = parts
+        .iter()
+        .filter_map(|(w, avg)| avg.filter(|_| *w > 0.0).map(|a| (w * a, *w)))
+        .fold((0.0, 0.0), |(s, ws), (x, w)| (s + x, ws + w));
+    (weight > 0.0).then(|| round1(sum / weight))
+}
+
+/// 과제 점수 — 완료 100, 지각 완료 100 × late_credit, 그 외 0
+pub fn assignment_score(status: AssignmentStatus, late_credit: f64) -> f64 {
+    match status {
+        AssignmentStatus::Completed => 100.0,
+        AssignmentStatus::Late => round1(100.0 * late_credit),
+        _ => 0.0,
+    }
+}
+
+/// 범위 접근 권한 — 클래스: 관리 가능한 클래스만 (manager 는 자기 클래스),
+/// 코스 수강생 전체: HYMN/admin 만. 실명 포함 내보내기도 같은 권한을 따른다.
+async fn authorize_scope(
+    st: &AppState,
+    actor_user_id: i64,
+    scope: GradebookScope,
+) -> AppResult<()> {
+    match scope {
+        GradebookScope::Classroom(id) => {
+            load_managed(st, actor_user_id, id).await?;
+        }
+        GradebookScope::Course(id) => {
+            let auth = check_manager_rbac(&st.db, actor_user_id).await?;
+            if auth == UserAuth::Manager {
+                return Err(AppError::Forbidden("GRADEBOOK_403_COURSE_SCOPE".into()));
+            }
+            if !repo::course_exists(&st.db, id).await? {
+                return Err(AppError::NotFound);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_weights(weights: &GradebookWeights) -> AppResult<()> {
+    if weights.study + weights.lesson + weights.assignment <= 0.0 {
+        return Err(AppError::BadRequest(
+            "At least one category weight must be positive".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn to_columns(category: GradebookCategory, rows: Vec<ColumnRow>) -> Vec<GradebookColumn> {
+    rows.into_iter()
+        .map(|r| GradebookColumn {
+            category,
+            target_id: r.target_id,
+            title: r.title,
+        })
+        .collect()
+}
+
+/// 계산된 성적부 + 행과 같은 순서의 암호화된 실명
+struct BuiltGradebook {
+    columns: Vec<GradebookColumn>,
+    rows: Vec<GradebookRow>,
+    names_enc: Vec<String>,
+}
+
+async fn build(
+    st: &AppState,
+    scope: GradebookScope,
+    weights: &GradebookWeights,
+) -> AppResult<BuiltGradebook> {
+    let (learners, studies, lessons, assignments) = match scope {
+        GradebookScope::Classroom(id) => (
+            repo::find_classroom_learners(&st.db, id).await?,
+            repo::find_classroom_studies(&st.db, id).await?,
+            repo::find_classroom_lessons(&st.db, id).await?,
+            repo::find_classroom_assignments(&st.db, id).await?,
+        ),
+        GradebookScope::Course(id) => (
+            repo::find_course_learners(&st.db, id).await?,
+            repo::find_course_studies(&st.db, id).await?,
+            repo::find_course_lessons(&st.db, id).await?,
+            Vec::new(),
+        ),
+    };
+
+    let user_ids: Vec<i64> = learners.iter().map(|l| l.user_id).collect();
+    let study_ids: Vec<i64> = studies.iter().map(|c| c.target_id).collect();
+    let lesson_ids: Vec<i64> = lessons.iter().map(|c| c.target_id).collect();
+
+    let mut study_scores: HashMap<(i64, i64), f64> = HashMap::new();
+    if !study_ids.is_empty() && !user_ids.is_empty() {
+        for r in repo::find_study_scores(&st.db, &user_ids, &study_ids).await? {
+            if r.task_total > 0 {
+                let pct = r.task_solved as f64 / r.task_total as f64 * 100.0;
+                study_scores.insert((r.user_id, r.study_id), round1(pct));
+            }
+        }
+    }
+    let mut lesson_scores: HashMap<(i64, i64), f64> = HashMap::new();
+    if !lesson_ids.is_empty() && !user_ids.is_empty() {
+        for r in repo::find_lesson_scores(&st.db, &user_ids, &lesson_ids).await? {
+            lesson_scores.insert(
+                (r.user_id, r.lesson_id),
+                r.progress_percent.clamp(0, 100) as f64,
+            );
+        }
+    }
+    let assignment_ids: Vec<i64> = assignments.iter().map(|c| c.target_id).collect();
+    let assignment_statuses = learner_statuses_many(st, &assignment_ids).await?;
+
+    let mut columns = to_columns(GradebookCategory::Study, studies);
+    columns.extend(to_columns(GradebookCategory::Lesson, lessons));
+    columns.extend(to_columns(GradebookCategory::Assignment, assignments));
+
+    let mut rows = Vec::with_capacity(learners.len());
+    let mut names_enc = Vec::with_capacity(learners.len());
+    for learner in learners {
+        let uid = learner.user_id;
+        let mut by_category: HashMap<GradebookCategory, Vec<f64>> = HashMap::new();
+        let scores: Vec<f64> = columns
+            .iter()
+            .map(|c| {
+                let score = match c.category {
+                    GradebookCategory::Study => study_scores
+                        .get(&(uid, c.target_id))
+                        .copied()
+                        .unwrap_or(0.0),
+                    GradebookCategory::Lesson => lesson_scores
+                        .get(&(uid, c.target_id))
+                        .copied()
+                        .unwrap_or(0.0),
+                    GradebookCategory::Assignment => {
+                        let status = assignment_statuses
+                            .get(&c.target_id)
+                            .and_then(|m| m.get(&uid))
+                            .copied()
+                            .unwrap_or(AssignmentStatus::NotStarted);
+                        assignment_score(status, weights.late_credit)
+                    }
+                };
+                by_category.entry(c.category).or_default().push(score);
+                score
+            })
+            .collect();
+
+        let avg = |cat| category_average(by_category.get(&cat).map_or(&[][..], Vec::as_slice));
+        let study_avg = avg(GradebookCategory::Study);
+        let lesson_avg = avg(GradebookCategory::Lesson);
+        let assignment_avg = avg(GradebookCategory::Assignment);
+        rows.push(GradebookRow {
+            user_id: uid,
+            nickname: learner.user_nickname,
+            name: None,
+            scores,
+            study_avg,
+            lesson_avg,
+            assignment_avg,
+            total: weighted_total(&[
+                (weights.study, study_avg),
+                (weights.lesson, lesson_avg),
+                (weights.assignment, assignment_avg),
+            ]),
+        });
+        names_enc.push(learner.user_name_enc);
+    }
+
+    Ok(BuiltGradebook {
+        columns,
+        rows,
+        names_enc,
+    })
+}
+
+fn to_export_res(row: ExportRow) -> GradebookExportRes {
+    let downloadable = row.export_status == GradebookExportStatus::Done
+        && row.has_file
+        && row.export_expires_at.is_some_and(|e| e > Utc::now());
+    GradebookExportRes {
+        export_id: row.export_id,
+        classroom_id: row.classroom_id,
+        course_id: row.course_id,
+        export_format: row.export_format,
+        include_names: row.export_include_names,
+        status: row.export_status,
+        error: row.export_error,
+        row_count: row.export_row_count,
+        file_name: row.export_file_name,
+        created_at: row.export_created_at,
+        finished_at: row.export_finished_at,
+        expires_at: row.export_expires_at,
+        download_url: downloadable
+            .then(|| format!("/gradebook/exports/{}/download", row.export_id)),
+    }
+}
+
+fn content_type(format: GradebookExportFormat) -> &'static str {
+    match format {
+        GradebookExportFormat::Csv => "text/csv; charset=utf-8",
+        GradebookExportFormat::Xlsx => {
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+        }
+    }
+}
+
+/// 내보내기 1건 생성 (요청 즉시 처리 / jobs::gradebook_export 공용)
+///
+/// 실명 포함이면 복호화 직전에 요청자 명의로 DECRYPT_GRADEBOOK_NAMES 감사 로그를 남긴다.
+pub async fn run_export(st: &AppState, export_id: i64) -> AppResult<()> {
+    let export = repo::find_export(&st.db, export_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let scope = GradebookScope::from_ids(export.classroom_id, export.course_id)?;
+    let weights: GradebookWeights =
+        serde_json::from_value(export.export_weights.clone()).unwrap_or_default();
+
+    let mut built = build(st, scope, &weights).await?;
+
+    if export.export_include_names {
+        write_audit_log(
+            st,
+            export.requested_by_user_id,
+            "DECRYPT_GRADEBOOK_NAMES",
+            "gradebook_export",
+            Some(export_id),
+            &serde_json::json!({
+                "classroom_id": export.classroom_id,
+                "course_id": export.course_id,
+                "learner_count": built.rows.len(),
+            }),
+            None,
+            None,
+        )
+        .await?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        for (row, enc) in built.rows.iter_mut().zip(&built.names_enc) {
+            row.name = Some(crypto.decrypt(enc, "users.user_name")?);
+        }
+    }
+
+    let (file, ext) = match export.export_format {
+        GradebookExportFormat::Csv => (
+            render::render_csv(&built.columns, &built.rows, export.export_include_names)?,
+            "csv",
+        ),
+        GradebookExportFormat::Xlsx => (
+            render::render_xlsx(&built.columns, &built.rows, export.export_include_names)?,
+            "xlsx",
+        ),
+    };
+    let file_name = format!(
+        "gradebook-{}-{}.{}",
+        scope.file_stem(),
+        Utc::now().format("%Y%m%d"),
+        ext
+    );
+    repo::finish_export_done(
+        &st.db,
+        export_id,
+        &file_name,
+        &file,
+        built.rows.len() as i32,
+        Utc::now() + Duration::hours(st.cfg.gradebook_export_ttl_hours),
+    )
+    .await?;
+
+    tracing::info!(
+        export_id,
+        rows = built.rows.len(),
+        bytes = file.len(),
+        "Gradebook export generated"
+    );
+    Ok(())
+}
+
+pub struct GradebookService;
+
+impl GradebookService {
+    /// 성적부 조회 (닉네임만, 실명 없음)
+    pub async fn view(
+        st: &AppState,
+        actor_user_id: i64,
+        query: GradebookQuery,
+    ) -> AppResult<GradebookRes> {
+        let scope = GradebookScope::from_ids(query.classroom_id, query.course_id)?;
+        let weights = query.weights();
+        check_weights(&weights)?;
+        authorize_scope(st, actor_user_id, scope).await?;
+
+        let built = build(st, scope, &weights).await?;
+        let (classroom_id, course_id) = scope.ids();
+        Ok(GradebookRes {
+            classroom_id,
+            course_id,
+            weights,
+            columns: built.columns,
+            rows: built.rows,
+        })
+    }
+
+    /// 내보내기 요청 — 소규모는 즉시 생성, 대규모는 pending 으로 두고 job 이 처리
+    pub async fn create_export(
+        st: &AppState,
+        actor_user_id: i64,
+        req: CreateGradebookExportReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<GradebookExportRes> {
+        let scope = GradebookScope::from_ids(req.classroom_id, req.course_id)?;
+        let weights = req.weights.unwrap_or_default();
+        check_weights(&weights)?;
+        authorize_scope(st, actor_user_id, scope).await?;
+
+        let include_names = req.include_names.unwrap_or(false);
+        let weights_json = serde_json::to_value(weights)
+            .map_err(|e| AppError::Internal(format!("gradebook weights: {e}")))?;
+        let (classroom_id, course_id) = scope.ids();
+        let export_id = repo::insert_export(
+            &st.db,
+            &NewExport {
+                requested_by_user_id: actor_user_id,
+                classroom_id,
+                course_id,
+                format: req.format,
+                weights: &weights_json,
+                include_names,
+            },
+        )
+        .await?;
+
+        let learner_count = match scope {
+            GradebookScope::Classroom(id) => repo::count_classroom_learners(&st.db, id).await?,
+            GradebookScope::Course(id) => repo::count_course_learners(&st.db, id).await?,
+        };
+        let inline = learner_count <= st.cfg.gradebook_export_sync_max_learners;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "EXPORT_GRADEBOOK",
+            "gradebook_export",
+            Some(export_id),
+            &serde_json::json!({
+                "classroom_id": classroom_id,
+                "course_id": course_id,
+                "format": req.format,
+                "include_names": include_names,
+                "learner_count": learner_count,
+                "async": !inline,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        if inline {
+            repo::mark_running(&st.db, export_id).await?;
+            if let Err(e) = run_export(st, export_id).await {
+                tracing::warn!(error = %e, export_id, "Gradebook export failed");
+                repo::finish_export_failed(&st.db, export_id, &e.to_string()).await?;
+            }
+        }
+
+        repo::find_export(&st.db, export_id)
+            .await?
+            .map(to_export_res)
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 내 내보내기 목록 (최근순)
+    pub async fn list_exports(
+        st: &AppState,
+        actor_user_id: i64,
+    ) -> AppResult<GradebookExportListRes> {
+        check_manager_rbac(&st.db, actor_user_id).await?;
+        let items = repo::find_exports_by_requester(&st.db, actor_user_id, EXPORT_LIST_LIMIT)
+            .await?
+            .into_iter()
+            .map(to_export_res)
+            .collect();
+        Ok(GradebookExportListRes { items })
+    }
+
+    async fn load_own_export(
+        st: &AppState,
+        actor_user_id: i64,
+        export_id: i64,
+    ) -> AppResult<ExportRow> {
+        check_manager_rbac(&st.db, actor_user_id).await?;
+        repo::find_export(&st.db, export_id)
+            .await?
+            .filter(|e| e.requested_by_user_id == actor_user_id)
+            .ok_or(AppError::NotFound)
+    }
+
+    pub async fn get_export(
+        st: &AppState,
+        actor_user_id: i64,
+        export_id: i64,
+    ) -> AppResult<GradebookExportRes> {
+        Self::load_own_export(st, actor_user_id, export_id)
+            .await
+            .map(to_export_res)
+    }
+
+    /// 파일 다운로드 — 요청자 본인만, 만료 전만. (content_type, file_name, bytes)
+    pub async fn download_export(
+        st: &AppState,
+        actor_user_id: i64,
+        export_id: i64,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<(&'static str, String, Vec<u8>)> {
+        let export = Self::load_own_export(st, actor_user_id, export_id).await?;
+        if export.export_status != GradebookExportStatus::Done {
+            return Err(AppError::Conflict("GRADEBOOK_409_NOT_READY".into()));
+        }
+        let file = repo::find_export_file(&st.db, export_id)
+            .await?
+            .ok_or_else(|| AppError::Conflict("GRADEBOOK_409_EXPIRED".into()))?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "DOWNLOAD_GRADEBOOK_EXPORT",
+            "gradebook_export",
+            Some(export_id),
+            &serde_json::json!({ "include_names": export.export_include_names }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        Ok((
+            content_type(export.export_format),
+            export
+                .export_file_name
+                .unwrap_or_else(|| "gradebook".into()),
+            file,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_requires_exactly_one_id() {
+        assert_eq!(
+            GradebookScope::from_ids(Some(1), None).unwrap(),
+            GradebookScope::Classroom(1)
+        );
+        assert_eq!(
+            GradebookScope::from_ids(None, Some(2)).unwrap(),
+            GradebookScope::Course(2)
+        );
+        assert!(GradebookScope::from_ids(Some(1), Some(2)).is_err());
+        assert!(GradebookScope::from_ids(None, None).is_err());
+    }
+
+    #[test]
+    fn category_average_rounds_and_skips_empty() {
+        assert_eq!(category_average(&[]), None);
+        assert_eq!(category_average(&[100.0, 50.0, 0.0]), Some(50.0));
+        assert_eq!(category_average(&[100.0, 0.0, 0.0]), Some(33.3));
+    }
+
+    #[test]
+    fn weighted_total_ignores_missing_and_zero_weight_categories() {
+        // lesson 항목 없음 → study/assignment 만으로 가중 평균
+        assert_eq!(
+            weighted_total(&[(2.0, Some(80.0)), (1.0, None), (1.0, Some(50.0))]),
+            Some(70.0)
+        );
+        // 가중치 0 카테고리는 분모에서도 제외
+        assert_eq!(
+            weighted_total(&[(0.0, Some(10.0)), (1.0, Some(90.0))]),
+            Some(90.0)
+        );
+        assert_eq!(weighted_total(&[(1.0, None), (0.0, Some(50.0))]), None);
+    }
+
+    #[test]
+    fn assignment_score_by_status() {
+        assert_eq!(assignment_score(AssignmentStatus::Completed, 0.5), 100.0);
+        assert_eq!(assignment_score(AssignmentStatus::Late, 0.5), 50.0);
+        assert_eq!(assignment_score(AssignmentStatus::Late, 0.0), 0.0);
+        assert_eq!(assignment_score(AssignmentStatus::InProgress, 0.5), 0.0);
+        assert_eq!(assignment_score(AssignmentStatus::Missed, 1.0), 0.0);
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 use crate::api::admin::translation::dto::TranslationMeta;
-use crate::types::{LessonAccess, LessonState, SupportedLanguage};
+use crate::types::{LessonAccess, LessonPrereqKind, LessonState, SupportedLanguage};
 
 #[derive(Debug, Deserialize, IntoParams, ToSchema)]
 pub struct LessonListReq {
@@ -66,6 +66,8 @@ pub struct LessonDetailRes {
     pub lesson_access: LessonAccess,
     pub items: Vec<LessonItemRes>,
     pub meta: LessonListMeta,
+    /// 선수 조건 잠금 상태
+    pub lock: LessonLockRes,
     /// 번역 메타 (Q1c A)
     pub translation_meta: TranslationMeta,
 }
@@ -86,8 +88,33 @@ pub struct LessonItemDetailRes {
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct LessonItemsRes {
+    /// 잠긴 레슨이면 빈 목록 (meta 는 전체 개수 유지)
     pub items: Vec<LessonItemDetailRes>,
     pub meta: LessonListMeta,
+    /// 선수 조건 잠금 상태
+    pub lock: LessonLockRes,
+}
+
+/// 미충족 선수 조건 1건
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LessonLockReason {
+    pub kind: LessonPrereqKind,
+    /// 선행 레슨/코스/학습 ID (kind 에 따라)
+    pub required_id: i32,
+    pub required_title: Option<String>,
+    /// 필요 값 (lesson/course = 진도율 %, study = 정답 문제 수)
+    pub threshold: i32,
+    /// 현재 값 (비로그인이면 0)
+    pub current: i32,
+}
+
+/// 레슨 잠금 상태 — 조건이 없거나 모두 충족 / 관리자 해제면 locked = false
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LessonLockRes {
+    pub locked: bool,
+    /// 관리자 수동 해제로 열린 경우 true
+    pub unlocked_by_override: bool,
+    pub reasons: Vec<LessonLockReason>,
 }
 
 #[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
@@ -53,10 +53,12 @@ pub async fn list_lessons(
 )]
 pub async fn get_lesson_detail(
     State(state): State<AppState>,
+    OptionalAuthUser(auth): OptionalAuthUser,
     Path(lesson_id): Path<i64>,
     Query(req): Query<LessonDetailReq>,
 ) -> AppResult<Json<LessonDetailRes>> {
-    let res = LessonService::get_lesson_detail(&state.db, lesson_id, req).await?;
+    let user_id = auth.map(|a| a.0.sub);
+    let res = LessonService::get_lesson_detail(&state.db, lesson_id, req, user_id).await?;
     Ok(Json(res))
 }
 
@@ -125,6 +127,7 @@ pub async fn get_lesson_progress(
     responses(
         (status = 200, description = "Lesson progress", body = LessonProgressRes),
         (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Lesson locked by prerequisites", body = crate::error::ErrorBody),
         (status = 404, description = "Not Found", body = crate::error::ErrorBody),
         (status = 422, description = "Unprocessable Entity", body = crate::error::ErrorBody)
     ),
@@ -1,7 +1,7 @@
 use sqlx::PgPool;
 
 use crate::error::AppResult;
-use crate::types::{LessonAccess, LessonState};
+use crate::types::{LessonAccess, LessonPrereqKind, LessonState};
 
 use super::dto::{LessonItemDetailRes, LessonItemRes, LessonProgressRes, LessonRes};
 
@@ -268,6 +268,88 @@ impl LessonRepo {
 
         Ok(row)
     }
+
+    /// 선수 조건 + 사용자 현재 값 (user_id 가 None 이면 current = 0)
+    pub async fn find_prerequisite_status(
+        pool: &PgPool,
+        lesson_id: i64,
+        user_id: Option<i64>,
+    ) -> AppResult<Vec<LessonPrereqStatusRow>> {
+        let rows = sqlx::query_as::<_, LessonPrereqStatusRow>(
+            r#"
+            SELECT
+                p.prereq_kind,
+                COALESCE(p.required_lesson_id, p.required_course_id, p.required_study_id) AS required_id,
+                COALESCE(l.lesson_title, c.course_title, s.study_title) AS required_title,
+                p.prereq_threshold AS threshold,
+                CASE p.prereq_kind
+                    WHEN 'lesson' THEN COALESCE((
+                        SELECT lp.lesson_progress_percent
+                        FROM lesson_progress lp
+                        WHERE lp.lesson_id = p.required_lesson_id AND lp.user_id = $2
+                    ), 0)
+                    WHEN 'course' THEN COALESCE((
+                        SELECT uc.user_course_progress_percent
+                        FROM users_course uc
+                        WHERE uc.course_id = p.required_course_id AND uc.user_id = $2
+                    ), 0)
+                    ELSE (
+                        SELECT COUNT(*)::int
+                        FROM study_task_status sts
+                        JOIN study_task t ON t.study_task_id = sts.study_task_id
+                        WHERE t.study_id = p.required_study_id
+                          AND sts.user_id = $2
+                          AND sts.study_task_status_is_solved = true
+                    )
+                END AS current
+            FROM lesson_prerequisite p
+            LEFT JOIN lesson l ON l.lesson_id = p.required_lesson_id
+            LEFT JOIN course c ON c.course_id = p.required_course_id
+            LEFT JOIN study s ON s.study_id = p.required_study_id
+            WHERE p.lesson_id = $1
+            ORDER BY p.prerequisite_id
+            "#,
+        )
+        .bind(lesson_id)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// 관리자 수동 해제 여부
+    pub async fn has_unlock_override(
+        pool: &PgPool,
+        lesson_id: i64,
+        user_id: i64,
+    ) -> AppResult<bool> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1
+                FROM lesson_unlock_override
+                WHERE lesson_id = $1
+                  AND user_id = $2
+            )
+            "#,
+        )
+        .bind(lesson_id)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(exists)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct LessonPrereqStatusRow {
+    pub prereq_kind: LessonPrereqKind,
+    pub required_id: i32,
+    pub required_title: Option<String>,
+    pub threshold: i32,
+    pub current: i32,
 }
 
 #[derive(Debug, sqlx::FromRow)]
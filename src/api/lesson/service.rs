@@ -9,9 +9,10 @@ use crate::types::{ContentType, LessonAccess, LessonState, SupportedLanguage};
 
 use super::dto::{
     LessonDetailReq, LessonDetailRes, LessonItemsReq, LessonItemsRes, LessonListMeta,
-    LessonListReq, LessonListRes, LessonProgressRes, LessonProgressUpdateReq,
+    LessonListReq, LessonListRes, LessonLockReason, LessonLockRes, LessonProgressRes,
+    LessonProgressUpdateReq,
 };
-use super::repo::LessonRepo;
+use super::repo::{LessonPrereqStatusRow, LessonRepo};
 
 pub struct LessonService;
 
@@ -88,6 +89,7 @@ impl LessonService {
         pool: &PgPool,
         lesson_id: i64,
         req: LessonDetailReq,
+        user_id: Option<i64>,
     ) -> AppResult<LessonDetailRes> {
         let lesson = LessonRepo::find_lesson_by_id(pool, lesson_id)
             .await?
@@ -103,6 +105,7 @@ impl LessonService {
 
         let offset = (page - 1) * per_page;
         let items = LessonRepo::find_items(pool, lesson_id, per_page, offset).await?;
+        let lock = Self::get_lesson_lock(pool, lesson_id, user_id).await?;
 
         // 번역 주입 + 메타 계산 (Q1c A)
         let mut title = lesson.title;
@@ -153,6 +156,7 @@ impl LessonService {
                 current_page: page,
                 per_page,
             },
+            lock,
             translation_meta,
         })
     }
@@ -213,13 +217,19 @@ impl LessonService {
 
         validate_pagination(page, per_page)?;
 
-        // 5. 아이템 조회
+        // 5. 선수 조건 잠금 확인 (잠겨 있으면 아이템 비공개)
+        let lock = Self::get_lesson_lock(&st.db, lesson_id, user_id).await?;
+
+        // 6. 아이템 조회
         let total_count = LessonRepo::count_items(&st.db, lesson_id).await?;
         let total_pages = compute_total_pages(total_count, per_page);
 
         let offset = (page - 1) * per_page;
-        let items =
-            LessonRepo::find_items_for_study_view(&st.db, lesson_id, per_page, offset).await?;
+        let items = if lock.locked {
+            Vec::new()
+        } else {
+            LessonRepo::find_items_for_study_view(&st.db, lesson_id, per_page, offset).await?
+        };
 
         Ok(LessonItemsRes {
             items,
@@ -229,6 +239,7 @@ impl LessonService {
                 current_page: page,
                 per_page,
             },
+            lock,
         })
     }
 
@@ -270,12 +281,45 @@ impl LessonService {
             }
         }
 
+        // 잠긴 레슨은 진도 기록 불가 (선수 조건 우회 방지)
+        if Self::get_lesson_lock(pool, lesson_id, Some(user_id))
+            .await?
+            .locked
+        {
+            return Err(AppError::Forbidden("LESSON_LOCKED".into()));
+        }
+
         let progress =
             LessonRepo::upsert_progress(pool, lesson_id, user_id, req.percent, req.last_seq)
                 .await?;
 
+        // 이 레슨을 포함한 수강 코스들의 진도율 갱신
+        crate::api::course::repo::recompute_progress_for_lesson(pool, user_id, lesson_id).await?;
+        // 100% 도달한 코스는 수료증 발급
+        let mut conn = pool.acquire().await?;
+        crate::api::certificate::service::issue_for_completed(&mut conn, Some(user_id), None)
+            .await?;
+
         Ok(progress)
     }
+
+    /// 레슨 잠금 상태 — 관리자 수동 해제가 있으면 조건과 무관하게 열림
+    pub async fn get_lesson_lock(
+        pool: &PgPool,
+        lesson_id: i64,
+        user_id: Option<i64>,
+    ) -> AppResult<LessonLockRes> {
+        let rows = LessonRepo::find_prerequisite_status(pool, lesson_id, user_id).await?;
+        if rows.is_empty() {
+            return Ok(evaluate_lock(rows, false));
+        }
+
+        let overridden = match user_id {
+            Some(uid) => LessonRepo::has_unlock_override(pool, lesson_id, uid).await?,
+            None => false,
+        };
+        Ok(evaluate_lock(rows, overridden))
+    }
 }
 
 // =============================================================================
@@ -314,9 +358,39 @@ fn validate_progress_percent(percent: i32) -> AppResult<()> {
     Ok(())
 }
 
+/// 선수 조건 평가 = 미충족 조건을 reasons 로 (모두 AND)
+fn evaluate_lock(rows: Vec<LessonPrereqStatusRow>, overridden: bool) -> LessonLockRes {
+    let reasons: Vec<LessonLockReason> = rows
+        .into_iter()
+        .filter(|r| r.current < r.threshold)
+        .map(|r| LessonLockReason {
+            kind: r.prereq_kind,
+            required_id: r.required_id,
+            required_title: r.required_title,
+            threshold: r.threshold,
+            current: r.current,
+        })
+        .collect();
+
+    if overridden {
+        return LessonLockRes {
+            locked: false,
+            unlocked_by_override: !reasons.is_empty(),
+            reasons: Vec::new(),
+        };
+    }
+
+    LessonLockRes {
+        locked: !reasons.is_empty(),
+        unlocked_by_override: false,
+        reasons,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::LessonPrereqKind;
 
     // ------------------------------------------------------------------------
     // validate_pagination
@@ -403,4 +477,61 @@ mod tests {
         let r = validate_progress_percent(200);
         assert!(matches!(r, Err(AppError::Unprocessable(_))));
     }
+
+    // ------------------------------------------------------------------------
+    // evaluate_lock
+    // ------------------------------------------------------------------------
+
+    fn prereq(kind: LessonPrereqKind, threshold: i32, current: i32) -> LessonPrereqStatusRow {
+        LessonPrereqStatusRow {
+            prereq_kind: kind,
+            required_id: 1,
+            required_title: None,
+            threshold,
+            current,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_lock_no_rules_is_unlocked() {
+        let lock = evaluate_lock(Vec::new(), false);
+        assert!(!lock.locked);
+        assert!(lock.reasons.is_empty());
+    }
+
+    #[test]
+    fn test_evaluate_lock_reports_only_unmet_rules() {
+        let lock = evaluate_lock(
+            vec![
+                prereq(LessonPrereqKind::Lesson, 80, 80),
+                prereq(LessonPrereqKind::Study, 10, 3),
+            ],
+            false,
+        );
+        assert!(lock.locked);
+        assert_eq!(lock.reasons.len(), 1, "80% 충족 조건은 제외");
+        assert_eq!(lock.reasons[0].kind, LessonPrereqKind::Study);
+        assert_eq!(lock.reasons[0].current, 3);
+    }
+
+    #[test]
+    fn test_evaluate_lock_all_met_is_unlocked() {
+        let lock = evaluate_lock(
+            vec![
+                prereq(LessonPrereqKind::Lesson, 80, 95),
+                prereq(LessonPrereqKind::Course, 50, 50),
+            ],
+            false,
+        );
+        assert!(!lock.locked);
+        assert!(!lock.unlocked_by_override);
+    }
+
+    #[test]
+    fn test_evaluate_lock_override_unlocks() {
+        let lock = evaluate_lock(vec![prereq(LessonPrereqKind::Lesson, 80, 10)], true);
+        assert!(!lock.locked);
+        assert!(lock.unlocked_by_override);
+        assert!(lock.reasons.is_empty());
+    }
 }
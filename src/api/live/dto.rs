@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{LiveEnrollmentState, LiveSessionState};
+
+// =============================================================================
+// 요청
+// =============================================================================
+
+/// 회차 생성 — 코스는 course_type = live 여야 함
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateLiveSessionReq {
+    #[validate(range(min = 1))]
+    pub course_id: i64,
+    /// 강사 (생략 시 본인, 타인 지정은 HYMN/admin 만)
+    pub teacher_user_id: Option<i64>,
+    #[validate(length(min = 1, max = 200))]
+    pub session_title: String,
+    #[validate(length(max = 4000))]
+    pub session_description: Option<String>,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    /// IANA 시간대 (예: "Asia/Seoul") — 메일/캘린더 표시용, 생략 시 Asia/Seoul
+    #[validate(length(min = 1, max = 64))]
+    pub timezone: Option<String>,
+    #[validate(range(min = 1, max = 1000))]
+    pub capacity: i32,
+}
+
+/// 회차 수정 — 정원 축소는 현재 확정 인원 미만으로 불가, 증원 시 대기자 자동 승격
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateLiveSessionReq {
+    /// 강사 변경 (HYMN/admin 만)
+    pub teacher_user_id: Option<i64>,
+    #[validate(length(min = 1, max = 200))]
+    pub session_title: Option<String>,
+    #[validate(length(max = 4000))]
+    pub session_description: Option<String>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    #[validate(length(min = 1, max = 64))]
+    pub timezone: Option<String>,
+    #[validate(range(min = 1, max = 1000))]
+    pub capacity: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LiveSessionListQuery {
+    /// 특정 코스의 회차만
+    pub course_id: Option<i64>,
+    /// true 면 종료된 회차 포함 (기본 false)
+    pub include_past: Option<bool>,
+}
+
+/// 출석 1건
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct LiveAttendanceRecordReq {
+    #[validate(range(min = 1))]
+    pub user_id: i64,
+    pub attended: bool,
+}
+
+/// 출석 기록 (UPSERT) — 확정(enrolled) 학습자만
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RecordLiveAttendanceReq {
+    #[validate(length(min = 1, max = 1000), nested)]
+    pub records: Vec<LiveAttendanceRecordReq>,
+}
+
+// =============================================================================
+// 응답
+// =============================================================================
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LiveSessionRes {
+    pub live_session_id: i64,
+    pub course_id: i64,
+    pub course_title: String,
+    pub teacher_user_id: i64,
+    pub teacher_nickname: String,
+    pub session_title: String,
+    pub session_description: Option<String>,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    pub timezone: String,
+    /// 회차 시간대 기준 현지 시각 (RFC 3339, offset 포함)
+    pub start_local: String,
+    pub end_local: String,
+    pub capacity: i32,
+    pub enrolled_count: i64,
+    pub waitlist_count: i64,
+    pub session_state: LiveSessionState,
+    /// 본인 신청 상태 (미신청 시 None)
+    pub my_enrollment_state: Option<LiveEnrollmentState>,
+    /// 본인 대기 순번 (1부터, waitlisted 일 때만)
+    pub my_waitlist_position: Option<i64>,
+    /// 참가 URL — 확정 학습자와 관리자에게만 공개
+    pub meeting_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LiveSessionListRes {
+    pub items: Vec<LiveSessionRes>,
+}
+
+/// 신청/취소 결과
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LiveEnrollmentRes {
+    pub live_session_id: i64,
+    pub enrollment_state: LiveEnrollmentState,
+    /// 대기 순번 (1부터, waitlisted 일 때만)
+    pub waitlist_position: Option<i64>,
+    /// 확정일 때만
+    pub meeting_url: Option<String>,
+}
+
+/// 명단 1명 (닉네임만 — 실명은 노출하지 않음)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LiveRosterEntry {
+    pub user_id: i64,
+    pub nickname: String,
+    pub enrollment_state: LiveEnrollmentState,
+    pub enrolled_at: DateTime<Utc>,
+    pub waitlist_position: Option<i64>,
+    /// 출석 기록 (미기록 시 None)
+    pub attended: Option<bool>,
+    pub attendance_recorded_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LiveRosterRes {
+    pub session: LiveSessionRes,
+    pub learners: Vec<LiveRosterEntry>,
+}
+
+/// 캘린더 구독 URL (토큰 원문은 발급 시 1회만 노출)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LiveCalendarFeedRes {
+    pub feed_url: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,405 @@
+use super::{
+    dto::{
+        CreateLiveSessionReq, LiveCalendarFeedRes, LiveEnrollmentRes, LiveRosterRes,
+        LiveSessionListQuery, LiveSessionListRes, LiveSessionRes, RecordLiveAttendanceReq,
+        UpdateLiveSessionReq,
+    },
+    service::LiveSessionService,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::extract::AppJson;
+use crate::{
+    api::auth::extractor::AuthUser,
+    error::{AppError, AppResult},
+    state::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+const ICS_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+// =============================================================================
+// 회차
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/live-sessions",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(LiveSessionListQuery),
+    responses(
+        (status = 200, description = "Managed sessions for managers/admins, sessions of enrolled courses for learners", body = LiveSessionListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_sessions(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Query(query): Query<LiveSessionListQuery>,
+) -> AppResult<Json<LiveSessionListRes>> {
+    let res = LiveSessionService::list(&st, claims.sub, query).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/live-sessions",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    request_body = CreateLiveSessionReq,
+    responses(
+        (status = 201, description = "Live session scheduled with a meeting room", body = LiveSessionRes),
+        (status = 400, description = "Validation error / not a live course / unknown timezone", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required / only admins assign other teachers", body = crate::error::ErrorBody),
+        (status = 404, description = "Course not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn create_session(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    headers: HeaderMap,
+    AppJson(req): AppJson<CreateLiveSessionReq>,
+) -> AppResult<(StatusCode, Json<LiveSessionRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = LiveSessionService::create(
+        &st,
+        claims.sub,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/live-sessions/me",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "My upcoming enrolled and waitlisted sessions", body = LiveSessionListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_sessions(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<LiveSessionListRes>> {
+    let res = LiveSessionService::list_mine(&st, claims.sub).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/live-sessions/{live_session_id}",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    responses(
+        (status = 200, description = "Session (meeting_url only for enrolled learners and managers)", body = LiveSessionRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Session not found or not visible", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_session(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+) -> AppResult<Json<LiveSessionRes>> {
+    let res = LiveSessionService::get(&st, claims.sub, live_session_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/live-sessions/{live_session_id}",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    request_body = UpdateLiveSessionReq,
+    responses(
+        (status = 200, description = "Session updated (waitlist promoted on capacity increase)", body = LiveSessionRes),
+        (status = 400, description = "Validation error / unknown timezone", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Session not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Cancelled / already started / capacity below enrolled count", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn update_session(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<UpdateLiveSessionReq>,
+) -> AppResult<Json<LiveSessionRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = LiveSessionService::update(
+        &st,
+        claims.sub,
+        live_session_id,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/live-sessions/{live_session_id}",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    responses(
+        (status = 204, description = "Session cancelled"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Session not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Already cancelled or ended", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn cancel_session(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+    headers: HeaderMap,
+) -> AppResult<StatusCode> {
+    LiveSessionService::cancel(
+        &st,
+        claims.sub,
+        live_session_id,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// =============================================================================
+// 신청 / 출석
+// =============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/live-sessions/{live_session_id}/enrollment",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    responses(
+        (status = 200, description = "Enrolled, or waitlisted when full (idempotent)", body = LiveEnrollmentRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Active course enrollment required", body = crate::error::ErrorBody),
+        (status = 404, description = "Session not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Session cancelled or already started", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn enroll(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+) -> AppResult<Json<LiveEnrollmentRes>> {
+    let res = LiveSessionService::enroll(&st, claims.sub, live_session_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/live-sessions/{live_session_id}/enrollment",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    responses(
+        (status = 200, description = "Enrollment cancelled (next waitlisted learner promoted)", body = LiveEnrollmentRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Not enrolled", body = crate::error::ErrorBody),
+        (status = 409, description = "Session already started", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn cancel_enrollment(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+) -> AppResult<Json<LiveEnrollmentRes>> {
+    let res = LiveSessionService::cancel_enrollment(&st, claims.sub, live_session_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/live-sessions/{live_session_id}/roster",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    responses(
+        (status = 200, description = "Enrolled and waitlisted learners with attendance (nicknames only)", body = LiveRosterRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Session not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn session_roster(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+) -> AppResult<Json<LiveRosterRes>> {
+    let res = LiveSessionService::roster(&st, claims.sub, live_session_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    put,
+    path = "/live-sessions/{live_session_id}/attendance",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    params(
+        ("live_session_id" = i64, Path, description = "Live session ID")
+    ),
+    request_body = RecordLiveAttendanceReq,
+    responses(
+        (status = 200, description = "Attendance recorded; course progress recomputed", body = LiveRosterRes),
+        (status = 400, description = "Validation error / learner not enrolled", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Manager role required", body = crate::error::ErrorBody),
+        (status = 404, description = "Session not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Session cancelled or not started", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn record_attendance(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(live_session_id): Path<i64>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<RecordLiveAttendanceReq>,
+) -> AppResult<Json<LiveRosterRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = LiveSessionService::record_attendance(
+        &st,
+        claims.sub,
+        live_session_id,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// 캘린더 (ICS)
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/live-sessions/me/calendar",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "My enrolled sessions as iCalendar (text/calendar)"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_calendar(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<impl IntoResponse> {
+    let ics = LiveSessionService::calendar_ics(&st, claims.sub).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, ICS_CONTENT_TYPE),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"amazing-korean-live.ics\"",
+            ),
+            (header::CACHE_CONTROL, "private, no-store"),
+        ],
+        ics,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/live-sessions/me/calendar-feed",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 201, description = "Subscription URL issued (previous URL revoked; token shown once)", body = LiveCalendarFeedRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn issue_calendar_feed(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<(StatusCode, Json<LiveCalendarFeedRes>)> {
+    let res = LiveSessionService::issue_calendar_feed(&st, claims.sub).await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/live-sessions/me/calendar-feed",
+    tag = "Live",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 204, description = "Subscription URL revoked"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "No subscription URL issued", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn revoke_calendar_feed(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<StatusCode> {
+    LiveSessionService::revoke_calendar_feed(&st, claims.sub).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/live-sessions/calendar/{token}",
+    tag = "Live",
+    params(
+        ("token" = String, Path, description = "Calendar subscription token")
+    ),
+    responses(
+        (status = 200, description = "iCalendar feed for calendar apps (text/calendar)"),
+        (status = 404, description = "Unknown or revoked token", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn calendar_feed(
+    State(st): State<AppState>,
+    Path(token): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    let ics = LiveSessionService::calendar_by_token(&st, &token).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, ICS_CONTENT_TYPE),
+            (header::CACHE_CONTROL, "private, max-age=300"),
+        ],
+        ics,
+    ))
+}
@@ -0,0 +1,151 @@
+//! iCalendar(RFC 5545) 렌더링 — 라이브 수업 개인 캘린더.
+//!
+//! 시각은 모두 UTC(`...Z`)로 내보내 VTIMEZONE 정의 없이도 어느 클라이언트에서나 같은 순간을
+//! 가리키게 한다. 취소된 회차는 `STATUS:CANCELLED` 로 남겨 구독 캘린더에서 지워지도록 한다.
+
+use chrono::{DateTime, Utc};
+
+/// VEVENT 1건
+#[derive(Debug, Clone)]
+pub struct IcsEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    pub url: Option<String>,
+    pub cancelled: bool,
+    /// 일정 변경 감지용 (SEQUENCE / LAST-MODIFIED)
+    pub updated_at: DateTime<Utc>,
+}
+
+const PRODID: &str = "-//Amazing Korean//Live Classes//KO";
+
+fn fmt_utc(t: DateTime<Utc>) -> String {
+    t.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// TEXT 값 이스케이프 (`\` `;` `,` 줄바꿈)
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// 75 옥텟 단위 줄 접기 (UTF-8 문자 경계 유지) + CRLF
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        // 이어지는 줄은 선행 공백 1바이트 포함 75 옥텟
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+pub fn render_calendar(calendar_name: &str, events: &[IcsEvent], now: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{PRODID}"));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(
+        &mut out,
+        &format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    );
+    for e in events {
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}", e.uid));
+        push_line(&mut out, &format!("DTSTAMP:{}", fmt_utc(now)));
+        push_line(
+            &mut out,
+            &format!("LAST-MODIFIED:{}", fmt_utc(e.updated_at)),
+        );
+        push_line(&mut out, &format!("SEQUENCE:{}", e.updated_at.timestamp()));
+        push_line(&mut out, &format!("DTSTART:{}", fmt_utc(e.start_at)));
+        push_line(&mut out, &format!("DTEND:{}", fmt_utc(e.end_at)));
+        push_line(&mut out, &format!("SUMMARY:{}", escape_text(&e.summary)));
+        if let Some(desc) = &e.description {
+            push_line(&mut out, &format!("DESCRIPTION:{}", escape_text(desc)));
+        }
+        if let Some(url) = &e.url {
+            push_line(&mut out, &format!("URL:{url}"));
+            push_line(&mut out, &format!("LOCATION:{}", escape_text(url)));
+        }
+        let status = if e.cancelled {
+            "CANCELLED"
+        } else {
+            "CONFIRMED"
+        };
+        push_line(&mut out, &format!("STATUS:{status}"));
+        push_line(&mut out, "END:VEVENT");
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event(cancelled: bool) -> IcsEvent {
+        IcsEvent {
+            uid: "live-session-7@amazingkorean".to_string(),
+            summary: "회화, 1회차; 인사".to_string(),
+            description: Some("준비물\n교재".to_string()),
+            start_at: Utc.with_ymd_and_hms(2026, 11, 2, 10, 0, 0).unwrap(),
+            end_at: Utc.with_ymd_and_hms(2026, 11, 2, 11, 0, 0).unwrap(),
+            url: Some("https://amk.test/live/room/abc".to_string()),
+            cancelled,
+            updated_at: Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn renders_utc_times_escaped_text_and_crlf() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap();
+        let ics = render_calendar("내 라이브 수업", &[event(false)], now);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART:20261102T100000Z\r\n"));
+        assert!(ics.contains("DTEND:20261102T110000Z\r\n"));
+        assert!(ics.contains("SUMMARY:회화\\, 1회차\\; 인사\r\n"));
+        assert!(ics.contains("DESCRIPTION:준비물\\n교재\r\n"));
+        assert!(ics.contains("STATUS:CONFIRMED\r\n"));
+        assert!(!ics.replace("\r\n", "").contains('\n'), "bare LF 금지");
+    }
+
+    #[test]
+    fn cancelled_event_is_marked() {
+        let ics = render_calendar("x", &[event(true)], Utc::now());
+        assert!(ics.contains("STATUS:CANCELLED\r\n"));
+    }
+
+    #[test]
+    fn long_lines_fold_at_75_octets_on_char_boundary() {
+        let mut e = event(false);
+        e.summary = "가".repeat(60); // 180 바이트
+        let ics = render_calendar("x", &[e], Utc::now());
+        for line in ics.split("\r\n") {
+            assert!(line.len() <= 75, "line too long: {} bytes", line.len());
+        }
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("SUMMARY:{}", "가".repeat(60))));
+    }
+}
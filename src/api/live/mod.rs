@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod handler;
+pub mod ics;
+pub mod repo;
+pub mod router;
+pub mod service;
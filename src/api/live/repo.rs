@@ -0,0 +1,731 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+
+use crate::error::AppResult;
+use crate::types::{LiveEnrollmentState, LiveSessionState};
+
+#[derive(Debug, FromRow)]
+pub struct LiveSessionRow {
+    pub live_session_id: i64,
+    pub course_id: i64,
+    pub course_title: String,
+    pub teacher_user_id: i64,
+    pub teacher_nickname: String,
+    pub created_by_user_id: i64,
+    pub session_title: String,
+    pub session_description: Option<String>,
+    pub session_start_at: DateTime<Utc>,
+    pub session_end_at: DateTime<Utc>,
+    pub session_timezone: String,
+    pub session_capacity: i32,
+    pub session_state: LiveSessionState,
+    pub meeting_external_id: String,
+    pub meeting_url: String,
+    pub enrolled_count: i64,
+    pub waitlist_count: i64,
+    pub session_created_at: DateTime<Utc>,
+    pub session_updated_at: DateTime<Utc>,
+}
+
+const SESSION_SELECT: &str = r#"
+    SELECT
+        s.live_session_id,
+        s.course_id::bigint AS course_id,
+        c.course_title,
+        s.teacher_user_id,
+        t.user_nickname AS teacher_nickname,
+        s.created_by_user_id,
+        s.session_title,
+        s.session_description,
+        s.session_start_at,
+        s.session_end_at,
+        s.session_timezone,
+        s.session_capacity,
+        s.session_state,
+        s.meeting_external_id,
+        s.meeting_url,
+        (SELECT COUNT(*) FROM live_enrollment e
+         WHERE e.live_session_id = s.live_session_id
+           AND e.enrollment_state = 'enrolled') AS enrolled_count,
+        (SELECT COUNT(*) FROM live_enrollment e
+         WHERE e.live_session_id = s.live_session_id
+           AND e.enrollment_state = 'waitlisted') AS waitlist_count,
+        s.session_created_at,
+        s.session_updated_at
+    FROM live_session s
+    JOIN course c ON c.course_id = s.course_id
+    JOIN users t ON t.user_id = s.teacher_user_id
+"#;
+
+/// 대기 순번 (`e` = live_enrollment 별칭, waitlisted 가 아니면 NULL)
+const WAITLIST_POSITION_OF_E: &str = r#"
+    CASE WHEN e.enrollment_state = 'waitlisted' THEN (
+        SELECT COUNT(*) FROM live_enrollment w
+        WHERE w.live_session_id = e.live_session_id
+          AND w.enrollment_state = 'waitlisted'
+          AND (w.enrollment_created_at, w.user_id) <= (e.enrollment_created_at, e.user_id)
+    ) END
+"#;
+
+pub async fn find_by_id(pool: &PgPool, live_session_id: i64) -> AppResult<Option<LiveSessionRow>> {
+    let sql = format!("{SESSION_SELECT} WHERE s.live_session_id = $1");
+    let row = sqlx::query_as::<_, LiveSessionRow>(&sql)
+        .bind(live_session_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 관리 회차 목록 — manager_user_id 지정 시 본인이 강사이거나 만든 회차만
+pub async fn find_managed(
+    pool: &PgPool,
+    manager_user_id: Option<i64>,
+    course_id: Option<i64>,
+    ended_after: Option<DateTime<Utc>>,
+) -> AppResult<Vec<LiveSessionRow>> {
+    let sql = format!(
+        r#"{SESSION_SELECT}
+        WHERE ($1::bigint IS NULL OR s.teacher_user_id = $1 OR s.created_by_user_id = $1)
+          AND ($2::bigint IS NULL OR s.course_id = $2)
+          AND ($3::timestamptz IS NULL OR s.session_end_at > $3)
+        ORDER BY s.session_start_at, s.live_session_id
+        "#
+    );
+    let rows = sqlx::query_as::<_, LiveSessionRow>(&sql)
+        .bind(manager_user_id)
+        .bind(course_id)
+        .bind(ended_after)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 학습자에게 보이는 회차 — 수강 중(활성 + 미만료)인 코스의 회차 또는 본인 신청 이력이 있는 회차
+pub async fn find_for_learner(
+    pool: &PgPool,
+    user_id: i64,
+    course_id: Option<i64>,
+    ended_after: Option<DateTime<Utc>>,
+) -> AppResult<Vec<LiveSessionRow>> {
+    let sql = format!(
+        r#"{SESSION_SELECT}
+        WHERE (
+                s.course_id IN (
+                    SELECT uc.course_id FROM users_course uc
+                    WHERE uc.user_id = $1
+                      AND uc.user_course_active = true
+                      AND (uc.user_course_expire_at IS NULL OR uc.user_course_expire_at > NOW())
+                )
+                OR EXISTS (SELECT 1 FROM live_enrollment e
+                           WHERE e.live_session_id = s.live_session_id AND e.user_id = $1)
+              )
+          AND ($2::bigint IS NULL OR s.course_id = $2)
+          AND ($3::timestamptz IS NULL OR s.session_end_at > $3)
+        ORDER BY s.session_start_at, s.live_session_id
+        "#
+    );
+    let rows = sqlx::query_as::<_, LiveSessionRow>(&sql)
+        .bind(user_id)
+        .bind(course_id)
+        .bind(ended_after)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 본인 신청 회차 (확정 + 대기) — 종료 전만
+pub async fn find_enrolled_upcoming(
+    pool: &PgPool,
+    user_id: i64,
+    now: DateTime<Utc>,
+) -> AppResult<Vec<LiveSessionRow>> {
+    let sql = format!(
+        r#"{SESSION_SELECT}
+        JOIN live_enrollment e ON e.live_session_id = s.live_session_id AND e.user_id = $1
+        WHERE e.enrollment_state IN ('enrolled', 'waitlisted')
+          AND s.session_end_at > $2
+        ORDER BY s.session_start_at, s.live_session_id
+        "#
+    );
+    let rows = sqlx::query_as::<_, LiveSessionRow>(&sql)
+        .bind(user_id)
+        .bind(now)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 캘린더 대상 — 확정 신청한 회차 (취소된 회차도 포함해 구독 캘린더에서 지워지도록)
+pub async fn find_calendar_sessions(
+    pool: &PgPool,
+    user_id: i64,
+    ended_after: DateTime<Utc>,
+) -> AppResult<Vec<LiveSessionRow>> {
+    let sql = format!(
+        r#"{SESSION_SELECT}
+        JOIN live_enrollment e ON e.live_session_id = s.live_session_id AND e.user_id = $1
+        WHERE e.enrollment_state = 'enrolled'
+          AND s.session_end_at > $2
+        ORDER BY s.session_start_at, s.live_session_id
+        "#
+    );
+    let rows = sqlx::query_as::<_, LiveSessionRow>(&sql)
+        .bind(user_id)
+        .bind(ended_after)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+#[derive(Debug, FromRow)]
+pub struct MyEnrollmentRow {
+    pub live_session_id: i64,
+    pub enrollment_state: LiveEnrollmentState,
+    pub waitlist_position: Option<i64>,
+}
+
+/// 본인 신청 상태 (회차 여러 건)
+pub async fn find_my_enrollments(
+    pool: &PgPool,
+    user_id: i64,
+    live_session_ids: &[i64],
+) -> AppResult<Vec<MyEnrollmentRow>> {
+    let sql = format!(
+        r#"
+        SELECT e.live_session_id, e.enrollment_state,
+               ({WAITLIST_POSITION_OF_E}) AS waitlist_position
+        FROM live_enrollment e
+        WHERE e.user_id = $1 AND e.live_session_id = ANY($2)
+        "#
+    );
+    let rows = sqlx::query_as::<_, MyEnrollmentRow>(&sql)
+        .bind(user_id)
+        .bind(live_session_ids)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 코스 종류 (`course_type_enum` 텍스트) — 코스가 없으면 None
+pub async fn find_course_type(pool: &PgPool, course_id: i64) -> AppResult<Option<String>> {
+    let course_type = sqlx::query_scalar::<_, String>(
+        "SELECT course_type::text FROM course WHERE course_id = $1",
+    )
+    .bind(course_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(course_type)
+}
+
+/// 코스 수강권 보유 여부 (활성 + 미만료)
+pub async fn has_course_access(pool: &PgPool, user_id: i64, course_id: i64) -> AppResult<bool> {
+    let ok = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM users_course
+            WHERE user_id = $1 AND course_id = $2
+              AND user_course_active = true
+              AND (user_course_expire_at IS NULL OR user_course_expire_at > NOW())
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(course_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(ok)
+}
+
+pub struct NewLiveSession<'a> {
+    pub course_id: i64,
+    pub teacher_user_id: i64,
+    pub created_by_user_id: i64,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    pub timezone: &'a str,
+    pub capacity: i32,
+    pub meeting_provider: &'a str,
+}
+
+/// 회차 생성 — 회의실은 id 확정 후 `set_meeting` 으로 채움 (같은 트랜잭션)
+pub async fn insert_session(conn: &mut PgConnection, s: &NewLiveSession<'_>) -> AppResult<i64> {
+    let id = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO live_session
+            (course_id, teacher_user_id, created_by_user_id, session_title, session_description,
+             session_start_at, session_end_at, session_timezone, session_capacity,
+             meeting_provider, meeting_external_id, meeting_url)
+        VALUES ($1::int, $2, $3, $4, $5, $6, $7, $8, $9, $10, '', '')
+        RETURNING live_session_id
+        "#,
+    )
+    .bind(s.course_id)
+    .bind(s.teacher_user_id)
+    .bind(s.created_by_user_id)
+    .bind(s.title)
+    .bind(s.description)
+    .bind(s.start_at)
+    .bind(s.end_at)
+    .bind(s.timezone)
+    .bind(s.capacity)
+    .bind(s.meeting_provider)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(id)
+}
+
+pub async fn set_meeting(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    external_id: &str,
+    url: &str,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE live_session
+        SET meeting_external_id = $2, meeting_url = $3
+        WHERE live_session_id = $1
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(external_id)
+    .bind(url)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// 잠금 조회용 최소 컬럼
+#[derive(Debug, FromRow)]
+pub struct LockedSessionRow {
+    pub course_id: i64,
+    pub session_start_at: DateTime<Utc>,
+    pub session_capacity: i32,
+    pub session_state: LiveSessionState,
+}
+
+/// 정원 판정 직렬화를 위해 회차 행 잠금
+pub async fn lock_session(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+) -> AppResult<Option<LockedSessionRow>> {
+    let row = sqlx::query_as::<_, LockedSessionRow>(
+        r#"
+        SELECT course_id::bigint AS course_id, session_start_at, session_capacity, session_state
+        FROM live_session
+        WHERE live_session_id = $1
+        FOR UPDATE
+        "#,
+    )
+    .bind(live_session_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    Ok(row)
+}
+
+pub struct LiveSessionPatch<'a> {
+    pub teacher_user_id: Option<i64>,
+    pub title: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub timezone: Option<&'a str>,
+    pub capacity: Option<i32>,
+}
+
+pub async fn update_session(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    p: &LiveSessionPatch<'_>,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE live_session
+        SET teacher_user_id     = COALESCE($2, teacher_user_id),
+            session_title       = COALESCE($3, session_title),
+            session_description = COALESCE($4, session_description),
+            session_start_at    = COALESCE($5, session_start_at),
+            session_end_at      = COALESCE($6, session_end_at),
+            session_timezone    = COALESCE($7, session_timezone),
+            session_capacity    = COALESCE($8, session_capacity),
+            session_updated_at  = NOW()
+        WHERE live_session_id = $1
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(p.teacher_user_id)
+    .bind(p.title)
+    .bind(p.description)
+    .bind(p.start_at)
+    .bind(p.end_at)
+    .bind(p.timezone)
+    .bind(p.capacity)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub async fn cancel_session(conn: &mut PgConnection, live_session_id: i64) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE live_session
+        SET session_state = 'cancelled', session_updated_at = NOW()
+        WHERE live_session_id = $1
+        "#,
+    )
+    .bind(live_session_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// 일정 변경 시 리마인더 재발송 대상이 되도록 발송 기록 삭제
+pub async fn clear_reminders(conn: &mut PgConnection, live_session_id: i64) -> AppResult<()> {
+    sqlx::query("DELETE FROM live_reminder WHERE live_session_id = $1")
+        .bind(live_session_id)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+pub async fn count_enrolled(conn: &mut PgConnection, live_session_id: i64) -> AppResult<i64> {
+    let n = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM live_enrollment
+        WHERE live_session_id = $1 AND enrollment_state = 'enrolled'
+        "#,
+    )
+    .bind(live_session_id)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(n)
+}
+
+pub async fn find_enrollment_state(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    user_id: i64,
+) -> AppResult<Option<LiveEnrollmentState>> {
+    let state = sqlx::query_scalar::<_, LiveEnrollmentState>(
+        r#"
+        SELECT enrollment_state FROM live_enrollment
+        WHERE live_session_id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(user_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    Ok(state)
+}
+
+/// 신청 (재신청 시 대기 순번 기준 시각도 갱신)
+pub async fn upsert_enrollment(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    user_id: i64,
+    state: LiveEnrollmentState,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO live_enrollment (live_session_id, user_id, enrollment_state)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (live_session_id, user_id) DO UPDATE
+        SET enrollment_state      = EXCLUDED.enrollment_state,
+            enrollment_created_at = NOW(),
+            enrollment_updated_at = NOW()
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(user_id)
+    .bind(state)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub async fn cancel_enrollment(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE live_enrollment
+        SET enrollment_state = 'cancelled', enrollment_updated_at = NOW()
+        WHERE live_session_id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// 대기자를 신청 순으로 최대 `slots` 명 확정 — 승격된 user_id 반환
+pub async fn promote_waitlist(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    slots: i64,
+) -> AppResult<Vec<i64>> {
+    if slots <= 0 {
+        return Ok(Vec::new());
+    }
+    let ids = sqlx::query_scalar::<_, i64>(
+        r#"
+        UPDATE live_enrollment
+        SET enrollment_state = 'enrolled', enrollment_updated_at = NOW()
+        WHERE live_session_id = $1
+          AND user_id IN (
+              SELECT user_id FROM live_enrollment
+              WHERE live_session_id = $1 AND enrollment_state = 'waitlisted'
+              ORDER BY enrollment_created_at, user_id
+              LIMIT $2
+          )
+        RETURNING user_id
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(slots)
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(ids)
+}
+
+pub async fn waitlist_position(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    user_id: i64,
+) -> AppResult<Option<i64>> {
+    let sql = format!(
+        r#"
+        SELECT ({WAITLIST_POSITION_OF_E})
+        FROM live_enrollment e
+        WHERE e.live_session_id = $1 AND e.user_id = $2
+        "#
+    );
+    let pos = sqlx::query_scalar::<_, Option<i64>>(&sql)
+        .bind(live_session_id)
+        .bind(user_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    Ok(pos.flatten())
+}
+
+#[derive(Debug, FromRow)]
+pub struct RosterRow {
+    pub user_id: i64,
+    pub nickname: String,
+    pub enrollment_state: LiveEnrollmentState,
+    pub enrollment_created_at: DateTime<Utc>,
+    pub waitlist_position: Option<i64>,
+    pub attended: Option<bool>,
+    pub recorded_at: Option<DateTime<Utc>>,
+}
+
+/// 명단 (확정 → 대기 순, 취소 제외)
+pub async fn find_roster(pool: &PgPool, live_session_id: i64) -> AppResult<Vec<RosterRow>> {
+    let sql = format!(
+        r#"
+        SELECT e.user_id, u.user_nickname AS nickname, e.enrollment_state,
+               e.enrollment_created_at,
+               ({WAITLIST_POSITION_OF_E}) AS waitlist_position,
+               a.attended, a.recorded_at
+        FROM live_enrollment e
+        JOIN users u ON u.user_id = e.user_id
+        LEFT JOIN live_attendance a
+               ON a.live_session_id = e.live_session_id AND a.user_id = e.user_id
+        WHERE e.live_session_id = $1 AND e.enrollment_state <> 'cancelled'
+        ORDER BY e.enrollment_state, e.enrollment_created_at, e.user_id
+        "#
+    );
+    let rows = sqlx::query_as::<_, RosterRow>(&sql)
+        .bind(live_session_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 주어진 사용자 중 확정(enrolled) 상태인 user_id
+pub async fn find_enrolled_among(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    user_ids: &[i64],
+) -> AppResult<Vec<i64>> {
+    let ids = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT user_id FROM live_enrollment
+        WHERE live_session_id = $1 AND user_id = ANY($2) AND enrollment_state = 'enrolled'
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(user_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(ids)
+}
+
+pub async fn upsert_attendance(
+    conn: &mut PgConnection,
+    live_session_id: i64,
+    user_id: i64,
+    attended: bool,
+    recorded_by_user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO live_attendance (live_session_id, user_id, attended, recorded_by_user_id)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (live_session_id, user_id) DO UPDATE
+        SET attended            = EXCLUDED.attended,
+            recorded_by_user_id = EXCLUDED.recorded_by_user_id,
+            recorded_at         = NOW()
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(user_id)
+    .bind(attended)
+    .bind(recorded_by_user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+#[derive(Debug, FromRow)]
+pub struct UserEmailRow {
+    pub user_id: i64,
+    pub user_email_enc: String,
+}
+
+/// 알림 대상 이메일 (암호문) — 활성 사용자만
+pub async fn find_user_emails(pool: &PgPool, user_ids: &[i64]) -> AppResult<Vec<UserEmailRow>> {
+    let rows = sqlx::query_as::<_, UserEmailRow>(
+        r#"
+        SELECT user_id, user_email AS user_email_enc
+        FROM users
+        WHERE user_id = ANY($1) AND user_state = true
+        "#,
+    )
+    .bind(user_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// =============================================================================
+// 리마인더
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct ReminderCandidateRow {
+    pub live_session_id: i64,
+    pub session_title: String,
+    pub session_start_at: DateTime<Utc>,
+    pub session_timezone: String,
+    pub meeting_url: String,
+    pub user_id: i64,
+    pub user_email_enc: String,
+}
+
+/// 시작이 (now, window_end] 안인 예정 회차의 확정 학습자 중 아직 리마인더를 받지 않은 대상
+pub async fn find_reminder_candidates(
+    pool: &PgPool,
+    now: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    limit: i64,
+) -> AppResult<Vec<ReminderCandidateRow>> {
+    let rows = sqlx::query_as::<_, ReminderCandidateRow>(
+        r#"
+        SELECT s.live_session_id, s.session_title, s.session_start_at, s.session_timezone,
+               s.meeting_url, u.user_id, u.user_email AS user_email_enc
+        FROM live_session s
+        JOIN live_enrollment e
+          ON e.live_session_id = s.live_session_id AND e.enrollment_state = 'enrolled'
+        JOIN users u ON u.user_id = e.user_id
+        WHERE s.session_state = 'scheduled'
+          AND s.session_start_at > $1
+          AND s.session_start_at <= $2
+          AND u.user_state = true
+          AND NOT EXISTS (SELECT 1 FROM live_reminder r
+                          WHERE r.live_session_id = s.live_session_id AND r.user_id = u.user_id)
+        ORDER BY s.session_start_at, s.live_session_id, u.user_id
+        LIMIT $3
+        "#,
+    )
+    .bind(now)
+    .bind(window_end)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 리마인더 발송 기록 선점 — 이미 기록돼 있으면 false (중복 발송 방지)
+pub async fn claim_reminder(pool: &PgPool, live_session_id: i64, user_id: i64) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        INSERT INTO live_reminder (live_session_id, user_id)
+        VALUES ($1, $2)
+        ON CONFLICT (live_session_id, user_id) DO NOTHING
+        "#,
+    )
+    .bind(live_session_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+// =============================================================================
+// 캘린더 구독 토큰
+// =============================================================================
+
+/// 구독 토큰 발급/재발급 (기존 토큰은 무효화)
+pub async fn upsert_calendar_feed(
+    pool: &PgPool,
+    user_id: i64,
+    token_hash: &str,
+) -> AppResult<DateTime<Utc>> {
+    let created_at = sqlx::query_scalar::<_, DateTime<Utc>>(
+        r#"
+        INSERT INTO live_calendar_feed (user_id, feed_token_hash)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id) DO UPDATE
+        SET feed_token_hash = EXCLUDED.feed_token_hash,
+            feed_created_at = NOW()
+        RETURNING feed_created_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(token_hash)
+    .fetch_one(pool)
+    .await?;
+    Ok(created_at)
+}
+
+pub async fn delete_calendar_feed(pool: &PgPool, user_id: i64) -> AppResult<bool> {
+    let res = sqlx::query("DELETE FROM live_calendar_feed WHERE user_id = $1")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// 토큰 해시 → 활성 사용자 user_id
+pub async fn find_calendar_feed_user(pool: &PgPool, token_hash: &str) -> AppResult<Option<i64>> {
+    let user_id = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT f.user_id
+        FROM live_calendar_feed f
+        JOIN users u ON u.user_id = f.user_id
+        WHERE f.feed_token_hash = $1 AND u.user_state = true
+        "#,
+    )
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await?;
+    Ok(user_id)
+}
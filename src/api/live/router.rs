@@ -0,0 +1,39 @@
+use super::handler;
+use crate::state::AppState;
+use axum::routing::{get, post, put};
+
+pub fn live_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(
+            "/live-sessions",
+            get(handler::list_sessions).post(handler::create_session),
+        )
+        .route("/live-sessions/me", get(handler::my_sessions))
+        .route("/live-sessions/me/calendar", get(handler::my_calendar))
+        .route(
+            "/live-sessions/me/calendar-feed",
+            post(handler::issue_calendar_feed).delete(handler::revoke_calendar_feed),
+        )
+        .route(
+            "/live-sessions/calendar/{token}",
+            get(handler::calendar_feed),
+        )
+        .route(
+            "/live-sessions/{live_session_id}",
+            get(handler::get_session)
+                .patch(handler::update_session)
+                .delete(handler::cancel_session),
+        )
+        .route(
+            "/live-sessions/{live_session_id}/enrollment",
+            post(handler::enroll).delete(handler::cancel_enrollment),
+        )
+        .route(
+            "/live-sessions/{live_session_id}/roster",
+            get(handler::session_roster),
+        )
+        .route(
+            "/live-sessions/{live_session_id}/attendance",
+            put(handler::record_attendance),
+        )
+}
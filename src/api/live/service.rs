@@ -0,0 +1,896 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use super::{
+    dto::{
+        CreateLiveSessionReq, LiveCalendarFeedRes, LiveEnrollmentRes, LiveRosterEntry,
+        LiveRosterRes, LiveSessionListQuery, LiveSessionListRes, LiveSessionRes,
+        RecordLiveAttendanceReq, UpdateLiveSessionReq,
+    },
+    ics::{render_calendar, IcsEvent},
+    repo::{self, LiveSessionPatch, LiveSessionRow, MyEnrollmentRow, NewLiveSession},
+};
+use crate::api::admin::user::repo::write_audit_log;
+use crate::api::classroom::service::check_manager_rbac;
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::external::email::{send_templated, EmailTemplate};
+use crate::external::live_meeting::LiveMeetingRequest;
+use crate::state::AppState;
+use crate::types::{LiveEnrollmentState, LiveSessionState, UserAuth};
+
+/// 기본 표시 시간대
+const DEFAULT_TIMEZONE: &str = "Asia/Seoul";
+/// 회차 최대 길이
+const MAX_SESSION_HOURS: i64 = 12;
+/// 캘린더에 포함할 지난 회차 범위
+const CALENDAR_PAST_DAYS: i64 = 30;
+
+/// IANA 시간대 이름 검증 (예: "Asia/Seoul", "America/New_York")
+pub fn parse_timezone(name: &str) -> AppResult<Tz> {
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| AppError::BadRequest(format!("Unknown timezone: {name}")))
+}
+
+/// 회차 시간대 기준 RFC 3339 (offset 포함)
+pub fn local_rfc3339(t: DateTime<Utc>, tz: Tz) -> String {
+    t.with_timezone(&tz).to_rfc3339()
+}
+
+/// 메일 표시용 현지 시각 (예: "2026-11-02 19:00 KST")
+pub fn local_display(t: DateTime<Utc>, tz: Tz) -> String {
+    t.with_timezone(&tz).format("%Y-%m-%d %H:%M %Z").to_string()
+}
+
+/// 일정 검증 — start < end, 최대 `MAX_SESSION_HOURS` 시간
+pub fn validate_window(start_at: DateTime<Utc>, end_at: DateTime<Utc>) -> AppResult<()> {
+    if end_at <= start_at {
+        return Err(AppError::BadRequest("end_at must be after start_at".into()));
+    }
+    if end_at - start_at > Duration::hours(MAX_SESSION_HOURS) {
+        return Err(AppError::BadRequest(format!(
+            "A live session cannot be longer than {MAX_SESSION_HOURS} hours"
+        )));
+    }
+    Ok(())
+}
+
+/// 정원 판정 — 남은 자리가 있으면 확정, 없으면 대기
+pub fn enrollment_state_for(capacity: i32, enrolled_count: i64) -> LiveEnrollmentState {
+    if enrolled_count < i64::from(capacity) {
+        LiveEnrollmentState::Enrolled
+    } else {
+        LiveEnrollmentState::Waitlisted
+    }
+}
+
+/// 회차 관리 권한 — manager 는 본인이 강사이거나 만든 회차만
+fn can_manage_session(actor_auth: UserAuth, actor_user_id: i64, row: &LiveSessionRow) -> bool {
+    match actor_auth {
+        UserAuth::Hymn | UserAuth::Admin => true,
+        UserAuth::Manager => {
+            row.teacher_user_id == actor_user_id || row.created_by_user_id == actor_user_id
+        }
+        UserAuth::Learner => false,
+    }
+}
+
+/// 구독 토큰 원문 생성 (발급 응답에만 포함, DB 에는 해시만 저장)
+fn generate_feed_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn hash_feed_token(token: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+fn feed_path(token: &str) -> String {
+    format!("/live-sessions/calendar/{token}")
+}
+
+async fn actor_auth(st: &AppState, actor_user_id: i64) -> AppResult<UserAuth> {
+    let actor = crate::api::user::repo::find_user(&st.db, actor_user_id)
+        .await?
+        .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+    Ok(actor.user_auth)
+}
+
+/// 강사 검증 — manager 이상 활성 사용자
+async fn check_teacher(st: &AppState, teacher_user_id: i64) -> AppResult<()> {
+    let teacher = crate::api::user::repo::find_user(&st.db, teacher_user_id)
+        .await?
+        .filter(|u| u.user_state)
+        .ok_or_else(|| AppError::BadRequest("Teacher not found".into()))?;
+    match teacher.user_auth {
+        UserAuth::Hymn | UserAuth::Admin | UserAuth::Manager => Ok(()),
+        UserAuth::Learner => Err(AppError::BadRequest(
+            "Teacher must be a manager or admin".into(),
+        )),
+    }
+}
+
+fn to_res(row: &LiveSessionRow, mine: Option<&MyEnrollmentRow>, show_url: bool) -> LiveSessionRes {
+    // 저장된 시간대는 생성/수정 시 검증됨 — 그래도 파싱 실패 시 UTC 로 표시
+    let tz = row.session_timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+    let enrolled = mine.is_some_and(|m| m.enrollment_state == LiveEnrollmentState::Enrolled);
+    LiveSessionRes {
+        live_session_id: row.live_session_id,
+        course_id: row.course_id,
+        course_title: row.course_title.clone(),
+        teacher_user_id: row.teacher_user_id,
+        teacher_nickname: row.teacher_nickname.clone(),
+        session_title: row.session_title.clone(),
+        session_description: row.session_description.clone(),
+        start_at: row.session_start_at,
+        end_at: row.session_end_at,
+        timezone: row.session_timezone.clone(),
+        start_local: local_rfc3339(row.session_start_at, tz),
+        end_local: local_rfc3339(row.session_end_at, tz),
+        capacity: row.session_capacity,
+        enrolled_count: row.enrolled_count,
+        waitlist_count: row.waitlist_count,
+        session_state: row.session_state,
+        my_enrollment_state: mine.map(|m| m.enrollment_state),
+        my_waitlist_position: mine.and_then(|m| m.waitlist_position),
+        meeting_url: (row.session_state == LiveSessionState::Scheduled && (show_url || enrolled))
+            .then(|| row.meeting_url.clone()),
+        created_at: row.session_created_at,
+        updated_at: row.session_updated_at,
+    }
+}
+
+/// 목록 응답 — 본인 신청 상태를 붙여 변환
+async fn with_my_enrollments(
+    st: &AppState,
+    user_id: i64,
+    rows: Vec<LiveSessionRow>,
+    show_url: bool,
+) -> AppResult<Vec<LiveSessionRes>> {
+    let ids: Vec<i64> = rows.iter().map(|r| r.live_session_id).collect();
+    let mine: HashMap<i64, MyEnrollmentRow> = repo::find_my_enrollments(&st.db, user_id, &ids)
+        .await?
+        .into_iter()
+        .map(|m| (m.live_session_id, m))
+        .collect();
+    Ok(rows
+        .iter()
+        .map(|r| to_res(r, mine.get(&r.live_session_id), show_url))
+        .collect())
+}
+
+/// 관리 대상 회차 로드 — 권한 밖 회차는 존재 여부를 노출하지 않도록 404
+async fn load_managed(
+    st: &AppState,
+    actor_user_id: i64,
+    live_session_id: i64,
+) -> AppResult<LiveSessionRow> {
+    let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+    repo::find_by_id(&st.db, live_session_id)
+        .await?
+        .filter(|r| can_manage_session(actor_auth, actor_user_id, r))
+        .ok_or(AppError::NotFound)
+}
+
+fn meeting_request(row: &LiveSessionRow) -> LiveMeetingRequest {
+    LiveMeetingRequest {
+        live_session_id: row.live_session_id,
+        title: row.session_title.clone(),
+        start_at: row.session_start_at,
+        end_at: row.session_end_at,
+        timezone: row.session_timezone.clone(),
+    }
+}
+
+/// 대기 → 확정 승격 안내 메일 (커밋 후 호출, 실패는 로그만)
+async fn notify_promoted(st: &AppState, row: &LiveSessionRow, user_ids: &[i64]) {
+    let Some(sender) = st.email.as_ref() else {
+        return;
+    };
+    if user_ids.is_empty() {
+        return;
+    }
+    let targets = match repo::find_user_emails(&st.db, user_ids).await {
+        Ok(t) => t,
+        Err(e) => {
+            tracing::warn!(error = %e, "live waitlist promotion: email lookup failed");
+            return;
+        }
+    };
+    let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+    let tz = row.session_timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+    for t in targets {
+        let result = match crypto.decrypt(&t.user_email_enc, "users.user_email") {
+            Ok(email) => {
+                send_templated(
+                    sender.as_ref(),
+                    &email,
+                    EmailTemplate::LiveWaitlistPromoted {
+                        session_title: row.session_title.clone(),
+                        start_at: local_display(row.session_start_at, tz),
+                        meeting_url: row.meeting_url.clone(),
+                    },
+                )
+                .await
+            }
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = result {
+            tracing::warn!(
+                error = %e,
+                live_session_id = row.live_session_id,
+                user_id = t.user_id,
+                "live waitlist promotion email failed"
+            );
+        }
+    }
+}
+
+pub struct LiveSessionService;
+
+impl LiveSessionService {
+    /// 회차 생성 — course_type = live 코스에만, 회의실은 프로바이더가 발급
+    pub async fn create(
+        st: &AppState,
+        actor_user_id: i64,
+        req: CreateLiveSessionReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<LiveSessionRes> {
+        let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+
+        let teacher_user_id = req.teacher_user_id.unwrap_or(actor_user_id);
+        if teacher_user_id != actor_user_id {
+            if actor_auth == UserAuth::Manager {
+                return Err(AppError::Forbidden(
+                    "LIVE_403_TEACHER_ASSIGN: managers can only schedule themselves".into(),
+                ));
+            }
+            check_teacher(st, teacher_user_id).await?;
+        }
+
+        match repo::find_course_type(&st.db, req.course_id)
+            .await?
+            .as_deref()
+        {
+            None => return Err(AppError::NotFound),
+            Some("live") => {}
+            Some(_) => {
+                return Err(AppError::BadRequest(
+                    "LIVE_400_NOT_LIVE_COURSE: course_type must be live".into(),
+                ))
+            }
+        }
+
+        validate_window(req.start_at, req.end_at)?;
+        if req.start_at <= Utc::now() {
+            return Err(AppError::BadRequest(
+                "start_at must be in the future".into(),
+            ));
+        }
+        let timezone = parse_timezone(req.timezone.as_deref().unwrap_or(DEFAULT_TIMEZONE))?;
+        let timezone_name = timezone.name();
+
+        let mut tx = st.db.begin().await?;
+        let live_session_id = repo::insert_session(
+            &mut tx,
+            &NewLiveSession {
+                course_id: req.course_id,
+                teacher_user_id,
+                created_by_user_id: actor_user_id,
+                title: &req.session_title,
+                description: req.session_description.as_deref(),
+                start_at: req.start_at,
+                end_at: req.end_at,
+                timezone: timezone_name,
+                capacity: req.capacity,
+                meeting_provider: st.live_meeting.name(),
+            },
+        )
+        .await?;
+        let meeting = st
+            .live_meeting
+            .create_meeting(&LiveMeetingRequest {
+                live_session_id,
+                title: req.session_title.clone(),
+                start_at: req.start_at,
+                end_at: req.end_at,
+                timezone: timezone_name.to_string(),
+            })
+            .await?;
+        repo::set_meeting(
+            &mut tx,
+            live_session_id,
+            &meeting.external_id,
+            &meeting.join_url,
+        )
+        .await?;
+        // 새 회차가 진도율 분모에 포함됨
+        crate::api::course::repo::recompute_progress_for_course(&mut *tx, req.course_id, None)
+            .await?;
+        tx.commit().await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "CREATE_LIVE_SESSION",
+            "live_session",
+            Some(live_session_id),
+            &serde_json::json!({
+                "course_id": req.course_id,
+                "teacher_user_id": teacher_user_id,
+                "start_at": req.start_at,
+                "end_at": req.end_at,
+                "timezone": timezone_name,
+                "capacity": req.capacity,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        let row = repo::find_by_id(&st.db, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        Ok(to_res(&row, None, true))
+    }
+
+    /// 회차 목록 — manager 이상은 관리 회차, 학습자는 수강 중인 코스의 회차
+    pub async fn list(
+        st: &AppState,
+        actor_user_id: i64,
+        query: LiveSessionListQuery,
+    ) -> AppResult<LiveSessionListRes> {
+        let ended_after = if query.include_past.unwrap_or(false) {
+            None
+        } else {
+            Some(Utc::now())
+        };
+        let auth = actor_auth(st, actor_user_id).await?;
+        let (rows, show_url) = match auth {
+            UserAuth::Hymn | UserAuth::Admin => (
+                repo::find_managed(&st.db, None, query.course_id, ended_after).await?,
+                true,
+            ),
+            UserAuth::Manager => (
+                repo::find_managed(&st.db, Some(actor_user_id), query.course_id, ended_after)
+                    .await?,
+                true,
+            ),
+            UserAuth::Learner => (
+                repo::find_for_learner(&st.db, actor_user_id, query.course_id, ended_after).await?,
+                false,
+            ),
+        };
+        Ok(LiveSessionListRes {
+            items: with_my_enrollments(st, actor_user_id, rows, show_url).await?,
+        })
+    }
+
+    /// 내 신청 회차 (확정 + 대기, 종료 전)
+    pub async fn list_mine(st: &AppState, user_id: i64) -> AppResult<LiveSessionListRes> {
+        let rows = repo::find_enrolled_upcoming(&st.db, user_id, Utc::now()).await?;
+        Ok(LiveSessionListRes {
+            items: with_my_enrollments(st, user_id, rows, false).await?,
+        })
+    }
+
+    /// 회차 상세 — 관리자 또는 코스 수강생/신청자만 (그 외 404)
+    pub async fn get(
+        st: &AppState,
+        actor_user_id: i64,
+        live_session_id: i64,
+    ) -> AppResult<LiveSessionRes> {
+        let row = repo::find_by_id(&st.db, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let auth = actor_auth(st, actor_user_id).await?;
+        let manages = can_manage_session(auth, actor_user_id, &row);
+        let mine = repo::find_my_enrollments(&st.db, actor_user_id, &[live_session_id])
+            .await?
+            .pop();
+        if !manages
+            && mine.is_none()
+            && !repo::has_course_access(&st.db, actor_user_id, row.course_id).await?
+        {
+            return Err(AppError::NotFound);
+        }
+        Ok(to_res(&row, mine.as_ref(), manages))
+    }
+
+    /// 회차 수정 — 일정 변경 시 프로바이더 반영 + 리마인더 재발송, 증원 시 대기자 승격
+    pub async fn update(
+        st: &AppState,
+        actor_user_id: i64,
+        live_session_id: i64,
+        req: UpdateLiveSessionReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<LiveSessionRes> {
+        let actor_auth = check_manager_rbac(&st.db, actor_user_id).await?;
+        let before = load_managed(st, actor_user_id, live_session_id).await?;
+        if before.session_state == LiveSessionState::Cancelled {
+            return Err(AppError::Conflict("LIVE_409_CANCELLED".into()));
+        }
+        if let Some(teacher_user_id) = req.teacher_user_id {
+            if teacher_user_id != before.teacher_user_id {
+                if actor_auth == UserAuth::Manager {
+                    return Err(AppError::Forbidden(
+                        "LIVE_403_TEACHER_ASSIGN: managers can only schedule themselves".into(),
+                    ));
+                }
+                check_teacher(st, teacher_user_id).await?;
+            }
+        }
+
+        let start_at = req.start_at.unwrap_or(before.session_start_at);
+        let end_at = req.end_at.unwrap_or(before.session_end_at);
+        let rescheduled = start_at != before.session_start_at || end_at != before.session_end_at;
+        if rescheduled {
+            if before.session_start_at <= Utc::now() {
+                return Err(AppError::Conflict("LIVE_409_STARTED".into()));
+            }
+            validate_window(start_at, end_at)?;
+            if start_at <= Utc::now() {
+                return Err(AppError::BadRequest(
+                    "start_at must be in the future".into(),
+                ));
+            }
+        }
+        let timezone = req
+            .timezone
+            .as_deref()
+            .map(parse_timezone)
+            .transpose()?
+            .map(|tz| tz.name());
+
+        let mut tx = st.db.begin().await?;
+        let locked = repo::lock_session(&mut tx, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let enrolled = repo::count_enrolled(&mut tx, live_session_id).await?;
+        if let Some(capacity) = req.capacity {
+            if i64::from(capacity) < enrolled {
+                return Err(AppError::Conflict(format!(
+                    "LIVE_409_CAPACITY_BELOW_ENROLLED: {enrolled} learners already enrolled"
+                )));
+            }
+        }
+        repo::update_session(
+            &mut tx,
+            live_session_id,
+            &LiveSessionPatch {
+                teacher_user_id: req.teacher_user_id,
+                title: req.session_title.as_deref(),
+                description: req.session_description.as_deref(),
+                start_at: req.start_at,
+                end_at: req.end_at,
+                timezone,
+                capacity: req.capacity,
+            },
+        )
+        .await?;
+        let capacity = req.capacity.unwrap_or(locked.session_capacity);
+        let promoted =
+            repo::promote_waitlist(&mut tx, live_session_id, i64::from(capacity) - enrolled)
+                .await?;
+        if rescheduled {
+            repo::clear_reminders(&mut tx, live_session_id).await?;
+        }
+        tx.commit().await?;
+
+        let after = repo::find_by_id(&st.db, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if rescheduled || timezone.is_some() || req.session_title.is_some() {
+            st.live_meeting
+                .update_meeting(&after.meeting_external_id, &meeting_request(&after))
+                .await?;
+        }
+        notify_promoted(st, &after, &promoted).await;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "UPDATE_LIVE_SESSION",
+            "live_session",
+            Some(live_session_id),
+            &serde_json::json!({
+                "before": {
+                    "teacher_user_id": before.teacher_user_id,
+                    "start_at": before.session_start_at,
+                    "end_at": before.session_end_at,
+                    "timezone": before.session_timezone,
+                    "capacity": before.session_capacity,
+                },
+                "after": {
+                    "teacher_user_id": after.teacher_user_id,
+                    "start_at": after.session_start_at,
+                    "end_at": after.session_end_at,
+                    "timezone": after.session_timezone,
+                    "capacity": after.session_capacity,
+                },
+                "promoted_user_ids": promoted,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        Ok(to_res(&after, None, true))
+    }
+
+    /// 회차 취소 — 신청/출석 기록은 보존, 진도율 분모에서 제외
+    pub async fn cancel(
+        st: &AppState,
+        actor_user_id: i64,
+        live_session_id: i64,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        let row = load_managed(st, actor_user_id, live_session_id).await?;
+        if row.session_state == LiveSessionState::Cancelled {
+            return Err(AppError::Conflict("LIVE_409_CANCELLED".into()));
+        }
+        if row.session_end_at <= Utc::now() {
+            return Err(AppError::Conflict("LIVE_409_ENDED".into()));
+        }
+
+        let mut tx = st.db.begin().await?;
+        repo::cancel_session(&mut tx, live_session_id).await?;
+        crate::api::course::repo::recompute_progress_for_course(&mut *tx, row.course_id, None)
+            .await?;
+        tx.commit().await?;
+        // 취소로 남은 회차가 모두 출석 처리된 수강생은 100% 도달 가능
+        let mut conn = st.db.acquire().await?;
+        crate::api::certificate::service::issue_for_completed(&mut conn, None, Some(row.course_id))
+            .await?;
+
+        if let Err(e) = st
+            .live_meeting
+            .cancel_meeting(&row.meeting_external_id)
+            .await
+        {
+            tracing::warn!(error = %e, live_session_id, "live meeting cancel failed");
+        }
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "CANCEL_LIVE_SESSION",
+            "live_session",
+            Some(live_session_id),
+            &serde_json::json!({
+                "course_id": row.course_id,
+                "start_at": row.session_start_at,
+                "enrolled_count": row.enrolled_count,
+                "waitlist_count": row.waitlist_count,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// 수강 신청 — 코스 수강권 필요, 정원 초과 시 대기. 이미 신청했으면 현재 상태 반환
+    pub async fn enroll(
+        st: &AppState,
+        user_id: i64,
+        live_session_id: i64,
+    ) -> AppResult<LiveEnrollmentRes> {
+        let row = repo::find_by_id(&st.db, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if !repo::has_course_access(&st.db, user_id, row.course_id).await? {
+            return Err(AppError::Forbidden(
+                "LIVE_403_COURSE_ACCESS: an active course enrollment is required".into(),
+            ));
+        }
+
+        let mut tx = st.db.begin().await?;
+        let locked = repo::lock_session(&mut tx, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if locked.session_state == LiveSessionState::Cancelled {
+            return Err(AppError::Conflict("LIVE_409_CANCELLED".into()));
+        }
+        if locked.session_start_at <= Utc::now() {
+            return Err(AppError::Conflict("LIVE_409_STARTED".into()));
+        }
+
+        let state = match repo::find_enrollment_state(&mut tx, live_session_id, user_id).await? {
+            Some(s @ (LiveEnrollmentState::Enrolled | LiveEnrollmentState::Waitlisted)) => s,
+            _ => {
+                let enrolled = repo::count_enrolled(&mut tx, live_session_id).await?;
+                let s = enrollment_state_for(locked.session_capacity, enrolled);
+                repo::upsert_enrollment(&mut tx, live_session_id, user_id, s).await?;
+                s
+            }
+        };
+        let waitlist_position = repo::waitlist_position(&mut tx, live_session_id, user_id).await?;
+        tx.commit().await?;
+
+        Ok(LiveEnrollmentRes {
+            live_session_id,
+            enrollment_state: state,
+            waitlist_position,
+            meeting_url: (state == LiveEnrollmentState::Enrolled).then_some(row.meeting_url),
+        })
+    }
+
+    /// 신청 취소 (시작 전) — 확정 자리가 비면 대기 1순위 승격
+    pub async fn cancel_enrollment(
+        st: &AppState,
+        user_id: i64,
+        live_session_id: i64,
+    ) -> AppResult<LiveEnrollmentRes> {
+        let mut tx = st.db.begin().await?;
+        let locked = repo::lock_session(&mut tx, live_session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let state = repo::find_enrollment_state(&mut tx, live_session_id, user_id)
+            .await?
+            .filter(|s| *s != LiveEnrollmentState::Cancelled)
+            .ok_or(AppError::NotFound)?;
+        if locked.session_start_at <= Utc::now() {
+            return Err(AppError::Conflict("LIVE_409_STARTED".into()));
+        }
+
+        repo::cancel_enrollment(&mut tx, live_session_id, user_id).await?;
+        let promoted = if state == LiveEnrollmentState::Enrolled
+            && locked.session_state == LiveSessionState::Scheduled
+        {
+            let enrolled = repo::count_enrolled(&mut tx, live_session_id).await?;
+            repo::promote_waitlist(
+                &mut tx,
+                live_session_id,
+                i64::from(locked.session_capacity) - enrolled,
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
+        tx.commit().await?;
+
+        if !promoted.is_empty() {
+            if let Some(row) = repo::find_by_id(&st.db, live_session_id).await? {
+                notify_promoted(st, &row, &promoted).await;
+            }
+        }
+
+        Ok(LiveEnrollmentRes {
+            live_session_id,
+            enrollment_state: LiveEnrollmentState::Cancelled,
+            waitlist_position: None,
+            meeting_url: None,
+        })
+    }
+
+    /// 명단 + 출석 (관리자)
+    pub async fn roster(
+        st: &AppState,
+        actor_user_id: i64,
+        live_session_id: i64,
+    ) -> AppResult<LiveRosterRes> {
+        let row = load_managed(st, actor_user_id, live_session_id).await?;
+        let learners = repo::find_roster(&st.db, live_session_id)
+            .await?
+            .into_iter()
+            .map(|r| LiveRosterEntry {
+                user_id: r.user_id,
+                nickname: r.nickname,
+                enrollment_state: r.enrollment_state,
+                enrolled_at: r.enrollment_created_at,
+                waitlist_position: r.waitlist_position,
+                attended: r.attended,
+                attendance_recorded_at: r.recorded_at,
+            })
+            .collect();
+        Ok(LiveRosterRes {
+            session: to_res(&row, None, true),
+            learners,
+        })
+    }
+
+    /// 출석 기록 (시작 후, 확정 학습자만) — 코스 진도율 재계산 + 100% 도달 시 수료증 발급
+    pub async fn record_attendance(
+        st: &AppState,
+        actor_user_id: i64,
+        live_session_id: i64,
+        req: RecordLiveAttendanceReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<LiveRosterRes> {
+        let row = load_managed(st, actor_user_id, live_session_id).await?;
+        if row.session_state == LiveSessionState::Cancelled {
+            return Err(AppError::Conflict("LIVE_409_CANCELLED".into()));
+        }
+        if row.session_start_at > Utc::now() {
+            return Err(AppError::Conflict("LIVE_409_NOT_STARTED".into()));
+        }
+
+        let mut user_ids: Vec<i64> = req.records.iter().map(|r| r.user_id).collect();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+        if user_ids.len() != req.records.len() {
+            return Err(AppError::BadRequest("Duplicate user_id in records".into()));
+        }
+
+        let mut tx = st.db.begin().await?;
+        let enrolled = repo::find_enrolled_among(&mut tx, live_session_id, &user_ids).await?;
+        if let Some(missing) = user_ids.iter().find(|id| !enrolled.contains(id)) {
+            return Err(AppError::BadRequest(format!(
+                "User {missing} is not enrolled in this session"
+            )));
+        }
+        for r in &req.records {
+            repo::upsert_attendance(
+                &mut tx,
+                live_session_id,
+                r.user_id,
+                r.attended,
+                actor_user_id,
+            )
+            .await?;
+            crate::api::course::repo::recompute_progress_for_course(
+                &mut *tx,
+                row.course_id,
+                Some(r.user_id),
+            )
+            .await?;
+        }
+        for user_id in &user_ids {
+            crate::api::certificate::service::issue_for_completed(
+                &mut tx,
+                Some(*user_id),
+                Some(row.course_id),
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "RECORD_LIVE_ATTENDANCE",
+            "live_session",
+            Some(live_session_id),
+            &serde_json::json!({
+                "attended": req.records.iter().filter(|r| r.attended).map(|r| r.user_id).collect::<Vec<_>>(),
+                "absent": req.records.iter().filter(|r| !r.attended).map(|r| r.user_id).collect::<Vec<_>>(),
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        Self::roster(st, actor_user_id, live_session_id).await
+    }
+
+    /// 내 라이브 수업 캘린더 (ICS)
+    pub async fn calendar_ics(st: &AppState, user_id: i64) -> AppResult<String> {
+        let now = Utc::now();
+        let rows =
+            repo::find_calendar_sessions(&st.db, user_id, now - Duration::days(CALENDAR_PAST_DAYS))
+                .await?;
+        let events: Vec<IcsEvent> = rows
+            .into_iter()
+            .map(|r| IcsEvent {
+                uid: format!("live-session-{}@amazingkorean", r.live_session_id),
+                summary: r.session_title,
+                description: Some(match r.session_description {
+                    Some(d) => format!("{}\n\n{}", r.course_title, d),
+                    None => r.course_title,
+                }),
+                start_at: r.session_start_at,
+                end_at: r.session_end_at,
+                cancelled: r.session_state == LiveSessionState::Cancelled,
+                url: (r.session_state == LiveSessionState::Scheduled).then_some(r.meeting_url),
+                updated_at: r.session_updated_at,
+            })
+            .collect();
+        Ok(render_calendar("Amazing Korean Live", &events, now))
+    }
+
+    /// 캘린더 구독 토큰 발급 (재발급 시 이전 URL 무효화)
+    pub async fn issue_calendar_feed(
+        st: &AppState,
+        user_id: i64,
+    ) -> AppResult<LiveCalendarFeedRes> {
+        let token = generate_feed_token();
+        let created_at =
+            repo::upsert_calendar_feed(&st.db, user_id, &hash_feed_token(&token)).await?;
+        Ok(LiveCalendarFeedRes {
+            feed_url: feed_path(&token),
+            created_at,
+        })
+    }
+
+    pub async fn revoke_calendar_feed(st: &AppState, user_id: i64) -> AppResult<()> {
+        if !repo::delete_calendar_feed(&st.db, user_id).await? {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    }
+
+    /// 구독 토큰으로 캘린더 조회 (캘린더 앱용, 인증 헤더 없음)
+    pub async fn calendar_by_token(st: &AppState, token: &str) -> AppResult<String> {
+        let user_id = repo::find_calendar_feed_user(&st.db, &hash_feed_token(token))
+            .await?
+            .ok_or(AppError::NotFound)?;
+        Self::calendar_ics(st, user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn timezone_accepts_iana_names_only() {
+        assert_eq!(parse_timezone("Asia/Seoul").unwrap(), Tz::Asia__Seoul);
+        assert_eq!(
+            parse_timezone(" America/New_York ").unwrap(),
+            Tz::America__New_York
+        );
+        assert!(parse_timezone("KST").is_err());
+        assert!(parse_timezone("Mars/Olympus").is_err());
+    }
+
+    #[test]
+    fn local_time_follows_session_timezone_and_dst() {
+        let t = Utc.with_ymd_and_hms(2026, 11, 2, 10, 0, 0).unwrap();
+        assert_eq!(local_display(t, Tz::Asia__Seoul), "2026-11-02 19:00 KST");
+        assert_eq!(
+            local_rfc3339(t, Tz::Asia__Seoul),
+            "2026-11-02T19:00:00+09:00"
+        );
+        // 2026-11-01 에 미국 서머타임 종료 → EST(-05:00)
+        assert_eq!(
+            local_display(t, Tz::America__New_York),
+            "2026-11-02 05:00 EST"
+        );
+        let summer = Utc.with_ymd_and_hms(2026, 7, 1, 10, 0, 0).unwrap();
+        assert_eq!(
+            local_rfc3339(summer, Tz::America__New_York),
+            "2026-07-01T06:00:00-04:00"
+        );
+    }
+
+    #[test]
+    fn window_must_be_ordered_and_bounded() {
+        let start = Utc.with_ymd_and_hms(2026, 11, 2, 10, 0, 0).unwrap();
+        assert!(validate_window(start, start + Duration::hours(1)).is_ok());
+        assert!(validate_window(start, start).is_err());
+        assert!(validate_window(start, start - Duration::minutes(1)).is_err());
+        assert!(validate_window(start, start + Duration::hours(MAX_SESSION_HOURS + 1)).is_err());
+    }
+
+    #[test]
+    fn capacity_decides_enrolled_or_waitlisted() {
+        assert_eq!(enrollment_state_for(2, 0), LiveEnrollmentState::Enrolled);
+        assert_eq!(enrollment_state_for(2, 1), LiveEnrollmentState::Enrolled);
+        assert_eq!(enrollment_state_for(2, 2), LiveEnrollmentState::Waitlisted);
+        assert_eq!(enrollment_state_for(2, 3), LiveEnrollmentState::Waitlisted);
+    }
+
+    #[test]
+    fn feed_token_hash_is_stable_and_distinct() {
+        let a = generate_feed_token();
+        let b = generate_feed_token();
+        assert_ne!(a, b);
+        assert_eq!(hash_feed_token(&a), hash_feed_token(&format!(" {a} ")));
+        assert_ne!(hash_feed_token(&a), hash_feed_token(&b));
+        assert_eq!(feed_path("tok"), "/live-sessions/calendar/tok");
+    }
+}
@@ -14,12 +14,18 @@ use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 pub mod admin;
+pub mod assignment;
 pub mod auth;
+pub mod certificate;
+pub mod classroom;
 pub mod course;
 pub mod ebook;
+pub mod gradebook;
 pub mod guide;
 pub mod health;
 pub mod lesson;
+pub mod live;
+pub mod org;
 pub mod payment;
 pub mod study;
 pub mod textbook;
@@ -30,11 +36,17 @@ pub mod video;
 use self::admin::ip_guard::admin_ip_guard;
 use self::admin::role_guard::admin_role_guard;
 use self::admin::router::admin_router;
+use self::assignment::router::assignment_router;
 use self::auth::router::auth_router;
+use self::certificate::router::certificate_router;
+use self::classroom::router::classroom_router;
 use self::course::router::course_router;
 use self::ebook::router::ebook_router;
+use self::gradebook::router::gradebook_router;
 use self::guide::router::router as guide_router;
 use self::lesson::router::router as lesson_router;
+use self::live::router::live_router;
+use self::org::router::org_router;
 use self::payment::router::payment_router;
 use self::study::router::router as study_router;
 use self::textbook::router::textbook_router;
@@ -44,6 +56,10 @@ use self::video::router::router as video_router;
 pub fn app_router(state: AppState) -> axum::Router {
     let router = axum::Router::new()
         .merge(course_router())
+        .merge(certificate_router())
+        .merge(assignment_router())
+        .merge(gradebook_router())
+        .merge(live_router())
         .merge(user_router())
         .nest("/auth", auth_router())
         // Admin 라우트에 IP allowlist + Role Guard 미들웨어 적용
@@ -60,6 +76,8 @@ pub fn app_router(state: AppState) -> axum::Router {
                     admin_ip_guard,
                 )),
         )
+        .nest("/classes", classroom_router())
+        .nest("/orgs", org_router())
         .nest("/lessons", lesson_router())
         .nest("/videos", video_router())
         .nest("/studies", study_router())
@@ -70,6 +88,8 @@ pub fn app_router(state: AppState) -> axum::Router {
         .route("/healthz", get(health::handler::health))
         .route("/health", get(health::handler::health))
         .route("/ready", get(health::handler::ready))
+        // JWT 서명 공개키 (JWKS) — 토큰 검증하는 다른 서비스가 비밀 공유 없이 검증
+        .route("/.well-known/jwks.json", get(auth::handler::jwks))
         // SEO: api 서브도메인 루트 + robots.txt — Google Search Console 의
         // "찾을 수 없음(404)" + "Soft 404" 카테고리 회피용.
         // api.amazingkorean.net 은 Google OAuth redirect_uri 로 불가피하게
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::types::{OrgLicenseScope, OrgSeatState, OrgSsoProtocol};
+
+// =============================================================================
+// 기관 / 라이선스
+// =============================================================================
+
+/// 기관
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct OrgRes {
+    pub org_id: i64,
+    pub org_name: String,
+    pub org_created_at: DateTime<Utc>,
+    pub org_updated_at: DateTime<Utc>,
+}
+
+/// 기관 관리자
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct OrgAdminRes {
+    pub user_id: i64,
+    pub nickname: String,
+    pub org_admin_added_at: DateTime<Utc>,
+}
+
+/// 좌석 라이선스 + 사용 현황
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct OrgLicenseRes {
+    pub license_id: i64,
+    pub org_id: i64,
+    pub license_scope: OrgLicenseScope,
+    /// scope=course 일 때만
+    pub course_id: Option<i32>,
+    pub course_title: Option<String>,
+    pub license_seat_count: i32,
+    pub license_valid_from: DateTime<Utc>,
+    pub license_valid_until: DateTime<Utc>,
+    /// 연결된 교재 일괄 주문 (선택)
+    pub textbook_order_id: Option<i64>,
+    pub license_note: Option<String>,
+    /// 수락 완료 좌석
+    pub seats_active: i64,
+    /// 수락 대기 중인 (미만료) 초대
+    pub seats_invited: i64,
+    /// 추가 초대 가능 좌석
+    pub seats_available: i64,
+    pub license_created_at: DateTime<Utc>,
+}
+
+/// 기관 상세 (라이선스 + 관리자)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgDetailRes {
+    pub org: OrgRes,
+    pub licenses: Vec<OrgLicenseRes>,
+    pub admins: Vec<OrgAdminRes>,
+}
+
+/// 내가 관리하는 기관 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MyOrgListRes {
+    pub items: Vec<OrgRes>,
+}
+
+// =============================================================================
+// 좌석
+// =============================================================================
+
+/// 좌석 이메일 일괄 초대 (라이선스 잔여 좌석 안에서 전부 또는 전무)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct InviteOrgSeatsReq {
+    #[validate(length(min = 1, max = 50))]
+    pub emails: Vec<String>,
+}
+
+/// 좌석 1개
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgSeatRes {
+    pub seat_id: i64,
+    pub license_id: i64,
+    pub email: String,
+    pub seat_state: OrgSeatState,
+    /// 수락한 사용자 (수락 전이면 None)
+    pub user_id: Option<i64>,
+    pub nickname: Option<String>,
+    pub seat_invited_at: DateTime<Utc>,
+    pub seat_invite_expires_at: DateTime<Utc>,
+    pub seat_accepted_at: Option<DateTime<Utc>>,
+    pub seat_revoked_at: Option<DateTime<Utc>>,
+}
+
+/// 라이선스 좌석 목록
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgSeatListRes {
+    pub license_id: i64,
+    pub items: Vec<OrgSeatRes>,
+}
+
+/// 초대하지 않은 이메일과 사유
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SkippedSeatInvite {
+    pub email: String,
+    /// already_seated: 이 라이선스에 이미 살아있는 좌석이 있음
+    pub reason: String,
+}
+
+/// 일괄 초대 결과
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InviteOrgSeatsRes {
+    pub license_id: i64,
+    pub invited: Vec<OrgSeatRes>,
+    pub skipped: Vec<SkippedSeatInvite>,
+}
+
+/// 좌석 초대 수락 (초대 메일 링크의 토큰)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AcceptOrgSeatReq {
+    #[validate(length(min = 1, max = 128))]
+    pub token: String,
+}
+
+/// 좌석 수락 결과
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgSeatAcceptRes {
+    pub seat_id: i64,
+    pub org_id: i64,
+    pub org_name: String,
+    pub license_scope: OrgLicenseScope,
+    pub course_id: Option<i32>,
+    pub license_valid_until: DateTime<Utc>,
+    /// 이번 수락으로 부여/연장된 코스 수강권 수 (더 긴 기존 수강권은 유지)
+    pub courses_granted: u64,
+}
+
+// =============================================================================
+// 대시보드
+// =============================================================================
+
+/// 기관 전체 좌석 합계
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgSeatTotals {
+    pub seat_count: i64,
+    pub seats_active: i64,
+    pub seats_invited: i64,
+    pub seats_available: i64,
+    /// 현재 유효기간 안의 라이선스 좌석 중 사용 중(active) 비율 (%)
+    pub utilization_percent: i32,
+}
+
+/// 좌석 학습자 1명의 학습 현황
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgLearnerActivity {
+    pub seat_id: i64,
+    pub license_id: i64,
+    pub user_id: i64,
+    pub nickname: String,
+    /// 좌석 초대 이메일
+    pub email: String,
+    pub seat_accepted_at: Option<DateTime<Utc>>,
+    /// 좌석으로 부여된 코스들의 평균 진도율 (%)
+    pub course_progress_avg: i32,
+    pub courses_completed: i64,
+    pub lessons_completed: i64,
+    pub videos_completed: i64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+/// 기관 대시보드 — 좌석 사용률 + 학습자 활동
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgDashboardRes {
+    pub org_id: i64,
+    pub org_name: String,
+    pub totals: OrgSeatTotals,
+    pub licenses: Vec<OrgLicenseRes>,
+    /// 최근 7일 안에 학습 활동이 있는 좌석 학습자 수
+    pub active_learners_7d: i64,
+    pub learners: Vec<OrgLearnerActivity>,
+}
+
+// =============================================================================
+// SSO
+// =============================================================================
+
+/// 기관 SSO 설정 (client secret / 메타데이터 원문은 응답에 포함하지 않음)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrgSsoRes {
+    pub org_id: i64,
+    pub protocol: OrgSsoProtocol,
+    pub enabled: bool,
+    /// SSO 전용 — 이 도메인 계정의 다른 로그인 방식과 일반 가입을 막음
+    pub enforced: bool,
+    /// 처음 로그인하는 IdP 사용자를 자동 가입 (JIT)
+    pub jit_enabled: bool,
+    /// 로그인 discovery 에 쓰는 이메일 도메인
+    pub domains: Vec<String>,
+    pub oidc_issuer: Option<String>,
+    pub oidc_client_id: Option<String>,
+    pub oidc_client_secret_set: bool,
+    /// SAML IdP entityID (메타데이터에서 추출)
+    pub saml_idp_entity_id: Option<String>,
+    /// users 필드 → IdP 속성/클레임 이름 (지정하지 않은 필드는 표준 이름)
+    pub attribute_map: BTreeMap<String, String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// SSO 전용(enforce) 켜기/끄기 — 기관 관리자
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateOrgSsoEnforcementReq {
+    pub enforced: bool,
+}
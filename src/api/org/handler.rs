@@ -0,0 +1,270 @@
+use super::{
+    dto::{
+        AcceptOrgSeatReq, InviteOrgSeatsReq, InviteOrgSeatsRes, MyOrgListRes, OrgDashboardRes,
+        OrgDetailRes, OrgSeatAcceptRes, OrgSeatListRes, OrgSsoRes, UpdateOrgSsoEnforcementReq,
+    },
+    service::OrgService,
+};
+use crate::api::admin::header_utils::{extract_client_ip, extract_user_agent};
+use crate::extract::AppJson;
+use crate::{
+    api::auth::extractor::AuthUser,
+    error::{AppError, AppResult},
+    state::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use validator::Validate;
+
+// =============================================================================
+// 기관 관리자
+// =============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/orgs/me",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "Organizations I administer", body = MyOrgListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn my_orgs(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+) -> AppResult<Json<MyOrgListRes>> {
+    let res = OrgService::list_mine(&st, claims.sub).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/orgs/{org_id}",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(
+        ("org_id" = i64, Path, description = "Organization ID")
+    ),
+    responses(
+        (status = 200, description = "Organization with licenses (seat usage) and admins", body = OrgDetailRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization not found or not administered by caller", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_org(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(org_id): Path<i64>,
+) -> AppResult<Json<OrgDetailRes>> {
+    let res = OrgService::get(&st, claims.sub, org_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/orgs/{org_id}/dashboard",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(
+        ("org_id" = i64, Path, description = "Organization ID")
+    ),
+    responses(
+        (status = 200, description = "Seat utilization and learner activity", body = OrgDashboardRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization not found or not administered by caller", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn org_dashboard(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(org_id): Path<i64>,
+) -> AppResult<Json<OrgDashboardRes>> {
+    let res = OrgService::dashboard(&st, claims.sub, org_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    get,
+    path = "/orgs/{org_id}/licenses/{license_id}/seats",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(
+        ("org_id" = i64, Path, description = "Organization ID"),
+        ("license_id" = i64, Path, description = "License ID")
+    ),
+    responses(
+        (status = 200, description = "Seats of the license", body = OrgSeatListRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization or license not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn list_seats(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path((org_id, license_id)): Path<(i64, i64)>,
+) -> AppResult<Json<OrgSeatListRes>> {
+    let res = OrgService::list_seats(&st, claims.sub, org_id, license_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    post,
+    path = "/orgs/{org_id}/licenses/{license_id}/seats",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(
+        ("org_id" = i64, Path, description = "Organization ID"),
+        ("license_id" = i64, Path, description = "License ID")
+    ),
+    request_body = InviteOrgSeatsReq,
+    responses(
+        (status = 201, description = "Seat invites sent (emails already seated on the license are skipped)", body = InviteOrgSeatsRes),
+        (status = 400, description = "Validation error", body = crate::error::ErrorBody),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization or license not found", body = crate::error::ErrorBody),
+        (status = 409, description = "License not currently valid or not enough free seats", body = crate::error::ErrorBody),
+        (status = 503, description = "Email service not configured", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn invite_seats(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path((org_id, license_id)): Path<(i64, i64)>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<InviteOrgSeatsReq>,
+) -> AppResult<(StatusCode, Json<InviteOrgSeatsRes>)> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = OrgService::invite_seats(
+        &st,
+        claims.sub,
+        org_id,
+        license_id,
+        &req.emails,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(res)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/orgs/{org_id}/seats/{seat_id}",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(
+        ("org_id" = i64, Path, description = "Organization ID"),
+        ("seat_id" = i64, Path, description = "Seat ID")
+    ),
+    responses(
+        (status = 204, description = "Seat revoked (seat-granted course access deactivated)"),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization or seat not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Seat already revoked", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn revoke_seat(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path((org_id, seat_id)): Path<(i64, i64)>,
+    headers: HeaderMap,
+) -> AppResult<StatusCode> {
+    OrgService::revoke_seat(
+        &st,
+        claims.sub,
+        org_id,
+        seat_id,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/orgs/{org_id}/sso",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    responses(
+        (status = 200, description = "Organization SSO configuration (secrets omitted)", body = OrgSsoRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization or SSO configuration not found", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn get_org_sso(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(org_id): Path<i64>,
+) -> AppResult<Json<OrgSsoRes>> {
+    let res = OrgService::get_sso(&st, claims.sub, org_id).await?;
+    Ok(Json(res))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/orgs/{org_id}/sso/enforcement",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    params(("org_id" = i64, Path, description = "Organization ID")),
+    request_body = UpdateOrgSsoEnforcementReq,
+    responses(
+        (status = 200, description = "SSO-only enforcement updated", body = OrgSsoRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 404, description = "Organization or SSO configuration not found", body = crate::error::ErrorBody),
+        (status = 409, description = "SSO is disabled for this organization", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn update_org_sso_enforcement(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    Path(org_id): Path<i64>,
+    headers: HeaderMap,
+    AppJson(req): AppJson<UpdateOrgSsoEnforcementReq>,
+) -> AppResult<Json<OrgSsoRes>> {
+    let res = OrgService::update_sso_enforcement(
+        &st,
+        claims.sub,
+        org_id,
+        req,
+        extract_client_ip(&headers),
+        extract_user_agent(&headers),
+    )
+    .await?;
+    Ok(Json(res))
+}
+
+// =============================================================================
+// 학습자
+// =============================================================================
+
+#[utoipa::path(
+    post,
+    path = "/orgs/seats/accept",
+    tag = "Organization",
+    security(("bearerAuth" = [])),
+    request_body = AcceptOrgSeatReq,
+    responses(
+        (status = 200, description = "Seat accepted and course access granted", body = OrgSeatAcceptRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Invite was sent to a different email", body = crate::error::ErrorBody),
+        (status = 404, description = "Invite not found", body = crate::error::ErrorBody),
+        (status = 409, description = "Seat already used/revoked, invite expired or license not currently valid", body = crate::error::ErrorBody)
+    )
+)]
+pub async fn accept_seat(
+    State(st): State<AppState>,
+    AuthUser(claims): AuthUser,
+    AppJson(req): AppJson<AcceptOrgSeatReq>,
+) -> AppResult<Json<OrgSeatAcceptRes>> {
+    req.validate()
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let res = OrgService::accept_seat(&st, claims.sub, &req.token).await?;
+    Ok(Json(res))
+}
@@ -0,0 +1,677 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+
+use super::dto::{OrgAdminRes, OrgLicenseRes, OrgRes};
+use crate::error::AppResult;
+use crate::types::{OrgLicenseScope, OrgSeatState, OrgSsoProtocol};
+
+/// 좌석 점유 조건 — 수락 완료 또는 만료 전 초대
+const SEAT_OCCUPIED: &str =
+    "(s.seat_state = 'active' OR (s.seat_state = 'invited' AND s.seat_invite_expires_at > NOW()))";
+
+// =============================================================================
+// 기관 / 관리자
+// =============================================================================
+
+pub async fn find_org(pool: &PgPool, org_id: i64) -> AppResult<Option<OrgRes>> {
+    let row = sqlx::query_as::<_, OrgRes>(
+        "SELECT org_id, org_name, org_created_at, org_updated_at FROM organization WHERE org_id = $1",
+    )
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+/// 사용자가 관리자로 등록된 기관 목록
+pub async fn find_orgs_administered_by(pool: &PgPool, user_id: i64) -> AppResult<Vec<OrgRes>> {
+    let rows = sqlx::query_as::<_, OrgRes>(
+        r#"
+        SELECT o.org_id, o.org_name, o.org_created_at, o.org_updated_at
+        FROM org_admin a
+        JOIN organization o ON o.org_id = a.org_id
+        WHERE a.user_id = $1
+        ORDER BY o.org_name, o.org_id
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn is_org_admin(pool: &PgPool, org_id: i64, user_id: i64) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS (SELECT 1 FROM org_admin WHERE org_id = $1 AND user_id = $2)",
+    )
+    .bind(org_id)
+    .bind(user_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
+
+pub async fn find_admins(pool: &PgPool, org_id: i64) -> AppResult<Vec<OrgAdminRes>> {
+    let rows = sqlx::query_as::<_, OrgAdminRes>(
+        r#"
+        SELECT a.user_id, u.user_nickname AS nickname, a.org_admin_added_at
+        FROM org_admin a
+        JOIN users u ON u.user_id = a.user_id
+        WHERE a.org_id = $1
+        ORDER BY a.org_admin_added_at, a.user_id
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// =============================================================================
+// 라이선스
+// =============================================================================
+
+const LICENSE_SELECT: &str = r#"
+    SELECT
+        l.license_id, l.org_id, l.license_scope, l.course_id, c.course_title,
+        l.license_seat_count, l.license_valid_from, l.license_valid_until,
+        l.textbook_order_id, l.license_note,
+        su.seats_active, su.seats_invited,
+        GREATEST(l.license_seat_count - su.seats_active - su.seats_invited, 0)::bigint AS seats_available,
+        l.license_created_at
+    FROM org_license l
+    LEFT JOIN course c ON c.course_id = l.course_id
+    CROSS JOIN LATERAL (
+        SELECT COUNT(*) FILTER (WHERE s.seat_state = 'active') AS seats_active,
+               COUNT(*) FILTER (WHERE s.seat_state = 'invited'
+                                AND s.seat_invite_expires_at > NOW()) AS seats_invited
+        FROM org_seat s WHERE s.license_id = l.license_id
+    ) su
+"#;
+
+pub async fn find_licenses(pool: &PgPool, org_id: i64) -> AppResult<Vec<OrgLicenseRes>> {
+    let sql = format!(
+        "{LICENSE_SELECT} WHERE l.org_id = $1 ORDER BY l.license_valid_until DESC, l.license_id DESC"
+    );
+    let rows = sqlx::query_as::<_, OrgLicenseRes>(&sql)
+        .bind(org_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_license(
+    pool: &PgPool,
+    org_id: i64,
+    license_id: i64,
+) -> AppResult<Option<OrgLicenseRes>> {
+    let sql = format!("{LICENSE_SELECT} WHERE l.org_id = $1 AND l.license_id = $2");
+    let row = sqlx::query_as::<_, OrgLicenseRes>(&sql)
+        .bind(org_id)
+        .bind(license_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 초대/좌석 수 변경용 라이선스 행 잠금
+#[derive(Debug, FromRow)]
+pub struct LicenseLockRow {
+    pub license_id: i64,
+    pub org_id: i64,
+    pub org_name: String,
+    pub license_scope: OrgLicenseScope,
+    pub course_id: Option<i32>,
+    pub course_title: Option<String>,
+    pub license_seat_count: i32,
+    pub license_valid_from: DateTime<Utc>,
+    pub license_valid_until: DateTime<Utc>,
+}
+
+pub async fn lock_license(
+    conn: &mut PgConnection,
+    org_id: i64,
+    license_id: i64,
+) -> AppResult<Option<LicenseLockRow>> {
+    let row = sqlx::query_as::<_, LicenseLockRow>(
+        r#"
+        SELECT l.license_id, l.org_id, o.org_name, l.license_scope, l.course_id, c.course_title,
+               l.license_seat_count, l.license_valid_from, l.license_valid_until
+        FROM org_license l
+        JOIN organization o ON o.org_id = l.org_id
+        LEFT JOIN course c ON c.course_id = l.course_id
+        WHERE l.org_id = $1 AND l.license_id = $2
+        FOR UPDATE OF l
+        "#,
+    )
+    .bind(org_id)
+    .bind(license_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    Ok(row)
+}
+
+/// 점유 좌석 수 (active + 만료 전 초대)
+pub async fn count_occupied_seats(conn: &mut PgConnection, license_id: i64) -> AppResult<i64> {
+    let sql =
+        format!("SELECT COUNT(*) FROM org_seat s WHERE s.license_id = $1 AND {SEAT_OCCUPIED}");
+    let n = sqlx::query_scalar::<_, i64>(&sql)
+        .bind(license_id)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(n)
+}
+
+/// 좌석으로 부여된 (살아있는) 수강권 만료일을 라이선스 종료일에 맞춤
+pub async fn sync_seat_course_expiry(
+    pool: &PgPool,
+    license_id: i64,
+    valid_until: DateTime<Utc>,
+) -> AppResult<u64> {
+    let res = sqlx::query(
+        r#"
+        UPDATE users_course uc
+        SET user_course_expire_at  = $2,
+            user_course_updated_at = NOW()
+        FROM org_seat s
+        WHERE uc.user_course_org_seat_id = s.seat_id
+          AND s.license_id = $1
+          AND s.seat_state = 'active'
+          AND uc.user_course_active = true
+        "#,
+    )
+    .bind(license_id)
+    .bind(valid_until)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+// =============================================================================
+// 좌석
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct SeatRow {
+    pub seat_id: i64,
+    pub license_id: i64,
+    pub seat_state: OrgSeatState,
+    pub seat_email: String,
+    pub user_id: Option<i64>,
+    pub nickname: Option<String>,
+    pub seat_invited_at: DateTime<Utc>,
+    pub seat_invite_expires_at: DateTime<Utc>,
+    pub seat_accepted_at: Option<DateTime<Utc>>,
+    pub seat_revoked_at: Option<DateTime<Utc>>,
+}
+
+const SEAT_SELECT: &str = r#"
+    SELECT s.seat_id, s.license_id, s.seat_state, s.seat_email, s.user_id,
+           u.user_nickname AS nickname,
+           s.seat_invited_at, s.seat_invite_expires_at, s.seat_accepted_at, s.seat_revoked_at
+    FROM org_seat s
+    LEFT JOIN users u ON u.user_id = s.user_id
+"#;
+
+/// 같은 라이선스에 같은 이메일의 살아있는 (회수되지 않은) 좌석이 있는지
+pub async fn has_live_seat(
+    conn: &mut PgConnection,
+    license_id: i64,
+    email_idx: &str,
+) -> AppResult<bool> {
+    let exists = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM org_seat
+            WHERE license_id = $1 AND seat_email_idx = $2 AND seat_state <> 'revoked'
+        )
+        "#,
+    )
+    .bind(license_id)
+    .bind(email_idx)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(exists)
+}
+
+/// 만료된 초대를 정리해 같은 이메일 재초대를 허용 (좌석 점유도 이미 해제된 상태)
+pub async fn revoke_expired_invite(
+    conn: &mut PgConnection,
+    license_id: i64,
+    email_idx: &str,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE org_seat
+        SET seat_state = 'revoked', seat_revoked_at = NOW()
+        WHERE license_id = $1 AND seat_email_idx = $2
+          AND seat_state = 'invited' AND seat_invite_expires_at <= NOW()
+        "#,
+    )
+    .bind(license_id)
+    .bind(email_idx)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub struct NewSeat<'a> {
+    pub license_id: i64,
+    pub email_enc: &'a str,
+    pub email_idx: &'a str,
+    pub token_hash: &'a str,
+    pub invited_by_user_id: i64,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub async fn insert_seat(conn: &mut PgConnection, seat: &NewSeat<'_>) -> AppResult<SeatRow> {
+    let row = sqlx::query_as::<_, SeatRow>(
+        r#"
+        INSERT INTO org_seat
+            (license_id, seat_email, seat_email_idx, seat_token_hash,
+             seat_invited_by_user_id, seat_invite_expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING seat_id, license_id, seat_state, seat_email, user_id,
+                  NULL::text AS nickname,
+                  seat_invited_at, seat_invite_expires_at, seat_accepted_at, seat_revoked_at
+        "#,
+    )
+    .bind(seat.license_id)
+    .bind(seat.email_enc)
+    .bind(seat.email_idx)
+    .bind(seat.token_hash)
+    .bind(seat.invited_by_user_id)
+    .bind(seat.expires_at)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(row)
+}
+
+pub async fn find_seats(pool: &PgPool, license_id: i64) -> AppResult<Vec<SeatRow>> {
+    let sql = format!(
+        "{SEAT_SELECT} WHERE s.license_id = $1 ORDER BY s.seat_invited_at DESC, s.seat_id DESC"
+    );
+    let rows = sqlx::query_as::<_, SeatRow>(&sql)
+        .bind(license_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 기관 소속 좌석 1개 (다른 기관 좌석은 None)
+pub async fn find_seat_in_org(
+    conn: &mut PgConnection,
+    org_id: i64,
+    seat_id: i64,
+) -> AppResult<Option<SeatRow>> {
+    let sql = format!(
+        r#"{SEAT_SELECT}
+        JOIN org_license l ON l.license_id = s.license_id
+        WHERE l.org_id = $1 AND s.seat_id = $2
+        FOR UPDATE OF s"#
+    );
+    let row = sqlx::query_as::<_, SeatRow>(&sql)
+        .bind(org_id)
+        .bind(seat_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    Ok(row)
+}
+
+/// 수락 대상 좌석 + 라이선스
+#[derive(Debug, FromRow)]
+pub struct SeatAcceptRow {
+    pub seat_id: i64,
+    pub seat_state: OrgSeatState,
+    pub seat_email_idx: String,
+    pub seat_invite_expires_at: DateTime<Utc>,
+    pub license_id: i64,
+    pub org_id: i64,
+    pub org_name: String,
+    pub license_scope: OrgLicenseScope,
+    pub course_id: Option<i32>,
+    pub license_valid_from: DateTime<Utc>,
+    pub license_valid_until: DateTime<Utc>,
+}
+
+/// 토큰 해시로 좌석 조회 (수락 경합 방지를 위해 행 잠금)
+pub async fn find_seat_by_token_hash_for_update(
+    conn: &mut PgConnection,
+    token_hash: &str,
+) -> AppResult<Option<SeatAcceptRow>> {
+    let row = sqlx::query_as::<_, SeatAcceptRow>(
+        r#"
+        SELECT s.seat_id, s.seat_state, s.seat_email_idx, s.seat_invite_expires_at,
+               l.license_id, l.org_id, o.org_name, l.license_scope, l.course_id,
+               l.license_valid_from, l.license_valid_until
+        FROM org_seat s
+        JOIN org_license l ON l.license_id = s.license_id
+        JOIN organization o ON o.org_id = l.org_id
+        WHERE s.seat_token_hash = $1
+        FOR UPDATE OF s
+        "#,
+    )
+    .bind(token_hash)
+    .fetch_optional(&mut *conn)
+    .await?;
+    Ok(row)
+}
+
+pub async fn mark_seat_accepted(
+    conn: &mut PgConnection,
+    seat_id: i64,
+    user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE org_seat
+        SET seat_state = 'active', user_id = $2, seat_accepted_at = NOW()
+        WHERE seat_id = $1
+        "#,
+    )
+    .bind(seat_id)
+    .bind(user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub async fn mark_seat_revoked(
+    conn: &mut PgConnection,
+    seat_id: i64,
+    revoked_by_user_id: i64,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE org_seat
+        SET seat_state = 'revoked', seat_revoked_at = NOW(), seat_revoked_by_user_id = $2
+        WHERE seat_id = $1
+        "#,
+    )
+    .bind(seat_id)
+    .bind(revoked_by_user_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+// =============================================================================
+// 좌석 수강권 (users_course)
+// =============================================================================
+
+/// 좌석 수강권 부여 — course_id 가 None 이면 (구독 범위) 전체 active 코스.
+/// 만료일은 라이선스 종료일. 이미 그보다 긴 활성 수강권이 있는 코스는 건드리지 않는다.
+pub async fn grant_seat_courses(
+    conn: &mut PgConnection,
+    seat_id: i64,
+    user_id: i64,
+    course_id: Option<i32>,
+    valid_until: DateTime<Utc>,
+) -> AppResult<u64> {
+    let res = sqlx::query(
+        r#"
+        INSERT INTO users_course
+            (user_id, course_id, user_course_active, user_course_expire_at, user_course_org_seat_id)
+        SELECT $1, c.course_id, true, $3, $2
+        FROM course c
+        WHERE ($4::int IS NULL AND c.course_state = 'active') OR c.course_id = $4
+        ON CONFLICT (user_id, course_id) DO UPDATE SET
+            user_course_active      = true,
+            user_course_expire_at   = EXCLUDED.user_course_expire_at,
+            user_course_org_seat_id = EXCLUDED.user_course_org_seat_id,
+            user_course_updated_at  = NOW()
+        WHERE NOT (
+            users_course.user_course_active
+            AND (users_course.user_course_expire_at IS NULL
+                 OR users_course.user_course_expire_at >= EXCLUDED.user_course_expire_at)
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(seat_id)
+    .bind(valid_until)
+    .bind(course_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+/// 좌석이 부여한 수강권 비활성화 (좌석 회수 시)
+pub async fn deactivate_seat_courses(conn: &mut PgConnection, seat_id: i64) -> AppResult<u64> {
+    let res = sqlx::query(
+        r#"
+        UPDATE users_course
+        SET user_course_active = false,
+            user_course_updated_at = NOW()
+        WHERE user_course_org_seat_id = $1 AND user_course_active = true
+        "#,
+    )
+    .bind(seat_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(res.rows_affected())
+}
+
+/// 현재 유효한 좌석 수강권 근거
+#[derive(Debug, FromRow)]
+pub struct SeatGrant {
+    pub seat_id: i64,
+    pub course_id: Option<i32>,
+    pub license_valid_until: DateTime<Utc>,
+}
+
+const SEAT_GRANT_SELECT: &str = r#"
+    SELECT s.seat_id, l.course_id, l.license_valid_until
+    FROM org_seat s
+    JOIN org_license l ON l.license_id = s.license_id
+    WHERE s.user_id = $1
+      AND s.seat_state = 'active'
+      AND l.license_valid_from <= NOW()
+      AND l.license_valid_until > NOW()
+"#;
+
+/// 사용자의 유효한 active 좌석 전체
+pub async fn find_active_seat_grants(pool: &PgPool, user_id: i64) -> AppResult<Vec<SeatGrant>> {
+    let sql = format!("{SEAT_GRANT_SELECT} ORDER BY l.license_valid_until");
+    let rows = sqlx::query_as::<_, SeatGrant>(&sql)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 코스 수강 근거가 되는 좌석 (코스 라이선스 또는 구독 라이선스) — 종료일이 가장 늦은 것
+pub async fn find_seat_grant_for_course(
+    pool: &PgPool,
+    user_id: i64,
+    course_id: i64,
+) -> AppResult<Option<SeatGrant>> {
+    let sql = format!(
+        "{SEAT_GRANT_SELECT} AND (l.license_scope = 'subscription' OR l.course_id = $2)
+         ORDER BY l.license_valid_until DESC LIMIT 1"
+    );
+    let row = sqlx::query_as::<_, SeatGrant>(&sql)
+        .bind(user_id)
+        .bind(course_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 구독 범위 라이선스의 유효한 좌석 보유 여부 (구독 전용 콘텐츠 접근)
+pub async fn has_active_subscription_seat(pool: &PgPool, user_id: i64) -> AppResult<bool> {
+    let sql = format!("SELECT EXISTS ({SEAT_GRANT_SELECT} AND l.license_scope = 'subscription')");
+    let exists = sqlx::query_scalar::<_, bool>(&sql)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(exists)
+}
+
+// =============================================================================
+// 대시보드
+// =============================================================================
+
+#[derive(Debug, FromRow)]
+pub struct LearnerActivityRow {
+    pub seat_id: i64,
+    pub license_id: i64,
+    pub user_id: i64,
+    pub user_nickname: String,
+    pub seat_email: String,
+    pub seat_accepted_at: Option<DateTime<Utc>>,
+    pub course_progress_avg: i32,
+    pub courses_completed: i64,
+    pub lessons_completed: i64,
+    pub videos_completed: i64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+/// 기관 active 좌석 학습자별 학습 현황 — 모든 행이 org_seat(active) 를 경유
+pub async fn find_learner_activity(
+    pool: &PgPool,
+    org_id: i64,
+) -> AppResult<Vec<LearnerActivityRow>> {
+    let rows = sqlx::query_as::<_, LearnerActivityRow>(
+        r#"
+        SELECT
+            s.seat_id,
+            s.license_id,
+            s.user_id,
+            u.user_nickname,
+            s.seat_email,
+            s.seat_accepted_at,
+            uc.course_progress_avg,
+            uc.courses_completed,
+            lp.lessons_completed,
+            v.videos_completed,
+            GREATEST(uc.last_at, lp.last_at, v.last_at) AS last_activity_at
+        FROM org_seat s
+        JOIN org_license l ON l.license_id = s.license_id
+        JOIN users u ON u.user_id = s.user_id
+        CROSS JOIN LATERAL (
+            SELECT COALESCE(ROUND(AVG(user_course_progress_percent)), 0)::int AS course_progress_avg,
+                   COUNT(*) FILTER (WHERE user_course_progress_percent >= 100) AS courses_completed,
+                   MAX(user_course_last_progress_at) AS last_at
+            FROM users_course WHERE user_course_org_seat_id = s.seat_id
+        ) uc
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) FILTER (WHERE lesson_progress_percent >= 100) AS lessons_completed,
+                   MAX(lesson_progress_last_progress_at) AS last_at
+            FROM lesson_progress WHERE user_id = s.user_id
+        ) lp
+        CROSS JOIN LATERAL (
+            SELECT COUNT(DISTINCT video_id) FILTER (WHERE video_completed_log) AS videos_completed,
+                   MAX(video_last_watched_at_log) AS last_at
+            FROM video_log WHERE user_id = s.user_id
+        ) v
+        WHERE l.org_id = $1 AND s.seat_state = 'active'
+        ORDER BY last_activity_at DESC NULLS LAST, s.seat_id
+        "#,
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// =============================================================================
+// SSO
+// =============================================================================
+
+/// 기관 SSO 설정 행 (client secret 은 암호문)
+#[derive(Debug, FromRow)]
+pub struct OrgSsoRow {
+    pub org_id: i64,
+    pub org_name: String,
+    pub sso_protocol: OrgSsoProtocol,
+    pub sso_enabled: bool,
+    pub sso_enforced: bool,
+    pub sso_jit_enabled: bool,
+    pub oidc_issuer: Option<String>,
+    pub oidc_client_id: Option<String>,
+    pub oidc_client_secret: Option<String>,
+    pub saml_metadata_xml: Option<String>,
+    pub saml_idp_entity_id: Option<String>,
+    pub sso_attribute_map: serde_json::Value,
+    pub sso_updated_at: DateTime<Utc>,
+}
+
+const SSO_SELECT: &str = r#"
+    SELECT
+        s.org_id, o.org_name, s.sso_protocol, s.sso_enabled, s.sso_enforced, s.sso_jit_enabled,
+        s.oidc_issuer, s.oidc_client_id, s.oidc_client_secret,
+        s.saml_metadata_xml, s.saml_idp_entity_id, s.sso_attribute_map, s.sso_updated_at
+    FROM org_sso s
+    JOIN organization o ON o.org_id = s.org_id
+"#;
+
+pub async fn find_sso(pool: &PgPool, org_id: i64) -> AppResult<Option<OrgSsoRow>> {
+    let sql = format!("{SSO_SELECT} WHERE s.org_id = $1");
+    let row = sqlx::query_as::<_, OrgSsoRow>(&sql)
+        .bind(org_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// 로그인 discovery — 이메일 도메인의 SSO 설정 (비활성 포함, 판단은 호출자)
+pub async fn find_sso_by_domain(pool: &PgPool, domain: &str) -> AppResult<Option<OrgSsoRow>> {
+    let sql = format!(
+        "{SSO_SELECT} JOIN org_sso_domain d ON d.org_id = s.org_id WHERE d.sso_domain = $1"
+    );
+    let row = sqlx::query_as::<_, OrgSsoRow>(&sql)
+        .bind(domain)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+pub async fn find_sso_domains(pool: &PgPool, org_id: i64) -> AppResult<Vec<String>> {
+    let rows = sqlx::query_scalar::<_, String>(
+        "SELECT sso_domain FROM org_sso_domain WHERE org_id = $1 ORDER BY sso_domain",
+    )
+    .bind(org_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 도메인이 SSO 전용(활성 + enforce)인지
+pub async fn is_sso_enforced_domain(pool: &PgPool, domain: &str) -> AppResult<bool> {
+    let enforced = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM org_sso_domain d
+            JOIN org_sso s ON s.org_id = d.org_id
+            WHERE d.sso_domain = $1 AND s.sso_enabled AND s.sso_enforced
+        )
+        "#,
+    )
+    .bind(domain)
+    .fetch_one(pool)
+    .await?;
+    Ok(enforced)
+}
+
+/// SSO 전용 설정 변경 — 설정이 없으면 false
+pub async fn update_sso_enforced(
+    pool: &PgPool,
+    org_id: i64,
+    enforced: bool,
+    updated_by_user_id: i64,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE org_sso
+        SET sso_enforced = $2, sso_updated_by_user_id = $3, sso_updated_at = NOW()
+        WHERE org_id = $1
+        "#,
+    )
+    .bind(org_id)
+    .bind(enforced)
+    .bind(updated_by_user_id)
+    .execute(pool)
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
@@ -0,0 +1,21 @@
+use super::handler;
+use crate::state::AppState;
+use axum::routing::{delete, get, patch, post};
+
+pub fn org_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/me", get(handler::my_orgs))
+        .route("/seats/accept", post(handler::accept_seat))
+        .route("/{org_id}", get(handler::get_org))
+        .route("/{org_id}/dashboard", get(handler::org_dashboard))
+        .route(
+            "/{org_id}/licenses/{license_id}/seats",
+            get(handler::list_seats).post(handler::invite_seats),
+        )
+        .route("/{org_id}/seats/{seat_id}", delete(handler::revoke_seat))
+        .route("/{org_id}/sso", get(handler::get_org_sso))
+        .route(
+            "/{org_id}/sso/enforcement",
+            patch(handler::update_org_sso_enforcement),
+        )
+}
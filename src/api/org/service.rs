@@ -0,0 +1,705 @@
+use std::collections::{BTreeMap, HashSet};
+use std::net::IpAddr;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use validator::ValidateEmail;
+
+use super::{
+    dto::{
+        InviteOrgSeatsRes, MyOrgListRes, OrgDashboardRes, OrgDetailRes, OrgLearnerActivity,
+        OrgLicenseRes, OrgRes, OrgSeatAcceptRes, OrgSeatListRes, OrgSeatRes, OrgSeatTotals,
+        OrgSsoRes, SkippedSeatInvite, UpdateOrgSsoEnforcementReq,
+    },
+    repo::{self, NewSeat, OrgSsoRow, SeatRow},
+};
+use crate::api::admin::user::repo::write_audit_log;
+use crate::api::certificate::service::issue_for_completed;
+use crate::api::payment::repo::PaymentRepo;
+use crate::crypto::CryptoService;
+use crate::error::{AppError, AppResult};
+use crate::external::email::{send_templated, EmailTemplate};
+use crate::state::AppState;
+use crate::types::{OrgLicenseScope, OrgSeatState, UserAuth};
+
+const SEAT_INVITE_TTL_DAYS: i64 = 14;
+const SEAT_EMAIL_AAD: &str = "org_seat.seat_email";
+const ACTIVE_LEARNER_WINDOW_DAYS: i64 = 7;
+
+/// 좌석 초대 토큰 원문 생성 (메일 링크에만 포함, DB 에는 해시만 저장)
+fn generate_seat_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn hash_seat_token(token: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+/// 초대 이메일 정규화 — 소문자/공백 제거 + 중복 제거 (입력 순서 유지). 형식 오류가 하나라도 있으면 400
+pub fn normalize_invite_emails(emails: &[String]) -> AppResult<Vec<String>> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::with_capacity(emails.len());
+    let mut invalid = Vec::new();
+    for raw in emails {
+        let email = raw.trim().to_lowercase();
+        if !email.validate_email() {
+            invalid.push(raw.clone());
+        } else if seen.insert(email.clone()) {
+            out.push(email);
+        }
+    }
+    if !invalid.is_empty() {
+        return Err(AppError::BadRequest(format!(
+            "Invalid email: {}",
+            invalid.join(", ")
+        )));
+    }
+    Ok(out)
+}
+
+/// 라이선스가 지금 유효기간 안인지 ([from, until))
+pub fn license_is_current(
+    valid_from: DateTime<Utc>,
+    valid_until: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> bool {
+    valid_from <= now && now < valid_until
+}
+
+/// 초대 메일에 표시할 수강 범위
+pub fn license_label(scope: OrgLicenseScope, course_title: Option<&str>) -> String {
+    match scope {
+        OrgLicenseScope::Subscription => "구독 (전체 코스)".to_string(),
+        OrgLicenseScope::Course => course_title.unwrap_or("코스").to_string(),
+    }
+}
+
+/// 현재 유효한 라이선스들의 좌석 합계
+pub fn seat_totals(licenses: &[OrgLicenseRes], now: DateTime<Utc>) -> OrgSeatTotals {
+    let current = licenses
+        .iter()
+        .filter(|l| license_is_current(l.license_valid_from, l.license_valid_until, now));
+    let mut totals = OrgSeatTotals {
+        seat_count: 0,
+        seats_active: 0,
+        seats_invited: 0,
+        seats_available: 0,
+        utilization_percent: 0,
+    };
+    for l in current {
+        totals.seat_count += i64::from(l.license_seat_count);
+        totals.seats_active += l.seats_active;
+        totals.seats_invited += l.seats_invited;
+        totals.seats_available += l.seats_available;
+    }
+    if totals.seat_count > 0 {
+        totals.utilization_percent =
+            ((totals.seats_active * 100) / totals.seat_count).min(100) as i32;
+    }
+    totals
+}
+
+fn to_seat(crypto: &CryptoService<'_>, row: SeatRow) -> AppResult<OrgSeatRes> {
+    Ok(OrgSeatRes {
+        seat_id: row.seat_id,
+        license_id: row.license_id,
+        email: crypto.decrypt(&row.seat_email, SEAT_EMAIL_AAD)?,
+        seat_state: row.seat_state,
+        user_id: row.user_id,
+        nickname: row.nickname,
+        seat_invited_at: row.seat_invited_at,
+        seat_invite_expires_at: row.seat_invite_expires_at,
+        seat_accepted_at: row.seat_accepted_at,
+        seat_revoked_at: row.seat_revoked_at,
+    })
+}
+
+/// 기관 접근 — 기관 관리자 본인 기관만, HYMN/admin 은 전체. 권한 밖 기관은 존재 여부를 노출하지 않도록 404
+pub(crate) async fn load_org(st: &AppState, actor_user_id: i64, org_id: i64) -> AppResult<OrgRes> {
+    let actor = crate::api::user::repo::find_user(&st.db, actor_user_id)
+        .await?
+        .ok_or(AppError::Unauthorized("Actor user not found".into()))?;
+    let allowed = matches!(actor.user_auth, UserAuth::Hymn | UserAuth::Admin)
+        || repo::is_org_admin(&st.db, org_id, actor_user_id).await?;
+    if !allowed {
+        return Err(AppError::NotFound);
+    }
+    repo::find_org(&st.db, org_id)
+        .await?
+        .ok_or(AppError::NotFound)
+}
+
+/// 유효한 좌석 수강권 재부여 — 구독 일시정지/수동 회수로 비활성화된 코스 중 좌석이 커버하는 것을 복원
+pub(crate) async fn restore_seat_entitlements(st: &AppState, user_id: i64) -> AppResult<u64> {
+    let mut conn = st.db.acquire().await?;
+    let mut restored = 0;
+    for grant in repo::find_active_seat_grants(&st.db, user_id).await? {
+        restored += repo::grant_seat_courses(
+            &mut conn,
+            grant.seat_id,
+            user_id,
+            grant.course_id,
+            grant.license_valid_until,
+        )
+        .await?;
+    }
+    Ok(restored)
+}
+
+/// 좌석 회수 후 남은 수강 근거 복원 — 다른 기관 좌석 / 개인 구독
+async fn restore_remaining_entitlements(st: &AppState, user_id: i64) -> AppResult<()> {
+    restore_seat_entitlements(st, user_id).await?;
+    if let Some(subscription) = PaymentRepo::get_active_subscription(&st.db, user_id).await? {
+        PaymentRepo::grant_all_courses(&st.db, user_id, subscription.current_period_end).await?;
+    }
+    Ok(())
+}
+
+/// 저장된 속성 매핑 (JSONB) → 필드별 속성 이름
+pub(crate) fn sso_attribute_map(row: &OrgSsoRow) -> BTreeMap<String, String> {
+    serde_json::from_value(row.sso_attribute_map.clone()).unwrap_or_default()
+}
+
+fn to_sso(row: OrgSsoRow, domains: Vec<String>) -> OrgSsoRes {
+    OrgSsoRes {
+        attribute_map: sso_attribute_map(&row),
+        org_id: row.org_id,
+        protocol: row.sso_protocol,
+        enabled: row.sso_enabled,
+        enforced: row.sso_enforced,
+        jit_enabled: row.sso_jit_enabled,
+        domains,
+        oidc_issuer: row.oidc_issuer,
+        oidc_client_id: row.oidc_client_id,
+        oidc_client_secret_set: row.oidc_client_secret.is_some(),
+        saml_idp_entity_id: row.saml_idp_entity_id,
+        updated_at: row.sso_updated_at,
+    }
+}
+
+/// 기관 SSO 설정 응답 — 설정이 없으면 404
+pub(crate) async fn load_sso(st: &AppState, org_id: i64) -> AppResult<OrgSsoRes> {
+    let row = repo::find_sso(&st.db, org_id)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    let domains = repo::find_sso_domains(&st.db, org_id).await?;
+    Ok(to_sso(row, domains))
+}
+
+pub struct OrgService;
+
+impl OrgService {
+    // =========================================================================
+    // 기관 관리자
+    // =========================================================================
+
+    /// 내가 관리하는 기관
+    pub async fn list_mine(st: &AppState, user_id: i64) -> AppResult<MyOrgListRes> {
+        let items = repo::find_orgs_administered_by(&st.db, user_id).await?;
+        Ok(MyOrgListRes { items })
+    }
+
+    /// 기관 상세 (라이선스별 좌석 현황 + 관리자)
+    pub async fn get(st: &AppState, actor_user_id: i64, org_id: i64) -> AppResult<OrgDetailRes> {
+        let org = load_org(st, actor_user_id, org_id).await?;
+        Ok(OrgDetailRes {
+            licenses: repo::find_licenses(&st.db, org_id).await?,
+            admins: repo::find_admins(&st.db, org_id).await?,
+            org,
+        })
+    }
+
+    /// 대시보드 — 좌석 사용률 + 좌석 학습자 활동
+    pub async fn dashboard(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+    ) -> AppResult<OrgDashboardRes> {
+        let org = load_org(st, actor_user_id, org_id).await?;
+        let now = Utc::now();
+        let licenses = repo::find_licenses(&st.db, org_id).await?;
+        let totals = seat_totals(&licenses, now);
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let learners = repo::find_learner_activity(&st.db, org_id)
+            .await?
+            .into_iter()
+            .map(|r| {
+                Ok(OrgLearnerActivity {
+                    seat_id: r.seat_id,
+                    license_id: r.license_id,
+                    user_id: r.user_id,
+                    nickname: r.user_nickname,
+                    email: crypto.decrypt(&r.seat_email, SEAT_EMAIL_AAD)?,
+                    seat_accepted_at: r.seat_accepted_at,
+                    course_progress_avg: r.course_progress_avg,
+                    courses_completed: r.courses_completed,
+                    lessons_completed: r.lessons_completed,
+                    videos_completed: r.videos_completed,
+                    last_activity_at: r.last_activity_at,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let since = now - Duration::days(ACTIVE_LEARNER_WINDOW_DAYS);
+        let active_learners_7d = learners
+            .iter()
+            .filter(|l| l.last_activity_at.is_some_and(|t| t >= since))
+            .count() as i64;
+
+        Ok(OrgDashboardRes {
+            org_id,
+            org_name: org.org_name,
+            totals,
+            licenses,
+            active_learners_7d,
+            learners,
+        })
+    }
+
+    /// 라이선스 좌석 목록
+    pub async fn list_seats(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        license_id: i64,
+    ) -> AppResult<OrgSeatListRes> {
+        load_org(st, actor_user_id, org_id).await?;
+        repo::find_license(&st.db, org_id, license_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let items = repo::find_seats(&st.db, license_id)
+            .await?
+            .into_iter()
+            .map(|r| to_seat(&crypto, r))
+            .collect::<AppResult<Vec<_>>>()?;
+        Ok(OrgSeatListRes { license_id, items })
+    }
+
+    /// 좌석 이메일 일괄 초대 — 잔여 좌석이 모자라면 아무도 초대하지 않음
+    pub async fn invite_seats(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        license_id: i64,
+        emails: &[String],
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<InviteOrgSeatsRes> {
+        load_org(st, actor_user_id, org_id).await?;
+        let email_sender = st
+            .email
+            .as_ref()
+            .ok_or_else(|| AppError::ServiceUnavailable("Email service not configured".into()))?;
+        let emails = normalize_invite_emails(emails)?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let now = Utc::now();
+        let mut tx = st.db.begin().await?;
+        let license = repo::lock_license(&mut tx, org_id, license_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if !license_is_current(license.license_valid_from, license.license_valid_until, now) {
+            return Err(AppError::Conflict("ORG_409_LICENSE_INACTIVE".into()));
+        }
+
+        let mut to_invite = Vec::with_capacity(emails.len());
+        let mut skipped = Vec::new();
+        for email in emails {
+            let email_idx = crypto.blind_index(&email)?;
+            repo::revoke_expired_invite(&mut tx, license_id, &email_idx).await?;
+            if repo::has_live_seat(&mut tx, license_id, &email_idx).await? {
+                skipped.push(SkippedSeatInvite {
+                    email,
+                    reason: "already_seated".into(),
+                });
+            } else {
+                to_invite.push((email, email_idx));
+            }
+        }
+
+        let occupied = repo::count_occupied_seats(&mut tx, license_id).await?;
+        let available = (i64::from(license.license_seat_count) - occupied).max(0);
+        if to_invite.len() as i64 > available {
+            return Err(AppError::Conflict("ORG_409_SEATS_EXHAUSTED".into()));
+        }
+
+        // 토큰 원문은 메일에만 — 발송까지 한 트랜잭션으로 묶어 실패 시 좌석이 남지 않게 한다
+        let expires_at = now + Duration::days(SEAT_INVITE_TTL_DAYS);
+        let label = license_label(license.license_scope, license.course_title.as_deref());
+        let valid_until = license.license_valid_until.format("%Y-%m-%d").to_string();
+        let mut invited = Vec::with_capacity(to_invite.len());
+        for (email, email_idx) in to_invite {
+            let token = generate_seat_token();
+            let email_enc = crypto.encrypt(&email, SEAT_EMAIL_AAD)?;
+            let row = repo::insert_seat(
+                &mut tx,
+                &NewSeat {
+                    license_id,
+                    email_enc: &email_enc,
+                    email_idx: &email_idx,
+                    token_hash: &hash_seat_token(&token),
+                    invited_by_user_id: actor_user_id,
+                    expires_at,
+                },
+            )
+            .await?;
+            send_templated(
+                email_sender.as_ref(),
+                &email,
+                EmailTemplate::OrgSeatInvite {
+                    invite_url: format!(
+                        "{}/orgs/seats/accept?token={}",
+                        st.cfg.frontend_url.trim_end_matches('/'),
+                        token
+                    ),
+                    org_name: license.org_name.clone(),
+                    license_label: label.clone(),
+                    valid_until: valid_until.clone(),
+                    expires_in_days: SEAT_INVITE_TTL_DAYS as i32,
+                },
+            )
+            .await?;
+            invited.push(to_seat(&crypto, row)?);
+        }
+        tx.commit().await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "INVITE_ORG_SEATS",
+            "org_license",
+            Some(license_id),
+            &serde_json::json!({
+                "org_id": org_id,
+                "invited": invited.len(),
+                "skipped": skipped.len(),
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        tracing::info!(
+            actor_user_id,
+            org_id,
+            license_id,
+            invited = invited.len(),
+            skipped = skipped.len(),
+            "Org seats invited"
+        );
+
+        Ok(InviteOrgSeatsRes {
+            license_id,
+            invited,
+            skipped,
+        })
+    }
+
+    /// 좌석 회수 — 초대 대기면 초대 무효화, 수락된 좌석이면 좌석이 부여한 수강권을 비활성화
+    pub async fn revoke_seat(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        seat_id: i64,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        load_org(st, actor_user_id, org_id).await?;
+
+        let mut tx = st.db.begin().await?;
+        let seat = repo::find_seat_in_org(&mut tx, org_id, seat_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if seat.seat_state == OrgSeatState::Revoked {
+            return Err(AppError::Conflict("ORG_409_SEAT_REVOKED".into()));
+        }
+        repo::mark_seat_revoked(&mut tx, seat_id, actor_user_id).await?;
+        let courses_revoked = repo::deactivate_seat_courses(&mut tx, seat_id).await?;
+        tx.commit().await?;
+
+        if let Some(user_id) = seat.user_id {
+            restore_remaining_entitlements(st, user_id).await?;
+        }
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "REVOKE_ORG_SEAT",
+            "org_seat",
+            Some(seat_id),
+            &serde_json::json!({
+                "org_id": org_id,
+                "license_id": seat.license_id,
+                "user_id": seat.user_id,
+                "courses_revoked": courses_revoked,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        tracing::info!(
+            actor_user_id,
+            org_id,
+            seat_id,
+            courses_revoked,
+            "Org seat revoked"
+        );
+        Ok(())
+    }
+
+    // =========================================================================
+    // SSO — IdP 설정은 HYMN/admin 이 등록, 기관 관리자는 조회와 SSO 전용 강제만
+    // =========================================================================
+
+    pub async fn get_sso(st: &AppState, actor_user_id: i64, org_id: i64) -> AppResult<OrgSsoRes> {
+        load_org(st, actor_user_id, org_id).await?;
+        load_sso(st, org_id).await
+    }
+
+    /// SSO 전용 켜기/끄기 — 켜면 기관 도메인 계정은 기관 IdP 로만 로그인/가입
+    pub async fn update_sso_enforcement(
+        st: &AppState,
+        actor_user_id: i64,
+        org_id: i64,
+        req: UpdateOrgSsoEnforcementReq,
+        ip_address: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> AppResult<OrgSsoRes> {
+        load_org(st, actor_user_id, org_id).await?;
+        let before = repo::find_sso(&st.db, org_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        if req.enforced && !before.sso_enabled {
+            return Err(AppError::Conflict("ORG_409_SSO_DISABLED".into()));
+        }
+        repo::update_sso_enforced(&st.db, org_id, req.enforced, actor_user_id).await?;
+
+        write_audit_log(
+            st,
+            actor_user_id,
+            "UPDATE_ORG_SSO_ENFORCEMENT",
+            "org_sso",
+            Some(org_id),
+            &serde_json::json!({
+                "org_id": org_id,
+                "before": before.sso_enforced,
+                "enforced": req.enforced,
+            }),
+            ip_address,
+            user_agent.as_deref(),
+        )
+        .await?;
+
+        tracing::info!(
+            actor_user_id,
+            org_id,
+            enforced = req.enforced,
+            "Org SSO enforcement updated"
+        );
+        load_sso(st, org_id).await
+    }
+
+    // =========================================================================
+    // 학습자
+    // =========================================================================
+
+    /// 좌석 초대 수락 — 로그인 계정 이메일이 초대 이메일과 같아야 함
+    pub async fn accept_seat(
+        st: &AppState,
+        user_id: i64,
+        token: &str,
+    ) -> AppResult<OrgSeatAcceptRes> {
+        let user_email_idx = crate::api::classroom::repo::find_user_email_idx(&st.db, user_id)
+            .await?
+            .ok_or(AppError::Unauthorized("User not found".into()))?;
+
+        let now = Utc::now();
+        let mut tx = st.db.begin().await?;
+        let seat = repo::find_seat_by_token_hash_for_update(&mut tx, &hash_seat_token(token))
+            .await?
+            .ok_or(AppError::NotFound)?;
+        match seat.seat_state {
+            OrgSeatState::Invited => {}
+            OrgSeatState::Active => return Err(AppError::Conflict("ORG_409_SEAT_USED".into())),
+            OrgSeatState::Revoked => return Err(AppError::Conflict("ORG_409_SEAT_REVOKED".into())),
+        }
+        if seat.seat_invite_expires_at <= now {
+            return Err(AppError::Conflict("ORG_409_SEAT_INVITE_EXPIRED".into()));
+        }
+        if !license_is_current(seat.license_valid_from, seat.license_valid_until, now) {
+            return Err(AppError::Conflict("ORG_409_LICENSE_INACTIVE".into()));
+        }
+        if seat.seat_email_idx != user_email_idx {
+            return Err(AppError::Forbidden("ORG_403_SEAT_EMAIL_MISMATCH".into()));
+        }
+
+        repo::mark_seat_accepted(&mut tx, seat.seat_id, user_id).await?;
+        let courses_granted = repo::grant_seat_courses(
+            &mut tx,
+            seat.seat_id,
+            user_id,
+            seat.course_id,
+            seat.license_valid_until,
+        )
+        .await?;
+        tx.commit().await?;
+
+        // 코스 좌석은 수강 전 진행한 레슨 진도를 반영 (수강 신청과 동일)
+        if let Some(course_id) = seat.course_id {
+            crate::api::course::repo::recompute_progress_for_course(
+                &st.db,
+                i64::from(course_id),
+                Some(user_id),
+            )
+            .await?;
+            let mut conn = st.db.acquire().await?;
+            issue_for_completed(&mut conn, Some(user_id), Some(i64::from(course_id))).await?;
+        }
+
+        tracing::info!(
+            user_id,
+            org_id = seat.org_id,
+            seat_id = seat.seat_id,
+            courses_granted,
+            "Org seat accepted"
+        );
+
+        Ok(OrgSeatAcceptRes {
+            seat_id: seat.seat_id,
+            org_id: seat.org_id,
+            org_name: seat.org_name,
+            license_scope: seat.license_scope,
+            course_id: seat.course_id,
+            license_valid_until: seat.license_valid_until,
+            courses_granted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn license(
+        seat_count: i32,
+        active: i64,
+        invited: i64,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> OrgLicenseRes {
+        OrgLicenseRes {
+            license_id: 1,
+            org_id: 1,
+            license_scope: OrgLicenseScope::Subscription,
+            course_id: None,
+            course_title: None,
+            license_seat_count: seat_count,
+            license_valid_from: from,
+            license_valid_until: until,
+            textbook_order_id: None,
+            license_note: None,
+            seats_active: active,
+            seats_invited: invited,
+            seats_available: (i64::from(seat_count) - active - invited).max(0),
+            license_created_at: from,
+        }
+    }
+
+    #[test]
+    fn normalize_invite_emails_lowercases_dedupes_and_rejects_invalid() {
+        let emails = vec![
+            " Kim@Example.com ".to_string(),
+            "lee@example.com".to_string(),
+            "kim@example.com".to_string(),
+        ];
+        assert_eq!(
+            normalize_invite_emails(&emails).unwrap(),
+            vec!["kim@example.com", "lee@example.com"]
+        );
+
+        let bad = vec!["ok@example.com".to_string(), "not-an-email".to_string()];
+        assert!(matches!(
+            normalize_invite_emails(&bad),
+            Err(AppError::BadRequest(msg)) if msg.contains("not-an-email")
+        ));
+    }
+
+    #[test]
+    fn license_window_is_half_open() {
+        let from = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2027, 3, 1, 0, 0, 0).unwrap();
+        assert!(license_is_current(from, until, from));
+        assert!(license_is_current(
+            from,
+            until,
+            until - Duration::seconds(1)
+        ));
+        assert!(!license_is_current(from, until, until));
+        assert!(!license_is_current(
+            from,
+            until,
+            from - Duration::seconds(1)
+        ));
+    }
+
+    #[test]
+    fn seat_totals_count_only_current_licenses() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap();
+        let current = license(
+            20,
+            15,
+            3,
+            now - Duration::days(30),
+            now + Duration::days(300),
+        );
+        let expired = license(
+            50,
+            50,
+            0,
+            now - Duration::days(400),
+            now - Duration::days(35),
+        );
+        let totals = seat_totals(&[current, expired], now);
+        assert_eq!(totals.seat_count, 20);
+        assert_eq!(totals.seats_active, 15);
+        assert_eq!(totals.seats_invited, 3);
+        assert_eq!(totals.seats_available, 2);
+        assert_eq!(totals.utilization_percent, 75);
+
+        assert_eq!(seat_totals(&[], now).utilization_percent, 0);
+    }
+
+    #[test]
+    fn license_label_names_scope() {
+        assert_eq!(
+            license_label(OrgLicenseScope::Subscription, None),
+            "구독 (전체 코스)"
+        );
+        assert_eq!(
+            license_label(OrgLicenseScope::Course, Some("초급 회화")),
+            "초급 회화"
+        );
+    }
+
+    #[test]
+    fn seat_token_hash_is_stable_and_distinct() {
+        let token = generate_seat_token();
+        assert_eq!(
+            hash_seat_token(&token),
+            hash_seat_token(&format!(" {token} "))
+        );
+        assert_ne!(
+            hash_seat_token(&token),
+            hash_seat_token(&generate_seat_token())
+        );
+        assert_ne!(hash_seat_token(&token), token);
+    }
+}
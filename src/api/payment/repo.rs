@@ -333,6 +333,7 @@ impl PaymentRepo {
 
     /// 활성 코스 전체에 수강권 부여 (UPSERT)
     /// 구독 활성화 시 호출 — 모든 active 코스에 users_course 레코드 생성/갱신
+    /// (더 오래 유효한 기관 좌석 수강권은 유지)
     pub async fn grant_all_courses(
         pool: &PgPool,
         user_id: i64,
@@ -346,7 +347,12 @@ impl PaymentRepo {
             ON CONFLICT (user_id, course_id) DO UPDATE SET
                 user_course_active = true,
                 user_course_expire_at = EXCLUDED.user_course_expire_at,
+                user_course_org_seat_id = NULL,
                 user_course_updated_at = NOW()
+            WHERE users_course.user_course_org_seat_id IS NULL
+               OR NOT users_course.user_course_active
+               OR EXCLUDED.user_course_expire_at IS NULL
+               OR users_course.user_course_expire_at < EXCLUDED.user_course_expire_at
             "#,
         )
         .bind(user_id)
@@ -357,7 +363,7 @@ impl PaymentRepo {
         Ok(result.rows_affected())
     }
 
-    /// 모든 수강권 비활성화 (구독 일시정지/취소 시)
+    /// 모든 수강권 비활성화 (구독 일시정지/취소 시) — 기관 좌석 수강권은 좌석 회수로만 해제
     pub async fn revoke_all_courses(pool: &PgPool, user_id: i64) -> AppResult<u64> {
         let result = sqlx::query(
             r#"
@@ -365,6 +371,7 @@ impl PaymentRepo {
             SET user_course_active = false,
                 user_course_updated_at = NOW()
             WHERE user_id = $1 AND user_course_active = true
+              AND user_course_org_seat_id IS NULL
             "#,
         )
         .bind(user_id)
@@ -374,7 +381,7 @@ impl PaymentRepo {
         Ok(result.rows_affected())
     }
 
-    /// 수강권 만료일 업데이트 (구독 갱신 시)
+    /// 수강권 만료일 업데이트 (구독 갱신 시, 기관 좌석 수강권 제외)
     pub async fn update_course_expiry(
         pool: &PgPool,
         user_id: i64,
@@ -386,6 +393,7 @@ impl PaymentRepo {
             SET user_course_expire_at = $2,
                 user_course_updated_at = NOW()
             WHERE user_id = $1 AND user_course_active = true
+              AND user_course_org_seat_id IS NULL
             "#,
         )
         .bind(user_id)
@@ -83,10 +83,13 @@ impl PaymentService {
         Ok(SubscriptionRes { subscription })
     }
 
-    /// 사용자가 활성 구독을 보유하고 있는지 확인
+    /// 사용자가 활성 구독을 보유하고 있는지 확인 (구독 범위 기관 좌석 포함)
     pub async fn has_active_subscription(st: &AppState, user_id: i64) -> AppResult<bool> {
         let row = PaymentRepo::get_active_subscription(&st.db, user_id).await?;
-        Ok(row.is_some())
+        if row.is_some() {
+            return Ok(true);
+        }
+        crate::api::org::repo::has_active_subscription_seat(&st.db, user_id).await
     }
 
     // =========================================================================
@@ -462,10 +465,14 @@ impl PaymentService {
             PaymentRepo::get_subscription_by_provider_id(&st.db, &provider_sub_id).await?;
         if let Some(row) = existing {
             let revoked = PaymentRepo::revoke_all_courses(&st.db, row.user_id).await?;
+            // 구독이 덮어썼던 코스 중 기관 좌석이 커버하는 것은 좌석 수강권으로 복원
+            let restored =
+                crate::api::org::service::restore_seat_entitlements(st, row.user_id).await?;
             tracing::info!(
                 user_id = row.user_id,
                 sub_id = %provider_sub_id,
                 courses_revoked = revoked,
+                seat_courses_restored = restored,
                 "Subscription paused — courses revoked"
             );
         }
@@ -61,6 +61,13 @@ pub enum SubmitAnswerReq {
     },
 }
 
+/// 정답 제출 컨텍스트 (Query String) — 영상 퀴즈 큐 포인트에서 제출 시 지정
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SubmitAnswerContext {
+    pub video_cue_point_id: Option<i64>,
+}
+
 // =========================================================================
 // Response DTOs (응답)
 // =========================================================================
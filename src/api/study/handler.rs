@@ -8,10 +8,10 @@ use crate::state::AppState;
 
 use super::dto::{
     FinishWritingSessionReq, StartWritingSessionReq, StudyDetailReq, StudyDetailRes, StudyListReq,
-    StudyListResp, StudyTaskDetailReq, StudyTaskDetailRes, SubmitAnswerReq, SubmitAnswerRes,
-    TaskExplainReq, TaskExplainRes, TaskStatusRes, WritingPracticeSeedReq, WritingPracticeSeedRes,
-    WritingSessionListReq, WritingSessionListRes, WritingSessionRes, WritingStatsReq,
-    WritingStatsRes,
+    StudyListResp, StudyTaskDetailReq, StudyTaskDetailRes, SubmitAnswerContext, SubmitAnswerReq,
+    SubmitAnswerRes, TaskExplainReq, TaskExplainRes, TaskStatusRes, WritingPracticeSeedReq,
+    WritingPracticeSeedRes, WritingSessionListReq, WritingSessionListRes, WritingSessionRes,
+    WritingStatsReq, WritingStatsRes,
 };
 use super::service::StudyService;
 
@@ -100,7 +100,8 @@ pub async fn get_study_task(
     post,
     path = "/studies/tasks/{id}/answer",
     params(
-        ("id" = i32, Path, description = "Study Task ID")
+        ("id" = i32, Path, description = "Study Task ID"),
+        ("video_cue_point_id" = Option<i64>, Query, description = "영상 퀴즈 큐 포인트에서 제출한 경우 큐 ID")
     ),
     request_body = SubmitAnswerReq,
     responses(
@@ -117,9 +118,10 @@ pub async fn submit_answer(
     State(state): State<AppState>,
     auth_user: AuthUser,
     Path(task_id): Path<i32>,
+    Query(ctx): Query<SubmitAnswerContext>,
     AppJson(req): AppJson<SubmitAnswerReq>,
 ) -> AppResult<Json<SubmitAnswerRes>> {
-    let res = StudyService::submit_answer(&state, auth_user, task_id, req).await?;
+    let res = StudyService::submit_answer(&state, auth_user, task_id, ctx, req).await?;
     Ok(Json(res))
 }
 
@@ -315,6 +315,7 @@ impl StudyRepo {
         task_id: i32,
         is_correct: bool,
         payload: &Value,
+        video_cue_point_id: Option<i64>,
     ) -> AppResult<()> {
         let mut tx = pool.begin().await?;
 
@@ -351,7 +352,8 @@ impl StudyRepo {
                 study_task_action_log,
                 study_task_try_no_log,
                 study_task_is_correct_log,
-                study_task_answer_log
+                study_task_answer_log,
+                video_cue_point_id
             )
             SELECT
                 $1,
@@ -360,7 +362,8 @@ impl StudyRepo {
                 $3,
                 $4,
                 $5,
-                $6
+                $6,
+                $8
             FROM login l
             WHERE l.login_session_id = CAST($7 AS uuid)
               AND l.user_id = $2
@@ -373,6 +376,7 @@ impl StudyRepo {
         .bind(is_correct)
         .bind(payload)
         .bind(session_id)
+        .bind(video_cue_point_id)
         .execute(&mut *tx)
         .await?;
 
@@ -4,6 +4,7 @@ use tracing::warn;
 use crate::api::admin::translation::dto::TranslationMeta;
 use crate::api::admin::translation::repo::TranslationRepo;
 use crate::api::auth::extractor::AuthUser;
+use crate::api::video::repo::VideoRepo;
 use crate::error::{AppError, AppResult};
 use crate::state::AppState;
 use crate::types::{
@@ -13,10 +14,10 @@ use crate::types::{
 // [Strict Mode] Import DTOs and Repo directly from the verified files
 use super::dto::{
     FinishWritingSessionReq, StartWritingSessionReq, StudyDetailReq, StudyDetailRes, StudyListMeta,
-    StudyListReq, StudyListResp, StudyListSort, StudyTaskDetailRes, SubmitAnswerReq,
-    SubmitAnswerRes, TaskExplainRes, TaskPayload, TaskStatusRes, WritingPracticeSeedReq,
-    WritingPracticeSeedRes, WritingSessionListReq, WritingSessionListRes, WritingSessionRes,
-    WritingStatsReq, WritingStatsRes,
+    StudyListReq, StudyListResp, StudyListSort, StudyTaskDetailRes, SubmitAnswerContext,
+    SubmitAnswerReq, SubmitAnswerRes, TaskExplainRes, TaskPayload, TaskStatusRes,
+    WritingPracticeSeedReq, WritingPracticeSeedRes, WritingSessionListReq, WritingSessionListRes,
+    WritingSessionRes, WritingStatsReq, WritingStatsRes,
 };
 use super::repo::StudyRepo;
 
@@ -398,6 +399,7 @@ impl StudyService {
         st: &AppState,
         auth_user: AuthUser,
         task_id: i32,
+        ctx: SubmitAnswerContext,
         req: SubmitAnswerReq,
     ) -> AppResult<SubmitAnswerRes> {
         let AuthUser(claims) = auth_user;
@@ -427,6 +429,15 @@ impl StudyService {
         let answer_key = StudyRepo::find_answer_key(&st.db, task_id).await?;
         let answer_key = answer_key.ok_or(AppError::NotFound)?;
 
+        // 영상 컨텍스트: 큐 포인트가 이 과제를 출제하는 공개 영상의 큐여야 함
+        if let Some(cue_point_id) = ctx.video_cue_point_id {
+            if !VideoRepo::cue_point_matches_task(&st.db, cue_point_id, task_id).await? {
+                return Err(AppError::BadRequest(
+                    "video_cue_point_id does not match this task".into(),
+                ));
+            }
+        }
+
         let req_kind = match &req {
             SubmitAnswerReq::Choice { .. } => StudyTaskKind::Choice,
             SubmitAnswerReq::Typing { .. } => StudyTaskKind::Typing,
@@ -471,6 +482,7 @@ impl StudyService {
             task_id,
             is_correct,
             &payload,
+            ctx.video_cue_point_id,
         )
         .await?;
 
@@ -111,6 +111,15 @@ pub struct SettingsUpdateReq {
     pub user_set_note_push: Option<bool>,
 }
 
+/// 세션(기기) 이름 변경 요청
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({ "device_name": "회사 노트북" }))]
+pub struct SessionRenameReq {
+    #[validate(length(min = 1, max = 100))]
+    pub device_name: String,
+}
+
 // =====================================================================
 // Response DTOs (응답)
 // =====================================================================
@@ -151,6 +160,9 @@ pub struct ProfileRes {
 
     /// MFA (2단계 인증) 활성화 여부
     pub mfa_enabled: bool,
+
+    /// 서비스 이용약관 + 개인정보 처리방침 동의 여부 (기관 SSO JIT 가입 직후는 false)
+    pub terms_accepted: bool,
 }
 
 /// 사용자 환경설정 정보
@@ -167,6 +179,92 @@ pub struct SettingsRes {
     pub updated_at: DateTime<Utc>,
 }
 
+/// 활성 로그인 세션 (기기) 항목
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SessionItem {
+    pub session_id: String,
+    /// 사용자가 붙인 기기 이름 (미설정 시 null)
+    pub device_name: Option<String>,
+    /// mobile / tablet / desktop / other
+    pub device: String,
+    pub browser: Option<String>,
+    pub os: Option<String>,
+    /// 로그인 방식 (email, google, apple, passkey ...)
+    pub login_method: String,
+    /// 대략적 위치: 국가 코드 (ISO 3166-1 alpha-2, 조회 불가 시 null)
+    pub country_code: Option<String>,
+    /// 대략적 위치: 통신사/네트워크 (조회 불가 시 null)
+    pub network: Option<String>,
+    /// 마스킹된 IP (IPv4 마지막 옥텟 / IPv6 하위 64비트 가림)
+    pub ip_masked: Option<String>,
+    /// 요청을 보낸 현재 기기 여부
+    pub current: bool,
+    #[schema(value_type = String, format = "date-time")]
+    pub signed_in_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub last_active_at: Option<DateTime<Utc>>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// 활성 세션 목록 (최근 활동 순)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SessionListRes {
+    pub items: Vec<SessionItem>,
+}
+
+/// 연결된 소셜 계정 (identity) 항목
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct IdentityItem {
+    pub identity_id: i64,
+    /// google / apple
+    pub provider: String,
+    /// 마스킹된 소셜 계정 이메일 (제공되지 않은 경우 null)
+    pub email_masked: Option<String>,
+    pub name: Option<String>,
+    #[schema(value_type = String, format = "date-time")]
+    pub linked_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+/// 로그인 수단 현황 — 비밀번호 / 소셜 계정 / 패스키
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct IdentityListRes {
+    pub has_password: bool,
+    pub passkey_count: i64,
+    pub items: Vec<IdentityItem>,
+}
+
+/// 모바일 소셜 계정 연결 (앱 SDK 가 받은 ID token)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct IdentityLinkReq {
+    #[validate(length(min = 1))]
+    pub id_token: String,
+}
+
+/// 소셜 전용 계정의 비밀번호 설정
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({ "new_password": "newStrongPassword123" }))]
+pub struct SetPasswordReq {
+    #[validate(length(min = 8, max = 72))]
+    pub new_password: String,
+}
+
+/// 약관 동의 — 동의 없이 생성된 계정 (기관 SSO JIT 가입) 이 첫 로그인 후 제출
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(example = json!({ "terms_service": true, "terms_personal": true }))]
+pub struct TermsConsentReq {
+    pub terms_service: bool,
+    pub terms_personal: bool,
+}
+
 // =====================================================================
 // 향후 추가할 내용
 // =====================================================================
@@ -1,14 +1,22 @@
 use super::{
-    dto::{ProfileRes, ProfileUpdateReq, SettingsRes, SettingsUpdateReq, SignupReq, SignupRes},
+    dto::{
+        IdentityLinkReq, IdentityListRes, ProfileRes, ProfileUpdateReq, SessionItem,
+        SessionListRes, SessionRenameReq, SetPasswordReq, SettingsRes, SettingsUpdateReq,
+        SignupReq, SignupRes, TermsConsentReq,
+    },
     service::UserService,
 };
+use crate::api::admin::header_utils::extract_user_agent;
+use crate::api::auth::dto::GoogleAuthUrlRes;
+use crate::api::auth::handler::oauth_link_cookie;
 use crate::extract::AppJson;
 use crate::{api::auth::extractor::AuthUser, error::AppResult, state::AppState};
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::{HeaderMap, StatusCode},
     Json,
 };
+use axum_extra::extract::cookie::CookieJar;
 
 // -------------------------------------------------------------------------
 // 1. 회원가입 (POST /users)
@@ -150,4 +158,252 @@ pub async fn update_settings(
     Ok(Json(settings))
 }
 
+// -------------------------------------------------------------------------
+// 6. 로그인 세션(기기) 목록 (GET /users/me/sessions)
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    get,
+    path = "/users/me/sessions",
+    tag = "user",
+    responses(
+        (status = 200, description = "활성 세션 목록 (current = 현재 기기)", body = SessionListRes, example = json!({
+            "items": [{
+                "session_id": "0f6c2f8e-3c1b-4b8e-9a55-1d2f6f7a9c10",
+                "device_name": null,
+                "device": "desktop",
+                "browser": "Chrome",
+                "os": "macOS",
+                "login_method": "email",
+                "country_code": "KR",
+                "network": "Korea Telecom",
+                "ip_masked": "203.0.113.*",
+                "current": true,
+                "signed_in_at": "2026-10-01T09:00:00Z",
+                "last_active_at": "2026-10-19T08:30:00Z",
+                "expires_at": "2026-10-31T09:00:00Z"
+            }]
+        })),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn list_sessions(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> AppResult<Json<SessionListRes>> {
+    let res = UserService::list_sessions(&st, auth_user.sub, &auth_user.session_id).await?;
+    Ok(Json(res))
+}
+
+// -------------------------------------------------------------------------
+// 7. 세션(기기) 이름 변경 (PATCH /users/me/sessions/{session_id})
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    patch,
+    path = "/users/me/sessions/{session_id}",
+    tag = "user",
+    params(("session_id" = String, Path, description = "세션 ID (UUID)")),
+    request_body = SessionRenameReq,
+    responses(
+        (status = 200, description = "이름 변경 성공", body = SessionItem),
+        (status = 400, description = "잘못된 요청", body = crate::error::ErrorBody),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody),
+        (status = 404, description = "본인의 활성 세션이 아님", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn rename_session(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(session_id): Path<String>,
+    AppJson(req): AppJson<SessionRenameReq>,
+) -> AppResult<Json<SessionItem>> {
+    let res =
+        UserService::rename_session(&st, auth_user.sub, &auth_user.session_id, &session_id, req)
+            .await?;
+    Ok(Json(res))
+}
+
+// -------------------------------------------------------------------------
+// 8. 세션 폐기 (DELETE /users/me/sessions/{session_id})
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    delete,
+    path = "/users/me/sessions/{session_id}",
+    tag = "user",
+    params(("session_id" = String, Path, description = "세션 ID (UUID)")),
+    responses(
+        (status = 204, description = "세션 폐기 성공 (해당 기기 즉시 로그아웃)"),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody),
+        (status = 404, description = "본인의 활성 세션이 아님", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn revoke_session(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+) -> AppResult<StatusCode> {
+    let ua = extract_user_agent(&headers);
+    UserService::revoke_session(&st, auth_user.sub, &session_id, ua).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// -------------------------------------------------------------------------
+// 9. 로그인 수단 현황 (GET /users/me/identities)
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    get,
+    path = "/users/me/identities",
+    tag = "user",
+    responses(
+        (status = 200, description = "비밀번호 여부 / 패스키 수 / 연결된 소셜 계정", body = IdentityListRes),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn list_identities(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+) -> AppResult<Json<IdentityListRes>> {
+    let res = UserService::list_identities(&st, auth_user.sub).await?;
+    Ok(Json(res))
+}
+
+// -------------------------------------------------------------------------
+// 10. 소셜 계정 연결 시작 — 웹 (POST /users/me/identities/{provider}/start)
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    post,
+    path = "/users/me/identities/{provider}/start",
+    tag = "user",
+    params(("provider" = String, Path, description = "google / kakao / naver / sso (계정 이메일 도메인의 기관 SSO)")),
+    responses(
+        (status = 200, description = "Provider 인증 URL (완료 후 /settings/security 로 리다이렉트)", body = GoogleAuthUrlRes),
+        (status = 400, description = "지원하지 않는 provider", body = crate::error::ErrorBody),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody),
+        (status = 403, description = "재인증 필요 (AUTH_403_REAUTH_REQUIRED)", body = crate::error::ErrorBody),
+        (status = 404, description = "sso: 계정 이메일 도메인에 활성 기관 SSO 없음", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn link_identity_start(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(provider): Path<String>,
+    jar: CookieJar,
+) -> AppResult<(CookieJar, Json<GoogleAuthUrlRes>)> {
+    let (auth_url, binding) =
+        UserService::link_identity_start(&st, auth_user.sub, &auth_user.session_id, &provider)
+            .await?;
+    // 콜백이 같은 브라우저에서 돌아왔는지 확인하는 바인딩 쿠키 (state 와 같은 수명)
+    let jar = jar.add(oauth_link_cookie(&st, binding, st.cfg.oauth_state_ttl_sec));
+    Ok((jar, Json(GoogleAuthUrlRes { auth_url })))
+}
+
+// -------------------------------------------------------------------------
+// 11. 소셜 계정 연결 — 모바일 ID token (POST /users/me/identities/{provider})
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    post,
+    path = "/users/me/identities/{provider}",
+    tag = "user",
+    params(("provider" = String, Path, description = "google / apple / kakao")),
+    request_body = IdentityLinkReq,
+    responses(
+        (status = 204, description = "연결 성공 (보안 알림 메일 발송)"),
+        (status = 400, description = "잘못된 요청 / 지원하지 않는 provider", body = crate::error::ErrorBody),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody),
+        (status = 403, description = "재인증 필요 (AUTH_403_REAUTH_REQUIRED)", body = crate::error::ErrorBody),
+        (status = 409, description = "이미 연결됨 또는 다른 계정에 연결된 소셜 계정", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn link_identity(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(provider): Path<String>,
+    AppJson(req): AppJson<IdentityLinkReq>,
+) -> AppResult<StatusCode> {
+    UserService::link_identity(&st, auth_user.sub, &auth_user.session_id, &provider, req).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// -------------------------------------------------------------------------
+// 12. 소셜 계정 연결 해제 (DELETE /users/me/identities/{provider})
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    delete,
+    path = "/users/me/identities/{provider}",
+    tag = "user",
+    params(("provider" = String, Path, description = "google / apple / kakao / naver")),
+    responses(
+        (status = 204, description = "연결 해제 성공 (보안 알림 메일 발송)"),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody),
+        (status = 404, description = "연결되지 않은 provider", body = crate::error::ErrorBody),
+        (status = 409, description = "마지막 로그인 수단 (LAST_LOGIN_METHOD)", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn unlink_identity(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(provider): Path<String>,
+) -> AppResult<StatusCode> {
+    UserService::unlink_identity(&st, auth_user.sub, &provider).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// -------------------------------------------------------------------------
+// 13. 비밀번호 설정 — 소셜 전용 계정 (PUT /users/me/password)
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    put,
+    path = "/users/me/password",
+    tag = "user",
+    request_body = SetPasswordReq,
+    responses(
+        (status = 204, description = "비밀번호 설정 성공 (보안 알림 메일 발송)"),
+        (status = 400, description = "잘못된 요청", body = crate::error::ErrorBody),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody),
+        (status = 403, description = "재인증 필요 (AUTH_403_REAUTH_REQUIRED)", body = crate::error::ErrorBody),
+        (status = 409, description = "이미 비밀번호가 있음 (재설정 흐름 사용)", body = crate::error::ErrorBody),
+        (status = 422, description = "비밀번호 정책 위반", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn set_password(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    AppJson(req): AppJson<SetPasswordReq>,
+) -> AppResult<StatusCode> {
+    UserService::set_password(&st, auth_user.sub, &auth_user.session_id, req).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// -------------------------------------------------------------------------
+// 14. 약관 동의 — 동의 없이 생성된 계정 (PUT /users/me/terms)
+// -------------------------------------------------------------------------
+#[utoipa::path(
+    put,
+    path = "/users/me/terms",
+    tag = "user",
+    request_body = TermsConsentReq,
+    responses(
+        (status = 204, description = "약관 동의 기록 (프로필 terms_accepted = true)"),
+        (status = 400, description = "두 약관 모두 동의하지 않음", body = crate::error::ErrorBody),
+        (status = 401, description = "인증 실패", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = []))
+)]
+pub async fn accept_terms(
+    State(st): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    AppJson(req): AppJson<TermsConsentReq>,
+) -> AppResult<StatusCode> {
+    UserService::accept_terms(&st, auth_user.sub, req).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 use crate::api::util::extract_client_ip;
@@ -57,7 +57,8 @@ pub async fn signup_tx(
             user_auth,
             user_created_at as created_at,
             (user_password IS NOT NULL) as has_password,
-            user_mfa_enabled as mfa_enabled
+            user_mfa_enabled as mfa_enabled,
+            (user_terms_service AND user_terms_personal) as terms_accepted
     "#,
     )
     .bind(email)
@@ -166,7 +167,8 @@ pub async fn find_user_by_email_idx(
             user_auth,
             user_created_at as created_at,
             (user_password IS NOT NULL) as has_password,
-            user_mfa_enabled as mfa_enabled
+            user_mfa_enabled as mfa_enabled,
+            (user_terms_service AND user_terms_personal) as terms_accepted
         FROM users
         WHERE user_email_idx = $1
     "#,
@@ -194,7 +196,8 @@ pub async fn find_user_by_nickname(pool: &PgPool, nickname: &str) -> AppResult<O
             user_auth,
             user_created_at as created_at,
             (user_password IS NOT NULL) as has_password,
-            user_mfa_enabled as mfa_enabled
+            user_mfa_enabled as mfa_enabled,
+            (user_terms_service AND user_terms_personal) as terms_accepted
         FROM users
         WHERE user_nickname = $1
     "#,
@@ -223,7 +226,8 @@ pub async fn find_user(pool: &PgPool, user_id: i64) -> AppResult<Option<ProfileR
             user_auth,
             user_created_at as created_at,
             (user_password IS NOT NULL) as has_password,
-            user_mfa_enabled as mfa_enabled
+            user_mfa_enabled as mfa_enabled,
+            (user_terms_service AND user_terms_personal) as terms_accepted
         FROM users
         WHERE user_id = $1
     "#,
@@ -275,7 +279,8 @@ pub async fn update_profile_tx(
             user_auth,
             user_created_at as created_at,
             (user_password IS NOT NULL) as has_password,
-            user_mfa_enabled as mfa_enabled
+            user_mfa_enabled as mfa_enabled,
+            (user_terms_service AND user_terms_personal) as terms_accepted
     "#,
     )
     .bind(user_id)
@@ -386,7 +391,7 @@ struct UserLogSource {
     user_nickname: String,
     user_language: String,
     user_country: String,
-    user_birthday: String,
+    user_birthday: Option<String>,
     user_gender: String,
     user_terms_service: bool,
     user_terms_personal: bool,
@@ -437,11 +442,14 @@ pub async fn insert_user_log_after_tx(
     let email_plain = crypto.decrypt(&u.user_email, "users.user_email")?;
     let masked_email = mask_email(&email_plain);
 
-    let decrypted_birthday = crypto.decrypt(&u.user_birthday, "users.user_birthday")?;
-    let birthday_masked: Option<String> =
-        NaiveDate::parse_from_str(&decrypted_birthday, "%Y-%m-%d")
-            .ok()
-            .map(|d| format!("{}-**-**", d.year()));
+    let decrypted_birthday = u
+        .user_birthday
+        .as_deref()
+        .map(|b| crypto.decrypt(b, "users.user_birthday"))
+        .transpose()?;
+    let birthday_masked: Option<String> = decrypted_birthday
+        .and_then(|b| NaiveDate::parse_from_str(&b, "%Y-%m-%d").ok())
+        .map(|d| format!("{}-**-**", d.year()));
 
     sqlx::query(
         r#"
@@ -584,3 +592,258 @@ pub async fn create_admin_user(
 
     Ok(user_id)
 }
+
+// =========================================================================
+// Sessions (로그인 기기)
+// =========================================================================
+
+/// 활성 세션 행. `login_ip` 는 암호문 그대로 — 서비스에서 복호화 후 마스킹한다.
+#[derive(Debug, sqlx::FromRow)]
+pub struct SessionRow {
+    pub session_id: String,
+    pub device_name: Option<String>,
+    pub device: String,
+    pub browser: Option<String>,
+    pub os: Option<String>,
+    pub login_method: String,
+    pub country_code: Option<String>,
+    pub network: Option<String>,
+    pub login_ip: Option<String>,
+    pub signed_in_at: DateTime<Utc>,
+    pub last_active_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// 조회 불가 지역 정보('LC'/'local' — insert_login_record_tx 기본값)는 NULL 로 노출
+const SESSION_SELECT: &str = r#"
+    SELECT
+        login_session_id::text AS session_id,
+        login_device_name AS device_name,
+        login_device::text AS device,
+        login_browser AS browser,
+        login_os AS os,
+        login_method::text AS login_method,
+        NULLIF(TRIM(login_country), 'LC') AS country_code,
+        NULLIF(login_org, 'local') AS network,
+        login_ip,
+        login_begin_at AS signed_in_at,
+        login_active_at AS last_active_at,
+        login_expire_at AS expires_at
+    FROM public.login
+    WHERE user_id = $1
+      AND login_state = 'active'::login_state_enum
+      AND login_expire_at > now()
+"#;
+
+/// 사용자의 활성 세션 목록 (최근 활동 순)
+pub async fn find_active_sessions(pool: &PgPool, user_id: i64) -> AppResult<Vec<SessionRow>> {
+    let rows = sqlx::query_as::<_, SessionRow>(&format!(
+        "{SESSION_SELECT} ORDER BY COALESCE(login_active_at, login_begin_at) DESC"
+    ))
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// 사용자의 활성 세션 단건
+pub async fn find_active_session(
+    pool: &PgPool,
+    user_id: i64,
+    session_id: &str,
+) -> AppResult<Option<SessionRow>> {
+    let row = sqlx::query_as::<_, SessionRow>(&format!(
+        "{SESSION_SELECT} AND login_session_id = CAST($2 AS uuid)"
+    ))
+    .bind(user_id)
+    .bind(session_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row)
+}
+
+/// 세션 기기 이름 변경 (본인 활성 세션만). 반환 = 변경 여부
+pub async fn update_session_device_name(
+    pool: &PgPool,
+    user_id: i64,
+    session_id: &str,
+    device_name: &str,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE public.login
+        SET login_device_name = $3,
+            login_updated_at = now()
+        WHERE user_id = $1
+          AND login_session_id = CAST($2 AS uuid)
+          AND login_state = 'active'::login_state_enum
+          AND login_expire_at > now()
+    "#,
+    )
+    .bind(user_id)
+    .bind(session_id)
+    .bind(device_name)
+    .execute(pool)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+// =========================================================================
+// Identities (연결된 로그인 수단)
+// =========================================================================
+
+/// 연결된 소셜 계정 행. `oauth_email` 은 암호문 그대로 — 서비스에서 복호화 후 마스킹한다.
+#[derive(Debug, sqlx::FromRow)]
+pub struct IdentityRow {
+    pub identity_id: i64,
+    pub provider: String,
+    pub oauth_email: Option<String>,
+    pub oauth_name: Option<String>,
+    pub linked_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+const IDENTITY_SELECT: &str = r#"
+    SELECT
+        user_oauth_id AS identity_id,
+        oauth_provider::text AS provider,
+        oauth_email,
+        oauth_name,
+        oauth_linked_at AS linked_at,
+        oauth_last_login_at AS last_login_at
+    FROM users_oauth
+    WHERE user_id = $1
+"#;
+
+/// 사용자의 연결된 소셜 계정 목록 (연결 순)
+pub async fn find_identities(pool: &PgPool, user_id: i64) -> AppResult<Vec<IdentityRow>> {
+    let rows = sqlx::query_as::<_, IdentityRow>(&format!(
+        "{IDENTITY_SELECT} ORDER BY oauth_linked_at, user_oauth_id"
+    ))
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// 남은 로그인 수단 개수 (마지막 수단 제거 방지용)
+#[derive(Debug, Clone, Copy, Default, sqlx::FromRow)]
+pub struct LoginMethodCounts {
+    pub has_password: bool,
+    pub identities: i64,
+    pub passkeys: i64,
+}
+
+impl LoginMethodCounts {
+    pub fn total(&self) -> i64 {
+        i64::from(self.has_password) + self.identities + self.passkeys
+    }
+}
+
+const LOGIN_METHOD_COUNTS_SELECT: &str = r#"
+    SELECT
+        (u.user_password IS NOT NULL) AS has_password,
+        (SELECT COUNT(*) FROM users_oauth o WHERE o.user_id = u.user_id) AS identities,
+        (SELECT COUNT(*) FROM user_passkey p WHERE p.user_id = u.user_id) AS passkeys
+    FROM users u
+    WHERE u.user_id = $1
+"#;
+
+/// 로그인 수단 개수 조회 (읽기 전용 — 목록 화면용, 잠금 없음)
+pub async fn find_login_method_counts(
+    pool: &PgPool,
+    user_id: i64,
+) -> AppResult<Option<LoginMethodCounts>> {
+    let row = sqlx::query_as::<_, LoginMethodCounts>(LOGIN_METHOD_COUNTS_SELECT)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row)
+}
+
+/// 로그인 수단 개수 조회 + users 행 잠금 (동시 해제 요청끼리 마지막 수단을 함께 지우지 않도록)
+pub async fn lock_login_method_counts_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: i64,
+) -> AppResult<Option<LoginMethodCounts>> {
+    let row = sqlx::query_as::<_, LoginMethodCounts>(&format!(
+        "{LOGIN_METHOD_COUNTS_SELECT} FOR UPDATE OF u"
+    ))
+    .bind(user_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(row)
+}
+
+/// provider 의 소셜 계정 연결 해제 (본인 연결만). 반환 = 삭제된 행
+pub async fn delete_identities_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: i64,
+    provider: &str,
+) -> AppResult<Vec<IdentityRow>> {
+    let rows = sqlx::query_as::<_, IdentityRow>(
+        r#"
+        DELETE FROM users_oauth
+        WHERE user_id = $1
+          AND oauth_provider::text = $2
+        RETURNING
+            user_oauth_id AS identity_id,
+            oauth_provider::text AS provider,
+            oauth_email,
+            oauth_name,
+            oauth_linked_at AS linked_at,
+            oauth_last_login_at AS last_login_at
+    "#,
+    )
+    .bind(user_id)
+    .bind(provider)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    Ok(rows)
+}
+
+/// 서비스 이용약관 + 개인정보 처리방침 동의 기록
+pub async fn accept_terms_tx(tx: &mut Transaction<'_, Postgres>, user_id: i64) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE users
+        SET user_terms_service = true,
+            user_terms_personal = true
+        WHERE user_id = $1
+    "#,
+    )
+    .bind(user_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// 소셜 전용 계정에 비밀번호 설정 (이미 있으면 변경하지 않음). 반환 = 설정 여부
+pub async fn set_password_if_unset_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: i64,
+    password_hash: &str,
+) -> AppResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE users
+        SET user_password = $2
+        WHERE user_id = $1
+          AND user_password IS NULL
+    "#,
+    )
+    .bind(user_id)
+    .bind(password_hash)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
@@ -1,7 +1,11 @@
-use super::handler::{get_me, get_settings, signup, update_me, update_settings};
+use super::handler::{
+    accept_terms, get_me, get_settings, link_identity, link_identity_start, list_identities,
+    list_sessions, rename_session, revoke_session, set_password, signup, unlink_identity,
+    update_me, update_settings,
+};
 use crate::state::AppState;
 use axum::{
-    routing::{get, post},
+    routing::{get, patch, post, put},
     Router,
 };
 
@@ -14,4 +18,20 @@ pub fn user_router() -> Router<AppState> {
             "/users/me/settings",
             get(get_settings).post(update_settings),
         )
+        .route("/users/me/sessions", get(list_sessions))
+        .route(
+            "/users/me/sessions/{session_id}",
+            patch(rename_session).delete(revoke_session),
+        )
+        .route("/users/me/identities", get(list_identities))
+        .route(
+            "/users/me/identities/{provider}",
+            post(link_identity).delete(unlink_identity),
+        )
+        .route(
+            "/users/me/identities/{provider}/start",
+            post(link_identity_start),
+        )
+        .route("/users/me/password", put(set_password))
+        .route("/users/me/terms", put(accept_terms))
 }
@@ -6,19 +6,26 @@ use hmac::{Hmac, Mac};
 use redis::AsyncCommands;
 use sha2::Sha256;
 use std::collections::HashSet;
+use std::net::IpAddr;
 use tracing::{info, warn};
+use uuid::Uuid;
 use validator::Validate;
 
 use super::{
-    dto::{ProfileRes, ProfileUpdateReq, SettingsRes, SettingsUpdateReq, SignupReq, SignupRes},
-    repo,
+    dto::{
+        IdentityItem, IdentityLinkReq, IdentityListRes, ProfileRes, ProfileUpdateReq, SessionItem,
+        SessionListRes, SessionRenameReq, SetPasswordReq, SettingsRes, SettingsUpdateReq,
+        SignupReq, SignupRes, TermsConsentReq,
+    },
+    repo::{self, IdentityRow, SessionRow},
 };
 use crate::{
-    api::auth::service::AuthService,
+    api::auth::service::{AuthService, OAuthProvider, SSO_PROVIDER},
     crypto::CryptoService,
     error::{AppError, AppResult},
     external::email::{self, EmailTemplate},
     state::AppState,
+    types::UserAuth,
 };
 
 type HmacSha256 = Hmac<Sha256>;
@@ -98,6 +105,11 @@ impl UserService {
         }
         req.language = lang;
 
+        // 1-3. 기관 SSO 전용 도메인은 일반 가입 불가 (기관 IdP 로그인 시 자동 가입)
+        if AuthService::is_sso_required(st, &req.email, UserAuth::Learner).await? {
+            return Err(AppError::Forbidden("AUTH_403_SSO_REQUIRED".into()));
+        }
+
         // [Step 2] Rate Limiting (Redis) — blind index로 키 생성 (이메일 PII 노출 방지)
         let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
         let email_idx = crypto.blind_index(&req.email)?;
@@ -403,6 +415,315 @@ impl UserService {
             crate::types::UserSetLanguage::db_to_frontend(&result.user_set_language);
         Ok(result)
     }
+
+    // =========================================================================
+    // 세션 (로그인 기기) 관리
+    // =========================================================================
+
+    /// 활성 세션 목록 — 요청한 access token 의 세션에 `current = true`
+    pub async fn list_sessions(
+        st: &AppState,
+        user_id: i64,
+        current_session_id: &str,
+    ) -> AppResult<SessionListRes> {
+        let rows = repo::find_active_sessions(&st.db, user_id).await?;
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+
+        let items = rows
+            .into_iter()
+            .map(|row| Self::session_item(&crypto, row, current_session_id))
+            .collect();
+        Ok(SessionListRes { items })
+    }
+
+    /// 세션 기기 이름 변경
+    pub async fn rename_session(
+        st: &AppState,
+        user_id: i64,
+        current_session_id: &str,
+        session_id: &str,
+        req: SessionRenameReq,
+    ) -> AppResult<SessionItem> {
+        req.validate()
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+        let device_name = req.device_name.trim();
+        if device_name.is_empty() {
+            return Err(AppError::BadRequest("device_name must not be blank".into()));
+        }
+        if Uuid::parse_str(session_id).is_err() {
+            return Err(AppError::NotFound);
+        }
+
+        if !repo::update_session_device_name(&st.db, user_id, session_id, device_name).await? {
+            return Err(AppError::NotFound);
+        }
+        let row = repo::find_active_session(&st.db, user_id, session_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        Ok(Self::session_item(&crypto, row, current_session_id))
+    }
+
+    /// 세션 폐기 (현재 기기 포함 — 현재 기기면 사실상 로그아웃)
+    pub async fn revoke_session(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        AuthService::revoke_session(st, user_id, session_id, user_agent).await
+    }
+
+    fn session_item(
+        crypto: &CryptoService,
+        row: SessionRow,
+        current_session_id: &str,
+    ) -> SessionItem {
+        // IP 복호화 실패는 목록 전체를 막지 않음 (마스킹 IP 만 생략)
+        let ip_masked = row
+            .login_ip
+            .as_deref()
+            .and_then(|enc| crypto.decrypt(enc, "login.login_ip").ok())
+            .and_then(|ip| Self::mask_ip(&ip));
+
+        SessionItem {
+            current: row.session_id == current_session_id,
+            session_id: row.session_id,
+            device_name: row.device_name,
+            device: row.device,
+            browser: row.browser,
+            os: row.os,
+            login_method: row.login_method,
+            country_code: row.country_code,
+            network: row.network,
+            ip_masked,
+            signed_in_at: row.signed_in_at,
+            last_active_at: row.last_active_at,
+            expires_at: row.expires_at,
+        }
+    }
+
+    /// IP 마스킹: IPv4 마지막 옥텟, IPv6 하위 64비트(인터페이스 ID)를 가린다.
+    /// 파싱 불가 값은 None.
+    fn mask_ip(ip: &str) -> Option<String> {
+        match ip.trim().parse::<IpAddr>().ok()? {
+            IpAddr::V4(v4) => {
+                let [a, b, c, _] = v4.octets();
+                Some(format!("{a}.{b}.{c}.*"))
+            }
+            IpAddr::V6(v6) => {
+                let seg = v6.segments();
+                Some(format!(
+                    "{:x}:{:x}:{:x}:{:x}:*",
+                    seg[0], seg[1], seg[2], seg[3]
+                ))
+            }
+        }
+    }
+
+    // =========================================================================
+    // 로그인 수단 (소셜 계정 연결 / 비밀번호 설정)
+    // =========================================================================
+
+    /// 로그인 수단 현황 — 비밀번호 여부, 패스키 수, 연결된 소셜 계정
+    pub async fn list_identities(st: &AppState, user_id: i64) -> AppResult<IdentityListRes> {
+        let counts = repo::find_login_method_counts(&st.db, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let items = repo::find_identities(&st.db, user_id)
+            .await?
+            .into_iter()
+            .map(|row| Self::identity_item(&crypto, row))
+            .collect();
+        Ok(IdentityListRes {
+            has_password: counts.has_password,
+            passkey_count: counts.passkeys,
+            items,
+        })
+    }
+
+    /// 소셜 계정 연결 시작 (웹 리다이렉트: google / kakao / naver / sso) — 완료 후 설정 화면으로 리다이렉트.
+    /// 재인증 필요. 반환 = (인증 URL, 브라우저 바인딩 쿠키 값)
+    pub async fn link_identity_start(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        provider: &str,
+    ) -> AppResult<(String, String)> {
+        // 기관 SSO 는 계정 이메일 도메인의 IdP 로 연결
+        if provider == SSO_PROVIDER {
+            AuthService::require_recent_auth(st, user_id, session_id).await?;
+            return AuthService::sso_link_start(st, user_id).await;
+        }
+        let provider = OAuthProvider::parse(provider)
+            .ok_or_else(|| AppError::BadRequest("OAUTH_PROVIDER_NOT_SUPPORTED".into()))?;
+        AuthService::require_recent_auth(st, user_id, session_id).await?;
+        AuthService::oauth_link_start(st, provider, user_id).await
+    }
+
+    /// 소셜 계정 연결 (모바일 ID token) — 재인증 필요
+    pub async fn link_identity(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        provider: &str,
+        req: IdentityLinkReq,
+    ) -> AppResult<()> {
+        req.validate()
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+        AuthService::require_recent_auth(st, user_id, session_id).await?;
+        AuthService::link_identity_with_id_token(st, user_id, provider, &req.id_token).await
+    }
+
+    /// 소셜 계정 연결 해제 — 마지막 로그인 수단이면 거부
+    pub async fn unlink_identity(st: &AppState, user_id: i64, provider: &str) -> AppResult<()> {
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let mut tx = st.db.begin().await?;
+
+        let counts = repo::lock_login_method_counts_tx(&mut tx, user_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let removed = repo::delete_identities_tx(&mut tx, user_id, provider).await?;
+        if removed.is_empty() {
+            return Err(AppError::NotFound);
+        }
+        if counts.total() - removed.len() as i64 <= 0 {
+            return Err(AppError::Conflict("LAST_LOGIN_METHOD".into()));
+        }
+        repo::insert_user_log_after_tx(&mut tx, &crypto, Some(user_id), user_id, "update", true)
+            .await?;
+        tx.commit().await?;
+
+        info!(
+            user_id,
+            provider, "OAuth identity unlinked from account settings"
+        );
+        let email = removed
+            .first()
+            .and_then(|row| row.oauth_email.as_deref())
+            .and_then(|enc| crypto.decrypt(enc, "user_oauth.oauth_email").ok());
+        Self::send_security_notice(
+            st,
+            user_id,
+            "소셜 계정 연결 해제",
+            &Self::identity_label(provider, email.as_deref()),
+        )
+        .await;
+        Ok(())
+    }
+
+    /// 소셜 전용 계정에 비밀번호 설정 (이미 있으면 409 — 변경은 재설정 흐름 사용). 재인증 필요
+    pub async fn set_password(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        req: SetPasswordReq,
+    ) -> AppResult<()> {
+        req.validate()
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+        AuthService::require_recent_auth(st, user_id, session_id).await?;
+        if !Self::validate_password_policy(&req.new_password) {
+            return Err(AppError::Unprocessable(
+                "AUTH_422_PASSWORD_POLICY_VIOLATION".into(),
+            ));
+        }
+        let password_hash = crate::api::auth::password::hash_password(&req.new_password)?;
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let mut tx = st.db.begin().await?;
+        if !repo::set_password_if_unset_tx(&mut tx, user_id, &password_hash).await? {
+            return Err(AppError::Conflict("PASSWORD_ALREADY_SET".into()));
+        }
+        repo::insert_user_log_after_tx(&mut tx, &crypto, Some(user_id), user_id, "update", true)
+            .await?;
+        tx.commit().await?;
+
+        info!(user_id, "Password set on OAuth-only account");
+        Self::send_security_notice(st, user_id, "비밀번호 설정", "이메일 + 비밀번호 로그인").await;
+        Ok(())
+    }
+
+    /// 약관 동의 — 두 약관 모두 동의해야 한다
+    pub async fn accept_terms(st: &AppState, user_id: i64, req: TermsConsentReq) -> AppResult<()> {
+        if !req.terms_service || !req.terms_personal {
+            return Err(AppError::BadRequest("Terms must be accepted".into()));
+        }
+
+        let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+        let mut tx = st.db.begin().await?;
+        repo::accept_terms_tx(&mut tx, user_id).await?;
+        repo::insert_user_log_after_tx(&mut tx, &crypto, Some(user_id), user_id, "update", true)
+            .await?;
+        tx.commit().await?;
+
+        info!(user_id, "Terms accepted");
+        Ok(())
+    }
+
+    /// 보안 설정 변경 알림 메일 (best-effort — 발송 실패해도 변경은 유지)
+    pub async fn send_security_notice(st: &AppState, user_id: i64, action: &str, detail: &str) {
+        let Some(email_sender) = st.email.as_ref() else {
+            return;
+        };
+        let result = async {
+            let user = repo::find_user(&st.db, user_id)
+                .await?
+                .ok_or(AppError::NotFound)?;
+            let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+            let email_plain = crypto.decrypt(&user.email, "users.user_email")?;
+            email::send_templated(
+                email_sender.as_ref(),
+                &email_plain,
+                EmailTemplate::SecurityNotice {
+                    action: action.to_string(),
+                    detail: detail.to_string(),
+                    changed_at: chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string(),
+                },
+            )
+            .await
+        }
+        .await;
+        if let Err(e) = result {
+            warn!(error = %e, user_id, "Failed to send security notice email");
+        }
+    }
+
+    /// 알림 메일 표시용 ("Google (us***@gmail.com)" / "Apple")
+    pub fn identity_label(provider: &str, email: Option<&str>) -> String {
+        let name = match provider {
+            "google" => "Google",
+            "apple" => "Apple",
+            "kakao" => "카카오",
+            "naver" => "네이버",
+            "sso" => "기관 SSO",
+            other => other,
+        };
+        match email {
+            Some(email) => format!("{name} ({})", AuthService::mask_email(email)),
+            None => name.to_string(),
+        }
+    }
+
+    fn identity_item(crypto: &CryptoService, row: IdentityRow) -> IdentityItem {
+        // 복호화 실패는 목록 전체를 막지 않음 (이메일만 생략)
+        let email_masked = row
+            .oauth_email
+            .as_deref()
+            .and_then(|enc| crypto.decrypt(enc, "user_oauth.oauth_email").ok())
+            .map(|email| AuthService::mask_email(&email));
+
+        IdentityItem {
+            identity_id: row.identity_id,
+            provider: row.provider,
+            email_masked,
+            name: row.oauth_name,
+            linked_at: row.linked_at,
+            last_login_at: row.last_login_at,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -533,4 +854,53 @@ mod tests {
         assert!(!UserService::is_valid_birthday(ymd(2026, 5, 11), today));
         assert!(!UserService::is_valid_birthday(ymd(2030, 1, 1), today));
     }
+
+    // ------------------------------------------------------------------------
+    // mask_ip: 세션 목록의 대략적 IP
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_mask_ip_hides_host_part() {
+        assert_eq!(
+            UserService::mask_ip("203.0.113.57").as_deref(),
+            Some("203.0.113.*")
+        );
+        assert_eq!(
+            UserService::mask_ip("2001:db8:85a3:1:8a2e:370:7334:1").as_deref(),
+            Some("2001:db8:85a3:1:*")
+        );
+        assert_eq!(UserService::mask_ip("not-an-ip"), None);
+    }
+
+    // ------------------------------------------------------------------------
+    // 로그인 수단 (identity)
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_identity_label_masks_email() {
+        assert_eq!(
+            UserService::identity_label("google", Some("user@gmail.com")),
+            "Google (us***@gmail.com)"
+        );
+        assert_eq!(UserService::identity_label("apple", None), "Apple");
+        assert_eq!(UserService::identity_label("kakao", None), "카카오");
+        assert_eq!(UserService::identity_label("sso", None), "기관 SSO");
+    }
+
+    #[test]
+    fn test_login_method_counts_total() {
+        let oauth_only = repo::LoginMethodCounts {
+            has_password: false,
+            identities: 1,
+            passkeys: 0,
+        };
+        assert_eq!(oauth_only.total(), 1, "마지막 수단 → 해제 거부 대상");
+
+        let mixed = repo::LoginMethodCounts {
+            has_password: true,
+            identities: 2,
+            passkeys: 1,
+        };
+        assert_eq!(mixed.total(), 4);
+    }
 }
@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 use validator::Validate;
 
 use crate::api::admin::translation::dto::TranslationMeta;
-use crate::types::SupportedLanguage;
+use crate::types::{StudyTaskKind, SupportedLanguage, VideoAccess, VideoHostKind};
 
 // =====================================================================
 // Request DTOs (요청)
@@ -55,6 +55,26 @@ pub struct IdParam {
     pub id: i64,
 }
 
+/// 자막 트랙 경로 파라미터 (`/videos/{id}/subtitles/{lang}`)
+#[derive(Debug, Deserialize)]
+pub struct SubtitlePathParam {
+    pub id: i64,
+    pub lang: SupportedLanguage,
+}
+
+/// HLS 파일 경로 파라미터 (`/videos/{id}/hls/{*file}`)
+#[derive(Debug, Deserialize)]
+pub struct HlsFilePathParam {
+    pub id: i64,
+    pub file: String,
+}
+
+/// HLS 서명 토큰 (Query String)
+#[derive(Debug, Deserialize)]
+pub struct HlsTokenQuery {
+    pub token: String,
+}
+
 /// 비디오 상세 조회 요청 (Query String) — 번역 언어 파라미터
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -64,20 +84,38 @@ pub struct VideoDetailReq {
 }
 
 /// 학습 진도 업데이트 요청
+///
+/// 진도율·완료는 `segments` 로 보고된 실제 시청 구간 커버리지로 계산한다.
+/// `progress_rate` 는 영상 길이(video_duration) 미상일 때만 참고값으로 사용 (완료 처리 불가).
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "snake_case")]
 #[schema(example = json!({
-    "progress_rate": 80,
-    "watch_duration_sec": 120
+    "segments": [{ "start_sec": 0, "end_sec": 15 }],
+    "watch_duration_sec": 15
 }))]
 pub struct VideoProgressUpdateReq {
     #[validate(range(min = 0, max = 100))]
+    #[serde(default)]
     pub progress_rate: i32,
 
     /// 이번 세션에서 시청한 시간 (초) - 누적됨
     #[validate(range(min = 0))]
     #[serde(default)]
     pub watch_duration_sec: i32,
+
+    /// 직전 보고 이후 시청한 구간 (초, `[start_sec, end_sec)`)
+    #[validate(length(max = 50), nested)]
+    #[serde(default)]
+    pub segments: Vec<WatchSegment>,
+}
+
+/// 시청 구간 `[start_sec, end_sec)`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Validate, ToSchema)]
+pub struct WatchSegment {
+    #[validate(range(min = 0))]
+    pub start_sec: i32,
+    #[validate(range(min = 1))]
+    pub end_sec: i32,
 }
 
 // =====================================================================
@@ -153,7 +191,9 @@ pub struct VideoTagDetail {
 #[serde(rename_all = "snake_case")]
 pub struct VideoDetailRes {
     pub video_id: i64,
-    pub video_url_vimeo: String,
+    pub video_host: VideoHostKind,
+    /// vimeo 호스트 임베드 URL (hls 비디오는 `/videos/{id}/playback` 사용)
+    pub video_url_vimeo: Option<String>,
     pub video_state: String,
 
     /// 비디오 제목 (Q1c B 이후 video 테이블 자체 컬럼. ?lang= 지정 시
@@ -175,6 +215,87 @@ pub struct VideoDetailRes {
     #[sqlx(skip)]
     #[serde(default)]
     pub translation_meta: TranslationMeta,
+
+    /// 제공 가능한 자막 트랙 (업로드 트랙 + ko 큐 번역 완료 언어) — 서비스 계층에서 주입
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub subtitles: Vec<VideoSubtitleTrack>,
+    /// 퀴즈 큐 포인트 (시간순) — 서비스 계층에서 주입
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub cue_points: Vec<VideoCuePoint>,
+}
+
+/// 영상 퀴즈 큐 포인트 — `time_ms` 에 일시정지 후 `/studies/tasks/{study_task_id}` 출제,
+/// 답안은 `POST /studies/tasks/{id}/answer?video_cue_point_id=` 로 제출
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VideoCuePoint {
+    pub cue_point_id: i64,
+    pub time_ms: i32,
+    pub study_task_id: i32,
+    pub kind: StudyTaskKind,
+    /// false 면 제출 전 재생 재개 불가
+    pub skippable: bool,
+}
+
+/// 재생 정보 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VideoPlaybackRes {
+    pub video_id: i64,
+    pub host: VideoHostKind,
+    /// vimeo: 임베드 URL / hls: 사용자·세션 바인딩 서명 master 재생목록 URL
+    pub url: String,
+    /// 서명 URL 만료 시각 (hls 만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// 재생 원본 행 (open 비디오)
+#[derive(Debug, Clone, FromRow)]
+pub struct VideoPlaybackRow {
+    pub video_access: VideoAccess,
+    pub video_host: VideoHostKind,
+    pub video_url_vimeo: Option<String>,
+    pub video_hls_path: Option<String>,
+    pub video_duration: Option<i32>,
+}
+
+/// 자막 트랙 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SubtitleTrackSource {
+    /// SRT/VTT 로 직접 업로드된 트랙
+    Uploaded,
+    /// ko 트랙 큐 타이밍 + approved 번역으로 조립된 트랙
+    Translated,
+}
+
+/// 자막 트랙 (WebVTT 는 `url` 에서 제공)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VideoSubtitleTrack {
+    pub lang: SupportedLanguage,
+    pub label: Option<String>,
+    pub source: SubtitleTrackSource,
+    /// 예: `/videos/12/subtitles/zh-CN`
+    pub url: String,
+}
+
+/// 업로드 트랙 요약 행
+#[derive(Debug, Clone, FromRow)]
+pub struct SubtitleTrackRow {
+    pub lang: SupportedLanguage,
+    pub subtitle_label: Option<String>,
+}
+
+/// 트랙 큐 행 (번역 트랙은 cue_text 가 번역문 — 누락 시 None)
+#[derive(Debug, Clone, FromRow)]
+pub struct SubtitleCueRow {
+    pub cue_start_ms: i32,
+    pub cue_end_ms: i32,
+    pub cue_text: Option<String>,
 }
 
 // ----------------------
@@ -199,6 +320,10 @@ pub struct VideoProgressRes {
     /// 총 누적 시청 시간 (초)
     #[sqlx(rename = "video_watch_duration_sec")]
     pub watch_duration_sec: i32,
+
+    /// 실제 시청한 고유 구간 길이 (초, 반복 시청 제외)
+    #[sqlx(rename = "video_watched_sec")]
+    pub watched_sec: i32,
 }
 
 #[cfg(test)]
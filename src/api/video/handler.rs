@@ -1,5 +1,7 @@
 use crate::extract::AppJson;
 use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::IntoResponse;
 use axum::Json;
 
 use crate::api::auth::extractor::AuthUser;
@@ -7,8 +9,8 @@ use crate::error::AppResult;
 use crate::state::AppState;
 
 use super::dto::{
-    IdParam, VideoDetailReq, VideoDetailRes, VideoListReq, VideoListRes, VideoProgressRes,
-    VideoProgressUpdateReq,
+    HlsFilePathParam, HlsTokenQuery, IdParam, SubtitlePathParam, VideoDetailReq, VideoDetailRes,
+    VideoListReq, VideoListRes, VideoPlaybackRes, VideoProgressRes, VideoProgressUpdateReq,
 };
 use super::service::VideoService;
 
@@ -68,6 +70,129 @@ pub async fn get_video_detail(
     Ok(Json(video))
 }
 
+/// 자막 트랙 (WebVTT)
+#[utoipa::path(
+    get,
+    path = "/videos/{id}/subtitles/{lang}",
+    params(
+        ("id" = i64, Path, description = "Video ID"),
+        ("lang" = String, Path, description = "자막 언어 (ko, en, ja, zh-CN ...)")
+    ),
+    responses(
+        (status = 200, description = "WebVTT subtitle track", body = String, content_type = "text/vtt"),
+        (status = 404, description = "Video or Track Not Found", body = crate::error::ErrorBody)
+    ),
+    tag = "videos"
+)]
+pub async fn get_video_subtitle(
+    State(state): State<AppState>,
+    Path(SubtitlePathParam { id, lang }): Path<SubtitlePathParam>,
+) -> AppResult<impl IntoResponse> {
+    let vtt = VideoService::get_subtitle_vtt(&state, id, lang).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/vtt; charset=utf-8"),
+            (header::CACHE_CONTROL, "public, max-age=300"),
+        ],
+        vtt,
+    ))
+}
+
+/// 재생 정보 (vimeo 임베드 URL / hls 서명 재생목록 URL)
+#[utoipa::path(
+    get,
+    path = "/videos/{id}/playback",
+    params(
+        ("id" = i64, Path, description = "Video ID")
+    ),
+    responses(
+        (status = 200, description = "Playback info", body = VideoPlaybackRes),
+        (status = 401, description = "Unauthorized", body = crate::error::ErrorBody),
+        (status = 403, description = "Not entitled", body = crate::error::ErrorBody),
+        (status = 404, description = "Video Not Found", body = crate::error::ErrorBody),
+        (status = 503, description = "HLS host not configured", body = crate::error::ErrorBody)
+    ),
+    security(("bearerAuth" = [])),
+    tag = "videos"
+)]
+pub async fn get_video_playback(
+    State(state): State<AppState>,
+    AuthUser(auth_user): AuthUser,
+    Path(IdParam { id }): Path<IdParam>,
+) -> AppResult<Json<VideoPlaybackRes>> {
+    let res = VideoService::get_playback(&state, auth_user.sub, &auth_user.session_id, id).await?;
+    Ok(Json(res))
+}
+
+/// 자체 HLS 재생목록/세그먼트 (서명 토큰)
+#[utoipa::path(
+    get,
+    path = "/videos/{id}/hls/{file}",
+    params(
+        ("id" = i64, Path, description = "Video ID"),
+        ("file" = String, Path, description = "비디오 디렉터리 기준 경로 (예: 720p/index.m3u8)"),
+        ("token" = String, Query, description = "서명 토큰")
+    ),
+    responses(
+        (status = 200, description = "Playlist (URI 서명 재작성) or segment", body = Vec<u8>, content_type = "application/octet-stream"),
+        (status = 401, description = "Session expired", body = crate::error::ErrorBody),
+        (status = 403, description = "Invalid/expired token or not entitled", body = crate::error::ErrorBody),
+        (status = 404, description = "Not Found", body = crate::error::ErrorBody)
+    ),
+    tag = "videos"
+)]
+pub async fn get_video_hls_file(
+    State(state): State<AppState>,
+    Path(HlsFilePathParam { id, file }): Path<HlsFilePathParam>,
+    Query(HlsTokenQuery { token }): Query<HlsTokenQuery>,
+) -> AppResult<impl IntoResponse> {
+    let (content_type, body) = VideoService::get_hls_file(&state, id, &file, &token).await?;
+    // 재생목록은 사용자별 서명 URL 을 담으므로 캐시 금지
+    let cache_control = if file.ends_with(".m3u8") {
+        "private, no-store"
+    } else {
+        "private, max-age=3600"
+    };
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, cache_control),
+        ],
+        body,
+    ))
+}
+
+/// 자체 HLS AES-128 키 (엔타이틀먼트 확인)
+#[utoipa::path(
+    get,
+    path = "/videos/{id}/hls-key",
+    params(
+        ("id" = i64, Path, description = "Video ID"),
+        ("token" = String, Query, description = "서명 토큰")
+    ),
+    responses(
+        (status = 200, description = "16-byte AES-128 key", body = Vec<u8>, content_type = "application/octet-stream"),
+        (status = 401, description = "Session expired", body = crate::error::ErrorBody),
+        (status = 403, description = "Invalid/expired token or not entitled", body = crate::error::ErrorBody),
+        (status = 404, description = "Not Found", body = crate::error::ErrorBody)
+    ),
+    tag = "videos"
+)]
+pub async fn get_video_hls_key(
+    State(state): State<AppState>,
+    Path(IdParam { id }): Path<IdParam>,
+    Query(HlsTokenQuery { token }): Query<HlsTokenQuery>,
+) -> AppResult<impl IntoResponse> {
+    let key = VideoService::get_hls_key(&state, id, &token).await?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/octet-stream"),
+            (header::CACHE_CONTROL, "private, no-store"),
+        ],
+        key,
+    ))
+}
+
 /// 내 학습 진도 조회
 #[utoipa::path(
     get,
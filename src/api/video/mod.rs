@@ -3,3 +3,5 @@ pub mod handler;
 pub mod repo;
 pub mod router;
 pub mod service;
+pub mod subtitle;
+pub mod watch;
@@ -1,7 +1,12 @@
-use sqlx::{PgPool, QueryBuilder, Row};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row, Transaction};
 
-use crate::api::video::dto::{VideoDetailRes, VideoListItem, VideoListReq, VideoProgressRes};
+use crate::api::video::dto::{
+    SubtitleCueRow, SubtitleTrackRow, VideoCuePoint, VideoDetailRes, VideoListItem, VideoListReq,
+    VideoPlaybackRow, VideoProgressRes,
+};
+use crate::api::video::watch::WatchRange;
 use crate::error::AppResult;
+use crate::types::SupportedLanguage;
 
 pub struct VideoRepo;
 
@@ -31,7 +36,7 @@ impl VideoRepo {
                 NULL::integer as duration_seconds,
                 NULL::text as language,
                 NULL::text as thumbnail_url,
-                false as has_captions,
+                EXISTS(SELECT 1 FROM video_subtitle vs WHERE vs.video_id = v.video_id) as has_captions,
 
                 v.video_state::text as state,
                 v.video_access::text as access,
@@ -156,6 +161,7 @@ impl VideoRepo {
             r#"
             SELECT
                 v.video_id::bigint as video_id,
+                v.video_host,
                 v.video_url_vimeo,
                 v.video_state::text as video_state,
                 -- Q1c B: video 테이블 title/subtitle 물리 컬럼 직접 참조 (서비스 계층에서
@@ -202,6 +208,158 @@ impl VideoRepo {
         Ok(exists)
     }
 
+    // =========================================================================
+    // Subtitles (video_subtitle / video_subtitle_cue)
+    // =========================================================================
+
+    /// 퀴즈 큐 포인트 (공개 학습의 과제만, 시간순)
+    pub async fn find_cue_points(pool: &PgPool, video_id: i64) -> AppResult<Vec<VideoCuePoint>> {
+        let rows = sqlx::query_as::<_, VideoCuePoint>(
+            r#"
+            SELECT
+                c.cue_point_id,
+                c.cue_time_ms AS time_ms,
+                c.study_task_id,
+                t.study_task_kind AS kind,
+                c.cue_skippable AS skippable
+            FROM video_cue_point c
+            JOIN study_task t ON t.study_task_id = c.study_task_id
+            JOIN study s ON s.study_id = t.study_id
+            WHERE c.video_id = $1
+              AND s.study_state = 'open'
+            ORDER BY c.cue_time_ms
+            "#,
+        )
+        .bind(video_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// 큐 포인트가 해당 과제를 출제하는 open 비디오의 큐인지
+    pub async fn cue_point_matches_task(
+        pool: &PgPool,
+        cue_point_id: i64,
+        study_task_id: i32,
+    ) -> AppResult<bool> {
+        let exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1
+                FROM video_cue_point c
+                JOIN video v ON v.video_id = c.video_id
+                WHERE c.cue_point_id = $1
+                  AND c.study_task_id = $2
+                  AND v.video_state = 'open'
+            )
+            "#,
+        )
+        .bind(cue_point_id)
+        .bind(study_task_id)
+        .fetch_one(pool)
+        .await?;
+        Ok(exists)
+    }
+
+    /// 업로드된 자막 트랙 목록
+    pub async fn find_subtitle_tracks(
+        pool: &PgPool,
+        video_id: i64,
+    ) -> AppResult<Vec<SubtitleTrackRow>> {
+        let rows = sqlx::query_as::<_, SubtitleTrackRow>(
+            r#"
+            SELECT lang, subtitle_label
+            FROM video_subtitle
+            WHERE video_id = $1
+            ORDER BY (lang = 'ko') DESC, lang
+            "#,
+        )
+        .bind(video_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// ko 트랙 큐가 전부 approved 번역된 언어 목록
+    pub async fn find_translated_subtitle_langs(
+        pool: &PgPool,
+        video_id: i64,
+    ) -> AppResult<Vec<SupportedLanguage>> {
+        let langs = sqlx::query_scalar::<_, SupportedLanguage>(
+            r#"
+            WITH ko AS (
+                SELECT c.cue_id
+                FROM video_subtitle s
+                JOIN video_subtitle_cue c ON c.video_subtitle_id = s.video_subtitle_id
+                WHERE s.video_id = $1 AND s.lang = 'ko'
+            )
+            SELECT ct.lang
+            FROM content_translations ct
+            WHERE ct.content_type = 'video_subtitle_cue'
+              AND ct.field_name = 'cue_text'
+              AND ct.status = 'approved'
+              AND ct.content_id IN (SELECT cue_id FROM ko)
+            GROUP BY ct.lang
+            HAVING COUNT(*) = (SELECT COUNT(*) FROM ko)
+            ORDER BY ct.lang
+            "#,
+        )
+        .bind(video_id)
+        .fetch_all(pool)
+        .await?;
+        Ok(langs)
+    }
+
+    /// 업로드 트랙 큐 (seq 순)
+    pub async fn find_subtitle_cues(
+        pool: &PgPool,
+        video_id: i64,
+        lang: SupportedLanguage,
+    ) -> AppResult<Vec<SubtitleCueRow>> {
+        let rows = sqlx::query_as::<_, SubtitleCueRow>(
+            r#"
+            SELECT c.cue_start_ms, c.cue_end_ms, c.cue_text
+            FROM video_subtitle s
+            JOIN video_subtitle_cue c ON c.video_subtitle_id = s.video_subtitle_id
+            WHERE s.video_id = $1 AND s.lang = $2
+            ORDER BY c.cue_seq
+            "#,
+        )
+        .bind(video_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// ko 큐 타이밍 + lang approved 번역 (번역 누락 큐는 cue_text = NULL)
+    pub async fn find_translated_subtitle_cues(
+        pool: &PgPool,
+        video_id: i64,
+        lang: SupportedLanguage,
+    ) -> AppResult<Vec<SubtitleCueRow>> {
+        let rows = sqlx::query_as::<_, SubtitleCueRow>(
+            r#"
+            SELECT c.cue_start_ms, c.cue_end_ms, ct.translated_text AS cue_text
+            FROM video_subtitle s
+            JOIN video_subtitle_cue c ON c.video_subtitle_id = s.video_subtitle_id
+            LEFT JOIN content_translations ct
+                ON ct.content_type = 'video_subtitle_cue'
+               AND ct.content_id = c.cue_id
+               AND ct.field_name = 'cue_text'
+               AND ct.lang = $2
+               AND ct.status = 'approved'
+            WHERE s.video_id = $1 AND s.lang = 'ko'
+            ORDER BY c.cue_seq
+            "#,
+        )
+        .bind(video_id)
+        .bind(lang)
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
     // =========================================================================
     // Progress (Learning Logs)
     // =========================================================================
@@ -219,7 +377,8 @@ impl VideoRepo {
                 COALESCE(video_progress_log, 0) AS video_progress_log,
                 video_completed_log,
                 video_last_watched_at_log,
-                COALESCE(video_watch_duration_sec, 0) AS video_watch_duration_sec
+                COALESCE(video_watch_duration_sec, 0) AS video_watch_duration_sec,
+                video_watched_sec
             FROM video_log
             WHERE user_id = $1 AND video_id = $2
             "#,
@@ -231,30 +390,61 @@ impl VideoRepo {
         Ok(row)
     }
 
+    /// 내 학습 진도 (잠금 — 동시 보고 시 최초 시청/완료 판정 중복 방지)
+    pub async fn get_progress_for_update_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: i64,
+        video_id: i64,
+    ) -> AppResult<Option<VideoProgressRes>> {
+        let row = sqlx::query_as::<_, VideoProgressRes>(
+            r#"
+            SELECT
+                video_id::bigint as video_id,
+                COALESCE(video_progress_log, 0) AS video_progress_log,
+                video_completed_log,
+                video_last_watched_at_log,
+                COALESCE(video_watch_duration_sec, 0) AS video_watch_duration_sec,
+                video_watched_sec
+            FROM video_log
+            WHERE user_id = $1 AND video_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(user_id)
+        .bind(video_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+        Ok(row)
+    }
+
     /// 학습 진도 업데이트 (Upsert) - 확장 버전
     /// is_new_view: true면 watch_count++, first_watched_at 설정
     /// watch_duration_sec: 이번 세션에서 시청한 시간 (누적됨)
+    /// watched_sec: 병합 구간 총 길이 (덮어씀)
     // TODO: video_last_ip_log는 현재 항상 NULL. IP 수집 시 암호화 필수 (Phase 3 참조)
-    pub async fn update_progress(
-        pool: &PgPool,
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_progress_tx(
+        tx: &mut Transaction<'_, Postgres>,
         user_id: i64,
         video_id: i64,
         progress_rate: i32,
         is_completed: bool,
         is_new_view: bool,
         watch_duration_sec: i32,
+        watched_sec: i32,
     ) -> AppResult<VideoProgressRes> {
         let row = sqlx::query_as::<_, VideoProgressRes>(
             r#"
             INSERT INTO video_log (
                 user_id, video_id,
                 video_progress_log, video_completed_log, video_last_watched_at_log,
-                video_watch_count_log, video_first_watched_at_log, video_watch_duration_sec
+                video_watch_count_log, video_first_watched_at_log, video_watch_duration_sec,
+                video_watched_sec
             )
             VALUES ($1, $2, $3, $4, NOW(),
                 CASE WHEN $5 THEN 1 ELSE 0 END,
                 CASE WHEN $5 THEN NOW() ELSE NULL END,
-                $6
+                $6, $7
             )
             ON CONFLICT (user_id, video_id) DO UPDATE
             SET
@@ -268,13 +458,15 @@ impl VideoRepo {
                     WHEN $5 THEN video_log.video_watch_count_log + 1
                     ELSE video_log.video_watch_count_log
                 END,
-                video_watch_duration_sec = video_log.video_watch_duration_sec + $6
+                video_watch_duration_sec = video_log.video_watch_duration_sec + $6,
+                video_watched_sec = EXCLUDED.video_watched_sec
             RETURNING
                 video_id::bigint as video_id,
                 COALESCE(video_progress_log, 0) AS video_progress_log,
                 video_completed_log,
                 video_last_watched_at_log,
-                video_watch_duration_sec
+                video_watch_duration_sec,
+                video_watched_sec
             "#,
         )
         .bind(user_id)
@@ -283,11 +475,107 @@ impl VideoRepo {
         .bind(is_completed)
         .bind(is_new_view)
         .bind(watch_duration_sec)
-        .fetch_one(pool)
+        .bind(watched_sec)
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(row)
+    }
+
+    /// 재생 원본 + 접근 등급 (open 비디오만)
+    pub async fn find_playback_source(
+        pool: &PgPool,
+        video_id: i64,
+    ) -> AppResult<Option<VideoPlaybackRow>> {
+        let row = sqlx::query_as::<_, VideoPlaybackRow>(
+            r#"
+            SELECT video_access, video_host, video_url_vimeo, video_hls_path, video_duration
+            FROM video
+            WHERE video_id = $1 AND video_state = 'open'
+            "#,
+        )
+        .bind(video_id)
+        .fetch_optional(pool)
         .await?;
         Ok(row)
     }
 
+    /// open 비디오 길이 (초) — 비디오 없으면 None, 길이 미상이면 Some(None)
+    pub async fn find_open_duration(
+        pool: &PgPool,
+        video_id: i64,
+    ) -> AppResult<Option<Option<i32>>> {
+        let row = sqlx::query_scalar::<_, Option<i32>>(
+            r#"SELECT video_duration FROM video WHERE video_id = $1 AND video_state = 'open'"#,
+        )
+        .bind(video_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// 병합된 시청 구간 (잠금 — 동시 보고 시 병합 유실 방지)
+    pub async fn find_watch_ranges_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: i64,
+        video_id: i64,
+    ) -> AppResult<Vec<WatchRange>> {
+        // 최초 보고 동시 진입 대비: 사용자×비디오 단위 advisory lock
+        sqlx::query("SELECT pg_advisory_xact_lock($1, $2)")
+            .bind(user_id as i32)
+            .bind(video_id as i32)
+            .execute(&mut **tx)
+            .await?;
+
+        let rows = sqlx::query_as::<_, (i32, i32)>(
+            r#"
+            SELECT range_start_sec, range_end_sec
+            FROM video_watch_range
+            WHERE user_id = $1 AND video_id = $2
+            ORDER BY range_start_sec
+            "#,
+        )
+        .bind(user_id)
+        .bind(video_id)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(start_sec, end_sec)| WatchRange { start_sec, end_sec })
+            .collect())
+    }
+
+    /// 시청 구간 교체 (병합 결과 저장)
+    pub async fn replace_watch_ranges_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: i64,
+        video_id: i64,
+        ranges: &[WatchRange],
+    ) -> AppResult<()> {
+        sqlx::query(r#"DELETE FROM video_watch_range WHERE user_id = $1 AND video_id = $2"#)
+            .bind(user_id)
+            .bind(video_id)
+            .execute(&mut **tx)
+            .await?;
+
+        let starts: Vec<i32> = ranges.iter().map(|r| r.start_sec).collect();
+        let ends: Vec<i32> = ranges.iter().map(|r| r.end_sec).collect();
+        sqlx::query(
+            r#"
+            INSERT INTO video_watch_range (user_id, video_id, range_start_sec, range_end_sec)
+            SELECT $1, $2, t.start_sec, t.end_sec
+            FROM UNNEST($3::int[], $4::int[]) AS t(start_sec, end_sec)
+            "#,
+        )
+        .bind(user_id)
+        .bind(video_id)
+        .bind(&starts)
+        .bind(&ends)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
     // =========================================================================
     // Daily Stats (video_stat_daily)
     // =========================================================================
@@ -8,6 +8,10 @@ pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(handler::list_videos))
         .route("/{id}", get(handler::get_video_detail))
+        .route("/{id}/subtitles/{lang}", get(handler::get_video_subtitle))
+        .route("/{id}/playback", get(handler::get_video_playback))
+        .route("/{id}/hls/{*file}", get(handler::get_video_hls_file))
+        .route("/{id}/hls-key", get(handler::get_video_hls_key))
         .route(
             "/{id}/progress",
             get(handler::get_video_progress).post(handler::update_video_progress),
@@ -1,17 +1,25 @@
 use std::collections::HashMap;
 
+use redis::AsyncCommands;
 use validator::Validate;
 
 use crate::api::admin::translation::dto::{TranslatedField, TranslationMeta};
 use crate::api::admin::translation::repo::TranslationRepo;
+use crate::api::auth::session::ensure_session_active;
+use crate::api::payment::service::PaymentService;
 use crate::api::video::dto::{
-    VideoDetailRes, VideoListMeta, VideoListReq, VideoListRes, VideoProgressRes,
-    VideoProgressUpdateReq, VideoTagDetail,
+    SubtitleTrackSource, VideoDetailRes, VideoListMeta, VideoListReq, VideoListRes,
+    VideoPlaybackRes, VideoPlaybackRow, VideoProgressRes, VideoProgressUpdateReq,
+    VideoSubtitleTrack, VideoTagDetail,
 };
 use crate::api::video::repo::VideoRepo;
+use crate::api::video::subtitle;
+use crate::api::video::watch::{self, WatchRange};
 use crate::error::{AppError, AppResult};
+use crate::external::hls;
+use crate::external::video_host::PlaybackViewer;
 use crate::state::AppState;
-use crate::types::{ContentType, SupportedLanguage};
+use crate::types::{ContentType, SupportedLanguage, VideoAccess, VideoHostKind};
 
 pub struct VideoService;
 
@@ -161,9 +169,259 @@ impl VideoService {
         };
 
         video.translation_meta = translation_meta;
+        video.subtitles = Self::list_subtitle_tracks(st, video.video_id).await?;
+        video.cue_points = VideoRepo::find_cue_points(&st.db, video.video_id).await?;
         Ok(video)
     }
 
+    /// 자막 트랙 목록 — 업로드 트랙 우선, 업로드 없는 언어는 ko 큐 번역 완료 시 번역 트랙
+    pub async fn list_subtitle_tracks(
+        st: &AppState,
+        video_id: i64,
+    ) -> AppResult<Vec<VideoSubtitleTrack>> {
+        let uploaded = VideoRepo::find_subtitle_tracks(&st.db, video_id).await?;
+        if !uploaded.iter().any(|t| t.lang == SupportedLanguage::Ko) {
+            // 번역 트랙은 ko 트랙 타이밍 기반 — ko 없으면 업로드 트랙만
+            return Ok(uploaded
+                .into_iter()
+                .map(|t| {
+                    subtitle_track(
+                        video_id,
+                        t.lang,
+                        t.subtitle_label,
+                        SubtitleTrackSource::Uploaded,
+                    )
+                })
+                .collect());
+        }
+
+        let translated = VideoRepo::find_translated_subtitle_langs(&st.db, video_id).await?;
+        let mut tracks: Vec<VideoSubtitleTrack> = uploaded
+            .iter()
+            .map(|t| {
+                subtitle_track(
+                    video_id,
+                    t.lang,
+                    t.subtitle_label.clone(),
+                    SubtitleTrackSource::Uploaded,
+                )
+            })
+            .collect();
+        tracks.extend(
+            translated
+                .into_iter()
+                .filter(|lang| !uploaded.iter().any(|t| t.lang == *lang))
+                .map(|lang| subtitle_track(video_id, lang, None, SubtitleTrackSource::Translated)),
+        );
+        Ok(tracks)
+    }
+
+    /// 공개 자막 WebVTT (open 상태 비디오만)
+    pub async fn get_subtitle_vtt(
+        st: &AppState,
+        video_id: i64,
+        lang: SupportedLanguage,
+    ) -> AppResult<String> {
+        if !VideoRepo::exists_by_id(&st.db, video_id).await? {
+            return Err(AppError::NotFound);
+        }
+        Self::render_subtitle_track(&st.db, video_id, lang)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+
+    /// 트랙 WebVTT 조립 (상태 무관 — admin Vimeo push 에서도 사용)
+    ///
+    /// 업로드 트랙이 있으면 그대로, 없으면 ko 큐 번역이 전부 approved 일 때만 번역 트랙.
+    pub async fn render_subtitle_track(
+        pool: &sqlx::PgPool,
+        video_id: i64,
+        lang: SupportedLanguage,
+    ) -> AppResult<Option<String>> {
+        let uploaded = VideoRepo::find_subtitle_cues(pool, video_id, lang).await?;
+        let cues = if !uploaded.is_empty() {
+            uploaded
+        } else if lang == SupportedLanguage::Ko {
+            return Ok(None);
+        } else {
+            let translated = VideoRepo::find_translated_subtitle_cues(pool, video_id, lang).await?;
+            if translated.is_empty() || translated.iter().any(|c| c.cue_text.is_none()) {
+                return Ok(None);
+            }
+            translated
+        };
+
+        Ok(Some(subtitle::render_vtt(cues.iter().map(|c| {
+            (
+                c.cue_start_ms,
+                c.cue_end_ms,
+                c.cue_text.as_deref().unwrap_or_default(),
+            )
+        }))))
+    }
+
+    /// 재생 정보 발급 (접근 등급 확인 후 호스트별 URL)
+    pub async fn get_playback(
+        st: &AppState,
+        user_id: i64,
+        session_id: &str,
+        video_id: i64,
+    ) -> AppResult<VideoPlaybackRes> {
+        let row = VideoRepo::find_playback_source(&st.db, video_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        Self::ensure_playback_entitled(st, user_id, video_id, row.video_access).await?;
+
+        let source = match row.video_host {
+            VideoHostKind::Vimeo => row.video_url_vimeo.as_deref(),
+            VideoHostKind::Hls => row.video_hls_path.as_deref(),
+        }
+        .ok_or(AppError::NotFound)?;
+        let viewer = PlaybackViewer {
+            user_id,
+            session_id: session_id.to_string(),
+        };
+        let playback = st
+            .video_hosts
+            .get(row.video_host)?
+            .playback(video_id, source, &viewer)?;
+
+        Self::record_watch_start(st, user_id, video_id).await;
+
+        Ok(VideoPlaybackRes {
+            video_id,
+            host: row.video_host,
+            url: playback.url,
+            expires_at: playback.expires_at,
+        })
+    }
+
+    /// 자체 HLS 파일 — (Content-Type, body)
+    ///
+    /// 재생목록: 세션 활성 + 접근 등급 재확인 후 내부 URI 를 서명 URL 로 재작성.
+    /// 세그먼트: 서명만 확인 (AES-128 키 없이는 복호 불가).
+    pub async fn get_hls_file(
+        st: &AppState,
+        video_id: i64,
+        file: &str,
+        token: &str,
+    ) -> AppResult<(&'static str, Vec<u8>)> {
+        let host = st.video_hosts.hls()?;
+        let now = chrono::Utc::now().timestamp();
+        let claims = host.verify_token(video_id, token, now)?;
+        let (row, hls_path) = Self::find_hls_source(st, video_id).await?;
+
+        if !file.ends_with(".m3u8") {
+            let body = host.read_file(&hls_path, file).await?;
+            return Ok((hls::content_type(file), body));
+        }
+
+        ensure_session_active(st, &claims.session_id, claims.user_id).await?;
+        Self::ensure_playback_entitled(st, claims.user_id, video_id, row.video_access).await?;
+
+        let content = String::from_utf8(host.read_file(&hls_path, file).await?)
+            .map_err(|_| AppError::Internal(format!("HLS playlist not UTF-8: {file}")))?;
+        // 재생목록 안의 URI 는 영상 길이만큼 연장 — 재생 도중 세그먼트 URL 만료 방지
+        let exp = now + host.url_ttl_sec() + i64::from(row.video_duration.unwrap_or(0).max(0));
+        let viewer = PlaybackViewer {
+            user_id: claims.user_id,
+            session_id: claims.session_id,
+        };
+        let token = host.sign_token(video_id, &viewer, exp);
+        let body = host.rewrite_playlist(video_id, file, &content, &token);
+        Ok((hls::content_type(file), body.into_bytes()))
+    }
+
+    /// AES-128 세그먼트 키 — 서명 + 세션 활성 + 접근 등급 모두 통과한 사용자만
+    pub async fn get_hls_key(st: &AppState, video_id: i64, token: &str) -> AppResult<Vec<u8>> {
+        let host = st.video_hosts.hls()?;
+        let claims = host.verify_token(video_id, token, chrono::Utc::now().timestamp())?;
+        let (row, hls_path) = Self::find_hls_source(st, video_id).await?;
+
+        ensure_session_active(st, &claims.session_id, claims.user_id).await?;
+        Self::ensure_playback_entitled(st, claims.user_id, video_id, row.video_access).await?;
+
+        host.read_key(&hls_path).await
+    }
+
+    async fn find_hls_source(
+        st: &AppState,
+        video_id: i64,
+    ) -> AppResult<(VideoPlaybackRow, String)> {
+        let row = VideoRepo::find_playback_source(&st.db, video_id)
+            .await?
+            .filter(|r| r.video_host == VideoHostKind::Hls)
+            .ok_or(AppError::NotFound)?;
+        let hls_path = row.video_hls_path.clone().ok_or(AppError::NotFound)?;
+        Ok((row, hls_path))
+    }
+
+    /// 재생 접근 등급 확인 (레슨과 동일 — private 불가, paid 는 활성 구독 필요)
+    async fn ensure_playback_entitled(
+        st: &AppState,
+        user_id: i64,
+        video_id: i64,
+        access: VideoAccess,
+    ) -> AppResult<()> {
+        match access {
+            VideoAccess::Private => Err(AppError::Forbidden("Forbidden".to_string())),
+            VideoAccess::Paid => {
+                if PaymentService::has_active_subscription(st, user_id).await? {
+                    Ok(())
+                } else {
+                    tracing::warn!(
+                        user_id,
+                        video_id,
+                        "User attempted to play paid video without subscription"
+                    );
+                    Err(AppError::Forbidden("Forbidden".to_string()))
+                }
+            }
+            VideoAccess::Public | VideoAccess::Promote => Ok(()),
+        }
+    }
+
+    fn watch_start_key(user_id: i64, video_id: i64) -> String {
+        format!("video:watch_start:{user_id}:{video_id}")
+    }
+
+    /// 재생 시작 시각 기록 (best-effort) — 진도 기록이 없는 첫 보고의 경과 시간 기준
+    async fn record_watch_start(st: &AppState, user_id: i64, video_id: i64) {
+        let result = async {
+            let mut conn = st
+                .redis
+                .get()
+                .await
+                .map_err(|e| AppError::Internal(e.to_string()))?;
+            let _: () = conn
+                .set_ex(
+                    Self::watch_start_key(user_id, video_id),
+                    chrono::Utc::now().timestamp(),
+                    watch::WATCH_START_TTL_SEC,
+                )
+                .await?;
+            AppResult::Ok(())
+        }
+        .await;
+        if let Err(e) = result {
+            tracing::warn!(user_id, video_id, error = %e, "failed to record watch start");
+        }
+    }
+
+    /// 재생 시작 시각 (없거나 Redis 오류면 None)
+    async fn find_watch_start(
+        st: &AppState,
+        user_id: i64,
+        video_id: i64,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        let mut conn = st.redis.get().await.ok()?;
+        let ts: Option<i64> = conn
+            .get(Self::watch_start_key(user_id, video_id))
+            .await
+            .ok()?;
+        ts.and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+    }
+
     /// 내 진도율 조회
     pub async fn get_video_progress(
         st: &AppState,
@@ -186,6 +444,7 @@ impl VideoService {
             is_completed: false,
             last_watched_at: None,
             watch_duration_sec: 0,
+            watched_sec: 0,
         }))
     }
 
@@ -201,32 +460,82 @@ impl VideoService {
             return Err(AppError::Unprocessable(e.to_string()));
         }
 
-        // 2. 비디오 존재 확인
-        let exists = VideoRepo::exists_by_id(&st.db, video_id).await?;
-        if !exists {
-            return Err(AppError::NotFound);
+        // 2. 비디오 존재 확인 (+ 커버리지 기준 길이)
+        let duration = VideoRepo::find_open_duration(&st.db, video_id)
+            .await?
+            .ok_or(AppError::NotFound)?
+            .filter(|d| *d > 0);
+
+        let segments: Vec<WatchRange> = req
+            .segments
+            .iter()
+            .map(|s| WatchRange {
+                start_sec: s.start_sec,
+                end_sec: s.end_sec,
+            })
+            .collect();
+        if segments.iter().any(WatchRange::is_empty) {
+            return Err(AppError::Unprocessable(
+                "segment end_sec must be greater than start_sec".into(),
+            ));
+        }
+        let segments = watch::clamp_to_duration(&segments, duration);
+
+        // 3. 첫 보고 기준 시각 — 진도 기록이 없으면 재생 발급 시각
+        let watch_start = Self::find_watch_start(st, user_id, video_id).await;
+
+        // 4. 구간 병합 — 새로 인정되는 시청 시간은 직전 보고(또는 재생 시작) 이후 경과 시간으로 상한
+        let mut tx = st.db.begin().await?;
+        let stored = VideoRepo::find_watch_ranges_tx(&mut tx, user_id, video_id).await?;
+        let existing = VideoRepo::get_progress_for_update_tx(&mut tx, user_id, video_id).await?;
+        let merged = watch::merge(&stored, &segments);
+        let watched_sec = watch::covered_seconds(&merged);
+        let gained = i64::from(watched_sec - watch::covered_seconds(&stored));
+
+        let elapsed = existing
+            .as_ref()
+            .and_then(|p| p.last_watched_at)
+            .or(watch_start)
+            .map(|t| (chrono::Utc::now() - t).num_seconds().max(0))
+            .unwrap_or(0);
+        let max_gain =
+            elapsed.saturating_mul(watch::MAX_PLAYBACK_RATE) + watch::SEGMENT_GAIN_SLACK_SEC;
+        if gained > max_gain {
+            return Err(AppError::Unprocessable(
+                "reported segments exceed elapsed watch time".into(),
+            ));
+        }
+        if gained > 0 {
+            VideoRepo::replace_watch_ranges_tx(&mut tx, user_id, video_id, &merged).await?;
         }
 
-        // 3. 기존 진도 조회 (통계 판단용)
-        let existing = VideoRepo::get_progress(&st.db, user_id, video_id).await?;
+        // 5. 진도율/완료 — 커버리지 기준 (길이 미상이면 자기신고 값, 완료 불가)
+        let (progress_rate, is_completed) = match duration {
+            Some(d) => {
+                let pct = watch::coverage_percent(watched_sec, d);
+                (pct, pct >= watch::COMPLETE_COVERAGE_PERCENT)
+            }
+            None => (req.progress_rate.min(99), false),
+        };
+        let progress_rate = progress_rate.max(existing.as_ref().map_or(0, |p| p.progress_rate));
 
-        // 4. 통계 판단
         let is_new_view = existing.is_none(); // 최초 시청 여부
         let was_completed = existing.as_ref().map(|p| p.is_completed).unwrap_or(false);
-        let is_completed = req.progress_rate >= 100;
         let is_new_complete = !was_completed && is_completed; // 이번에 처음 완료
 
-        // 5. Upsert Log (with is_new_view flag)
-        let res = VideoRepo::update_progress(
-            &st.db,
+        // Upsert Log (with is_new_view flag)
+        let res = VideoRepo::update_progress_tx(
+            &mut tx,
             user_id,
             video_id,
-            req.progress_rate,
+            progress_rate,
             is_completed,
             is_new_view,
             req.watch_duration_sec,
+            watched_sec,
         )
         .await?;
+        tx.commit().await?;
 
         // 6. 일별 통계 업데이트
         if is_new_view {
@@ -242,6 +551,20 @@ impl VideoService {
     }
 }
 
+fn subtitle_track(
+    video_id: i64,
+    lang: SupportedLanguage,
+    label: Option<String>,
+    source: SubtitleTrackSource,
+) -> VideoSubtitleTrack {
+    VideoSubtitleTrack {
+        lang,
+        label,
+        source,
+        url: format!("/videos/{video_id}/subtitles/{}", subtitle::lang_code(lang)),
+    }
+}
+
 /// VideoTag 번역을 tags[] 에 주입 (Q1c C).
 /// Gemini 3차 리뷰 반영: tag.title/subtitle 은 Option — source 에 있을 때만 카운트.
 fn apply_tag_translations(
@@ -0,0 +1,296 @@
+//! 자막 파서/렌더러 (순수 함수 — DB 무관)
+//!
+//! 업로드는 SRT / WebVTT 모두 허용하고, 저장은 큐(cue) 단위로 정규화한다.
+//! 출력은 항상 WebVTT (`render_vtt`). 큐 설정(align 등)·STYLE/REGION/NOTE 블록은
+//! 보존하지 않음 — 20개 언어 번역 큐와 타이밍을 공유하기 위해 텍스트+타이밍만 유지.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::types::SupportedLanguage;
+
+/// 트랙 1개당 최대 큐 수 (2시간 영상 기준 여유 포함)
+pub const MAX_CUES: usize = 5000;
+/// 큐 1개 텍스트 최대 길이 (문자)
+pub const MAX_CUE_TEXT_CHARS: usize = 1000;
+
+/// 업로드 원본 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+        }
+    }
+}
+
+/// 정규화된 큐
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleCue {
+    pub start_ms: i32,
+    pub end_ms: i32,
+    pub text: String,
+}
+
+/// SRT/VTT 본문 파싱 — 형식은 `WEBVTT` 헤더 유무로 판별 (`format` 지정 시 일치 검증)
+pub fn parse_subtitle(
+    input: &str,
+    format: Option<SubtitleFormat>,
+) -> Result<(SubtitleFormat, Vec<SubtitleCue>), String> {
+    let normalized = input
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n");
+
+    let detected = if normalized.trim_start().starts_with("WEBVTT") {
+        SubtitleFormat::Vtt
+    } else {
+        SubtitleFormat::Srt
+    };
+    if let Some(expected) = format {
+        if expected != detected {
+            return Err(format!(
+                "declared format '{}' does not match content ('{}')",
+                expected.as_str(),
+                detected.as_str()
+            ));
+        }
+    }
+
+    let mut cues = Vec::new();
+    let mut line_no = 0usize;
+    let mut first_block = true;
+
+    for block in normalized.split("\n\n") {
+        let block_start = line_no + 1;
+        line_no += block.lines().count().max(1) + 1;
+
+        let lines: Vec<&str> = block
+            .lines()
+            .map(|l| l.trim_end())
+            .skip_while(|l| l.trim().is_empty())
+            .collect();
+        if lines.is_empty() {
+            continue;
+        }
+
+        if detected == SubtitleFormat::Vtt {
+            // 헤더 블록 + 메타 블록 스킵
+            if first_block {
+                first_block = false;
+                continue;
+            }
+            let head = lines[0].trim_start();
+            if head.starts_with("NOTE") || head == "STYLE" || head == "REGION" {
+                continue;
+            }
+        }
+        first_block = false;
+
+        let Some(timing_idx) = lines.iter().position(|l| l.contains("-->")) else {
+            return Err(format!("line {block_start}: cue without timing line"));
+        };
+        // SRT 는 인덱스 1줄, VTT 는 선택적 식별자 1줄만 허용
+        if timing_idx > 1 {
+            return Err(format!("line {block_start}: unexpected text before timing"));
+        }
+
+        let (start_ms, end_ms) = parse_timing(lines[timing_idx])
+            .ok_or_else(|| format!("line {}: invalid timing", block_start + timing_idx))?;
+        if end_ms <= start_ms {
+            return Err(format!(
+                "line {}: cue end must be after start",
+                block_start + timing_idx
+            ));
+        }
+
+        let text = sanitize_cue_text(&lines[timing_idx + 1..].join("\n"));
+        if text.is_empty() {
+            return Err(format!("line {block_start}: empty cue text"));
+        }
+        if text.chars().count() > MAX_CUE_TEXT_CHARS {
+            return Err(format!("line {block_start}: cue text too long"));
+        }
+
+        cues.push(SubtitleCue {
+            start_ms,
+            end_ms,
+            text,
+        });
+        if cues.len() > MAX_CUES {
+            return Err(format!("too many cues (max {MAX_CUES})"));
+        }
+    }
+
+    if cues.is_empty() {
+        return Err("no cues found".into());
+    }
+
+    cues.sort_by_key(|c| c.start_ms);
+    Ok((detected, cues))
+}
+
+/// 큐 텍스트 정리 — 빈 줄(큐 경계)·`-->`(타이밍 구분자) 제거, 줄 끝 공백 정리
+pub fn sanitize_cue_text(text: &str) -> String {
+    text.replace("-->", "->")
+        .lines()
+        .map(str::trim_end)
+        .filter(|l| !l.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 큐 목록 → WebVTT 본문
+pub fn render_vtt<'a>(cues: impl IntoIterator<Item = (i32, i32, &'a str)>) -> String {
+    let mut out = String::from("WEBVTT\n");
+    for (idx, (start_ms, end_ms, text)) in cues.into_iter().enumerate() {
+        out.push_str(&format!(
+            "\n{}\n{} --> {}\n{}\n",
+            idx + 1,
+            format_timestamp(start_ms),
+            format_timestamp(end_ms),
+            sanitize_cue_text(text)
+        ));
+    }
+    out
+}
+
+/// URL 경로·Vimeo `language` 에 쓰는 언어 코드 (serde 표기 — 예: "ko", "zh-CN")
+pub fn lang_code(lang: SupportedLanguage) -> String {
+    serde_json::to_value(lang)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn parse_timing(line: &str) -> Option<(i32, i32)> {
+    let (start, rest) = line.split_once("-->")?;
+    // VTT 큐 설정 (align:start 등) 은 공백 뒤에 붙음 — 버림
+    let end = rest.split_whitespace().next()?;
+    Some((parse_timestamp(start.trim())?, parse_timestamp(end)?))
+}
+
+/// `hh:mm:ss.mmm` / `mm:ss.mmm` / SRT `hh:mm:ss,mmm`
+fn parse_timestamp(ts: &str) -> Option<i32> {
+    let (clock, millis) = ts.split_once(['.', ','])?;
+    if millis.len() != 3 || !millis.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let parts: Vec<&str> = clock.split(':').collect();
+    let (h, m, s) = match parts.as_slice() {
+        [h, m, s] => (*h, *m, *s),
+        [m, s] => ("0", *m, *s),
+        _ => return None,
+    };
+    let num = |v: &str| -> Option<i64> {
+        (!v.is_empty() && v.chars().all(|c| c.is_ascii_digit()))
+            .then(|| v.parse().ok())
+            .flatten()
+    };
+    let (h, m, s, ms) = (num(h)?, num(m)?, num(s)?, num(millis)?);
+    if m >= 60 || s >= 60 {
+        return None;
+    }
+    i32::try_from(((h * 60 + m) * 60 + s) * 1000 + ms).ok()
+}
+
+fn format_timestamp(ms: i32) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        ms % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_srt_with_bom_and_crlf() {
+        let srt = "\u{feff}1\r\n00:00:01,000 --> 00:00:03,500\r\n안녕하세요\r\n\r\n2\r\n00:00:04,000 --> 00:00:06,000\r\n반갑습니다\r\n두 번째 줄\r\n";
+        let (fmt, cues) = parse_subtitle(srt, None).unwrap();
+        assert_eq!(fmt, SubtitleFormat::Srt);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start_ms, 1000);
+        assert_eq!(cues[0].end_ms, 3500);
+        assert_eq!(cues[1].text, "반갑습니다\n두 번째 줄");
+    }
+
+    #[test]
+    fn parses_vtt_skipping_header_notes_and_settings() {
+        let vtt = "WEBVTT - lesson 1\nKind: captions\n\nNOTE 검수 필요\n\nSTYLE\n::cue { color: red }\n\nintro\n00:01.000 --> 00:02.000 align:start line:0\n하나\n\n01:00:00.000 --> 01:00:01.250\n둘\n";
+        let (fmt, cues) = parse_subtitle(vtt, Some(SubtitleFormat::Vtt)).unwrap();
+        assert_eq!(fmt, SubtitleFormat::Vtt);
+        assert_eq!(
+            cues,
+            vec![
+                SubtitleCue {
+                    start_ms: 1000,
+                    end_ms: 2000,
+                    text: "하나".into()
+                },
+                SubtitleCue {
+                    start_ms: 3_600_000,
+                    end_ms: 3_601_250,
+                    text: "둘".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_declared_format_mismatch() {
+        let srt = "1\n00:00:01,000 --> 00:00:02,000\nhi\n";
+        assert!(parse_subtitle(srt, Some(SubtitleFormat::Vtt)).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_timing_and_reversed_cues() {
+        assert!(parse_subtitle("1\n00:00:01 --> 00:00:02,000\nhi\n", None).is_err());
+        assert!(parse_subtitle("1\n00:00:61,000 --> 00:01:02,000\nhi\n", None).is_err());
+        assert!(parse_subtitle("1\n00:00:03,000 --> 00:00:02,000\nhi\n", None).is_err());
+    }
+
+    #[test]
+    fn rejects_empty_cue_text_and_empty_file() {
+        assert!(parse_subtitle("1\n00:00:01,000 --> 00:00:02,000\n", None).is_err());
+        assert!(parse_subtitle("WEBVTT\n\n", None).is_err());
+    }
+
+    #[test]
+    fn sorts_cues_by_start() {
+        let srt = "1\n00:00:05,000 --> 00:00:06,000\nb\n\n2\n00:00:01,000 --> 00:00:02,000\na\n";
+        let (_, cues) = parse_subtitle(srt, None).unwrap();
+        assert_eq!(cues[0].text, "a");
+    }
+
+    #[test]
+    fn lang_code_matches_serde_form() {
+        assert_eq!(lang_code(SupportedLanguage::Ko), "ko");
+        assert_eq!(lang_code(SupportedLanguage::ZhCn), "zh-CN");
+    }
+
+    #[test]
+    fn render_roundtrips_through_parser() {
+        let vtt = render_vtt([
+            (1000, 2500, "첫 줄"),
+            (3_723_004, 3_724_000, "둘\n\n셋 --> 넷"),
+        ]);
+        assert!(vtt.starts_with("WEBVTT\n"));
+        assert!(vtt.contains("01:02:03.004 --> 01:02:04.000"));
+        let (_, cues) = parse_subtitle(&vtt, None).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[1].text, "둘\n셋 -> 넷");
+    }
+}
@@ -0,0 +1,126 @@
+//! 구간 시청 기록 병합/커버리지 계산 (순수 함수 — DB 무관)
+//!
+//! 구간은 초 단위 반열린 구간 `[start, end)`. 저장은 항상 정렬·병합된 상태를 유지하고,
+//! 인접 구간(`a.end == b.start`)도 하나로 합친다.
+
+/// 커버리지 완료 기준 (%) — 인트로/엔딩 스킵 여유
+pub const COMPLETE_COVERAGE_PERCENT: i32 = 90;
+/// 1회 보고당 최대 구간 수
+pub const MAX_SEGMENTS_PER_REPORT: usize = 50;
+/// 최대 재생 속도 — 보고 간격 대비 새로 인정할 수 있는 시청 시간 상한 계산용
+pub const MAX_PLAYBACK_RATE: i64 = 2;
+/// 네트워크 지연·첫 보고 여유 (초)
+pub const SEGMENT_GAIN_SLACK_SEC: i64 = 30;
+/// 재생 시작 시각 보관 기간 (초) — 진도 기록이 없는 첫 보고의 경과 시간 기준
+pub const WATCH_START_TTL_SEC: u64 = 6 * 3600;
+
+/// 시청 구간 `[start_sec, end_sec)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchRange {
+    pub start_sec: i32,
+    pub end_sec: i32,
+}
+
+impl WatchRange {
+    pub fn len(&self) -> i32 {
+        self.end_sec - self.start_sec
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end_sec <= self.start_sec
+    }
+}
+
+/// 정렬 + 병합 (빈 구간 제거)
+pub fn normalize(ranges: impl IntoIterator<Item = WatchRange>) -> Vec<WatchRange> {
+    let mut sorted: Vec<WatchRange> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+    sorted.sort_by_key(|r| (r.start_sec, r.end_sec));
+
+    let mut merged: Vec<WatchRange> = Vec::with_capacity(sorted.len());
+    for r in sorted {
+        match merged.last_mut() {
+            Some(last) if r.start_sec <= last.end_sec => {
+                last.end_sec = last.end_sec.max(r.end_sec);
+            }
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+/// 기존 병합 구간 + 새 구간 병합
+pub fn merge(existing: &[WatchRange], new: &[WatchRange]) -> Vec<WatchRange> {
+    normalize(existing.iter().chain(new.iter()).copied())
+}
+
+/// 병합 구간 총 길이 (초)
+pub fn covered_seconds(merged: &[WatchRange]) -> i32 {
+    merged.iter().map(WatchRange::len).sum()
+}
+
+/// 영상 길이 대비 커버리지 (%) — 0..=100
+pub fn coverage_percent(covered_sec: i32, duration_sec: i32) -> i32 {
+    if duration_sec <= 0 {
+        return 0;
+    }
+    let pct = (i64::from(covered_sec) * 100) / i64::from(duration_sec);
+    pct.clamp(0, 100) as i32
+}
+
+/// 구간을 영상 길이 안으로 자름 (길이 미상이면 그대로)
+pub fn clamp_to_duration(ranges: &[WatchRange], duration_sec: Option<i32>) -> Vec<WatchRange> {
+    ranges
+        .iter()
+        .map(|r| match duration_sec {
+            Some(d) if d > 0 => WatchRange {
+                start_sec: r.start_sec.min(d),
+                end_sec: r.end_sec.min(d),
+            },
+            _ => *r,
+        })
+        .filter(|r| !r.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(start_sec: i32, end_sec: i32) -> WatchRange {
+        WatchRange { start_sec, end_sec }
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let merged = normalize([r(30, 40), r(0, 10), r(10, 20), r(35, 50), r(60, 60)]);
+        assert_eq!(merged, vec![r(0, 20), r(30, 50)]);
+        assert_eq!(covered_seconds(&merged), 40);
+    }
+
+    #[test]
+    fn rewatch_does_not_increase_coverage() {
+        let existing = vec![r(0, 60)];
+        let merged = merge(&existing, &[r(10, 50)]);
+        assert_eq!(merged, existing);
+    }
+
+    #[test]
+    fn new_range_bridges_gap() {
+        let merged = merge(&[r(0, 10), r(20, 30)], &[r(5, 25)]);
+        assert_eq!(merged, vec![r(0, 30)]);
+    }
+
+    #[test]
+    fn coverage_percent_is_clamped() {
+        assert_eq!(coverage_percent(45, 100), 45);
+        assert_eq!(coverage_percent(120, 100), 100);
+        assert_eq!(coverage_percent(10, 0), 0);
+    }
+
+    #[test]
+    fn clamps_ranges_to_duration() {
+        let clamped = clamp_to_duration(&[r(90, 130), r(150, 160)], Some(100));
+        assert_eq!(clamped, vec![r(90, 100)]);
+        assert_eq!(clamp_to_duration(&[r(90, 130)], None), vec![r(90, 130)]);
+    }
+}
@@ -71,7 +71,7 @@ fn rekey_targets() -> Vec<RekeyTarget> {
                 RekeyColumn {
                     name: "user_birthday",
                     aad: "users.user_birthday",
-                    strict: true,
+                    strict: false,
                 },
             ],
         },
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 use std::env;
 use std::fmt;
 
+use crate::api::auth::jwt::JwtKeyring;
 use crate::crypto::KeyRing;
+use crate::types::LoginRiskAction;
 
 #[derive(Clone)]
 pub struct Config {
@@ -14,6 +16,7 @@ pub struct Config {
     pub redis_url: String,
     #[allow(dead_code)]
     pub jwt_secret: String,
+    pub jwt_keyring: JwtKeyring, // 서명/검증 키링 (JWT_SIGNING_KEY_V{n}, 미설정 시 JWT_SECRET HS256)
     #[allow(dead_code)]
     pub jwt_expire_hours: i64,
     #[allow(dead_code)]
@@ -37,6 +40,16 @@ pub struct Config {
     pub rate_limit_textbook_max: i64,     // 교재 주문 IP당 최대 횟수/윈도우 (기본: 5)
     pub cors_origins: Vec<String>,
     pub vimeo_access_token: Option<String>,
+    // Vimeo 메타 동기화 job 주기 (초, 기본 86400, <=0 비활성)
+    pub vimeo_sync_interval_sec: i64,
+    // Vimeo 메타 동기화 요청 간 최소 간격 (ms, 기본 1000) — API rate limit 보호
+    pub vimeo_sync_request_interval_ms: u64,
+    // 자체 HLS 루트 디렉터리 (미설정 시 hls 호스트 비활성)
+    pub hls_root_dir: Option<String>,
+    // HLS 서명 URL 유효 시간 (초, 기본 300)
+    pub hls_url_ttl_sec: i64,
+    // 수료증 렌더링 폰트 (한글 글리프 포함 TTF/OTF, 미설정 시 이미지 발급 불가)
+    pub certificate_font_path: Option<String>,
     pub admin_ip_allowlist: Vec<String>, // Admin 접근 허용 IP 목록 (비어있으면 모든 IP 허용)
     // Google OAuth
     pub google_client_id: Option<String>,
@@ -52,6 +65,23 @@ pub struct Config {
     // Apple OAuth (모바일 Sign in with Apple)
     pub apple_client_id: Option<String>, // Apple Bundle ID (e.g., net.amazingkorean.app)
     pub apple_team_id: Option<String>,   // Apple Team ID
+    // 카카오 로그인 (OIDC)
+    pub kakao_client_id: Option<String>,     // REST API 키
+    pub kakao_client_secret: Option<String>, // 콘솔에서 Client Secret 사용 시
+    pub kakao_redirect_uri: Option<String>,
+    pub kakao_native_app_key: Option<String>, // 모바일 SDK ID token aud (네이티브 앱 키)
+    /// 카카오 issuer override (test 전용, wiremock 주입). production = None = https://kauth.kakao.com
+    pub kakao_issuer_override: Option<String>,
+    // 네이버 로그인 (OAuth2)
+    pub naver_client_id: Option<String>,
+    pub naver_client_secret: Option<String>,
+    pub naver_redirect_uri: Option<String>,
+    /// 네이버 인증(nid) / API(openapi) base URL override (test 전용, wiremock 주입)
+    pub naver_auth_url_override: Option<String>,
+    pub naver_api_url_override: Option<String>,
+    // 기관 SSO (OIDC / SAML)
+    /// SSO 콜백/ACS 를 받는 API 공개 URL (예: https://api.amazingkorean.net). 미설정 시 기관 SSO 비활성
+    pub sso_public_base_url: Option<String>,
     // Email Provider
     pub email_provider: String,         // "resend" | "none" (기본: "none")
     pub resend_api_key: Option<String>, // Resend API 키 (email_provider=resend 시 필수)
@@ -59,10 +89,17 @@ pub struct Config {
     // Password Reset (비밀번호 재설정)
     pub verification_code_ttl_sec: i64, // 인증코드 유효시간 (초, 기본 600 = 10분)
     pub reset_token_ttl_sec: i64,       // reset_token 유효시간 (초, 기본 1800 = 30분)
+    pub passwordless_login_ttl_sec: i64, // 매직 링크/로그인 코드 유효시간 (초, 기본 600 = 10분)
     // MFA (Multi-Factor Authentication)
     pub mfa_token_ttl_sec: i64,  // MFA 토큰 유효시간 (초, 기본: 300 = 5분)
+    pub reauth_ttl_sec: i64,     // 재인증(step-up) 유효시간 (초, 기본: 300 = 5분)
     pub rate_limit_mfa_max: i64, // MFA 코드 검증 최대 시도 횟수 (기본: 5)
     pub rate_limit_mfa_window_sec: i64, // MFA 코드 검증 레이트리밋 윈도우 (초, 기본: 300)
+    // WebAuthn (패스키)
+    pub webauthn_rp_id: String, // Relying Party ID (기본: FRONTEND_URL 의 호스트)
+    pub webauthn_rp_name: String, // 인증기에 표시되는 서비스 이름 (기본: AmazingKorean)
+    pub webauthn_origins: Vec<String>, // 허용 origin 목록 (기본: FRONTEND_URL, 앱은 android:apk-key-hash:... 추가)
+    pub webauthn_challenge_ttl_sec: i64, // 등록/인증 챌린지 유효시간 (초, 기본: 300)
     // 동시 세션 수 제한 (역할별)
     pub max_sessions_learner: i64, // Learner 최대 동시 세션 (기본: 5, 초과 시 FIFO 자동 퇴장)
     pub max_sessions_manager: i64, // Manager 최대 동시 세션 (기본: 1, 초과 시 evict=last-login-wins)
@@ -70,6 +107,39 @@ pub struct Config {
     pub max_sessions_hymn: i64,    // HYMN 최대 동시 세션 (기본: 1, 초과 시 evict=last-login-wins)
     // 세션 reaper: login_expire_at 지난 active 행을 주기적으로 expired 정리 (초, 기본 300, <=0 비활성)
     pub session_reaper_interval_sec: i64,
+    // 예약 공개/비공개 job 주기 (초, 기본 60, <=0 비활성)
+    pub publish_scheduler_interval_sec: i64,
+    // 과제 마감 리마인더 job 주기 (초, 기본 900, <=0 비활성)
+    pub assignment_reminder_interval_sec: i64,
+    // 마감 몇 시간 전부터 리마인더 대상 (기본 24)
+    pub assignment_reminder_hours_before: i64,
+    // 성적부 내보내기 job 주기 (초, 기본 10, <=0 비활성)
+    pub gradebook_export_interval_sec: i64,
+    // 내보내기 파일 보관 시간 (기본 24)
+    pub gradebook_export_ttl_hours: i64,
+    // 이 인원 이하면 요청 시 즉시 생성, 초과면 job 으로 비동기 생성 (기본 100)
+    pub gradebook_export_sync_max_learners: i64,
+    // 라이브 수업 회의실 프로바이더 ("local" = 자체 회의실 URL 발급 stub)
+    pub live_meeting_provider: String,
+    // local 프로바이더 회의실 URL 베이스 (미설정 시 {FRONTEND_URL}/live/room)
+    pub live_meeting_base_url: Option<String>,
+    // 라이브 수업 리마인더 job 주기 (초, 기본 300, <=0 비활성)
+    pub live_reminder_interval_sec: i64,
+    // 시작 몇 분 전부터 리마인더 대상 (기본 60)
+    pub live_reminder_minutes_before: i64,
+    // 오프라인 GeoIP (MaxMind .mmdb). 미설정 시 해당 DB 조회 생략
+    pub geoip_city_db_path: Option<String>,
+    pub geoip_asn_db_path: Option<String>,
+    // mmdb 파일 변경 감지 주기 (초, 기본 300, <=0 이면 재로딩 안 함)
+    pub geoip_reload_interval_sec: i64,
+    // mmdb 미설정/미적중 시 ip-api.com HTTP 조회 (기본 false — 사용자 IP 외부 전송)
+    pub ipgeo_http_fallback: bool,
+    // 로그인 위험 신호 조치 (LOGIN_RISK_ACTION = off | log | alert | step_up, 기본 log). None = 평가 안 함
+    pub login_risk_action: Option<LoginRiskAction>,
+    // 이 속도(km/h)를 넘는 직전 로그인 대비 이동은 impossible_travel (기본 900 — 여객기 순항 속도)
+    pub login_risk_max_travel_kmh: f64,
+    // 새 국가/새 기기 판정에 쓰는 이력 기간 (일, 기본 180)
+    pub login_risk_history_days: i64,
     // RevenueCat (모바일 IAP)
     pub revenuecat_api_key: Option<String>, // RevenueCat 서버 API 키
     pub revenuecat_webhook_auth_token: Option<String>, // RevenueCat 웹훅 Bearer 토큰
@@ -125,6 +195,46 @@ impl Config {
                 jwt_secret.len()
             );
         }
+
+        // JWT 서명 키링: JWT_SIGNING_KEY_V{n} 패턴 (n = 1~255, PKCS#8 PEM)
+        // Ed25519 → EdDSA, RSA → RS256. kid = "v{n}". 현재 버전 외 키는 검증 + JWKS 게시 전용.
+        // 미설정 시 JWT_SECRET HS256 단일 키 (기존 동작)
+        let jwt_keyring = {
+            let mut keys: Vec<(u8, String)> = Vec::new();
+            for ver in 1..=255u8 {
+                if let Ok(pem) = env::var(format!("JWT_SIGNING_KEY_V{}", ver)) {
+                    if pem.trim().is_empty() {
+                        continue;
+                    }
+                    // .env 한 줄 표기 허용: 리터럴 "\n" → 개행
+                    keys.push((ver, pem.replace("\\n", "\n")));
+                }
+            }
+
+            if keys.is_empty() {
+                JwtKeyring::hs256(&jwt_secret)
+            } else {
+                let current_version = match env::var("JWT_SIGNING_CURRENT_VERSION") {
+                    Ok(v) => v
+                        .parse::<u8>()
+                        .expect("JWT_SIGNING_CURRENT_VERSION must be a number (1-255)"),
+                    Err(_) => keys.iter().map(|(v, _)| *v).max().unwrap_or(1),
+                };
+                // HS256 → 비대칭 전환 과도기에만 명시적으로 켠다 (kid 없는 기존 HS256 토큰을 만료까지 허용).
+                // 켜져 있는 동안 기동 시 경고 — 전환 후 JWT_ACCESS_TTL_MIN 이 지나면 끌 것.
+                let accept_legacy = env::var("JWT_LEGACY_HS256_VERIFY")
+                    .unwrap_or_else(|_| "false".into())
+                    .parse::<bool>()
+                    .expect("JWT_LEGACY_HS256_VERIFY must be true or false");
+
+                JwtKeyring::from_pem_keys(
+                    keys,
+                    current_version,
+                    accept_legacy.then_some(jwt_secret.as_str()),
+                )
+                .unwrap_or_else(|e| panic!("Failed to create JWT keyring: {e}"))
+            }
+        };
         let jwt_expire_hours = env::var("JWT_EXPIRE_HOURS")
             .unwrap_or_else(|_| "24".into())
             .parse::<i64>()
@@ -214,6 +324,27 @@ impl Config {
 
         // Vimeo API Access Token (optional)
         let vimeo_access_token = env::var("VIMEO_ACCESS_TOKEN").ok();
+        // Vimeo 메타 동기화 job (토큰 미설정 시 job 자체가 skip)
+        let vimeo_sync_interval_sec = env::var("VIMEO_SYNC_INTERVAL_SEC")
+            .unwrap_or_else(|_| "86400".into())
+            .parse::<i64>()
+            .expect("VIMEO_SYNC_INTERVAL_SEC must be a number");
+        let vimeo_sync_request_interval_ms = env::var("VIMEO_SYNC_REQUEST_INTERVAL_MS")
+            .unwrap_or_else(|_| "1000".into())
+            .parse::<u64>()
+            .expect("VIMEO_SYNC_REQUEST_INTERVAL_MS must be a number");
+
+        // 자체 HLS 호스트 (optional) — {HLS_ROOT_DIR}/{video_hls_path}/master.m3u8
+        let hls_root_dir = env::var("HLS_ROOT_DIR").ok().filter(|s| !s.is_empty());
+        let hls_url_ttl_sec = env::var("HLS_URL_TTL_SEC")
+            .unwrap_or_else(|_| "300".into())
+            .parse::<i64>()
+            .expect("HLS_URL_TTL_SEC must be a number");
+
+        // 수료증 PNG 렌더링 폰트 (optional)
+        let certificate_font_path = env::var("CERTIFICATE_FONT_PATH")
+            .ok()
+            .filter(|s| !s.is_empty());
 
         // Admin IP Allowlist (optional, 쉼표로 구분)
         // 예: "127.0.0.1,192.168.1.0/24,10.0.0.0/8"
@@ -243,6 +374,38 @@ impl Config {
         // Apple OAuth (optional, 모바일 Sign in with Apple)
         let apple_client_id = env::var("APPLE_CLIENT_ID").ok().filter(|s| !s.is_empty());
         let apple_team_id = env::var("APPLE_TEAM_ID").ok().filter(|s| !s.is_empty());
+
+        // 카카오 / 네이버 로그인 (optional)
+        let kakao_client_id = env::var("KAKAO_CLIENT_ID").ok().filter(|s| !s.is_empty());
+        let kakao_client_secret = env::var("KAKAO_CLIENT_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let kakao_redirect_uri = env::var("KAKAO_REDIRECT_URI")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let kakao_native_app_key = env::var("KAKAO_NATIVE_APP_KEY")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let kakao_issuer_override = env::var("KAKAO_ISSUER_OVERRIDE")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let naver_client_id = env::var("NAVER_CLIENT_ID").ok().filter(|s| !s.is_empty());
+        let naver_client_secret = env::var("NAVER_CLIENT_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let naver_redirect_uri = env::var("NAVER_REDIRECT_URI")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let naver_auth_url_override = env::var("NAVER_AUTH_URL_OVERRIDE")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let naver_api_url_override = env::var("NAVER_API_URL_OVERRIDE")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let sso_public_base_url = env::var("SSO_PUBLIC_BASE_URL")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.trim_end_matches('/').to_string());
         let oauth_state_ttl_sec = env::var("OAUTH_STATE_TTL_SEC")
             .unwrap_or_else(|_| "300".into())
             .parse::<i64>()
@@ -268,12 +431,20 @@ impl Config {
             .unwrap_or_else(|_| "1800".into())
             .parse::<i64>()
             .expect("RESET_TOKEN_TTL_SEC must be a number");
+        let passwordless_login_ttl_sec = env::var("PASSWORDLESS_LOGIN_TTL_SEC")
+            .unwrap_or_else(|_| "600".into())
+            .parse::<i64>()
+            .expect("PASSWORDLESS_LOGIN_TTL_SEC must be a number");
 
         // MFA (Multi-Factor Authentication)
         let mfa_token_ttl_sec = env::var("MFA_TOKEN_TTL_SEC")
             .unwrap_or_else(|_| "300".into())
             .parse::<i64>()
             .expect("MFA_TOKEN_TTL_SEC must be a number");
+        let reauth_ttl_sec = env::var("REAUTH_TTL_SEC")
+            .unwrap_or_else(|_| "300".into())
+            .parse::<i64>()
+            .expect("REAUTH_TTL_SEC must be a number");
         let rate_limit_mfa_max = env::var("RATE_LIMIT_MFA_MAX")
             .unwrap_or_else(|_| "5".into())
             .parse::<i64>()
@@ -283,6 +454,28 @@ impl Config {
             .parse::<i64>()
             .expect("RATE_LIMIT_MFA_WINDOW_SEC must be a number");
 
+        // WebAuthn (패스키) — RP ID 는 origin 의 등록 가능 도메인이어야 함
+        let webauthn_rp_id = env::var("WEBAUTHN_RP_ID")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| url_host(&frontend_url));
+        let webauthn_rp_name =
+            env::var("WEBAUTHN_RP_NAME").unwrap_or_else(|_| "AmazingKorean".into());
+        let webauthn_origins: Vec<String> = env::var("WEBAUTHN_ORIGINS")
+            .ok()
+            .map(|v| {
+                v.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .filter(|v: &Vec<String>| !v.is_empty())
+            .unwrap_or_else(|| vec![frontend_url.trim_end_matches('/').to_string()]);
+        let webauthn_challenge_ttl_sec = env::var("WEBAUTHN_CHALLENGE_TTL_SEC")
+            .unwrap_or_else(|_| "300".into())
+            .parse::<i64>()
+            .expect("WEBAUTHN_CHALLENGE_TTL_SEC must be a number");
+
         // 동시 세션 수 제한
         let max_sessions_learner = env::var("MAX_SESSIONS_LEARNER")
             .unwrap_or_else(|_| "5".into())
@@ -306,6 +499,85 @@ impl Config {
             .unwrap_or_else(|_| "300".into())
             .parse::<i64>()
             .expect("SESSION_REAPER_INTERVAL_SEC must be a number");
+        // 예약 공개/비공개 job 주기 (초). 기본 60. <=0 이면 비활성 (예약은 쌓이기만 함).
+        let publish_scheduler_interval_sec = env::var("PUBLISH_SCHEDULER_INTERVAL_SEC")
+            .unwrap_or_else(|_| "60".into())
+            .parse::<i64>()
+            .expect("PUBLISH_SCHEDULER_INTERVAL_SEC must be a number");
+        // 과제 마감 리마인더 job 주기 (초). 기본 900. <=0 이면 비활성. 이메일 미설정이면 job 자체를 띄우지 않음.
+        let assignment_reminder_interval_sec = env::var("ASSIGNMENT_REMINDER_INTERVAL_SEC")
+            .unwrap_or_else(|_| "900".into())
+            .parse::<i64>()
+            .expect("ASSIGNMENT_REMINDER_INTERVAL_SEC must be a number");
+        let assignment_reminder_hours_before = env::var("ASSIGNMENT_REMINDER_HOURS_BEFORE")
+            .unwrap_or_else(|_| "24".into())
+            .parse::<i64>()
+            .expect("ASSIGNMENT_REMINDER_HOURS_BEFORE must be a number");
+        // 성적부 내보내기 — 대규모 범위는 job 이 비동기 생성, 파일은 TTL 후 비움
+        let gradebook_export_interval_sec = env::var("GRADEBOOK_EXPORT_INTERVAL_SEC")
+            .unwrap_or_else(|_| "10".into())
+            .parse::<i64>()
+            .expect("GRADEBOOK_EXPORT_INTERVAL_SEC must be a number");
+        let gradebook_export_ttl_hours = env::var("GRADEBOOK_EXPORT_TTL_HOURS")
+            .unwrap_or_else(|_| "24".into())
+            .parse::<i64>()
+            .expect("GRADEBOOK_EXPORT_TTL_HOURS must be a number");
+        let gradebook_export_sync_max_learners = env::var("GRADEBOOK_EXPORT_SYNC_MAX_LEARNERS")
+            .unwrap_or_else(|_| "100".into())
+            .parse::<i64>()
+            .expect("GRADEBOOK_EXPORT_SYNC_MAX_LEARNERS must be a number");
+        // 라이브 수업 — 회의실 프로바이더 + 시작 전 리마인더
+        let live_meeting_provider =
+            env::var("LIVE_MEETING_PROVIDER").unwrap_or_else(|_| "local".into());
+        let live_meeting_base_url = env::var("LIVE_MEETING_BASE_URL")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let live_reminder_interval_sec = env::var("LIVE_REMINDER_INTERVAL_SEC")
+            .unwrap_or_else(|_| "300".into())
+            .parse::<i64>()
+            .expect("LIVE_REMINDER_INTERVAL_SEC must be a number");
+        let live_reminder_minutes_before = env::var("LIVE_REMINDER_MINUTES_BEFORE")
+            .unwrap_or_else(|_| "60".into())
+            .parse::<i64>()
+            .expect("LIVE_REMINDER_MINUTES_BEFORE must be a number");
+
+        // 오프라인 GeoIP — geoipupdate 가 파일을 제자리 교체하면 reload job 이 다시 읽음
+        let geoip_city_db_path = env::var("GEOIP_CITY_DB_PATH")
+            .ok()
+            .filter(|s| !s.is_empty());
+        let geoip_asn_db_path = env::var("GEOIP_ASN_DB_PATH").ok().filter(|s| !s.is_empty());
+        let geoip_reload_interval_sec = env::var("GEOIP_RELOAD_INTERVAL_SEC")
+            .unwrap_or_else(|_| "300".into())
+            .parse::<i64>()
+            .expect("GEOIP_RELOAD_INTERVAL_SEC must be a number");
+        let ipgeo_http_fallback = env::var("IPGEO_HTTP_FALLBACK")
+            .unwrap_or_else(|_| "false".into())
+            .parse::<bool>()
+            .expect("IPGEO_HTTP_FALLBACK must be true or false");
+
+        // 로그인 위험 평가 — 새 국가 / 새 기기 / 불가능한 이동 속도
+        let login_risk_action = match env::var("LOGIN_RISK_ACTION")
+            .unwrap_or_else(|_| "log".into())
+            .to_lowercase()
+            .as_str()
+        {
+            "off" => None,
+            "log" => Some(LoginRiskAction::Log),
+            "alert" => Some(LoginRiskAction::Alert),
+            "step_up" => Some(LoginRiskAction::StepUp),
+            other => panic!(
+                "Unknown LOGIN_RISK_ACTION '{}'. Must be 'off', 'log', 'alert' or 'step_up'.",
+                other
+            ),
+        };
+        let login_risk_max_travel_kmh = env::var("LOGIN_RISK_MAX_TRAVEL_KMH")
+            .unwrap_or_else(|_| "900".into())
+            .parse::<f64>()
+            .expect("LOGIN_RISK_MAX_TRAVEL_KMH must be a number");
+        let login_risk_history_days = env::var("LOGIN_RISK_HISTORY_DAYS")
+            .unwrap_or_else(|_| "180".into())
+            .parse::<i64>()
+            .expect("LOGIN_RISK_HISTORY_DAYS must be a number");
 
         // RevenueCat (모바일 IAP)
         let revenuecat_api_key = env::var("REVENUECAT_API_KEY")
@@ -544,6 +816,7 @@ impl Config {
             bind_addr,
             redis_url,
             jwt_secret,
+            jwt_keyring,
             jwt_expire_hours,
             enable_docs,
             skip_db,
@@ -564,6 +837,11 @@ impl Config {
             rate_limit_textbook_max,
             cors_origins,
             vimeo_access_token,
+            vimeo_sync_interval_sec,
+            vimeo_sync_request_interval_ms,
+            hls_root_dir,
+            hls_url_ttl_sec,
+            certificate_font_path,
             admin_ip_allowlist,
             google_client_id,
             google_client_secret,
@@ -575,19 +853,53 @@ impl Config {
             frontend_url,
             apple_client_id,
             apple_team_id,
+            kakao_client_id,
+            kakao_client_secret,
+            kakao_redirect_uri,
+            kakao_native_app_key,
+            kakao_issuer_override,
+            naver_client_id,
+            naver_client_secret,
+            naver_redirect_uri,
+            naver_auth_url_override,
+            naver_api_url_override,
+            sso_public_base_url,
             email_provider,
             resend_api_key,
             email_from_address,
             verification_code_ttl_sec,
             reset_token_ttl_sec,
+            passwordless_login_ttl_sec,
             mfa_token_ttl_sec,
+            reauth_ttl_sec,
             rate_limit_mfa_max,
             rate_limit_mfa_window_sec,
+            webauthn_rp_id,
+            webauthn_rp_name,
+            webauthn_origins,
+            webauthn_challenge_ttl_sec,
             max_sessions_learner,
             max_sessions_manager,
             max_sessions_admin,
             max_sessions_hymn,
             session_reaper_interval_sec,
+            publish_scheduler_interval_sec,
+            assignment_reminder_interval_sec,
+            assignment_reminder_hours_before,
+            gradebook_export_interval_sec,
+            gradebook_export_ttl_hours,
+            gradebook_export_sync_max_learners,
+            live_meeting_provider,
+            live_meeting_base_url,
+            live_reminder_interval_sec,
+            live_reminder_minutes_before,
+            geoip_city_db_path,
+            geoip_asn_db_path,
+            geoip_reload_interval_sec,
+            ipgeo_http_fallback,
+            login_risk_action,
+            login_risk_max_travel_kmh,
+            login_risk_history_days,
             revenuecat_api_key,
             revenuecat_webhook_auth_token,
             payment_provider,
@@ -769,6 +1081,7 @@ impl fmt::Debug for Config {
             .field("bind_addr", &self.bind_addr)
             .field("redis_url", &"***")
             .field("jwt_secret", &"***")
+            .field("jwt_keyring", &self.jwt_keyring)
             .field("jwt_expire_hours", &self.jwt_expire_hours)
             .field("enable_docs", &self.enable_docs)
             .field("skip_db", &self.skip_db)
@@ -804,6 +1117,14 @@ impl fmt::Debug for Config {
                 "vimeo_access_token",
                 &self.vimeo_access_token.as_ref().map(|_| "***"),
             )
+            .field("vimeo_sync_interval_sec", &self.vimeo_sync_interval_sec)
+            .field(
+                "vimeo_sync_request_interval_ms",
+                &self.vimeo_sync_request_interval_ms,
+            )
+            .field("hls_root_dir", &self.hls_root_dir)
+            .field("hls_url_ttl_sec", &self.hls_url_ttl_sec)
+            .field("certificate_font_path", &self.certificate_font_path)
             .field("admin_ip_allowlist", &self.admin_ip_allowlist)
             .field(
                 "google_client_id",
@@ -827,6 +1148,32 @@ impl fmt::Debug for Config {
                 &self.apple_client_id.as_ref().map(|_| "***"),
             )
             .field("apple_team_id", &self.apple_team_id.as_ref().map(|_| "***"))
+            .field(
+                "kakao_client_id",
+                &self.kakao_client_id.as_ref().map(|_| "***"),
+            )
+            .field(
+                "kakao_client_secret",
+                &self.kakao_client_secret.as_ref().map(|_| "***"),
+            )
+            .field("kakao_redirect_uri", &self.kakao_redirect_uri)
+            .field(
+                "kakao_native_app_key",
+                &self.kakao_native_app_key.as_ref().map(|_| "***"),
+            )
+            .field("kakao_issuer_override", &self.kakao_issuer_override)
+            .field(
+                "naver_client_id",
+                &self.naver_client_id.as_ref().map(|_| "***"),
+            )
+            .field(
+                "naver_client_secret",
+                &self.naver_client_secret.as_ref().map(|_| "***"),
+            )
+            .field("naver_redirect_uri", &self.naver_redirect_uri)
+            .field("naver_auth_url_override", &self.naver_auth_url_override)
+            .field("naver_api_url_override", &self.naver_api_url_override)
+            .field("sso_public_base_url", &self.sso_public_base_url)
             .field("email_provider", &self.email_provider)
             .field(
                 "resend_api_key",
@@ -835,9 +1182,21 @@ impl fmt::Debug for Config {
             .field("email_from_address", &self.email_from_address)
             .field("verification_code_ttl_sec", &self.verification_code_ttl_sec)
             .field("reset_token_ttl_sec", &self.reset_token_ttl_sec)
+            .field(
+                "passwordless_login_ttl_sec",
+                &self.passwordless_login_ttl_sec,
+            )
             .field("mfa_token_ttl_sec", &self.mfa_token_ttl_sec)
+            .field("reauth_ttl_sec", &self.reauth_ttl_sec)
             .field("rate_limit_mfa_max", &self.rate_limit_mfa_max)
             .field("rate_limit_mfa_window_sec", &self.rate_limit_mfa_window_sec)
+            .field("webauthn_rp_id", &self.webauthn_rp_id)
+            .field("webauthn_rp_name", &self.webauthn_rp_name)
+            .field("webauthn_origins", &self.webauthn_origins)
+            .field(
+                "webauthn_challenge_ttl_sec",
+                &self.webauthn_challenge_ttl_sec,
+            )
             .field("max_sessions_learner", &self.max_sessions_learner)
             .field("max_sessions_manager", &self.max_sessions_manager)
             .field("max_sessions_admin", &self.max_sessions_admin)
@@ -846,6 +1205,47 @@ impl fmt::Debug for Config {
                 "session_reaper_interval_sec",
                 &self.session_reaper_interval_sec,
             )
+            .field(
+                "publish_scheduler_interval_sec",
+                &self.publish_scheduler_interval_sec,
+            )
+            .field(
+                "assignment_reminder_interval_sec",
+                &self.assignment_reminder_interval_sec,
+            )
+            .field(
+                "assignment_reminder_hours_before",
+                &self.assignment_reminder_hours_before,
+            )
+            .field(
+                "gradebook_export_interval_sec",
+                &self.gradebook_export_interval_sec,
+            )
+            .field(
+                "gradebook_export_ttl_hours",
+                &self.gradebook_export_ttl_hours,
+            )
+            .field(
+                "gradebook_export_sync_max_learners",
+                &self.gradebook_export_sync_max_learners,
+            )
+            .field("live_meeting_provider", &self.live_meeting_provider)
+            .field("live_meeting_base_url", &self.live_meeting_base_url)
+            .field(
+                "live_reminder_interval_sec",
+                &self.live_reminder_interval_sec,
+            )
+            .field(
+                "live_reminder_minutes_before",
+                &self.live_reminder_minutes_before,
+            )
+            .field("geoip_city_db_path", &self.geoip_city_db_path)
+            .field("geoip_asn_db_path", &self.geoip_asn_db_path)
+            .field("geoip_reload_interval_sec", &self.geoip_reload_interval_sec)
+            .field("ipgeo_http_fallback", &self.ipgeo_http_fallback)
+            .field("login_risk_action", &self.login_risk_action)
+            .field("login_risk_max_travel_kmh", &self.login_risk_max_travel_kmh)
+            .field("login_risk_history_days", &self.login_risk_history_days)
             .field(
                 "revenuecat_api_key",
                 &self.revenuecat_api_key.as_ref().map(|_| "***"),
@@ -931,6 +1331,17 @@ fn billing_interval_from_price_id(
     None
 }
 
+/// URL 에서 호스트만 추출 (WebAuthn RP ID 기본값). 스킴/포트/경로 제거.
+fn url_host(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = without_scheme.split(['/', '?', '#']).next().unwrap_or("");
+    let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    match host.strip_prefix('[') {
+        Some(v6) => v6.split(']').next().unwrap_or("").to_string(),
+        None => host.split(':').next().unwrap_or("").to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1022,4 +1433,15 @@ mod tests {
             billing_interval_from_price_id("pri_6m", None, None, Some("pri_6m"), Some("pri_12m"));
         assert_eq!(result, Some(BillingInterval::Month6));
     }
+
+    #[test]
+    fn test_url_host_strips_scheme_port_and_path() {
+        assert_eq!(url_host("https://amazingkorean.net"), "amazingkorean.net");
+        assert_eq!(url_host("http://localhost:5173/"), "localhost");
+        assert_eq!(
+            url_host("https://app.amazingkorean.net/login?next=/"),
+            "app.amazingkorean.net"
+        );
+        assert_eq!(url_host("http://[::1]:8080"), "::1");
+    }
 }
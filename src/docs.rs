@@ -50,11 +50,39 @@ impl Modify for SecurityAddon {
         crate::api::auth::handler::google_auth_callback,
         crate::api::auth::handler::google_mobile_login,
         crate::api::auth::handler::apple_mobile_login,
+        crate::api::auth::handler::kakao_auth_start,
+        crate::api::auth::handler::kakao_auth_callback,
+        crate::api::auth::handler::naver_auth_start,
+        crate::api::auth::handler::naver_auth_callback,
+        crate::api::auth::handler::sso_discover,
+        crate::api::auth::handler::sso_oidc_callback,
+        crate::api::auth::handler::sso_saml_acs,
+        crate::api::auth::handler::sso_saml_metadata,
+        crate::api::auth::handler::kakao_mobile_login,
+        crate::api::auth::handler::naver_mobile_login,
         crate::api::auth::handler::mfa_setup,
         crate::api::auth::handler::mfa_verify_setup,
         crate::api::auth::handler::mfa_login,
         crate::api::auth::handler::mfa_login_mobile,
         crate::api::auth::handler::mfa_disable,
+        crate::api::auth::handler::passkey_mfa_options,
+        crate::api::auth::handler::passkey_register_options,
+        crate::api::auth::handler::passkey_register,
+        crate::api::auth::handler::list_passkeys,
+        crate::api::auth::handler::rename_passkey,
+        crate::api::auth::handler::delete_passkey,
+        crate::api::auth::handler::passkey_login_options,
+        crate::api::auth::handler::passkey_login,
+        crate::api::auth::handler::passkey_login_mobile,
+        crate::api::auth::handler::jwks,
+        crate::api::auth::handler::login_step_up,
+        crate::api::auth::handler::login_step_up_mobile,
+        crate::api::auth::handler::risk_revoke,
+        crate::api::auth::handler::passwordless_request,
+        crate::api::auth::handler::passwordless_verify,
+        crate::api::auth::handler::passwordless_verify_mobile,
+        crate::api::auth::handler::reauth,
+        crate::api::auth::handler::reauth_email_code,
 
         // user (me/settings)
         crate::api::user::handler::signup,
@@ -62,10 +90,23 @@ impl Modify for SecurityAddon {
         crate::api::user::handler::update_me,
         crate::api::user::handler::get_settings,
         crate::api::user::handler::update_settings,
+        crate::api::user::handler::list_sessions,
+        crate::api::user::handler::rename_session,
+        crate::api::user::handler::revoke_session,
+        crate::api::user::handler::list_identities,
+        crate::api::user::handler::link_identity_start,
+        crate::api::user::handler::link_identity,
+        crate::api::user::handler::unlink_identity,
+        crate::api::user::handler::set_password,
+        crate::api::user::handler::accept_terms,
 
         // videos (user)
         crate::api::video::handler::list_videos,
         crate::api::video::handler::get_video_detail,
+        crate::api::video::handler::get_video_subtitle,
+        crate::api::video::handler::get_video_playback,
+        crate::api::video::handler::get_video_hls_file,
+        crate::api::video::handler::get_video_hls_key,
         crate::api::video::handler::get_video_progress,
         crate::api::video::handler::update_video_progress,
 
@@ -119,6 +160,8 @@ impl Modify for SecurityAddon {
         crate::api::admin::video::handler::admin_list_videos,
         crate::api::admin::video::handler::admin_create_video,
         crate::api::admin::video::handler::admin_create_vimeo_upload_ticket,
+        crate::api::admin::video::handler::admin_get_vimeo_sync_report,
+        crate::api::admin::video::handler::admin_resync_vimeo,
         crate::api::admin::video::handler::admin_bulk_create_videos,
         crate::api::admin::video::handler::admin_get_video,
         crate::api::admin::video::handler::admin_get_vimeo_preview,
@@ -146,6 +189,25 @@ impl Modify for SecurityAddon {
         crate::api::admin::lesson::handler::admin_bulk_create_lessons,
         crate::api::admin::lesson::handler::admin_bulk_update_lessons,
         crate::api::admin::lesson::handler::admin_update_lesson,
+        crate::api::admin::lesson::prerequisite::handler::admin_list_lesson_prerequisites,
+        crate::api::admin::lesson::prerequisite::handler::admin_set_lesson_prerequisites,
+        crate::api::admin::lesson::prerequisite::handler::admin_list_lesson_unlocks,
+        crate::api::admin::lesson::prerequisite::handler::admin_create_lesson_unlock,
+        crate::api::admin::lesson::prerequisite::handler::admin_delete_lesson_unlock,
+
+        // admin - course curriculum
+        crate::api::admin::course::handler::admin_list_course_lessons,
+        crate::api::admin::course::handler::admin_attach_course_lesson,
+        crate::api::admin::course::handler::admin_reorder_course_lessons,
+        crate::api::admin::course::handler::admin_detach_course_lesson,
+        crate::api::admin::course::handler::admin_list_course_certificates,
+        crate::api::admin::course::handler::admin_revoke_course_certificate,
+
+        // admin - publish schedule
+        crate::api::admin::schedule::handler::admin_publish_calendar,
+        crate::api::admin::schedule::handler::admin_list_target_schedules,
+        crate::api::admin::schedule::handler::admin_set_target_schedule,
+        crate::api::admin::schedule::handler::admin_cancel_schedule,
 
         // admin - studies
         crate::api::admin::study::handler::admin_list_studies,
@@ -172,9 +234,20 @@ impl Modify for SecurityAddon {
 
         // admin - video stats
         crate::api::admin::video::stats::handler::admin_get_video_daily_stats,
+        crate::api::admin::video::stats::handler::admin_get_video_watch_heatmap,
+        crate::api::admin::video::stats::handler::admin_get_video_cue_stats,
         crate::api::admin::video::stats::handler::admin_get_aggregate_daily_stats,
         crate::api::admin::video::stats::handler::admin_get_stats_summary,
         crate::api::admin::video::stats::handler::admin_get_top_videos,
+        crate::api::admin::video::subtitle::handler::admin_list_subtitles,
+        crate::api::admin::video::subtitle::handler::admin_get_subtitle,
+        crate::api::admin::video::subtitle::handler::admin_upload_subtitle,
+        crate::api::admin::video::subtitle::handler::admin_delete_subtitle,
+        crate::api::admin::video::subtitle::handler::admin_push_subtitle_vimeo,
+        crate::api::admin::video::cue::handler::admin_list_cue_points,
+        crate::api::admin::video::cue::handler::admin_create_cue_point,
+        crate::api::admin::video::cue::handler::admin_update_cue_point,
+        crate::api::admin::video::cue::handler::admin_delete_cue_point,
 
         // admin - study stats
         crate::api::admin::study::stats::handler::admin_get_daily_stats,
@@ -200,6 +273,15 @@ impl Modify for SecurityAddon {
         crate::api::admin::translation::handler::admin_get_source_fields,
         crate::api::admin::translation::handler::admin_search_translations,
         crate::api::admin::translation::handler::admin_get_translation_stats,
+        crate::api::admin::translation::handler::admin_list_glossary,
+        crate::api::admin::translation::handler::admin_create_glossary,
+        crate::api::admin::translation::handler::admin_update_glossary,
+        crate::api::admin::translation::handler::admin_delete_glossary,
+        crate::api::admin::translation::handler::admin_scan_translation_qa,
+        crate::api::admin::translation::handler::admin_get_translation_qa,
+        crate::api::admin::translation::handler::admin_list_publish_policies,
+        crate::api::admin::translation::handler::admin_update_publish_policy,
+        crate::api::admin::translation::handler::admin_get_publish_coverage,
 
         // admin - upgrade (관리자 초대)
         crate::api::admin::upgrade::handler::create_invite,
@@ -231,6 +313,22 @@ impl Modify for SecurityAddon {
         crate::api::admin::payment::handler::list_grants,
         crate::api::admin::payment::handler::revoke_grant,
 
+        // admin - org
+        crate::api::admin::org::handler::list_orgs,
+        crate::api::admin::org::handler::create_org,
+        crate::api::admin::org::handler::get_org,
+        crate::api::admin::org::handler::update_org,
+        crate::api::admin::org::handler::add_admin,
+        crate::api::admin::org::handler::remove_admin,
+        crate::api::admin::org::handler::create_license,
+        crate::api::admin::org::handler::update_license,
+        crate::api::admin::org::handler::get_sso,
+        crate::api::admin::org::handler::upsert_sso,
+        crate::api::admin::org::handler::delete_sso,
+
+        // admin - security
+        crate::api::admin::security::handler::list_risk_events,
+
         // admin - textbook
         crate::api::admin::textbook::handler::list_orders,
         crate::api::admin::textbook::handler::get_order,
@@ -245,6 +343,71 @@ impl Modify for SecurityAddon {
         crate::api::course::handler::list,
         crate::api::course::handler::create,
         crate::api::course::handler::get_by_id,
+        crate::api::course::handler::get_curriculum,
+        crate::api::course::handler::enroll,
+        crate::api::course::handler::my_courses,
+
+        // certificate
+        crate::api::certificate::handler::my_certificates,
+        crate::api::certificate::handler::my_certificate_image,
+        crate::api::certificate::handler::verify_certificate,
+
+        // classroom (manager)
+        crate::api::classroom::handler::list_classrooms,
+        crate::api::classroom::handler::create_classroom,
+        crate::api::classroom::handler::get_classroom,
+        crate::api::classroom::handler::update_classroom,
+        crate::api::classroom::handler::list_invites,
+        crate::api::classroom::handler::invite_learner,
+        crate::api::classroom::handler::list_learners,
+        crate::api::classroom::handler::learner_progress,
+        crate::api::classroom::handler::remove_learner,
+        crate::api::classroom::handler::list_joined,
+        crate::api::classroom::handler::join_classroom,
+        crate::api::classroom::handler::accept_invite,
+
+        // assignment
+        crate::api::assignment::handler::list_assignments,
+        crate::api::assignment::handler::create_assignment,
+        crate::api::assignment::handler::get_assignment,
+        crate::api::assignment::handler::update_assignment,
+        crate::api::assignment::handler::delete_assignment,
+        crate::api::assignment::handler::assignment_tracking,
+        crate::api::assignment::handler::my_assignments,
+
+        // gradebook
+        crate::api::gradebook::handler::view_gradebook,
+        crate::api::gradebook::handler::create_export,
+        crate::api::gradebook::handler::list_exports,
+        crate::api::gradebook::handler::get_export,
+        crate::api::gradebook::handler::download_export,
+
+        // live
+        crate::api::live::handler::list_sessions,
+        crate::api::live::handler::create_session,
+        crate::api::live::handler::my_sessions,
+        crate::api::live::handler::get_session,
+        crate::api::live::handler::update_session,
+        crate::api::live::handler::cancel_session,
+        crate::api::live::handler::enroll,
+        crate::api::live::handler::cancel_enrollment,
+        crate::api::live::handler::session_roster,
+        crate::api::live::handler::record_attendance,
+        crate::api::live::handler::my_calendar,
+        crate::api::live::handler::issue_calendar_feed,
+        crate::api::live::handler::revoke_calendar_feed,
+        crate::api::live::handler::calendar_feed,
+
+        // org
+        crate::api::org::handler::my_orgs,
+        crate::api::org::handler::accept_seat,
+        crate::api::org::handler::get_org,
+        crate::api::org::handler::org_dashboard,
+        crate::api::org::handler::list_seats,
+        crate::api::org::handler::invite_seats,
+        crate::api::org::handler::revoke_seat,
+        crate::api::org::handler::get_org_sso,
+        crate::api::org::handler::update_org_sso_enforcement,
 
         // admin - ebook
         crate::api::admin::ebook::handler::list_purchases,
@@ -305,6 +468,8 @@ impl Modify for SecurityAddon {
             crate::api::auth::dto::GoogleAuthUrlRes,
             crate::api::auth::dto::GoogleMobileLoginReq,
             crate::api::auth::dto::AppleMobileLoginReq,
+            crate::api::auth::dto::KakaoMobileLoginReq,
+            crate::api::auth::dto::NaverMobileLoginReq,
             crate::api::auth::dto::MfaChallengeRes,
             crate::api::auth::dto::MfaLoginReq,
             crate::api::auth::dto::MfaSetupRes,
@@ -312,6 +477,26 @@ impl Modify for SecurityAddon {
             crate::api::auth::dto::MfaVerifySetupRes,
             crate::api::auth::dto::MfaDisableReq,
             crate::api::auth::dto::MfaDisableRes,
+            crate::api::auth::dto::PasskeyAttestationResponse,
+            crate::api::auth::dto::PasskeyRegistrationCredential,
+            crate::api::auth::dto::PasskeyAssertionResponse,
+            crate::api::auth::dto::PasskeyAssertionCredential,
+            crate::api::auth::dto::PasskeyOptionsRes,
+            crate::api::auth::dto::PasskeyRegisterReq,
+            crate::api::auth::dto::PasskeyLoginReq,
+            crate::api::auth::dto::PasskeyMfaOptionsReq,
+            crate::api::auth::dto::PasskeyRenameReq,
+            crate::api::auth::dto::PasskeyRes,
+            crate::api::auth::dto::PasskeyListRes,
+            crate::api::auth::dto::StepUpLoginReq,
+            crate::api::auth::dto::RiskRevokeReq,
+            crate::api::auth::dto::PasswordlessMethod,
+            crate::api::auth::dto::PasswordlessRequestReq,
+            crate::api::auth::dto::PasswordlessRequestRes,
+            crate::api::auth::dto::PasswordlessVerifyReq,
+            crate::api::auth::dto::ReauthMethod,
+            crate::api::auth::dto::ReauthReq,
+            crate::api::auth::dto::ReauthRes,
 
             // user dto
             crate::api::user::dto::SignupReq,
@@ -320,6 +505,14 @@ impl Modify for SecurityAddon {
             crate::api::user::dto::ProfileUpdateReq,
             crate::api::user::dto::SettingsRes,
             crate::api::user::dto::SettingsUpdateReq,
+            crate::api::user::dto::SessionRenameReq,
+            crate::api::user::dto::SessionItem,
+            crate::api::user::dto::SessionListRes,
+            crate::api::user::dto::IdentityItem,
+            crate::api::user::dto::IdentityListRes,
+            crate::api::user::dto::IdentityLinkReq,
+            crate::api::user::dto::SetPasswordReq,
+            crate::api::user::dto::TermsConsentReq,
             /*crate::api::user::dto::StudyLangItem, // 향후 추가할 내용*/
 
             // course dto
@@ -328,12 +521,127 @@ impl Modify for SecurityAddon {
             crate::api::course::dto::CourseDetailRes,
             crate::api::course::dto::CreateCourseReq,
             crate::api::course::dto::CreateCourseRes,
+            crate::api::course::dto::CourseLessonNode,
+            crate::api::course::dto::CourseEnrollmentRes,
+            crate::api::course::dto::CourseCurriculumRes,
+            crate::api::course::dto::MyCourseListRes,
+
+            // admin - course curriculum dto
+            crate::api::admin::course::dto::CourseLessonAttachReq,
+            crate::api::admin::course::dto::CourseLessonReorderReq,
+            crate::api::admin::course::dto::AdminCourseLessonRes,
+            crate::api::admin::course::dto::AdminCourseLessonListRes,
+            crate::api::admin::course::dto::CertificateRevokeReq,
+            crate::api::admin::course::dto::AdminCertificateRes,
+            crate::api::admin::course::dto::AdminCertificateListRes,
+            crate::types::PublishTarget,
+            crate::types::PublishScheduleStatus,
+            crate::api::admin::schedule::dto::PublishScheduleSetReq,
+            crate::api::admin::schedule::dto::PublishScheduleRes,
+            crate::api::admin::schedule::dto::PublishScheduleListRes,
+            crate::api::admin::schedule::dto::PublishCalendarRes,
+            crate::api::certificate::dto::CertificateStatus,
+            crate::api::certificate::dto::MyCertificateRes,
+            crate::api::certificate::dto::MyCertificateListRes,
+            crate::api::certificate::dto::CertificateVerifyRes,
+            crate::types::ClassroomMemberState,
+            crate::api::classroom::dto::CreateClassroomReq,
+            crate::api::classroom::dto::UpdateClassroomReq,
+            crate::api::classroom::dto::ClassroomRes,
+            crate::api::classroom::dto::ClassroomListRes,
+            crate::api::classroom::dto::ClassroomInviteReq,
+            crate::api::classroom::dto::ClassroomInviteRes,
+            crate::api::classroom::dto::ClassroomInviteListRes,
+            crate::api::classroom::dto::JoinClassroomReq,
+            crate::api::classroom::dto::AcceptClassroomInviteReq,
+            crate::api::classroom::dto::JoinedClassroomRes,
+            crate::api::classroom::dto::JoinedClassroomListRes,
+            crate::api::classroom::dto::LearnerProgressSummary,
+            crate::api::classroom::dto::ClassroomLearnerRes,
+            crate::api::classroom::dto::ClassroomLearnerListRes,
+            crate::api::classroom::dto::LearnerVideoProgress,
+            crate::api::classroom::dto::LearnerLessonProgress,
+            crate::api::classroom::dto::LearnerStudyProgress,
+            crate::api::classroom::dto::LearnerGuideProgress,
+            crate::api::classroom::dto::ClassroomLearnerProgressRes,
+
+            // assignment dto
+            crate::types::AssignmentItemType,
+            crate::types::AssignmentLatePolicy,
+            crate::api::assignment::dto::AssignmentItemReq,
+            crate::api::assignment::dto::CreateAssignmentReq,
+            crate::api::assignment::dto::UpdateAssignmentReq,
+            crate::api::assignment::dto::AssignmentItemRes,
+            crate::api::assignment::dto::AssignmentRes,
+            crate::api::assignment::dto::AssignmentListRes,
+            crate::api::assignment::dto::AssignmentStatus,
+            crate::api::assignment::dto::AssignmentItemProgress,
+            crate::api::assignment::dto::AssignmentLearnerStatus,
+            crate::api::assignment::dto::AssignmentTrackingSummary,
+            crate::api::assignment::dto::AssignmentTrackingRes,
+            crate::api::assignment::dto::MyAssignmentRes,
+            crate::api::assignment::dto::MyAssignmentListRes,
+
+            // gradebook dto
+            crate::types::GradebookExportStatus,
+            crate::types::GradebookExportFormat,
+            crate::api::gradebook::dto::GradebookWeights,
+            crate::api::gradebook::dto::CreateGradebookExportReq,
+            crate::api::gradebook::dto::GradebookCategory,
+            crate::api::gradebook::dto::GradebookColumn,
+            crate::api::gradebook::dto::GradebookRow,
+            crate::api::gradebook::dto::GradebookRes,
+            crate::api::gradebook::dto::GradebookExportRes,
+            crate::api::gradebook::dto::GradebookExportListRes,
+
+            // live dto
+            crate::types::LiveSessionState,
+            crate::types::LiveEnrollmentState,
+            crate::api::live::dto::CreateLiveSessionReq,
+            crate::api::live::dto::UpdateLiveSessionReq,
+            crate::api::live::dto::LiveAttendanceRecordReq,
+            crate::api::live::dto::RecordLiveAttendanceReq,
+            crate::api::live::dto::LiveSessionRes,
+            crate::api::live::dto::LiveSessionListRes,
+            crate::api::live::dto::LiveEnrollmentRes,
+            crate::api::live::dto::LiveRosterEntry,
+            crate::api::live::dto::LiveRosterRes,
+            crate::api::live::dto::LiveCalendarFeedRes,
+
+            // org dto
+            crate::types::OrgLicenseScope,
+            crate::types::OrgSeatState,
+            crate::types::OrgSsoProtocol,
+            crate::types::LoginRiskAction,
+            crate::api::org::dto::OrgRes,
+            crate::api::org::dto::OrgAdminRes,
+            crate::api::org::dto::OrgLicenseRes,
+            crate::api::org::dto::OrgDetailRes,
+            crate::api::org::dto::MyOrgListRes,
+            crate::api::org::dto::InviteOrgSeatsReq,
+            crate::api::org::dto::OrgSeatRes,
+            crate::api::org::dto::OrgSeatListRes,
+            crate::api::org::dto::SkippedSeatInvite,
+            crate::api::org::dto::InviteOrgSeatsRes,
+            crate::api::org::dto::AcceptOrgSeatReq,
+            crate::api::org::dto::OrgSeatAcceptRes,
+            crate::api::org::dto::OrgSeatTotals,
+            crate::api::org::dto::OrgLearnerActivity,
+            crate::api::org::dto::OrgDashboardRes,
+            crate::api::org::dto::OrgSsoRes,
+            crate::api::org::dto::UpdateOrgSsoEnforcementReq,
 
             // videos dto
             crate::api::video::dto::VideoListReq,
             crate::api::video::dto::VideoListItem,
             crate::api::video::dto::VideoTagDetail,
             crate::api::video::dto::VideoDetailRes,
+            crate::api::video::dto::WatchSegment,
+            crate::api::video::dto::VideoSubtitleTrack,
+            crate::api::video::dto::VideoPlaybackRes,
+            crate::api::video::dto::VideoCuePoint,
+            crate::types::VideoHostKind,
+            crate::api::video::dto::SubtitleTrackSource,
             crate::api::video::dto::VideoProgressRes,
             crate::api::video::dto::VideoProgressUpdateReq,
 
@@ -350,6 +658,9 @@ impl Modify for SecurityAddon {
             crate::api::lesson::dto::LessonItemsRes,
             crate::api::lesson::dto::LessonProgressRes,
             crate::api::lesson::dto::LessonProgressUpdateReq,
+            crate::api::lesson::dto::LessonLockReason,
+            crate::api::lesson::dto::LessonLockRes,
+            crate::types::LessonPrereqKind,
 
             // studies dto
             crate::api::study::dto::StudyListResp,
@@ -444,6 +755,12 @@ impl Modify for SecurityAddon {
             crate::api::admin::video::dto::VideoTagBulkUpdateReq,
             crate::api::admin::video::dto::VideoTagUpdateReq,
             crate::api::admin::video::dto::VideoUpdateReq,
+            crate::api::admin::video::dto::VimeoResyncReq,
+            crate::api::admin::video::dto::VimeoResyncRes,
+            crate::api::admin::video::dto::VimeoSyncRunRes,
+            crate::api::admin::video::dto::VimeoSyncItemResult,
+            crate::api::admin::video::dto::VimeoSyncFlaggedItem,
+            crate::api::admin::video::dto::VimeoSyncReportRes,
 
             // admin - lessons dto
             crate::api::admin::lesson::dto::LessonListReq,
@@ -480,6 +797,13 @@ impl Modify for SecurityAddon {
             crate::api::admin::lesson::dto::AdminLessonProgressListRes,
             crate::api::admin::lesson::dto::AdminLessonRes,
             crate::api::admin::lesson::dto::AdminLessonListRes,
+            crate::api::admin::lesson::prerequisite::dto::LessonPrerequisiteRuleReq,
+            crate::api::admin::lesson::prerequisite::dto::LessonPrerequisiteSetReq,
+            crate::api::admin::lesson::prerequisite::dto::LessonUnlockCreateReq,
+            crate::api::admin::lesson::prerequisite::dto::AdminLessonPrerequisiteRes,
+            crate::api::admin::lesson::prerequisite::dto::AdminLessonPrerequisiteListRes,
+            crate::api::admin::lesson::prerequisite::dto::AdminLessonUnlockRes,
+            crate::api::admin::lesson::prerequisite::dto::AdminLessonUnlockListRes,
 
             // admin - studies dto
             crate::api::admin::study::dto::StudyListReq,
@@ -552,11 +876,48 @@ impl Modify for SecurityAddon {
             crate::api::admin::translation::dto::TranslationSearchRes,
             crate::api::admin::translation::dto::TranslationStatItem,
             crate::api::admin::translation::dto::TranslationStatsRes,
+            crate::api::admin::translation::dto::TranslationQaStatItem,
+            crate::api::admin::translation::dto::GlossaryListReq,
+            crate::api::admin::translation::dto::GlossaryListRes,
+            crate::api::admin::translation::dto::GlossaryCreateReq,
+            crate::api::admin::translation::dto::GlossaryUpdateReq,
+            crate::api::admin::translation::dto::TranslationGlossaryRes,
+            crate::api::admin::translation::dto::TranslationQaScanReq,
+            crate::api::admin::translation::dto::TranslationQaScanRes,
+            crate::api::admin::translation::dto::TranslationQaItem,
+            crate::api::admin::translation::qa::QaIssue,
+            crate::api::admin::translation::qa::QaIssueCode,
+            crate::api::admin::translation::qa::QaSeverity,
+            crate::api::admin::translation::dto::PublishPolicyRes,
+            crate::api::admin::translation::dto::PublishPolicyListRes,
+            crate::api::admin::translation::dto::PublishPolicyUpdateReq,
+            crate::api::admin::translation::dto::PublishCoverageReq,
+            crate::api::admin::translation::dto::PublishCoverageRes,
+            crate::api::admin::translation::dto::PublishMissingField,
 
             // admin - video stats dto
             crate::api::admin::video::stats::dto::DailyStatsQuery,
             crate::api::admin::video::stats::dto::DailyStatItem,
             crate::api::admin::video::stats::dto::DailyStatsRes,
+            crate::api::admin::video::stats::dto::WatchHeatmapQuery,
+            crate::api::admin::video::stats::dto::WatchHeatmapBucket,
+            crate::api::admin::video::stats::dto::WatchHeatmapRes,
+            crate::api::admin::video::stats::dto::VideoCueStatItem,
+            crate::api::admin::video::stats::dto::VideoCueStatsRes,
+
+            // admin - video subtitle dto
+            crate::api::video::subtitle::SubtitleFormat,
+            crate::api::admin::video::subtitle::dto::SubtitleUploadReq,
+            crate::api::admin::video::subtitle::dto::SubtitleUploadRes,
+            crate::api::admin::video::subtitle::dto::AdminSubtitleTrackRes,
+            crate::api::admin::video::subtitle::dto::AdminSubtitleListRes,
+            crate::api::admin::video::subtitle::dto::AdminSubtitleCueRes,
+            crate::api::admin::video::subtitle::dto::AdminSubtitleDetailRes,
+            crate::api::admin::video::subtitle::dto::SubtitleVimeoPushRes,
+            crate::api::admin::video::cue::dto::VideoCuePointCreateReq,
+            crate::api::admin::video::cue::dto::VideoCuePointUpdateReq,
+            crate::api::admin::video::cue::dto::AdminVideoCuePointRes,
+            crate::api::admin::video::cue::dto::AdminVideoCuePointListRes,
 
             // admin - upgrade dto (관리자 초대)
             crate::api::admin::upgrade::dto::UpgradeInviteReq,
@@ -605,6 +966,24 @@ impl Modify for SecurityAddon {
             crate::api::admin::payment::dto::AdminGrantListRes,
             crate::api::admin::payment::dto::AdminCancelSubReq,
 
+            // admin - org dto
+            crate::api::admin::org::dto::AdminOrgListReq,
+            crate::api::admin::org::dto::AdminOrgSummary,
+            crate::api::admin::org::dto::AdminOrgMeta,
+            crate::api::admin::org::dto::AdminOrgListRes,
+            crate::api::admin::org::dto::AdminCreateOrgReq,
+            crate::api::admin::org::dto::AdminUpdateOrgReq,
+            crate::api::admin::org::dto::AdminAddOrgAdminReq,
+            crate::api::admin::org::dto::AdminCreateLicenseReq,
+            crate::api::admin::org::dto::AdminUpdateLicenseReq,
+            crate::api::admin::org::dto::AdminUpsertOrgSsoReq,
+
+            // admin - security dto
+            crate::api::admin::security::dto::AdminRiskEventListReq,
+            crate::api::admin::security::dto::AdminRiskEventItem,
+            crate::api::admin::security::dto::AdminRiskEventMeta,
+            crate::api::admin::security::dto::AdminRiskEventListRes,
+
             // admin - textbook dto
             crate::api::admin::textbook::dto::AdminTextbookListReq,
             crate::api::admin::textbook::dto::AdminTextbookMeta,
@@ -651,11 +1030,20 @@ impl Modify for SecurityAddon {
         (name = "lesson", description = "Lesson APIs"),
         (name = "admin", description = "Admin user & content management"),
         (name = "admin_translation", description = "Admin translation management"),
+        (name = "admin_schedule", description = "Admin scheduled publish/unpublish calendar"),
         (name = "Payment", description = "Subscription and payment APIs (webhooks intentionally excluded)"),
         (name = "Textbook", description = "Textbook catalog and orders (user-facing)"),
         (name = "admin_payment", description = "Admin subscription/transaction/grant management"),
+        (name = "admin_org", description = "Admin organizations, org admins and seat licenses"),
+        (name = "admin_security", description = "Admin login risk events (new country, new device, impossible travel)"),
         (name = "Admin Textbook", description = "Admin textbook order management"),
         (name = "Course", description = "Course catalog (user-facing)"),
+        (name = "Certificate", description = "Course completion certificates and public verification"),
+        (name = "Classroom", description = "Manager classrooms (own learners only) and learner join/invite acceptance"),
+        (name = "Gradebook", description = "Weighted learner gradebook and asynchronous CSV/XLSX exports with expiring downloads"),
+        (name = "Live", description = "Live class sessions for live courses: capacity with waitlist, attendance feeding course progress, reminders and ICS calendar feeds"),
+        (name = "Organization", description = "Organization seat licenses: seat invites/revocation, seat acceptance and org learner dashboard"),
+        (name = "Assignment", description = "Assignments with due dates, late policy and completion tracking derived from learning progress"),
         (name = "Admin Ebook", description = "Admin ebook purchase management + watermark verification"),
         (name = "Ebook", description = "Ebook catalog, purchase (Paddle/IAP), and DRM-protected viewer (user-facing)")
     )
@@ -689,6 +1077,16 @@ mod tests {
             "/auth/google/callback",
             "/auth/google-mobile",
             "/auth/apple-mobile",
+            "/auth/kakao",
+            "/auth/kakao/callback",
+            "/auth/naver",
+            "/auth/naver/callback",
+            "/auth/kakao-mobile",
+            "/auth/naver-mobile",
+            "/auth/sso/discover",
+            "/auth/sso/oidc/callback",
+            "/auth/sso/saml/acs",
+            "/auth/sso/saml/metadata",
             "/auth/mfa/login-mobile",
             // payment user-facing (3)
             "/payment/plans",
@@ -794,6 +1192,8 @@ mod tests {
             "GoogleAuthUrlRes",
             "GoogleMobileLoginReq",
             "AppleMobileLoginReq",
+            "KakaoMobileLoginReq",
+            "NaverMobileLoginReq",
             // payment
             "PlansRes",
             "SubscriptionRes",
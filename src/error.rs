@@ -17,6 +17,9 @@ pub enum AppError {
     BadRequest(String),
     #[error("Unprocessable entity: {0}")]
     Unprocessable(String),
+    /// 422 + 구조화 details (예: 공개 정책 미충족 필드/언어 목록)
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableDetails(String, serde_json::Value),
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
     #[error("Forbidden: {0}")]
@@ -104,6 +107,13 @@ impl IntoResponse for AppError {
                 None,
                 None,
             ),
+            AppError::UnprocessableDetails(msg, details) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "UNPROCESSABLE_ENTITY".to_string(),
+                msg.clone(),
+                Some(details),
+                None,
+            ),
             AppError::Unauthorized(msg) => (
                 StatusCode::UNAUTHORIZED,
                 "UNAUTHORIZED".to_string(),
@@ -303,6 +313,19 @@ mod tests {
         assert_eq!(extract_error_field(&body, "code"), "UNPROCESSABLE_ENTITY");
     }
 
+    #[tokio::test]
+    async fn unprocessable_details_returns_422_with_details() {
+        let details = serde_json::json!({ "missing": [{ "field_name": "video_title" }] });
+        let (status, body) = into_parts(AppError::UnprocessableDetails(
+            "policy".into(),
+            details.clone(),
+        ))
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(extract_error_field(&body, "code"), "UNPROCESSABLE_ENTITY");
+        assert_eq!(body["error"]["details"], details);
+    }
+
     #[tokio::test]
     async fn unauthorized_returns_401_with_unauthorized_code() {
         let (status, body) = into_parts(AppError::Unauthorized("token".into())).await;
@@ -1,42 +1,21 @@
-use reqwest::Client;
 use serde::Deserialize;
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
 
 use crate::api::auth::service::OAuthUserInfo;
-use crate::error::{AppError, AppResult};
+use crate::error::AppResult;
+use crate::external::oidc::{OidcClient, OidcClientConfig, ProviderMetadata};
 
 pub(crate) const APPLE_JWKS_URL: &str = "https://appleid.apple.com/auth/keys";
 const APPLE_ISSUER: &str = "https://appleid.apple.com";
+const APPLE_AUTH_URL: &str = "https://appleid.apple.com/auth/authorize";
+const APPLE_TOKEN_URL: &str = "https://appleid.apple.com/auth/token";
 
 /// Apple OAuth 클라이언트 (Sign in with Apple — 모바일 ID token 직접 검증).
 ///
 /// AppState 에 `Arc<AppleOAuthClient>` 싱글톤으로 보관해 요청마다 재생성하지 않도록
 /// 한다. `reqwest::Client` 는 내부 커넥션 풀을 가지고 있어 재사용이 필수. JWKS 는
-/// 토큰 `kid` 기반으로 캐싱해 매 로그인마다 Apple 서버를 때리지 않는다.
+/// `OidcClient` 의 공유 캐시가 토큰 `kid` 기반으로 보관해 매 로그인마다 Apple 서버를 때리지 않는다.
 pub struct AppleOAuthClient {
-    client: Client,
-    client_id: String, // Apple Bundle ID (e.g., net.amazingkorean.app)
-    /// JWKS endpoint URL (default = 공식 Apple. test 시 wiremock 으로 override).
-    jwks_url: String,
-    /// kid → DecodingKey 캐시. Apple 이 키 로테이션을 해도 토큰 kid 가 바뀌면
-    /// cache miss 로 떨어져 자동으로 새 키를 가져온다.
-    jwks_cache: Arc<RwLock<HashMap<String, jsonwebtoken::DecodingKey>>>,
-}
-
-/// Apple JWKS response
-#[derive(Debug, Deserialize)]
-struct AppleJwks {
-    keys: Vec<AppleJwk>,
-}
-
-/// Single JWK from Apple's JWKS endpoint
-#[derive(Debug, Deserialize)]
-struct AppleJwk {
-    kid: String,
-    n: String,
-    e: String,
+    inner: OidcClient, // client_id = Apple Bundle ID (e.g., net.amazingkorean.app)
 }
 
 /// Apple ID Token Claims
@@ -65,88 +44,27 @@ impl AppleOAuthClient {
     ///
     /// B5 Tier 2: builder fail 시 panic 회피 → Result 전파.
     pub fn with_url(client_id: String, jwks_url: String) -> AppResult<Self> {
-        // N-10: 외부 서비스 hang 방지 (timeout 15초)
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(15))
-            .build()
-            .map_err(|e| AppError::Internal(format!("apple client init: {}", e)))?;
-        Ok(Self {
-            client,
-            client_id,
-            jwks_url,
-            jwks_cache: Arc::new(RwLock::new(HashMap::new())),
-        })
+        let inner = OidcClient::new(
+            "Apple",
+            ProviderMetadata {
+                issuer: Some(APPLE_ISSUER.to_string()),
+                authorization_endpoint: APPLE_AUTH_URL.to_string(),
+                token_endpoint: APPLE_TOKEN_URL.to_string(),
+                jwks_uri: Some(jwks_url),
+                userinfo_endpoint: None,
+            },
+            OidcClientConfig {
+                client_id,
+                scope: "name email",
+                ..Default::default()
+            },
+        )?;
+        Ok(Self { inner })
     }
 
-    /// kid 에 대응하는 DecodingKey 를 캐시에서 조회하거나, miss 시 Apple JWKS 를
-    /// 한 번만 호출해 캐시에 적재한다.
-    async fn get_decoding_key(&self, kid: &str) -> AppResult<jsonwebtoken::DecodingKey> {
-        // 1차 read-lock 으로 캐시 조회
-        {
-            let cache = self.jwks_cache.read().await;
-            if let Some(key) = cache.get(kid) {
-                return Ok(key.clone());
-            }
-        }
-
-        // Cache miss — JWKS 전체를 가져와 캐시에 모두 적재.
-        let jwks: AppleJwks = self
-            .client
-            .get(&self.jwks_url)
-            .send()
-            .await
-            .map_err(|e| AppError::External(format!("Failed to fetch Apple JWKS: {}", e)))?
-            .json()
-            .await
-            .map_err(|e| AppError::External(format!("Failed to parse Apple JWKS: {}", e)))?;
-
-        let mut cache = self.jwks_cache.write().await;
-        // Double-checked locking: read-lock 해제와 write-lock 획득 사이에 다른 요청이
-        // 이미 JWKS 를 적재했을 수 있음. 중복 DecodingKey 생성/삽입을 피해 동시
-        // 캐시 미스 시 작업량을 감소시킨다.
-        if let Some(key) = cache.get(kid) {
-            return Ok(key.clone());
-        }
-        for jwk in &jwks.keys {
-            let decoding_key = jsonwebtoken::DecodingKey::from_rsa_components(&jwk.n, &jwk.e)
-                .map_err(|e| {
-                    AppError::External(format!("Failed to create Apple decoding key: {}", e))
-                })?;
-            cache.insert(jwk.kid.clone(), decoding_key);
-        }
-
-        cache
-            .get(kid)
-            .cloned()
-            .ok_or_else(|| AppError::External("No matching key found in Apple JWKS".into()))
-    }
-
-    /// ID Token 디코딩 + Apple JWKS 서명 검증 (google.rs::decode_id_token 패턴 복제)
+    /// ID Token 디코딩 + Apple JWKS 서명 검증 (issuer + audience)
     pub async fn decode_id_token(&self, id_token: &str) -> AppResult<AppleIdTokenClaims> {
-        // JWT 헤더에서 kid 추출
-        let header = jsonwebtoken::decode_header(id_token)
-            .map_err(|e| AppError::External(format!("Invalid Apple ID token header: {}", e)))?;
-
-        let kid = header
-            .kid
-            .ok_or_else(|| AppError::External("Apple ID token missing kid in header".into()))?;
-
-        // 캐시 우선 조회, miss 면 JWKS 1회 fetch + 전체 적재
-        let decoding_key = self.get_decoding_key(&kid).await?;
-
-        // 검증 설정: issuer + audience + 서명
-        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
-        validation.set_issuer(&[APPLE_ISSUER]);
-        validation.set_audience(&[&self.client_id]);
-
-        // 서명 검증 + 디코딩
-        let token_data =
-            jsonwebtoken::decode::<AppleIdTokenClaims>(id_token, &decoding_key, &validation)
-                .map_err(|e| {
-                    AppError::External(format!("Apple ID token verification failed: {}", e))
-                })?;
-
-        Ok(token_data.claims)
+        self.inner.verify_id_token(id_token).await
     }
 
     /// Claims에서 OAuthUserInfo 추출
@@ -127,6 +127,70 @@ pub enum EmailTemplate {
         invited_by: String,
         expires_in_min: i32,
     },
+    /// 클래스 초대 (manager → 학습자)
+    ClassroomInvite {
+        invite_url: String,
+        classroom_name: String,
+        invited_by: String,
+        expires_in_days: i32,
+    },
+    /// 과제 마감 리마인더 (due_at 은 표시용 문자열)
+    AssignmentReminder {
+        assignment_title: String,
+        due_at: String,
+        assignment_url: String,
+    },
+    /// 라이브 수업 시작 전 리마인더 (start_at 은 회차 시간대 기준 표시용 문자열)
+    LiveSessionReminder {
+        session_title: String,
+        start_at: String,
+        meeting_url: String,
+    },
+    /// 라이브 수업 대기 → 확정 승격 안내
+    LiveWaitlistPromoted {
+        session_title: String,
+        start_at: String,
+        meeting_url: String,
+    },
+    /// 기관 좌석 초대 (valid_until 은 표시용 문자열)
+    OrgSeatInvite {
+        invite_url: String,
+        org_name: String,
+        license_label: String,
+        valid_until: String,
+        expires_in_days: i32,
+    },
+    /// 새 국가/기기 등 위험 신호가 있는 로그인 알림 (revoke_url = 전체 세션 폐기)
+    LoginRiskAlert {
+        signed_in_at: String,
+        location: String,
+        device: String,
+        reasons: Vec<String>,
+        revoke_url: String,
+        expires_in_days: i32,
+    },
+    /// 위험 로그인 추가 확인 (step-up) 인증 코드
+    LoginStepUpCode {
+        code: String,
+        location: String,
+        device: String,
+        expires_in_min: i32,
+    },
+    /// 비밀번호 없는 로그인 — 매직 링크
+    MagicLinkLogin {
+        login_url: String,
+        expires_in_min: i32,
+    },
+    /// 비밀번호 없는 로그인 — 6자리 일회용 코드
+    LoginCode { code: String, expires_in_min: i32 },
+    /// 보안 설정 변경 전 본인 재확인 (패스키 등록, 소셜 계정 연결 등) 6자리 코드
+    ReauthCode { code: String, expires_in_min: i32 },
+    /// 계정 보안 설정 변경 알림 (소셜 계정 연결/해제, 비밀번호 설정 등)
+    SecurityNotice {
+        action: String,
+        detail: String,
+        changed_at: String,
+    },
     /// 교재 주문 접수 확인
     TextbookOrderConfirmation {
         order_code: String,
@@ -159,14 +223,701 @@ pub enum EmailTemplate {
     },
 }
 
-/// 템플릿을 HTML/텍스트로 렌더링
-fn render_template(template: EmailTemplate) -> (String, String, String) {
-    match template {
-        EmailTemplate::PasswordResetCode {
+/// 템플릿을 HTML/텍스트로 렌더링
+fn render_template(template: EmailTemplate) -> (String, String, String) {
+    match template {
+        EmailTemplate::PasswordResetCode {
+            code,
+            expires_in_min,
+        } => {
+            let subject = "[Amazing Korean] 비밀번호 재설정 인증 코드".to_string();
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">비밀번호 재설정</h2>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                아래 인증 코드를 입력하여 비밀번호를 재설정하세요.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 30px; text-align: center; margin-bottom: 30px;">
+                                <span style="font-size: 36px; font-weight: bold; letter-spacing: 8px; color: #333;">{code}</span>
+                            </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 코드는 <strong>{expires_in_min}분</strong> 후 만료됩니다.
+                            </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                본인이 요청하지 않았다면 이 이메일을 무시하세요.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 비밀번호 재설정\n\n인증 코드: {code}\n\n이 코드는 {expires_in_min}분 후 만료됩니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::EmailVerification {
+            code,
+            expires_in_min,
+        } => {
+            let subject = "[Amazing Korean] 이메일 인증 코드".to_string();
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">이메일 인증</h2>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                Amazing Korean에 가입해 주셔서 감사합니다!<br>
+                                아래 인증 코드를 입력하여 이메일을 인증하세요.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 30px; text-align: center; margin-bottom: 30px;">
+                                <span style="font-size: 36px; font-weight: bold; letter-spacing: 8px; color: #333;">{code}</span>
+                            </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 코드는 <strong>{expires_in_min}분</strong> 후 만료됩니다.
+                            </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                본인이 요청하지 않았다면 이 이메일을 무시하세요.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 이메일 인증\n\nAmazing Korean에 가입해 주셔서 감사합니다!\n\n인증 코드: {code}\n\n이 코드는 {expires_in_min}분 후 만료됩니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::Welcome { nickname } => {
+            let subject = "[Amazing Korean] 가입을 환영합니다!".to_string();
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">{nickname}님, 환영합니다!</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                Amazing Korean에 가입해 주셔서 감사합니다.
+                            </p>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                지금 바로 한국어 학습을 시작해 보세요!
+                            </p>
+                            <div style="text-align: center;">
+                                <a href="https://amazingkorean.net" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    학습 시작하기
+                                </a>
+                            </div>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 가입을 환영합니다!\n\n{nickname}님, Amazing Korean에 가입해 주셔서 감사합니다.\n\n지금 바로 한국어 학습을 시작해 보세요!\n\nhttps://amazingkorean.net"
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::AdminInvite {
+            invite_url,
+            role,
+            invited_by,
+            expires_in_min,
+        } => {
+            let role_display = match role.as_str() {
+                "admin" => "관리자 (Admin)",
+                "manager" => "매니저 (Manager)",
+                _ => &role,
+            };
+            let subject = "[Amazing Korean] 관리자 초대".to_string();
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">관리자 초대</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                Amazing Korean 관리자로 초대되었습니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>권한:</strong> {role_display}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>초대자:</strong> {invited_by}
+                                </p>
+                            </div>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                아래 버튼을 클릭하여 관리자 계정을 생성하세요.
+                            </p>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{invite_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    관리자 계정 생성
+                                </a>
+                            </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 링크는 <strong>{expires_in_min}분</strong> 후 만료됩니다.
+                            </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                본인이 요청하지 않았다면 이 이메일을 무시하세요.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 관리자 초대\n\nAmazing Korean 관리자로 초대되었습니다.\n\n권한: {role_display}\n초대자: {invited_by}\n\n아래 링크를 클릭하여 관리자 계정을 생성하세요:\n{invite_url}\n\n이 링크는 {expires_in_min}분 후 만료됩니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::ClassroomInvite {
+            invite_url,
+            classroom_name,
+            invited_by,
+            expires_in_days,
+        } => {
+            let subject = format!("[Amazing Korean] {classroom_name} 클래스 초대");
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">클래스 초대</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                Amazing Korean 클래스에 초대되었습니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>클래스:</strong> {classroom_name}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>초대자:</strong> {invited_by}
+                                </p>
+                            </div>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                로그인 후 아래 버튼을 클릭하면 클래스에 참여합니다.
+                            </p>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{invite_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    클래스 참여하기
+                                </a>
+                            </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 링크는 <strong>{expires_in_days}일</strong> 후 만료됩니다.
+                            </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                초대받은 이메일과 같은 계정으로 로그인해야 참여할 수 있습니다.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 클래스 초대\n\nAmazing Korean 클래스에 초대되었습니다.\n\n클래스: {classroom_name}\n초대자: {invited_by}\n\n로그인 후 아래 링크를 클릭하면 클래스에 참여합니다:\n{invite_url}\n\n이 링크는 {expires_in_days}일 후 만료됩니다.\n초대받은 이메일과 같은 계정으로 로그인해야 참여할 수 있습니다."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::AssignmentReminder {
+            assignment_title,
+            due_at,
+            assignment_url,
+        } => {
+            let subject = format!("[Amazing Korean] 과제 마감 안내: {assignment_title}");
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">과제 마감 안내</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                아직 완료하지 않은 과제의 마감이 다가오고 있습니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>과제:</strong> {assignment_title}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>마감:</strong> {due_at}
+                                </p>
+                            </div>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{assignment_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    과제 확인하기
+                                </a>
+                            </div>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 과제 마감 안내\n\n아직 완료하지 않은 과제의 마감이 다가오고 있습니다.\n\n과제: {assignment_title}\n마감: {due_at}\n\n과제 확인:\n{assignment_url}"
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::LiveSessionReminder {
+            session_title,
+            start_at,
+            meeting_url,
+        } => {
+            let subject = format!("[Amazing Korean] 라이브 수업 시작 안내: {session_title}");
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">라이브 수업 시작 안내</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                신청하신 라이브 수업이 곧 시작됩니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>수업:</strong> {session_title}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>시작:</strong> {start_at}
+                                </p>
+                            </div>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{meeting_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    수업 참여하기
+                                </a>
+                            </div>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 라이브 수업 시작 안내\n\n신청하신 라이브 수업이 곧 시작됩니다.\n\n수업: {session_title}\n시작: {start_at}\n\n수업 참여:\n{meeting_url}"
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::LiveWaitlistPromoted {
+            session_title,
+            start_at,
+            meeting_url,
+        } => {
+            let subject = format!("[Amazing Korean] 라이브 수업 신청 확정: {session_title}");
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">라이브 수업 신청 확정</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                자리가 생겨 대기 중이던 라이브 수업 신청이 확정되었습니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>수업:</strong> {session_title}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>시작:</strong> {start_at}
+                                </p>
+                            </div>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{meeting_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    수업 참여 링크
+                                </a>
+                            </div>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 라이브 수업 신청 확정\n\n자리가 생겨 대기 중이던 라이브 수업 신청이 확정되었습니다.\n\n수업: {session_title}\n시작: {start_at}\n\n수업 참여:\n{meeting_url}"
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::OrgSeatInvite {
+            invite_url,
+            org_name,
+            license_label,
+            valid_until,
+            expires_in_days,
+        } => {
+            let subject = format!("[Amazing Korean] {org_name} 수강 좌석 초대");
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">수강 좌석 초대</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                {org_name}에서 Amazing Korean 수강 좌석을 배정했습니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>기관:</strong> {org_name}
+                                </p>
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>수강 범위:</strong> {license_label}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>이용 기간:</strong> {valid_until} 까지
+                                </p>
+                            </div>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                로그인 후 아래 버튼을 클릭하면 수강권이 활성화됩니다.
+                            </p>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{invite_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    좌석 수락하기
+                                </a>
+                            </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 링크는 <strong>{expires_in_days}일</strong> 후 만료됩니다.
+                            </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                초대받은 이메일과 같은 계정으로 로그인해야 수락할 수 있습니다.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 수강 좌석 초대\n\n{org_name}에서 Amazing Korean 수강 좌석을 배정했습니다.\n\n기관: {org_name}\n수강 범위: {license_label}\n이용 기간: {valid_until} 까지\n\n로그인 후 아래 링크를 클릭하면 수강권이 활성화됩니다:\n{invite_url}\n\n이 링크는 {expires_in_days}일 후 만료됩니다.\n초대받은 이메일과 같은 계정으로 로그인해야 수락할 수 있습니다."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::LoginRiskAlert {
+            signed_in_at,
+            location,
+            device,
+            reasons,
+            revoke_url,
+            expires_in_days,
+        } => {
+            let subject = "[Amazing Korean] 새로운 환경에서 로그인되었습니다".to_string();
+            let reasons_html: String = reasons
+                .iter()
+                .map(|r| format!("<li style=\"margin: 0 0 6px 0;\">{r}</li>"))
+                .collect();
+            let reasons_text = reasons
+                .iter()
+                .map(|r| format!("- {r}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">새로운 환경에서 로그인</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                평소와 다른 환경에서 계정에 로그인했습니다.
+                            </p>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 20px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>시각:</strong> {signed_in_at}
+                                </p>
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>위치:</strong> {location}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>기기:</strong> {device}
+                                </p>
+                            </div>
+                            <ul style="margin: 0 0 30px 0; padding-left: 20px; color: #666; font-size: 14px;">
+                                {reasons_html}
+                            </ul>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                본인이 로그인한 것이라면 이 메일을 무시하세요. 본인이 아니라면 아래 버튼으로 모든 기기에서 즉시 로그아웃한 뒤 비밀번호를 변경하세요.
+                            </p>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{revoke_url}" style="display: inline-block; background-color: #c0392b; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    본인이 아닙니다
+                                </a>
+                            </div>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                이 링크는 <strong>{expires_in_days}일</strong> 후 만료됩니다.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 새로운 환경에서 로그인\n\n평소와 다른 환경에서 계정에 로그인했습니다.\n\n시각: {signed_in_at}\n위치: {location}\n기기: {device}\n\n{reasons_text}\n\n본인이 아니라면 아래 링크로 모든 기기에서 로그아웃한 뒤 비밀번호를 변경하세요:\n{revoke_url}\n\n이 링크는 {expires_in_days}일 후 만료됩니다."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::LoginStepUpCode {
             code,
+            location,
+            device,
             expires_in_min,
         } => {
-            let subject = "[Amazing Korean] 비밀번호 재설정 인증 코드".to_string();
+            let subject = "[Amazing Korean] 로그인 확인 인증 코드".to_string();
             let html_body = format!(
                 r#"<!DOCTYPE html>
 <html lang="ko">
@@ -186,16 +937,85 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
                     </tr>
                     <tr>
                         <td style="padding: 40px;">
-                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">비밀번호 재설정</h2>
-                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
-                                아래 인증 코드를 입력하여 비밀번호를 재설정하세요.
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">로그인 확인</h2>
+                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                평소와 다른 환경의 로그인이라 추가 확인이 필요합니다. 아래 인증 코드를 입력하세요.
                             </p>
-                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 30px; text-align: center; margin-bottom: 30px;">
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 30px; text-align: center; margin-bottom: 20px;">
                                 <span style="font-size: 36px; font-weight: bold; letter-spacing: 8px; color: #333;">{code}</span>
                             </div>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>위치:</strong> {location}
+                                </p>
+                                <p style="margin: 0; color: #666; font-size: 14px;">
+                                    <strong>기기:</strong> {device}
+                                </p>
+                            </div>
                             <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
                                 이 코드는 <strong>{expires_in_min}분</strong> 후 만료됩니다.
                             </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                본인이 시도한 로그인이 아니라면 비밀번호를 변경하세요.
+                            </p>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 20px 40px; background-color: #f8f9fa; border-radius: 0 0 8px 8px;">
+                            <p style="margin: 0; color: #999; font-size: 12px; text-align: center;">
+                                © Amazing Korean. All rights reserved.
+                            </p>
+                        </td>
+                    </tr>
+                </table>
+            </td>
+        </tr>
+    </table>
+</body>
+</html>"#
+            );
+            let text_body = format!(
+                "[Amazing Korean] 로그인 확인 인증 코드\n\n평소와 다른 환경의 로그인이라 추가 확인이 필요합니다.\n\n인증 코드: {code}\n위치: {location}\n기기: {device}\n\n이 코드는 {expires_in_min}분 후 만료됩니다.\n본인이 시도한 로그인이 아니라면 비밀번호를 변경하세요."
+            );
+            (subject, html_body, text_body)
+        }
+
+        EmailTemplate::MagicLinkLogin {
+            login_url,
+            expires_in_min,
+        } => {
+            let subject = "[Amazing Korean] 로그인 링크".to_string();
+            let html_body = format!(
+                r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+</head>
+<body style="margin: 0; padding: 0; font-family: 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; background-color: #f5f5f5;">
+    <table role="presentation" style="width: 100%; border-collapse: collapse;">
+        <tr>
+            <td style="padding: 40px 0;">
+                <table role="presentation" style="width: 100%; max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; box-shadow: 0 2px 8px rgba(0,0,0,0.1);">
+                    <tr>
+                        <td style="padding: 40px 40px 20px 40px; text-align: center; border-bottom: 1px solid #eee;">
+                            <h1 style="margin: 0; color: #333; font-size: 24px;">Amazing Korean</h1>
+                        </td>
+                    </tr>
+                    <tr>
+                        <td style="padding: 40px;">
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">로그인 링크</h2>
+                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
+                                아래 버튼을 클릭하면 비밀번호 없이 바로 로그인됩니다.
+                            </p>
+                            <div style="text-align: center; margin-bottom: 30px;">
+                                <a href="{login_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
+                                    로그인하기
+                                </a>
+                            </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 링크는 <strong>{expires_in_min}분</strong> 후 만료되며 한 번만 사용할 수 있습니다.
+                            </p>
                             <p style="margin: 0; color: #999; font-size: 14px;">
                                 본인이 요청하지 않았다면 이 이메일을 무시하세요.
                             </p>
@@ -216,16 +1036,16 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
 </html>"#
             );
             let text_body = format!(
-                "[Amazing Korean] 비밀번호 재설정\n\n인증 코드: {code}\n\n이 코드는 {expires_in_min}분 후 만료됩니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
+                "[Amazing Korean] 로그인 링크\n\n아래 링크를 클릭하면 비밀번호 없이 바로 로그인됩니다:\n{login_url}\n\n이 링크는 {expires_in_min}분 후 만료되며 한 번만 사용할 수 있습니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
             );
             (subject, html_body, text_body)
         }
 
-        EmailTemplate::EmailVerification {
+        EmailTemplate::LoginCode {
             code,
             expires_in_min,
         } => {
-            let subject = "[Amazing Korean] 이메일 인증 코드".to_string();
+            let subject = "[Amazing Korean] 로그인 코드".to_string();
             let html_body = format!(
                 r#"<!DOCTYPE html>
 <html lang="ko">
@@ -245,16 +1065,15 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
                     </tr>
                     <tr>
                         <td style="padding: 40px;">
-                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">이메일 인증</h2>
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">로그인 코드</h2>
                             <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
-                                Amazing Korean에 가입해 주셔서 감사합니다!<br>
-                                아래 인증 코드를 입력하여 이메일을 인증하세요.
+                                아래 코드를 입력하면 비밀번호 없이 로그인됩니다.
                             </p>
                             <div style="background-color: #f8f9fa; border-radius: 8px; padding: 30px; text-align: center; margin-bottom: 30px;">
                                 <span style="font-size: 36px; font-weight: bold; letter-spacing: 8px; color: #333;">{code}</span>
                             </div>
                             <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
-                                이 코드는 <strong>{expires_in_min}분</strong> 후 만료됩니다.
+                                이 코드는 <strong>{expires_in_min}분</strong> 후 만료되며 한 번만 사용할 수 있습니다.
                             </p>
                             <p style="margin: 0; color: #999; font-size: 14px;">
                                 본인이 요청하지 않았다면 이 이메일을 무시하세요.
@@ -276,13 +1095,16 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
 </html>"#
             );
             let text_body = format!(
-                "[Amazing Korean] 이메일 인증\n\nAmazing Korean에 가입해 주셔서 감사합니다!\n\n인증 코드: {code}\n\n이 코드는 {expires_in_min}분 후 만료됩니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
+                "[Amazing Korean] 로그인 코드\n\n아래 코드를 입력하면 비밀번호 없이 로그인됩니다.\n\n로그인 코드: {code}\n\n이 코드는 {expires_in_min}분 후 만료되며 한 번만 사용할 수 있습니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
             );
             (subject, html_body, text_body)
         }
 
-        EmailTemplate::Welcome { nickname } => {
-            let subject = "[Amazing Korean] 가입을 환영합니다!".to_string();
+        EmailTemplate::ReauthCode {
+            code,
+            expires_in_min,
+        } => {
+            let subject = "[Amazing Korean] 본인 확인 코드".to_string();
             let html_body = format!(
                 r#"<!DOCTYPE html>
 <html lang="ko">
@@ -302,18 +1124,19 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
                     </tr>
                     <tr>
                         <td style="padding: 40px;">
-                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">{nickname}님, 환영합니다!</h2>
-                            <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
-                                Amazing Korean에 가입해 주셔서 감사합니다.
-                            </p>
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">본인 확인 코드</h2>
                             <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
-                                지금 바로 한국어 학습을 시작해 보세요!
+                                보안 설정을 변경하려면 아래 코드를 입력해 본인임을 확인해 주세요.
                             </p>
-                            <div style="text-align: center;">
-                                <a href="https://amazingkorean.net" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
-                                    학습 시작하기
-                                </a>
+                            <div style="background-color: #f8f9fa; border-radius: 8px; padding: 30px; text-align: center; margin-bottom: 30px;">
+                                <span style="font-size: 36px; font-weight: bold; letter-spacing: 8px; color: #333;">{code}</span>
                             </div>
+                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
+                                이 코드는 <strong>{expires_in_min}분</strong> 후 만료되며 한 번만 사용할 수 있습니다.
+                            </p>
+                            <p style="margin: 0; color: #999; font-size: 14px;">
+                                본인이 요청하지 않았다면 즉시 비밀번호를 재설정하고 모든 기기에서 로그아웃하세요.
+                            </p>
                         </td>
                     </tr>
                     <tr>
@@ -331,23 +1154,17 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
 </html>"#
             );
             let text_body = format!(
-                "[Amazing Korean] 가입을 환영합니다!\n\n{nickname}님, Amazing Korean에 가입해 주셔서 감사합니다.\n\n지금 바로 한국어 학습을 시작해 보세요!\n\nhttps://amazingkorean.net"
+                "[Amazing Korean] 본인 확인 코드\n\n보안 설정을 변경하려면 아래 코드를 입력해 본인임을 확인해 주세요.\n\n확인 코드: {code}\n\n이 코드는 {expires_in_min}분 후 만료되며 한 번만 사용할 수 있습니다.\n\n본인이 요청하지 않았다면 즉시 비밀번호를 재설정하고 모든 기기에서 로그아웃하세요."
             );
             (subject, html_body, text_body)
         }
 
-        EmailTemplate::AdminInvite {
-            invite_url,
-            role,
-            invited_by,
-            expires_in_min,
+        EmailTemplate::SecurityNotice {
+            action,
+            detail,
+            changed_at,
         } => {
-            let role_display = match role.as_str() {
-                "admin" => "관리자 (Admin)",
-                "manager" => "매니저 (Manager)",
-                _ => &role,
-            };
-            let subject = "[Amazing Korean] 관리자 초대".to_string();
+            let subject = "[Amazing Korean] 계정 보안 설정 변경 알림".to_string();
             let html_body = format!(
                 r#"<!DOCTYPE html>
 <html lang="ko">
@@ -367,31 +1184,23 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
                     </tr>
                     <tr>
                         <td style="padding: 40px;">
-                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">관리자 초대</h2>
+                            <h2 style="margin: 0 0 20px 0; color: #333; font-size: 20px;">보안 설정 변경</h2>
                             <p style="margin: 0 0 20px 0; color: #666; font-size: 16px; line-height: 1.6;">
-                                Amazing Korean 관리자로 초대되었습니다.
+                                계정의 로그인 설정이 변경되었습니다.
                             </p>
                             <div style="background-color: #f8f9fa; border-radius: 8px; padding: 20px; margin-bottom: 30px;">
                                 <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
-                                    <strong>권한:</strong> {role_display}
+                                    <strong>변경 내용:</strong> {action}
+                                </p>
+                                <p style="margin: 0 0 10px 0; color: #666; font-size: 14px;">
+                                    <strong>대상:</strong> {detail}
                                 </p>
                                 <p style="margin: 0; color: #666; font-size: 14px;">
-                                    <strong>초대자:</strong> {invited_by}
+                                    <strong>일시:</strong> {changed_at}
                                 </p>
                             </div>
-                            <p style="margin: 0 0 30px 0; color: #666; font-size: 16px; line-height: 1.6;">
-                                아래 버튼을 클릭하여 관리자 계정을 생성하세요.
-                            </p>
-                            <div style="text-align: center; margin-bottom: 30px;">
-                                <a href="{invite_url}" style="display: inline-block; background-color: #333; color: #ffffff; text-decoration: none; padding: 14px 30px; border-radius: 6px; font-size: 16px; font-weight: bold;">
-                                    관리자 계정 생성
-                                </a>
-                            </div>
-                            <p style="margin: 0 0 10px 0; color: #999; font-size: 14px;">
-                                이 링크는 <strong>{expires_in_min}분</strong> 후 만료됩니다.
-                            </p>
                             <p style="margin: 0; color: #999; font-size: 14px;">
-                                본인이 요청하지 않았다면 이 이메일을 무시하세요.
+                                본인이 변경하지 않았다면 즉시 비밀번호를 재설정하고 모든 기기에서 로그아웃하세요.
                             </p>
                         </td>
                     </tr>
@@ -410,7 +1219,7 @@ fn render_template(template: EmailTemplate) -> (String, String, String) {
 </html>"#
             );
             let text_body = format!(
-                "[Amazing Korean] 관리자 초대\n\nAmazing Korean 관리자로 초대되었습니다.\n\n권한: {role_display}\n초대자: {invited_by}\n\n아래 링크를 클릭하여 관리자 계정을 생성하세요:\n{invite_url}\n\n이 링크는 {expires_in_min}분 후 만료됩니다.\n\n본인이 요청하지 않았다면 이 이메일을 무시하세요."
+                "[Amazing Korean] 계정 보안 설정 변경 알림\n\n계정의 로그인 설정이 변경되었습니다.\n\n변경 내용: {action}\n대상: {detail}\n일시: {changed_at}\n\n본인이 변경하지 않았다면 즉시 비밀번호를 재설정하고 모든 기기에서 로그아웃하세요."
             );
             (subject, html_body, text_body)
         }
@@ -844,6 +1653,146 @@ mod tests {
         assert!(html.contains("ceo@amk.test"));
     }
 
+    #[test]
+    fn test_render_classroom_invite_includes_class_name_and_url() {
+        let (subject, html, text) = render_template(EmailTemplate::ClassroomInvite {
+            invite_url: "https://amk.test/classes/join?invite=TOKEN".to_string(),
+            classroom_name: "월요일 초급반".to_string(),
+            invited_by: "김선생".to_string(),
+            expires_in_days: 7,
+        });
+        assert!(subject.contains("월요일 초급반"), "subject: {}", subject);
+        assert!(html.contains("https://amk.test/classes/join?invite=TOKEN"));
+        assert!(html.contains("김선생"));
+        assert!(text.contains("7일"), "text: 만료 일수");
+    }
+
+    #[test]
+    fn test_render_assignment_reminder() {
+        let (subject, html, text) = render_template(EmailTemplate::AssignmentReminder {
+            assignment_title: "3주차 복습".to_string(),
+            due_at: "2026-11-02 09:00 UTC".to_string(),
+            assignment_url: "https://amk.test/assignments".to_string(),
+        });
+        assert!(subject.contains("3주차 복습"), "subject: {}", subject);
+        assert!(html.contains("https://amk.test/assignments"));
+        assert!(text.contains("2026-11-02 09:00 UTC"), "text: 마감 시각");
+    }
+
+    #[test]
+    fn test_render_live_session_reminder() {
+        let (subject, html, text) = render_template(EmailTemplate::LiveSessionReminder {
+            session_title: "초급 회화 3회차".to_string(),
+            start_at: "2026-11-02 19:00 KST".to_string(),
+            meeting_url: "https://amk.test/live/room/abc".to_string(),
+        });
+        assert!(subject.contains("초급 회화 3회차"), "subject: {}", subject);
+        assert!(html.contains("https://amk.test/live/room/abc"));
+        assert!(text.contains("2026-11-02 19:00 KST"), "text: 시작 시각");
+    }
+
+    #[test]
+    fn test_render_org_seat_invite() {
+        let (subject, html, text) = render_template(EmailTemplate::OrgSeatInvite {
+            invite_url: "https://amk.test/orgs/seats/accept?token=TOKEN".to_string(),
+            org_name: "한빛어학원".to_string(),
+            license_label: "구독 (전체 코스)".to_string(),
+            valid_until: "2027-03-01".to_string(),
+            expires_in_days: 14,
+        });
+        assert!(subject.contains("한빛어학원"), "subject: {}", subject);
+        assert!(html.contains("https://amk.test/orgs/seats/accept?token=TOKEN"));
+        assert!(html.contains("구독 (전체 코스)"));
+        assert!(text.contains("2027-03-01"), "text: 이용 기간");
+        assert!(text.contains("14일"), "text: 만료 일수");
+    }
+
+    #[test]
+    fn test_render_login_risk_alert() {
+        let (subject, html, text) = render_template(EmailTemplate::LoginRiskAlert {
+            signed_in_at: "2026-11-02 09:00 UTC".to_string(),
+            location: "London, GB".to_string(),
+            device: "Firefox / Windows 10".to_string(),
+            reasons: vec!["처음 로그인한 국가".to_string()],
+            revoke_url: "https://amk.test/security/revoke?token=TOKEN".to_string(),
+            expires_in_days: 7,
+        });
+        assert!(subject.contains("로그인"), "subject: {}", subject);
+        assert!(html.contains("https://amk.test/security/revoke?token=TOKEN"));
+        assert!(html.contains("<li"), "html: 사유 목록");
+        assert!(text.contains("- 처음 로그인한 국가"), "text: 사유");
+        assert!(text.contains("London, GB"));
+    }
+
+    #[test]
+    fn test_render_login_step_up_code() {
+        let (_subject, html, text) = render_template(EmailTemplate::LoginStepUpCode {
+            code: "482913".to_string(),
+            location: "Tokyo, JP".to_string(),
+            device: "Chrome / Mac OSX".to_string(),
+            expires_in_min: 10,
+        });
+        assert!(html.contains("482913"));
+        assert!(text.contains("Tokyo, JP"));
+        assert!(text.contains("10분"), "text: 만료 분");
+    }
+
+    #[test]
+    fn test_render_magic_link_login() {
+        let (subject, html, text) = render_template(EmailTemplate::MagicLinkLogin {
+            login_url: "https://amk.test/login/magic?token=abc".to_string(),
+            expires_in_min: 10,
+        });
+        assert!(subject.contains("로그인 링크"));
+        assert!(html.contains("https://amk.test/login/magic?token=abc"));
+        assert!(text.contains("10분"), "text: 만료 분");
+    }
+
+    #[test]
+    fn test_render_login_code() {
+        let (_subject, html, text) = render_template(EmailTemplate::LoginCode {
+            code: "305718".to_string(),
+            expires_in_min: 10,
+        });
+        assert!(html.contains("305718"));
+        assert!(text.contains("로그인 코드: 305718"));
+    }
+
+    #[test]
+    fn test_render_reauth_code() {
+        let (subject, html, text) = render_template(EmailTemplate::ReauthCode {
+            code: "728104".to_string(),
+            expires_in_min: 10,
+        });
+        assert!(subject.contains("본인 확인"));
+        assert!(html.contains("728104"));
+        assert!(text.contains("확인 코드: 728104"));
+    }
+
+    #[test]
+    fn test_render_security_notice() {
+        let (subject, html, text) = render_template(EmailTemplate::SecurityNotice {
+            action: "소셜 계정 연결 해제".to_string(),
+            detail: "Google (us***@gmail.com)".to_string(),
+            changed_at: "2026-10-19 09:00 UTC".to_string(),
+        });
+        assert!(subject.contains("보안 설정 변경"));
+        assert!(html.contains("소셜 계정 연결 해제"));
+        assert!(text.contains("Google (us***@gmail.com)"));
+    }
+
+    #[test]
+    fn test_render_live_waitlist_promoted() {
+        let (subject, html, text) = render_template(EmailTemplate::LiveWaitlistPromoted {
+            session_title: "초급 회화 3회차".to_string(),
+            start_at: "2026-11-02 19:00 KST".to_string(),
+            meeting_url: "https://amk.test/live/room/abc".to_string(),
+        });
+        assert!(subject.contains("확정"), "subject: {}", subject);
+        assert!(html.contains("https://amk.test/live/room/abc"));
+        assert!(text.contains("초급 회화 3회차"));
+    }
+
     #[test]
     fn test_render_admin_invite_unknown_role_uses_raw_label() {
         // role 이 "admin" / "manager" 외 값일 때 fallback = 입력값 그대로
@@ -1,48 +1,20 @@
-use reqwest::Client;
 use serde::Deserialize;
 
-use crate::error::{AppError, AppResult};
+use crate::error::AppResult;
+use crate::external::oidc::{
+    AuthFlowState, OidcClient, OidcClientConfig, OidcTokenResponse, ProviderMetadata,
+};
 
 const GOOGLE_AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
 pub(crate) const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
 pub(crate) const GOOGLE_JWKS_URL: &str = "https://www.googleapis.com/oauth2/v3/certs";
+const GOOGLE_ISSUER: &str = "https://accounts.google.com";
 
-/// Google OAuth 클라이언트
+/// Google OAuth 클라이언트 (범용 `OidcClient` + Google 클레임).
+///
+/// Google endpoint 는 고정이라 discovery 대신 상수를 쓴다 (요청마다 discovery 왕복 회피).
 pub struct GoogleOAuthClient {
-    client: Client,
-    client_id: String,
-    client_secret: String,
-    redirect_uri: String,
-    /// Token 교환 endpoint URL (default = 공식 Google. test 시 wiremock 으로 override).
-    token_url: String,
-    /// JWKS endpoint URL (default = 공식 Google. test 시 wiremock 으로 override).
-    jwks_url: String,
-}
-
-/// Google Token 교환 응답
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)] // 역직렬화에 필요하지만 id_token만 사용
-pub struct GoogleTokenResponse {
-    pub access_token: String,
-    pub id_token: String,
-    pub token_type: String,
-    pub expires_in: i64,
-    pub scope: String,
-    pub refresh_token: Option<String>,
-}
-
-/// Google JWKS response
-#[derive(Debug, Deserialize)]
-struct GoogleJwks {
-    keys: Vec<GoogleJwk>,
-}
-
-/// Single JWK from Google's JWKS endpoint
-#[derive(Debug, Deserialize)]
-struct GoogleJwk {
-    kid: String,
-    n: String,
-    e: String,
+    inner: OidcClient,
 }
 
 /// Google ID Token Claims
@@ -97,7 +69,7 @@ impl GoogleOAuthClient {
 
     /// URL override 가능한 생성자. test 환경에서 wiremock URL 주입용.
     ///
-    /// B5 Tier 2: builder fail 시 panic 회피 → Result 전파.
+    /// 모바일 ID token 검증 전용이면 client_secret / redirect_uri 를 빈 문자열로 넘긴다.
     pub fn with_urls(
         client_id: String,
         client_secret: String,
@@ -105,116 +77,50 @@ impl GoogleOAuthClient {
         token_url: String,
         jwks_url: String,
     ) -> AppResult<Self> {
-        // N-10: 외부 서비스 hang 방지 (timeout 15초)
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(15))
-            .build()
-            .map_err(|e| AppError::Internal(format!("google oauth client init: {}", e)))?;
-        Ok(Self {
-            client,
-            client_id,
-            client_secret,
-            redirect_uri,
-            token_url,
-            jwks_url,
-        })
+        let inner = OidcClient::new(
+            "Google",
+            ProviderMetadata {
+                issuer: Some(GOOGLE_ISSUER.to_string()),
+                authorization_endpoint: GOOGLE_AUTH_URL.to_string(),
+                token_endpoint: token_url,
+                jwks_uri: Some(jwks_url),
+                userinfo_endpoint: None,
+            },
+            OidcClientConfig {
+                client_id,
+                client_secret: (!client_secret.is_empty()).then_some(client_secret),
+                redirect_uri: (!redirect_uri.is_empty()).then_some(redirect_uri),
+                scope: "openid email profile",
+                audiences: Vec::new(),
+                pkce: true,
+            },
+        )?
+        .accept_issuer("accounts.google.com");
+        Ok(Self { inner })
     }
 
     /// 1단계: Authorization URL 생성
-    /// state: CSRF 방지용 (Redis에 저장)
-    /// nonce: ID Token Replay 방지용
-    pub fn build_auth_url(&self, state: &str, nonce: &str) -> String {
-        let params = [
-            ("client_id", self.client_id.as_str()),
-            ("redirect_uri", self.redirect_uri.as_str()),
-            ("response_type", "code"),
-            ("scope", "openid email profile"),
-            ("state", state),
-            ("nonce", nonce),
-            ("access_type", "offline"),
-            ("prompt", "consent"),
-        ];
-
-        let query = serde_urlencoded::to_string(params).unwrap_or_default();
-        format!("{}?{}", GOOGLE_AUTH_URL, query)
+    /// state: CSRF 방지용, flow: nonce(ID Token Replay 방지) + PKCE verifier (Redis에 저장)
+    pub fn build_auth_url(&self, state: &str, flow: &AuthFlowState) -> String {
+        self.inner.authorization_url(
+            state,
+            flow,
+            &[("access_type", "offline"), ("prompt", "consent")],
+        )
     }
 
     /// 2단계: Authorization Code → Tokens 교환
-    pub async fn exchange_code(&self, code: &str) -> AppResult<GoogleTokenResponse> {
-        let params = [
-            ("client_id", self.client_id.as_str()),
-            ("client_secret", self.client_secret.as_str()),
-            ("redirect_uri", self.redirect_uri.as_str()),
-            ("grant_type", "authorization_code"),
-            ("code", code),
-        ];
-
-        let response = self
-            .client
-            .post(&self.token_url)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| AppError::External(format!("Google token exchange failed: {}", e)))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(AppError::External(format!(
-                "Google OAuth error: {} - {}",
-                status, body
-            )));
-        }
-
-        response
-            .json()
-            .await
-            .map_err(|e| AppError::External(format!("Google token parse error: {}", e)))
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        pkce_verifier: Option<&str>,
+    ) -> AppResult<OidcTokenResponse> {
+        self.inner.exchange_code(code, pkce_verifier, &[]).await
     }
 
-    /// 3단계: ID Token 디코딩 + Google JWKS 서명 검증
+    /// 3단계: ID Token 디코딩 + Google JWKS 서명 검증 (issuer + audience)
     pub async fn decode_id_token(&self, id_token: &str) -> AppResult<GoogleIdTokenClaims> {
-        // JWT 헤더에서 kid 추출 (서명 검증에 사용할 키 식별)
-        let header = jsonwebtoken::decode_header(id_token)
-            .map_err(|e| AppError::External(format!("Invalid ID token header: {}", e)))?;
-
-        let kid = header
-            .kid
-            .ok_or_else(|| AppError::External("ID token missing kid in header".into()))?;
-
-        // Google JWKS에서 공개키 가져오기
-        let jwks: GoogleJwks = self
-            .client
-            .get(&self.jwks_url)
-            .send()
-            .await
-            .map_err(|e| AppError::External(format!("Failed to fetch Google JWKS: {}", e)))?
-            .json()
-            .await
-            .map_err(|e| AppError::External(format!("Failed to parse Google JWKS: {}", e)))?;
-
-        // kid에 매칭되는 키 찾기
-        let jwk = jwks
-            .keys
-            .iter()
-            .find(|k| k.kid == kid)
-            .ok_or_else(|| AppError::External("No matching key found in Google JWKS".into()))?;
-
-        // RSA 공개키로 디코딩 키 생성
-        let decoding_key = jsonwebtoken::DecodingKey::from_rsa_components(&jwk.n, &jwk.e)
-            .map_err(|e| AppError::External(format!("Failed to create decoding key: {}", e)))?;
-
-        // 검증 설정: issuer + audience + 서명
-        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
-        validation.set_issuer(&["https://accounts.google.com", "accounts.google.com"]);
-        validation.set_audience(&[&self.client_id]);
-
-        // 서명 검증 + 디코딩
-        let token_data =
-            jsonwebtoken::decode::<GoogleIdTokenClaims>(id_token, &decoding_key, &validation)
-                .map_err(|e| AppError::External(format!("ID token verification failed: {}", e)))?;
-
-        Ok(token_data.claims)
+        self.inner.verify_id_token(id_token).await
     }
 
     /// ID Token Claims에서 사용자 정보 추출
@@ -229,9 +135,8 @@ impl GoogleOAuthClient {
     }
 
     /// Client ID getter (audience 검증용)
-    #[allow(dead_code)]
     pub fn client_id(&self) -> &str {
-        &self.client_id
+        self.inner.client_id()
     }
 }
 
@@ -248,12 +153,14 @@ mod tests {
         )
         .expect("client init in test");
 
-        let url = client.build_auth_url("test-state", "test-nonce");
+        let flow = AuthFlowState::new("google", true);
+        let url = client.build_auth_url("test-state", &flow);
 
         assert!(url.starts_with(GOOGLE_AUTH_URL));
         assert!(url.contains("client_id=test-client-id"));
         assert!(url.contains("state=test-state"));
-        assert!(url.contains("nonce=test-nonce"));
+        assert!(url.contains(&format!("nonce={}", flow.nonce)));
         assert!(url.contains("scope=openid"));
+        assert!(url.contains("code_challenge_method=S256"));
     }
 }
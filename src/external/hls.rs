@@ -0,0 +1,450 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use base64::engine::{general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::error::{AppError, AppResult};
+use crate::external::video_host::{PlaybackViewer, VideoHost, VideoHostMeta, VideoPlayback};
+use crate::types::VideoHostKind;
+
+/// 비디오 디렉터리 진입 재생목록
+pub const MASTER_PLAYLIST: &str = "master.m3u8";
+/// AES-128 세그먼트 키 (16 bytes) — 파일 라우트로는 절대 제공하지 않음
+pub const KEY_FILE: &str = "enc.key";
+/// 파일 라우트로 제공 가능한 확장자
+const SERVABLE_EXTENSIONS: [&str; 6] = ["m3u8", "ts", "m4s", "mp4", "aac", "vtt"];
+
+/// 자체 HLS 호스트
+///
+/// `{root_dir}/{video_hls_path}/` 아래 패키징 완료된 재생목록/세그먼트를 서빙한다.
+/// 모든 URL 은 `(video_id, user_id, session_id, exp)` 에 HMAC 서명된 토큰을 달고 나가며,
+/// 재생목록 응답 시 내부 URI 를 서명 URL 로, 키 URI 를 `/videos/{id}/hls-key` 로 재작성한다.
+pub struct HlsHost {
+    root_dir: PathBuf,
+    signing_key: [u8; 32],
+    url_ttl_sec: i64,
+}
+
+/// 검증된 서명 토큰
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HlsToken {
+    pub user_id: i64,
+    pub session_id: String,
+    pub exp: i64,
+}
+
+impl HlsHost {
+    pub fn new(root_dir: PathBuf, signing_key: [u8; 32], url_ttl_sec: i64) -> Self {
+        Self {
+            root_dir,
+            signing_key,
+            url_ttl_sec,
+        }
+    }
+
+    pub fn url_ttl_sec(&self) -> i64 {
+        self.url_ttl_sec
+    }
+
+    fn mac(&self, video_id: i64, payload: &str) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.signing_key).expect("HMAC accepts any key size");
+        mac.update(format!("hls:{video_id}:{payload}").as_bytes());
+        mac
+    }
+
+    /// 토큰 = base64url("{user_id}:{exp}:{session_id}") + "." + base64url(HMAC)
+    pub fn sign_token(&self, video_id: i64, viewer: &PlaybackViewer, exp: i64) -> String {
+        let payload = format!("{}:{}:{}", viewer.user_id, exp, viewer.session_id);
+        let sig = self.mac(video_id, &payload).finalize().into_bytes();
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(sig)
+        )
+    }
+
+    /// 서명·만료 검증 (세션 활성/엔타이틀먼트는 호출 측에서 확인)
+    pub fn verify_token(&self, video_id: i64, token: &str, now: i64) -> AppResult<HlsToken> {
+        let invalid = || AppError::Forbidden("HLS_TOKEN_INVALID".into());
+
+        let (payload_b64, sig_b64) = token.split_once('.').ok_or_else(invalid)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload_b64)
+            .ok()
+            .and_then(|b| String::from_utf8(b).ok())
+            .ok_or_else(invalid)?;
+        let sig = URL_SAFE_NO_PAD.decode(sig_b64).map_err(|_| invalid())?;
+        self.mac(video_id, &payload)
+            .verify_slice(&sig)
+            .map_err(|_| invalid())?;
+
+        let mut parts = payload.splitn(3, ':');
+        let user_id = parts
+            .next()
+            .and_then(|v| v.parse::<i64>().ok())
+            .ok_or_else(invalid)?;
+        let exp = parts
+            .next()
+            .and_then(|v| v.parse::<i64>().ok())
+            .ok_or_else(invalid)?;
+        let session_id = parts
+            .next()
+            .filter(|v| !v.is_empty())
+            .ok_or_else(invalid)?
+            .to_string();
+
+        if exp < now {
+            return Err(AppError::Forbidden("HLS_TOKEN_EXPIRED".into()));
+        }
+        Ok(HlsToken {
+            user_id,
+            session_id,
+            exp,
+        })
+    }
+
+    /// 재생목록/세그먼트 파일 (키 파일·허용 외 확장자·경로 탈출은 404)
+    pub async fn read_file(&self, hls_path: &str, file: &str) -> AppResult<Vec<u8>> {
+        if !is_servable(file) {
+            return Err(AppError::NotFound);
+        }
+        self.read(hls_path, file).await
+    }
+
+    /// AES-128 키 (16 bytes)
+    pub async fn read_key(&self, hls_path: &str) -> AppResult<Vec<u8>> {
+        let key = self.read(hls_path, KEY_FILE).await?;
+        if key.len() != 16 {
+            return Err(AppError::Internal(format!(
+                "invalid HLS key length for {hls_path}: {}",
+                key.len()
+            )));
+        }
+        Ok(key)
+    }
+
+    /// 재생목록 내부 URI 를 서명 URL 로 재작성
+    pub fn rewrite_playlist(
+        &self,
+        video_id: i64,
+        file: &str,
+        content: &str,
+        token: &str,
+    ) -> String {
+        let key_url = format!("/videos/{video_id}/hls-key?token={token}");
+        rewrite_playlist(
+            content,
+            file,
+            |rel| format!("/videos/{video_id}/hls/{rel}?token={token}"),
+            &key_url,
+        )
+    }
+
+    async fn read(&self, hls_path: &str, file: &str) -> AppResult<Vec<u8>> {
+        if !is_safe_rel_path(hls_path) || !is_safe_rel_path(file) {
+            return Err(AppError::NotFound);
+        }
+        let path = self.root_dir.join(hls_path).join(file);
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(bytes),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(AppError::NotFound),
+            Err(e) => Err(AppError::Internal(format!(
+                "HLS read {}: {e}",
+                path.display()
+            ))),
+        }
+    }
+
+    async fn read_text(&self, hls_path: &str, file: &str) -> AppResult<String> {
+        let bytes = self.read(hls_path, file).await?;
+        String::from_utf8(bytes)
+            .map_err(|_| AppError::BadRequest(format!("HLS_PLAYLIST_INVALID: {file}")))
+    }
+}
+
+#[async_trait]
+impl VideoHost for HlsHost {
+    fn kind(&self) -> VideoHostKind {
+        VideoHostKind::Hls
+    }
+
+    /// 패키징 검증 — master 재생목록 존재, AES-128 암호화, 16 byte 키. 길이는 EXTINF 합
+    async fn fetch_meta(&self, source: &str) -> AppResult<Option<VideoHostMeta>> {
+        if !is_safe_rel_path(source) {
+            return Err(AppError::BadRequest("INVALID_HLS_PATH".into()));
+        }
+        let not_found = |e: AppError| match e {
+            AppError::NotFound => AppError::BadRequest("HLS_PLAYLIST_NOT_FOUND".into()),
+            other => other,
+        };
+
+        let master = self
+            .read_text(source, MASTER_PLAYLIST)
+            .await
+            .map_err(not_found)?;
+        let media = match first_variant(&master).and_then(|uri| resolve_uri(MASTER_PLAYLIST, uri)) {
+            Some(variant) => self.read_text(source, &variant).await.map_err(not_found)?,
+            None => master,
+        };
+
+        if !is_aes128_encrypted(&media) {
+            return Err(AppError::BadRequest("HLS_NOT_ENCRYPTED".into()));
+        }
+        self.read_key(source).await.map_err(|e| match e {
+            AppError::NotFound | AppError::Internal(_) => {
+                AppError::BadRequest("HLS_KEY_MISSING".into())
+            }
+            other => other,
+        })?;
+
+        Ok(Some(VideoHostMeta {
+            duration: playlist_duration_sec(&media).round() as i32,
+            thumbnail_url: None,
+            title: None,
+            description: None,
+        }))
+    }
+
+    fn playback(
+        &self,
+        video_id: i64,
+        _source: &str,
+        viewer: &PlaybackViewer,
+    ) -> AppResult<VideoPlayback> {
+        let exp = Utc::now().timestamp() + self.url_ttl_sec;
+        let token = self.sign_token(video_id, viewer, exp);
+        Ok(VideoPlayback {
+            url: format!("/videos/{video_id}/hls/{MASTER_PLAYLIST}?token={token}"),
+            expires_at: Utc.timestamp_opt(exp, 0).single(),
+        })
+    }
+}
+
+/// `a/b/c.ts` 형태만 허용 — 빈 구간, `.`/`..`, 숨김 파일, 그 외 문자 거부
+pub fn is_safe_rel_path(path: &str) -> bool {
+    !path.is_empty()
+        && path.split('/').all(|seg| {
+            !seg.is_empty()
+                && !seg.starts_with('.')
+                && seg
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+}
+
+fn is_servable(file: &str) -> bool {
+    file.rsplit_once('.')
+        .is_some_and(|(_, ext)| SERVABLE_EXTENSIONS.contains(&ext))
+}
+
+/// 파일 확장자별 Content-Type
+pub fn content_type(file: &str) -> &'static str {
+    match file.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("m3u8") => "application/vnd.apple.mpegurl",
+        Some("ts") => "video/mp2t",
+        Some("m4s") | Some("mp4") => "video/mp4",
+        Some("aac") => "audio/aac",
+        Some("vtt") => "text/vtt; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+/// 재생목록 기준 상대 URI → 비디오 디렉터리 기준 경로. 절대 URL/경로는 None (그대로 둠)
+fn resolve_uri(playlist_file: &str, uri: &str) -> Option<String> {
+    let uri = uri.split(['?', '#']).next().unwrap_or_default();
+    if uri.is_empty() || uri.contains("://") || uri.starts_with('/') {
+        return None;
+    }
+    Some(match playlist_file.rsplit_once('/') {
+        Some((dir, _)) => format!("{dir}/{uri}"),
+        None => uri.to_string(),
+    })
+}
+
+/// 태그 속성의 `URI="..."` 값 범위
+fn quoted_uri_range(line: &str) -> Option<(usize, usize)> {
+    let start = line.find("URI=\"")? + "URI=\"".len();
+    let len = line[start..].find('"')?;
+    Some((start, start + len))
+}
+
+/// 재생목록 재작성
+///
+/// - URI 라인 / `URI="..."` 속성 (EXT-X-MAP, EXT-X-MEDIA 등) → `segment_url(상대경로)`
+/// - EXT-X-KEY / EXT-X-SESSION-KEY 의 URI → `key_url`
+pub fn rewrite_playlist(
+    content: &str,
+    playlist_file: &str,
+    segment_url: impl Fn(&str) -> String,
+    key_url: &str,
+) -> String {
+    let mut out = String::with_capacity(content.len() * 2);
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            out.push_str(line);
+        } else if trimmed.starts_with('#') {
+            match quoted_uri_range(trimmed) {
+                Some((start, end)) => {
+                    let is_key = trimmed.starts_with("#EXT-X-KEY")
+                        || trimmed.starts_with("#EXT-X-SESSION-KEY");
+                    let replacement = if is_key {
+                        Some(key_url.to_string())
+                    } else {
+                        resolve_uri(playlist_file, &trimmed[start..end]).map(|r| segment_url(&r))
+                    };
+                    match replacement {
+                        Some(uri) => {
+                            out.push_str(&trimmed[..start]);
+                            out.push_str(&uri);
+                            out.push_str(&trimmed[end..]);
+                        }
+                        None => out.push_str(trimmed),
+                    }
+                }
+                None => out.push_str(trimmed),
+            }
+        } else {
+            match resolve_uri(playlist_file, trimmed) {
+                Some(rel) => out.push_str(&segment_url(&rel)),
+                None => out.push_str(trimmed),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// master 재생목록의 첫 번째 variant URI
+pub fn first_variant(content: &str) -> Option<&str> {
+    let mut lines = content.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line.starts_with("#EXT-X-STREAM-INF") {
+            return lines.find(|l| !l.is_empty() && !l.starts_with('#'));
+        }
+    }
+    None
+}
+
+/// media 재생목록 길이 (EXTINF 합, 초)
+pub fn playlist_duration_sec(content: &str) -> f64 {
+    content
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("#EXTINF:"))
+        .filter_map(|v| v.split(',').next()?.trim().parse::<f64>().ok())
+        .sum()
+}
+
+pub fn is_aes128_encrypted(content: &str) -> bool {
+    content
+        .lines()
+        .any(|l| l.trim().starts_with("#EXT-X-KEY") && l.contains("METHOD=AES-128"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host() -> HlsHost {
+        HlsHost::new(PathBuf::from("/tmp/hls"), [7u8; 32], 300)
+    }
+
+    fn viewer() -> PlaybackViewer {
+        PlaybackViewer {
+            user_id: 42,
+            session_id: "6f1c-session".into(),
+        }
+    }
+
+    #[test]
+    fn token_roundtrip_and_binding() {
+        let h = host();
+        let token = h.sign_token(10, &viewer(), 1_000);
+
+        let parsed = h.verify_token(10, &token, 999).unwrap();
+        assert_eq!(parsed.user_id, 42);
+        assert_eq!(parsed.session_id, "6f1c-session");
+        assert_eq!(parsed.exp, 1_000);
+
+        // 다른 비디오에 재사용 불가
+        assert!(h.verify_token(11, &token, 999).is_err());
+        // 만료
+        assert!(matches!(
+            h.verify_token(10, &token, 1_001),
+            Err(AppError::Forbidden(m)) if m == "HLS_TOKEN_EXPIRED"
+        ));
+    }
+
+    #[test]
+    fn token_tamper_rejected() {
+        let h = host();
+        let token = h.sign_token(10, &viewer(), 1_000);
+        let (_, sig) = token.split_once('.').unwrap();
+        let forged = format!("{}.{}", URL_SAFE_NO_PAD.encode("43:1000:6f1c-session"), sig);
+        assert!(h.verify_token(10, &forged, 0).is_err());
+        assert!(h.verify_token(10, "garbage", 0).is_err());
+
+        let other = HlsHost::new(PathBuf::from("/tmp/hls"), [8u8; 32], 300);
+        assert!(other.verify_token(10, &token, 0).is_err());
+    }
+
+    #[test]
+    fn rel_path_safety() {
+        assert!(is_safe_rel_path("course1/lesson-01"));
+        assert!(is_safe_rel_path("720p/seg_001.ts"));
+        assert!(!is_safe_rel_path("../etc/passwd"));
+        assert!(!is_safe_rel_path("a/../b"));
+        assert!(!is_safe_rel_path("/abs"));
+        assert!(!is_safe_rel_path("a//b"));
+        assert!(!is_safe_rel_path(".hidden"));
+        assert!(!is_safe_rel_path("a\\b"));
+
+        assert!(is_servable("720p/index.m3u8"));
+        assert!(is_servable("seg_001.ts"));
+        assert!(!is_servable(KEY_FILE));
+        assert!(!is_servable("notes.txt"));
+    }
+
+    #[test]
+    fn rewrite_signs_uris_and_replaces_key() {
+        let playlist = "#EXTM3U\n\
+#EXT-X-KEY:METHOD=AES-128,URI=\"enc.key\",IV=0x01\n\
+#EXT-X-MAP:URI=\"init.mp4\"\n\
+#EXTINF:6.0,\n\
+seg_000.m4s\n\
+#EXTINF:4.5,\n\
+https://cdn.example.com/seg_001.m4s\n\
+#EXT-X-ENDLIST\n";
+        let out = rewrite_playlist(playlist, "720p/index.m3u8", |r| format!("S({r})"), "KEY");
+
+        assert!(out.contains("#EXT-X-KEY:METHOD=AES-128,URI=\"KEY\",IV=0x01"));
+        assert!(out.contains("#EXT-X-MAP:URI=\"S(720p/init.mp4)\""));
+        assert!(out.contains("\nS(720p/seg_000.m4s)\n"));
+        assert!(out.contains("\nhttps://cdn.example.com/seg_001.m4s\n"));
+        assert!(!out.contains("enc.key"));
+    }
+
+    #[test]
+    fn master_variant_and_duration() {
+        let master = "#EXTM3U\n\
+#EXT-X-STREAM-INF:BANDWIDTH=800000,RESOLUTION=640x360\n\
+360p/index.m3u8\n\
+#EXT-X-STREAM-INF:BANDWIDTH=2800000,RESOLUTION=1280x720\n\
+720p/index.m3u8\n";
+        assert_eq!(first_variant(master), Some("360p/index.m3u8"));
+        assert_eq!(
+            resolve_uri(MASTER_PLAYLIST, "360p/index.m3u8?v=2").as_deref(),
+            Some("360p/index.m3u8")
+        );
+
+        let media = "#EXTM3U\n#EXT-X-KEY:METHOD=AES-128,URI=\"k\"\n#EXTINF:6.006,\na.ts\n#EXTINF:5.994,title\nb.ts\n";
+        assert_eq!(first_variant(media), None);
+        assert!((playlist_duration_sec(media) - 12.0).abs() < 1e-9);
+        assert!(is_aes128_encrypted(media));
+        assert!(!is_aes128_encrypted("#EXTM3U\n#EXTINF:6,\na.ts\n"));
+    }
+}
@@ -1,10 +1,23 @@
-//! IP Geolocation client using ip-api.com
+//! IP Geolocation client
 //!
-//! Free tier: 45 requests/minute (non-commercial use)
-//! HTTP only (HTTPS requires paid plan)
+//! 1차: 오프라인 MaxMind DB (`.mmdb` — GeoLite2/GeoIP2 City + ASN).
+//!      로그인 경로에서 외부 호출 없이 조회 → 지연 없음, 사용자 IP 제3자 전송 없음.
+//! 2차 (선택): ip-api.com HTTP — `IPGEO_HTTP_FALLBACK=true` 일 때만, mmdb 미설정/미적중 시.
+//!      Free tier: 45 requests/minute (non-commercial use), HTTP only (HTTPS requires paid plan)
+//!
+//! mmdb 파일은 geoipupdate 등으로 제자리 교체되므로 `jobs::geoip_reload` 가 주기적으로
+//! 파일 mtime 을 확인해 바뀌었으면 다시 읽는다. 읽기 실패 시 기존 리더를 유지한다.
+
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
 
+use maxminddb::{geoip2, MaxMindDBError, Reader};
 use serde::Deserialize;
-use tracing::{debug, warn};
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
 
 /// IP geolocation data
 #[derive(Debug, Clone, Default)]
@@ -15,6 +28,11 @@ pub struct GeoLocation {
     pub asn: Option<i64>,
     /// Organization/ISP name
     pub org: Option<String>,
+    /// City name (English, mmdb City DB 만 제공)
+    pub city: Option<String>,
+    /// 대략적 위경도 (mmdb City DB 만 제공)
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
 
 /// ip-api.com response structure
@@ -32,31 +50,192 @@ struct IpApiResponse {
     isp: Option<String>,
 }
 
-/// IP Geolocation client
-pub struct IpGeoClient {
+// =============================================================================
+// 오프라인 mmdb
+// =============================================================================
+
+struct LoadedMmdb {
+    reader: Arc<Reader<Vec<u8>>>,
+    modified: Option<SystemTime>,
+}
+
+/// 재로딩 가능한 mmdb 파일 1개
+struct MmdbFile {
+    label: &'static str,
+    path: PathBuf,
+    loaded: RwLock<Option<LoadedMmdb>>,
+}
+
+impl MmdbFile {
+    /// 초기 로드 실패는 경고만 남긴다 (파일이 나중에 배치되면 재로딩 시 적재)
+    fn open(label: &'static str, path: &Path) -> Self {
+        let file = Self {
+            label,
+            path: path.to_path_buf(),
+            loaded: RwLock::new(None),
+        };
+        file.reload_if_changed();
+        file
+    }
+
+    fn reader(&self) -> Option<Arc<Reader<Vec<u8>>>> {
+        self.loaded
+            .read()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(|l| l.reader.clone()))
+    }
+
+    /// mtime 이 바뀌었거나 아직 적재 전이면 다시 읽는다 (블로킹 I/O). 반환 = 교체 여부
+    fn reload_if_changed(&self) -> bool {
+        let modified = match std::fs::metadata(&self.path) {
+            Ok(meta) => meta.modified().ok(),
+            Err(e) => {
+                if self.reader().is_none() {
+                    warn!(db = self.label, path = %self.path.display(), error = %e, "GeoIP mmdb not found");
+                }
+                return false;
+            }
+        };
+
+        let unchanged = self
+            .loaded
+            .read()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(|l| l.modified == modified))
+            .unwrap_or(false);
+        if unchanged {
+            return false;
+        }
+
+        match Reader::open_readfile(&self.path) {
+            Ok(reader) => {
+                info!(
+                    db = self.label,
+                    path = %self.path.display(),
+                    database_type = %reader.metadata.database_type,
+                    build_epoch = reader.metadata.build_epoch,
+                    "GeoIP mmdb loaded"
+                );
+                if let Ok(mut guard) = self.loaded.write() {
+                    *guard = Some(LoadedMmdb {
+                        reader: Arc::new(reader),
+                        modified,
+                    });
+                }
+                true
+            }
+            Err(e) => {
+                // 교체 도중(부분 기록) 등 — 기존 리더 유지, 다음 주기에 재시도
+                warn!(db = self.label, path = %self.path.display(), error = %e, "GeoIP mmdb load failed; keeping previous reader");
+                false
+            }
+        }
+    }
+}
+
+/// mmdb 조회 결과. 미적중(AddressNotFound)은 None, 그 외 오류는 경고 후 None
+fn mmdb_lookup<'a, T>(label: &str, reader: &'a Reader<Vec<u8>>, addr: IpAddr) -> Option<T>
+where
+    T: Deserialize<'a>,
+{
+    match reader.lookup::<T>(addr) {
+        Ok(v) => Some(v),
+        Err(MaxMindDBError::AddressNotFoundError(_)) => None,
+        Err(e) => {
+            warn!(db = label, ip = %addr, error = %e, "GeoIP mmdb lookup failed");
+            None
+        }
+    }
+}
+
+// =============================================================================
+// Client
+// =============================================================================
+
+/// ip-api.com HTTP 백엔드
+struct HttpBackend {
     client: reqwest::Client,
     base_url: String,
 }
 
+/// IP Geolocation client
+pub struct IpGeoClient {
+    city_db: Option<MmdbFile>,
+    asn_db: Option<MmdbFile>,
+    http: Option<HttpBackend>,
+}
+
 impl IpGeoClient {
-    /// Create a new IP geolocation client
-    ///
+    /// Config 기반 생성 (`GEOIP_CITY_DB_PATH` / `GEOIP_ASN_DB_PATH` / `IPGEO_HTTP_FALLBACK`)
+    pub fn from_config(cfg: &Config) -> crate::error::AppResult<Self> {
+        Self::new(
+            cfg.geoip_city_db_path.as_deref().map(Path::new),
+            cfg.geoip_asn_db_path.as_deref().map(Path::new),
+            cfg.ipgeo_http_fallback,
+        )
+    }
+
     /// B5 Tier 2: builder fail (TLS roots 로드 등) 시 panic 회피 → Result 전파.
-    pub fn new() -> crate::error::AppResult<Self> {
-        let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(5))
-            .build()
-            .map_err(|e| crate::error::AppError::Internal(format!("ipgeo client init: {}", e)))?;
+    pub fn new(
+        city_db_path: Option<&Path>,
+        asn_db_path: Option<&Path>,
+        http_fallback: bool,
+    ) -> crate::error::AppResult<Self> {
+        let http = if http_fallback {
+            let client = reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(5))
+                .build()
+                .map_err(|e| {
+                    crate::error::AppError::Internal(format!("ipgeo client init: {}", e))
+                })?;
+            Some(HttpBackend {
+                client,
+                // ip-api.com free tier is HTTP only
+                base_url: "http://ip-api.com/json".to_string(),
+            })
+        } else {
+            None
+        };
+
         Ok(Self {
-            client,
-            // ip-api.com free tier is HTTP only
-            base_url: "http://ip-api.com/json".to_string(),
+            city_db: city_db_path.map(|p| MmdbFile::open("city", p)),
+            asn_db: asn_db_path.map(|p| MmdbFile::open("asn", p)),
+            http,
         })
     }
 
+    /// 활성 백엔드 요약 (부팅 로그용)
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if self.city_db.is_some() {
+            parts.push("mmdb-city");
+        }
+        if self.asn_db.is_some() {
+            parts.push("mmdb-asn");
+        }
+        if self.http.is_some() {
+            parts.push("ip-api.com fallback");
+        }
+        if parts.is_empty() {
+            "disabled".to_string()
+        } else {
+            parts.join(" + ")
+        }
+    }
+
+    /// mmdb 재로딩 주기 작업에서 호출 (블로킹 I/O — spawn_blocking 안에서 실행할 것).
+    /// 반환 = 교체된 DB 수
+    pub fn reload_if_changed(&self) -> usize {
+        [&self.city_db, &self.asn_db]
+            .into_iter()
+            .flatten()
+            .filter(|db| db.reload_if_changed())
+            .count()
+    }
+
     /// Lookup geolocation for an IP address
     ///
-    /// Returns None if lookup fails (non-blocking, best-effort)
+    /// Returns empty GeoLocation if lookup fails (non-blocking, best-effort)
     pub async fn lookup(&self, ip: &str) -> GeoLocation {
         // Skip private/local IPs
         if Self::is_private_ip(ip) {
@@ -64,12 +243,56 @@ impl IpGeoClient {
             return GeoLocation::default();
         }
 
+        if let Ok(addr) = ip.parse::<IpAddr>() {
+            if let Some(geo) = self.lookup_mmdb(addr) {
+                return geo;
+            }
+        }
+
+        match &self.http {
+            Some(http) => self.lookup_http(http, ip).await,
+            None => GeoLocation::default(),
+        }
+    }
+
+    /// 오프라인 조회. 두 DB 모두 미적중/미적재면 None (→ HTTP 폴백 대상)
+    fn lookup_mmdb(&self, addr: IpAddr) -> Option<GeoLocation> {
+        let mut geo = GeoLocation::default();
+        let mut hit = false;
+
+        if let Some(reader) = self.city_db.as_ref().and_then(|db| db.reader()) {
+            if let Some(city) = mmdb_lookup::<geoip2::City>("city", &reader, addr) {
+                hit = true;
+                geo.country_code = city.country.and_then(|c| c.iso_code).map(str::to_string);
+                geo.city = city
+                    .city
+                    .and_then(|c| c.names)
+                    .and_then(|names| names.get("en").map(|s| s.to_string()));
+                if let Some(loc) = city.location {
+                    geo.latitude = loc.latitude;
+                    geo.longitude = loc.longitude;
+                }
+            }
+        }
+
+        if let Some(reader) = self.asn_db.as_ref().and_then(|db| db.reader()) {
+            if let Some(asn) = mmdb_lookup::<geoip2::Asn>("asn", &reader, addr) {
+                hit = true;
+                geo.asn = asn.autonomous_system_number.map(i64::from);
+                geo.org = asn.autonomous_system_organization.map(str::to_string);
+            }
+        }
+
+        hit.then_some(geo)
+    }
+
+    async fn lookup_http(&self, http: &HttpBackend, ip: &str) -> GeoLocation {
         let url = format!(
             "{}/{}?fields=status,countryCode,as,org,isp",
-            self.base_url, ip
+            http.base_url, ip
         );
 
-        match self.client.get(&url).send().await {
+        match http.client.get(&url).send().await {
             Ok(response) => match response.json::<IpApiResponse>().await {
                 Ok(data) => {
                     if data.status == "success" {
@@ -107,12 +330,12 @@ impl IpGeoClient {
             country_code: data.country_code,
             asn,
             org,
+            ..Default::default()
         }
     }
 
     /// Check if IP is private/local (RFC 1918, loopback, etc.)
     fn is_private_ip(ip: &str) -> bool {
-        use std::net::IpAddr;
         match ip.parse::<IpAddr>() {
             Ok(IpAddr::V4(v4)) => v4.is_private() || v4.is_loopback(),
             Ok(IpAddr::V6(v6)) => v6.is_loopback(),
@@ -129,7 +352,7 @@ mod tests {
 
     #[test]
     fn test_parse_asn() {
-        let client = IpGeoClient::new().expect("ipgeo client init in test");
+        let client = IpGeoClient::new(None, None, true).expect("ipgeo client init in test");
         let response = IpApiResponse {
             status: "success".to_string(),
             country_code: Some("KR".to_string()),
@@ -152,4 +375,103 @@ mod tests {
         assert!(IpGeoClient::is_private_ip("172.16.0.1"));
         assert!(!IpGeoClient::is_private_ip("8.8.8.8"));
     }
+
+    // ------------------------------------------------------------------------
+    // 오프라인 mmdb (tests/fixtures/geoip-test.mmdb — scripts/gen_geoip_fixture.py)
+    // ------------------------------------------------------------------------
+
+    fn fixture_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/geoip-test.mmdb")
+    }
+
+    fn mmdb_client() -> IpGeoClient {
+        let path = fixture_path();
+        IpGeoClient::new(Some(&path), Some(&path), false).expect("ipgeo client init in test")
+    }
+
+    #[tokio::test]
+    async fn test_mmdb_lookup_ipv4_city_and_asn() {
+        let geo = mmdb_client().lookup("1.2.3.4").await;
+        assert_eq!(geo.country_code.as_deref(), Some("KR"));
+        assert_eq!(geo.city.as_deref(), Some("Seoul"));
+        assert_eq!(geo.asn, Some(4766));
+        assert_eq!(geo.org.as_deref(), Some("Korea Telecom"));
+        let (lat, lon) = (geo.latitude.unwrap(), geo.longitude.unwrap());
+        assert!((lat - 37.5665).abs() < 1e-6 && (lon - 126.978).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_mmdb_lookup_ipv6() {
+        let geo = mmdb_client().lookup("2001:db8::1").await;
+        assert_eq!(geo.country_code.as_deref(), Some("JP"));
+        assert_eq!(geo.asn, Some(2516));
+    }
+
+    #[tokio::test]
+    async fn test_mmdb_miss_without_fallback_is_empty() {
+        // 9.9.9.9 는 픽스처에 없음 + HTTP 폴백 꺼짐 → 외부 호출 없이 빈 결과
+        let geo = mmdb_client().lookup("9.9.9.9").await;
+        assert!(geo.country_code.is_none() && geo.asn.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_missing_mmdb_file_does_not_fail_init() {
+        let client = IpGeoClient::new(Some(Path::new("/nonexistent/city.mmdb")), None, false)
+            .expect("init must survive a missing file");
+        assert!(client.lookup("1.2.3.4").await.country_code.is_none());
+        assert_eq!(client.reload_if_changed(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reload_picks_up_replaced_file() {
+        let dir = std::env::temp_dir().join(format!("amk-geoip-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("city.mmdb");
+
+        // 처음엔 파일 없음 → 빈 결과
+        let client = IpGeoClient::new(Some(&path), None, false).unwrap();
+        assert!(client.lookup("8.8.8.8").await.country_code.is_none());
+
+        // geoipupdate 처럼 파일 배치 → 재로딩 후 조회 가능
+        std::fs::copy(fixture_path(), &path).unwrap();
+        assert_eq!(client.reload_if_changed(), 1);
+        assert_eq!(
+            client.lookup("8.8.8.8").await.country_code.as_deref(),
+            Some("US")
+        );
+        // 변경 없음 → 재로딩 안 함
+        assert_eq!(client.reload_if_changed(), 0);
+
+        // 손상된 파일로 교체 → 기존 리더 유지
+        std::fs::write(&path, b"garbage").unwrap();
+        let later = SystemTime::now() + std::time::Duration::from_secs(5);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(client.reload_if_changed(), 0);
+        assert_eq!(
+            client.lookup("8.8.8.8").await.country_code.as_deref(),
+            Some("US")
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_describe_backends() {
+        assert_eq!(
+            IpGeoClient::new(None, None, false).unwrap().describe(),
+            "disabled"
+        );
+        let path = fixture_path();
+        assert_eq!(
+            IpGeoClient::new(Some(&path), None, true)
+                .unwrap()
+                .describe(),
+            "mmdb-city + ip-api.com fallback"
+        );
+    }
 }
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use crate::api::auth::service::OAuthUserInfo;
+use crate::error::AppResult;
+use crate::external::oidc::{AuthFlowState, OidcClient, OidcClientConfig, OidcTokenResponse};
+
+pub(crate) const KAKAO_ISSUER: &str = "https://kauth.kakao.com";
+
+/// 카카오 로그인 클라이언트 (카카오 OpenID Connect — discovery 기반 `OidcClient`).
+///
+/// 웹은 Authorization Code + PKCE, 모바일은 카카오 SDK 가 받은 ID token 을 직접 검증한다.
+/// ID token aud 는 발급받은 앱 키 (웹 = REST API 키, 네이티브 SDK = 네이티브 앱 키).
+pub struct KakaoOAuthClient {
+    inner: OidcClient,
+}
+
+/// 카카오 ID Token Claims
+/// 카카오 특이사항: email 은 이메일 동의 + 유효·인증된 카카오계정 이메일일 때만 포함
+#[derive(Debug, Deserialize)]
+pub struct KakaoIdTokenClaims {
+    #[allow(dead_code)]
+    pub iss: String, // "https://kauth.kakao.com" (jsonwebtoken이 검증)
+    #[allow(dead_code)]
+    pub aud: String, // 앱 키 (jsonwebtoken이 검증)
+    pub sub: String, // 카카오 회원번호
+    pub nickname: Option<String>,
+    pub picture: Option<String>,
+    pub email: Option<String>,
+    #[allow(dead_code)]
+    pub iat: i64,
+    #[allow(dead_code)]
+    pub exp: i64, // jsonwebtoken이 검증
+    pub nonce: Option<String>, // 웹 흐름 Replay 방지 (SDK 로그인은 없을 수 있음)
+}
+
+impl KakaoOAuthClient {
+    /// issuer discovery 로 생성. `audiences` 가 비면 client_id(REST API 키)만 허용
+    pub async fn discover(
+        issuer: &str,
+        client_id: String,
+        client_secret: Option<String>,
+        redirect_uri: Option<String>,
+        audiences: Vec<String>,
+    ) -> AppResult<Self> {
+        let inner = OidcClient::discover(
+            "Kakao",
+            issuer,
+            OidcClientConfig {
+                client_id,
+                client_secret,
+                redirect_uri,
+                scope: "openid account_email profile_nickname profile_image",
+                audiences,
+                pkce: true,
+            },
+        )
+        .await?;
+        Ok(Self { inner })
+    }
+
+    /// Authorization URL (state / nonce / PKCE challenge 포함)
+    pub fn build_auth_url(&self, state: &str, flow: &AuthFlowState) -> String {
+        self.inner.authorization_url(state, flow, &[])
+    }
+
+    /// Authorization Code → Tokens 교환
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        pkce_verifier: Option<&str>,
+    ) -> AppResult<OidcTokenResponse> {
+        self.inner.exchange_code(code, pkce_verifier, &[]).await
+    }
+
+    /// ID Token 디코딩 + 카카오 JWKS 서명 검증 (issuer + audience)
+    pub async fn decode_id_token(&self, id_token: &str) -> AppResult<KakaoIdTokenClaims> {
+        self.inner.verify_id_token(id_token).await
+    }
+
+    /// Claims에서 OAuthUserInfo 추출 (이메일이 있으면 카카오가 인증한 이메일)
+    pub fn extract_user_info(&self, claims: &KakaoIdTokenClaims) -> OAuthUserInfo {
+        OAuthUserInfo {
+            sub: claims.sub.clone(),
+            email: claims.email.clone().unwrap_or_default(),
+            email_verified: claims.email.is_some(),
+            name: claims.nickname.clone(),
+            picture: claims.picture.clone(),
+        }
+    }
+}
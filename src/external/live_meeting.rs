@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use std::sync::Arc;
+
+use crate::config::Config;
+use crate::error::{AppError, AppResult};
+
+/// 회의실 생성/변경 요청
+#[derive(Debug, Clone)]
+pub struct LiveMeetingRequest {
+    pub live_session_id: i64,
+    pub title: String,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    /// IANA 시간대 (예: "Asia/Seoul") — 외부 프로바이더 표시용
+    pub timezone: String,
+}
+
+/// 발급된 회의실
+#[derive(Debug, Clone)]
+pub struct LiveMeeting {
+    /// 프로바이더 측 회의 식별자 (변경/취소 시 사용)
+    pub external_id: String,
+    pub join_url: String,
+}
+
+/// 라이브 수업 회의실 프로바이더 추상화
+///
+/// 세션 생성 시 회의실을 발급받고, 일정 변경/취소를 프로바이더에 반영한다.
+/// 참가 권한(수강 확정 여부) 확인은 호출 측 책임.
+#[async_trait]
+pub trait LiveMeetingProvider: Send + Sync {
+    /// `live_session.meeting_provider` 에 기록되는 이름
+    fn name(&self) -> &'static str;
+
+    async fn create_meeting(&self, req: &LiveMeetingRequest) -> AppResult<LiveMeeting>;
+
+    /// 일정 변경 반영 (URL 은 유지)
+    async fn update_meeting(&self, external_id: &str, req: &LiveMeetingRequest) -> AppResult<()>;
+
+    async fn cancel_meeting(&self, external_id: &str) -> AppResult<()>;
+}
+
+/// 자체 회의실 stub — 추측 불가능한 방 ID 로 `{base_url}/{room}` URL 만 발급
+pub struct LocalMeetingProvider {
+    base_url: String,
+}
+
+impl LocalMeetingProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl LiveMeetingProvider for LocalMeetingProvider {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn create_meeting(&self, _req: &LiveMeetingRequest) -> AppResult<LiveMeeting> {
+        let mut bytes = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let room = hex::encode(bytes);
+        Ok(LiveMeeting {
+            join_url: format!("{}/{}", self.base_url, room),
+            external_id: room,
+        })
+    }
+
+    async fn update_meeting(&self, _external_id: &str, _req: &LiveMeetingRequest) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn cancel_meeting(&self, _external_id: &str) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+/// LIVE_MEETING_PROVIDER 설정에 따라 프로바이더 생성
+pub fn from_config(cfg: &Config) -> AppResult<Arc<dyn LiveMeetingProvider>> {
+    match cfg.live_meeting_provider.as_str() {
+        "local" => {
+            let base_url = cfg
+                .live_meeting_base_url
+                .clone()
+                .unwrap_or_else(|| format!("{}/live/room", cfg.frontend_url.trim_end_matches('/')));
+            Ok(Arc::new(LocalMeetingProvider::new(base_url)))
+        }
+        other => Err(AppError::Internal(format!(
+            "Unknown LIVE_MEETING_PROVIDER '{other}'. Must be 'local'."
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req() -> LiveMeetingRequest {
+        LiveMeetingRequest {
+            live_session_id: 1,
+            title: "회화 1회차".to_string(),
+            start_at: Utc::now(),
+            end_at: Utc::now(),
+            timezone: "Asia/Seoul".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn local_provider_issues_distinct_rooms_under_base_url() {
+        let provider = LocalMeetingProvider::new("https://amk.test/live/room/");
+        let a = provider.create_meeting(&req()).await.unwrap();
+        let b = provider.create_meeting(&req()).await.unwrap();
+        assert_eq!(
+            a.join_url,
+            format!("https://amk.test/live/room/{}", a.external_id)
+        );
+        assert_eq!(a.external_id.len(), 24);
+        assert_ne!(a.external_id, b.external_id);
+    }
+}
@@ -1,7 +1,14 @@
 pub mod apple;
 pub mod email;
 pub mod google;
+pub mod hls;
 pub mod ipgeo;
+pub mod kakao;
+pub mod live_meeting;
+pub mod naver;
+pub mod oidc;
 pub mod payment;
 pub mod revenuecat;
+pub mod saml;
+pub mod video_host;
 pub mod vimeo;
@@ -0,0 +1,166 @@
+use serde::Deserialize;
+
+use crate::api::auth::service::OAuthUserInfo;
+use crate::error::{AppError, AppResult};
+use crate::external::oidc::{
+    AuthFlowState, OidcClient, OidcClientConfig, OidcTokenResponse, ProviderMetadata,
+};
+
+pub(crate) const NAVER_AUTH_BASE_URL: &str = "https://nid.naver.com";
+pub(crate) const NAVER_API_BASE_URL: &str = "https://openapi.naver.com";
+
+/// 네이버 로그인 클라이언트 (순수 OAuth2 — ID token 없음, 프로필 API 로 사용자 확인).
+///
+/// 네이버는 PKCE 를 지원하지 않고 토큰 교환에도 state 를 요구한다.
+pub struct NaverOAuthClient {
+    inner: OidcClient,
+}
+
+/// 네이버 프로필 API 응답 (`/v1/nid/me`)
+#[derive(Debug, Deserialize)]
+struct NaverProfileRes {
+    resultcode: String,
+    message: String,
+    response: Option<NaverProfile>,
+}
+
+/// 네이버 프로필 (동의한 항목만 포함)
+#[derive(Debug, Deserialize)]
+pub struct NaverProfile {
+    pub id: String, // 애플리케이션별 고유 식별자
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub nickname: Option<String>,
+    pub profile_image: Option<String>,
+}
+
+impl NaverOAuthClient {
+    /// 새 네이버 클라이언트 생성 (production = 공식 네이버 URL).
+    pub fn new(
+        client_id: String,
+        client_secret: Option<String>,
+        redirect_uri: Option<String>,
+    ) -> AppResult<Self> {
+        Self::with_urls(
+            client_id,
+            client_secret,
+            redirect_uri,
+            NAVER_AUTH_BASE_URL,
+            NAVER_API_BASE_URL,
+        )
+    }
+
+    /// URL override 가능한 생성자. test 환경에서 wiremock URL 주입용.
+    pub fn with_urls(
+        client_id: String,
+        client_secret: Option<String>,
+        redirect_uri: Option<String>,
+        auth_base_url: &str,
+        api_base_url: &str,
+    ) -> AppResult<Self> {
+        let inner = OidcClient::new(
+            "Naver",
+            ProviderMetadata {
+                issuer: None,
+                authorization_endpoint: format!("{}/oauth2.0/authorize", auth_base_url),
+                token_endpoint: format!("{}/oauth2.0/token", auth_base_url),
+                jwks_uri: None,
+                userinfo_endpoint: Some(format!("{}/v1/nid/me", api_base_url)),
+            },
+            OidcClientConfig {
+                client_id,
+                client_secret,
+                redirect_uri,
+                scope: "",
+                audiences: Vec::new(),
+                pkce: false,
+            },
+        )?;
+        Ok(Self { inner })
+    }
+
+    /// Authorization URL (state 만 — 네이버는 nonce / PKCE 미사용)
+    pub fn build_auth_url(&self, state: &str, flow: &AuthFlowState) -> String {
+        self.inner.authorization_url(state, flow, &[])
+    }
+
+    /// Authorization Code → Tokens 교환 (네이버는 state 재전송 필요)
+    pub async fn exchange_code(&self, code: &str, state: &str) -> AppResult<OidcTokenResponse> {
+        self.inner
+            .exchange_code(code, None, &[("state", state)])
+            .await
+    }
+
+    /// access token 으로 프로필 조회
+    pub async fn fetch_profile(&self, access_token: &str) -> AppResult<NaverProfile> {
+        let res: NaverProfileRes = self.inner.fetch_userinfo(access_token).await?;
+        match res.response {
+            Some(profile) if res.resultcode == "00" => Ok(profile),
+            _ => Err(AppError::External(format!(
+                "Naver profile error: {} - {}",
+                res.resultcode, res.message
+            ))),
+        }
+    }
+
+    /// 프로필에서 OAuthUserInfo 추출.
+    ///
+    /// 네이버는 이메일 인증 여부를 알려주지 않는다. 네이버 메일(@naver.com)만 계정 소유로
+    /// 인증된 것으로 보고, 그 외 연락처 이메일은 미인증으로 취급한다.
+    pub fn extract_user_info(&self, profile: &NaverProfile) -> OAuthUserInfo {
+        let email = profile.email.clone().unwrap_or_default();
+        OAuthUserInfo {
+            sub: profile.id.clone(),
+            email_verified: email.to_lowercase().ends_with("@naver.com"),
+            email,
+            name: profile.name.clone().or_else(|| profile.nickname.clone()),
+            picture: profile.profile_image.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(email: Option<&str>) -> NaverProfile {
+        NaverProfile {
+            id: "naver-id-1".into(),
+            email: email.map(str::to_string),
+            name: None,
+            nickname: Some("nick".into()),
+            profile_image: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_user_info_trusts_only_naver_mail() {
+        let client = NaverOAuthClient::new("cid".into(), None, None).expect("client init");
+
+        let info = client.extract_user_info(&profile(Some("User@Naver.com")));
+        assert!(info.email_verified);
+        assert_eq!(info.name.as_deref(), Some("nick"));
+
+        let info = client.extract_user_info(&profile(Some("user@gmail.com")));
+        assert!(!info.email_verified);
+
+        let info = client.extract_user_info(&profile(None));
+        assert!(!info.email_verified && info.email.is_empty());
+    }
+
+    #[test]
+    fn test_build_auth_url_has_state_without_pkce() {
+        let client = NaverOAuthClient::new(
+            "cid".into(),
+            Some("secret".into()),
+            Some("http://localhost:3000/auth/naver/callback".into()),
+        )
+        .expect("client init");
+        let url = client.build_auth_url("st-1", &AuthFlowState::new("naver", false));
+
+        assert!(url.starts_with("https://nid.naver.com/oauth2.0/authorize?"));
+        assert!(url.contains("state=st-1"));
+        assert!(!url.contains("code_challenge"));
+        assert!(!url.contains("secret"));
+    }
+}
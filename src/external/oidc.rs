@@ -0,0 +1,661 @@
+//! 범용 OIDC / OAuth2 클라이언트 (Google · Apple · 카카오 · 네이버 공용).
+//!
+//! - discovery: `{issuer}/.well-known/openid-configuration` 을 issuer 별로 캐시 (`DISCOVERY_CACHE_TTL`)
+//! - JWKS: jwks_uri 별 kid → DecodingKey 캐시. 모르는 kid 면 다시 받아 키 로테이션을 따라간다
+//!   (최소 간격 `JWKS_REFETCH_INTERVAL`, 새 키 세트를 받기 전까지 기존 키 유지)
+//! - PKCE (S256) + state / nonce: Redis `ak:oauth_state:{state}` 에 일회용으로 저장
+//!
+//! 클레임 형태나 사용자 정보 API 같은 공급자별 차이는 google.rs / apple.rs / kakao.rs / naver.rs 가 맡는다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use base64::engine::{general_purpose::URL_SAFE_NO_PAD, Engine};
+use deadpool_redis::Pool as RedisPool;
+use jsonwebtoken::{jwk::JwkSet, Algorithm, DecodingKey, Validation};
+use rand::RngCore;
+use redis::AsyncCommands;
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use crate::error::{AppError, AppResult};
+
+/// OAuth state 저장 키 prefix (값 = `AuthFlowState` JSON)
+const OAUTH_STATE_PREFIX: &str = "ak:oauth_state:";
+
+/// state 없이 nonce 문자열만 저장하던 이전 형식의 공급자 (배포 직전에 시작된 Google 로그인)
+const LEGACY_STATE_PROVIDER: &str = "google";
+
+// N-10: 외부 서비스 hang 방지 (timeout 15초)
+fn http_client(provider: &str) -> AppResult<Client> {
+    Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| AppError::Internal(format!("{} oauth client init: {}", provider, e)))
+}
+
+// =============================================================================
+// Discovery
+// =============================================================================
+
+/// 공급자 endpoint 메타데이터 (OIDC discovery 문서의 필요한 부분)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    /// OIDC issuer. 순수 OAuth2 공급자(네이버)는 None
+    #[serde(default)]
+    pub issuer: Option<String>,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    /// None = ID token 미발급 (순수 OAuth2)
+    #[serde(default)]
+    pub jwks_uri: Option<String>,
+    #[serde(default)]
+    pub userinfo_endpoint: Option<String>,
+}
+
+/// discovery 문서 캐시 유효 기간 — 공급자 / 기관 IdP 의 endpoint 변경을 재시작 없이 따라간다
+const DISCOVERY_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+type DiscoveryCache = Mutex<HashMap<String, (ProviderMetadata, Instant)>>;
+
+fn discovery_cache() -> &'static DiscoveryCache {
+    static CACHE: OnceLock<DiscoveryCache> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// `{issuer}/.well-known/openid-configuration` 조회 (issuer 별 캐시, `DISCOVERY_CACHE_TTL`).
+///
+/// discovery 문서의 issuer 가 요청한 issuer 와 다르면 거부한다 (OIDC Discovery §4.3).
+/// 만료 후 다시 받지 못하면 이전 문서를 계속 쓴다 (IdP 일시 장애로 로그인이 막히지 않도록).
+pub async fn discover(issuer: &str) -> AppResult<ProviderMetadata> {
+    let issuer = issuer.trim_end_matches('/');
+    let cached = discovery_cache()
+        .lock()
+        .map_err(|_| AppError::Internal("oidc discovery cache poisoned".into()))?
+        .get(issuer)
+        .cloned();
+    if let Some((meta, fetched_at)) = &cached {
+        if fetched_at.elapsed() < DISCOVERY_CACHE_TTL {
+            return Ok(meta.clone());
+        }
+    }
+
+    match fetch_discovery(issuer).await {
+        Ok(meta) => {
+            discovery_cache()
+                .lock()
+                .map_err(|_| AppError::Internal("oidc discovery cache poisoned".into()))?
+                .insert(issuer.to_string(), (meta.clone(), Instant::now()));
+            Ok(meta)
+        }
+        Err(e) => match cached {
+            Some((meta, _)) => {
+                warn!(issuer, error = %e, "OIDC discovery refresh failed, keeping cached metadata");
+                Ok(meta)
+            }
+            None => Err(e),
+        },
+    }
+}
+
+async fn fetch_discovery(issuer: &str) -> AppResult<ProviderMetadata> {
+    let url = format!("{}/.well-known/openid-configuration", issuer);
+    let meta: ProviderMetadata = http_client("oidc")?
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| AppError::External(format!("OIDC discovery failed ({}): {}", issuer, e)))?
+        .error_for_status()
+        .map_err(|e| AppError::External(format!("OIDC discovery failed ({}): {}", issuer, e)))?
+        .json()
+        .await
+        .map_err(|e| {
+            AppError::External(format!("OIDC discovery parse error ({}): {}", issuer, e))
+        })?;
+
+    if meta.issuer.as_deref().map(|i| i.trim_end_matches('/')) != Some(issuer) {
+        return Err(AppError::External(format!(
+            "OIDC discovery issuer mismatch: expected {}, got {:?}",
+            issuer, meta.issuer
+        )));
+    }
+    Ok(meta)
+}
+
+// =============================================================================
+// JWKS 캐시
+// =============================================================================
+
+/// 모르는 kid 로 JWKS 를 다시 받는 최소 간격 — 임의 kid 를 단 토큰으로 공급자에 요청을 쏟아내지 않도록
+const JWKS_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// jwks_uri 하나의 kid → DecodingKey 캐시.
+///
+/// 요청마다 클라이언트를 새로 만들어도 키는 `JwksCache::shared` 로 프로세스 전체가 공유한다.
+pub struct JwksCache {
+    client: Client,
+    jwks_url: String,
+    keys: RwLock<HashMap<String, DecodingKey>>,
+    /// 마지막 JWKS 요청 시각. 다시 받기를 한 번에 하나로 묶는 락을 겸한다
+    last_fetch: tokio::sync::Mutex<Option<Instant>>,
+}
+
+impl JwksCache {
+    /// jwks_uri 별 공유 캐시 (없으면 생성)
+    pub fn shared(jwks_url: &str) -> AppResult<Arc<JwksCache>> {
+        static REGISTRY: OnceLock<Mutex<HashMap<String, Arc<JwksCache>>>> = OnceLock::new();
+        let mut registry = REGISTRY
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .map_err(|_| AppError::Internal("jwks registry poisoned".into()))?;
+        if let Some(cache) = registry.get(jwks_url) {
+            return Ok(cache.clone());
+        }
+        let cache = Arc::new(JwksCache {
+            client: http_client("jwks")?,
+            jwks_url: jwks_url.to_string(),
+            keys: RwLock::new(HashMap::new()),
+            last_fetch: tokio::sync::Mutex::new(None),
+        });
+        registry.insert(jwks_url.to_string(), cache.clone());
+        Ok(cache)
+    }
+
+    /// kid 에 대응하는 DecodingKey. 캐시 miss 면 JWKS 를 다시 받아 전체를 교체한다.
+    ///
+    /// 다시 받기는 `JWKS_REFETCH_INTERVAL` 에 한 번 (키를 한 번도 못 받았으면 제한 없음).
+    /// 받기 실패 / 쓸 수 있는 키가 없는 응답이면 기존 키를 그대로 둔다.
+    pub async fn decoding_key(&self, kid: &str) -> AppResult<DecodingKey> {
+        if let Some(key) = self.keys.read().await.get(kid) {
+            return Ok(key.clone());
+        }
+
+        // 동시 캐시 미스는 한 요청만 받아오고, 나머지는 락을 얻은 뒤 다시 확인한다
+        let mut last_fetch = self.last_fetch.lock().await;
+        {
+            let keys = self.keys.read().await;
+            if let Some(key) = keys.get(kid) {
+                return Ok(key.clone());
+            }
+            let throttled = last_fetch.is_some_and(|at| at.elapsed() < JWKS_REFETCH_INTERVAL);
+            if throttled && !keys.is_empty() {
+                return Err(AppError::External("No matching key found in JWKS".into()));
+            }
+        }
+        *last_fetch = Some(Instant::now());
+
+        let jwks: JwkSet = self
+            .client
+            .get(&self.jwks_url)
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(|e| AppError::External(format!("Failed to fetch JWKS: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| AppError::External(format!("Failed to parse JWKS: {}", e)))?;
+        let fresh = usable_keys(&jwks);
+        if fresh.is_empty() {
+            return Err(AppError::External("JWKS has no usable keys".into()));
+        }
+
+        // 로테이션으로 빠진 키는 함께 정리
+        let mut keys = self.keys.write().await;
+        *keys = fresh;
+        keys.get(kid)
+            .cloned()
+            .ok_or_else(|| AppError::External("No matching key found in JWKS".into()))
+    }
+}
+
+/// JWKS → kid 별 DecodingKey. kid 가 없거나 해석 못 하는 키(미지원 kty 등)는 건너뛴다
+fn usable_keys(jwks: &JwkSet) -> HashMap<String, DecodingKey> {
+    jwks.keys
+        .iter()
+        .filter_map(|jwk| {
+            let kid = jwk.common.key_id.clone()?;
+            DecodingKey::from_jwk(jwk).ok().map(|key| (kid, key))
+        })
+        .collect()
+}
+
+// =============================================================================
+// PKCE + state / nonce (Redis)
+// =============================================================================
+
+/// 43자 URL-safe 랜덤 문자열 (32 bytes)
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// PKCE S256 code_challenge = BASE64URL(SHA256(code_verifier))
+pub fn pkce_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+/// 인가 요청 ~ 콜백 사이에 Redis 에 보관하는 값
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthFlowState {
+    /// state 를 발급한 공급자 — 다른 공급자 콜백으로 재사용 불가
+    pub provider: String,
+    /// ID token Replay 방지
+    pub nonce: String,
+    /// PKCE code_verifier (PKCE 미지원 공급자는 None)
+    #[serde(default)]
+    pub pkce_verifier: Option<String>,
+}
+
+impl AuthFlowState {
+    pub fn new(provider: &str, pkce: bool) -> Self {
+        Self {
+            provider: provider.to_string(),
+            nonce: random_token(),
+            pkce_verifier: pkce.then(random_token),
+        }
+    }
+
+    /// JSON 이 아니면 이전 형식(nonce 문자열만 저장한 Google state)으로 본다
+    fn parse(raw: &str) -> Self {
+        serde_json::from_str(raw).unwrap_or_else(|_| Self {
+            provider: LEGACY_STATE_PROVIDER.to_string(),
+            nonce: raw.to_string(),
+            pkce_verifier: None,
+        })
+    }
+}
+
+/// state → AuthFlowState 저장 (TTL = OAUTH_STATE_TTL_SEC)
+pub async fn save_flow_state(
+    redis: &RedisPool,
+    state: &str,
+    flow: &AuthFlowState,
+    ttl_sec: u64,
+) -> AppResult<()> {
+    let value = serde_json::to_string(flow).map_err(|e| AppError::Internal(e.to_string()))?;
+    let mut conn = redis
+        .get()
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    let _: () = conn
+        .set_ex(format!("{}{}", OAUTH_STATE_PREFIX, state), value, ttl_sec)
+        .await?;
+    Ok(())
+}
+
+/// state 조회 + 삭제 (GETDEL, 일회용). 없거나 다른 공급자의 state 면 AUTH_401_INVALID_OAUTH_STATE
+pub async fn take_flow_state(
+    redis: &RedisPool,
+    state: &str,
+    provider: &str,
+) -> AppResult<AuthFlowState> {
+    let mut conn = redis
+        .get()
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    // GETDEL — 같은 state 로 동시에 들어온 콜백 중 하나만 통과
+    let raw: Option<String> = conn
+        .get_del(format!("{}{}", OAUTH_STATE_PREFIX, state))
+        .await?;
+    let Some(raw) = raw else {
+        return Err(AppError::Unauthorized(
+            "AUTH_401_INVALID_OAUTH_STATE".into(),
+        ));
+    };
+
+    let flow = AuthFlowState::parse(&raw);
+    if flow.provider != provider {
+        return Err(AppError::Unauthorized(
+            "AUTH_401_INVALID_OAUTH_STATE".into(),
+        ));
+    }
+    Ok(flow)
+}
+
+// =============================================================================
+// 클라이언트
+// =============================================================================
+
+/// 공급자 무관 클라이언트 설정
+#[derive(Debug, Clone, Default)]
+pub struct OidcClientConfig {
+    pub client_id: String,
+    /// None = public client (모바일 ID token 검증 전용 등)
+    pub client_secret: Option<String>,
+    pub redirect_uri: Option<String>,
+    /// 빈 문자열이면 scope 파라미터 생략 (네이버)
+    pub scope: &'static str,
+    /// ID token aud 로 허용할 값. 비우면 client_id 만 허용
+    pub audiences: Vec<String>,
+    /// PKCE(S256) 사용 여부
+    pub pkce: bool,
+}
+
+/// Token 교환 응답 (RFC 6749 §5.1 + OIDC id_token)
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)] // 역직렬화에 필요하지만 id_token / access_token 만 사용
+pub struct OidcTokenResponse {
+    #[serde(default)]
+    pub access_token: String,
+    pub id_token: Option<String>,
+    pub token_type: Option<String>,
+    /// 네이버는 문자열("3600")로 내려줘 숫자로 고정하지 않는다
+    pub expires_in: Option<serde_json::Value>,
+    pub scope: Option<String>,
+    pub refresh_token: Option<String>,
+    /// 네이버처럼 실패도 200 으로 내려주는 공급자 대비
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+pub struct OidcClient {
+    client: Client,
+    /// 에러 메시지용 공급자 이름 ("Google", "Kakao" ...)
+    provider: &'static str,
+    metadata: ProviderMetadata,
+    config: OidcClientConfig,
+    /// ID token iss 로 허용할 값 (metadata.issuer + 추가 허용분)
+    issuers: Vec<String>,
+    jwks: Option<Arc<JwksCache>>,
+}
+
+impl OidcClient {
+    /// 고정 endpoint 로 생성 (discovery 를 쓰지 않는 공급자 / test URL override)
+    pub fn new(
+        provider: &'static str,
+        metadata: ProviderMetadata,
+        config: OidcClientConfig,
+    ) -> AppResult<Self> {
+        let jwks = metadata
+            .jwks_uri
+            .as_deref()
+            .map(JwksCache::shared)
+            .transpose()?;
+        Ok(Self {
+            client: http_client(provider)?,
+            provider,
+            issuers: metadata.issuer.iter().cloned().collect(),
+            metadata,
+            config,
+            jwks,
+        })
+    }
+
+    /// issuer discovery 로 생성
+    pub async fn discover(
+        provider: &'static str,
+        issuer: &str,
+        config: OidcClientConfig,
+    ) -> AppResult<Self> {
+        let metadata = discover(issuer).await?;
+        Self::new(provider, metadata, config)
+    }
+
+    /// 추가로 허용할 iss (Google 은 scheme 없는 "accounts.google.com" 도 발급)
+    pub fn accept_issuer(mut self, issuer: &str) -> Self {
+        self.issuers.push(issuer.to_string());
+        self
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.config.client_id
+    }
+
+    /// Authorization URL. OIDC 공급자면 nonce, PKCE 사용 시 code_challenge 포함
+    pub fn authorization_url(
+        &self,
+        state: &str,
+        flow: &AuthFlowState,
+        extra: &[(&str, &str)],
+    ) -> String {
+        let challenge = flow
+            .pkce_verifier
+            .as_deref()
+            .filter(|_| self.config.pkce)
+            .map(pkce_challenge);
+
+        let mut params: Vec<(&str, &str)> = vec![
+            ("client_id", self.config.client_id.as_str()),
+            ("response_type", "code"),
+            ("state", state),
+        ];
+        if let Some(redirect_uri) = self.config.redirect_uri.as_deref() {
+            params.push(("redirect_uri", redirect_uri));
+        }
+        if !self.config.scope.is_empty() {
+            params.push(("scope", self.config.scope));
+        }
+        if self.jwks.is_some() {
+            params.push(("nonce", flow.nonce.as_str()));
+        }
+        if let Some(challenge) = challenge.as_deref() {
+            params.push(("code_challenge", challenge));
+            params.push(("code_challenge_method", "S256"));
+        }
+        params.extend_from_slice(extra);
+
+        let query = serde_urlencoded::to_string(params).unwrap_or_default();
+        format!("{}?{}", self.metadata.authorization_endpoint, query)
+    }
+
+    /// Authorization Code → Tokens 교환
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        pkce_verifier: Option<&str>,
+        extra: &[(&str, &str)],
+    ) -> AppResult<OidcTokenResponse> {
+        let mut params: Vec<(&str, &str)> = vec![
+            ("grant_type", "authorization_code"),
+            ("client_id", self.config.client_id.as_str()),
+            ("code", code),
+        ];
+        if let Some(secret) = self.config.client_secret.as_deref() {
+            params.push(("client_secret", secret));
+        }
+        if let Some(redirect_uri) = self.config.redirect_uri.as_deref() {
+            params.push(("redirect_uri", redirect_uri));
+        }
+        if let Some(verifier) = pkce_verifier.filter(|_| self.config.pkce) {
+            params.push(("code_verifier", verifier));
+        }
+        params.extend_from_slice(extra);
+
+        let response = self
+            .client
+            .post(&self.metadata.token_endpoint)
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::External(format!("{} token exchange failed: {}", self.provider, e))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::External(format!(
+                "{} OAuth error: {} - {}",
+                self.provider, status, body
+            )));
+        }
+
+        let token: OidcTokenResponse = response.json().await.map_err(|e| {
+            AppError::External(format!("{} token parse error: {}", self.provider, e))
+        })?;
+        if let Some(error) = token.error.as_deref() {
+            return Err(AppError::External(format!(
+                "{} OAuth error: {} - {}",
+                self.provider,
+                error,
+                token.error_description.as_deref().unwrap_or_default()
+            )));
+        }
+        Ok(token)
+    }
+
+    /// ID token 서명(JWKS, RS256) + iss / aud / exp 검증 후 클레임 반환.
+    /// nonce 는 호출 측이 `AuthFlowState` 와 비교한다 (모바일 SDK 토큰은 nonce 가 없을 수 있음).
+    pub async fn verify_id_token<C: DeserializeOwned>(&self, id_token: &str) -> AppResult<C> {
+        let jwks = self.jwks.as_ref().ok_or_else(|| {
+            AppError::Internal(format!("{} does not issue ID tokens", self.provider))
+        })?;
+
+        let header = jsonwebtoken::decode_header(id_token).map_err(|e| {
+            AppError::External(format!("Invalid {} ID token header: {}", self.provider, e))
+        })?;
+        let kid = header.kid.ok_or_else(|| {
+            AppError::External(format!("{} ID token missing kid in header", self.provider))
+        })?;
+        let decoding_key = jwks.decoding_key(&kid).await?;
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_issuer(&self.issuers);
+        if self.config.audiences.is_empty() {
+            validation.set_audience(&[&self.config.client_id]);
+        } else {
+            validation.set_audience(&self.config.audiences);
+        }
+
+        jsonwebtoken::decode::<C>(id_token, &decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| {
+                AppError::External(format!(
+                    "{} ID token verification failed: {}",
+                    self.provider, e
+                ))
+            })
+    }
+
+    /// userinfo endpoint 조회 (Bearer access token)
+    pub async fn fetch_userinfo<T: DeserializeOwned>(&self, access_token: &str) -> AppResult<T> {
+        let url = self.metadata.userinfo_endpoint.as_deref().ok_or_else(|| {
+            AppError::Internal(format!(
+                "{} userinfo endpoint not configured",
+                self.provider
+            ))
+        })?;
+
+        let response = self
+            .client
+            .get(url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::External(format!("{} userinfo request failed: {}", self.provider, e))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::External(format!(
+                "{} userinfo error: {} - {}",
+                self.provider, status, body
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            AppError::External(format!("{} userinfo parse error: {}", self.provider, e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_client(pkce: bool, jwks_uri: Option<&str>) -> OidcClient {
+        OidcClient::new(
+            "Test",
+            ProviderMetadata {
+                issuer: Some("https://idp.example.com".into()),
+                authorization_endpoint: "https://idp.example.com/authorize".into(),
+                token_endpoint: "https://idp.example.com/token".into(),
+                jwks_uri: jwks_uri.map(str::to_string),
+                userinfo_endpoint: None,
+            },
+            OidcClientConfig {
+                client_id: "client-1".into(),
+                redirect_uri: Some("http://localhost:3000/cb".into()),
+                scope: "openid email",
+                pkce,
+                ..Default::default()
+            },
+        )
+        .expect("client init in test")
+    }
+
+    #[test]
+    fn test_pkce_challenge_rfc7636_vector() {
+        // RFC 7636 Appendix B
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_flow_state_roundtrip_and_legacy() {
+        let flow = AuthFlowState::new("kakao", true);
+        assert_eq!(flow.nonce.len(), 43);
+        let raw = serde_json::to_string(&flow).unwrap();
+        let parsed = AuthFlowState::parse(&raw);
+        assert_eq!(parsed.provider, "kakao");
+        assert_eq!(parsed.pkce_verifier, flow.pkce_verifier);
+
+        let legacy = AuthFlowState::parse("3f1c0e5a-nonce");
+        assert_eq!(legacy.provider, "google");
+        assert_eq!(legacy.nonce, "3f1c0e5a-nonce");
+        assert!(legacy.pkce_verifier.is_none());
+    }
+
+    #[test]
+    fn test_usable_keys_skips_keys_without_kid() {
+        let jwks: JwkSet = serde_json::from_value(serde_json::json!({
+            "keys": [
+                { "kty": "RSA", "kid": "k1", "n": "sXch", "e": "AQAB" },
+                { "kty": "RSA", "n": "sXch", "e": "AQAB" },
+            ],
+        }))
+        .unwrap();
+        let keys = usable_keys(&jwks);
+        assert_eq!(keys.len(), 1);
+        assert!(keys.contains_key("k1"));
+
+        // 빈 세트 = 교체할 키 없음 → decoding_key 는 기존 키를 유지
+        let empty: JwkSet = serde_json::from_value(serde_json::json!({ "keys": [] })).unwrap();
+        assert!(usable_keys(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_authorization_url_includes_nonce_and_pkce() {
+        let client = test_client(true, Some("https://idp.example.com/jwks-url-test-1"));
+        let flow = AuthFlowState::new("test", true);
+        let url = client.authorization_url("st-1", &flow, &[("prompt", "login")]);
+
+        assert!(url.starts_with("https://idp.example.com/authorize?"));
+        assert!(url.contains("state=st-1"));
+        assert!(url.contains(&format!("nonce={}", flow.nonce)));
+        let challenge = pkce_challenge(flow.pkce_verifier.as_deref().unwrap());
+        assert!(url.contains(&format!("code_challenge={}", challenge)));
+        assert!(url.contains("code_challenge_method=S256"));
+        assert!(url.contains("prompt=login"));
+    }
+
+    #[test]
+    fn test_authorization_url_plain_oauth2_omits_nonce_and_pkce() {
+        let client = test_client(false, None);
+        let flow = AuthFlowState::new("test", false);
+        let url = client.authorization_url("st-2", &flow, &[]);
+
+        assert!(!url.contains("nonce="));
+        assert!(!url.contains("code_challenge"));
+        assert!(url.contains("scope=openid+email"));
+    }
+}
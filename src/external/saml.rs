@@ -0,0 +1,1026 @@
+//! SAML 2.0 Service Provider (기관 SSO).
+//!
+//! - IdP 메타데이터: entityID, HTTP-Redirect SSO URL, 서명 인증서(RSA)
+//! - AuthnRequest: HTTP-Redirect 바인딩 (서명 없음 — 요청 위조는 InResponseTo 대조로 막는다)
+//! - Response: HTTP-POST 바인딩. XML-DSig 중 IdP 들이 실제로 쓰는 조합만 검증한다
+//!   (exclusive c14n 주석 제외 + enveloped-signature, RSA-SHA256, SHA-256 digest)
+//!
+//! 서명 래핑(XSW) 방지: Reference 가 가리키는 ID 는 문서에서 유일해야 하고, 값은 서명 검증한
+//! 요소(Response 의 직계 Assertion 1개)에서만 읽는다. 암호화된 assertion 과 IdP 시작 로그인
+//! (InResponseTo 없음)은 지원하지 않는다.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use base64::engine::{general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Duration, Utc};
+use flate2::{write::DeflateEncoder, Compression};
+use rand::RngCore;
+use roxmltree::{Document, Node, NodeId};
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::signature::Verifier;
+use rsa::RsaPublicKey;
+use sha2::{Digest, Sha256};
+use x509_cert::der::{Decode, Encode};
+use x509_cert::Certificate;
+
+use crate::error::{AppError, AppResult};
+
+pub const NS_PROTOCOL: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+pub const NS_ASSERTION: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+const NS_METADATA: &str = "urn:oasis:names:tc:SAML:2.0:metadata";
+pub const NS_DSIG: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+pub const ALG_EXC_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+pub const ALG_ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+pub const ALG_RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+pub const ALG_SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+
+const BINDING_REDIRECT: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect";
+const BINDING_POST: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+const STATUS_SUCCESS: &str = "urn:oasis:names:tc:SAML:2.0:status:Success";
+const CM_BEARER: &str = "urn:oasis:names:tc:SAML:2.0:cm:bearer";
+
+/// IdP / SP 시계 오차 허용 (초)
+const CLOCK_SKEW_SECS: i64 = 180;
+
+/// 응답 검증 실패 — 상세 사유는 로그/리다이렉트 설명용
+fn reject(reason: impl std::fmt::Display) -> AppError {
+    AppError::Unauthorized(format!("SAML response rejected: {}", reason))
+}
+
+fn invalid_metadata(reason: impl std::fmt::Display) -> AppError {
+    AppError::BadRequest(format!("Invalid SAML metadata: {}", reason))
+}
+
+fn is(node: &Node, ns: &str, name: &str) -> bool {
+    node.is_element() && node.tag_name().namespace() == Some(ns) && node.tag_name().name() == name
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, ns: &str, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|c| is(c, ns, name))
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    ns: &'static str,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |c| is(c, ns, name))
+}
+
+fn text_of(node: Node) -> String {
+    node.children()
+        .filter(|c| c.is_text())
+        .filter_map(|c| c.text())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// 공백/개행이 섞인 base64 (XML 본문, POST 폼 값) 디코딩
+fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    let compact: String = value.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    STANDARD.decode(compact).ok()
+}
+
+fn parse_instant(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// =============================================================================
+// Exclusive XML Canonicalization 1.0 (주석 제외)
+// =============================================================================
+
+/// qname 의 prefix (없으면 ""). roxmltree 는 원문 prefix 를 보관하지 않아 입력 텍스트에서 읽는다
+fn qname_prefix(qname: &str) -> &str {
+    qname.split_once(':').map(|(p, _)| p).unwrap_or("")
+}
+
+fn element_qname<'input>(doc: &Document<'input>, node: Node) -> &'input str {
+    let rest = &doc.input_text()[node.range().start + 1..];
+    let end = rest
+        .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+        .unwrap_or(rest.len());
+    &rest[..end]
+}
+
+fn escape_c14n_text(value: &str, out: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '\r' => out.push_str("&#xD;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+fn escape_c14n_attr(value: &str, out: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' => out.push_str("&#x9;"),
+            '\n' => out.push_str("&#xA;"),
+            '\r' => out.push_str("&#xD;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+struct ExcC14n<'a, 'input> {
+    doc: &'a Document<'input>,
+    /// InclusiveNamespaces PrefixList (기본 namespace = "")
+    inclusive: &'a [String],
+    /// enveloped-signature 변환으로 빠지는 Signature 요소
+    exclude: Option<NodeId>,
+    out: String,
+}
+
+impl<'a, 'input> ExcC14n<'a, 'input> {
+    /// `rendered` = 출력된 조상 요소들이 선언한 (prefix, uri), 뒤쪽이 가까운 조상
+    fn element(&mut self, node: Node<'a, 'input>, rendered: &[(&'a str, &'a str)]) {
+        let qname = element_qname(self.doc, node);
+        let input = self.doc.input_text();
+
+        // 이 요소에서 가시적으로 쓰이는 namespace (요소 자신 + prefix 있는 속성) + inclusive 목록
+        let mut needed: Vec<(&'a str, &'a str)> = vec![(
+            qname_prefix(qname),
+            node.tag_name().namespace().unwrap_or(""),
+        )];
+        let mut attrs = Vec::new();
+        for attr in node.attributes() {
+            let attr_qname = &input[attr.range_qname()];
+            let prefix = qname_prefix(attr_qname);
+            if let Some(ns) = attr.namespace() {
+                if prefix != "xml" {
+                    needed.push((prefix, ns));
+                }
+            }
+            attrs.push((
+                attr.namespace().unwrap_or(""),
+                attr.name(),
+                attr_qname,
+                attr,
+            ));
+        }
+        for prefix in self.inclusive {
+            let lookup = (!prefix.is_empty()).then_some(prefix.as_str());
+            if let Some(uri) = node.lookup_namespace_uri(lookup) {
+                needed.push((prefix.as_str(), uri));
+            }
+        }
+
+        let mut decls: Vec<(&'a str, &'a str)> = Vec::new();
+        for (prefix, uri) in needed {
+            if decls.iter().any(|(p, _)| *p == prefix) {
+                continue;
+            }
+            let current = rendered
+                .iter()
+                .rev()
+                .find(|(p, _)| *p == prefix)
+                .map(|(_, u)| *u);
+            let emit = if prefix.is_empty() {
+                current.unwrap_or("") != uri
+            } else {
+                current != Some(uri)
+            };
+            if emit {
+                decls.push((prefix, uri));
+            }
+        }
+        decls.sort_by(|a, b| a.0.cmp(b.0));
+        attrs.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        self.out.push('<');
+        self.out.push_str(qname);
+        for (prefix, uri) in &decls {
+            if prefix.is_empty() {
+                self.out.push_str(" xmlns=\"");
+            } else {
+                self.out.push_str(" xmlns:");
+                self.out.push_str(prefix);
+                self.out.push_str("=\"");
+            }
+            escape_c14n_attr(uri, &mut self.out);
+            self.out.push('"');
+        }
+        for (_, _, attr_qname, attr) in &attrs {
+            self.out.push(' ');
+            self.out.push_str(attr_qname);
+            self.out.push_str("=\"");
+            escape_c14n_attr(attr.value(), &mut self.out);
+            self.out.push('"');
+        }
+        self.out.push('>');
+
+        let mut scope: Vec<(&'a str, &'a str)> = rendered.to_vec();
+        scope.extend(decls);
+        for c in node.children() {
+            if Some(c.id()) == self.exclude {
+                continue;
+            }
+            if c.is_element() {
+                self.element(c, &scope);
+            } else if c.is_text() {
+                escape_c14n_text(c.text().unwrap_or(""), &mut self.out);
+            } else if let Some(pi) = c.pi() {
+                self.out.push_str("<?");
+                self.out.push_str(pi.target);
+                if let Some(value) = pi.value {
+                    self.out.push(' ');
+                    self.out.push_str(value);
+                }
+                self.out.push_str("?>");
+            }
+        }
+
+        self.out.push_str("</");
+        self.out.push_str(qname);
+        self.out.push('>');
+    }
+}
+
+/// `node` 하위 트리의 exclusive c14n 결과. `exclude` 요소(enveloped Signature)는 빠진다
+pub fn canonicalize(
+    doc: &Document,
+    node: Node,
+    exclude: Option<NodeId>,
+    inclusive_prefixes: &[String],
+) -> String {
+    let mut c14n = ExcC14n {
+        doc,
+        inclusive: inclusive_prefixes,
+        exclude,
+        out: String::new(),
+    };
+    c14n.element(node, &[]);
+    c14n.out
+}
+
+/// `<ec:InclusiveNamespaces PrefixList="...">` (exc-c14n 변환/정규화 요소의 자식)
+fn inclusive_prefixes(algorithm_node: Node) -> Vec<String> {
+    child(algorithm_node, ALG_EXC_C14N, "InclusiveNamespaces")
+        .and_then(|n| n.attribute("PrefixList"))
+        .map(|list| {
+            list.split_ascii_whitespace()
+                .map(|p| {
+                    if p == "#default" {
+                        String::new()
+                    } else {
+                        p.to_string()
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// =============================================================================
+// XML 서명 검증
+// =============================================================================
+
+/// `element` 의 enveloped 서명 검증. 서명이 없으면 Ok(false), 있는데 틀리면 Err
+fn verify_enveloped_signature(
+    doc: &Document,
+    element: Node,
+    keys: &[RsaPublicKey],
+) -> AppResult<bool> {
+    let Some(signature) = child(element, NS_DSIG, "Signature") else {
+        return Ok(false);
+    };
+    let id = element
+        .attribute("ID")
+        .ok_or_else(|| reject("signed element has no ID"))?;
+    let same_id = doc
+        .descendants()
+        .filter(|n| n.is_element() && n.attribute("ID") == Some(id))
+        .count();
+    if same_id != 1 {
+        return Err(reject("duplicate ID"));
+    }
+
+    let signed_info =
+        child(signature, NS_DSIG, "SignedInfo").ok_or_else(|| reject("missing SignedInfo"))?;
+    let c14n_method = child(signed_info, NS_DSIG, "CanonicalizationMethod")
+        .filter(|n| n.attribute("Algorithm") == Some(ALG_EXC_C14N))
+        .ok_or_else(|| reject("unsupported canonicalization method"))?;
+    if child(signed_info, NS_DSIG, "SignatureMethod").and_then(|n| n.attribute("Algorithm"))
+        != Some(ALG_RSA_SHA256)
+    {
+        return Err(reject("unsupported signature method"));
+    }
+
+    let mut references = children(signed_info, NS_DSIG, "Reference");
+    let reference = match (references.next(), references.next()) {
+        (Some(r), None) => r,
+        _ => return Err(reject("expected exactly one Reference")),
+    };
+    if reference.attribute("URI") != Some(format!("#{}", id).as_str()) {
+        return Err(reject("Reference does not point to the signed element"));
+    }
+
+    let mut element_inclusive = Vec::new();
+    let mut has_exc_c14n = false;
+    if let Some(transforms) = child(reference, NS_DSIG, "Transforms") {
+        for transform in children(transforms, NS_DSIG, "Transform") {
+            match transform.attribute("Algorithm") {
+                Some(ALG_ENVELOPED_SIGNATURE) => {}
+                Some(ALG_EXC_C14N) => {
+                    has_exc_c14n = true;
+                    element_inclusive = inclusive_prefixes(transform);
+                }
+                _ => return Err(reject("unsupported transform")),
+            }
+        }
+    }
+    if !has_exc_c14n {
+        return Err(reject("unsupported canonicalization transform"));
+    }
+    if child(reference, NS_DSIG, "DigestMethod").and_then(|n| n.attribute("Algorithm"))
+        != Some(ALG_SHA256)
+    {
+        return Err(reject("unsupported digest method"));
+    }
+    let expected_digest = child(reference, NS_DSIG, "DigestValue")
+        .and_then(|n| decode_base64(&text_of(n)))
+        .ok_or_else(|| reject("invalid DigestValue"))?;
+
+    let canonical = canonicalize(doc, element, Some(signature.id()), &element_inclusive);
+    if Sha256::digest(canonical.as_bytes()).as_slice() != expected_digest.as_slice() {
+        return Err(reject("digest mismatch"));
+    }
+
+    let signature_value = child(signature, NS_DSIG, "SignatureValue")
+        .and_then(|n| decode_base64(&text_of(n)))
+        .ok_or_else(|| reject("invalid SignatureValue"))?;
+    let signature_value = Signature::try_from(signature_value.as_slice())
+        .map_err(|_| reject("invalid SignatureValue"))?;
+    let signed_info_c14n = canonicalize(doc, signed_info, None, &inclusive_prefixes(c14n_method));
+
+    let verified = keys.iter().any(|key| {
+        VerifyingKey::<Sha256>::new(key.clone())
+            .verify(signed_info_c14n.as_bytes(), &signature_value)
+            .is_ok()
+    });
+    if !verified {
+        return Err(reject("signature does not match the IdP certificate"));
+    }
+    Ok(true)
+}
+
+// =============================================================================
+// IdP 메타데이터
+// =============================================================================
+
+/// IdP 메타데이터에서 쓰는 값
+#[derive(Debug, Clone)]
+pub struct IdpMetadata {
+    pub entity_id: String,
+    /// HTTP-Redirect 바인딩 SingleSignOnService
+    pub sso_url: String,
+    signing_keys: Vec<RsaPublicKey>,
+}
+
+impl IdpMetadata {
+    /// 메타데이터 XML 파싱 (EntitiesDescriptor 묶음이면 IDPSSODescriptor 가 있는 첫 EntityDescriptor)
+    pub fn parse(xml: &str) -> AppResult<Self> {
+        let doc = Document::parse(xml).map_err(invalid_metadata)?;
+        let (entity, idp) = doc
+            .descendants()
+            .filter(|n| is(n, NS_METADATA, "EntityDescriptor"))
+            .find_map(|e| child(e, NS_METADATA, "IDPSSODescriptor").map(|idp| (e, idp)))
+            .ok_or_else(|| invalid_metadata("no IDPSSODescriptor"))?;
+
+        let entity_id = entity
+            .attribute("entityID")
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| invalid_metadata("missing entityID"))?
+            .trim()
+            .to_string();
+        let sso_url = children(idp, NS_METADATA, "SingleSignOnService")
+            .find(|s| s.attribute("Binding") == Some(BINDING_REDIRECT))
+            .and_then(|s| s.attribute("Location"))
+            .ok_or_else(|| invalid_metadata("no HTTP-Redirect SingleSignOnService"))?
+            .trim()
+            .to_string();
+
+        let mut signing_keys = Vec::new();
+        for key_descriptor in children(idp, NS_METADATA, "KeyDescriptor")
+            .filter(|k| matches!(k.attribute("use"), None | Some("signing")))
+        {
+            for cert in key_descriptor
+                .descendants()
+                .filter(|n| is(n, NS_DSIG, "X509Certificate"))
+            {
+                let der = decode_base64(&text_of(cert))
+                    .ok_or_else(|| invalid_metadata("invalid X509Certificate"))?;
+                let cert = Certificate::from_der(&der).map_err(invalid_metadata)?;
+                let spki = cert
+                    .tbs_certificate
+                    .subject_public_key_info
+                    .to_der()
+                    .map_err(invalid_metadata)?;
+                // RSA 외 키(EC 등)는 지원하지 않으므로 건너뛴다
+                if let Ok(key) = RsaPublicKey::from_public_key_der(&spki) {
+                    signing_keys.push(key);
+                }
+            }
+        }
+        if signing_keys.is_empty() {
+            return Err(invalid_metadata("no RSA signing certificate"));
+        }
+
+        Ok(Self {
+            entity_id,
+            sso_url,
+            signing_keys,
+        })
+    }
+}
+
+// =============================================================================
+// Service Provider
+// =============================================================================
+
+/// 검증을 통과한 assertion 에서 읽은 값
+#[derive(Debug, Clone)]
+pub struct SamlAssertion {
+    pub name_id: String,
+    /// Attribute Name 과 FriendlyName 양쪽 키로 보관
+    pub attributes: HashMap<String, Vec<String>>,
+}
+
+/// AuthnRequest ID (xs:ID — 숫자로 시작하면 안 됨)
+pub fn new_request_id() -> String {
+    let mut bytes = [0u8; 20];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!("_{}", hex::encode(bytes))
+}
+
+/// 이 서비스 (SP) — 모든 기관이 같은 entityID / ACS 를 쓴다
+#[derive(Debug, Clone)]
+pub struct SamlServiceProvider {
+    pub entity_id: String,
+    pub acs_url: String,
+}
+
+impl SamlServiceProvider {
+    /// HTTP-Redirect 바인딩 AuthnRequest URL (DEFLATE + base64 + URL 인코딩)
+    pub fn authn_request_url(
+        &self,
+        idp: &IdpMetadata,
+        request_id: &str,
+        relay_state: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<String> {
+        let request = format!(
+            r#"<samlp:AuthnRequest xmlns:samlp="{}" xmlns:saml="{}" ID="{}" Version="2.0" IssueInstant="{}" Destination="{}" AssertionConsumerServiceURL="{}" ProtocolBinding="{}"><saml:Issuer>{}</saml:Issuer><samlp:NameIDPolicy AllowCreate="true"/></samlp:AuthnRequest>"#,
+            NS_PROTOCOL,
+            NS_ASSERTION,
+            xml_escape(request_id),
+            now.format("%Y-%m-%dT%H:%M:%SZ"),
+            xml_escape(&idp.sso_url),
+            xml_escape(&self.acs_url),
+            BINDING_POST,
+            xml_escape(&self.entity_id),
+        );
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(request.as_bytes())
+            .map_err(|e| AppError::Internal(format!("SAML request deflate: {}", e)))?;
+        let deflated = encoder
+            .finish()
+            .map_err(|e| AppError::Internal(format!("SAML request deflate: {}", e)))?;
+
+        let separator = if idp.sso_url.contains('?') { '&' } else { '?' };
+        Ok(format!(
+            "{}{}SAMLRequest={}&RelayState={}",
+            idp.sso_url,
+            separator,
+            urlencoding::encode(&STANDARD.encode(deflated)),
+            urlencoding::encode(relay_state)
+        ))
+    }
+
+    /// IdP 등록용 SP 메타데이터
+    pub fn metadata_xml(&self) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<md:EntityDescriptor xmlns:md="{}" entityID="{}"><md:SPSSODescriptor AuthnRequestsSigned="false" WantAssertionsSigned="true" protocolSupportEnumeration="{}"><md:NameIDFormat>urn:oasis:names:tc:SAML:1.1:nameid-format:unspecified</md:NameIDFormat><md:AssertionConsumerService Binding="{}" Location="{}" index="0" isDefault="true"/></md:SPSSODescriptor></md:EntityDescriptor>
+"#,
+            NS_METADATA,
+            xml_escape(&self.entity_id),
+            NS_PROTOCOL,
+            BINDING_POST,
+            xml_escape(&self.acs_url),
+        )
+    }
+
+    /// ACS 로 POST 된 SAMLResponse 검증 → assertion 값.
+    ///
+    /// Response 또는 Assertion 중 하나 이상이 IdP 인증서로 서명돼 있어야 하며,
+    /// 발급자 / 수신자 / audience / 유효시간 / InResponseTo(= 우리가 보낸 요청) 를 모두 확인한다.
+    pub fn validate_response(
+        &self,
+        idp: &IdpMetadata,
+        saml_response: &str,
+        request_id: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<SamlAssertion> {
+        let xml = decode_base64(saml_response)
+            .and_then(|b| String::from_utf8(b).ok())
+            .ok_or_else(|| reject("invalid encoding"))?;
+        // DTD 는 기본 옵션에서 거부된다 (엔티티 확장 공격 차단)
+        let doc = Document::parse(&xml).map_err(|e| reject(format!("malformed XML: {}", e)))?;
+        let response = doc.root_element();
+        if !is(&response, NS_PROTOCOL, "Response") {
+            return Err(reject("not a SAML Response"));
+        }
+
+        if let Some(destination) = response.attribute("Destination") {
+            if destination != self.acs_url {
+                return Err(reject("Destination mismatch"));
+            }
+        }
+        if response.attribute("InResponseTo") != Some(request_id) {
+            return Err(reject("InResponseTo mismatch"));
+        }
+        let status = child(response, NS_PROTOCOL, "Status")
+            .and_then(|s| child(s, NS_PROTOCOL, "StatusCode"))
+            .and_then(|c| c.attribute("Value"))
+            .ok_or_else(|| reject("missing StatusCode"))?;
+        if status != STATUS_SUCCESS {
+            return Err(reject(format!("IdP returned {}", status)));
+        }
+        if child(response, NS_ASSERTION, "EncryptedAssertion").is_some() {
+            return Err(reject(
+                "encrypted assertions are not supported (disable assertion encryption at the IdP)",
+            ));
+        }
+
+        let mut assertions = children(response, NS_ASSERTION, "Assertion");
+        let assertion = match (assertions.next(), assertions.next()) {
+            (Some(a), None) => a,
+            _ => return Err(reject("expected exactly one Assertion")),
+        };
+
+        let response_signed = verify_enveloped_signature(&doc, response, &idp.signing_keys)?;
+        let assertion_signed = verify_enveloped_signature(&doc, assertion, &idp.signing_keys)?;
+        if !response_signed && !assertion_signed {
+            return Err(reject("response is not signed"));
+        }
+
+        if let Some(issuer) = child(response, NS_ASSERTION, "Issuer") {
+            if text_of(issuer) != idp.entity_id {
+                return Err(reject("Issuer mismatch"));
+            }
+        }
+        if child(assertion, NS_ASSERTION, "Issuer")
+            .map(text_of)
+            .as_deref()
+            != Some(idp.entity_id.as_str())
+        {
+            return Err(reject("Issuer mismatch"));
+        }
+
+        let skew = Duration::seconds(CLOCK_SKEW_SECS);
+        let subject =
+            child(assertion, NS_ASSERTION, "Subject").ok_or_else(|| reject("missing Subject"))?;
+        let name_id = child(subject, NS_ASSERTION, "NameID")
+            .map(text_of)
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| reject("missing NameID"))?;
+        let confirmed = children(subject, NS_ASSERTION, "SubjectConfirmation")
+            .filter(|c| c.attribute("Method") == Some(CM_BEARER))
+            .filter_map(|c| child(c, NS_ASSERTION, "SubjectConfirmationData"))
+            .any(|data| {
+                data.attribute("Recipient") == Some(self.acs_url.as_str())
+                    && data
+                        .attribute("NotOnOrAfter")
+                        .and_then(parse_instant)
+                        .is_some_and(|t| now < t + skew)
+                    && data
+                        .attribute("InResponseTo")
+                        .is_none_or(|v| v == request_id)
+            });
+        if !confirmed {
+            return Err(reject("no valid bearer SubjectConfirmation"));
+        }
+
+        let conditions = child(assertion, NS_ASSERTION, "Conditions")
+            .ok_or_else(|| reject("missing Conditions"))?;
+        if let Some(not_before) = conditions.attribute("NotBefore") {
+            let not_before =
+                parse_instant(not_before).ok_or_else(|| reject("invalid NotBefore"))?;
+            if now + skew < not_before {
+                return Err(reject("assertion not yet valid"));
+            }
+        }
+        if let Some(not_on_or_after) = conditions.attribute("NotOnOrAfter") {
+            let not_on_or_after =
+                parse_instant(not_on_or_after).ok_or_else(|| reject("invalid NotOnOrAfter"))?;
+            if now >= not_on_or_after + skew {
+                return Err(reject("assertion expired"));
+            }
+        }
+        let mut restrictions = children(conditions, NS_ASSERTION, "AudienceRestriction").peekable();
+        if restrictions.peek().is_none() {
+            return Err(reject("missing AudienceRestriction"));
+        }
+        for restriction in restrictions {
+            if !children(restriction, NS_ASSERTION, "Audience")
+                .any(|a| text_of(a) == self.entity_id)
+            {
+                return Err(reject("Audience mismatch"));
+            }
+        }
+
+        let mut attributes: HashMap<String, Vec<String>> = HashMap::new();
+        for statement in children(assertion, NS_ASSERTION, "AttributeStatement") {
+            for attribute in children(statement, NS_ASSERTION, "Attribute") {
+                let values: Vec<String> = children(attribute, NS_ASSERTION, "AttributeValue")
+                    .map(text_of)
+                    .filter(|v| !v.is_empty())
+                    .collect();
+                for key in [
+                    attribute.attribute("Name"),
+                    attribute.attribute("FriendlyName"),
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    attributes
+                        .entry(key.to_string())
+                        .or_default()
+                        .extend(values.iter().cloned());
+                }
+            }
+        }
+
+        Ok(SamlAssertion {
+            name_id,
+            attributes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::DeflateDecoder;
+    use rsa::pkcs1v15::SigningKey;
+    use rsa::pkcs8::DecodePrivateKey;
+    use rsa::signature::{SignatureEncoding, Signer};
+    use rsa::RsaPrivateKey;
+    use std::io::Read;
+
+    const TEST_KEY_PEM: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/saml-idp-test.key.pem"
+    ));
+    const TEST_CERT_PEM: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/saml-idp-test.cert.pem"
+    ));
+    const IDP_ENTITY: &str = "https://idp.example.ac.kr/saml";
+    const REQUEST_ID: &str = "_req1";
+
+    fn sp() -> SamlServiceProvider {
+        SamlServiceProvider {
+            entity_id: "https://api.example.com/auth/sso/saml/metadata".into(),
+            acs_url: "https://api.example.com/auth/sso/saml/acs".into(),
+        }
+    }
+
+    fn cert_base64() -> String {
+        TEST_CERT_PEM
+            .lines()
+            .filter(|l| !l.starts_with("-----"))
+            .collect()
+    }
+
+    fn metadata() -> IdpMetadata {
+        IdpMetadata::parse(&format!(
+            r#"<md:EntityDescriptor xmlns:md="{NS_METADATA}" xmlns:ds="{NS_DSIG}" entityID="{IDP_ENTITY}">
+  <md:IDPSSODescriptor protocolSupportEnumeration="{NS_PROTOCOL}">
+    <md:KeyDescriptor use="signing"><ds:KeyInfo><ds:X509Data><ds:X509Certificate>
+{}
+    </ds:X509Certificate></ds:X509Data></ds:KeyInfo></md:KeyDescriptor>
+    <md:SingleSignOnService Binding="{BINDING_POST}" Location="https://idp.example.ac.kr/post"/>
+    <md:SingleSignOnService Binding="{BINDING_REDIRECT}" Location="https://idp.example.ac.kr/sso"/>
+  </md:IDPSSODescriptor>
+</md:EntityDescriptor>"#,
+            cert_base64()
+        ))
+        .expect("metadata")
+    }
+
+    /// `<saml:Issuer>` 바로 뒤에 enveloped 서명 삽입 (IdP 역할)
+    fn sign(xml: &str, id: &str) -> String {
+        let doc = Document::parse(xml).expect("parse");
+        let target = doc
+            .descendants()
+            .find(|n| n.attribute("ID") == Some(id))
+            .expect("target");
+        let digest = STANDARD.encode(Sha256::digest(
+            canonicalize(&doc, target, None, &[]).as_bytes(),
+        ));
+        let signed_info = format!(
+            r##"<ds:SignedInfo xmlns:ds="{NS_DSIG}"><ds:CanonicalizationMethod Algorithm="{ALG_EXC_C14N}"></ds:CanonicalizationMethod><ds:SignatureMethod Algorithm="{ALG_RSA_SHA256}"></ds:SignatureMethod><ds:Reference URI="#{id}"><ds:Transforms><ds:Transform Algorithm="{ALG_ENVELOPED_SIGNATURE}"></ds:Transform><ds:Transform Algorithm="{ALG_EXC_C14N}"></ds:Transform></ds:Transforms><ds:DigestMethod Algorithm="{ALG_SHA256}"></ds:DigestMethod><ds:DigestValue>{digest}</ds:DigestValue></ds:Reference></ds:SignedInfo>"##
+        );
+        let key = RsaPrivateKey::from_pkcs8_pem(TEST_KEY_PEM).expect("key");
+        let signature = SigningKey::<Sha256>::new(key).sign(signed_info.as_bytes());
+        let signature_xml = format!(
+            r#"<ds:Signature xmlns:ds="{NS_DSIG}">{signed_info}<ds:SignatureValue>{}</ds:SignatureValue></ds:Signature>"#,
+            STANDARD.encode(signature.to_vec())
+        );
+
+        let issuer_end = format!("{IDP_ENTITY}</saml:Issuer>");
+        let at = xml[xml.find(&format!(r#"ID="{id}""#)).expect("id")..]
+            .find(&issuer_end)
+            .expect("issuer")
+            + xml.find(&format!(r#"ID="{id}""#)).expect("id")
+            + issuer_end.len();
+        format!("{}{}{}", &xml[..at], signature_xml, &xml[at..])
+    }
+
+    fn assertion(id: &str, audience: &str, not_on_or_after: &str) -> String {
+        format!(
+            r#"<saml:Assertion xmlns:saml="{NS_ASSERTION}" xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" ID="{id}" Version="2.0" IssueInstant="2026-10-19T00:00:00Z">
+  <saml:Issuer>{IDP_ENTITY}</saml:Issuer>
+  <saml:Subject>
+    <saml:NameID Format="urn:oasis:names:tc:SAML:1.1:nameid-format:unspecified">stu-2026001</saml:NameID>
+    <saml:SubjectConfirmation Method="{CM_BEARER}">
+      <saml:SubjectConfirmationData InResponseTo="{REQUEST_ID}" NotOnOrAfter="{not_on_or_after}" Recipient="https://api.example.com/auth/sso/saml/acs"/>
+    </saml:SubjectConfirmation>
+  </saml:Subject>
+  <saml:Conditions NotBefore="2026-10-19T00:00:00Z" NotOnOrAfter="{not_on_or_after}">
+    <saml:AudienceRestriction><saml:Audience>{audience}</saml:Audience></saml:AudienceRestriction>
+  </saml:Conditions>
+  <saml:AttributeStatement>
+    <saml:Attribute Name="urn:oid:0.9.2342.19200300.100.1.3" FriendlyName="mail">
+      <saml:AttributeValue xsi:type="xs:string">Kim.Student@Univ.ac.kr</saml:AttributeValue>
+    </saml:Attribute>
+    <saml:Attribute Name="displayName"><saml:AttributeValue>김학생 &amp; 동아리</saml:AttributeValue></saml:Attribute>
+  </saml:AttributeStatement>
+</saml:Assertion>"#
+        )
+    }
+
+    fn response(assertion: &str) -> String {
+        format!(
+            r#"<samlp:Response xmlns:samlp="{NS_PROTOCOL}" xmlns:saml="{NS_ASSERTION}" ID="_resp1" Version="2.0" IssueInstant="2026-10-19T00:00:00Z" Destination="https://api.example.com/auth/sso/saml/acs" InResponseTo="{REQUEST_ID}"><saml:Issuer>{IDP_ENTITY}</saml:Issuer><samlp:Status><samlp:StatusCode Value="{STATUS_SUCCESS}"/></samlp:Status>{assertion}</samlp:Response>"#
+        )
+    }
+
+    /// `<samlp:Extensions>` 안에 임의 내용을 넣은 Response (서명된 assertion 을 숨기는 XSW 용)
+    fn response_with_extensions(extensions: &str, assertion: &str) -> String {
+        response(assertion).replace(
+            "<samlp:Status>",
+            &format!("<samlp:Extensions>{extensions}</samlp:Extensions><samlp:Status>"),
+        )
+    }
+
+    /// 서명된 XML 에서 `<ds:Signature>` 요소만 잘라냄
+    fn signature_of(signed: &str) -> &str {
+        let start = signed.find("<ds:Signature ").expect("signature start");
+        let end = signed.find("</ds:Signature>").expect("signature end") + "</ds:Signature>".len();
+        &signed[start..end]
+    }
+
+    fn assert_rejected(xml: &str, reason: &str) {
+        match validate(xml) {
+            Err(AppError::Unauthorized(m)) => assert!(m.contains(reason), "{m}"),
+            other => panic!("expected rejection ({reason}), got {other:?}"),
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        parse_instant("2026-10-19T00:01:00Z").unwrap()
+    }
+
+    fn validate(xml: &str) -> AppResult<SamlAssertion> {
+        sp().validate_response(&metadata(), &STANDARD.encode(xml), REQUEST_ID, now())
+    }
+
+    fn valid_assertion() -> String {
+        sign(
+            &assertion("_a1", &sp().entity_id, "2026-10-19T00:05:00Z"),
+            "_a1",
+        )
+    }
+
+    #[test]
+    fn test_canonicalize_exclusive_namespaces_and_escaping() {
+        let xml = r#"<root xmlns="urn:d" xmlns:a="urn:a" xmlns:b="urn:b" xmlns:unused="urn:u"><a:child z="2" b:attr="1" y="&amp;&lt;&quot;&#9;"><!-- note --><inner>t&gt;<empty/></inner></a:child></root>"#;
+        let doc = Document::parse(xml).unwrap();
+        let child = doc.root_element().first_element_child().unwrap();
+
+        assert_eq!(
+            canonicalize(&doc, child, None, &[]),
+            r#"<a:child xmlns:a="urn:a" xmlns:b="urn:b" y="&amp;&lt;&quot;&#x9;" z="2" b:attr="1"><inner xmlns="urn:d">t&gt;<empty></empty></inner></a:child>"#
+        );
+        // InclusiveNamespaces "#default" — 기본 namespace 를 apex 에 선언, 하위에서 재선언 안 함
+        assert_eq!(
+            canonicalize(&doc, child, None, &[String::new()]),
+            r#"<a:child xmlns="urn:d" xmlns:a="urn:a" xmlns:b="urn:b" y="&amp;&lt;&quot;&#x9;" z="2" b:attr="1"><inner>t&gt;<empty></empty></inner></a:child>"#
+        );
+    }
+
+    #[test]
+    fn test_metadata_parse() {
+        let idp = metadata();
+        assert_eq!(idp.entity_id, IDP_ENTITY);
+        assert_eq!(idp.sso_url, "https://idp.example.ac.kr/sso");
+        assert_eq!(idp.signing_keys.len(), 1);
+
+        let err = IdpMetadata::parse(&format!(
+            r#"<md:EntityDescriptor xmlns:md="{NS_METADATA}" entityID="x"><md:IDPSSODescriptor><md:SingleSignOnService Binding="{BINDING_REDIRECT}" Location="https://x"/></md:IDPSSODescriptor></md:EntityDescriptor>"#
+        ))
+        .unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(_)));
+    }
+
+    #[test]
+    fn test_authn_request_url_roundtrip() {
+        let url = sp()
+            .authn_request_url(&metadata(), "_abc", "relay-1", now())
+            .unwrap();
+        assert!(url.starts_with("https://idp.example.ac.kr/sso?SAMLRequest="));
+        assert!(url.ends_with("&RelayState=relay-1"));
+
+        let encoded = url
+            .split("SAMLRequest=")
+            .nth(1)
+            .unwrap()
+            .split('&')
+            .next()
+            .unwrap();
+        let deflated = STANDARD
+            .decode(urlencoding::decode(encoded).unwrap().as_bytes())
+            .unwrap();
+        let mut xml = String::new();
+        DeflateDecoder::new(deflated.as_slice())
+            .read_to_string(&mut xml)
+            .unwrap();
+        assert!(xml.contains(r#"ID="_abc""#));
+        assert!(xml.contains(
+            r#"AssertionConsumerServiceURL="https://api.example.com/auth/sso/saml/acs""#
+        ));
+        assert!(new_request_id().starts_with('_'));
+    }
+
+    #[test]
+    fn test_validate_signed_assertion() {
+        let res = validate(&response(&valid_assertion())).expect("valid");
+        assert_eq!(res.name_id, "stu-2026001");
+        assert_eq!(res.attributes["mail"], vec!["Kim.Student@Univ.ac.kr"]);
+        assert_eq!(
+            res.attributes["urn:oid:0.9.2342.19200300.100.1.3"],
+            vec!["Kim.Student@Univ.ac.kr"]
+        );
+        assert_eq!(res.attributes["displayName"], vec!["김학생 & 동아리"]);
+    }
+
+    #[test]
+    fn test_validate_signed_response() {
+        let unsigned = assertion("_a1", &sp().entity_id, "2026-10-19T00:05:00Z");
+        let xml = sign(&response(&unsigned), "_resp1");
+        assert!(validate(&xml).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_tampering() {
+        // 서명 후 속성 값 변경
+        let tampered = valid_assertion().replace("Kim.Student@", "Admin@");
+        assert!(validate(&response(&tampered)).is_err());
+
+        // 서명 없음
+        let unsigned = assertion("_a1", &sp().entity_id, "2026-10-19T00:05:00Z");
+        assert!(validate(&response(&unsigned)).is_err());
+
+        // 서명된 assertion 옆에 위조 assertion 추가 (XSW)
+        let forged = assertion("_a2", &sp().entity_id, "2026-10-19T00:05:00Z");
+        assert!(validate(&response(&format!("{}{}", valid_assertion(), forged))).is_err());
+
+        // 다른 SP 용 / 만료된 assertion (서명은 유효)
+        let other = sign(
+            &assertion("_a1", "https://other.example", "2026-10-19T00:05:00Z"),
+            "_a1",
+        );
+        assert!(validate(&response(&other)).is_err());
+        let expired = sign(
+            &assertion("_a1", &sp().entity_id, "2026-10-18T23:50:00Z"),
+            "_a1",
+        );
+        assert!(validate(&response(&expired)).is_err());
+
+        // 다른 요청에 대한 응답
+        let res = sp().validate_response(
+            &metadata(),
+            &STANDARD.encode(response(&valid_assertion())),
+            "_other",
+            now(),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_rejects_signature_wrapping() {
+        let signed = valid_assertion();
+        let evil = assertion("_evil", &sp().entity_id, "2026-10-19T00:05:00Z")
+            .replace("stu-2026001", "admin");
+        let issuer_end = format!("{IDP_ENTITY}</saml:Issuer>");
+
+        // 위조 assertion 에 원본 서명을 복사 + 원본은 Extensions 에 숨김 → Reference 불일치
+        let evil_with_copied_sig = evil.replacen(
+            &issuer_end,
+            &format!("{issuer_end}{}", signature_of(&signed)),
+            1,
+        );
+        assert_rejected(
+            &response_with_extensions(&signed, &evil_with_copied_sig),
+            "Reference does not point",
+        );
+
+        // 위조 assertion 이 원본과 같은 ID 를 쓰는 경우 → 중복 ID
+        let evil_same_id = evil_with_copied_sig.replace(r#"ID="_evil""#, r#"ID="_a1""#);
+        assert_rejected(
+            &response_with_extensions(&signed, &evil_same_id),
+            "duplicate ID",
+        );
+
+        // 원본 서명된 assertion 을 위조 assertion 안쪽(Subject 옆)에 중첩
+        let evil_nesting = evil.replacen("<saml:Subject>", &format!("{signed}<saml:Subject>"), 1);
+        assert_rejected(&response(&evil_nesting), "not signed");
+    }
+
+    #[test]
+    fn test_rejects_unsigned_response_with_detached_signed_assertion() {
+        let signed = valid_assertion();
+
+        // 서명된 assertion 은 Extensions 에만 있고, 실제 처리 대상은 서명 없는 assertion
+        let unsigned = assertion("_a2", &sp().entity_id, "2026-10-19T00:05:00Z")
+            .replace("stu-2026001", "admin");
+        assert_rejected(&response_with_extensions(&signed, &unsigned), "not signed");
+
+        // Response 직계 assertion 이 아예 없으면 거부
+        assert_rejected(
+            &response_with_extensions(&signed, ""),
+            "exactly one Assertion",
+        );
+    }
+
+    #[test]
+    fn test_rejects_multiple_assertions_even_if_each_is_signed() {
+        let second = sign(
+            &assertion("_a2", &sp().entity_id, "2026-10-19T00:05:00Z")
+                .replace("stu-2026001", "admin"),
+            "_a2",
+        );
+        assert_rejected(
+            &response(&format!("{}{}", valid_assertion(), second)),
+            "exactly one Assertion",
+        );
+    }
+
+    #[test]
+    fn test_name_id_comment_injection_does_not_truncate() {
+        // IdP 가 서명한 NameID 에 주석을 끼워 넣어도 (c14n 이 주석을 제외해 서명은 유효)
+        // 첫 텍스트 노드만 읽어 다른 사용자로 잘리지 않아야 한다
+        let signed = sign(
+            &assertion("_a1", &sp().entity_id, "2026-10-19T00:05:00Z")
+                .replace("stu-2026001", "victim@univ.ac.kr.evil.example"),
+            "_a1",
+        );
+        let injected = signed.replace(
+            "victim@univ.ac.kr.evil.example",
+            "victim@univ.ac.kr<!---->.evil.example",
+        );
+        let res = validate(&response(&injected)).expect("comments are outside the signature");
+        assert_eq!(res.name_id, "victim@univ.ac.kr.evil.example");
+    }
+}
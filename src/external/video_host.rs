@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::config::Config;
+use crate::error::{AppError, AppResult};
+use crate::external::hls::HlsHost;
+use crate::external::vimeo::VimeoClient;
+use crate::types::VideoHostKind;
+
+/// 호스트에서 가져온 영상 메타데이터
+#[derive(Debug, Clone)]
+pub struct VideoHostMeta {
+    pub duration: i32,
+    pub thumbnail_url: Option<String>,
+    /// 호스트 측 제목/설명 (Vimeo 만 제공)
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+/// 재생 요청자 — 서명 URL 바인딩 대상
+#[derive(Debug, Clone)]
+pub struct PlaybackViewer {
+    pub user_id: i64,
+    pub session_id: String,
+}
+
+/// 재생 정보 (Vimeo: 임베드 URL, HLS: 서명된 master 재생목록 URL)
+#[derive(Debug, Clone)]
+pub struct VideoPlayback {
+    pub url: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// 비디오 호스트 추상화
+///
+/// `source` 는 호스트별 원본 식별자 (vimeo: `video_url_vimeo`, hls: `video_hls_path`).
+/// 엔타이틀먼트 확인은 호출 측 책임.
+#[async_trait]
+pub trait VideoHost: Send + Sync {
+    fn kind(&self) -> VideoHostKind;
+
+    /// 원본 확인 + 메타데이터 조회. `Ok(None)` = 조회 불가 (설정 미비 등, 생성은 진행)
+    async fn fetch_meta(&self, source: &str) -> AppResult<Option<VideoHostMeta>>;
+
+    /// 재생 URL 발급
+    fn playback(
+        &self,
+        video_id: i64,
+        source: &str,
+        viewer: &PlaybackViewer,
+    ) -> AppResult<VideoPlayback>;
+}
+
+/// Vimeo 호스트 (VIMEO_ACCESS_TOKEN 미설정 시 메타 조회만 skip)
+pub struct VimeoHost {
+    client: Option<VimeoClient>,
+}
+
+impl VimeoHost {
+    pub fn new(access_token: Option<String>) -> AppResult<Self> {
+        let client = match access_token {
+            Some(token) if !token.is_empty() => Some(VimeoClient::new(token)?),
+            _ => None,
+        };
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl VideoHost for VimeoHost {
+    fn kind(&self) -> VideoHostKind {
+        VideoHostKind::Vimeo
+    }
+
+    async fn fetch_meta(&self, source: &str) -> AppResult<Option<VideoHostMeta>> {
+        let Some(client) = &self.client else {
+            tracing::warn!("Vimeo access token not configured, skipping metadata sync");
+            return Ok(None);
+        };
+        let Some(vimeo_video_id) = VimeoClient::extract_video_id(source) else {
+            tracing::warn!("Could not extract Vimeo video ID from URL: {}", source);
+            return Ok(None);
+        };
+
+        let meta = client.get_video_meta(&vimeo_video_id).await?;
+        Ok(Some(VideoHostMeta {
+            duration: meta.duration,
+            thumbnail_url: meta.thumbnail_url,
+            title: Some(meta.name),
+            description: meta.description,
+        }))
+    }
+
+    fn playback(
+        &self,
+        _video_id: i64,
+        source: &str,
+        _viewer: &PlaybackViewer,
+    ) -> AppResult<VideoPlayback> {
+        // Vimeo 는 플레이어 측 도메인 제한으로 보호 — URL 그대로
+        Ok(VideoPlayback {
+            url: source.to_string(),
+            expires_at: None,
+        })
+    }
+}
+
+/// 호스트 레지스트리 (AppState 싱글톤)
+pub struct VideoHosts {
+    vimeo: VimeoHost,
+    hls: Option<HlsHost>,
+}
+
+impl VideoHosts {
+    pub fn from_config(cfg: &Config) -> AppResult<Self> {
+        let vimeo = VimeoHost::new(cfg.vimeo_access_token.clone())?;
+
+        let hls = match &cfg.hls_root_dir {
+            Some(root) => {
+                // blind index 키와 용도 분리 — HMAC(hmac_key, "hls-url-signing")
+                let mut mac = Hmac::<Sha256>::new_from_slice(&cfg.hmac_key)
+                    .map_err(|e| AppError::Internal(format!("hls signing key: {e}")))?;
+                mac.update(b"hls-url-signing");
+                let signing_key: [u8; 32] = mac.finalize().into_bytes().into();
+                Some(HlsHost::new(
+                    root.into(),
+                    signing_key,
+                    cfg.hls_url_ttl_sec.max(30),
+                ))
+            }
+            None => None,
+        };
+
+        Ok(Self { vimeo, hls })
+    }
+
+    pub fn get(&self, kind: VideoHostKind) -> AppResult<&dyn VideoHost> {
+        match kind {
+            VideoHostKind::Vimeo => Ok(&self.vimeo),
+            VideoHostKind::Hls => Ok(self.hls()?),
+        }
+    }
+
+    /// 자체 HLS 호스트 (HLS_ROOT_DIR 미설정 시 503)
+    pub fn hls(&self) -> AppResult<&HlsHost> {
+        self.hls
+            .as_ref()
+            .ok_or_else(|| AppError::ServiceUnavailable("HLS host not configured".into()))
+    }
+}
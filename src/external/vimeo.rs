@@ -18,6 +18,32 @@ pub struct VimeoVideoMeta {
     pub description: Option<String>,
     pub duration: i32,
     pub thumbnail_url: Option<String>,
+    /// privacy.view (anybody | unlisted | disable | nobody ...) — "nobody" 는 비공개
+    pub privacy_view: Option<String>,
+}
+
+impl VimeoVideoMeta {
+    /// 소유자 외 재생 불가 (privacy.view = nobody)
+    pub fn is_private(&self) -> bool {
+        self.privacy_view.as_deref() == Some("nobody")
+    }
+}
+
+/// 429 대기 상한 (초) — Retry-After 가 더 길면 재시도하지 않고 TooManyRequests
+const MAX_RETRY_AFTER_SEC: u64 = 300;
+
+/// API 1회 조회 결과 (404/비공개/레이트리밋을 에러와 구분)
+#[derive(Debug, Clone)]
+enum VimeoMetaFetch {
+    Found(VimeoVideoMeta),
+    /// 404 — Vimeo 에서 삭제됨
+    NotFound,
+    /// 403 — 토큰으로 접근 불가 (비공개 전환/권한 회수)
+    Private,
+    /// 429 — `retry_after_sec` 후 재시도
+    RateLimited {
+        retry_after_sec: u64,
+    },
 }
 
 /// Vimeo API 응답 구조
@@ -27,6 +53,12 @@ struct VimeoVideoResponse {
     description: Option<String>,
     duration: i32,
     pictures: Option<VimeoPictures>,
+    privacy: Option<VimeoPrivacy>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VimeoPrivacy {
+    view: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -87,8 +119,33 @@ impl VimeoClient {
         None
     }
 
-    /// Vimeo API에서 영상 메타데이터 조회
+    /// Vimeo API에서 영상 메타데이터 조회 (429 는 Retry-After 만큼 기다려 1회 재시도)
+    ///
+    /// 404 → `NotFound`, 403 → `Forbidden`, 재시도 후에도 429 → `TooManyRequests`,
+    /// 그 외 실패 → `External`. 동기화 job 은 이 구분으로 not_found / private / error 를 기록한다.
     pub async fn get_video_meta(&self, video_id: &str) -> AppResult<VimeoVideoMeta> {
+        let mut fetched = self.fetch_video_meta(video_id).await?;
+        if let VimeoMetaFetch::RateLimited { retry_after_sec } = fetched {
+            if retry_after_sec <= MAX_RETRY_AFTER_SEC {
+                tracing::info!(retry_after_sec, "vimeo rate limited, waiting");
+                tokio::time::sleep(std::time::Duration::from_secs(retry_after_sec)).await;
+                fetched = self.fetch_video_meta(video_id).await?;
+            }
+        }
+        match fetched {
+            VimeoMetaFetch::Found(meta) => Ok(meta),
+            VimeoMetaFetch::NotFound => Err(AppError::NotFound),
+            VimeoMetaFetch::Private => Err(AppError::Forbidden(
+                "Vimeo video is private or not accessible".into(),
+            )),
+            VimeoMetaFetch::RateLimited { .. } => {
+                Err(AppError::TooManyRequests("Vimeo API rate limited".into()))
+            }
+        }
+    }
+
+    /// 영상 메타데이터 1회 조회 — 404/403/429 는 `VimeoMetaFetch` 로 구분해 반환
+    async fn fetch_video_meta(&self, video_id: &str) -> AppResult<VimeoMetaFetch> {
         let url = format!("{}/videos/{}", VIMEO_API_BASE, video_id);
 
         let response = self
@@ -100,13 +157,26 @@ impl VimeoClient {
             .await
             .map_err(|e| AppError::External(format!("Vimeo API request failed: {}", e)))?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(AppError::External(format!(
-                "Vimeo API error: {} - {}",
-                status, body
-            )));
+        match response.status() {
+            reqwest::StatusCode::NOT_FOUND => return Ok(VimeoMetaFetch::NotFound),
+            reqwest::StatusCode::FORBIDDEN => return Ok(VimeoMetaFetch::Private),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                let retry_after_sec = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .unwrap_or(60);
+                return Ok(VimeoMetaFetch::RateLimited { retry_after_sec });
+            }
+            status if !status.is_success() => {
+                let body = response.text().await.unwrap_or_default();
+                return Err(AppError::External(format!(
+                    "Vimeo API error: {} - {}",
+                    status, body
+                )));
+            }
+            _ => {}
         }
 
         let data: VimeoVideoResponse = response
@@ -123,12 +193,13 @@ impl VimeoClient {
                 .map(|s| s.link)
         });
 
-        Ok(VimeoVideoMeta {
+        Ok(VimeoMetaFetch::Found(VimeoVideoMeta {
             name: data.name,
             description: data.description,
             duration: data.duration,
             thumbnail_url,
-        })
+            privacy_view: data.privacy.and_then(|p| p.view),
+        }))
     }
 
     /// Vimeo에 업로드 티켓 생성 (tus resumable upload)
@@ -185,6 +256,112 @@ impl VimeoClient {
 
         Ok((data.uri, video_id, data.upload.upload_link))
     }
+
+    /// 텍스트 트랙(자막) 업로드 — 트랙 생성 → VTT 본문 PUT → 활성화, 트랙 URI 반환
+    ///
+    /// `language` 는 BCP-47 코드 (예: "ko", "zh-CN")
+    pub async fn upload_text_track(
+        &self,
+        video_id: &str,
+        language: &str,
+        name: &str,
+        vtt: &str,
+    ) -> AppResult<String> {
+        let url = format!("{}/videos/{}/texttracks", VIMEO_API_BASE, video_id);
+        let body = serde_json::json!({
+            "type": "subtitles",
+            "language": language,
+            "name": name
+        });
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("bearer {}", self.access_token))
+            .header("Accept", "application/vnd.vimeo.*+json;version=3.4")
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| AppError::External(format!("Vimeo texttrack request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::External(format!(
+                "Vimeo texttrack error: {} - {}",
+                status, body
+            )));
+        }
+
+        let track: VimeoTextTrackResponse = response.json().await.map_err(|e| {
+            AppError::External(format!("Vimeo texttrack response parse error: {}", e))
+        })?;
+
+        // 업로드 링크에는 인증 헤더 불필요
+        let response = self
+            .client
+            .put(&track.link)
+            .header("Content-Type", "text/vtt")
+            .body(vtt.to_string())
+            .send()
+            .await
+            .map_err(|e| AppError::External(format!("Vimeo texttrack upload failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::External(format!(
+                "Vimeo texttrack upload error: {}",
+                response.status()
+            )));
+        }
+
+        let response = self
+            .client
+            .patch(format!("{}{}", VIMEO_API_BASE, track.uri))
+            .header("Authorization", format!("bearer {}", self.access_token))
+            .header("Accept", "application/vnd.vimeo.*+json;version=3.4")
+            .json(&serde_json::json!({ "active": true }))
+            .send()
+            .await
+            .map_err(|e| AppError::External(format!("Vimeo texttrack activate failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::External(format!(
+                "Vimeo texttrack activate error: {}",
+                response.status()
+            )));
+        }
+
+        Ok(track.uri)
+    }
+
+    /// 텍스트 트랙 삭제 (재push 전 기존 트랙 교체용). 이미 삭제된 트랙(404)은 성공 처리.
+    pub async fn delete_text_track(&self, track_uri: &str) -> AppResult<()> {
+        let response = self
+            .client
+            .delete(format!("{}{}", VIMEO_API_BASE, track_uri))
+            .header("Authorization", format!("bearer {}", self.access_token))
+            .header("Accept", "application/vnd.vimeo.*+json;version=3.4")
+            .send()
+            .await
+            .map_err(|e| AppError::External(format!("Vimeo texttrack delete failed: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() && status != reqwest::StatusCode::NOT_FOUND {
+            return Err(AppError::External(format!(
+                "Vimeo texttrack delete error: {}",
+                status
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Vimeo 텍스트 트랙 생성 응답
+#[derive(Debug, Deserialize)]
+struct VimeoTextTrackResponse {
+    uri: String,
+    link: String,
 }
 
 /// Vimeo 업로드 티켓 응답 구조
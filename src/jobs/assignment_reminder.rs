@@ -0,0 +1,93 @@
+//! 과제 마감 리마인더.
+//!
+//! 마감이 `hours_before` 시간 안으로 다가온 과제의 대상 학습자 중 아직 완료 기록이 없는
+//! 학습자에게 메일을 (과제, 학습자) 당 1회 보낸다. 발송 전 `assignment_reminder` 행을 먼저
+//! 선점해 여러 인스턴스가 떠 있어도 중복 발송되지 않는다. 완료 기록은 학습자가 과제를
+//! 조회할 때 고정되므로, 발송 직전에 진행을 다시 계산해 이미 끝낸 학습자는 건너뛴다.
+
+use chrono::{Duration as ChronoDuration, Utc};
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::api::assignment::{repo, service::is_learner_done};
+use crate::crypto::CryptoService;
+use crate::error::AppResult;
+use crate::external::email::{send_templated, EmailTemplate};
+use crate::state::AppState;
+
+/// 한 tick 에 처리할 최대 건수
+const MAX_PER_TICK: i64 = 500;
+
+/// 리마인더 job 을 백그라운드 task 로 띄운다. `interval_sec <= 0` 또는 이메일 미설정이면 비활성.
+pub fn spawn(state: AppState, interval_sec: i64, hours_before: i64) {
+    if interval_sec <= 0 {
+        tracing::info!("assignment reminder disabled (ASSIGNMENT_REMINDER_INTERVAL_SEC <= 0)");
+        return;
+    }
+    if state.email.is_none() {
+        tracing::info!("assignment reminder disabled (email service not configured)");
+        return;
+    }
+    let period = Duration::from_secs(interval_sec as u64);
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match run_once(&state, hours_before).await {
+                Ok(0) => {}
+                Ok(sent) => tracing::info!(sent, "assignment reminder: emails sent"),
+                Err(e) => tracing::warn!(error = %e, "assignment reminder run failed"),
+            }
+        }
+    });
+}
+
+/// 대상 (과제, 학습자) 를 최대 `MAX_PER_TICK` 건 처리하고 발송 건수를 반환
+pub async fn run_once(st: &AppState, hours_before: i64) -> AppResult<usize> {
+    let Some(sender) = st.email.as_ref() else {
+        return Ok(0);
+    };
+    let now = Utc::now();
+    let candidates = repo::find_reminder_candidates(
+        &st.db,
+        now,
+        now + ChronoDuration::hours(hours_before),
+        MAX_PER_TICK,
+    )
+    .await?;
+
+    let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+    let assignment_url = format!("{}/assignments", st.cfg.frontend_url.trim_end_matches('/'));
+    let mut sent = 0;
+    for c in candidates {
+        if !repo::claim_reminder(&st.db, c.assignment_id, c.user_id).await? {
+            continue;
+        }
+        if is_learner_done(st, c.assignment_id, c.user_id).await? {
+            continue;
+        }
+        let email = crypto.decrypt(&c.user_email_enc, "users.user_email")?;
+        let result = send_templated(
+            sender.as_ref(),
+            &email,
+            EmailTemplate::AssignmentReminder {
+                assignment_title: c.assignment_title,
+                due_at: c.assignment_due_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                assignment_url: assignment_url.clone(),
+            },
+        )
+        .await;
+        // 발송 실패는 재시도하지 않음 (선점 행 유지) — 한 명 실패로 나머지를 막지 않도록 로그만
+        match result {
+            Ok(()) => sent += 1,
+            Err(e) => tracing::warn!(
+                error = %e,
+                assignment_id = c.assignment_id,
+                user_id = c.user_id,
+                "assignment reminder email failed"
+            ),
+        }
+    }
+    Ok(sent)
+}
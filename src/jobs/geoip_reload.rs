@@ -0,0 +1,35 @@
+//! 오프라인 GeoIP mmdb 재로딩.
+//!
+//! geoipupdate 등이 `GEOIP_CITY_DB_PATH` / `GEOIP_ASN_DB_PATH` 파일을 제자리 교체하면
+//! 주기적으로 mtime 을 비교해 새 리더로 바꾼다. 파일 읽기는 블로킹이므로 spawn_blocking 에서
+//! 실행하고, 새 파일이 깨져 있으면 기존 리더를 유지한다 (조회 중단 없음).
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::external::ipgeo::IpGeoClient;
+
+/// 재로딩 job 을 백그라운드 task 로 띄운다. `interval_sec <= 0` 이면 비활성.
+pub fn spawn(ipgeo: Arc<IpGeoClient>, interval_sec: i64) {
+    if interval_sec <= 0 {
+        tracing::info!("geoip reload disabled (GEOIP_RELOAD_INTERVAL_SEC <= 0)");
+        return;
+    }
+    let period = Duration::from_secs(interval_sec as u64);
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // 첫 tick 은 즉시 완료 — 부팅 시 이미 로드했으므로 건너뜀
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let client = ipgeo.clone();
+            match tokio::task::spawn_blocking(move || client.reload_if_changed()).await {
+                Ok(0) => {}
+                Ok(reloaded) => tracing::info!(reloaded, "geoip reload: mmdb replaced"),
+                Err(e) => tracing::warn!(error = %e, "geoip reload task failed"),
+            }
+        }
+    });
+}
@@ -0,0 +1,60 @@
+//! 성적부 내보내기 생성.
+//!
+//! 대상 인원이 많아 요청 시 즉시 생성하지 않은 `gradebook_export` pending 작업을 1건씩
+//! 선점(SKIP LOCKED)해 파일을 만든다. 인스턴스가 죽어 running 으로 멈춘 작업은
+//! `STALE_AFTER_SEC` 후 다시 선점된다. 매 tick 만료된 파일 본문도 비운다.
+
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::api::gradebook::{repo, service::run_export};
+use crate::error::AppResult;
+use crate::state::AppState;
+
+/// 한 tick 에 처리할 최대 작업 수
+const MAX_PER_TICK: usize = 5;
+/// running 상태로 이 시간 이상 멈춘 작업은 재시도
+const STALE_AFTER_SEC: i64 = 900;
+
+/// 내보내기 job 을 백그라운드 task 로 띄운다. `interval_sec <= 0` 이면 비활성.
+pub fn spawn(state: AppState, interval_sec: i64) {
+    if interval_sec <= 0 {
+        tracing::info!("gradebook export worker disabled (GRADEBOOK_EXPORT_INTERVAL_SEC <= 0)");
+        return;
+    }
+    let period = Duration::from_secs(interval_sec as u64);
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match run_pending(&state).await {
+                Ok((0, 0)) => {}
+                Ok((processed, purged)) => tracing::info!(
+                    processed,
+                    purged,
+                    "gradebook export worker: exports processed"
+                ),
+                Err(e) => tracing::warn!(error = %e, "gradebook export worker run failed"),
+            }
+        }
+    });
+}
+
+/// pending 작업을 최대 `MAX_PER_TICK` 건 처리 — (처리 건수, 만료로 비운 파일 수)
+pub async fn run_pending(st: &AppState) -> AppResult<(usize, u64)> {
+    let purged = repo::purge_expired_files(&st.db).await?;
+
+    let mut processed = 0;
+    for _ in 0..MAX_PER_TICK {
+        let Some(export_id) = repo::claim_next_export(&st.db, STALE_AFTER_SEC).await? else {
+            break;
+        };
+        if let Err(e) = run_export(st, export_id).await {
+            tracing::warn!(error = %e, export_id, "Gradebook export failed");
+            repo::finish_export_failed(&st.db, export_id, &e.to_string()).await?;
+        }
+        processed += 1;
+    }
+    Ok((processed, purged))
+}
@@ -0,0 +1,90 @@
+//! 라이브 수업 시작 전 리마인더.
+//!
+//! 시작이 `minutes_before` 분 안으로 다가온 예정 회차의 확정(enrolled) 학습자에게 메일을
+//! (회차, 학습자) 당 1회 보낸다. 발송 전 `live_reminder` 행을 먼저 선점해 여러 인스턴스가
+//! 떠 있어도 중복 발송되지 않는다. 일정이 바뀌면 선점 기록이 지워져 새 시각 기준으로 다시 보낸다.
+
+use chrono::{Duration as ChronoDuration, Utc};
+use chrono_tz::Tz;
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::api::live::{repo, service::local_display};
+use crate::crypto::CryptoService;
+use crate::error::AppResult;
+use crate::external::email::{send_templated, EmailTemplate};
+use crate::state::AppState;
+
+/// 한 tick 에 처리할 최대 건수
+const MAX_PER_TICK: i64 = 500;
+
+/// 리마인더 job 을 백그라운드 task 로 띄운다. `interval_sec <= 0` 또는 이메일 미설정이면 비활성.
+pub fn spawn(state: AppState, interval_sec: i64, minutes_before: i64) {
+    if interval_sec <= 0 {
+        tracing::info!("live reminder disabled (LIVE_REMINDER_INTERVAL_SEC <= 0)");
+        return;
+    }
+    if state.email.is_none() {
+        tracing::info!("live reminder disabled (email service not configured)");
+        return;
+    }
+    let period = Duration::from_secs(interval_sec as u64);
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match run_once(&state, minutes_before).await {
+                Ok(0) => {}
+                Ok(sent) => tracing::info!(sent, "live reminder: emails sent"),
+                Err(e) => tracing::warn!(error = %e, "live reminder run failed"),
+            }
+        }
+    });
+}
+
+/// 대상 (회차, 학습자) 를 최대 `MAX_PER_TICK` 건 처리하고 발송 건수를 반환
+pub async fn run_once(st: &AppState, minutes_before: i64) -> AppResult<usize> {
+    let Some(sender) = st.email.as_ref() else {
+        return Ok(0);
+    };
+    let now = Utc::now();
+    let candidates = repo::find_reminder_candidates(
+        &st.db,
+        now,
+        now + ChronoDuration::minutes(minutes_before),
+        MAX_PER_TICK,
+    )
+    .await?;
+
+    let crypto = CryptoService::new(&st.cfg.encryption_ring, &st.cfg.hmac_key);
+    let mut sent = 0;
+    for c in candidates {
+        if !repo::claim_reminder(&st.db, c.live_session_id, c.user_id).await? {
+            continue;
+        }
+        let email = crypto.decrypt(&c.user_email_enc, "users.user_email")?;
+        let tz = c.session_timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+        let result = send_templated(
+            sender.as_ref(),
+            &email,
+            EmailTemplate::LiveSessionReminder {
+                session_title: c.session_title,
+                start_at: local_display(c.session_start_at, tz),
+                meeting_url: c.meeting_url,
+            },
+        )
+        .await;
+        // 발송 실패는 재시도하지 않음 (선점 행 유지) — 한 명 실패로 나머지를 막지 않도록 로그만
+        match result {
+            Ok(()) => sent += 1,
+            Err(e) => tracing::warn!(
+                error = %e,
+                live_session_id = c.live_session_id,
+                user_id = c.user_id,
+                "live reminder email failed"
+            ),
+        }
+    }
+    Ok(sent)
+}
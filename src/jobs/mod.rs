@@ -1,3 +1,9 @@
 //! 백그라운드 작업(주기적 task) 모음.
 
+pub mod assignment_reminder;
+pub mod geoip_reload;
+pub mod gradebook_export;
+pub mod live_reminder;
+pub mod publish_scheduler;
 pub mod session_reaper;
+pub mod vimeo_sync;
@@ -0,0 +1,201 @@
+//! 예약 공개/비공개 실행.
+//!
+//! `content_publish_schedule` 의 pending 중 실행 시각이 지난 건을 1건씩 잠가(SKIP LOCKED)
+//! 대상 state 를 전환한다. 변경 로그(admin_*_log, action = publish/unpublish)와 감사 로그는
+//! 예약을 만든 관리자 명의로 남기되 `source = "scheduler"` 로 수동 변경과 구분한다.
+//! 감사 로그도 실행 트랜잭션 안에서 쓴다 (tick 실패/재시도 시 고아·중복 행 방지).
+//! video/lesson/study 공개는 수동 공개와 같은 번역 커버리지 정책을 따르며, 미충족이면
+//! 예약을 `failed` 로 닫는다 (예약 시 HYMN 이 force_publish 를 켠 경우만 우회 + PUBLISH_OVERRIDE).
+
+use sqlx::{Pool, Postgres};
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::api::admin::schedule::repo::{self, DueScheduleRow};
+use crate::api::admin::schedule::service::scheduled_state;
+use crate::api::admin::translation::service::TranslationService;
+use crate::api::admin::user::repo::{create_audit_log_tx, AuditLogParams};
+use crate::error::AppResult;
+use crate::types::{AdminAction, ContentType, PublishScheduleStatus, PublishTarget};
+
+/// 한 tick 에 처리할 최대 건수 (밀린 예약이 많아도 tick 하나가 과도하게 길어지지 않도록)
+const MAX_PER_TICK: usize = 200;
+
+/// 예약 실행 job 을 백그라운드 task 로 띄운다. `interval_sec <= 0` 이면 비활성.
+pub fn spawn(db: Pool<Postgres>, interval_sec: i64) {
+    if interval_sec <= 0 {
+        tracing::info!("publish scheduler disabled (PUBLISH_SCHEDULER_INTERVAL_SEC <= 0)");
+        return;
+    }
+    let period = Duration::from_secs(interval_sec as u64);
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match run_due(&db).await {
+                Ok(summary) if summary.done + summary.failed == 0 => {}
+                Ok(summary) => tracing::info!(
+                    done = summary.done,
+                    failed = summary.failed,
+                    "publish scheduler: schedules executed"
+                ),
+                Err(e) => tracing::warn!(error = %e, "publish scheduler run failed"),
+            }
+        }
+    });
+}
+
+#[derive(Debug, Default)]
+pub struct PublishRunSummary {
+    pub done: usize,
+    pub failed: usize,
+}
+
+/// 실행 시각이 지난 예약을 최대 `MAX_PER_TICK` 건 처리
+pub async fn run_due(db: &Pool<Postgres>) -> AppResult<PublishRunSummary> {
+    let mut summary = PublishRunSummary::default();
+    for _ in 0..MAX_PER_TICK {
+        let mut tx = db.begin().await?;
+        let Some(due) = repo::claim_due_tx(&mut tx).await? else {
+            break;
+        };
+
+        match execute_one(db, &mut tx, &due).await? {
+            Ok(()) => {
+                repo::finish_schedule_tx(
+                    &mut tx,
+                    due.schedule_id,
+                    PublishScheduleStatus::Done,
+                    None,
+                )
+                .await?;
+                tx.commit().await?;
+                summary.done += 1;
+            }
+            Err(reason) => {
+                repo::finish_schedule_tx(
+                    &mut tx,
+                    due.schedule_id,
+                    PublishScheduleStatus::Failed,
+                    Some(&reason),
+                )
+                .await?;
+                tx.commit().await?;
+                tracing::warn!(
+                    schedule_id = due.schedule_id,
+                    target = ?due.target_type,
+                    target_id = due.target_id,
+                    reason,
+                    "publish schedule failed"
+                );
+                summary.failed += 1;
+            }
+        }
+    }
+    Ok(summary)
+}
+
+fn policy_content_type(target: PublishTarget) -> Option<ContentType> {
+    match target {
+        PublishTarget::Video => Some(ContentType::Video),
+        PublishTarget::Lesson => Some(ContentType::Lesson),
+        PublishTarget::Study => Some(ContentType::Study),
+        PublishTarget::Guide | PublishTarget::Course => None,
+    }
+}
+
+fn target_table_name(target: PublishTarget) -> &'static str {
+    repo::target_table(target).table
+}
+
+/// 예약 1건 실행. 바깥 Err = DB 오류(다음 tick 재시도), 안쪽 Err = 실패 사유(예약 종료)
+async fn execute_one(
+    db: &Pool<Postgres>,
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    due: &DueScheduleRow,
+) -> AppResult<Result<(), String>> {
+    let Some(before_state) = repo::find_target_state_tx(tx, due.target_type, due.target_id).await?
+    else {
+        return Ok(Err("target not found".into()));
+    };
+    if due.target_type == PublishTarget::Course && before_state == "deleted" {
+        return Ok(Err("course is deleted".into()));
+    }
+
+    let state = scheduled_state(due.target_type, due.schedule_action);
+    let source = serde_json::json!({ "source": "scheduler", "schedule_id": due.schedule_id });
+
+    if due.schedule_action == AdminAction::Publish && before_state != state {
+        if let Some(content_type) = policy_content_type(due.target_type) {
+            let report =
+                TranslationService::get_publish_coverage(db, content_type, due.target_id).await?;
+            if !report.satisfied {
+                if !due.force_publish {
+                    return Ok(Err(format!(
+                        "translation coverage policy not met: {} field(s) missing",
+                        report.missing.len()
+                    )));
+                }
+                let mut details = serde_json::to_value(&report).unwrap_or_default();
+                details["scheduler"] = source.clone();
+                create_audit_log_tx(
+                    tx,
+                    &AuditLogParams {
+                        admin_id: due.created_by_user_id,
+                        action_type: "PUBLISH_OVERRIDE",
+                        target_table: target_table_name(due.target_type),
+                        target_id: Some(due.target_id),
+                        details: &details,
+                        ip_address: None,
+                        user_agent: None,
+                    },
+                )
+                .await?;
+            }
+        }
+    }
+
+    repo::update_target_state_tx(
+        tx,
+        due.target_type,
+        due.target_id,
+        state,
+        due.created_by_user_id,
+    )
+    .await?;
+
+    let before = serde_json::json!({ "state": before_state });
+    let mut after = source.clone();
+    after["state"] = serde_json::Value::from(state);
+    repo::create_content_log_tx(
+        tx,
+        due.target_type,
+        due.target_id,
+        due.created_by_user_id,
+        due.schedule_action,
+        &before,
+        &after,
+    )
+    .await?;
+
+    let action_type = match due.schedule_action {
+        AdminAction::Publish => "SCHEDULED_PUBLISH",
+        _ => "SCHEDULED_UNPUBLISH",
+    };
+    create_audit_log_tx(
+        tx,
+        &AuditLogParams {
+            admin_id: due.created_by_user_id,
+            action_type,
+            target_table: target_table_name(due.target_type),
+            target_id: Some(due.target_id),
+            details: &serde_json::json!({ "before": before, "after": after }),
+            ip_address: None,
+            user_agent: None,
+        },
+    )
+    .await?;
+
+    Ok(Ok(()))
+}
@@ -0,0 +1,379 @@
+//! Vimeo 메타데이터 주기 동기화.
+//!
+//! 생성/미리보기 시점에만 가져오던 duration·thumbnail·제목을 주기적으로 다시 조회해
+//! Vimeo 측 교체/삭제로 인한 DB drift 를 정리한다. 404 는 `not_found`, 403·privacy=nobody 는
+//! `private` 로 표시만 하고 비디오 상태는 건드리지 않는다 (관리자 판단). 제목/설명은 Vimeo 쪽이
+//! 마지막 스냅샷 대비 바뀐 경우에만 반영해 관리자가 직접 고친 제목을 덮어쓰지 않는다.
+//! 관리자 수동 재동기화(`POST /admin/videos/vimeo/resync`)도 같은 실행 경로를 쓴다.
+//! 전체 실행은 Redis 락(`SET NX EX`)으로 인스턴스 간 하나만 돈다.
+
+use deadpool_redis::Pool as RedisPool;
+use sqlx::{Pool, Postgres};
+use std::time::Duration;
+use tokio::time::{interval, sleep, MissedTickBehavior};
+
+use crate::api::admin::video::dto::{VimeoSyncItemResult, VimeoSyncRunRes, VimeoSyncTarget};
+use crate::api::admin::video::repo;
+use crate::error::{AppError, AppResult};
+use crate::external::vimeo::{VimeoClient, VimeoVideoMeta};
+
+/// 전체 실행 락 — 값은 의미 없음, 보유 여부만 본다
+const FULL_RUN_LOCK_KEY: &str = "ak:vimeo_sync:full_run";
+/// 락 TTL (초) — 프로세스가 죽어 해제하지 못해도 이 시간이 지나면 다음 실행 가능
+const FULL_RUN_LOCK_TTL_SEC: u64 = 6 * 3600;
+
+/// 동기화 job 을 백그라운드 task 로 띄운다.
+/// `interval_sec <= 0` 또는 Vimeo 토큰 미설정이면 비활성.
+pub fn spawn(
+    db: Pool<Postgres>,
+    redis: RedisPool,
+    access_token: Option<String>,
+    interval_sec: i64,
+    request_interval_ms: u64,
+) {
+    let Some(access_token) = access_token.filter(|t| !t.is_empty()) else {
+        tracing::info!("vimeo sync disabled (VIMEO_ACCESS_TOKEN not configured)");
+        return;
+    };
+    if interval_sec <= 0 {
+        tracing::info!("vimeo sync disabled (VIMEO_SYNC_INTERVAL_SEC <= 0)");
+        return;
+    }
+    let period = Duration::from_secs(interval_sec as u64);
+    let pace = Duration::from_millis(request_interval_ms);
+    tokio::spawn(async move {
+        let client = match VimeoClient::new(access_token) {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!(error = %e, "vimeo sync disabled (client init failed)");
+                return;
+            }
+        };
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // 부팅 직후 즉시 실행하지 않음 (배포마다 전체 조회 방지)
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let run = match start_run(&db, &redis, "schedule", None, None).await {
+                Ok(run) => run,
+                Err(AppError::Conflict(_)) => {
+                    tracing::info!("vimeo sync skipped: another full run in progress");
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "vimeo sync run start failed");
+                    continue;
+                }
+            };
+            match execute_run(&db, &redis, &client, pace, run, None).await {
+                Ok((run, _)) => tracing::info!(
+                    run_id = run.run_id,
+                    checked = run.checked_count,
+                    updated = run.updated_count,
+                    not_found = run.not_found_count,
+                    private = run.private_count,
+                    failed = run.failed_count,
+                    "vimeo sync finished"
+                ),
+                Err(e) => tracing::warn!(error = %e, "vimeo sync run failed"),
+            }
+        }
+    });
+}
+
+/// 실행 기록 생성. 전체 실행(`video_ids` 없음)이 이미 진행 중이면 Conflict.
+pub async fn start_run(
+    db: &Pool<Postgres>,
+    redis: &RedisPool,
+    trigger: &str,
+    actor_user_id: Option<i64>,
+    video_ids: Option<&[i64]>,
+) -> AppResult<VimeoSyncRunRes> {
+    let full = video_ids.is_none();
+    if full && !acquire_full_run_lock(redis).await? {
+        return Err(AppError::Conflict("vimeo sync already running".into()));
+    }
+    let created = repo::create_vimeo_sync_run(db, trigger, full, actor_user_id).await;
+    if full && created.is_err() {
+        release_full_run_lock(redis).await;
+    }
+    created
+}
+
+/// 전체 실행 락 획득 (원자적 SET NX EX) — 이미 보유 중이면 false
+async fn acquire_full_run_lock(redis: &RedisPool) -> AppResult<bool> {
+    let mut conn = redis
+        .get()
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    let acquired: Option<String> = redis::cmd("SET")
+        .arg(FULL_RUN_LOCK_KEY)
+        .arg(1)
+        .arg("NX")
+        .arg("EX")
+        .arg(FULL_RUN_LOCK_TTL_SEC)
+        .query_async(&mut conn)
+        .await?;
+    Ok(acquired.is_some())
+}
+
+/// 전체 실행 락 해제 (best-effort — 실패해도 TTL 로 풀림)
+async fn release_full_run_lock(redis: &RedisPool) {
+    let result = async {
+        let mut conn = redis
+            .get()
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        let _: () = redis::cmd("DEL")
+            .arg(FULL_RUN_LOCK_KEY)
+            .query_async(&mut conn)
+            .await?;
+        AppResult::Ok(())
+    }
+    .await;
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "vimeo sync lock release failed");
+    }
+}
+
+/// 대상 비디오를 순차 조회 (요청 간 `pace` 간격) 후 실행 기록 종료. 전체 실행이면 끝난 뒤 락 해제
+pub async fn execute_run(
+    db: &Pool<Postgres>,
+    redis: &RedisPool,
+    client: &VimeoClient,
+    pace: Duration,
+    run: VimeoSyncRunRes,
+    video_ids: Option<&[i64]>,
+) -> AppResult<(VimeoSyncRunRes, Vec<VimeoSyncItemResult>)> {
+    let full = run.run_full;
+    let result = run_targets(db, client, pace, run, video_ids).await;
+    if full {
+        release_full_run_lock(redis).await;
+    }
+    result
+}
+
+async fn run_targets(
+    db: &Pool<Postgres>,
+    client: &VimeoClient,
+    pace: Duration,
+    run: VimeoSyncRunRes,
+    video_ids: Option<&[i64]>,
+) -> AppResult<(VimeoSyncRunRes, Vec<VimeoSyncItemResult>)> {
+    let targets = match repo::find_vimeo_sync_targets(db, video_ids).await {
+        Ok(t) => t,
+        Err(e) => {
+            // 실행 기록이 '진행 중' 으로 남지 않도록 종료 처리
+            repo::finish_vimeo_sync_run(db, run.run_id, [0; 5]).await?;
+            return Err(e);
+        }
+    };
+
+    let mut items = Vec::with_capacity(targets.len());
+    for (idx, target) in targets.iter().enumerate() {
+        if idx > 0 {
+            sleep(pace).await;
+        }
+        let item = sync_one(db, client, target).await;
+        items.push(item);
+    }
+
+    let count = |status: &str| items.iter().filter(|i| i.sync_status == status).count() as i32;
+    let updated = items
+        .iter()
+        .filter(|i| !i.changed_fields.is_empty())
+        .count() as i32;
+    let run = repo::finish_vimeo_sync_run(
+        db,
+        run.run_id,
+        [
+            items.len() as i32,
+            updated,
+            count("not_found"),
+            count("private"),
+            count("error"),
+        ],
+    )
+    .await?;
+
+    Ok((run, items))
+}
+
+async fn sync_one(
+    db: &Pool<Postgres>,
+    client: &VimeoClient,
+    target: &VimeoSyncTarget,
+) -> VimeoSyncItemResult {
+    let result = |status: &str, changed: Vec<&str>, error: Option<String>| VimeoSyncItemResult {
+        video_id: target.video_id,
+        sync_status: status.to_string(),
+        changed_fields: changed.into_iter().map(str::to_string).collect(),
+        error,
+    };
+
+    let Some(vimeo_video_id) = VimeoClient::extract_video_id(&target.video_url_vimeo) else {
+        let msg = "invalid Vimeo URL";
+        if let Err(e) = record(db, target.video_id, "error", None, Some(msg)).await {
+            tracing::warn!(video_id = target.video_id, error = %e, "vimeo sync item failed");
+        }
+        return result("error", vec![], Some(msg.to_string()));
+    };
+
+    // 429 대기/재시도는 get_video_meta 가 처리 (Retry-After 상한 초과 시 TooManyRequests)
+    let outcome = match client.get_video_meta(&vimeo_video_id).await {
+        Ok(meta) if meta.is_private() => record(db, target.video_id, "private", None, None)
+            .await
+            .map(|_| result("private", vec![], None)),
+        Ok(meta) => apply_meta(db, target, &meta)
+            .await
+            .map(|changed| result("ok", changed, None)),
+        Err(AppError::NotFound) => record(db, target.video_id, "not_found", None, None)
+            .await
+            .map(|_| result("not_found", vec![], None)),
+        Err(AppError::Forbidden(_)) => record(db, target.video_id, "private", None, None)
+            .await
+            .map(|_| result("private", vec![], None)),
+        Err(e) => {
+            let msg = e.to_string();
+            record(db, target.video_id, "error", None, Some(&msg))
+                .await
+                .map(|_| result("error", vec![], Some(msg)))
+        }
+    };
+
+    outcome.unwrap_or_else(|e| {
+        tracing::warn!(video_id = target.video_id, error = %e, "vimeo sync item failed");
+        result("error", vec![], Some(e.to_string()))
+    })
+}
+
+async fn record(
+    db: &Pool<Postgres>,
+    video_id: i64,
+    status: &str,
+    snapshot: Option<(&str, Option<&str>)>,
+    error: Option<&str>,
+) -> AppResult<()> {
+    repo::upsert_vimeo_sync_status(db, video_id, status, snapshot, error).await
+}
+
+/// 변경 필드 반영 + 스냅샷 갱신 → 변경된 필드 목록
+async fn apply_meta(
+    db: &Pool<Postgres>,
+    target: &VimeoSyncTarget,
+    meta: &VimeoVideoMeta,
+) -> AppResult<Vec<&'static str>> {
+    let changed = changed_fields(target, meta);
+    let title_changed = changed.iter().any(|f| *f == "title" || *f == "description");
+
+    let mut tx = db.begin().await?;
+    if title_changed {
+        repo::update_vimeo_meta(
+            &mut tx,
+            target.video_id,
+            meta.duration,
+            meta.thumbnail_url.as_deref(),
+            &meta.name,
+            meta.description.as_deref(),
+        )
+        .await?;
+    } else if !changed.is_empty() {
+        repo::update_vimeo_media(
+            &mut tx,
+            target.video_id,
+            meta.duration,
+            meta.thumbnail_url.as_deref(),
+        )
+        .await?;
+    }
+    repo::upsert_vimeo_sync_status(
+        &mut *tx,
+        target.video_id,
+        "ok",
+        Some((&meta.name, meta.description.as_deref())),
+        None,
+    )
+    .await?;
+    tx.commit().await?;
+
+    Ok(changed)
+}
+
+/// DB 값/마지막 스냅샷 대비 바뀐 필드.
+///
+/// 제목·설명은 스냅샷이 있고 Vimeo 쪽 값이 달라졌을 때만 — 스냅샷이 없으면(최초 동기화)
+/// 현재 관리자 제목이 의도된 값인지 알 수 없으므로 기준만 기록한다.
+pub fn changed_fields(target: &VimeoSyncTarget, meta: &VimeoVideoMeta) -> Vec<&'static str> {
+    let mut changed = Vec::new();
+    if target.video_duration != Some(meta.duration) {
+        changed.push("duration");
+    }
+    if meta.thumbnail_url.is_some() && target.video_thumbnail != meta.thumbnail_url {
+        changed.push("thumbnail");
+    }
+    if target.vimeo_name.as_deref().is_some_and(|n| n != meta.name) {
+        changed.push("title");
+    }
+    if target.vimeo_name.is_some() && target.vimeo_description != meta.description {
+        changed.push("description");
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target() -> VimeoSyncTarget {
+        VimeoSyncTarget {
+            video_id: 1,
+            video_url_vimeo: "https://vimeo.com/123".into(),
+            video_duration: Some(120),
+            video_thumbnail: Some("https://i.vimeocdn.com/a.jpg".into()),
+            vimeo_name: Some("인사".into()),
+            vimeo_description: None,
+        }
+    }
+
+    fn meta() -> VimeoVideoMeta {
+        VimeoVideoMeta {
+            name: "인사".into(),
+            description: None,
+            duration: 120,
+            thumbnail_url: Some("https://i.vimeocdn.com/a.jpg".into()),
+            privacy_view: Some("anybody".into()),
+        }
+    }
+
+    #[test]
+    fn unchanged_meta_has_no_changed_fields() {
+        assert!(changed_fields(&target(), &meta()).is_empty());
+    }
+
+    #[test]
+    fn replaced_video_updates_duration_and_thumbnail() {
+        let mut m = meta();
+        m.duration = 300;
+        m.thumbnail_url = Some("https://i.vimeocdn.com/b.jpg".into());
+        assert_eq!(changed_fields(&target(), &m), vec!["duration", "thumbnail"]);
+    }
+
+    #[test]
+    fn missing_thumbnail_does_not_clear_existing() {
+        let mut m = meta();
+        m.thumbnail_url = None;
+        assert!(changed_fields(&target(), &m).is_empty());
+    }
+
+    #[test]
+    fn title_change_requires_snapshot() {
+        let mut m = meta();
+        m.name = "새 제목".into();
+        assert_eq!(changed_fields(&target(), &m), vec!["title"]);
+
+        let mut t = target();
+        t.vimeo_name = None;
+        assert!(changed_fields(&t, &m).is_empty());
+    }
+}
@@ -31,6 +31,12 @@ async fn main() -> anyhow::Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    if cfg.jwt_keyring.accepts_legacy_hs256() {
+        tracing::warn!(
+            "⚠️ JWT_LEGACY_HS256_VERIFY=true — kid-less HS256 tokens are accepted alongside the asymmetric keyring; disable once JWT_ACCESS_TTL_MIN has passed since the switch"
+        );
+    }
+
     // 3) Postgres 풀 생성
     let database_url = if cfg.database_url.contains("?") {
         cfg.database_url.clone()
@@ -99,9 +105,10 @@ async fn main() -> anyhow::Result<()> {
 
     // 6) IpGeoClient 생성
     let ipgeo = Arc::new(
-        external::ipgeo::IpGeoClient::new().expect("IpGeoClient init must succeed at startup"),
+        external::ipgeo::IpGeoClient::from_config(&cfg)
+            .expect("IpGeoClient init must succeed at startup"),
     );
-    tracing::info!("🌍 IP Geolocation client enabled (ip-api.com)");
+    tracing::info!("🌍 IP Geolocation client enabled ({})", ipgeo.describe());
 
     // 6.7) PaymentProvider 생성 (PAYMENT_PROVIDER 설정에 따라 분기)
     let payment: Option<Arc<dyn external::payment::PaymentProvider>> = match cfg
@@ -169,10 +176,27 @@ async fn main() -> anyhow::Result<()> {
             None
         };
 
+    // 6.7) 비디오 호스트 (Vimeo / 자체 HLS)
+    let video_hosts = Arc::new(
+        external::video_host::VideoHosts::from_config(&cfg)
+            .expect("VideoHosts init must succeed at startup"),
+    );
+    if cfg.hls_root_dir.is_some() {
+        tracing::info!("Self-hosted HLS enabled");
+    }
+
+    // 6.8) 라이브 수업 회의실 프로바이더
+    let live_meeting = external::live_meeting::from_config(&cfg)
+        .expect("LiveMeetingProvider init must succeed at startup");
+    tracing::info!("🎥 Live meeting provider: {}", live_meeting.name());
+
     // 6.9) E-book 워터마크 폰트 초기화
     let watermark_font_path = format!("{}/NotoSans-Regular.ttf", cfg.ebook_page_images_dir);
     amazing_korean_api::api::ebook::watermark::init_font(&watermark_font_path);
 
+    // 6.10) 수료증 폰트 초기화
+    amazing_korean_api::api::certificate::render::init_font(cfg.certificate_font_path.as_deref());
+
     // 7) AppState 생성
     let app_state = AppState {
         db: pool,
@@ -184,6 +208,8 @@ async fn main() -> anyhow::Result<()> {
         payment,
         revenuecat,
         apple_oauth,
+        video_hosts,
+        live_meeting,
     };
 
     // 8) [CORS] 설정 정의
@@ -221,9 +247,38 @@ async fn main() -> anyhow::Result<()> {
         .expose_headers([HeaderName::from_static("x-request-id")])
         .allow_credentials(true); // 쿠키(Refresh Token) 교환을 위해 필수
 
-    // 8) 백그라운드 세션 reaper 기동 (app_state 가 router 로 move 되기 전 db 핸들 확보)
+    // 8) 백그라운드 세션 reaper · Vimeo 동기화 · 예약 공개 · 과제 리마인더 · 성적부 내보내기 · 라이브 수업 리마인더 · GeoIP 재로딩 기동 (app_state 가 router 로 move 되기 전 db 핸들 확보)
     let reaper_db = app_state.db.clone();
     amazing_korean_api::jobs::session_reaper::spawn(reaper_db, cfg.session_reaper_interval_sec);
+    amazing_korean_api::jobs::vimeo_sync::spawn(
+        app_state.db.clone(),
+        app_state.redis.clone(),
+        cfg.vimeo_access_token.clone(),
+        cfg.vimeo_sync_interval_sec,
+        cfg.vimeo_sync_request_interval_ms,
+    );
+    amazing_korean_api::jobs::publish_scheduler::spawn(
+        app_state.db.clone(),
+        cfg.publish_scheduler_interval_sec,
+    );
+    amazing_korean_api::jobs::assignment_reminder::spawn(
+        app_state.clone(),
+        cfg.assignment_reminder_interval_sec,
+        cfg.assignment_reminder_hours_before,
+    );
+    amazing_korean_api::jobs::gradebook_export::spawn(
+        app_state.clone(),
+        cfg.gradebook_export_interval_sec,
+    );
+    amazing_korean_api::jobs::live_reminder::spawn(
+        app_state.clone(),
+        cfg.live_reminder_interval_sec,
+        cfg.live_reminder_minutes_before,
+    );
+    amazing_korean_api::jobs::geoip_reload::spawn(
+        app_state.ipgeo.clone(),
+        cfg.geoip_reload_interval_sec,
+    );
 
     // 9) 라우터에 trace_id → CORS → 보안 헤더 레이어 적용
     //    trace_id 는 가장 바깥쪽 (요청 진입 시 먼저 주입 · 응답 헤더 최종 에코)
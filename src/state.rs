@@ -8,8 +8,10 @@ use crate::config::Config;
 use crate::external::apple::AppleOAuthClient;
 use crate::external::email::EmailSender;
 use crate::external::ipgeo::IpGeoClient;
+use crate::external::live_meeting::LiveMeetingProvider;
 use crate::external::payment::PaymentProvider;
 use crate::external::revenuecat::RevenueCatClient;
+use crate::external::video_host::VideoHosts;
 
 #[derive(Clone, FromRef)]
 pub struct AppState {
@@ -27,6 +29,10 @@ pub struct AppState {
     pub revenuecat: Option<Arc<dyn RevenueCatClient>>,
     /// Apple OAuth 클라이언트 (Sign in with Apple — JWKS 캐시 + reqwest 커넥션 풀 싱글톤)
     pub apple_oauth: Option<Arc<AppleOAuthClient>>,
+    /// 비디오 호스트 레지스트리 (Vimeo + HLS_ROOT_DIR 설정 시 자체 HLS)
+    pub video_hosts: Arc<VideoHosts>,
+    /// 라이브 수업 회의실 프로바이더 (LIVE_MEETING_PROVIDER 설정에 따라 local stub 사용)
+    pub live_meeting: Arc<dyn LiveMeetingProvider>,
 }
 
 impl AsRef<AppState> for AppState {
@@ -147,6 +147,19 @@ pub enum LoginMethod {
     Email,
     Google,
     Apple,
+    Kakao,
+    Naver,
+    Passkey,
+    /// 비밀번호 없는 로그인 — 이메일 매직 링크
+    #[sqlx(rename = "email_link")]
+    #[serde(rename = "email_link")]
+    EmailLink,
+    /// 비밀번호 없는 로그인 — 이메일 6자리 코드
+    #[sqlx(rename = "email_code")]
+    #[serde(rename = "email_code")]
+    EmailCode,
+    /// 기관 SSO (OIDC / SAML) — 기관 IdP 로그인
+    Sso,
 }
 
 /// 로그인 세션 상태
@@ -197,7 +210,7 @@ pub enum AdminAction {
 // -----------------------------------------------------------------------------
 
 /// 번역 대상 콘텐츠 타입
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(type_name = "content_type_enum", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum ContentType {
@@ -213,6 +226,10 @@ pub enum ContentType {
     StudyTaskWriting,
     ExplanationUnit,
     ExplanationBlock,
+    /// guide 블록 번역 (field_name = 'text', 원문 = guide_block.text_en)
+    GuideBlock,
+    /// ko 자막 트랙 큐 번역 (content_id = cue_id, field_name = 'cue_text')
+    VideoSubtitleCue,
 }
 
 /// 번역 상태 (draft → reviewed → approved)
@@ -228,7 +245,7 @@ pub enum TranslationStatus {
 /// 번역 지원 언어 — 37개 (ko, en 포함)
 /// content_translations 테이블 전용 (user 테이블과 독립적으로 확장 가능)
 /// 2026-04-28: es_es / pt_pt 지역 variant 추가 (2026-04-21 "pt_pt → pt 병합" 정책 번복).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(type_name = "supported_language_enum", rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum SupportedLanguage {
@@ -304,6 +321,16 @@ pub enum VideoAccess {
     Promote,
 }
 
+/// 비디오 호스트 (vimeo: Vimeo 임베드, hls: 자체 HLS 서빙)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "video_host_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum VideoHostKind {
+    #[default]
+    Vimeo,
+    Hls,
+}
+
 /// 학습(Study) 상태
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(type_name = "study_state_enum", rename_all = "lowercase")]
@@ -417,6 +444,163 @@ pub enum LessonItemKind {
     Task,
 }
 
+/// 레슨 선수 조건 종류 (`lesson_prerequisite.prereq_kind`)
+/// - lesson: 선행 레슨 진도율 ≥ threshold(%)
+/// - course: 선행 코스 진도율 ≥ threshold(%)
+/// - study: 선행 학습의 정답 처리된 문제 수 ≥ threshold(개)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "lesson_prereq_kind_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum LessonPrereqKind {
+    Lesson,
+    Course,
+    Study,
+}
+
+/// 예약 공개/비공개 대상 콘텐츠
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "publish_target_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum PublishTarget {
+    Video,
+    Study,
+    Lesson,
+    Guide,
+    Course,
+}
+
+/// 예약 공개/비공개 처리 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "publish_schedule_status_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum PublishScheduleStatus {
+    Pending,
+    Done,
+    Cancelled,
+    Failed,
+}
+
+/// 클래스 멤버 상태 (제거된 멤버도 행 유지)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "classroom_member_state_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ClassroomMemberState {
+    Active,
+    Removed,
+}
+
+/// 과제 항목 종류 (target_id 가 가리키는 콘텐츠)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "assignment_item_type_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentItemType {
+    Study,
+    Lesson,
+    Video,
+    Guide,
+}
+
+/// 과제 지각 정책
+/// - allow: 마감 후 완료도 인정 (late 표시, late_until 까지)
+/// - deny: 마감 후 완료는 인정하지 않음
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "assignment_late_policy_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentLatePolicy {
+    Allow,
+    Deny,
+}
+
+/// 성적부 내보내기 작업 상태 (pending → running → done | failed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "gradebook_export_status_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum GradebookExportStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+/// 성적부 내보내기 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "gradebook_export_format_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum GradebookExportFormat {
+    Csv,
+    Xlsx,
+}
+
+/// 라이브 수업 회차 상태 (종료 여부는 session_end_at 으로 판단)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "live_session_state_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum LiveSessionState {
+    Scheduled,
+    Cancelled,
+}
+
+/// 라이브 수업 신청 상태
+/// - enrolled: 정원 내 확정 (참가 URL 공개)
+/// - waitlisted: 대기 — 자리가 나면 신청 순으로 자동 승격
+/// - cancelled: 본인 취소
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "live_enrollment_state_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum LiveEnrollmentState {
+    Enrolled,
+    Waitlisted,
+    Cancelled,
+}
+
+/// 기관 라이선스 범위
+/// - course: 지정 코스 1개
+/// - subscription: 구독 등급 (전체 active 코스 + 구독 전용 비디오)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "org_license_scope_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum OrgLicenseScope {
+    Course,
+    Subscription,
+}
+
+/// 기관 좌석 상태
+/// - invited: 이메일 초대 발송 (만료 전까지 좌석 점유)
+/// - active: 학습자 수락 — 수강권 부여됨
+/// - revoked: 기관 관리자가 회수 (행 유지)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "org_seat_state_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum OrgSeatState {
+    Invited,
+    Active,
+    Revoked,
+}
+
+/// 기관 SSO 프로토콜
+/// - oidc: OpenID Connect (issuer discovery + Authorization Code / PKCE)
+/// - saml: SAML 2.0 (IdP 메타데이터, HTTP-Redirect 요청 / HTTP-POST 응답)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "org_sso_protocol_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum OrgSsoProtocol {
+    Oidc,
+    Saml,
+}
+
+/// 로그인 위험 신호 감지 시 조치 (`LOGIN_RISK_ACTION`)
+/// - log: 이벤트 기록만
+/// - alert: 기록 + 알림 메일 ("본인이 아닙니다" 전체 세션 폐기 링크)
+/// - step_up: 2차 인증 없이 들어온 로그인은 메일 인증 코드 확인 후 세션 발급
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "login_risk_action_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LoginRiskAction {
+    Log,
+    Alert,
+    StepUp,
+}
+
 // 해설(explanation) 콘텐츠 enum 3종(unit_kind/source/block_type) → guide 도메인으로
 // 대체되어 제거 (PR-4a, 2026-06-14). DB enum 타입은 20260615 마이그로 DROP.
 // content_type_enum 의 explanation_unit/block 값은 PG 제약상 휴면 잔존 (AMK_GUIDE_CONTENT_DESIGN §5).
@@ -0,0 +1,90 @@
+//! admin/lesson/prerequisite 통합 테스트 — 실 DB 경로.
+//!
+//! 핵심: 선수 조건 순환 검사와 저장이 그래프 락 안에서 원자적으로 수행되어,
+//! 각자 단독으로는 순환이 없는 두 요청(A→B, B→A)이 동시에 들어와도 하나만 저장된다.
+//! 자체 격리 데이터(prq-*), 정리 포함. CI "backend integration" 잡에서 --include-ignored 로 실행.
+
+mod common;
+
+use amazing_korean_api::api::admin::lesson::prerequisite::dto::{
+    LessonPrerequisiteRuleReq, LessonPrerequisiteSetReq,
+};
+use amazing_korean_api::api::admin::lesson::prerequisite::service::set_prerequisites;
+use amazing_korean_api::error::AppError;
+use amazing_korean_api::state::AppState;
+use amazing_korean_api::types::LessonPrereqKind;
+
+async fn insert_lesson(st: &AppState, idx: &str) -> i32 {
+    sqlx::query_scalar(
+        "INSERT INTO lesson (lesson_idx, lesson_title) VALUES ($1, $1) RETURNING lesson_id",
+    )
+    .bind(idx)
+    .fetch_one(&st.db)
+    .await
+    .expect("insert lesson")
+}
+
+fn requires(lesson_id: i32) -> LessonPrerequisiteSetReq {
+    LessonPrerequisiteSetReq {
+        rules: vec![LessonPrerequisiteRuleReq {
+            kind: LessonPrereqKind::Lesson,
+            required_id: lesson_id,
+            threshold: 80,
+        }],
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis (.env.test) — CI backend integration"]
+#[tokio::test]
+async fn concurrent_opposite_prerequisites_do_not_create_cycle() {
+    let idx = format!("prq-{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+    let st = common::make_test_state().await;
+    let admin = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    sqlx::query("UPDATE users SET user_auth = 'admin' WHERE user_id = $1")
+        .bind(admin)
+        .execute(&st.db)
+        .await
+        .expect("promote admin");
+    let a = insert_lesson(&st, &format!("{idx}-a")).await;
+    let b = insert_lesson(&st, &format!("{idx}-b")).await;
+
+    let (ra, rb) = tokio::join!(
+        set_prerequisites(&st, admin, a, requires(b), None, None),
+        set_prerequisites(&st, admin, b, requires(a), None, None),
+    );
+
+    let stored: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM lesson_prerequisite WHERE lesson_id = ANY($1)")
+            .bind([a, b])
+            .fetch_one(&st.db)
+            .await
+            .expect("count prerequisites");
+
+    for (table, col) in [
+        ("lesson_prerequisite", "lesson_id"),
+        ("admin_lesson_log", "admin_pick_lesson_id"),
+        ("lesson", "lesson_id"),
+    ] {
+        sqlx::query(&format!("DELETE FROM {table} WHERE {col} = ANY($1)"))
+            .bind([a, b])
+            .execute(&st.db)
+            .await
+            .expect("cleanup lessons");
+    }
+    common::cleanup_test_user(&st, admin).await;
+
+    assert_eq!(
+        stored, 1,
+        "exactly one direction stored: {:?} / {:?}",
+        ra, rb
+    );
+    let rejected = match (ra, rb) {
+        (Ok(_), Err(e)) | (Err(e), Ok(_)) => e,
+        (ra, rb) => panic!("exactly one request should succeed: {:?} / {:?}", ra, rb),
+    };
+    assert!(
+        matches!(rejected, AppError::BadRequest(_)),
+        "{:?}",
+        rejected
+    );
+}
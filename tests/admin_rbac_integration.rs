@@ -3,7 +3,7 @@
 //! ## 범위 — RBAC matrix 검증
 //!
 //! - Hymn / Admin → 200 (통과)
-//! - Manager → 403 ("class-based access ... coming soon")
+//! - Manager → 403 (클래스 관리는 `/classes` 영역)
 //! - Learner → 403 ("Insufficient permissions")
 //! - Authorization 헤더 누락 → 401
 //! - 잘못된 JWT → 401
@@ -40,7 +40,7 @@ fn make_token_sid(state: &AppState, role: UserAuth, session_id: &str) -> String
         session_id,
         role,
         state.cfg.jwt_access_ttl_min,
-        &state.cfg.jwt_secret,
+        &state.cfg.jwt_keyring,
     )
     .expect("create token");
     token_res.access_token
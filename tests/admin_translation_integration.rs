@@ -0,0 +1,225 @@
+//! admin/translation 통합 테스트 — 실 DB 경로.
+//!
+//! 핵심: approved 번역의 승인 게이트 (QA 오류가 있는 문구로는 approved 상태를 유지할 수 없음),
+//! 공개 정책이 켜진 콘텐츠 타입은 open 으로 바로 생성 불가.
+//! 원천 텍스트는 guide 블록(text_en)으로 시드. 자체 격리 데이터(guidev2-at-*), 정리 포함.
+//! CI "backend integration" 잡에서 --include-ignored 로 실행.
+
+mod common;
+
+use amazing_korean_api::api::admin::lesson::dto::LessonCreateReq;
+use amazing_korean_api::api::admin::lesson::service::admin_create_lesson;
+use amazing_korean_api::api::admin::translation::dto::TranslationUpdateReq;
+use amazing_korean_api::api::admin::translation::service::TranslationService;
+use amazing_korean_api::error::AppError;
+use amazing_korean_api::state::AppState;
+use amazing_korean_api::types::{LessonState, TranslationStatus};
+
+/// 격리 단원 + 블록 1 (원천 "Hello {name}!") + ja approved 번역 시드. (block_id, translation_id) 반환.
+async fn seed(st: &AppState, idx: &str, seq: i32) -> (i64, i64) {
+    cleanup(st, idx).await;
+    let gid: i64 = sqlx::query_scalar(
+        r#"INSERT INTO guide (guide_idx, guide_seq, guide_state, guide_category, guide_theme,
+              sentence_start, sentence_end, title_ko, title_en)
+           VALUES ($1, $2, 'ready', 'sentence_structure', 'blue', $2, $2, '테스트', 'Test')
+           RETURNING guide_id"#,
+    )
+    .bind(idx)
+    .bind(seq)
+    .fetch_one(&st.db)
+    .await
+    .expect("seed guide");
+
+    let bid: i64 = sqlx::query_scalar(
+        r#"INSERT INTO guide_block (guide_id, block_seq, block_type, sentence_no,
+              text_ko, text_en, source_version)
+           VALUES ($1, 10, 'section', $2, '안녕하세요 {name}!', 'Hello {name}!', 1)
+           RETURNING guide_block_id"#,
+    )
+    .bind(gid)
+    .bind(seq)
+    .fetch_one(&st.db)
+    .await
+    .expect("seed block");
+
+    let tid: i64 = sqlx::query_scalar(
+        r#"INSERT INTO content_translations
+              (content_type, content_id, field_name, lang, translated_text, status, source_version)
+           VALUES ('guide_block', $1, 'text', 'ja', 'こんにちは {name}!', 'approved', 1)
+           RETURNING translation_id"#,
+    )
+    .bind(bid)
+    .fetch_one(&st.db)
+    .await
+    .expect("seed translation");
+
+    (bid, tid)
+}
+
+async fn cleanup(st: &AppState, idx: &str) {
+    sqlx::query(
+        r#"DELETE FROM content_translations WHERE content_type='guide_block'
+           AND content_id IN (SELECT guide_block_id FROM guide_block b
+             JOIN guide g ON g.guide_id=b.guide_id WHERE g.guide_idx=$1)"#,
+    )
+    .bind(idx)
+    .execute(&st.db)
+    .await
+    .ok();
+    sqlx::query("DELETE FROM guide WHERE guide_idx=$1")
+        .bind(idx)
+        .execute(&st.db)
+        .await
+        .ok();
+}
+
+fn text_only(text: &str) -> TranslationUpdateReq {
+    TranslationUpdateReq {
+        translated_text: Some(text.into()),
+        status: None,
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis (.env.test) — CI backend integration"]
+#[tokio::test]
+async fn editing_approved_translation_text_runs_qa_gate() {
+    let idx = "guidev2-at-qa-gate";
+    let st = common::make_test_state().await;
+    let (_bid, tid) = seed(&st, idx, 9261).await;
+
+    // placeholder 누락 문구 → approved 유지 불가 (422), 행은 그대로
+    let broken =
+        TranslationService::update_translation(&st.db, tid, text_only("こんにちは!")).await;
+    let unchanged = TranslationService::get_translation(&st.db, tid)
+        .await
+        .expect("get after rejected edit");
+
+    // QA 통과 문구 → approved 유지
+    let fixed =
+        TranslationService::update_translation(&st.db, tid, text_only("やあ {name}!")).await;
+
+    cleanup(&st, idx).await;
+
+    assert!(
+        matches!(broken, Err(AppError::Unprocessable(_))),
+        "approved 번역 문구 수정도 QA 게이트, got {:?}",
+        broken
+    );
+    assert_eq!(unchanged.translated_text, "こんにちは {name}!");
+    assert_eq!(unchanged.status, TranslationStatus::Approved);
+    let fixed = fixed.expect("QA 통과 문구 수정");
+    assert_eq!(fixed.translated_text, "やあ {name}!");
+    assert_eq!(fixed.status, TranslationStatus::Approved);
+}
+
+#[ignore = "requires local PostgreSQL + Redis (.env.test) — CI backend integration"]
+#[tokio::test]
+async fn editing_draft_translation_text_skips_qa_gate() {
+    let idx = "guidev2-at-draft";
+    let st = common::make_test_state().await;
+    let (_bid, tid) = seed(&st, idx, 9262).await;
+    sqlx::query("UPDATE content_translations SET status = 'draft' WHERE translation_id = $1")
+        .bind(tid)
+        .execute(&st.db)
+        .await
+        .expect("demote to draft");
+
+    // 초안은 QA 오류가 있어도 저장 가능 — 승인 시점에 막힌다
+    let saved = TranslationService::update_translation(&st.db, tid, text_only("こんにちは!")).await;
+    let approve = TranslationService::update_translation(
+        &st.db,
+        tid,
+        TranslationUpdateReq {
+            translated_text: None,
+            status: Some(TranslationStatus::Approved),
+        },
+    )
+    .await;
+
+    cleanup(&st, idx).await;
+
+    assert_eq!(saved.expect("draft edit").status, TranslationStatus::Draft);
+    assert!(
+        matches!(approve, Err(AppError::Unprocessable(_))),
+        "QA 오류 번역 승인 거부, got {:?}",
+        approve
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis (.env.test) — CI backend integration"]
+#[tokio::test]
+async fn creating_open_lesson_is_rejected_while_policy_enabled() {
+    let idx = format!(
+        "at-open-{}",
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    );
+    let st = common::make_test_state().await;
+    let admin = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    sqlx::query("UPDATE users SET user_auth = 'admin' WHERE user_id = $1")
+        .bind(admin)
+        .execute(&st.db)
+        .await
+        .expect("promote admin");
+    // 기본 시드 정책 (lesson: 20개 언어 × approved) 이 켜져 있다고 가정하지 않고 명시
+    let prev_enabled: bool = sqlx::query_scalar(
+        "SELECT policy_enabled FROM content_publish_policy WHERE policy_content_type = 'lesson'",
+    )
+    .fetch_one(&st.db)
+    .await
+    .expect("lesson policy");
+    sqlx::query(
+        "UPDATE content_publish_policy SET policy_enabled = TRUE WHERE policy_content_type = 'lesson'",
+    )
+    .execute(&st.db)
+    .await
+    .expect("enable lesson policy");
+
+    let req = |state: LessonState| LessonCreateReq {
+        lesson_idx: format!("{idx}-{state:?}"),
+        lesson_title: "번역 없는 레슨".into(),
+        lesson_subtitle: None,
+        lesson_description: None,
+        lesson_state: Some(state),
+        lesson_access: None,
+    };
+    let open = admin_create_lesson(&st, admin, req(LessonState::Open), None, None).await;
+    let ready = admin_create_lesson(&st, admin, req(LessonState::Ready), None, None).await;
+
+    let created_ids: Vec<i32> =
+        sqlx::query_scalar("SELECT lesson_id FROM lesson WHERE lesson_idx LIKE $1")
+            .bind(format!("{idx}-%"))
+            .fetch_all(&st.db)
+            .await
+            .expect("created lessons");
+    for table in ["admin_lesson_log", "lesson"] {
+        let col = if table == "lesson" {
+            "lesson_id"
+        } else {
+            "admin_pick_lesson_id"
+        };
+        sqlx::query(&format!("DELETE FROM {table} WHERE {col} = ANY($1)"))
+            .bind(&created_ids)
+            .execute(&st.db)
+            .await
+            .expect("cleanup lessons");
+    }
+    sqlx::query(
+        "UPDATE content_publish_policy SET policy_enabled = $1 WHERE policy_content_type = 'lesson'",
+    )
+    .bind(prev_enabled)
+    .execute(&st.db)
+    .await
+    .expect("restore lesson policy");
+    common::cleanup_test_user(&st, admin).await;
+
+    assert!(
+        matches!(open, Err(AppError::UnprocessableDetails(_, _))),
+        "정책 활성 시 open 생성 거부, got {:?}",
+        open.map(|l| l.lesson_id)
+    );
+    assert_eq!(
+        ready.expect("ready 생성은 허용").lesson_state,
+        LessonState::Ready
+    );
+    assert_eq!(created_ids.len(), 1, "거부된 open 레슨은 생성되지 않음");
+}
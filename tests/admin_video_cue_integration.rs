@@ -0,0 +1,300 @@
+//! admin/video 퀴즈 큐 포인트 통합 테스트 — 실 DB 경로.
+//!
+//! 핵심: 큐 생성 검증 (영상 길이 초과 / 없는 study_task) + 큐별 정답률 집계.
+//! 자체 격리 데이터(cue-it-*) 를 만들고 테스트 끝에 정리한다.
+//! CI "backend integration" 잡에서 --include-ignored 로 실행.
+
+mod common;
+
+use amazing_korean_api::api::admin::video::cue::dto::VideoCuePointCreateReq;
+use amazing_korean_api::api::admin::video::cue::service as cue;
+use amazing_korean_api::api::admin::video::stats::service as stats;
+use amazing_korean_api::error::AppError;
+use amazing_korean_api::state::AppState;
+use uuid::Uuid;
+
+/// 격리 시드 — 60초 영상 + 학습 1개 + 객관식 task 1개 + admin. (admin, video_id, study_id, task_id)
+async fn seed(st: &AppState, tag: &str) -> (i64, i64, i32, i32) {
+    let spec = common::TestUserSpec::random();
+    let admin = common::insert_test_user(st, &spec).await;
+    sqlx::query("UPDATE users SET user_auth = 'admin' WHERE user_id = $1")
+        .bind(admin)
+        .execute(&st.db)
+        .await
+        .expect("promote admin");
+
+    let video_id: i32 = sqlx::query_scalar(
+        r#"INSERT INTO video (video_idx, video_title, video_url_vimeo, video_duration)
+           VALUES ($1, 'cue test', 'https://vimeo.com/1', 60)
+           RETURNING video_id"#,
+    )
+    .bind(format!("cue-it-{tag}"))
+    .fetch_one(&st.db)
+    .await
+    .expect("seed video");
+
+    let study_id: i32 =
+        sqlx::query_scalar("INSERT INTO study (study_idx) VALUES ($1) RETURNING study_id")
+            .bind(format!("cue-it-{tag}"))
+            .fetch_one(&st.db)
+            .await
+            .expect("seed study");
+
+    let task_id: i32 = sqlx::query_scalar(
+        r#"INSERT INTO study_task (study_id, study_task_kind, study_task_idx)
+           VALUES ($1, 'choice', $2)
+           RETURNING study_task_id"#,
+    )
+    .bind(study_id)
+    .bind(format!("cue-it-{tag}-1"))
+    .fetch_one(&st.db)
+    .await
+    .expect("seed study_task");
+
+    (admin, i64::from(video_id), study_id, task_id)
+}
+
+/// study_task 삭제 = 답안 로그 CASCADE → login 정리 가능
+async fn cleanup(st: &AppState, video_id: i64, study_id: i32, user_ids: &[i64]) {
+    let _ = sqlx::query("DELETE FROM video WHERE video_id = $1")
+        .bind(video_id as i32)
+        .execute(&st.db)
+        .await;
+    let _ = sqlx::query("DELETE FROM study_task WHERE study_id = $1")
+        .bind(study_id)
+        .execute(&st.db)
+        .await;
+    let _ = sqlx::query("DELETE FROM study WHERE study_id = $1")
+        .bind(study_id)
+        .execute(&st.db)
+        .await;
+    for uid in user_ids {
+        common::cleanup_test_user(st, *uid).await;
+    }
+}
+
+fn create_req(study_task_id: i32, cue_time_ms: i32) -> VideoCuePointCreateReq {
+    VideoCuePointCreateReq {
+        study_task_id,
+        cue_time_ms,
+        cue_skippable: None,
+    }
+}
+
+/// 학습자 + login row 생성 (study_task_log.login_id FK). (user_id, login_id)
+async fn make_learner(st: &AppState) -> (i64, i64) {
+    let uid = common::insert_test_user(st, &common::TestUserSpec::random()).await;
+    let login_id: i64 = sqlx::query_scalar(
+        r#"INSERT INTO login (user_id, login_country, login_asn, login_org, login_session_id)
+           VALUES ($1, 'KR', 0, 'test', $2)
+           RETURNING login_id"#,
+    )
+    .bind(uid)
+    .bind(Uuid::new_v4())
+    .fetch_one(&st.db)
+    .await
+    .expect("seed login");
+    (uid, login_id)
+}
+
+/// 큐 컨텍스트 제출 로그 (순서대로 1초 간격)
+async fn submit(st: &AppState, task_id: i32, cue_id: i64, learner: (i64, i64), results: &[bool]) {
+    for (i, ok) in results.iter().enumerate() {
+        sqlx::query(
+            r#"INSERT INTO study_task_log (
+                   study_task_id, user_id, login_id, study_task_action_log,
+                   study_task_try_no_log, study_task_is_correct_log,
+                   study_task_created_at_log, video_cue_point_id
+               )
+               VALUES ($1, $2, $3, 'finish', $4, $5, NOW() + make_interval(secs => $4), $6)"#,
+        )
+        .bind(task_id)
+        .bind(learner.0)
+        .bind(learner.1)
+        .bind(i as i32 + 1)
+        .bind(*ok)
+        .bind(cue_id)
+        .execute(&st.db)
+        .await
+        .expect("seed study_task_log");
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_create_cue_point_rejects_time_beyond_video_duration() {
+    let st = common::make_test_state().await;
+    let tag = Uuid::new_v4().simple().to_string();
+    let (admin, video_id, study_id, task_id) = seed(&st, &tag).await;
+
+    let result = cue::create_cue_point(
+        &st,
+        admin,
+        video_id,
+        create_req(task_id, 60_001),
+        None,
+        None,
+    )
+    .await;
+    let within = cue::create_cue_point(
+        &st,
+        admin,
+        video_id,
+        create_req(task_id, 60_000),
+        None,
+        None,
+    )
+    .await;
+
+    cleanup(&st, video_id, study_id, &[admin]).await;
+
+    match result {
+        Err(AppError::BadRequest(msg)) => assert!(msg.contains("duration"), "msg={msg}"),
+        other => panic!(
+            "60.001s on 60s video → BadRequest expected, got {:?}",
+            other
+        ),
+    }
+    assert!(within.is_ok(), "영상 끝 시점은 허용: {:?}", within.err());
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_create_cue_point_rejects_unknown_study_task() {
+    let st = common::make_test_state().await;
+    let tag = Uuid::new_v4().simple().to_string();
+    let (admin, video_id, study_id, _task_id) = seed(&st, &tag).await;
+
+    let result = cue::create_cue_point(
+        &st,
+        admin,
+        video_id,
+        create_req(999_999_988, 1_000),
+        None,
+        None,
+    )
+    .await;
+
+    cleanup(&st, video_id, study_id, &[admin]).await;
+
+    match result {
+        Err(AppError::BadRequest(msg)) => assert!(msg.contains("not found"), "msg={msg}"),
+        other => panic!("unknown study_task → BadRequest expected, got {:?}", other),
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_create_cue_point_unknown_video_returns_not_found() {
+    let st = common::make_test_state().await;
+    let tag = Uuid::new_v4().simple().to_string();
+    let (admin, video_id, study_id, task_id) = seed(&st, &tag).await;
+
+    let result = cue::create_cue_point(
+        &st,
+        admin,
+        999_999_988,
+        create_req(task_id, 1_000),
+        None,
+        None,
+    )
+    .await;
+
+    cleanup(&st, video_id, study_id, &[admin]).await;
+
+    assert!(
+        matches!(result, Err(AppError::NotFound)),
+        "unknown video → NotFound expected, got {:?}",
+        result
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_cue_stats_per_cue_first_try_and_unsolved() {
+    let st = common::make_test_state().await;
+    let tag = Uuid::new_v4().simple().to_string();
+    let (admin, video_id, study_id, task_id) = seed(&st, &tag).await;
+
+    let easy = cue::create_cue_point(
+        &st,
+        admin,
+        video_id,
+        create_req(task_id, 10_000),
+        None,
+        None,
+    )
+    .await
+    .expect("create easy cue");
+    let hard = cue::create_cue_point(
+        &st,
+        admin,
+        video_id,
+        create_req(task_id, 20_000),
+        None,
+        None,
+    )
+    .await
+    .expect("create hard cue");
+    let untouched = cue::create_cue_point(
+        &st,
+        admin,
+        video_id,
+        create_req(task_id, 30_000),
+        None,
+        None,
+    )
+    .await
+    .expect("create untouched cue");
+
+    let a = make_learner(&st).await;
+    let b = make_learner(&st).await;
+    // easy: 둘 다 첫 제출 정답
+    submit(&st, task_id, easy.cue_point_id, a, &[true]).await;
+    submit(&st, task_id, easy.cue_point_id, b, &[true]).await;
+    // hard: a = 오답 후 정답, b = 두 번 모두 오답 (미해결)
+    submit(&st, task_id, hard.cue_point_id, a, &[false, true]).await;
+    submit(&st, task_id, hard.cue_point_id, b, &[false, false]).await;
+
+    let res = stats::get_cue_stats(&st, video_id).await;
+
+    cleanup(&st, video_id, study_id, &[admin, a.0, b.0]).await;
+
+    let res = res.expect("get_cue_stats");
+    assert_eq!(res.items.len(), 3, "큐 3개 (제출 없는 큐 포함)");
+    let cue_ids: Vec<i64> = res.items.iter().map(|i| i.cue_point_id).collect();
+    assert_eq!(
+        cue_ids,
+        vec![easy.cue_point_id, hard.cue_point_id, untouched.cue_point_id],
+        "시간순"
+    );
+
+    let e = &res.items[0];
+    assert_eq!((e.learners, e.attempts, e.correct_attempts), (2, 2, 2));
+    assert_eq!(e.first_try_fail_rate, 0.0);
+    assert_eq!(e.unsolved_learners, 0);
+
+    let h = &res.items[1];
+    assert_eq!((h.learners, h.attempts, h.correct_attempts), (2, 4, 1));
+    assert_eq!(h.first_try_fail_rate, 1.0);
+    assert_eq!(h.unsolved_learners, 1);
+    assert_eq!(h.avg_attempts, 2.0);
+
+    let u = &res.items[2];
+    assert_eq!((u.learners, u.attempts), (0, 0));
+
+    assert_eq!(res.hardest_cue_point_id, Some(hard.cue_point_id));
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_cue_stats_unknown_video_returns_not_found() {
+    let st = common::make_test_state().await;
+
+    let result = stats::get_cue_stats(&st, 999_999_988).await;
+    assert!(
+        matches!(result, Err(AppError::NotFound)),
+        "unknown video → NotFound expected, got {:?}",
+        result
+    );
+}
@@ -0,0 +1,166 @@
+//! Phase 3 통합 테스트 — `AssignmentService` 추적 화면 실명 노출 정책.
+//!
+//! ## 범위 — 추적 화면의 실명은 include_names 일 때만 복호화 + 감사 로그
+//!
+//! manager + 학습자 1명 + 클래스 + 비디오 1개 과제를 만들고 테스트 끝에 정리한다.
+
+mod common;
+
+use amazing_korean_api::api::assignment::dto::{AssignmentItemReq, CreateAssignmentReq};
+use amazing_korean_api::api::assignment::service::AssignmentService;
+use amazing_korean_api::api::classroom::dto::CreateClassroomReq;
+use amazing_korean_api::api::classroom::service::ClassroomService;
+use amazing_korean_api::state::AppState;
+use amazing_korean_api::types::AssignmentItemType;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+struct Seeded {
+    manager: i64,
+    learner: i64,
+    learner_name: String,
+    classroom_id: i64,
+    video_id: i32,
+    assignment_id: i64,
+}
+
+async fn seed(st: &AppState) -> Seeded {
+    let manager = common::insert_test_user(st, &common::TestUserSpec::random()).await;
+    sqlx::query("UPDATE users SET user_auth = 'manager' WHERE user_id = $1")
+        .bind(manager)
+        .execute(&st.db)
+        .await
+        .expect("promote manager");
+    let learner_spec = common::TestUserSpec::random();
+    let learner = common::insert_test_user(st, &learner_spec).await;
+
+    let classroom = ClassroomService::create(
+        st,
+        manager,
+        CreateClassroomReq {
+            classroom_name: "assignment name policy".into(),
+            classroom_description: None,
+        },
+        None,
+        None,
+    )
+    .await
+    .expect("create classroom");
+    ClassroomService::join_by_code(st, learner, &classroom.classroom_join_code)
+        .await
+        .expect("join classroom");
+
+    let video_id: i32 = sqlx::query_scalar(
+        r#"INSERT INTO video (video_idx, video_title, video_url_vimeo, video_duration)
+           VALUES ($1, 'assignment test', 'https://vimeo.com/1', 60)
+           RETURNING video_id"#,
+    )
+    .bind(format!("asg-it-{}", Uuid::new_v4().simple()))
+    .fetch_one(&st.db)
+    .await
+    .expect("seed video");
+
+    let assignment = AssignmentService::create(
+        st,
+        manager,
+        CreateAssignmentReq {
+            classroom_id: Some(classroom.classroom_id),
+            user_ids: None,
+            assignment_title: "watch".into(),
+            assignment_description: None,
+            open_at: None,
+            due_at: Utc::now() + Duration::days(7),
+            late_policy: None,
+            late_until: None,
+            items: vec![AssignmentItemReq {
+                item_type: AssignmentItemType::Video,
+                target_id: i64::from(video_id),
+                sentence_start: None,
+                sentence_end: None,
+            }],
+        },
+        None,
+        None,
+    )
+    .await
+    .expect("create assignment");
+
+    Seeded {
+        manager,
+        learner,
+        learner_name: learner_spec.name,
+        classroom_id: classroom.classroom_id,
+        video_id,
+        assignment_id: assignment.assignment_id,
+    }
+}
+
+async fn cleanup(st: &AppState, s: &Seeded) {
+    let _ = sqlx::query("DELETE FROM admin_action_log WHERE admin_id = $1")
+        .bind(s.manager)
+        .execute(&st.db)
+        .await;
+    let _ = sqlx::query("DELETE FROM classroom WHERE classroom_id = $1")
+        .bind(s.classroom_id)
+        .execute(&st.db)
+        .await;
+    let _ = sqlx::query("DELETE FROM assignment WHERE assignment_id = $1")
+        .bind(s.assignment_id)
+        .execute(&st.db)
+        .await;
+    let _ = sqlx::query("DELETE FROM video WHERE video_id = $1")
+        .bind(s.video_id)
+        .execute(&st.db)
+        .await;
+    common::cleanup_test_user(st, s.learner).await;
+    common::cleanup_test_user(st, s.manager).await;
+}
+
+async fn count_decrypt_audits(st: &AppState, s: &Seeded) -> i64 {
+    sqlx::query_scalar(
+        r#"SELECT COUNT(*) FROM admin_action_log
+           WHERE admin_id = $1 AND action_type = 'DECRYPT_ASSIGNMENT_NAMES' AND target_id = $2"#,
+    )
+    .bind(s.manager)
+    .bind(s.assignment_id)
+    .fetch_one(&st.db)
+    .await
+    .expect("count audit")
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_tracking_masks_names_by_default() {
+    let st = common::make_test_state().await;
+    let s = seed(&st).await;
+
+    let res = AssignmentService::tracking(&st, s.manager, s.assignment_id, false, None, None).await;
+    let audits = count_decrypt_audits(&st, &s).await;
+
+    cleanup(&st, &s).await;
+
+    let res = res.expect("tracking");
+    assert_eq!(res.learners.len(), 1);
+    assert_eq!(res.learners[0].user_id, s.learner);
+    assert!(res.learners[0].name.is_none(), "기본은 실명 미포함");
+    assert_eq!(audits, 0, "복호화 없으면 감사 로그 없음");
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_tracking_include_names_decrypts_and_audits() {
+    let st = common::make_test_state().await;
+    let s = seed(&st).await;
+
+    let res = AssignmentService::tracking(&st, s.manager, s.assignment_id, true, None, None).await;
+    let audits = count_decrypt_audits(&st, &s).await;
+
+    cleanup(&st, &s).await;
+
+    let res = res.expect("tracking");
+    assert_eq!(
+        res.learners[0].name.as_deref(),
+        Some(s.learner_name.as_str())
+    );
+    assert_eq!(audits, 1, "실명 복호화 = 감사 로그 1건");
+}
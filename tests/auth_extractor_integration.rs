@@ -40,7 +40,7 @@ fn make_token_sid(state: &AppState, role: UserAuth, session_id: &str) -> String
         session_id,
         role,
         state.cfg.jwt_access_ttl_min,
-        &state.cfg.jwt_secret,
+        &state.cfg.jwt_keyring,
     )
     .expect("create token");
     token_res.access_token
@@ -130,9 +130,14 @@ async fn test_auth_user_rejects_malformed_token() {
 async fn test_auth_user_rejects_token_signed_with_wrong_secret() {
     let st = common::make_test_state().await;
     // 같은 구조의 토큰을 다른 secret 으로 발급 → secret 불일치 → 401
-    let (token_res, _) =
-        jwt::create_token(1, "s", UserAuth::Admin, 30, "totally-different-secret-XYZ")
-            .expect("create token");
+    let (token_res, _) = jwt::create_token(
+        1,
+        "s",
+        UserAuth::Admin,
+        30,
+        &jwt::JwtKeyring::hs256("totally-different-secret-XYZ"),
+    )
+    .expect("create token");
     let (status, _) = call_protected(st, Some(&format!("Bearer {}", token_res.access_token))).await;
     assert_eq!(status, StatusCode::UNAUTHORIZED);
 }
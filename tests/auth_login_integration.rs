@@ -337,7 +337,8 @@ async fn test_mfa_login_succeeds_with_valid_totp_code() {
 
     let req = MfaLoginReq {
         mfa_token: mfa_token.clone(),
-        code,
+        code: Some(code),
+        passkey: None,
     };
     let result = AuthService::mfa_login(&st, req, "10.0.1.20".to_string()).await;
     match result {
@@ -390,7 +391,8 @@ async fn test_mfa_login_rejects_invalid_totp_code() {
     // 잘못된 코드 (6자리 숫자, but TOTP 미일치 + 백업 코드 fail)
     let req = MfaLoginReq {
         mfa_token: mfa_token.clone(),
-        code: "000000".to_string(),
+        code: Some("000000".to_string()),
+        passkey: None,
     };
     let result = AuthService::mfa_login(&st, req, "10.0.1.21".to_string()).await;
     match result {
@@ -665,7 +667,8 @@ async fn test_mfa_login_returns_token_expired_for_unknown_token() {
     let unique_token = uuid::Uuid::new_v4().to_string();
     let req = MfaLoginReq {
         mfa_token: unique_token,
-        code: "123456".to_string(),
+        code: Some("123456".to_string()),
+        passkey: None,
     };
     let result = AuthService::mfa_login(&st, req, "10.0.1.10".to_string()).await;
     match result {
@@ -0,0 +1,286 @@
+//! Phase 3 통합 테스트 — 비밀번호 없는 로그인 (매직 링크 / 6자리 코드).
+//!
+//! ## 범위
+//!
+//! - 링크 / 코드 일회용 (동시 사용 시 하나만 통과, 틀린 코드는 코드를 소모하지 않음)
+//! - 발송 레이트리밋: 이메일+IP, 그리고 IP 를 바꿔도 걸리는 이메일 단위
+//! - 코드 시도 횟수는 코드를 새로 받아도 초기화되지 않음
+//! - MFA 사용 계정은 세션 대신 MFA 챌린지
+//!
+//! 메일은 `CapturingEmailSender` 로 받아 본문에서 링크 토큰 / 코드를 꺼낸다.
+
+mod common;
+
+use std::sync::Arc;
+
+use amazing_korean_api::api::auth::dto::{
+    PasswordlessMethod, PasswordlessRequestReq, PasswordlessVerifyReq,
+};
+use amazing_korean_api::api::auth::handler::ParsedUa;
+use amazing_korean_api::api::auth::service::{AuthService, LoginOutcome};
+use amazing_korean_api::error::AppError;
+use amazing_korean_api::state::AppState;
+use common::{CapturedEmail, TestUserSpec};
+use tokio::sync::Mutex;
+
+type Outbox = Arc<Mutex<Vec<CapturedEmail>>>;
+
+fn parsed_ua() -> ParsedUa {
+    ParsedUa {
+        os: None,
+        browser: None,
+        device: "other".into(),
+    }
+}
+
+async fn request(
+    st: &AppState,
+    email: &str,
+    method: PasswordlessMethod,
+    ip: &str,
+) -> Result<(), AppError> {
+    AuthService::passwordless_request(
+        st,
+        PasswordlessRequestReq {
+            email: email.to_string(),
+            method,
+        },
+        ip.to_string(),
+    )
+    .await
+    .map(|_| ())
+}
+
+async fn verify_code(st: &AppState, email: &str, code: &str) -> Result<LoginOutcome, AppError> {
+    AuthService::passwordless_login(
+        st,
+        PasswordlessVerifyReq {
+            token: None,
+            email: Some(email.to_string()),
+            code: Some(code.to_string()),
+        },
+        "10.0.47.1".to_string(),
+        None,
+        parsed_ua(),
+    )
+    .await
+}
+
+async fn verify_link(st: &AppState, token: &str) -> Result<LoginOutcome, AppError> {
+    AuthService::passwordless_login(
+        st,
+        PasswordlessVerifyReq {
+            token: Some(token.to_string()),
+            email: None,
+            code: None,
+        },
+        "10.0.47.1".to_string(),
+        None,
+        parsed_ua(),
+    )
+    .await
+}
+
+/// 마지막 메일 본문에서 `marker` 뒤의 값 (공백/개행 전까지)
+async fn last_mail_value(outbox: &Outbox, marker: &str) -> String {
+    let sent = outbox.lock().await;
+    sent.last()
+        .and_then(|m| m.text.split(marker).nth(1))
+        .and_then(|rest| rest.split_whitespace().next())
+        .map(str::to_string)
+        .unwrap_or_else(|| panic!("메일 본문에 '{marker}' 없음"))
+}
+
+fn wrong_code(code: &str) -> String {
+    if code == "000000" {
+        "111111".into()
+    } else {
+        "000000".into()
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_code_is_single_use_and_wrong_code_does_not_burn_it() {
+    let (st, outbox) = common::make_test_state_with_capturing_email().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+
+    request(&st, &spec.email, PasswordlessMethod::Code, "10.0.47.10")
+        .await
+        .expect("request code");
+    let code = last_mail_value(&outbox, "로그인 코드: ").await;
+
+    let wrong = verify_code(&st, &spec.email, &wrong_code(&code)).await;
+    let first = verify_code(&st, &spec.email, &code).await;
+    let replay = verify_code(&st, &spec.email, &code).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(
+        matches!(wrong, Err(AppError::Unauthorized(_))),
+        "틀린 코드 = 401"
+    );
+    assert!(
+        matches!(first, Ok(LoginOutcome::Success(_))),
+        "틀린 시도 후에도 올바른 코드는 통과: {:?}",
+        first.err()
+    );
+    assert!(
+        matches!(replay, Err(AppError::Unauthorized(ref m)) if m == "AUTH_401_INVALID_OR_EXPIRED_CODE"),
+        "코드 재사용 거부, got {:?}",
+        replay.err()
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_magic_link_is_single_use_under_concurrency() {
+    let (st, outbox) = common::make_test_state_with_capturing_email().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+
+    request(&st, &spec.email, PasswordlessMethod::Link, "10.0.47.11")
+        .await
+        .expect("request link");
+    let token = last_mail_value(&outbox, "/login/magic?token=").await;
+
+    let (a, b) = tokio::join!(verify_link(&st, &token), verify_link(&st, &token));
+    let replay = verify_link(&st, &token).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    let passed = [&a, &b]
+        .iter()
+        .filter(|r| matches!(r, Ok(LoginOutcome::Success(_))))
+        .count();
+    assert_eq!(passed, 1, "동시 사용 중 하나만 세션 발급");
+    assert!(
+        matches!(replay, Err(AppError::Unauthorized(ref m)) if m == "PASSWORDLESS_LINK_EXPIRED"),
+        "링크 재사용 거부, got {:?}",
+        replay.err()
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_send_limit_per_email_and_ip() {
+    let (st, _outbox) = common::make_test_state_with_capturing_email().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let max = st.cfg.rate_limit_email_max;
+
+    let mut results = Vec::new();
+    for _ in 0..=max {
+        results.push(request(&st, &spec.email, PasswordlessMethod::Code, "10.0.47.20").await);
+    }
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(results[..max as usize].iter().all(|r| r.is_ok()));
+    assert!(
+        matches!(results[max as usize], Err(AppError::TooManyRequests(ref m)) if m == "AUTH_429_TOO_MANY_LOGIN_EMAILS"),
+        "같은 이메일+IP 초과 = 429"
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_send_limit_per_email_ignores_ip_rotation() {
+    let (st, outbox) = common::make_test_state_with_capturing_email().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let max = st.cfg.rate_limit_email_max;
+
+    let mut results = Vec::new();
+    for i in 0..=max {
+        let ip = format!("10.0.48.{}", i + 1);
+        results.push(request(&st, &spec.email, PasswordlessMethod::Code, &ip).await);
+    }
+    let delivered = outbox.lock().await.len();
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(results[..max as usize].iter().all(|r| r.is_ok()));
+    assert!(
+        matches!(results[max as usize], Err(AppError::TooManyRequests(_))),
+        "IP 를 바꿔도 이메일 단위 한도 초과 = 429"
+    );
+    assert_eq!(delivered as i64, max, "한도만큼만 발송");
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_code_attempts_survive_new_code() {
+    let (st, outbox) = common::make_test_state_with_capturing_email().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let max = st.cfg.rate_limit_mfa_max;
+
+    request(&st, &spec.email, PasswordlessMethod::Code, "10.0.47.30")
+        .await
+        .expect("first code");
+    let first_code = last_mail_value(&outbox, "로그인 코드: ").await;
+    for _ in 0..max {
+        let _ = verify_code(&st, &spec.email, &wrong_code(&first_code)).await;
+    }
+
+    // 새 코드를 받아도 시도 횟수는 그대로 → 올바른 새 코드도 429
+    request(&st, &spec.email, PasswordlessMethod::Code, "10.0.47.31")
+        .await
+        .expect("second code");
+    let second_code = last_mail_value(&outbox, "로그인 코드: ").await;
+    let result = verify_code(&st, &spec.email, &second_code).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(
+        matches!(result, Err(AppError::TooManyRequests(ref m)) if m == "AUTH_429_TOO_MANY_CODE_ATTEMPTS"),
+        "새 코드 발급으로 시도 횟수 초기화 불가, got {:?}",
+        result.err()
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_mfa_account_gets_challenge_instead_of_session() {
+    let (st, outbox) = common::make_test_state_with_capturing_email().await;
+    let mut spec = TestUserSpec::random();
+    spec.mfa_enabled = true;
+    let (user_id, _secret) = common::insert_test_user_with_mfa(&st, &spec).await;
+
+    request(&st, &spec.email, PasswordlessMethod::Code, "10.0.47.40")
+        .await
+        .expect("request code");
+    let code = last_mail_value(&outbox, "로그인 코드: ").await;
+    let result = verify_code(&st, &spec.email, &code).await;
+    let sessions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM login WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&st.db)
+        .await
+        .expect("count sessions");
+
+    let pending = match &result {
+        Ok(LoginOutcome::MfaChallenge { mfa_token, .. }) => {
+            let mut conn = st.redis.get().await.expect("redis conn");
+            redis::AsyncCommands::get::<_, Option<String>>(
+                &mut conn,
+                format!("ak:mfa_pending:{mfa_token}"),
+            )
+            .await
+            .expect("get mfa_pending")
+        }
+        _ => None,
+    };
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(
+        matches!(result, Ok(LoginOutcome::MfaChallenge { user_id: uid, .. }) if uid == user_id),
+        "MFA 계정 = 챌린지"
+    );
+    assert_eq!(sessions, 0, "MFA 완료 전 세션 없음");
+    let pending: serde_json::Value =
+        serde_json::from_str(&pending.expect("mfa_pending 저장")).expect("pending json");
+    assert_eq!(pending["login_method"], "email_code");
+}
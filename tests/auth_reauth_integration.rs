@@ -0,0 +1,254 @@
+//! Phase 3 통합 테스트 — 보안 설정 변경 전 재인증 (step-up).
+//!
+//! ## 범위
+//!
+//! - 재인증 없이 패스키 등록 옵션/등록 요청 → 403 `AUTH_403_REAUTH_REQUIRED`
+//! - 패스키 삭제도 재인증 필요
+//! - 비밀번호 / TOTP / 메일 코드 재인증 후 통과, 재인증 표식은 세션 단위
+//! - 메일 코드 일회용, 잘못된 비밀번호 거부
+//!
+//! 실제 WebAuthn attestation 은 만들지 않는다 — 등록 옵션 발급까지만 확인.
+
+mod common;
+
+use amazing_korean_api::api::auth::dto::{
+    PasskeyAttestationResponse, PasskeyRegisterReq, PasskeyRegistrationCredential, ReauthMethod,
+    ReauthReq,
+};
+use amazing_korean_api::api::auth::service::AuthService;
+use amazing_korean_api::error::AppError;
+use uuid::Uuid;
+
+fn reauth_req(method: ReauthMethod, password: Option<&str>, code: Option<&str>) -> ReauthReq {
+    ReauthReq {
+        method,
+        password: password.map(str::to_string),
+        code: code.map(str::to_string),
+    }
+}
+
+fn assert_reauth_required<T: std::fmt::Debug>(result: Result<T, AppError>) {
+    match result {
+        Err(AppError::Forbidden(msg)) => assert_eq!(msg, "AUTH_403_REAUTH_REQUIRED"),
+        other => panic!("expected AUTH_403_REAUTH_REQUIRED, got {:?}", other),
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_passkey_register_without_reauth_is_rejected() {
+    let st = common::make_test_state().await;
+    let user_id = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    let session_id = Uuid::new_v4().to_string();
+    common::seed_session(&st, &session_id, user_id).await;
+
+    let options = AuthService::passkey_register_options(&st, user_id, &session_id).await;
+    let register = AuthService::passkey_register(
+        &st,
+        user_id,
+        &session_id,
+        PasskeyRegisterReq {
+            ceremony_id: Uuid::new_v4().to_string(),
+            name: None,
+            credential: PasskeyRegistrationCredential {
+                id: "AQID".into(),
+                raw_id: "AQID".into(),
+                credential_type: "public-key".into(),
+                response: PasskeyAttestationResponse {
+                    client_data_json: "e30".into(),
+                    attestation_object: "oA".into(),
+                    transports: vec![],
+                },
+            },
+        },
+    )
+    .await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert_reauth_required(options);
+    assert_reauth_required(register);
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_passkey_delete_requires_reauth() {
+    let st = common::make_test_state().await;
+    let spec = common::TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let session_id = Uuid::new_v4().to_string();
+    common::seed_session(&st, &session_id, user_id).await;
+    let passkey_id: i64 = sqlx::query_scalar(
+        r#"INSERT INTO user_passkey (user_id, passkey_credential_id, passkey_public_key,
+              passkey_alg, passkey_name)
+           VALUES ($1, $2, '\x00', -7, '노트북')
+           RETURNING passkey_id"#,
+    )
+    .bind(user_id)
+    .bind(Uuid::new_v4().simple().to_string())
+    .fetch_one(&st.db)
+    .await
+    .expect("seed passkey");
+
+    let without_reauth = AuthService::delete_passkey(&st, user_id, &session_id, passkey_id).await;
+    AuthService::reauth(
+        &st,
+        user_id,
+        &session_id,
+        reauth_req(ReauthMethod::Password, Some(&spec.password), None),
+    )
+    .await
+    .expect("password reauth");
+    let after_reauth = AuthService::delete_passkey(&st, user_id, &session_id, passkey_id).await;
+    let remaining: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM user_passkey WHERE passkey_id = $1")
+            .bind(passkey_id)
+            .fetch_one(&st.db)
+            .await
+            .expect("count passkeys");
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert_reauth_required(without_reauth);
+    after_reauth.expect("delete after reauth");
+    assert_eq!(remaining, 0);
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_password_reauth_unlocks_only_current_session() {
+    let st = common::make_test_state().await;
+    let spec = common::TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let session_a = Uuid::new_v4().to_string();
+    let session_b = Uuid::new_v4().to_string();
+
+    let wrong = AuthService::reauth(
+        &st,
+        user_id,
+        &session_a,
+        reauth_req(ReauthMethod::Password, Some("WrongPass999"), None),
+    )
+    .await;
+    let ok = AuthService::reauth(
+        &st,
+        user_id,
+        &session_a,
+        reauth_req(ReauthMethod::Password, Some(&spec.password), None),
+    )
+    .await;
+    let guard_a = AuthService::require_recent_auth(&st, user_id, &session_a).await;
+    let guard_b = AuthService::require_recent_auth(&st, user_id, &session_b).await;
+    let options = AuthService::passkey_register_options(&st, user_id, &session_a).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(
+        matches!(wrong, Err(AppError::Unauthorized(ref m)) if m == "REAUTH_INVALID_CREDENTIALS"),
+        "wrong password → 401, got {:?}",
+        wrong
+    );
+    assert_eq!(
+        ok.expect("password reauth").expires_in,
+        st.cfg.reauth_ttl_sec
+    );
+    assert!(guard_a.is_ok(), "재인증한 세션은 통과");
+    assert_reauth_required(guard_b);
+    let options = options.expect("register options after reauth");
+    assert!(options.ceremony_id.is_some());
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_totp_reauth_requires_mfa_and_valid_code() {
+    let st = common::make_test_state().await;
+    let mut spec = common::TestUserSpec::random();
+    spec.mfa_enabled = true;
+    let (user_id, secret) = common::insert_test_user_with_mfa(&st, &spec).await;
+    let plain_user = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    let session_id = Uuid::new_v4().to_string();
+
+    let wrong = AuthService::reauth(
+        &st,
+        user_id,
+        &session_id,
+        reauth_req(ReauthMethod::Totp, None, Some("000000")),
+    )
+    .await;
+    let ok = AuthService::reauth(
+        &st,
+        user_id,
+        &session_id,
+        reauth_req(
+            ReauthMethod::Totp,
+            None,
+            Some(&common::generate_totp_code(&secret)),
+        ),
+    )
+    .await;
+    let no_mfa = AuthService::reauth(
+        &st,
+        plain_user,
+        &Uuid::new_v4().to_string(),
+        reauth_req(ReauthMethod::Totp, None, Some("123456")),
+    )
+    .await;
+
+    common::cleanup_test_user(&st, user_id).await;
+    common::cleanup_test_user(&st, plain_user).await;
+
+    assert!(
+        matches!(wrong, Err(AppError::Unauthorized(_))),
+        "{:?}",
+        wrong
+    );
+    assert!(ok.is_ok(), "valid TOTP: {:?}", ok.err());
+    assert!(
+        matches!(no_mfa, Err(AppError::BadRequest(ref m)) if m == "REAUTH_METHOD_UNAVAILABLE"),
+        "MFA 미설정 계정은 TOTP 재인증 불가, got {:?}",
+        no_mfa
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_email_code_reauth_is_single_use() {
+    let (st, sent) = common::make_test_state_with_capturing_email().await;
+    let user_id = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    let session_id = Uuid::new_v4().to_string();
+
+    let issued = AuthService::reauth_email_code(&st, user_id, &session_id).await;
+    let code = {
+        let captured = sent.lock().await;
+        captured
+            .last()
+            .and_then(|m| m.text.split("확인 코드: ").nth(1))
+            .map(|rest| rest.chars().take(6).collect::<String>())
+    };
+    let code = code.unwrap_or_default();
+    let first = AuthService::reauth(
+        &st,
+        user_id,
+        &session_id,
+        reauth_req(ReauthMethod::EmailCode, None, Some(&code)),
+    )
+    .await;
+    let replay = AuthService::reauth(
+        &st,
+        user_id,
+        &session_id,
+        reauth_req(ReauthMethod::EmailCode, None, Some(&code)),
+    )
+    .await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(issued.is_ok(), "code sent: {:?}", issued.err());
+    assert_eq!(code.len(), 6, "메일 본문에 6자리 코드");
+    assert!(first.is_ok(), "first use: {:?}", first.err());
+    assert!(
+        matches!(replay, Err(AppError::Unauthorized(ref m)) if m == "REAUTH_CODE_EXPIRED"),
+        "코드 재사용 거부, got {:?}",
+        replay
+    );
+}
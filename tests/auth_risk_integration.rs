@@ -0,0 +1,56 @@
+//! Phase 3 통합 테스트 — 위험 로그인 알림 "본인이 아닙니다" 링크.
+//!
+//! ## 범위
+//!
+//! - revoke 토큰 일회용: 같은 토큰 동시 사용 시 하나만 통과, 재사용은 401
+//!
+//! 알림 메일 발송 경로 대신 `ak:risk_revoke:{sha256(token)}` 를 직접 시드한다.
+
+mod common;
+
+use amazing_korean_api::api::auth::dto::RiskRevokeReq;
+use amazing_korean_api::api::auth::service::AuthService;
+use amazing_korean_api::error::AppError;
+use sha2::{Digest, Sha256};
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_risk_revoke_token_is_single_use_under_concurrency() {
+    let st = common::make_test_state().await;
+    let user_id = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    {
+        let mut conn = st.redis.get().await.expect("redis conn (seed)");
+        let _: () = redis::AsyncCommands::set_ex(
+            &mut conn,
+            format!("ak:risk_revoke:{}", hex::encode(Sha256::digest(&token))),
+            serde_json::json!({ "user_id": user_id, "risk_event_id": 0 }).to_string(),
+            300,
+        )
+        .await
+        .expect("seed risk revoke token");
+    }
+
+    let req = || RiskRevokeReq {
+        token: token.clone(),
+    };
+    let (a, b) = tokio::join!(
+        AuthService::risk_revoke(&st, req()),
+        AuthService::risk_revoke(&st, req())
+    );
+    let replay = AuthService::risk_revoke(&st, req()).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert_eq!(
+        [a.is_ok(), b.is_ok()].iter().filter(|ok| **ok).count(),
+        1,
+        "동시 요청 중 하나만 통과"
+    );
+    assert!(
+        matches!(replay, Err(AppError::Unauthorized(ref m)) if m == "RISK_REVOKE_TOKEN_EXPIRED"),
+        "재사용 거부, got {:?}",
+        replay.err()
+    );
+}
@@ -0,0 +1,124 @@
+//! Phase 3 통합 테스트 — `ClassroomService` 학습자 실명 노출 정책.
+//!
+//! ## 범위 — 학습자 목록/진도의 실명은 include_names 일 때만 복호화 + 감사 로그
+//!
+//! manager + 학습자 1명 + 클래스를 만들고 테스트 끝에 정리한다.
+
+mod common;
+
+use amazing_korean_api::api::classroom::dto::CreateClassroomReq;
+use amazing_korean_api::api::classroom::service::ClassroomService;
+use amazing_korean_api::state::AppState;
+
+/// manager 1명 + 학습자 1명이 참여한 클래스. (manager_id, learner_id, learner_name, classroom_id)
+async fn seed(st: &AppState) -> (i64, i64, String, i64) {
+    let manager = common::insert_test_user(st, &common::TestUserSpec::random()).await;
+    sqlx::query("UPDATE users SET user_auth = 'manager' WHERE user_id = $1")
+        .bind(manager)
+        .execute(&st.db)
+        .await
+        .expect("promote manager");
+    let learner_spec = common::TestUserSpec::random();
+    let learner = common::insert_test_user(st, &learner_spec).await;
+
+    let classroom = ClassroomService::create(
+        st,
+        manager,
+        CreateClassroomReq {
+            classroom_name: "name policy".into(),
+            classroom_description: None,
+        },
+        None,
+        None,
+    )
+    .await
+    .expect("create classroom");
+    ClassroomService::join_by_code(st, learner, &classroom.classroom_join_code)
+        .await
+        .expect("join classroom");
+
+    (manager, learner, learner_spec.name, classroom.classroom_id)
+}
+
+async fn cleanup(st: &AppState, manager: i64, learner: i64, classroom_id: i64) {
+    let _ = sqlx::query("DELETE FROM admin_action_log WHERE admin_id = $1")
+        .bind(manager)
+        .execute(&st.db)
+        .await;
+    let _ = sqlx::query("DELETE FROM classroom WHERE classroom_id = $1")
+        .bind(classroom_id)
+        .execute(&st.db)
+        .await;
+    common::cleanup_test_user(st, learner).await;
+    common::cleanup_test_user(st, manager).await;
+}
+
+async fn count_decrypt_audits(st: &AppState, manager: i64, classroom_id: i64) -> i64 {
+    sqlx::query_scalar(
+        r#"SELECT COUNT(*) FROM admin_action_log
+           WHERE admin_id = $1 AND action_type = 'DECRYPT_CLASSROOM_NAMES' AND target_id = $2"#,
+    )
+    .bind(manager)
+    .bind(classroom_id)
+    .fetch_one(&st.db)
+    .await
+    .expect("count audit")
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_list_learners_masks_names_by_default() {
+    let st = common::make_test_state().await;
+    let (manager, learner, _name, classroom_id) = seed(&st).await;
+
+    let res = ClassroomService::list_learners(&st, manager, classroom_id, false, None, None).await;
+    let audits = count_decrypt_audits(&st, manager, classroom_id).await;
+
+    cleanup(&st, manager, learner, classroom_id).await;
+
+    let res = res.expect("list_learners");
+    assert_eq!(res.items.len(), 1);
+    assert_eq!(res.items[0].user_id, learner);
+    assert!(res.items[0].name.is_none(), "기본은 실명 미포함");
+    assert_eq!(audits, 0, "복호화 없으면 감사 로그 없음");
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_list_learners_include_names_decrypts_and_audits() {
+    let st = common::make_test_state().await;
+    let (manager, learner, name, classroom_id) = seed(&st).await;
+
+    let res = ClassroomService::list_learners(&st, manager, classroom_id, true, None, None).await;
+    let audits = count_decrypt_audits(&st, manager, classroom_id).await;
+
+    cleanup(&st, manager, learner, classroom_id).await;
+
+    let res = res.expect("list_learners");
+    assert_eq!(res.items[0].name.as_deref(), Some(name.as_str()));
+    assert_eq!(audits, 1, "실명 복호화 = 감사 로그 1건");
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_learner_progress_names_follow_same_policy() {
+    let st = common::make_test_state().await;
+    let (manager, learner, name, classroom_id) = seed(&st).await;
+
+    let masked =
+        ClassroomService::learner_progress(&st, manager, classroom_id, learner, false, None, None)
+            .await;
+    let named =
+        ClassroomService::learner_progress(&st, manager, classroom_id, learner, true, None, None)
+            .await;
+    let audits = count_decrypt_audits(&st, manager, classroom_id).await;
+
+    cleanup(&st, manager, learner, classroom_id).await;
+
+    assert!(masked.expect("masked progress").learner.name.is_none());
+    assert_eq!(
+        named.expect("named progress").learner.name.as_deref(),
+        Some(name.as_str())
+    );
+    assert_eq!(audits, 1);
+}
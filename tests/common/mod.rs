@@ -15,7 +15,7 @@
 //!
 //! - Phase 2 = `EMAIL_PROVIDER=none` / `PAYMENT_PROVIDER=none` (mock 불필요, email 미사용 함수)
 //! - Phase 3 = `make_test_state_with_capturing_email()` (CapturingEmailSender 주입, 발송 캡처)
-//! - `IpGeoClient::new()` = HTTP 클라이언트 default (test 함수가 ipgeo 호출 안 하면 영향 0)
+//! - `IpGeoClient::from_config()` = env 기준 (mmdb 미설정 + HTTP 폴백 off 면 조회 결과 빈 값)
 //! - `payment` / `revenuecat` / `apple_oauth` = None
 //! - `started_at` = `Instant::now()`
 //!
@@ -29,6 +29,8 @@ use amazing_korean_api::config::Config;
 use amazing_korean_api::error::{AppError, AppResult};
 use amazing_korean_api::external::email::EmailSender;
 use amazing_korean_api::external::ipgeo::IpGeoClient;
+use amazing_korean_api::external::live_meeting;
+use amazing_korean_api::external::video_host::VideoHosts;
 use amazing_korean_api::state::AppState;
 use async_trait::async_trait;
 use deadpool_redis::{Config as RedisConfig, Runtime};
@@ -55,7 +57,9 @@ pub async fn make_test_state() -> AppState {
         .create_pool(Some(Runtime::Tokio1))
         .expect("RedisPool 생성 실패 — REDIS_URL 확인");
 
-    let ipgeo = Arc::new(IpGeoClient::new().expect("IpGeoClient init in test"));
+    let ipgeo = Arc::new(IpGeoClient::from_config(&cfg).expect("IpGeoClient init in test"));
+    let video_hosts = Arc::new(VideoHosts::from_config(&cfg).expect("VideoHosts init in test"));
+    let live_meeting = live_meeting::from_config(&cfg).expect("LiveMeetingProvider init in test");
 
     AppState {
         db,
@@ -67,6 +71,8 @@ pub async fn make_test_state() -> AppState {
         payment: None,
         revenuecat: None,
         apple_oauth: None,
+        video_hosts,
+        live_meeting,
     }
 }
 
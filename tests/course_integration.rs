@@ -0,0 +1,68 @@
+//! 통합 테스트 — `CourseService` 커리큘럼 노출 범위.
+//!
+//! ## 범위 — 공개(active) 코스만 노출, 비공개(inactive) 코스는 수강자에게만, 삭제 코스는 항상 404
+
+mod common;
+
+use amazing_korean_api::api::course::service::CourseService;
+use amazing_korean_api::error::AppError;
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_curriculum_hides_unpublished_courses() {
+    let st = common::make_test_state().await;
+    let user = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    let idx = format!("crs-{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+
+    let mut course_ids = Vec::new();
+    for state in ["active", "inactive", "deleted"] {
+        let id: i32 = sqlx::query_scalar(
+            "INSERT INTO course (updated_by_user_id, course_idx, course_title, course_state)
+             VALUES ($1, $2, $2, $3::course_state_enum)
+             RETURNING course_id",
+        )
+        .bind(user)
+        .bind(format!("{idx}-{state}"))
+        .bind(state)
+        .fetch_one(&st.db)
+        .await
+        .expect("insert course");
+        course_ids.push(i64::from(id));
+    }
+    let [active, inactive, deleted] = course_ids[..] else {
+        unreachable!()
+    };
+
+    let anon_active = CourseService::get_curriculum(&st, active, None, None).await;
+    let anon_inactive = CourseService::get_curriculum(&st, inactive, None, None).await;
+    let anon_deleted = CourseService::get_curriculum(&st, deleted, None, None).await;
+
+    for id in [inactive, deleted] {
+        sqlx::query("INSERT INTO users_course (user_id, course_id) VALUES ($1, $2)")
+            .bind(user)
+            .bind(id as i32)
+            .execute(&st.db)
+            .await
+            .expect("enroll");
+    }
+    let enrolled_inactive = CourseService::get_curriculum(&st, inactive, Some(user), None).await;
+    let enrolled_deleted = CourseService::get_curriculum(&st, deleted, Some(user), None).await;
+
+    sqlx::query("DELETE FROM users_course WHERE user_id = $1")
+        .bind(user)
+        .execute(&st.db)
+        .await
+        .expect("cleanup enrollments");
+    sqlx::query("DELETE FROM course WHERE course_idx LIKE $1")
+        .bind(format!("{idx}-%"))
+        .execute(&st.db)
+        .await
+        .expect("cleanup courses");
+    common::cleanup_test_user(&st, user).await;
+
+    assert!(anon_active.is_ok(), "{:?}", anon_active.err());
+    assert!(matches!(anon_inactive, Err(AppError::NotFound)));
+    assert!(matches!(anon_deleted, Err(AppError::NotFound)));
+    assert!(enrolled_inactive.is_ok(), "{:?}", enrolled_inactive.err());
+    assert!(matches!(enrolled_deleted, Err(AppError::NotFound)));
+}
@@ -76,7 +76,7 @@ async fn test_get_lesson_detail_returns_not_found_for_unknown_id() {
         per_page: None,
         lang: None,
     };
-    let result = LessonService::get_lesson_detail(&st.db, 999_999_989, req).await;
+    let result = LessonService::get_lesson_detail(&st.db, 999_999_989, req, None).await;
     match result {
         Err(AppError::NotFound) => {}
         Err(e) => panic!("unknown id → NotFound expected, got Err: {:?}", e),
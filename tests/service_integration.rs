@@ -259,7 +259,7 @@ async fn test_reset_password_rejects_access_token_as_reset_token() {
         "victim-session",
         UserAuth::Learner,
         st.cfg.jwt_access_ttl_min,
-        &st.cfg.jwt_secret,
+        &st.cfg.jwt_keyring,
     )
     .expect("create access token");
 
@@ -0,0 +1,324 @@
+//! Phase 3 통합 테스트 — 계정 설정의 로그인 수단 (소셜 계정 연결 / 비밀번호 설정).
+//!
+//! ## 범위
+//!
+//! - 재인증 없이 연결 시작 / 모바일 연결 / 비밀번호 설정 → 403 `AUTH_403_REAUTH_REQUIRED`
+//! - 웹 연결 link intent 는 연결을 시작한 브라우저(바인딩 쿠키 값)에서만 소모 가능
+//! - 다른 계정에 연결된 소셜 계정 → 409 `OAUTH_IDENTITY_LINKED_TO_OTHER_ACCOUNT`
+//! - 마지막 로그인 수단 연결 해제 → 409 `LAST_LOGIN_METHOD`
+//! - 비밀번호가 이미 있으면 → 409 `PASSWORD_ALREADY_SET`
+//!
+//! 소셜 계정은 wiremock `/jwks` + 서명된 Google ID token (모바일 연결) 으로 만든다.
+//! 비밀번호 없는 계정의 재인증 표식은 `ak:reauth:{session_id}` 를 직접 시드한다.
+
+mod common;
+
+use std::sync::OnceLock;
+
+use amazing_korean_api::api::auth::dto::{ReauthMethod, ReauthReq};
+use amazing_korean_api::api::auth::service::AuthService;
+use amazing_korean_api::api::user::dto::{IdentityLinkReq, SetPasswordReq};
+use amazing_korean_api::api::user::service::UserService;
+use amazing_korean_api::error::AppError;
+use amazing_korean_api::state::AppState;
+use base64::engine::{general_purpose::URL_SAFE_NO_PAD, Engine};
+use common::TestUserSpec;
+use rsa::pkcs8::EncodePrivateKey;
+use rsa::traits::PublicKeyParts;
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const MOBILE_CLIENT_ID: &str = "phase3-link-mobile-client-id";
+
+struct TestKey {
+    private_pem: String,
+    n_b64: String,
+    e_b64: String,
+}
+
+/// 첫 호출 시 RSA 2048 생성 (1-2초). 후속 호출은 캐시 재사용.
+fn test_key() -> &'static TestKey {
+    static CACHE: OnceLock<TestKey> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        let private = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).expect("RSA keygen");
+        let public = RsaPublicKey::from(&private);
+        TestKey {
+            private_pem: private
+                .to_pkcs8_pem(rsa::pkcs8::LineEnding::LF)
+                .expect("pkcs8 pem")
+                .to_string(),
+            n_b64: URL_SAFE_NO_PAD.encode(public.n().to_bytes_be()),
+            e_b64: URL_SAFE_NO_PAD.encode(public.e().to_bytes_be()),
+        }
+    })
+}
+
+/// wiremock `/jwks` + Google 모바일 client 설정을 붙인 AppState
+async fn google_mobile_state() -> (AppState, MockServer) {
+    let mock = MockServer::start().await;
+    let key = test_key();
+    Mock::given(method("GET"))
+        .and(path("/jwks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "keys": [{
+                "kty": "RSA", "use": "sig", "alg": "RS256",
+                "kid": "phase3-link-key", "n": key.n_b64, "e": key.e_b64,
+            }],
+        })))
+        .mount(&mock)
+        .await;
+
+    let mut st = common::make_test_state().await;
+    st.cfg.google_mobile_client_id = Some(MOBILE_CLIENT_ID.into());
+    // override 는 token / jwks 둘 다 있어야 적용 (모바일은 /token 호출 없음)
+    st.cfg.google_token_url_override = Some(format!("{}/token", mock.uri()));
+    st.cfg.google_jwks_url_override = Some(format!("{}/jwks", mock.uri()));
+    (st, mock)
+}
+
+/// Google 모바일 ID token (subject 고정)
+fn google_id_token(sub: &str) -> String {
+    let now = chrono::Utc::now().timestamp();
+    let claims = serde_json::json!({
+        "iss": "https://accounts.google.com",
+        "azp": MOBILE_CLIENT_ID,
+        "aud": MOBILE_CLIENT_ID,
+        "sub": sub,
+        "email": format!("{sub}@example.com"),
+        "email_verified": true,
+        "iat": now,
+        "exp": now + 3600,
+    });
+    let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+    header.kid = Some("phase3-link-key".into());
+    let encoding_key = jsonwebtoken::EncodingKey::from_rsa_pem(test_key().private_pem.as_bytes())
+        .expect("encoding key from pem");
+    jsonwebtoken::encode(&header, &claims, &encoding_key).expect("jwt encode")
+}
+
+fn link_req(sub: &str) -> IdentityLinkReq {
+    IdentityLinkReq {
+        id_token: google_id_token(sub),
+    }
+}
+
+fn set_password_req() -> SetPasswordReq {
+    SetPasswordReq {
+        new_password: "NewPassw0rd!".into(),
+    }
+}
+
+/// 세션 + 재인증 표식 시드 → session_id
+async fn reauthed_session(st: &AppState, user_id: i64) -> String {
+    let session_id = Uuid::new_v4().to_string();
+    common::seed_session(st, &session_id, user_id).await;
+    let mut conn = st.redis.get().await.expect("redis conn (seed reauth)");
+    let _: () = redis::AsyncCommands::set_ex(
+        &mut conn,
+        format!("ak:reauth:{session_id}"),
+        user_id,
+        st.cfg.reauth_ttl_sec as u64,
+    )
+    .await
+    .expect("seed ak:reauth");
+    session_id
+}
+
+async fn cleanup(st: &AppState, user_id: i64) {
+    let _ = sqlx::query("DELETE FROM users_oauth WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&st.db)
+        .await;
+    common::cleanup_test_user(st, user_id).await;
+}
+
+fn assert_reauth_required<T: std::fmt::Debug>(result: Result<T, AppError>) {
+    match result {
+        Err(AppError::Forbidden(msg)) => assert_eq!(msg, "AUTH_403_REAUTH_REQUIRED"),
+        other => panic!("expected AUTH_403_REAUTH_REQUIRED, got {:?}", other),
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_identity_changes_require_reauth() {
+    let (st, _mock) = google_mobile_state().await;
+    let mut spec = TestUserSpec::random();
+    spec.oauth_only = true;
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let session_id = Uuid::new_v4().to_string();
+    common::seed_session(&st, &session_id, user_id).await;
+    let sub = format!("link-sub-{}", Uuid::new_v4());
+
+    let start = UserService::link_identity_start(&st, user_id, &session_id, "google").await;
+    let link =
+        UserService::link_identity(&st, user_id, &session_id, "google", link_req(&sub)).await;
+    let set_pw = UserService::set_password(&st, user_id, &session_id, set_password_req()).await;
+    let has_password: bool =
+        sqlx::query_scalar("SELECT user_password IS NOT NULL FROM users WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&st.db)
+            .await
+            .expect("password state");
+
+    cleanup(&st, user_id).await;
+
+    assert_reauth_required(start);
+    assert_reauth_required(link);
+    assert_reauth_required(set_pw);
+    assert!(!has_password, "거부된 요청은 비밀번호를 설정하지 않음");
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_link_intent_is_bound_to_starting_browser() {
+    let mut st = common::make_test_state().await;
+    st.cfg.google_client_id = Some("phase3-client-id".into());
+    st.cfg.google_client_secret = Some("phase3-secret".into());
+    st.cfg.google_redirect_uri = Some("http://localhost:3000/auth/google/callback".into());
+    let user_id = common::insert_test_user(&st, &TestUserSpec::random()).await;
+    let session_id = reauthed_session(&st, user_id).await;
+
+    let state_of = |url: &str| {
+        url.split(['?', '&'])
+            .find_map(|pair| pair.strip_prefix("state="))
+            .map(str::to_string)
+            .expect("state param")
+    };
+
+    // 다른 브라우저 (쿠키 없음 / 다른 값) → 403, intent 는 소모되어 재시도 불가
+    let (url, _binding) = UserService::link_identity_start(&st, user_id, &session_id, "google")
+        .await
+        .expect("link start");
+    let state = state_of(&url);
+    let foreign = AuthService::take_oauth_link_intent(&st, &state, Some("attacker")).await;
+    let replay = AuthService::take_oauth_link_intent(&st, &state, None).await;
+
+    // 시작한 브라우저 → 연결 대상 user_id
+    let (url, binding) = UserService::link_identity_start(&st, user_id, &session_id, "google")
+        .await
+        .expect("link start");
+    let own = AuthService::take_oauth_link_intent(&st, &state_of(&url), Some(&binding)).await;
+
+    cleanup(&st, user_id).await;
+
+    assert!(
+        matches!(foreign, Err(AppError::Forbidden(ref m)) if m == "OAUTH_LINK_BROWSER_MISMATCH"),
+        "바인딩 불일치 = 403, got {:?}",
+        foreign
+    );
+    assert!(
+        matches!(replay, Ok(None)),
+        "소모된 intent 는 일반 로그인 콜백으로 취급, got {:?}",
+        replay
+    );
+    assert_eq!(own.expect("own browser"), Some(user_id));
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_link_identity_owned_by_other_account_is_rejected() {
+    let (st, _mock) = google_mobile_state().await;
+    let owner = common::insert_test_user(&st, &TestUserSpec::random()).await;
+    let other = common::insert_test_user(&st, &TestUserSpec::random()).await;
+    let owner_session = reauthed_session(&st, owner).await;
+    let other_session = reauthed_session(&st, other).await;
+    let sub = format!("link-sub-{}", Uuid::new_v4());
+
+    let first =
+        UserService::link_identity(&st, owner, &owner_session, "google", link_req(&sub)).await;
+    let stolen =
+        UserService::link_identity(&st, other, &other_session, "google", link_req(&sub)).await;
+    let again =
+        UserService::link_identity(&st, owner, &owner_session, "google", link_req(&sub)).await;
+    let other_identities = UserService::list_identities(&st, other).await;
+
+    cleanup(&st, owner).await;
+    cleanup(&st, other).await;
+
+    assert!(first.is_ok(), "첫 연결: {:?}", first.err());
+    assert!(
+        matches!(stolen, Err(AppError::Conflict(ref m)) if m == "OAUTH_IDENTITY_LINKED_TO_OTHER_ACCOUNT"),
+        "다른 계정의 소셜 계정 = 409, got {:?}",
+        stolen
+    );
+    assert!(
+        matches!(again, Err(AppError::Conflict(ref m)) if m == "OAUTH_IDENTITY_ALREADY_LINKED"),
+        "got {:?}",
+        again
+    );
+    assert!(other_identities.expect("list other").items.is_empty());
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_unlink_last_login_method_is_rejected() {
+    let (st, _mock) = google_mobile_state().await;
+    let mut spec = TestUserSpec::random();
+    spec.oauth_only = true;
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let session_id = reauthed_session(&st, user_id).await;
+    let sub = format!("link-sub-{}", Uuid::new_v4());
+
+    UserService::link_identity(&st, user_id, &session_id, "google", link_req(&sub))
+        .await
+        .expect("link google");
+    let last = UserService::unlink_identity(&st, user_id, "google").await;
+    let kept = UserService::list_identities(&st, user_id).await;
+
+    // 비밀번호를 설정하면 해제 가능
+    UserService::set_password(&st, user_id, &session_id, set_password_req())
+        .await
+        .expect("set password");
+    let unlinked = UserService::unlink_identity(&st, user_id, "google").await;
+
+    cleanup(&st, user_id).await;
+
+    assert!(
+        matches!(last, Err(AppError::Conflict(ref m)) if m == "LAST_LOGIN_METHOD"),
+        "유일한 로그인 수단 해제 = 409, got {:?}",
+        last
+    );
+    let kept = kept.expect("list identities");
+    assert!(!kept.has_password);
+    assert_eq!(kept.items.len(), 1, "거부된 해제는 연결 유지");
+    assert!(
+        unlinked.is_ok(),
+        "비밀번호 설정 후 해제: {:?}",
+        unlinked.err()
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_set_password_conflicts_when_already_set() {
+    let st = common::make_test_state().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let session_id = Uuid::new_v4().to_string();
+    common::seed_session(&st, &session_id, user_id).await;
+
+    AuthService::reauth(
+        &st,
+        user_id,
+        &session_id,
+        ReauthReq {
+            method: ReauthMethod::Password,
+            password: Some(spec.password.clone()),
+            code: None,
+        },
+    )
+    .await
+    .expect("password reauth");
+    let result = UserService::set_password(&st, user_id, &session_id, set_password_req()).await;
+
+    cleanup(&st, user_id).await;
+
+    assert!(
+        matches!(result, Err(AppError::Conflict(ref m)) if m == "PASSWORD_ALREADY_SET"),
+        "기존 비밀번호 덮어쓰기 거부, got {:?}",
+        result
+    );
+}
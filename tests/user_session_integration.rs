@@ -0,0 +1,186 @@
+//! Phase 3 통합 테스트 — 로그인 기기(세션) 관리.
+//!
+//! ## 범위
+//!
+//! - 목록: 요청한 access token 의 세션에만 `current = true`
+//! - 폐기: DB login 행 revoked + Redis `ak:session` / `ak:refresh` / `ak:user_sessions` 정리
+//! - 이름 변경 / 폐기는 본인 세션만 (다른 사용자 세션 = 404, 대상 세션은 그대로)
+//!
+//! 실제 로그인 2회로 세션을 만들고 `cleanup_test_user` 로 정리한다.
+
+mod common;
+
+use amazing_korean_api::api::auth::dto::LoginReq;
+use amazing_korean_api::api::auth::handler::ParsedUa;
+use amazing_korean_api::api::auth::service::{AuthService, LoginOutcome};
+use amazing_korean_api::api::user::dto::SessionRenameReq;
+use amazing_korean_api::api::user::service::UserService;
+use amazing_korean_api::error::AppError;
+use amazing_korean_api::state::AppState;
+use common::TestUserSpec;
+use redis::AsyncCommands;
+
+/// 비밀번호 로그인으로 세션 1개 생성 → session_id
+async fn login(st: &AppState, spec: &TestUserSpec, ip: &str) -> String {
+    let out = AuthService::login(
+        st,
+        LoginReq {
+            email: spec.email.clone(),
+            password: spec.password.clone(),
+        },
+        ip.to_string(),
+        None,
+        ParsedUa {
+            os: None,
+            browser: None,
+            device: "desktop".into(),
+        },
+    )
+    .await
+    .unwrap_or_else(|e| panic!("login failed: {e:?}"));
+    match out {
+        LoginOutcome::Success(s) => s.login_res.session_id,
+        LoginOutcome::MfaChallenge { .. } => panic!("MFA 미설정 계정인데 MFA 챌린지"),
+    }
+}
+
+async fn refresh_hash(st: &AppState, session_id: &str) -> String {
+    sqlx::query_scalar(
+        "SELECT login_refresh_hash FROM login WHERE login_session_id = CAST($1 AS uuid)",
+    )
+    .bind(session_id)
+    .fetch_one(&st.db)
+    .await
+    .expect("refresh hash")
+}
+
+fn rename_req(name: &str) -> SessionRenameReq {
+    SessionRenameReq {
+        device_name: name.to_string(),
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_list_sessions_marks_only_current_device() {
+    let st = common::make_test_state().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let first = login(&st, &spec, "10.0.44.1").await;
+    let second = login(&st, &spec, "10.0.44.2").await;
+
+    let res = UserService::list_sessions(&st, user_id, &second).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    let res = res.expect("list_sessions");
+    assert_eq!(res.items.len(), 2);
+    let current: Vec<&str> = res
+        .items
+        .iter()
+        .filter(|i| i.current)
+        .map(|i| i.session_id.as_str())
+        .collect();
+    assert_eq!(current, vec![second.as_str()], "요청 세션만 current");
+    assert!(res
+        .items
+        .iter()
+        .any(|i| i.session_id == first && !i.current));
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_revoke_session_removes_redis_keys() {
+    let st = common::make_test_state().await;
+    let spec = TestUserSpec::random();
+    let user_id = common::insert_test_user(&st, &spec).await;
+    let keep = login(&st, &spec, "10.0.44.3").await;
+    let target = login(&st, &spec, "10.0.44.4").await;
+    let hash = refresh_hash(&st, &target).await;
+
+    let res = UserService::revoke_session(&st, user_id, &target, None).await;
+
+    let mut conn = st.redis.get().await.expect("redis conn");
+    let session_alive: bool = conn
+        .exists(format!("ak:session:{target}"))
+        .await
+        .expect("exists session");
+    let refresh_alive: bool = conn
+        .exists(format!("ak:refresh:{hash}"))
+        .await
+        .expect("exists refresh");
+    let in_set: bool = conn
+        .sismember(format!("ak:user_sessions:{user_id}"), &target)
+        .await
+        .expect("sismember");
+    let keep_alive: bool = conn
+        .exists(format!("ak:session:{keep}"))
+        .await
+        .expect("exists kept session");
+    let state: String = sqlx::query_scalar(
+        "SELECT login_state::text FROM login WHERE login_session_id = CAST($1 AS uuid)",
+    )
+    .bind(&target)
+    .fetch_one(&st.db)
+    .await
+    .expect("login state");
+    let listed = UserService::list_sessions(&st, user_id, &keep).await;
+
+    common::cleanup_test_user(&st, user_id).await;
+
+    assert!(res.is_ok(), "revoke: {:?}", res.err());
+    assert!(!session_alive, "ak:session 삭제");
+    assert!(!refresh_alive, "ak:refresh 삭제");
+    assert!(!in_set, "ak:user_sessions 에서 제거");
+    assert!(keep_alive, "다른 세션은 유지");
+    assert_eq!(state, "revoked");
+    let listed = listed.expect("list after revoke");
+    assert_eq!(listed.items.len(), 1);
+    assert_eq!(listed.items[0].session_id, keep);
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_rename_and_revoke_only_own_sessions() {
+    let st = common::make_test_state().await;
+    let owner_spec = TestUserSpec::random();
+    let owner = common::insert_test_user(&st, &owner_spec).await;
+    let other = common::insert_test_user(&st, &TestUserSpec::random()).await;
+    let owner_session = login(&st, &owner_spec, "10.0.44.5").await;
+
+    let rename_other =
+        UserService::rename_session(&st, other, "", &owner_session, rename_req("hijack")).await;
+    let revoke_other = UserService::revoke_session(&st, other, &owner_session, None).await;
+    let rename_own = UserService::rename_session(
+        &st,
+        owner,
+        &owner_session,
+        &owner_session,
+        rename_req("내 노트북"),
+    )
+    .await;
+
+    let mut conn = st.redis.get().await.expect("redis conn");
+    let still_alive: bool = conn
+        .exists(format!("ak:session:{owner_session}"))
+        .await
+        .expect("exists session");
+
+    common::cleanup_test_user(&st, owner).await;
+    common::cleanup_test_user(&st, other).await;
+
+    assert!(
+        matches!(rename_other, Err(AppError::NotFound)),
+        "다른 사용자 세션 이름 변경 = 404, got {:?}",
+        rename_other
+    );
+    assert!(
+        matches!(revoke_other, Err(AppError::NotFound)),
+        "다른 사용자 세션 폐기 = 404, got {:?}",
+        revoke_other
+    );
+    assert!(still_alive, "거부된 폐기는 세션에 영향 없음");
+    let renamed = rename_own.expect("rename own session");
+    assert_eq!(renamed.device_name.as_deref(), Some("내 노트북"));
+    assert!(renamed.current);
+}
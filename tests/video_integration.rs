@@ -1,10 +1,11 @@
 //! Phase 3 통합 테스트 — `VideoService` (B6 트랙).
 //!
-//! ## 범위 — pagination validation + non-existent lookup
+//! ## 범위 — pagination validation + non-existent lookup + 첫 진도 보고 기준 시각
+//!   + Vimeo 전체 동기화 동시 시작 락
 
 mod common;
 
-use amazing_korean_api::api::video::dto::VideoListReq;
+use amazing_korean_api::api::video::dto::{VideoListReq, VideoProgressUpdateReq, WatchSegment};
 use amazing_korean_api::api::video::service::VideoService;
 use amazing_korean_api::error::AppError;
 
@@ -79,3 +80,106 @@ async fn test_list_videos_happy_returns_default_pagination() {
     assert_eq!(res.meta.current_page, 1, "page=1");
     assert_eq!(res.meta.per_page, 20, "per_page=20");
 }
+
+fn segment_report(start_sec: i32, end_sec: i32) -> VideoProgressUpdateReq {
+    VideoProgressUpdateReq {
+        progress_rate: 0,
+        watch_duration_sec: end_sec - start_sec,
+        segments: vec![WatchSegment { start_sec, end_sec }],
+    }
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_first_progress_report_uses_playback_start_as_baseline() {
+    use redis::AsyncCommands;
+
+    let st = common::make_test_state().await;
+    let with_start = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    let without_start = common::insert_test_user(&st, &common::TestUserSpec::random()).await;
+    let video_id: i32 = sqlx::query_scalar(
+        r#"INSERT INTO video (video_idx, video_title, video_url_vimeo, video_duration, video_state)
+           VALUES ($1, 'progress baseline', 'https://vimeo.com/1', 600, 'open')
+           RETURNING video_id"#,
+    )
+    .bind(format!("vp-it-{}", uuid::Uuid::new_v4().simple()))
+    .fetch_one(&st.db)
+    .await
+    .expect("seed video");
+    let video_id = i64::from(video_id);
+
+    // 60초 전에 재생 발급을 받은 것으로 기록 (get_playback 과 같은 키)
+    let mut conn = st.redis.get().await.expect("redis");
+    let _: () = conn
+        .set_ex(
+            format!("video:watch_start:{with_start}:{video_id}"),
+            chrono::Utc::now().timestamp() - 60,
+            60,
+        )
+        .await
+        .expect("seed watch start");
+
+    // 첫 보고가 여유분(30초)보다 길어도 재생 시작 이후 경과 시간 안이면 인정
+    let started =
+        VideoService::update_video_progress(&st, with_start, video_id, segment_report(0, 90)).await;
+    // 재생 시작 기록이 없으면 기존대로 여유분까지만
+    let unstarted =
+        VideoService::update_video_progress(&st, without_start, video_id, segment_report(0, 90))
+            .await;
+
+    for table in ["video_watch_range", "video_log", "video_stat_daily"] {
+        sqlx::query(&format!("DELETE FROM {table} WHERE video_id = $1"))
+            .bind(video_id as i32)
+            .execute(&st.db)
+            .await
+            .expect("cleanup video rows");
+    }
+    sqlx::query("DELETE FROM video WHERE video_id = $1")
+        .bind(video_id as i32)
+        .execute(&st.db)
+        .await
+        .expect("cleanup video");
+    common::cleanup_test_user(&st, with_start).await;
+    common::cleanup_test_user(&st, without_start).await;
+
+    let started = started.expect("first report within elapsed playback time");
+    assert_eq!(started.watched_sec, 90);
+    assert_eq!(started.progress_rate, 15);
+    assert!(
+        matches!(unstarted, Err(AppError::Unprocessable(_))),
+        "got {:?}",
+        unstarted.map(|r| r.watched_sec)
+    );
+}
+
+#[ignore = "requires local PostgreSQL + Redis + .env.test (Phase 3 보류 정책)"]
+#[tokio::test]
+async fn test_concurrent_full_vimeo_sync_starts_only_one_run() {
+    use amazing_korean_api::jobs::vimeo_sync::start_run;
+    use redis::AsyncCommands;
+
+    let st = common::make_test_state().await;
+    let lock_key = "ak:vimeo_sync:full_run";
+    {
+        let mut conn = st.redis.get().await.expect("redis conn");
+        let _: () = conn.del(lock_key).await.expect("clear lock");
+    }
+
+    let (a, b) = tokio::join!(
+        start_run(&st.db, &st.redis, "manual", None, None),
+        start_run(&st.db, &st.redis, "schedule", None, None),
+    );
+    let (started, rejected) = match (a, b) {
+        (Ok(run), Err(e)) | (Err(e), Ok(run)) => (run, e),
+        (a, b) => panic!("exactly one full run expected, got {:?} / {:?}", a, b),
+    };
+    assert!(matches!(rejected, AppError::Conflict(_)), "{:?}", rejected);
+
+    sqlx::query("DELETE FROM vimeo_sync_run WHERE run_id = $1")
+        .bind(started.run_id)
+        .execute(&st.db)
+        .await
+        .expect("cleanup run");
+    let mut conn = st.redis.get().await.expect("redis conn");
+    let _: () = conn.del(lock_key).await.expect("release lock");
+}